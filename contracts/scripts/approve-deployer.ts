@@ -2,6 +2,7 @@ import { ApiPromise, WsProvider } from '@polkadot/api';
 import { Keyring } from '@polkadot/keyring';
 import { cryptoWaitReady, blake2AsU8a, encodeAddress } from '@polkadot/util-crypto';
 import { u8aConcat } from '@polkadot/util';
+import { networkOptions, signedExtensions } from './qnch-network';
 
 // Configuration
 const WS_ENDPOINT = process.env.WS_ENDPOINT || 'ws://127.0.0.1:8545';
@@ -75,7 +76,7 @@ export async function approveDeployer(accountAddress: string): Promise<boolean>
   // Connect to the chain
   console.log(`📡 Connecting to ${WS_ENDPOINT}...`);
   const provider = new WsProvider(WS_ENDPOINT);
-  const api = await ApiPromise.create({ provider });
+  const api = await ApiPromise.create({ provider, signedExtensions });
 
   // Get chain info
   const chain = await api.rpc.system.chain();
@@ -102,7 +103,7 @@ export async function approveDeployer(accountAddress: string): Promise<boolean>
 
     // Sign and send
     console.log('  📤 Submitting sudo transaction...');
-    const hash = await sudoTx.signAndSend(alice);
+    const hash = await sudoTx.signAndSend(alice, await networkOptions(api));
     console.log(`  ✅ Transaction sent: ${hash.toString()}`);
 
     // Wait for finalization
@@ -136,7 +137,7 @@ export async function isDeployerAuthorized(accountAddress: string): Promise<bool
   await cryptoWaitReady();
 
   const provider = new WsProvider(WS_ENDPOINT);
-  const api = await ApiPromise.create({ provider });
+  const api = await ApiPromise.create({ provider, signedExtensions });
 
   try {
     // Convert H160 to AccountId32 for querying
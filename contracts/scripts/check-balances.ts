@@ -4,6 +4,7 @@ import { u8aConcat } from '@polkadot/util';
 import { HDKey } from '@scure/bip32';
 import { mnemonicToSeedSync } from '@scure/bip39';
 import { computeAddress } from 'ethers';
+import { signedExtensions } from './qnch-network';
 
 // Configuration
 const WS_ENDPOINT = process.env.WS_ENDPOINT || 'ws://127.0.0.1:8545';
@@ -62,7 +63,7 @@ async function main() {
 
   // Connect to the chain
   const provider = new WsProvider(WS_ENDPOINT);
-  const api = await ApiPromise.create({ provider });
+  const api = await ApiPromise.create({ provider, signedExtensions });
 
   // Derive the Hardhat accounts
   const accounts = deriveHardhatAccounts(HARDHAT_MNEMONIC, ACCOUNT_COUNT);
@@ -5,6 +5,7 @@ import { u8aConcat } from '@polkadot/util';
 import { HDKey } from '@scure/bip32';
 import { mnemonicToSeedSync } from '@scure/bip39';
 import { computeAddress } from 'ethers';
+import { networkOptions, signedExtensions } from './qnch-network';
 
 // Configuration
 const WS_ENDPOINT = process.env.WS_ENDPOINT || 'ws://127.0.0.1:8545';
@@ -70,7 +71,8 @@ async function main() {
   // Connect to the chain
   console.log(`📡 Connecting to ${WS_ENDPOINT}...`);
   const provider = new WsProvider(WS_ENDPOINT);
-  const api = await ApiPromise.create({ provider });
+  const api = await ApiPromise.create({ provider, signedExtensions });
+  const options = await networkOptions(api);
 
   // Get chain info
   const chain = await api.rpc.system.chain();
@@ -127,7 +129,7 @@ async function main() {
       await new Promise<string>((resolve, reject) => {
         let unsubscribe: (() => void) | undefined;
 
-        transfer.signAndSend(alice, ({ status, dispatchError }) => {
+        transfer.signAndSend(alice, options, ({ status, dispatchError }) => {
           if (status.isInBlock) {
             console.log(`  ✅ Transaction included in block: ${status.asInBlock.toString()}`);
 
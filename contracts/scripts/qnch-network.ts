@@ -0,0 +1,31 @@
+import { ApiPromise } from '@polkadot/api';
+
+/**
+ * `CheckNetwork` signed extension of the qnch runtime, to pass to `ApiPromise.create`
+ *
+ * Substrate transactions name the EVM chain id of the network they are meant for and sign
+ * its genesis hash, so that a transaction signed for staging is refused by production and
+ * the other way round. The genesis hash is signed under its own name, next to the one of
+ * `CheckGenesis`.
+ */
+export const signedExtensions = {
+  CheckNetwork: {
+    extrinsic: { chainId: 'Compact<u64>' },
+    payload: { networkGenesisHash: 'Hash' },
+  },
+};
+
+/**
+ * Signing options binding a transaction to the network with EVM chain id `CHAIN_ID`
+ *
+ * Without `CHAIN_ID`, transactions are bound to the network `api` is connected to, which
+ * is fine for a local node but defeats the check against the wrong endpoint.
+ */
+export async function networkOptions(api: ApiPromise): Promise<Record<string, unknown>> {
+  let chainId = process.env.CHAIN_ID;
+  if (!chainId) {
+    chainId = (await api.query.evmChainId.chainId()).toString();
+    console.log(`⚠️  CHAIN_ID not set, signing for the connected network (${chainId})`);
+  }
+  return { chainId, networkGenesisHash: api.genesisHash };
+}
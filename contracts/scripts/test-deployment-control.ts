@@ -7,6 +7,7 @@ import { mnemonicToSeedSync } from '@scure/bip39';
 import { ethers, computeAddress } from 'ethers';
 import fs from 'fs';
 import path from 'path';
+import { networkOptions, signedExtensions } from './qnch-network';
 
 // Configuration
 const WS_ENDPOINT = process.env.WS_ENDPOINT || 'ws://127.0.0.1:8545';
@@ -145,7 +146,8 @@ async function main() {
   // Connect to the chain
   console.log(`📡 Connecting to ${WS_ENDPOINT}...`);
   const provider = new WsProvider(WS_ENDPOINT);
-  const api = await ApiPromise.create({ provider });
+  const api = await ApiPromise.create({ provider, signedExtensions });
+  const options = await networkOptions(api);
 
   // Get chain info
   const chain = await api.rpc.system.chain();
@@ -194,7 +196,7 @@ async function main() {
       const spinner = ['⠋', '⠙', '⠹', '⠸', '⠼', '⠴', '⠦', '⠧', '⠇', '⠏'];
       let spinnerIndex = 0;
 
-      sudoTx.signAndSend(alice, ({ status, events, dispatchError }) => {
+      sudoTx.signAndSend(alice, options, ({ status, events, dispatchError }) => {
         // Clear spinner if active
         if (spinnerInterval) {
           clearInterval(spinnerInterval);
@@ -0,0 +1,47 @@
+//! `qnch_addressOf`, converting between EVM addresses and native accounts.
+
+use jsonrpsee::{core::RpcResult, proc_macros::rpc};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use sp_core::H160;
+
+use crate::schema::Address;
+
+/// An EVM address or a native account
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(untagged)]
+pub enum AddressOrAccount<AccountId> {
+    /// EVM address, `0x` prefixed hex
+    Address(#[schemars(with = "Address")] H160),
+    /// Native account, in the account type's own format
+    Account(AccountId),
+}
+
+/// Both sides of an EVM address and its account
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct AddressPair<AccountId> {
+    /// EVM address, `null` if the account is given and its address is unknown
+    #[schemars(with = "Option<Address>")]
+    pub address: Option<H160>,
+    /// Account holding the native balance of the address
+    pub account: AccountId,
+    /// Whether the address and the account are the same, as with 20-byte accounts
+    pub unified: bool,
+}
+
+/// Address mapping RPC methods.
+#[rpc(server)]
+pub trait AddressMappingApi<BlockHash, AccountId> {
+    /// The account of an EVM address, or the EVM address of an account
+    ///
+    /// Addresses map to accounts through the runtime's address mapping. Unless accounts are
+    /// the addresses themselves, the mapping is one-way and the address of an account is
+    /// only known once the account was seen at it.
+    #[method(name = "qnch_addressOf")]
+    fn address_of(
+        &self,
+        address: AddressOrAccount<AccountId>,
+        at: Option<BlockHash>,
+    ) -> RpcResult<AddressPair<AccountId>>;
+}
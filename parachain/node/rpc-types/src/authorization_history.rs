@@ -0,0 +1,34 @@
+//! `qnch_wasAuthorizedAt`, answering whether an account was an authorized deployer at a past
+//! block.
+
+use jsonrpsee::{core::RpcResult, proc_macros::rpc};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// Authorization of an account at a past block
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct AuthorizationAt<Hash, BlockNumber> {
+    /// Hash of the block the answer refers to
+    pub block_hash: Hash,
+    /// Whether the account was an authorized deployer at the block
+    pub authorized: bool,
+    /// Block at which the account was authorized, if it was and the answer comes from the
+    /// state of the block
+    pub authorized_at: Option<BlockNumber>,
+}
+
+/// Authorization history RPC methods.
+#[rpc(server)]
+pub trait AuthorizationHistoryApi<Hash, AccountId, BlockNumber> {
+    /// Whether `account` was an authorized deployer at the block `block` of the best chain
+    ///
+    /// Answered from the state of the block, or from the authorization transitions index at
+    /// the best block when the node pruned that state.
+    #[method(name = "qnch_wasAuthorizedAt")]
+    fn was_authorized_at(
+        &self,
+        account: AccountId,
+        block: BlockNumber,
+    ) -> RpcResult<AuthorizationAt<Hash, BlockNumber>>;
+}
@@ -0,0 +1,9 @@
+//! Print the OpenRPC document of the `qnch_*` methods
+
+fn main() {
+    let document = qnch_rpc_types::openrpc::document();
+    println!(
+        "{}",
+        serde_json::to_string_pretty(&document).expect("documents are plain JSON values; qed")
+    );
+}
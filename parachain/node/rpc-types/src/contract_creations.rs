@@ -0,0 +1,41 @@
+//! `qnch_contractCreations`, listing the contracts created in a block, internal creations
+//! included.
+
+use jsonrpsee::{core::RpcResult, proc_macros::rpc};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use sp_core::H160;
+
+use crate::schema::Address;
+
+/// A contract creation, by a transaction or by a contract
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ContractCreation {
+    /// Index of the extrinsic creating the contract
+    pub extrinsic_index: u32,
+    /// Address executing the creation: the transaction sender, or the contract running
+    /// `CREATE` or `CREATE2`
+    #[schemars(with = "Address")]
+    pub creator: H160,
+    /// Address of the created contract
+    #[schemars(with = "Address")]
+    pub contract: H160,
+    /// Whether the creator holds code at the block, the creation being internal
+    pub internal: bool,
+}
+
+/// Contract creation index RPC methods.
+#[rpc(server)]
+pub trait ContractCreationsApi<BlockHash> {
+    /// Contracts created in the block `at`, the best block by default, in order of creation
+    ///
+    /// Read from the offchain index rather than by replaying the block, which requires the
+    /// node to run with `--enable-offchain-indexing true`. `null` for blocks imported before
+    /// the index was enabled.
+    #[method(name = "qnch_contractCreations")]
+    fn contract_creations(
+        &self,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Option<Vec<ContractCreation>>>;
+}
@@ -0,0 +1,63 @@
+//! `qnch_contractInfo`, `qnch_listFactories` and `qnch_deployerStatus`, exposing EVM
+//! deployment control state.
+
+use jsonrpsee::{core::RpcResult, proc_macros::rpc};
+use pallet_evm_deployment_control_runtime_api::{ContractInfo, DeployerStatus, FactoryEntry};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use sp_core::H160;
+
+/// Number of entries returned when a page does not specify a limit
+pub const DEFAULT_PAGE_SIZE: u32 = 100;
+
+/// Maximum number of entries returned in a single page
+pub const MAX_PAGE_SIZE: u32 = 1000;
+
+/// Page of a list query
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct Pagination<Key> {
+    /// Return entries after this key, from the start if `None`
+    pub start_after: Option<Key>,
+    /// Maximum number of entries to return, capped at 1000
+    pub limit: Option<u32>,
+}
+
+impl<Key> Pagination<Key> {
+    /// Number of entries to return
+    pub fn page_size(&self) -> u32 {
+        self.limit.unwrap_or(DEFAULT_PAGE_SIZE).min(MAX_PAGE_SIZE)
+    }
+}
+
+/// Deployment control RPC methods.
+#[rpc(client, server)]
+pub trait DeploymentControlApi<BlockHash, AccountId, BlockNumber> {
+    /// Code, storage and provenance information of the contract at `address`
+    ///
+    /// Returns `null` if there is no code at the address.
+    #[method(name = "qnch_contractInfo")]
+    fn contract_info(
+        &self,
+        address: H160,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Option<ContractInfo<BlockNumber>>>;
+
+    /// Approved factories in address order
+    ///
+    /// Pass the address of the last returned factory as `startAfter` to fetch the next page.
+    #[method(name = "qnch_listFactories")]
+    fn list_factories(
+        &self,
+        pagination: Option<Pagination<H160>>,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<FactoryEntry<AccountId, BlockNumber>>>;
+
+    /// Authorization of `account` to deploy contracts
+    #[method(name = "qnch_deployerStatus")]
+    fn deployer_status(
+        &self,
+        account: AccountId,
+        at: Option<BlockHash>,
+    ) -> RpcResult<DeployerStatus<BlockNumber>>;
+}
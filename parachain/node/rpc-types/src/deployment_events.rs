@@ -0,0 +1,52 @@
+//! `qnch_subscribeDeploymentEvents`, streaming deployment control events.
+
+use jsonrpsee::proc_macros::rpc;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use sp_core::H160;
+
+use crate::schema::Address;
+
+/// A deployment control event
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(tag = "type")]
+pub enum DeploymentEvent<AccountId> {
+    /// A deployer was authorized
+    DeployerAuthorized { deployer: AccountId },
+    /// A deployer's authorization was revoked
+    DeployerRevoked { deployer: AccountId },
+    /// A contract was deployed through `deploy_contract`
+    ContractDeployed {
+        deployer: AccountId,
+        #[schemars(with = "Address")]
+        contract: H160,
+        label: String,
+        version: String,
+    },
+}
+
+/// A deployment control event and the block it was emitted in
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct DeploymentEventNotification<AccountId, Hash, BlockNumber> {
+    /// Hash of the block
+    pub block_hash: Hash,
+    /// Number of the block
+    pub block_number: BlockNumber,
+    /// Index of the extrinsic emitting the event, `None` for events of block hooks
+    pub extrinsic_index: Option<u32>,
+    /// The event
+    pub event: DeploymentEvent<AccountId>,
+}
+
+/// Deployment control subscriptions.
+#[rpc(server)]
+pub trait DeploymentEventsApi<AccountId, Hash, BlockNumber> {
+    /// Stream the deployment control events of every finalized block, in block order
+    #[subscription(
+        name = "qnch_subscribeDeploymentEvents" => "qnch_deploymentEvent",
+        unsubscribe = "qnch_unsubscribeDeploymentEvents",
+        item = DeploymentEventNotification<AccountId, Hash, BlockNumber>
+    )]
+    fn subscribe_deployment_events(&self);
+}
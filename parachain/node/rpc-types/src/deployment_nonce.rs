@@ -0,0 +1,56 @@
+//! `qnch_nextDeploymentNonce`, everything a deployment pipeline needs before signing its
+//! next contract creation.
+
+use jsonrpsee::{core::RpcResult, proc_macros::rpc};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use sp_core::{H160, H256, U256};
+
+use crate::schema::Hash;
+
+/// Nonce of an address and the deployment control state its next contract creation
+/// depends on
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct NextDeploymentNonce<BlockHash, BlockNumber> {
+    /// Best block the state was read at
+    pub block_hash: BlockHash,
+    /// Nonce to sign the next transaction of the address with, following its transactions
+    /// ready in the pool
+    #[schemars(with = "String")]
+    pub nonce: U256,
+    /// Transactions of the address ready in the pool
+    pub pending: u32,
+    /// Contract creations among them
+    pub pending_creations: u32,
+    /// `DeploymentValidationError` code a contract creation of the address would be refused
+    /// with in the next block, `null` if it would be admitted
+    pub refusal_code: Option<u8>,
+    /// Explanation of the refusal
+    pub refusal: Option<String>,
+    /// Contract creations left to the address in the quota period of the next block once
+    /// its pending creations are applied, `null` without a quota
+    pub remaining_quota: Option<u32>,
+    /// First block of the next quota period, `null` without a quota
+    pub quota_resets_at: Option<BlockNumber>,
+    /// Init code hashes of the deployment intents the address announced and has not
+    /// fulfilled yet
+    #[schemars(with = "Vec<Hash>")]
+    pub announced_intents: Vec<H256>,
+}
+
+/// Deployment nonce RPC methods.
+#[rpc(server)]
+pub trait DeploymentNonceApi<BlockHash, BlockNumber> {
+    /// Nonce of `address` and the deployment control state its next contract creation
+    /// depends on, in a single read of the best block and the transaction pool
+    ///
+    /// Deployment pipelines sign a sequence of transactions from the returned nonce on,
+    /// knowing whether the creations will be admitted, how many the quota still allows and
+    /// which intents are announced.
+    #[method(name = "qnch_nextDeploymentNonce")]
+    fn next_deployment_nonce(
+        &self,
+        address: H160,
+    ) -> RpcResult<NextDeploymentNonce<BlockHash, BlockNumber>>;
+}
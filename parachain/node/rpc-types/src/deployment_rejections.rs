@@ -0,0 +1,42 @@
+//! `qnch_deploymentRejection`, explaining why a contract creation was dropped.
+
+use jsonrpsee::{core::RpcResult, proc_macros::rpc};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use sp_core::H256;
+
+/// Why a contract creation was removed from the pool
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct DeploymentRejection<Hash, BlockNumber> {
+    /// `DeploymentValidationError` code of the refusal
+    pub code: u8,
+    /// Best block on top of which the transaction was checked
+    pub block_hash: Hash,
+    /// Number of that block
+    pub block_number: BlockNumber,
+}
+
+/// Why a contract creation was removed from the transaction pool
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct Rejection<Hash, BlockNumber> {
+    /// Explanation of the refusal
+    pub reason: String,
+    /// Refusal code and the block it was checked at
+    #[serde(flatten)]
+    pub rejection: DeploymentRejection<Hash, BlockNumber>,
+}
+
+/// Deployment rejection RPC methods.
+#[rpc(server)]
+pub trait DeploymentRejectionsApi<Hash, BlockNumber> {
+    /// Why the contract creation `transaction_hash` was removed from the pool
+    ///
+    /// Returns `null` if this node did not remove the transaction or has forgotten about it.
+    #[method(name = "qnch_deploymentRejection")]
+    fn deployment_rejection(
+        &self,
+        transaction_hash: H256,
+    ) -> RpcResult<Option<Rejection<Hash, BlockNumber>>>;
+}
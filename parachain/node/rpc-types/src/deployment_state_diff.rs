@@ -0,0 +1,43 @@
+//! `qnch_deploymentStateDiff`, listing deployment control changes between two blocks.
+
+use jsonrpsee::{core::RpcResult, proc_macros::rpc};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use sp_core::H160;
+
+use crate::schema::Address;
+
+/// Deployers and factories added and removed between two blocks
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct DeploymentStateDiff<AccountId, BlockNumber> {
+    /// Number of the block the diff starts from
+    pub from_block: BlockNumber,
+    /// Number of the block the diff ends at
+    pub to_block: BlockNumber,
+    /// Deployers authorized at `toBlock` but not at `fromBlock`
+    pub added_deployers: Vec<AccountId>,
+    /// Deployers authorized at `fromBlock` but no longer at `toBlock`
+    pub removed_deployers: Vec<AccountId>,
+    /// Factories approved at `toBlock` but not at `fromBlock`
+    #[schemars(with = "Vec<Address>")]
+    pub added_factories: Vec<H160>,
+    /// Factories approved at `fromBlock` but no longer at `toBlock`
+    #[schemars(with = "Vec<Address>")]
+    pub removed_factories: Vec<H160>,
+}
+
+/// Deployment state diff RPC methods.
+#[rpc(server)]
+pub trait DeploymentStateDiffApi<BlockHash, AccountId, BlockNumber> {
+    /// Deployers and factories added and removed between `from_block` and `to_block`
+    ///
+    /// Computed from the deployment control storage at both blocks, so an entry removed and
+    /// added back in between does not appear. A suspended factory is still approved.
+    #[method(name = "qnch_deploymentStateDiff")]
+    fn deployment_state_diff(
+        &self,
+        from_block: BlockHash,
+        to_block: BlockHash,
+    ) -> RpcResult<DeploymentStateDiff<AccountId, BlockNumber>>;
+}
@@ -0,0 +1,31 @@
+//! `qnch_gasToWeight` and `qnch_weightToGas`, converting between EVM gas and transaction
+//! weight.
+
+use jsonrpsee::{core::RpcResult, proc_macros::rpc};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// Weight of a transaction, in the two dimensions a block limits
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct TransactionWeight {
+    /// Execution time, in picoseconds
+    pub ref_time: u64,
+    /// Proof size, in bytes
+    pub proof_size: u64,
+}
+
+/// Gas weight RPC methods.
+#[rpc(server)]
+pub trait GasWeightApi<BlockHash> {
+    /// Weight an Ethereum transaction with the gas limit `gas` takes in a block
+    ///
+    /// Gas limits reserve proof size as well as execution time, so a gas limit fitting in
+    /// the execution time of a block can still be too high for its proof size.
+    #[method(name = "qnch_gasToWeight")]
+    fn gas_to_weight(&self, gas: u64, at: Option<BlockHash>) -> RpcResult<TransactionWeight>;
+
+    /// Largest gas limit of an Ethereum transaction whose weight fits in `weight`
+    #[method(name = "qnch_weightToGas")]
+    fn weight_to_gas(&self, weight: TransactionWeight, at: Option<BlockHash>) -> RpcResult<u64>;
+}
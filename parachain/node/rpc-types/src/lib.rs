@@ -0,0 +1,43 @@
+//! # qnch RPC types
+//!
+//! Definitions of the custom `qnch_*` JSON-RPC methods served by the node, with the types
+//! they carry, and the OpenRPC document derived from them. The node implements the server
+//! traits; the polkadot.js and ethers plugins are generated from the document, printed by
+//! the `qnch-openrpc` binary.
+//!
+//! Every method of the traits must be described in [`openrpc::document`], which the tests
+//! check against the methods the servers register.
+
+mod address_mapping;
+mod authorization_history;
+mod contract_creations;
+mod deployment_control;
+mod deployment_events;
+mod deployment_nonce;
+mod deployment_rejections;
+mod deployment_state_diff;
+mod gas_weight;
+pub mod openrpc;
+pub mod schema;
+mod session_keys;
+mod tx_diagnostics;
+
+#[cfg(test)]
+mod tests;
+
+pub use self::{
+    address_mapping::{AddressMappingApiServer, AddressOrAccount, AddressPair},
+    authorization_history::{AuthorizationAt, AuthorizationHistoryApiServer},
+    contract_creations::{ContractCreation, ContractCreationsApiServer},
+    deployment_control::{
+        DeploymentControlApiClient, DeploymentControlApiServer, Pagination, DEFAULT_PAGE_SIZE,
+        MAX_PAGE_SIZE,
+    },
+    deployment_events::{DeploymentEvent, DeploymentEventNotification, DeploymentEventsApiServer},
+    deployment_nonce::{DeploymentNonceApiServer, NextDeploymentNonce},
+    deployment_rejections::{DeploymentRejection, DeploymentRejectionsApiServer, Rejection},
+    deployment_state_diff::{DeploymentStateDiff, DeploymentStateDiffApiServer},
+    gas_weight::{GasWeightApiServer, TransactionWeight},
+    session_keys::{CollatorKeys, QueuedSessionKeys, SessionKeysApiServer},
+    tx_diagnostics::{Diagnosis, TransactionDiagnosticsApiServer},
+};
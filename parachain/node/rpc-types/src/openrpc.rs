@@ -0,0 +1,229 @@
+//! OpenRPC document of the `qnch_*` methods
+//!
+//! Parameter and result schemas are derived from the types of the method definitions, with
+//! the generic parameters substituted by the stand-ins of [`crate::schema`].
+
+use pallet_evm_deployment_control_runtime_api::{ContractInfo, DeployerStatus, FactoryEntry};
+use schemars::{gen::SchemaGenerator, gen::SchemaSettings, JsonSchema};
+use serde_json::{json, Value};
+
+use crate::{
+    schema::{AccountId, Address, BlockNumber, Data, Hash},
+    AddressOrAccount, AddressPair, AuthorizationAt, ContractCreation, DeploymentEventNotification,
+    DeploymentStateDiff, Diagnosis, NextDeploymentNonce, Pagination, QueuedSessionKeys, Rejection,
+    TransactionWeight,
+};
+
+/// Version of the OpenRPC specification the document follows
+const OPENRPC_VERSION: &str = "1.2.6";
+
+/// OpenRPC document describing every `qnch_*` method
+pub fn document() -> Value {
+    let mut gen = SchemaSettings::draft07()
+        .with(|settings| settings.definitions_path = "#/components/schemas/".into())
+        .into_generator();
+
+    let methods = vec![
+        method(
+            "qnch_contractInfo",
+            "Code, storage and provenance information of the contract at `address`, `null` \
+             if there is no code at the address",
+            vec![
+                param::<Address>(&mut gen, "address", true),
+                param::<Hash>(&mut gen, "at", false),
+            ],
+            param::<Option<ContractInfo<BlockNumber>>>(&mut gen, "contractInfo", false),
+        ),
+        method(
+            "qnch_listFactories",
+            "Approved factories in address order, pass the address of the last returned \
+             factory as `startAfter` to fetch the next page",
+            vec![
+                param::<Pagination<Address>>(&mut gen, "pagination", false),
+                param::<Hash>(&mut gen, "at", false),
+            ],
+            param::<Vec<FactoryEntry<AccountId, BlockNumber>>>(&mut gen, "factories", true),
+        ),
+        method(
+            "qnch_deployerStatus",
+            "Authorization of `account` to deploy contracts, answered from the storage of \
+             runtimes that predate the `deployer_status` runtime API",
+            vec![
+                param::<AccountId>(&mut gen, "account", true),
+                param::<Hash>(&mut gen, "at", false),
+            ],
+            param::<DeployerStatus<BlockNumber>>(&mut gen, "status", true),
+        ),
+        method(
+            "qnch_wasAuthorizedAt",
+            "Whether `account` was an authorized deployer at the block `block` of the best \
+             chain, answered from the state of the block or, once it is pruned, from the \
+             authorization transitions index",
+            vec![
+                param::<AccountId>(&mut gen, "account", true),
+                param::<BlockNumber>(&mut gen, "block", true),
+            ],
+            param::<AuthorizationAt<Hash, BlockNumber>>(&mut gen, "authorization", true),
+        ),
+        method(
+            "qnch_deploymentRejection",
+            "Why the contract creation `transactionHash` was removed from the pool, `null` if \
+             this node did not remove the transaction or has forgotten about it",
+            vec![param::<Hash>(&mut gen, "transactionHash", true)],
+            param::<Option<Rejection<Hash, BlockNumber>>>(&mut gen, "rejection", false),
+        ),
+        method(
+            "qnch_nextDeploymentNonce",
+            "Nonce to sign the next transaction of `address` with, following its transactions \
+             ready in the pool, with whether its next contract creation would be admitted, \
+             its remaining deployment quota and its announced deployment intents",
+            vec![param::<Address>(&mut gen, "address", true)],
+            param::<NextDeploymentNonce<Hash, BlockNumber>>(&mut gen, "nextNonce", true),
+        ),
+        method(
+            "qnch_deploymentStateDiff",
+            "Deployers and factories added and removed between `fromBlock` and `toBlock`, \
+             computed from the deployment control storage at both blocks",
+            vec![
+                param::<Hash>(&mut gen, "fromBlock", true),
+                param::<Hash>(&mut gen, "toBlock", true),
+            ],
+            param::<DeploymentStateDiff<AccountId, BlockNumber>>(&mut gen, "diff", true),
+        ),
+        method(
+            "qnch_whyFailed",
+            "Why the Ethereum transaction `transactionHash` failed, `null` if the transaction \
+             is not in a canonical block",
+            vec![param::<Hash>(&mut gen, "transactionHash", true)],
+            param::<Option<Diagnosis>>(&mut gen, "diagnosis", false),
+        ),
+        method(
+            "qnch_queuedSessionKeys",
+            "Collators of the next session with the keys they will author with; keys set \
+             with `session.setKeys` are queued at the next session change and used from the \
+             one after",
+            vec![param::<Hash>(&mut gen, "at", false)],
+            param::<QueuedSessionKeys<AccountId>>(&mut gen, "queuedKeys", true),
+        ),
+        method(
+            "qnch_nextSessionKeys",
+            "Keys `collator` last set with `session.setKeys`, `null` if the collator never set \
+             keys or purged them",
+            vec![
+                param::<AccountId>(&mut gen, "collator", true),
+                param::<Hash>(&mut gen, "at", false),
+            ],
+            param::<Option<Data>>(&mut gen, "keys", false),
+        ),
+        method(
+            "qnch_addressOf",
+            "The account holding the native balance of an EVM address, or the EVM address of \
+             an account, `null` if the address mapping is one-way and the account was never \
+             seen at an address",
+            vec![
+                param::<AddressOrAccount<AccountId>>(&mut gen, "address", true),
+                param::<Hash>(&mut gen, "at", false),
+            ],
+            param::<AddressPair<AccountId>>(&mut gen, "pair", true),
+        ),
+        method(
+            "qnch_gasToWeight",
+            "Weight an Ethereum transaction with the gas limit `gas` takes in a block, with \
+             the proof size the gas limit reserves",
+            vec![
+                param::<u64>(&mut gen, "gas", true),
+                param::<Hash>(&mut gen, "at", false),
+            ],
+            param::<TransactionWeight>(&mut gen, "weight", true),
+        ),
+        method(
+            "qnch_weightToGas",
+            "Largest gas limit of an Ethereum transaction whose weight fits in `weight`, \
+             bounded by both its execution time and its proof size",
+            vec![
+                param::<TransactionWeight>(&mut gen, "weight", true),
+                param::<Hash>(&mut gen, "at", false),
+            ],
+            param::<u64>(&mut gen, "gas", true),
+        ),
+        method(
+            "qnch_contractCreations",
+            "Contracts created in the block `at`, internal creations included, read from the \
+             offchain index of nodes running with `--enable-offchain-indexing true`, `null` for \
+             blocks imported before the index was enabled",
+            vec![param::<Hash>(&mut gen, "at", false)],
+            param::<Option<Vec<ContractCreation>>>(&mut gen, "creations", false),
+        ),
+        subscription(
+            "qnch_subscribeDeploymentEvents",
+            "qnch_deploymentEvent",
+            "Stream the deployment control events of every finalized block, in block order",
+            param::<DeploymentEventNotification<AccountId, Hash, BlockNumber>>(
+                &mut gen,
+                "notification",
+                true,
+            ),
+        ),
+        method(
+            "qnch_unsubscribeDeploymentEvents",
+            "Stop a `qnch_subscribeDeploymentEvents` subscription",
+            vec![param::<String>(&mut gen, "subscription", true)],
+            param::<bool>(&mut gen, "unsubscribed", true),
+        ),
+    ];
+
+    json!({
+        "openrpc": OPENRPC_VERSION,
+        "info": {
+            "title": "qnch JSON-RPC methods",
+            "version": env!("CARGO_PKG_VERSION"),
+        },
+        "methods": methods,
+        "components": {
+            "schemas": gen.take_definitions(),
+        },
+    })
+}
+
+/// Names of the methods of the document
+pub fn method_names(document: &Value) -> Vec<&str> {
+    document["methods"]
+        .as_array()
+        .into_iter()
+        .flatten()
+        .filter_map(|method| method["name"].as_str())
+        .collect()
+}
+
+fn method(name: &str, summary: &str, params: Vec<Value>, result: Value) -> Value {
+    json!({
+        "name": name,
+        "summary": summary,
+        "params": params,
+        "result": result,
+    })
+}
+
+/// A subscription, whose notifications are described by the `x-notification` extension
+fn subscription(name: &str, notification: &str, summary: &str, item: Value) -> Value {
+    let mut method = method(
+        name,
+        summary,
+        Vec::new(),
+        json!({ "name": "subscription", "schema": { "type": "string" } }),
+    );
+    method["x-notification"] = json!({
+        "name": notification,
+        "params": [item],
+    });
+    method
+}
+
+/// Content descriptor of a parameter or result of type `T`
+fn param<T: JsonSchema>(gen: &mut SchemaGenerator, name: &str, required: bool) -> Value {
+    json!({
+        "name": name,
+        "required": required,
+        "schema": gen.subschema_for::<T>(),
+    })
+}
@@ -0,0 +1,71 @@
+//! JSON schemas of the primitive types carried by the methods
+//!
+//! Stand-ins for types without a schema of their own, either given to `#[schemars(with)]`
+//! or substituted for the generic parameters of the method definitions.
+
+use schemars::{
+    gen::SchemaGenerator,
+    schema::{InstanceType, Metadata, Schema, SchemaObject, StringValidation},
+    JsonSchema,
+};
+
+macro_rules! string_schema {
+    ($(#[doc = $doc:literal])* $name:ident, $description:literal, $pattern:literal) => {
+        $(#[doc = $doc])*
+        pub struct $name;
+
+        impl JsonSchema for $name {
+            fn schema_name() -> String {
+                stringify!($name).into()
+            }
+
+            fn json_schema(_: &mut SchemaGenerator) -> Schema {
+                string($description, $pattern)
+            }
+        }
+    };
+}
+
+string_schema!(
+    /// 20 byte EVM address
+    Address,
+    "20 byte EVM address, 0x prefixed hex",
+    "^0x[0-9a-fA-F]{40}$"
+);
+string_schema!(
+    /// 32 byte hash
+    Hash,
+    "32 byte hash, 0x prefixed hex",
+    "^0x[0-9a-fA-F]{64}$"
+);
+string_schema!(
+    /// Arbitrary bytes
+    Data,
+    "Bytes, 0x prefixed hex",
+    "^0x([0-9a-fA-F]{2})*$"
+);
+string_schema!(
+    /// Substrate account
+    AccountId,
+    "32 byte account, SS58 encoded",
+    "^[1-9A-HJ-NP-Za-km-z]{47,48}$"
+);
+
+/// Block number
+pub type BlockNumber = u32;
+
+fn string(description: &str, pattern: &str) -> Schema {
+    SchemaObject {
+        metadata: Some(Box::new(Metadata {
+            description: Some(description.into()),
+            ..Default::default()
+        })),
+        instance_type: Some(InstanceType::String.into()),
+        string: Some(Box::new(StringValidation {
+            pattern: Some(pattern.into()),
+            ..Default::default()
+        })),
+        ..Default::default()
+    }
+    .into()
+}
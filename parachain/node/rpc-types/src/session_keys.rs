@@ -0,0 +1,52 @@
+//! `qnch_queuedSessionKeys` and `qnch_nextSessionKeys`, following the rotation of collator
+//! session keys.
+
+use jsonrpsee::{core::RpcResult, proc_macros::rpc};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use sp_core::Bytes;
+
+use crate::schema::Data;
+
+/// Session keys of a collator
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct CollatorKeys<AccountId> {
+    /// The collator
+    pub collator: AccountId,
+    /// SCALE-encoded session keys, in the layout returned by `author_rotateKeys`
+    #[schemars(with = "Data")]
+    pub keys: Bytes,
+}
+
+/// Collators queued for the next session
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct QueuedSessionKeys<AccountId> {
+    /// Index of the current session
+    pub session: u32,
+    /// Collators of the next session with the keys they will author with
+    pub queued: Vec<CollatorKeys<AccountId>>,
+}
+
+/// Session keys RPC methods.
+#[rpc(server)]
+pub trait SessionKeysApi<BlockHash, AccountId> {
+    /// Collators of the next session with the keys they will author with
+    ///
+    /// Keys set with `session.setKeys` are queued at the next session change and used from
+    /// the one after, so the old keys must stay in the keystore until then.
+    #[method(name = "qnch_queuedSessionKeys")]
+    fn queued_session_keys(&self, at: Option<BlockHash>)
+        -> RpcResult<QueuedSessionKeys<AccountId>>;
+
+    /// Keys `collator` last set with `session.setKeys`
+    ///
+    /// Returns `null` if the collator never set keys or purged them.
+    #[method(name = "qnch_nextSessionKeys")]
+    fn next_session_keys(
+        &self,
+        collator: AccountId,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Option<Bytes>>;
+}
@@ -0,0 +1,216 @@
+use std::collections::BTreeSet;
+
+use jsonrpsee::{
+    core::{async_trait, RpcResult},
+    PendingSubscriptionSink,
+};
+use pallet_evm_deployment_control_runtime_api::{ContractInfo, DeployerStatus, FactoryEntry};
+use serde_json::Value;
+use sp_core::{Bytes, H160, H256};
+
+use crate::*;
+
+/// Server implementing every trait, registering their methods
+struct Stub;
+
+impl DeploymentControlApiServer<H256, String, u32> for Stub {
+    fn contract_info(&self, _: H160, _: Option<H256>) -> RpcResult<Option<ContractInfo<u32>>> {
+        Ok(None)
+    }
+
+    fn list_factories(
+        &self,
+        _: Option<Pagination<H160>>,
+        _: Option<H256>,
+    ) -> RpcResult<Vec<FactoryEntry<String, u32>>> {
+        Ok(Vec::new())
+    }
+
+    fn deployer_status(&self, _: String, _: Option<H256>) -> RpcResult<DeployerStatus<u32>> {
+        Ok(DeployerStatus {
+            authorized: false,
+            authorized_at: None,
+            deployer_admin: false,
+        })
+    }
+}
+
+impl AuthorizationHistoryApiServer<H256, String, u32> for Stub {
+    fn was_authorized_at(&self, _: String, _: u32) -> RpcResult<AuthorizationAt<H256, u32>> {
+        Ok(AuthorizationAt {
+            block_hash: H256::zero(),
+            authorized: false,
+            authorized_at: None,
+        })
+    }
+}
+
+impl AddressMappingApiServer<H256, String> for Stub {
+    fn address_of(
+        &self,
+        address: AddressOrAccount<String>,
+        _: Option<H256>,
+    ) -> RpcResult<AddressPair<String>> {
+        Ok(AddressPair {
+            address: None,
+            account: match address {
+                AddressOrAccount::Address(address) => format!("{address:?}"),
+                AddressOrAccount::Account(account) => account,
+            },
+            unified: false,
+        })
+    }
+}
+
+impl DeploymentEventsApiServer<String, H256, u32> for Stub {
+    fn subscribe_deployment_events(&self, _: PendingSubscriptionSink) {}
+}
+
+impl DeploymentNonceApiServer<H256, u32> for Stub {
+    fn next_deployment_nonce(&self, _: H160) -> RpcResult<NextDeploymentNonce<H256, u32>> {
+        Ok(NextDeploymentNonce {
+            block_hash: H256::zero(),
+            nonce: 0.into(),
+            pending: 0,
+            pending_creations: 0,
+            refusal_code: None,
+            refusal: None,
+            remaining_quota: None,
+            quota_resets_at: None,
+            announced_intents: Vec::new(),
+        })
+    }
+}
+
+impl DeploymentRejectionsApiServer<H256, u32> for Stub {
+    fn deployment_rejection(&self, _: H256) -> RpcResult<Option<Rejection<H256, u32>>> {
+        Ok(None)
+    }
+}
+
+impl DeploymentStateDiffApiServer<H256, String, u32> for Stub {
+    fn deployment_state_diff(
+        &self,
+        _: H256,
+        _: H256,
+    ) -> RpcResult<DeploymentStateDiff<String, u32>> {
+        Ok(DeploymentStateDiff {
+            from_block: 0,
+            to_block: 0,
+            added_deployers: Vec::new(),
+            removed_deployers: Vec::new(),
+            added_factories: Vec::new(),
+            removed_factories: Vec::new(),
+        })
+    }
+}
+
+impl SessionKeysApiServer<H256, String> for Stub {
+    fn queued_session_keys(&self, _: Option<H256>) -> RpcResult<QueuedSessionKeys<String>> {
+        Ok(QueuedSessionKeys {
+            session: 0,
+            queued: Vec::new(),
+        })
+    }
+
+    fn next_session_keys(&self, _: String, _: Option<H256>) -> RpcResult<Option<Bytes>> {
+        Ok(None)
+    }
+}
+
+impl GasWeightApiServer<H256> for Stub {
+    fn gas_to_weight(&self, _: u64, _: Option<H256>) -> RpcResult<TransactionWeight> {
+        Ok(TransactionWeight {
+            ref_time: 0,
+            proof_size: 0,
+        })
+    }
+
+    fn weight_to_gas(&self, _: TransactionWeight, _: Option<H256>) -> RpcResult<u64> {
+        Ok(0)
+    }
+}
+
+impl ContractCreationsApiServer<H256> for Stub {
+    fn contract_creations(&self, _: Option<H256>) -> RpcResult<Option<Vec<ContractCreation>>> {
+        Ok(None)
+    }
+}
+
+#[async_trait]
+impl TransactionDiagnosticsApiServer for Stub {
+    async fn why_failed(&self, _: H256) -> RpcResult<Option<Diagnosis>> {
+        Ok(None)
+    }
+}
+
+fn registered_methods() -> BTreeSet<&'static str> {
+    let modules = [
+        AddressMappingApiServer::into_rpc(Stub),
+        AuthorizationHistoryApiServer::into_rpc(Stub),
+        ContractCreationsApiServer::into_rpc(Stub),
+        DeploymentControlApiServer::into_rpc(Stub),
+        DeploymentEventsApiServer::into_rpc(Stub),
+        DeploymentNonceApiServer::into_rpc(Stub),
+        DeploymentRejectionsApiServer::into_rpc(Stub),
+        DeploymentStateDiffApiServer::into_rpc(Stub),
+        GasWeightApiServer::into_rpc(Stub),
+        SessionKeysApiServer::into_rpc(Stub),
+        TransactionDiagnosticsApiServer::into_rpc(Stub),
+    ];
+
+    modules
+        .iter()
+        .flat_map(|module| module.method_names())
+        .collect()
+}
+
+#[test]
+fn document_describes_every_method() {
+    let document = openrpc::document();
+    let described: BTreeSet<_> = openrpc::method_names(&document).into_iter().collect();
+
+    assert_eq!(described, registered_methods());
+}
+
+#[test]
+fn addresses_and_accounts_are_told_apart() {
+    let address: AddressOrAccount<String> =
+        serde_json::from_str("\"0x2222222222222222222222222222222222222222\"").unwrap();
+    assert_eq!(address, AddressOrAccount::Address(H160::repeat_byte(0x22)));
+
+    let ss58 = "5GrwvaEF5zXb26Fz9rcQpDWS57CtERHpNehXCPcNoHGKutQY";
+    let account: AddressOrAccount<String> = serde_json::from_value(ss58.into()).unwrap();
+    assert_eq!(account, AddressOrAccount::Account(ss58.into()));
+}
+
+#[test]
+fn document_references_resolve() {
+    fn references(value: &Value, found: &mut Vec<String>) {
+        match value {
+            Value::Object(map) => {
+                if let Some(Value::String(reference)) = map.get("$ref") {
+                    found.push(reference.clone());
+                }
+                map.values().for_each(|value| references(value, found));
+            }
+            Value::Array(values) => values.iter().for_each(|value| references(value, found)),
+            _ => {}
+        }
+    }
+
+    let document = openrpc::document();
+    let mut found = Vec::new();
+    references(&document, &mut found);
+
+    assert!(!found.is_empty());
+    for reference in found {
+        let name = reference
+            .strip_prefix("#/components/schemas/")
+            .expect("references point to the components");
+        assert!(
+            document["components"]["schemas"].get(name).is_some(),
+            "unresolved reference {reference}"
+        );
+    }
+}
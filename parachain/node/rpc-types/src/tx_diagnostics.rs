@@ -0,0 +1,57 @@
+//! `qnch_whyFailed`, explaining why an Ethereum transaction failed.
+
+use jsonrpsee::{core::RpcResult, proc_macros::rpc};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use sp_core::{Bytes, H256};
+
+use crate::schema::Data;
+
+/// Why a transaction failed
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(tag = "kind", rename_all = "camelCase")]
+pub enum Diagnosis {
+    /// The transaction executed successfully
+    Succeeded,
+    /// The sender may not deploy contracts
+    UnauthorizedDeployment {
+        /// Why the deployment is refused
+        reason: String,
+    },
+    /// The call is paused by the emergency pause
+    PausedContract,
+    /// The recipient of the transferred value is not on the transfer allowlist
+    DenyListedRecipient,
+    /// The transaction was rejected before execution
+    Rejected {
+        /// The runtime error
+        error: String,
+    },
+    /// The execution ran out of gas
+    OutOfGas,
+    /// The execution reverted
+    #[serde(rename_all = "camelCase")]
+    Reverted {
+        /// Decoded `Error(string)` or `Panic(uint256)` reason, if any
+        reason: Option<String>,
+        /// Raw return data, empty for contract creations
+        #[schemars(with = "Data")]
+        data: Bytes,
+    },
+    /// The execution halted on an EVM error
+    Halted {
+        /// The EVM exit reason
+        error: String,
+    },
+}
+
+/// Failed transaction diagnostics RPC methods.
+#[rpc(server)]
+pub trait TransactionDiagnosticsApi {
+    /// Why the Ethereum transaction `transaction_hash` failed
+    ///
+    /// Returns `null` if the transaction is not in a canonical block, or if the runtime was
+    /// built without the `evm-tracing` feature.
+    #[method(name = "qnch_whyFailed")]
+    async fn why_failed(&self, transaction_hash: H256) -> RpcResult<Option<Diagnosis>>;
+}
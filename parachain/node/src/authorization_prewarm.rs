@@ -0,0 +1,122 @@
+//! Pre-warming of the trie cache for block authoring
+//!
+//! Applying a contract creation reads the deployer's authorization, window override and
+//! quota usage, and a call creating contracts through an approved factory reads the
+//! factory's record and permissions. Read cold, each of these walks the trie down from the
+//! database while the proposer holds the authoring slot, which adds up under factory-heavy
+//! load.
+//!
+//! After every new best block, collators read these keys for the front of the ready queue,
+//! which is where the proposer draws transactions from, at the new block. The reads leave
+//! the trie nodes in the shared trie cache, so that the proposer building on the block
+//! finds them there. `--no-authorization-prewarm` turns it off.
+
+use std::{collections::BTreeSet, sync::Arc, time::Instant};
+
+use ethereum::{TransactionAction, TransactionV2};
+use fp_self_contained::SelfContainedCall;
+use futures::StreamExt;
+use pallet_evm_deployment_control::{
+    ApprovedFactories, AuthorizedDeployers, ContractDeployer, DeploymentQuota,
+    DeploymentQuotaUsage, DeploymentWindows, EvmDeploymentPolicy, FactoryPermissionsOf,
+    WindowOverrides,
+};
+use parachain_template_runtime::{
+    opaque::Block, AccountId, Runtime, RuntimeCall, UncheckedExtrinsic,
+};
+use parity_scale_codec::{Decode, Encode};
+use sc_client_api::{Backend, BlockchainEvents, StorageProvider};
+use sc_transaction_pool_api::{InPoolTransaction, TransactionPool};
+use sp_core::{storage::StorageKey, H160};
+use sp_runtime::OpaqueExtrinsic;
+
+const LOG_TARGET: &str = "authorization-prewarm";
+
+/// Number of ready transactions whose keys are read after every block
+///
+/// As many as the deployment filter checks, more than fit in a block.
+const MAX_WARMED_TRANSACTIONS: usize = 1024;
+
+/// Read the authorization and factory keys of the front of the ready queue at every new
+/// best block
+pub async fn prewarm_authorization_keys<C, BE, P>(client: Arc<C>, pool: Arc<P>)
+where
+    C: StorageProvider<Block, BE> + BlockchainEvents<Block>,
+    BE: Backend<Block>,
+    P: TransactionPool<Block = Block>,
+{
+    let mut imports = client.import_notification_stream();
+
+    while let Some(notification) = imports.next().await {
+        if !notification.is_new_best {
+            continue;
+        }
+
+        let mut keys = BTreeSet::new();
+        for tx in pool.ready().take(MAX_WARMED_TRANSACTIONS) {
+            touched_keys(tx.data(), &mut keys);
+        }
+        if keys.is_empty() {
+            continue;
+        }
+
+        // Settings read by every contract creation
+        keys.insert(EvmDeploymentPolicy::<Runtime>::hashed_key().to_vec());
+        keys.insert(DeploymentWindows::<Runtime>::hashed_key().to_vec());
+        keys.insert(DeploymentQuota::<Runtime>::hashed_key().to_vec());
+
+        let started = Instant::now();
+        for key in &keys {
+            if let Err(err) = client.storage(notification.hash, &StorageKey(key.clone())) {
+                log::debug!(target: LOG_TARGET, "Failed to pre-warm the trie cache: {err}");
+                break;
+            }
+        }
+        log::debug!(
+            target: LOG_TARGET,
+            "Read {} authorization and factory keys in {:?}",
+            keys.len(),
+            started.elapsed()
+        );
+    }
+}
+
+/// Add the keys the runtime reads applying `extrinsic` to `keys`, if it is an Ethereum
+/// transaction
+fn touched_keys(extrinsic: &OpaqueExtrinsic, keys: &mut BTreeSet<Vec<u8>>) {
+    let Ok(extrinsic) = UncheckedExtrinsic::decode(&mut &extrinsic.encode()[..]) else {
+        return;
+    };
+    let call = &extrinsic.0.function;
+    let RuntimeCall::Ethereum(pallet_ethereum::Call::transact { transaction }) = call else {
+        return;
+    };
+    let action = match transaction {
+        TransactionV2::Legacy(t) => t.action,
+        TransactionV2::EIP2930(t) => t.action,
+        TransactionV2::EIP1559(t) => t.action,
+    };
+
+    match action {
+        TransactionAction::Create => {
+            // Recovers the sender as the runtime does, off the proposer's critical path
+            let Some(Ok(sender)) = call.check_self_contained() else {
+                return;
+            };
+            let deployer = account_of(sender);
+            keys.insert(AuthorizedDeployers::<Runtime>::hashed_key_for(&deployer));
+            keys.insert(WindowOverrides::<Runtime>::hashed_key_for(&deployer));
+            keys.insert(DeploymentQuotaUsage::<Runtime>::hashed_key_for(&deployer));
+        }
+        TransactionAction::Call(target) => {
+            keys.insert(ApprovedFactories::<Runtime>::hashed_key_for(target));
+            keys.insert(FactoryPermissionsOf::<Runtime>::hashed_key_for(target));
+        }
+    }
+}
+
+fn account_of(address: H160) -> AccountId {
+    <<Runtime as pallet_evm_deployment_control::Config>::ContractDeployer as ContractDeployer<
+        AccountId,
+    >>::into_account_id(address)
+}
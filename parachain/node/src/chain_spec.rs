@@ -4,7 +4,11 @@ use runtime::{AccountId, AuraId, Signature, EXISTENTIAL_DEPOSIT};
 use sc_chain_spec::{ChainSpecExtension, ChainSpecGroup};
 use sc_service::ChainType;
 use serde::{Deserialize, Serialize};
-use sp_core::{sr25519, Pair, Public};
+#[cfg(feature = "unified-accounts")]
+use sp_core::ecdsa;
+#[cfg(not(feature = "unified-accounts"))]
+use sp_core::sr25519;
+use sp_core::{Pair, Public};
 use sp_runtime::traits::{IdentifyAccount, Verify};
 
 /// Specialized `ChainSpec` for the normal parachain runtime.
@@ -40,6 +44,14 @@ impl Extensions {
 
 type AccountPublic = <Signature as Verify>::Signer;
 
+/// Key type of the dev accounts, signing for the runtime's account type
+#[cfg(not(feature = "unified-accounts"))]
+type AccountKey = sr25519::Public;
+
+/// Key type of the dev accounts, whose 20-byte accounts derive from their ECDSA keys
+#[cfg(feature = "unified-accounts")]
+type AccountKey = ecdsa::Public;
+
 /// Generate collator keys from seed.
 ///
 /// This function's return type must always match the session keys of the chain in tuple format.
@@ -85,29 +97,29 @@ pub fn development_config() -> ChainSpec {
         // initial collators.
         vec![
             (
-                get_account_id_from_seed::<sr25519::Public>("Alice"),
+                get_account_id_from_seed::<AccountKey>("Alice"),
                 get_collator_keys_from_seed("Alice"),
             ),
             (
-                get_account_id_from_seed::<sr25519::Public>("Bob"),
+                get_account_id_from_seed::<AccountKey>("Bob"),
                 get_collator_keys_from_seed("Bob"),
             ),
         ],
         vec![
-            get_account_id_from_seed::<sr25519::Public>("Alice"),
-            get_account_id_from_seed::<sr25519::Public>("Bob"),
-            get_account_id_from_seed::<sr25519::Public>("Charlie"),
-            get_account_id_from_seed::<sr25519::Public>("Dave"),
-            get_account_id_from_seed::<sr25519::Public>("Eve"),
-            get_account_id_from_seed::<sr25519::Public>("Ferdie"),
-            get_account_id_from_seed::<sr25519::Public>("Alice//stash"),
-            get_account_id_from_seed::<sr25519::Public>("Bob//stash"),
-            get_account_id_from_seed::<sr25519::Public>("Charlie//stash"),
-            get_account_id_from_seed::<sr25519::Public>("Dave//stash"),
-            get_account_id_from_seed::<sr25519::Public>("Eve//stash"),
-            get_account_id_from_seed::<sr25519::Public>("Ferdie//stash"),
+            get_account_id_from_seed::<AccountKey>("Alice"),
+            get_account_id_from_seed::<AccountKey>("Bob"),
+            get_account_id_from_seed::<AccountKey>("Charlie"),
+            get_account_id_from_seed::<AccountKey>("Dave"),
+            get_account_id_from_seed::<AccountKey>("Eve"),
+            get_account_id_from_seed::<AccountKey>("Ferdie"),
+            get_account_id_from_seed::<AccountKey>("Alice//stash"),
+            get_account_id_from_seed::<AccountKey>("Bob//stash"),
+            get_account_id_from_seed::<AccountKey>("Charlie//stash"),
+            get_account_id_from_seed::<AccountKey>("Dave//stash"),
+            get_account_id_from_seed::<AccountKey>("Eve//stash"),
+            get_account_id_from_seed::<AccountKey>("Ferdie//stash"),
         ],
-        get_account_id_from_seed::<sr25519::Public>("Alice"),
+        get_account_id_from_seed::<AccountKey>("Alice"),
         2000.into(),
     ))
     .with_properties(properties)
@@ -138,29 +150,29 @@ pub fn local_testnet_config() -> ChainSpec {
         // initial collators.
         vec![
             (
-                get_account_id_from_seed::<sr25519::Public>("Alice"),
+                get_account_id_from_seed::<AccountKey>("Alice"),
                 get_collator_keys_from_seed("Alice"),
             ),
             (
-                get_account_id_from_seed::<sr25519::Public>("Bob"),
+                get_account_id_from_seed::<AccountKey>("Bob"),
                 get_collator_keys_from_seed("Bob"),
             ),
         ],
         vec![
-            get_account_id_from_seed::<sr25519::Public>("Alice"),
-            get_account_id_from_seed::<sr25519::Public>("Bob"),
-            get_account_id_from_seed::<sr25519::Public>("Charlie"),
-            get_account_id_from_seed::<sr25519::Public>("Dave"),
-            get_account_id_from_seed::<sr25519::Public>("Eve"),
-            get_account_id_from_seed::<sr25519::Public>("Ferdie"),
-            get_account_id_from_seed::<sr25519::Public>("Alice//stash"),
-            get_account_id_from_seed::<sr25519::Public>("Bob//stash"),
-            get_account_id_from_seed::<sr25519::Public>("Charlie//stash"),
-            get_account_id_from_seed::<sr25519::Public>("Dave//stash"),
-            get_account_id_from_seed::<sr25519::Public>("Eve//stash"),
-            get_account_id_from_seed::<sr25519::Public>("Ferdie//stash"),
+            get_account_id_from_seed::<AccountKey>("Alice"),
+            get_account_id_from_seed::<AccountKey>("Bob"),
+            get_account_id_from_seed::<AccountKey>("Charlie"),
+            get_account_id_from_seed::<AccountKey>("Dave"),
+            get_account_id_from_seed::<AccountKey>("Eve"),
+            get_account_id_from_seed::<AccountKey>("Ferdie"),
+            get_account_id_from_seed::<AccountKey>("Alice//stash"),
+            get_account_id_from_seed::<AccountKey>("Bob//stash"),
+            get_account_id_from_seed::<AccountKey>("Charlie//stash"),
+            get_account_id_from_seed::<AccountKey>("Dave//stash"),
+            get_account_id_from_seed::<AccountKey>("Eve//stash"),
+            get_account_id_from_seed::<AccountKey>("Ferdie//stash"),
         ],
-        get_account_id_from_seed::<sr25519::Public>("Alice"),
+        get_account_id_from_seed::<AccountKey>("Alice"),
         2000.into(),
     ))
     .with_protocol_id("template-local")
@@ -200,6 +212,14 @@ fn testnet_genesis(
         "polkadotXcm": {
             "safeXcmVersion": Some(SAFE_XCM_VERSION),
         },
+        "evmDeploymentControl": {
+            // Deployers are given as SS58 accounts or as EVM addresses
+            "authorizedDeployers": [
+                root.clone(),
+                // Hardhat test account 0
+                "0xf39Fd6e51aad88F6F4ce6aB8827279cffFb92266",
+            ],
+        },
         "sudo": { "key": Some(root) },
         "evm": {
             "accounts": {
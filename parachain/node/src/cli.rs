@@ -1,4 +1,7 @@
-use crate::eth::EthConfiguration;
+use crate::{
+    eth::EthConfiguration, graphql::GraphqlConfiguration, legacy_rpc::LegacyRpcConfiguration,
+    rest_gateway::RestGatewayConfiguration,
+};
 use std::path::PathBuf;
 
 /// Sub-commands supported by the collator.
@@ -17,6 +20,21 @@ pub enum Subcommand {
     /// Export the state of a given block into a chain spec.
     ExportState(sc_cli::ExportStateCmd),
 
+    /// Export the deployment control state of a given block as JSON.
+    ExportDeploymentState(crate::deployment_state::ExportDeploymentStateCmd),
+
+    /// Import a deployment control state into the genesis of a chain specification.
+    ImportDeploymentState(crate::deployment_state::ImportDeploymentStateCmd),
+
+    /// Bundle the raw chain specification with its relay chain's for light clients.
+    ExportLightClientSpec(crate::light_client_spec::ExportLightClientSpecCmd),
+
+    /// Generate test vectors of the deployment control rules as JSON.
+    GenerateTestVectors(crate::test_vectors::GenerateTestVectorsCmd),
+
+    /// Verify a runtime blob against the on-chain code.
+    VerifyRuntime(crate::verify_runtime::VerifyRuntimeCmd),
+
     /// Import blocks.
     ImportBlocks(sc_cli::ImportBlocksCmd),
 
@@ -80,12 +98,44 @@ pub struct Cli {
     #[arg(long)]
     pub no_hardware_benchmarks: bool,
 
+    /// Disable pre-warming the trie cache for block authoring.
+    ///
+    /// By default collators read the authorization and factory keys of the ready
+    /// transactions after every new best block, so that the proposer finds them cached.
+    #[arg(long)]
+    pub no_authorization_prewarm: bool,
+
+    /// Milliseconds the collator may spend building a block.
+    ///
+    /// Must fit in a parachain slot, which `elastic-scaling` builds shorten to a third of a
+    /// relay chain slot, one per core. Lower it when collators fall behind importing the
+    /// blocks built for the other cores.
+    #[arg(long, value_name = "MS", default_value_t = 2000)]
+    pub authoring_duration: u64,
+
+    /// Milliseconds the collator shifts its slots by, to author away from the relay chain
+    /// block imports.
+    #[arg(long, value_name = "MS", default_value_t = 1000)]
+    pub slot_drift: u64,
+
     /// Relay chain arguments
     #[arg(raw = true)]
     pub relay_chain_args: Vec<String>,
     // Frontier arguments
     #[command(flatten)]
     pub eth: EthConfiguration,
+
+    // REST gateway arguments
+    #[command(flatten)]
+    pub rest_gateway: RestGatewayConfiguration,
+
+    // GraphQL endpoint arguments
+    #[command(flatten)]
+    pub graphql: GraphqlConfiguration,
+
+    // Legacy chain id endpoint arguments
+    #[command(flatten)]
+    pub legacy_rpc: LegacyRpcConfiguration,
 }
 
 #[derive(Debug)]
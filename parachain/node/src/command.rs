@@ -1,4 +1,4 @@
-use std::net::SocketAddr;
+use std::{net::SocketAddr, time::Duration};
 
 use cumulus_client_service::storage_proof_size::HostFunctions as ReclaimHostFunctions;
 use cumulus_primitives_core::ParaId;
@@ -140,6 +140,25 @@ pub fn run() -> Result<()> {
                 Ok(cmd.run(components.client, config.chain_spec))
             })
         }
+        Some(Subcommand::ExportDeploymentState(cmd)) => {
+            construct_async_run!(|components, cli, cmd, config, eth_cfg| {
+                Ok(cmd.run(components.client))
+            })
+        }
+        Some(Subcommand::ImportDeploymentState(cmd)) => {
+            let runner = cli.create_runner(cmd)?;
+            runner.sync_run(|config| cmd.run(config.chain_spec))
+        }
+        Some(Subcommand::ExportLightClientSpec(cmd)) => {
+            let runner = cli.create_runner(cmd)?;
+            runner.sync_run(|config| cmd.run(config.chain_spec))
+        }
+        Some(Subcommand::GenerateTestVectors(cmd)) => cmd.run(),
+        Some(Subcommand::VerifyRuntime(cmd)) => {
+            construct_async_run!(|components, cli, cmd, config, eth_cfg| {
+                Ok(cmd.run(components.client))
+            })
+        }
         Some(Subcommand::ImportBlocks(cmd)) => {
             construct_async_run!(|components, cli, cmd, config, eth_cfg| {
                 Ok(cmd.run(components.client, components.import_queue))
@@ -291,8 +310,14 @@ pub fn run() -> Result<()> {
                     polkadot_config,
                     collator_options,
                     &cli.eth,
+                    &cli.rest_gateway,
+                    &cli.graphql,
+                    &cli.legacy_rpc,
                     id,
                     hwbench,
+                    !cli.no_authorization_prewarm,
+                    Duration::from_millis(cli.authoring_duration),
+                    Duration::from_millis(cli.slot_drift),
                 )
                 .await
                 .map(|r| r.0)
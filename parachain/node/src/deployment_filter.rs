@@ -0,0 +1,149 @@
+//! Pruning of doomed contract creations from the transaction pool
+//!
+//! Contract creations are checked against the deployment policy when they enter the pool,
+//! but creations from senders whose authorization was revoked since keep sitting in the
+//! ready queue until the pool revalidates them. A spammer flooding the pool that way makes
+//! the proposer spend authoring time executing each of them only to see it rejected.
+//!
+//! After every new best block, collators check the front of the ready queue, which is
+//! where the proposer draws transactions from, with the `denied_deployments` runtime API
+//! and remove the creations of unauthorized senders before building the next block.
+//!
+//! Senders only see such a transaction vanish from the pool, without a receipt. The reason
+//! of each removal is therefore kept in [`DeploymentRejections`], served by the
+//! `qnch_deploymentRejection` RPC method.
+
+use std::{
+    collections::{HashMap, VecDeque},
+    sync::{Arc, Mutex},
+};
+
+use futures::StreamExt;
+use pallet_evm_deployment_control_runtime_api::{versions, DeploymentControlApi};
+use parachain_template_runtime::{opaque::Block, AccountId, BlockNumber, Hash};
+use sc_client_api::BlockchainEvents;
+use sc_transaction_pool_api::{InPoolTransaction, TransactionPool};
+use sp_api::{ApiExt, ProvideRuntimeApi};
+use sp_core::H256;
+use sp_runtime::traits::Header as HeaderT;
+
+const LOG_TARGET: &str = "deployment-filter";
+
+/// Number of ready transactions checked after every block
+///
+/// Far more than fit in a block, so that the transactions the proposer gets to are covered.
+const MAX_CHECKED_TRANSACTIONS: usize = 1024;
+
+/// Number of rejections kept, the oldest are forgotten first
+const MAX_KEPT_REJECTIONS: usize = 4096;
+
+/// Why a contract creation was removed from the pool
+pub type DeploymentRejection = qnch_rpc_types::DeploymentRejection<Hash, BlockNumber>;
+
+/// Recent rejections of contract creations, by Ethereum transaction hash
+#[derive(Clone, Default)]
+pub struct DeploymentRejections(Arc<Mutex<RejectionLog>>);
+
+#[derive(Default)]
+struct RejectionLog {
+    order: VecDeque<H256>,
+    rejections: HashMap<H256, DeploymentRejection>,
+}
+
+impl DeploymentRejections {
+    /// Rejection of the Ethereum transaction `transaction_hash`, if it is still kept
+    pub fn get(&self, transaction_hash: &H256) -> Option<DeploymentRejection> {
+        let log = self.0.lock().expect("rejection log lock poisoned");
+        log.rejections.get(transaction_hash).cloned()
+    }
+
+    fn record(&self, transaction_hash: H256, rejection: DeploymentRejection) {
+        let mut log = self.0.lock().expect("rejection log lock poisoned");
+        if log.rejections.insert(transaction_hash, rejection).is_none() {
+            log.order.push_back(transaction_hash);
+        }
+        while log.order.len() > MAX_KEPT_REJECTIONS {
+            if let Some(oldest) = log.order.pop_front() {
+                log.rejections.remove(&oldest);
+            }
+        }
+    }
+}
+
+/// Remove contract creations of unauthorized senders from the ready queue after every new
+/// best block, recording why in `rejections`
+pub async fn prune_denied_deployments<C, P>(
+    client: Arc<C>,
+    pool: Arc<P>,
+    rejections: DeploymentRejections,
+) where
+    C: ProvideRuntimeApi<Block> + BlockchainEvents<Block>,
+    C::Api: DeploymentControlApi<Block, AccountId, BlockNumber>,
+    P: TransactionPool<Block = Block>,
+{
+    let mut imports = client.import_notification_stream();
+
+    while let Some(notification) = imports.next().await {
+        if !notification.is_new_best {
+            continue;
+        }
+
+        let hash = notification.hash;
+        let api = client.runtime_api();
+
+        // Runtimes from before the API are left to the pool's revalidation
+        let version = match api
+            .api_version::<dyn DeploymentControlApi<Block, AccountId, BlockNumber>>(hash)
+        {
+            Ok(Some(version)) if version >= versions::DENIED_DEPLOYMENTS => version,
+            _ => continue,
+        };
+
+        let ready: Vec<_> = pool.ready().take(MAX_CHECKED_TRANSACTIONS).collect();
+        if ready.is_empty() {
+            continue;
+        }
+
+        let extrinsics = ready.iter().map(|tx| tx.data().clone()).collect();
+        let denied = if version >= versions::DEPLOYMENT_REFUSALS {
+            api.deployment_refusals(hash, extrinsics).map(|refusals| {
+                let block_number = *notification.header.number();
+                refusals
+                    .into_iter()
+                    .map(|refusal| {
+                        let rejection = DeploymentRejection {
+                            code: refusal.code,
+                            block_hash: hash,
+                            block_number,
+                        };
+                        rejections.record(refusal.transaction_hash, rejection);
+                        refusal.index
+                    })
+                    .collect()
+            })
+        } else {
+            api.denied_deployments(hash, extrinsics)
+        };
+        let denied: Vec<u32> = match denied {
+            Ok(denied) => denied,
+            Err(err) => {
+                log::debug!(target: LOG_TARGET, "Failed to check ready deployments: {err}");
+                continue;
+            }
+        };
+
+        let hashes: Vec<_> = denied
+            .into_iter()
+            .filter_map(|index| ready.get(index as usize))
+            .map(|tx| tx.hash().clone())
+            .collect();
+        if !hashes.is_empty() {
+            log::debug!(
+                target: LOG_TARGET,
+                "Removing {} unauthorized contract creations from the pool",
+                hashes.len()
+            );
+            pool.remove_invalid(&hashes);
+        }
+    }
+}
@@ -0,0 +1,310 @@
+//! Export and import of the deployment control state
+//!
+//! `export-deployment-state` dumps the authorized deployers, approved factories and
+//! contract provenance records of the `EvmDeploymentControl` pallet at a block as JSON.
+//! `import-deployment-state` writes such a dump into the genesis storage of a chain spec,
+//! so that the state can be carried over to a new testnet or restored in a disaster
+//! recovery drill.
+//!
+//! Block numbers are kept as exported and therefore refer to the source chain.
+
+use std::{fs, io::Write, path::PathBuf, sync::Arc};
+
+use frame_support::storage::StoragePrefixedMap;
+use pallet_evm_deployment_control::{
+    ApprovedFactories, AuthorizedDeployers, ContractProvenance, DeployerFlags, DeployerInfo,
+    FactoryInfo, FactoryStatus, ProvenanceRecord,
+};
+use parachain_template_runtime::{AccountId, Block, BlockNumber, Runtime};
+use parity_scale_codec::{Decode, Encode};
+use sc_cli::{BlockNumberOrHash, CliConfiguration, DatabaseParams, PruningParams, SharedParams};
+use sc_client_api::{Backend, StorageProvider};
+use serde::{Deserialize, Serialize};
+use sp_blockchain::HeaderBackend;
+use sp_core::{storage::StorageKey, H160};
+use sp_runtime::generic::BlockId;
+
+/// Length of the `Blake2_128Concat` hash preceding the encoded key
+pub(crate) const BLAKE2_128_CONCAT_PREFIX_LEN: usize = 16;
+
+/// Deployment control state as written by `export-deployment-state`
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DeploymentState {
+    /// Block the state was exported at
+    pub block: BlockNumber,
+    /// Authorized deployers
+    pub deployers: Vec<Deployer>,
+    /// Approved factories
+    pub factories: Vec<Factory>,
+    /// Provenance records of created contracts
+    pub provenance: Vec<Provenance>,
+}
+
+/// An authorized deployer
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Deployer {
+    pub account: AccountId,
+    pub authorized_at: BlockNumber,
+    /// Bits of the deployer's `DeployerFlags`, absent from dumps of storage version 1
+    #[serde(default)]
+    pub flags: u8,
+}
+
+/// An approved factory
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Factory {
+    pub address: H160,
+    pub admin: AccountId,
+    pub children: u32,
+    pub active: bool,
+    pub registered_at: BlockNumber,
+}
+
+/// Provenance record of a contract
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Provenance {
+    pub contract: H160,
+    pub deployer: H160,
+    pub block: BlockNumber,
+}
+
+impl DeploymentState {
+    /// Storage entries recreating the state
+    fn storage_entries(&self) -> impl Iterator<Item = (Vec<u8>, Vec<u8>)> + '_ {
+        let deployers = self.deployers.iter().map(|deployer| {
+            (
+                AuthorizedDeployers::<Runtime>::hashed_key_for(&deployer.account),
+                DeployerInfo {
+                    authorized_at: deployer.authorized_at,
+                    flags: DeployerFlags(deployer.flags),
+                }
+                .encode(),
+            )
+        });
+        let factories = self.factories.iter().map(|factory| {
+            let status = if factory.active {
+                FactoryStatus::Active
+            } else {
+                FactoryStatus::Suspended
+            };
+            let info = FactoryInfo {
+                admin: factory.admin.clone(),
+                children: factory.children,
+                status,
+                registered_at: factory.registered_at,
+            };
+            (
+                ApprovedFactories::<Runtime>::hashed_key_for(factory.address),
+                info.encode(),
+            )
+        });
+        let provenance = self.provenance.iter().map(|record| {
+            (
+                ContractProvenance::<Runtime>::hashed_key_for(record.contract),
+                ProvenanceRecord {
+                    deployer: record.deployer,
+                    block: record.block,
+                }
+                .encode(),
+            )
+        });
+
+        deployers.chain(factories).chain(provenance)
+    }
+}
+
+/// The `export-deployment-state` command
+#[derive(Debug, Clone, clap::Parser)]
+pub struct ExportDeploymentStateCmd {
+    /// Block hash or number to export the state at, the best block if omitted
+    #[arg(value_name = "HASH or NUMBER")]
+    pub input: Option<BlockNumberOrHash>,
+
+    /// File to write the JSON state to, standard output if omitted
+    #[arg(long, short)]
+    pub output: Option<PathBuf>,
+
+    #[allow(missing_docs)]
+    #[clap(flatten)]
+    pub shared_params: SharedParams,
+
+    #[allow(missing_docs)]
+    #[clap(flatten)]
+    pub pruning_params: PruningParams,
+
+    #[allow(missing_docs)]
+    #[clap(flatten)]
+    pub database_params: DatabaseParams,
+}
+
+impl ExportDeploymentStateCmd {
+    /// Run the command
+    pub async fn run<C, BE>(&self, client: Arc<C>) -> sc_cli::Result<()>
+    where
+        C: StorageProvider<Block, BE> + HeaderBackend<Block>,
+        BE: Backend<Block>,
+    {
+        let hash = match self
+            .input
+            .as_ref()
+            .map(|block| block.parse::<Block>())
+            .transpose()?
+        {
+            Some(BlockId::Hash(hash)) => hash,
+            Some(BlockId::Number(number)) => client
+                .hash(number)?
+                .ok_or_else(|| format!("Block {number} not found"))?,
+            None => client.info().best_hash,
+        };
+        let block = client
+            .number(hash)?
+            .ok_or_else(|| format!("Block {hash:?} not found"))?;
+
+        let deployers = storage_pairs(
+            &*client,
+            hash,
+            AuthorizedDeployers::<Runtime>::final_prefix(),
+        )?
+        .into_iter()
+        .map(|(key, value)| {
+            let account = decode(&key[BLAKE2_128_CONCAT_PREFIX_LEN..], "deployer")?;
+            let info: DeployerInfo<BlockNumber> = decode(&value, "deployer")?;
+            Ok(Deployer {
+                account,
+                authorized_at: info.authorized_at,
+                flags: info.flags.0,
+            })
+        })
+        .collect::<sc_cli::Result<_>>()?;
+
+        let factories =
+            storage_pairs(&*client, hash, ApprovedFactories::<Runtime>::final_prefix())?
+                .into_iter()
+                .map(|(key, value)| {
+                    let info: FactoryInfo<AccountId, BlockNumber> = decode(&value, "factory")?;
+                    Ok(Factory {
+                        address: decode(&key, "factory")?,
+                        admin: info.admin,
+                        children: info.children,
+                        active: info.status == FactoryStatus::Active,
+                        registered_at: info.registered_at,
+                    })
+                })
+                .collect::<sc_cli::Result<_>>()?;
+
+        let provenance = storage_pairs(
+            &*client,
+            hash,
+            ContractProvenance::<Runtime>::final_prefix(),
+        )?
+        .into_iter()
+        .map(|(key, value)| {
+            let record: ProvenanceRecord<BlockNumber> = decode(&value, "provenance")?;
+            Ok(Provenance {
+                contract: decode(&key, "provenance")?,
+                deployer: record.deployer,
+                block: record.block,
+            })
+        })
+        .collect::<sc_cli::Result<_>>()?;
+
+        let state = DeploymentState {
+            block,
+            deployers,
+            factories,
+            provenance,
+        };
+        let json = serde_json::to_vec_pretty(&state)
+            .map_err(|err| format!("Failed to serialize the state: {err}"))?;
+        write_output(self.output.as_ref(), &json)
+    }
+}
+
+impl CliConfiguration for ExportDeploymentStateCmd {
+    fn shared_params(&self) -> &SharedParams {
+        &self.shared_params
+    }
+
+    fn pruning_params(&self) -> Option<&PruningParams> {
+        Some(&self.pruning_params)
+    }
+
+    fn database_params(&self) -> Option<&DatabaseParams> {
+        Some(&self.database_params)
+    }
+}
+
+/// The `import-deployment-state` command
+#[derive(Debug, Clone, clap::Parser)]
+pub struct ImportDeploymentStateCmd {
+    /// JSON file written by `export-deployment-state`
+    #[arg(long, short)]
+    pub input: PathBuf,
+
+    /// File to write the raw chain spec to, standard output if omitted
+    #[arg(long, short)]
+    pub output: Option<PathBuf>,
+
+    #[allow(missing_docs)]
+    #[clap(flatten)]
+    pub shared_params: SharedParams,
+}
+
+impl ImportDeploymentStateCmd {
+    /// Run the command
+    ///
+    /// Adds the state to the genesis storage of the chain spec selected with `--chain`,
+    /// next to any deployer already configured there, and writes the result as a raw
+    /// chain spec.
+    pub fn run(&self, mut spec: Box<dyn sc_service::ChainSpec>) -> sc_cli::Result<()> {
+        let state: DeploymentState = serde_json::from_slice(&fs::read(&self.input)?)
+            .map_err(|err| format!("Invalid deployment state: {err}"))?;
+
+        let mut storage = spec.as_storage_builder().build_storage()?;
+        storage.top.extend(state.storage_entries());
+        spec.set_storage(storage);
+
+        write_output(self.output.as_ref(), spec.as_json(true)?.as_bytes())
+    }
+}
+
+impl CliConfiguration for ImportDeploymentStateCmd {
+    fn shared_params(&self) -> &SharedParams {
+        &self.shared_params
+    }
+}
+
+/// Keys, stripped of `prefix`, and values of the storage entries under `prefix`
+fn storage_pairs<C, BE>(
+    client: &C,
+    hash: <Block as sp_runtime::traits::Block>::Hash,
+    prefix: [u8; 32],
+) -> sc_cli::Result<Vec<(Vec<u8>, Vec<u8>)>>
+where
+    C: StorageProvider<Block, BE>,
+    BE: Backend<Block>,
+{
+    let prefix = StorageKey(prefix.to_vec());
+
+    Ok(client
+        .storage_pairs(hash, Some(&prefix), None)?
+        .map(|(key, value)| (key.0[prefix.0.len()..].to_vec(), value.0))
+        .collect())
+}
+
+fn decode<T: Decode>(mut bytes: &[u8], entry: &str) -> sc_cli::Result<T> {
+    T::decode(&mut bytes).map_err(|err| format!("Invalid {entry} entry: {err}").into())
+}
+
+fn write_output(output: Option<&PathBuf>, bytes: &[u8]) -> sc_cli::Result<()> {
+    match output {
+        Some(path) => fs::write(path, bytes)?,
+        None => std::io::stdout().write_all(bytes)?,
+    }
+
+    Ok(())
+}
@@ -10,6 +10,7 @@ use futures::{future, prelude::*};
 use sc_client_api::BlockchainEvents;
 use sc_executor::{HostFunctions, WasmExecutor};
 
+use sc_network::config::SyncMode;
 use sc_network_sync::SyncingService;
 use sc_service::{error::Error as ServiceError, Configuration, TaskManager};
 /// Full backend.
@@ -18,6 +19,7 @@ pub type FullBackend = sc_service::TFullBackend<Block>;
 pub type FullClient<RuntimeApi, Executor> =
     sc_service::TFullClient<Block, RuntimeApi, WasmExecutor<Executor>>;
 use sp_api::ConstructRuntimeApi;
+use sp_runtime::traits::Header as HeaderT;
 // Frontier
 use fc_mapping_sync::{kv::MappingSyncWorker, SyncStrategy};
 use fc_rpc::{EthTask, StorageOverride};
@@ -93,6 +95,45 @@ pub struct EthConfiguration {
     /// Default value is 200MB.
     #[arg(long, default_value = "209715200")]
     pub frontier_sql_backend_cache_size: u64,
+
+    /// Block from which the key-value Frontier backend indexes Ethereum blocks.
+    /// Defaults to genesis, or to the first block imported after a warp sync.
+    #[arg(long)]
+    pub frontier_sync_from: Option<u32>,
+
+    /// Number of threads validating the transactions submitted over the Ethereum RPC.
+    /// A sender's transactions are validated by the same thread, in order of submission.
+    /// Defaults to the number of CPUs.
+    #[arg(long)]
+    pub eth_validation_threads: Option<usize>,
+}
+
+/// Block from which the mapping sync worker indexes Ethereum blocks.
+#[derive(Debug, Copy, Clone)]
+pub enum MappingSyncStart {
+    /// Index from the given block.
+    Block(u32),
+    /// Index from the first new best block imported, the blocks below a warp sync target
+    /// having no state to read Ethereum blocks from.
+    FirstImported,
+}
+
+impl EthConfiguration {
+    /// Number of threads validating Ethereum RPC submissions
+    pub fn validation_threads(&self) -> usize {
+        self.eth_validation_threads.unwrap_or_else(|| {
+            std::thread::available_parallelism().map_or(1, |threads| threads.get())
+        })
+    }
+
+    /// Block from which to index Ethereum blocks under the given sync mode.
+    pub fn mapping_sync_start(&self, sync_mode: &SyncMode) -> MappingSyncStart {
+        match self.frontier_sync_from {
+            Some(block) => MappingSyncStart::Block(block),
+            None if matches!(sync_mode, SyncMode::Warp) => MappingSyncStart::FirstImported,
+            None => MappingSyncStart::Block(0),
+        }
+    }
 }
 
 pub struct FrontierPartialComponents {
@@ -136,6 +177,7 @@ pub async fn spawn_frontier_tasks<RuntimeApi, Executor>(
     overrides: Arc<dyn StorageOverride<Block>>,
     fee_history_cache: FeeHistoryCache,
     fee_history_cache_limit: FeeHistoryCacheLimit,
+    mapping_sync_start: MappingSyncStart,
     sync: Arc<SyncingService<Block>>,
     pubsub_notification_sinks: Arc<
         fc_mapping_sync::EthereumBlockNotificationSinks<
@@ -152,26 +194,59 @@ pub async fn spawn_frontier_tasks<RuntimeApi, Executor>(
 
     match &*frontier_backend {
         fc_db::Backend::KeyValue(b) => {
+            let client = client.clone();
+            let overrides = overrides.clone();
+            let b = b.clone();
             task_manager.spawn_essential_handle().spawn(
                 "frontier-mapping-sync-worker",
                 Some("frontier"),
-                MappingSyncWorker::new(
-                    client.import_notification_stream(),
-                    Duration::new(6, 0),
-                    client.clone(),
-                    backend,
-                    overrides.clone(),
-                    b.clone(),
-                    3,
-                    0,
-                    SyncStrategy::Parachain,
-                    sync,
-                    pubsub_notification_sinks,
-                )
-                .for_each(|()| future::ready(())),
+                async move {
+                    let sync_from = match mapping_sync_start {
+                        MappingSyncStart::Block(block) => block,
+                        MappingSyncStart::FirstImported => {
+                            let first = client
+                                .import_notification_stream()
+                                .filter(|notification| future::ready(notification.is_new_best))
+                                .next()
+                                .await;
+                            match first {
+                                Some(notification) => *notification.header.number(),
+                                None => return,
+                            }
+                        }
+                    };
+                    log::info!(
+                        target: "frontier",
+                        "Indexing Ethereum blocks from block {sync_from}, pass it as \
+                         --frontier-sync-from when restarting with --sync warp",
+                    );
+
+                    MappingSyncWorker::new(
+                        client.import_notification_stream(),
+                        Duration::new(6, 0),
+                        client.clone(),
+                        backend,
+                        overrides,
+                        b,
+                        3,
+                        sync_from,
+                        SyncStrategy::Parachain,
+                        sync,
+                        pubsub_notification_sinks,
+                    )
+                    .for_each(|()| future::ready(()))
+                    .await
+                },
             );
         }
         fc_db::Backend::Sql(b) => {
+            if !matches!(mapping_sync_start, MappingSyncStart::Block(0)) {
+                log::warn!(
+                    target: "frontier",
+                    "The SQL backend indexes from genesis, use the key-value backend on warp \
+                     synced nodes",
+                );
+            }
             task_manager.spawn_essential_handle().spawn_blocking(
                 "frontier-mapping-sync-worker",
                 Some("frontier"),
@@ -0,0 +1,248 @@
+//! Concurrent validation of transactions submitted over the Ethereum RPC
+//!
+//! The transaction pool validates every submission on the two threads of its chain API,
+//! whatever the number of CPUs. During deployment storms, `eth_sendRawTransaction` calls
+//! queue up behind these two threads while each validation executes the deployment checks.
+//!
+//! [`ParallelValidation`] is the pool given to the RPC methods. It validates their
+//! submissions on its own lanes, one blocking thread each, and imports the outcome into the
+//! pool as the pool would have. A sender's transactions always go to the same lane, which
+//! validates them in order of submission, so that a sender's nonces reach the pool in the
+//! order they were sent. Everything else, gossiped transactions included, is left to the
+//! pool.
+
+use std::{collections::HashMap, pin::Pin, sync::Arc};
+
+use fp_self_contained::SelfContainedCall;
+use futures::{future, Future, FutureExt};
+use parachain_template_runtime::{opaque::Block, UncheckedExtrinsic};
+use parity_scale_codec::{Decode, Encode};
+use sc_transaction_pool::{error, BasicPool, ChainApi, ValidatedTransaction};
+use sc_transaction_pool_api::{
+    error::Error as TxPoolError, ImportNotificationStream, PoolFuture, PoolStatus,
+    ReadyTransactions, TransactionFor, TransactionPool, TransactionSource,
+    TransactionStatusStreamFor, TxHash,
+};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_core::{traits::SpawnNamed, H160, H256};
+use sp_runtime::{
+    traits::NumberFor, transaction_validity::TransactionValidityError, OpaqueExtrinsic,
+};
+use sp_transaction_pool::runtime_api::TaggedTransactionQueue;
+use tokio::sync::{
+    mpsc::{self, error::TrySendError},
+    oneshot,
+};
+
+/// Submissions waiting for a lane, past which submitters wait for room
+const MAX_QUEUED_PER_LANE: usize = 256;
+
+type Job = Box<dyn FnOnce() + Send>;
+
+/// Transaction pool validating submissions on a bounded number of lanes before importing
+/// them into `pool`
+pub struct ParallelValidation<C, A: ChainApi<Block = Block>> {
+    client: Arc<C>,
+    pool: Arc<BasicPool<A, Block>>,
+    lanes: Vec<mpsc::Sender<Job>>,
+}
+
+impl<C, A> ParallelValidation<C, A>
+where
+    C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+    C::Api: TaggedTransactionQueue<Block>,
+    A: ChainApi<Block = Block> + 'static,
+{
+    /// Validate submissions on `lanes` threads spawned with `spawner`
+    pub fn new(
+        client: Arc<C>,
+        pool: Arc<BasicPool<A, Block>>,
+        lanes: usize,
+        spawner: &impl SpawnNamed,
+    ) -> Self {
+        let lanes = (0..lanes.max(1))
+            .map(|_| {
+                let (sender, mut jobs) = mpsc::channel::<Job>(MAX_QUEUED_PER_LANE);
+                spawner.spawn_blocking(
+                    "eth-validation-lane",
+                    Some("transaction-pool"),
+                    async move {
+                        while let Some(job) = jobs.recv().await {
+                            job();
+                        }
+                    }
+                    .boxed(),
+                );
+                sender
+            })
+            .collect();
+
+        Self { client, pool, lanes }
+    }
+
+    /// Lane validating `xt`, the lane of its sender for Ethereum transactions
+    fn lane_of(&self, xt: &OpaqueExtrinsic) -> &mpsc::Sender<Job> {
+        let key = match ethereum_sender(xt) {
+            Some(sender) => sender.to_low_u64_be(),
+            None => self.pool.hash_of(xt).to_low_u64_be(),
+        };
+        &self.lanes[(key % self.lanes.len() as u64) as usize]
+    }
+}
+
+/// Sender of `xt`, if it is an Ethereum transaction with a valid signature
+fn ethereum_sender(xt: &OpaqueExtrinsic) -> Option<H160> {
+    let extrinsic = UncheckedExtrinsic::decode(&mut &xt.encode()[..]).ok()?;
+    extrinsic.0.function.check_self_contained()?.ok()
+}
+
+/// Validate `xt` at `at` as the pool's chain API does, and import the outcome into `pool`
+fn validate_and_import<C, A>(
+    client: &C,
+    pool: &BasicPool<A, Block>,
+    at: H256,
+    source: TransactionSource,
+    xt: OpaqueExtrinsic,
+) -> Result<H256, A::Error>
+where
+    C: ProvideRuntimeApi<Block> + HeaderBackend<Block>,
+    C::Api: TaggedTransactionQueue<Block>,
+    A: ChainApi<Block = Block>,
+{
+    let validated_pool = pool.pool().validated_pool();
+    let hash = pool.hash_of(&xt);
+    validated_pool.check_is_known(&hash, false)?;
+
+    let number = client
+        .number(at)
+        .map_err(|err| error::Error::RuntimeApi(err.to_string()))?
+        .ok_or_else(|| error::Error::RuntimeApi(format!("Unknown block {at:?}")))?;
+    let validity = client
+        .runtime_api()
+        .validate_transaction(at, source, xt.clone(), at)
+        .map_err(|err| error::Error::RuntimeApi(err.to_string()))?;
+
+    let validated = match validity {
+        Ok(validity) => {
+            let bytes = xt.encoded_size();
+            ValidatedTransaction::valid_at(number.into(), hash, source, xt, bytes, validity)
+        }
+        // Submitted as such, for the pool to ban invalid transactions as it does
+        Err(TransactionValidityError::Invalid(err)) => {
+            let err = error::Error::from(TxPoolError::InvalidTransaction(err));
+            ValidatedTransaction::Invalid(hash, err.into())
+        }
+        Err(TransactionValidityError::Unknown(err)) => {
+            let err = error::Error::from(TxPoolError::UnknownTransaction(err));
+            ValidatedTransaction::Unknown(hash, err.into())
+        }
+    };
+    validated_pool
+        .submit(std::iter::once(validated))
+        .pop()
+        .expect("one result per submitted transaction")
+}
+
+impl<C, A> TransactionPool for ParallelValidation<C, A>
+where
+    C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+    C::Api: TaggedTransactionQueue<Block>,
+    A: ChainApi<Block = Block> + 'static,
+{
+    type Block = Block;
+    type Hash = TxHash<BasicPool<A, Block>>;
+    type InPoolTransaction = <BasicPool<A, Block> as TransactionPool>::InPoolTransaction;
+    type Error = A::Error;
+
+    fn submit_at(
+        &self,
+        at: H256,
+        source: TransactionSource,
+        xts: Vec<TransactionFor<Self>>,
+    ) -> PoolFuture<Vec<Result<TxHash<Self>, Self::Error>>, Self::Error> {
+        // Queued in order, so that the transactions of a sender keep it
+        let submissions: Vec<_> =
+            xts.into_iter().map(|xt| self.submit_one(at, source, xt)).collect();
+        future::join_all(submissions).map(Ok).boxed()
+    }
+
+    fn submit_one(
+        &self,
+        at: H256,
+        source: TransactionSource,
+        xt: TransactionFor<Self>,
+    ) -> PoolFuture<TxHash<Self>, Self::Error> {
+        let lane = self.lane_of(&xt).clone();
+        let client = self.client.clone();
+        let pool = self.pool.clone();
+        let (result, outcome) = oneshot::channel();
+        let job: Job = Box::new(move || {
+            let _ = result.send(validate_and_import(&*client, &pool, at, source, xt));
+        });
+        // Takes the lane's next slot now rather than when first polled
+        let queued = lane.try_send(job);
+
+        async move {
+            let lane_closed = || error::Error::RuntimeApi("Validation lane closed".into());
+            match queued {
+                Ok(()) => {}
+                Err(TrySendError::Full(job)) => lane.send(job).await.map_err(|_| lane_closed())?,
+                Err(TrySendError::Closed(_)) => return Err(lane_closed().into()),
+            }
+            outcome.await.map_err(|_| lane_closed())?
+        }
+        .boxed()
+    }
+
+    /// Left to the pool, the Ethereum RPC never watches its submissions
+    fn submit_and_watch(
+        &self,
+        at: H256,
+        source: TransactionSource,
+        xt: TransactionFor<Self>,
+    ) -> PoolFuture<Pin<Box<TransactionStatusStreamFor<Self>>>, Self::Error> {
+        self.pool.submit_and_watch(at, source, xt)
+    }
+
+    fn ready_at(
+        &self,
+        at: NumberFor<Block>,
+    ) -> Pin<
+        Box<
+            dyn Future<
+                    Output = Box<dyn ReadyTransactions<Item = Arc<Self::InPoolTransaction>> + Send>,
+                > + Send,
+        >,
+    > {
+        self.pool.ready_at(at)
+    }
+
+    fn ready(&self) -> Box<dyn ReadyTransactions<Item = Arc<Self::InPoolTransaction>> + Send> {
+        self.pool.ready()
+    }
+
+    fn remove_invalid(&self, hashes: &[TxHash<Self>]) -> Vec<Arc<Self::InPoolTransaction>> {
+        self.pool.remove_invalid(hashes)
+    }
+
+    fn status(&self) -> PoolStatus {
+        self.pool.status()
+    }
+
+    fn import_notification_stream(&self) -> ImportNotificationStream<TxHash<Self>> {
+        self.pool.import_notification_stream()
+    }
+
+    fn on_broadcasted(&self, propagations: HashMap<TxHash<Self>, Vec<String>>) {
+        self.pool.on_broadcasted(propagations)
+    }
+
+    fn hash_of(&self, xt: &TransactionFor<Self>) -> TxHash<Self> {
+        self.pool.hash_of(xt)
+    }
+
+    fn ready_transaction(&self, hash: &TxHash<Self>) -> Option<Arc<Self::InPoolTransaction>> {
+        self.pool.ready_transaction(hash)
+    }
+}
@@ -0,0 +1,478 @@
+//! GraphQL endpoint over the deployment control registry
+//!
+//! Enabled with `--enable-graphql`, the node answers GraphQL queries POSTed to `/graphql`
+//! about deployers, approved factories, deployed contracts and deployment control events,
+//! so that frontends can query the registry without running their own indexer.
+//!
+//! Deployers, factories and contracts are read through runtime APIs at the best block.
+//! Events are read from the `System::Events` storage of finalized blocks, like the
+//! `qnch_subscribeDeploymentEvents` subscription, at most `MAX_EVENT_BLOCK_RANGE` blocks
+//! per query.
+
+use std::{
+    convert::Infallible,
+    marker::PhantomData,
+    net::{Ipv4Addr, SocketAddr},
+    str::FromStr,
+    sync::Arc,
+};
+
+use async_graphql::{
+    ComplexObject, Context, EmptyMutation, EmptySubscription, Enum, Object, Schema, SimpleObject,
+};
+use hyper::{
+    header::CONTENT_TYPE,
+    service::{make_service_fn, service_fn},
+    Body, Method, Request, Response, Server, StatusCode,
+};
+use pallet_deployer_reputation_runtime_api::DeployerReputationApi;
+use pallet_evm_deployment_control_runtime_api::{
+    ContractInfo, DeploymentControlApi, FactoryEntry, FactoryStatus as ApiFactoryStatus,
+};
+use parachain_template_runtime::{opaque::Block, AccountId, BlockNumber};
+use qnch_rpc_types::{DeploymentEvent as RpcDeploymentEvent, Pagination};
+use sc_client_api::{Backend, StorageProvider};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_core::H160;
+
+use crate::rpc::{block_events, DeploymentEventNotification};
+
+const LOG_TARGET: &str = "graphql";
+
+/// Largest number of blocks whose events a single query reads
+const MAX_EVENT_BLOCK_RANGE: BlockNumber = 1000;
+
+/// Deepest nesting of selections accepted in a query
+const MAX_QUERY_DEPTH: usize = 8;
+
+/// Configuration of the GraphQL endpoint.
+#[derive(Clone, Debug, clap::Parser)]
+pub struct GraphqlConfiguration {
+    /// Serve GraphQL queries over the deployment control registry.
+    #[arg(long)]
+    pub enable_graphql: bool,
+
+    /// Port of the GraphQL endpoint.
+    #[arg(long, default_value = "8081")]
+    pub graphql_port: u16,
+
+    /// Listen on all interfaces rather than on localhost only.
+    #[arg(long)]
+    pub graphql_external: bool,
+}
+
+impl GraphqlConfiguration {
+    /// Address to serve the endpoint on, `None` if it is disabled
+    pub fn listen_address(&self) -> Option<SocketAddr> {
+        let ip = if self.graphql_external {
+            Ipv4Addr::UNSPECIFIED
+        } else {
+            Ipv4Addr::LOCALHOST
+        };
+        self.enable_graphql
+            .then(|| SocketAddr::new(ip.into(), self.graphql_port))
+    }
+}
+
+/// Chain data the schema resolves against
+trait Registry: Send + Sync {
+    fn contract(&self, address: H160) -> Result<Option<ContractInfo<BlockNumber>>, String>;
+
+    fn factories(
+        &self,
+        pagination: Pagination<H160>,
+    ) -> Result<Vec<FactoryEntry<AccountId, BlockNumber>>, String>;
+
+    fn deployer(&self, address: H160) -> Result<Deployer, String>;
+
+    fn events(&self, from: BlockNumber, to: Option<BlockNumber>) -> Vec<DeploymentEvent>;
+}
+
+/// Registry backed by the node's client
+struct ClientRegistry<C, BE> {
+    client: Arc<C>,
+    _marker: PhantomData<BE>,
+}
+
+impl<C, BE> Registry for ClientRegistry<C, BE>
+where
+    C: ProvideRuntimeApi<Block>
+        + HeaderBackend<Block>
+        + StorageProvider<Block, BE>
+        + Send
+        + Sync
+        + 'static,
+    C::Api: DeploymentControlApi<Block, AccountId, BlockNumber>
+        + DeployerReputationApi<Block, AccountId, BlockNumber>,
+    BE: Backend<Block> + 'static,
+{
+    fn contract(&self, address: H160) -> Result<Option<ContractInfo<BlockNumber>>, String> {
+        let at = self.client.info().best_hash;
+        self.client.runtime_api().contract_info(at, address).map_err(runtime_error)
+    }
+
+    fn factories(
+        &self,
+        pagination: Pagination<H160>,
+    ) -> Result<Vec<FactoryEntry<AccountId, BlockNumber>>, String> {
+        let at = self.client.info().best_hash;
+        self.client
+            .runtime_api()
+            .list_factories(at, pagination.start_after, pagination.page_size())
+            .map_err(runtime_error)
+    }
+
+    fn deployer(&self, address: H160) -> Result<Deployer, String> {
+        let at = self.client.info().best_hash;
+        let api = self.client.runtime_api();
+        let account = crate::rest_gateway::account_of(address);
+        let reputation = api.reputation(at, account.clone()).map_err(runtime_error)?;
+        let suspension_floor = api.suspension_floor(at).map_err(runtime_error)?;
+
+        Ok(Deployer {
+            address: format!("{address:?}"),
+            account: account.to_string(),
+            score: reputation.score,
+            suspended_at: reputation.suspended_at,
+            suspension_floor,
+        })
+    }
+
+    fn events(&self, from: BlockNumber, to: Option<BlockNumber>) -> Vec<DeploymentEvent> {
+        let finalized = self.client.info().finalized_number;
+        let last = from.saturating_add(MAX_EVENT_BLOCK_RANGE - 1);
+        let to = to.unwrap_or(last).min(last).min(finalized);
+
+        (from..=to)
+            .filter_map(|number| self.client.hash(number).ok().flatten())
+            .flat_map(|hash| block_events(&*self.client, hash))
+            .map(DeploymentEvent::from)
+            .collect()
+    }
+}
+
+fn runtime_error(err: sp_api::ApiError) -> String {
+    format!("Runtime API call failed: {err:?}")
+}
+
+fn parse_address(address: &str) -> async_graphql::Result<H160> {
+    H160::from_str(address).map_err(|_| "Invalid address.".into())
+}
+
+fn registry<'a>(ctx: &Context<'a>) -> &'a Arc<dyn Registry> {
+    ctx.data_unchecked::<Arc<dyn Registry>>()
+}
+
+/// Root of the queries
+struct Query;
+
+#[Object]
+impl Query {
+    /// Code, storage and provenance information of the contract at `address`, `null` if
+    /// there is no code at the address
+    async fn contract(
+        &self,
+        ctx: &Context<'_>,
+        address: String,
+    ) -> async_graphql::Result<Option<Contract>> {
+        let address = parse_address(&address)?;
+        Ok(registry(ctx).contract(address)?.map(|info| Contract::new(address, info)))
+    }
+
+    /// Approved factories in address order, at most 1000 per page
+    async fn factories(
+        &self,
+        ctx: &Context<'_>,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    ) -> async_graphql::Result<Vec<Factory>> {
+        let start_after = start_after.as_deref().map(parse_address).transpose()?;
+        let factories = registry(ctx).factories(Pagination { start_after, limit })?;
+        Ok(factories.into_iter().map(Factory::from).collect())
+    }
+
+    /// Reputation of the deployer at `address`
+    async fn deployer(
+        &self,
+        ctx: &Context<'_>,
+        address: String,
+    ) -> async_graphql::Result<Deployer> {
+        Ok(registry(ctx).deployer(parse_address(&address)?)?)
+    }
+
+    /// Deployment control events of the finalized blocks `fromBlock` to `toBlock`, covering
+    /// at most 1000 blocks
+    async fn deployment_events(
+        &self,
+        ctx: &Context<'_>,
+        from_block: BlockNumber,
+        to_block: Option<BlockNumber>,
+    ) -> Vec<DeploymentEvent> {
+        registry(ctx).events(from_block, to_block)
+    }
+}
+
+/// A deployed contract
+#[derive(SimpleObject)]
+#[graphql(complex)]
+struct Contract {
+    /// Address of the contract
+    address: String,
+    /// Size of the contract code in bytes
+    code_size: u64,
+    /// Keccak-256 hash of the contract code
+    code_hash: String,
+    /// Number of storage slots in use, counted up to 10000, which means at least as many
+    storage_entries: u64,
+    /// Balance of the contract account
+    balance: String,
+    /// Nonce of the contract account
+    nonce: String,
+    /// Address that created the contract, if recorded
+    deployer_address: Option<String>,
+    /// Block in which the contract was created, if recorded
+    deployed_at: Option<BlockNumber>,
+    #[graphql(skip)]
+    deployer: Option<H160>,
+}
+
+impl Contract {
+    fn new(address: H160, info: ContractInfo<BlockNumber>) -> Self {
+        Self {
+            address: format!("{address:?}"),
+            code_size: info.code_size,
+            code_hash: format!("{:?}", info.code_hash),
+            storage_entries: info.storage_entries,
+            balance: info.balance.to_string(),
+            nonce: info.nonce.to_string(),
+            deployer_address: info.deployer.map(|deployer| format!("{deployer:?}")),
+            deployed_at: info.deployed_at,
+            deployer: info.deployer,
+        }
+    }
+}
+
+#[ComplexObject]
+impl Contract {
+    /// Reputation of the address that created the contract, if recorded
+    async fn deployer(&self, ctx: &Context<'_>) -> async_graphql::Result<Option<Deployer>> {
+        match self.deployer {
+            Some(address) => Ok(Some(registry(ctx).deployer(address)?)),
+            None => Ok(None),
+        }
+    }
+}
+
+/// Status of an approved factory
+#[derive(Enum, Clone, Copy, PartialEq, Eq)]
+enum FactoryStatus {
+    /// The factory may deploy contracts
+    Active,
+    /// The factory is temporarily barred from deploying contracts
+    Suspended,
+}
+
+/// An approved factory contract
+#[derive(SimpleObject)]
+struct Factory {
+    /// Address of the factory contract
+    address: String,
+    /// Account administering the factory
+    admin: String,
+    /// Number of contracts recorded with the factory as deployer
+    children: u32,
+    /// Current status of the factory
+    status: FactoryStatus,
+    /// Block at which the factory was registered
+    registered_at: BlockNumber,
+}
+
+impl From<FactoryEntry<AccountId, BlockNumber>> for Factory {
+    fn from(entry: FactoryEntry<AccountId, BlockNumber>) -> Self {
+        Self {
+            address: format!("{:?}", entry.address),
+            admin: entry.admin.to_string(),
+            children: entry.children,
+            status: match entry.status {
+                ApiFactoryStatus::Active => FactoryStatus::Active,
+                ApiFactoryStatus::Suspended => FactoryStatus::Suspended,
+            },
+            registered_at: entry.registered_at,
+        }
+    }
+}
+
+/// Reputation of a deployer
+#[derive(SimpleObject)]
+struct Deployer {
+    /// EVM address of the deployer
+    address: String,
+    /// Account of the deployer under the runtime's address mapping
+    account: String,
+    /// Current reputation score
+    score: i32,
+    /// Block at which the deployer was suspended for falling below the floor, if suspended
+    suspended_at: Option<BlockNumber>,
+    /// Score below which deployers are suspended
+    suspension_floor: i32,
+}
+
+/// Kind of a deployment control event
+#[derive(Enum, Clone, Copy, PartialEq, Eq)]
+enum DeploymentEventKind {
+    /// A deployer was authorized
+    DeployerAuthorized,
+    /// A deployer's authorization was revoked
+    DeployerRevoked,
+    /// A contract was deployed through `deploy_contract`
+    ContractDeployed,
+}
+
+/// A deployment control event and the block it was emitted in
+#[derive(SimpleObject)]
+struct DeploymentEvent {
+    /// Hash of the block
+    block_hash: String,
+    /// Number of the block
+    block_number: BlockNumber,
+    /// Index of the extrinsic emitting the event, `null` for events of block hooks
+    extrinsic_index: Option<u32>,
+    /// Kind of the event
+    kind: DeploymentEventKind,
+    /// Account of the deployer concerned
+    deployer: String,
+    /// Deployed contract, for `CONTRACT_DEPLOYED`
+    contract: Option<String>,
+    /// Label of the deployed contract, for `CONTRACT_DEPLOYED`
+    label: Option<String>,
+    /// Version of the deployed contract, for `CONTRACT_DEPLOYED`
+    version: Option<String>,
+}
+
+impl From<DeploymentEventNotification> for DeploymentEvent {
+    fn from(notification: DeploymentEventNotification) -> Self {
+        let (kind, deployer, contract, label, version) = match notification.event {
+            RpcDeploymentEvent::DeployerAuthorized { deployer } => {
+                (DeploymentEventKind::DeployerAuthorized, deployer, None, None, None)
+            }
+            RpcDeploymentEvent::DeployerRevoked { deployer } => {
+                (DeploymentEventKind::DeployerRevoked, deployer, None, None, None)
+            }
+            RpcDeploymentEvent::ContractDeployed {
+                deployer,
+                contract,
+                label,
+                version,
+            } => (
+                DeploymentEventKind::ContractDeployed,
+                deployer,
+                Some(format!("{contract:?}")),
+                Some(label),
+                Some(version),
+            ),
+        };
+
+        Self {
+            block_hash: format!("{:?}", notification.block_hash),
+            block_number: notification.block_number,
+            extrinsic_index: notification.extrinsic_index,
+            kind,
+            deployer: deployer.to_string(),
+            contract,
+            label,
+            version,
+        }
+    }
+}
+
+type RegistrySchema = Schema<Query, EmptyMutation, EmptySubscription>;
+
+/// Serve the GraphQL endpoint on `address` until the node shuts down
+pub async fn run<C, BE>(client: Arc<C>, address: SocketAddr)
+where
+    C: ProvideRuntimeApi<Block>
+        + HeaderBackend<Block>
+        + StorageProvider<Block, BE>
+        + Send
+        + Sync
+        + 'static,
+    C::Api: DeploymentControlApi<Block, AccountId, BlockNumber>
+        + DeployerReputationApi<Block, AccountId, BlockNumber>,
+    BE: Backend<Block> + 'static,
+{
+    let registry: Arc<dyn Registry> = Arc::new(ClientRegistry {
+        client,
+        _marker: PhantomData,
+    });
+    let schema = Schema::build(Query, EmptyMutation, EmptySubscription)
+        .data(registry)
+        .limit_depth(MAX_QUERY_DEPTH)
+        .finish();
+
+    let make_service = make_service_fn(move |_| {
+        let schema = schema.clone();
+        async move {
+            Ok::<_, Infallible>(service_fn(move |request| {
+                let schema = schema.clone();
+                async move { Ok::<_, Infallible>(handle(&schema, request).await) }
+            }))
+        }
+    });
+
+    let server = match Server::try_bind(&address) {
+        Ok(server) => server,
+        Err(err) => {
+            log::error!(
+                target: LOG_TARGET,
+                "Unable to bind the GraphQL endpoint to {address}: {err}"
+            );
+            return;
+        }
+    };
+    log::info!(target: LOG_TARGET, "GraphQL endpoint listening on http://{address}/graphql");
+
+    if let Err(err) = server.serve(make_service).await {
+        log::error!(target: LOG_TARGET, "GraphQL endpoint stopped: {err}");
+    }
+}
+
+/// Execute the GraphQL request in the body of a `POST /graphql`
+async fn handle(schema: &RegistrySchema, request: Request<Body>) -> Response<Body> {
+    if request.uri().path() != "/graphql" {
+        return respond(StatusCode::NOT_FOUND, r#"{"error":"Unknown resource."}"#.into());
+    }
+    if request.method() != Method::POST {
+        return respond(
+            StatusCode::METHOD_NOT_ALLOWED,
+            r#"{"error":"Only POST requests are served."}"#.into(),
+        );
+    }
+
+    let query = match hyper::body::to_bytes(request.into_body()).await {
+        Ok(body) => serde_json::from_slice::<async_graphql::Request>(&body),
+        Err(_) => {
+            return respond(StatusCode::BAD_REQUEST, r#"{"error":"Unreadable body."}"#.into());
+        }
+    };
+    let Ok(query) = query else {
+        return respond(
+            StatusCode::BAD_REQUEST,
+            r#"{"error":"Invalid GraphQL request."}"#.into(),
+        );
+    };
+
+    let response = schema.execute(query).await;
+    respond(
+        StatusCode::OK,
+        serde_json::to_string(&response).expect("GraphQL responses serialize to JSON"),
+    )
+}
+
+fn respond(status: StatusCode, body: String) -> Response<Body> {
+    Response::builder()
+        .status(status)
+        .header(CONTENT_TYPE, "application/json")
+        .body(Body::from(body))
+        .expect("status and header are valid")
+}
@@ -0,0 +1,295 @@
+//! Read-only JSON-RPC endpoint reporting a retired EVM chain id
+//!
+//! After the chain id is renumbered, wallets still configured with the old id refuse to talk
+//! to the node, or sign transactions the runtime rejects without telling the user why.
+//! Enabled with `--legacy-chain-id <id>`, the node serves a second HTTP JSON-RPC endpoint
+//! for the migration period:
+//! - `eth_chainId` and `net_version` report the legacy id, so that the wallet keeps working
+//! - other `eth_`, `net_` and `web3_` reads are forwarded to the node's RPC server
+//! - `eth_sendRawTransaction` is refused, with a reason naming the new chain id when the
+//!   transaction was signed for the legacy one, or carries no replay protection at all
+//! - signing, `eth_sendTransaction` and subscriptions are refused as unsupported
+//!
+//! Transactions signed for the legacy id would never be valid on the renumbered chain, and
+//! the endpoint never submits any: the node's own endpoint remains the only way in.
+
+use std::{
+    convert::Infallible,
+    net::{Ipv4Addr, SocketAddr},
+    sync::Arc,
+};
+
+use ethereum::{EnvelopedDecodable, TransactionV2};
+use fp_rpc::EthereumRuntimeRPCApi;
+use hyper::{
+    client::HttpConnector,
+    header::CONTENT_TYPE,
+    service::{make_service_fn, service_fn},
+    Body, Client, Method, Request, Response, Server, StatusCode, Uri,
+};
+use parachain_template_runtime::opaque::Block;
+use serde_json::{json, Value};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_core::bytes::from_hex;
+
+const LOG_TARGET: &str = "legacy-rpc";
+
+/// Configuration of the legacy chain id endpoint.
+#[derive(Clone, Debug, clap::Parser)]
+pub struct LegacyRpcConfiguration {
+    /// Serve a read-only JSON-RPC endpoint reporting this retired EVM chain id.
+    #[arg(long)]
+    pub legacy_chain_id: Option<u64>,
+
+    /// Port of the legacy chain id endpoint.
+    #[arg(long, default_value = "9955")]
+    pub legacy_rpc_port: u16,
+
+    /// Listen on all interfaces rather than on localhost only.
+    #[arg(long)]
+    pub legacy_rpc_external: bool,
+
+    /// JSON-RPC server the legacy chain id endpoint forwards reads to.
+    #[arg(long, default_value = "http://127.0.0.1:9944")]
+    pub legacy_rpc_upstream: Uri,
+}
+
+impl LegacyRpcConfiguration {
+    /// Address to serve the endpoint on, `None` if it is disabled
+    pub fn listen_address(&self) -> Option<SocketAddr> {
+        let ip = if self.legacy_rpc_external {
+            Ipv4Addr::UNSPECIFIED
+        } else {
+            Ipv4Addr::LOCALHOST
+        };
+        self.legacy_chain_id.map(|_| SocketAddr::new(ip.into(), self.legacy_rpc_port))
+    }
+}
+
+/// Error answered to a JSON-RPC request
+struct RpcError(i64, String);
+
+impl RpcError {
+    /// Transaction refused, EIP-1474 code
+    fn rejected(message: impl Into<String>) -> Self {
+        Self(-32003, message.into())
+    }
+
+    /// Method the endpoint does not serve, EIP-1474 code
+    fn unsupported(method: &str) -> Self {
+        Self(
+            -32004,
+            format!("{method} is not supported by the read-only legacy chain id endpoint"),
+        )
+    }
+
+    fn invalid_params(message: impl Into<String>) -> Self {
+        Self(-32602, message.into())
+    }
+
+    fn internal(message: impl Into<String>) -> Self {
+        Self(-32603, message.into())
+    }
+}
+
+struct Endpoint<C> {
+    client: Arc<C>,
+    legacy_chain_id: u64,
+    upstream: Uri,
+    http: Client<HttpConnector>,
+}
+
+/// Serve the legacy chain id endpoint on `address` until the node shuts down
+pub async fn run<C>(client: Arc<C>, address: SocketAddr, config: LegacyRpcConfiguration)
+where
+    C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+    C::Api: EthereumRuntimeRPCApi<Block>,
+{
+    let Some(legacy_chain_id) = config.legacy_chain_id else {
+        return;
+    };
+    let endpoint = Arc::new(Endpoint {
+        client,
+        legacy_chain_id,
+        upstream: config.legacy_rpc_upstream,
+        http: Client::new(),
+    });
+    if endpoint.chain_id().ok() == Some(legacy_chain_id) {
+        log::warn!(
+            target: LOG_TARGET,
+            "The legacy chain id {legacy_chain_id} is the chain id of the runtime"
+        );
+    }
+
+    let make_service = make_service_fn(move |_| {
+        let endpoint = endpoint.clone();
+        async move {
+            Ok::<_, Infallible>(service_fn(move |request| {
+                let endpoint = endpoint.clone();
+                async move { Ok::<_, Infallible>(endpoint.handle(request).await) }
+            }))
+        }
+    });
+
+    let server = match Server::try_bind(&address) {
+        Ok(server) => server,
+        Err(err) => {
+            log::error!(
+                target: LOG_TARGET,
+                "Unable to bind the legacy chain id endpoint to {address}: {err}"
+            );
+            return;
+        }
+    };
+    log::info!(
+        target: LOG_TARGET,
+        "Legacy chain id {legacy_chain_id} endpoint listening on http://{address}"
+    );
+
+    if let Err(err) = server.serve(make_service).await {
+        log::error!(target: LOG_TARGET, "Legacy chain id endpoint stopped: {err}");
+    }
+}
+
+impl<C> Endpoint<C>
+where
+    C: ProvideRuntimeApi<Block> + HeaderBackend<Block>,
+    C::Api: EthereumRuntimeRPCApi<Block>,
+{
+    /// Answer a single or batched JSON-RPC request
+    async fn handle(&self, request: Request<Body>) -> Response<Body> {
+        if request.method() != Method::POST {
+            return respond(
+                StatusCode::METHOD_NOT_ALLOWED,
+                json!({ "error": "Only POST requests are served." }),
+            );
+        }
+        let payload = hyper::body::to_bytes(request.into_body())
+            .await
+            .ok()
+            .and_then(|body| serde_json::from_slice::<Value>(&body).ok());
+
+        let answer = match payload {
+            Some(Value::Array(requests)) => {
+                let mut answers = Vec::with_capacity(requests.len());
+                for request in requests {
+                    answers.push(self.answer(request).await);
+                }
+                Value::Array(answers)
+            }
+            Some(request) => self.answer(request).await,
+            None => error_response(Value::Null, RpcError(-32700, "Parse error".into())),
+        };
+        respond(StatusCode::OK, answer)
+    }
+
+    async fn answer(&self, request: Value) -> Value {
+        let id = request.get("id").cloned().unwrap_or(Value::Null);
+        let method = request.get("method").and_then(Value::as_str).unwrap_or_default();
+
+        let result = match method {
+            "eth_chainId" => Ok(json!(format!("{:#x}", self.legacy_chain_id))),
+            "net_version" => Ok(json!(self.legacy_chain_id.to_string())),
+            "eth_sendRawTransaction" => Err(self.refuse_raw_transaction(&request)),
+            "eth_sendTransaction" | "eth_subscribe" | "eth_unsubscribe" => {
+                Err(RpcError::unsupported(method))
+            }
+            _ if method.starts_with("eth_sign") => Err(RpcError::unsupported(method)),
+            _ if ["eth_", "net_", "web3_"].iter().any(|prefix| method.starts_with(prefix)) => {
+                return self.forward(request).await;
+            }
+            _ => Err(RpcError(-32601, format!("Method not found: {method}"))),
+        };
+
+        match result {
+            Ok(result) => json!({ "jsonrpc": "2.0", "id": id, "result": result }),
+            Err(err) => error_response(id, err),
+        }
+    }
+
+    /// EVM chain id of the runtime at the best block
+    fn chain_id(&self) -> Result<u64, RpcError> {
+        self.client
+            .runtime_api()
+            .chain_id(self.client.info().best_hash)
+            .map_err(|err| RpcError::internal(format!("Runtime API call failed: {err:?}")))
+    }
+
+    /// Why the raw transaction of an `eth_sendRawTransaction` request is refused
+    fn refuse_raw_transaction(&self, request: &Value) -> RpcError {
+        let raw = request
+            .get("params")
+            .and_then(|params| params.get(0))
+            .and_then(Value::as_str)
+            .and_then(|raw| from_hex(raw).ok());
+        let Some(transaction) =
+            raw.and_then(|raw| <TransactionV2 as EnvelopedDecodable>::decode(&raw).ok())
+        else {
+            return RpcError::invalid_params("Expected a hex-encoded signed transaction");
+        };
+        let signed_for = match &transaction {
+            TransactionV2::Legacy(t) => t.signature.chain_id(),
+            TransactionV2::EIP2930(t) => Some(t.chain_id),
+            TransactionV2::EIP1559(t) => Some(t.chain_id),
+        };
+        let chain_id = match self.chain_id() {
+            Ok(chain_id) => chain_id,
+            Err(err) => return err,
+        };
+
+        match signed_for {
+            None => RpcError::rejected(format!(
+                "Transaction without EIP-155 replay protection refused: sign it for chain id \
+                 {chain_id}"
+            )),
+            Some(id) if id == self.legacy_chain_id => RpcError::rejected(format!(
+                "Transaction signed for the retired chain id {id}: switch the wallet's network \
+                 to chain id {chain_id} and sign it again"
+            )),
+            Some(id) if id == chain_id => RpcError::rejected(format!(
+                "This endpoint is read-only for wallets on the retired chain id {}: send \
+                 transactions signed for chain id {chain_id} to the node's main endpoint",
+                self.legacy_chain_id
+            )),
+            Some(id) => RpcError::rejected(format!(
+                "Transaction signed for chain id {id}, which is not this chain's ({chain_id})"
+            )),
+        }
+    }
+
+    /// Answer of the upstream server to `request`
+    async fn forward(&self, request: Value) -> Value {
+        let id = request.get("id").cloned().unwrap_or(Value::Null);
+        let upstream = Request::post(self.upstream.clone())
+            .header(CONTENT_TYPE, "application/json")
+            .body(Body::from(request.to_string()))
+            .expect("uri and header are valid");
+
+        let answer = match self.http.request(upstream).await {
+            Ok(response) => hyper::body::to_bytes(response.into_body())
+                .await
+                .ok()
+                .and_then(|body| serde_json::from_slice(&body).ok()),
+            Err(err) => {
+                log::debug!(target: LOG_TARGET, "Upstream request failed: {err}");
+                None
+            }
+        };
+        answer.unwrap_or_else(|| {
+            error_response(id, RpcError::internal("The node's RPC server did not answer"))
+        })
+    }
+}
+
+fn error_response(id: Value, RpcError(code, message): RpcError) -> Value {
+    json!({ "jsonrpc": "2.0", "id": id, "error": { "code": code, "message": message } })
+}
+
+fn respond(status: StatusCode, body: Value) -> Response<Body> {
+    Response::builder()
+        .status(status)
+        .header(CONTENT_TYPE, "application/json")
+        .body(Body::from(body.to_string()))
+        .expect("status and header are valid")
+}
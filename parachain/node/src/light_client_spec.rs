@@ -0,0 +1,88 @@
+//! Chain specifications bundled for light clients
+//!
+//! Browser dApps run smoldot to check deployment authorizations against the chain rather
+//! than trusting an RPC node. Smoldot follows a parachain through the heads its relay chain
+//! finalizes, so it needs the raw parachain spec together with the spec of that relay
+//! chain. The relay chain spec should carry a `lightSyncState` checkpoint, as returned by
+//! `sync_state_genSyncSpec` on a relay chain node, so that smoldot does not sync from
+//! genesis.
+//!
+//! `export-light-client-spec` writes both specs to a single JSON file for the dApps to
+//! embed, after checking that they belong together.
+
+use std::{fs, io::Write, path::PathBuf};
+
+use sc_cli::{CliConfiguration, SharedParams};
+use serde_json::{json, Value};
+
+use crate::chain_spec::Extensions;
+
+/// The `export-light-client-spec` command
+#[derive(Debug, Clone, clap::Parser)]
+pub struct ExportLightClientSpecCmd {
+    /// Spec of the relay chain, with a `lightSyncState` checkpoint
+    #[arg(long, value_name = "PATH")]
+    pub relay_chain_spec: PathBuf,
+
+    /// Accept a relay chain spec without checkpoint, syncing from its genesis
+    #[arg(long)]
+    pub allow_missing_checkpoint: bool,
+
+    /// File to write the bundle to, standard output if omitted
+    #[arg(long, short)]
+    pub output: Option<PathBuf>,
+
+    #[allow(missing_docs)]
+    #[clap(flatten)]
+    pub shared_params: SharedParams,
+}
+
+impl ExportLightClientSpecCmd {
+    /// Run the command
+    ///
+    /// Bundles the spec selected with `--chain`, in its raw form, with the relay chain spec.
+    pub fn run(&self, spec: Box<dyn sc_service::ChainSpec>) -> sc_cli::Result<()> {
+        let relay_chain_spec: Value = serde_json::from_slice(&fs::read(&self.relay_chain_spec)?)
+            .map_err(|err| format!("Invalid relay chain spec: {err}"))?;
+        let relay_chain_id = relay_chain_spec["id"]
+            .as_str()
+            .ok_or("The relay chain spec has no id")?;
+
+        let extensions = Extensions::try_get(&*spec).ok_or("The chain spec is not a parachain")?;
+        if extensions.relay_chain != relay_chain_id {
+            return Err(format!(
+                "The chain spec names relay chain {} but the relay chain spec is {relay_chain_id}",
+                extensions.relay_chain
+            )
+            .into());
+        }
+        if relay_chain_spec.get("lightSyncState").is_none() && !self.allow_missing_checkpoint {
+            return Err("The relay chain spec has no lightSyncState checkpoint".into());
+        }
+        if spec.boot_nodes().is_empty() {
+            log::warn!("The chain spec has no boot nodes, light clients will not find peers");
+        }
+
+        let chain_spec: Value = serde_json::from_str(&spec.as_json(true)?)
+            .map_err(|err| format!("Invalid chain spec: {err}"))?;
+        let bundle = json!({
+            "chainSpec": chain_spec,
+            "relayChainSpec": relay_chain_spec,
+        });
+        let json = serde_json::to_vec_pretty(&bundle)
+            .map_err(|err| format!("Failed to serialize the bundle: {err}"))?;
+
+        match &self.output {
+            Some(path) => fs::write(path, json)?,
+            None => std::io::stdout().write_all(&json)?,
+        }
+
+        Ok(())
+    }
+}
+
+impl CliConfiguration for ExportLightClientSpecCmd {
+    fn shared_params(&self) -> &SharedParams {
+        &self.shared_params
+    }
+}
@@ -2,12 +2,22 @@
 
 #![warn(missing_docs)]
 
+mod authorization_prewarm;
 mod chain_spec;
 mod cli;
 mod command;
+mod deployment_filter;
+mod deployment_state;
 mod eth;
+mod eth_validation;
+mod graphql;
+mod legacy_rpc;
+mod light_client_spec;
+mod rest_gateway;
 mod rpc;
 mod service;
+mod test_vectors;
+mod verify_runtime;
 
 fn main() -> sc_cli::Result<()> {
     command::run()
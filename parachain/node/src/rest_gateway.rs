@@ -0,0 +1,260 @@
+//! Minimal REST gateway for integrators that cannot speak JSON-RPC or SCALE
+//!
+//! Enabled with `--enable-rest-gateway`, the node serves read-only JSON resources
+//! translated from runtime APIs at the best block:
+//! - `GET /accounts/{address}`: balance and nonce of an EVM account
+//! - `GET /deployers/{address}`: reputation of a deployer
+//! - `GET /factories?startAfter={address}&limit={n}`: approved factories in address order
+//! - `GET /contracts/{address}`: code, storage and provenance information of a contract
+//!
+//! Addresses are 0x-prefixed H160s. Errors are returned as `{"error": "..."}` with the
+//! matching HTTP status.
+
+use std::{
+    convert::Infallible,
+    net::{Ipv4Addr, SocketAddr},
+    str::FromStr,
+    sync::Arc,
+};
+
+use fp_rpc::EthereumRuntimeRPCApi;
+use hyper::{
+    header::CONTENT_TYPE,
+    service::{make_service_fn, service_fn},
+    Body, Method, Request, Response, Server, StatusCode,
+};
+use pallet_deployer_reputation_runtime_api::DeployerReputationApi;
+use pallet_evm_deployment_control::ContractDeployer;
+use pallet_evm_deployment_control_runtime_api::DeploymentControlApi;
+use parachain_template_runtime::{opaque::Block, AccountId, BlockNumber, Runtime};
+use qnch_rpc_types::Pagination;
+use serde::Serialize;
+use serde_json::json;
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_core::{H160, U256};
+
+const LOG_TARGET: &str = "rest-gateway";
+
+/// Configuration of the REST gateway.
+#[derive(Clone, Debug, clap::Parser)]
+pub struct RestGatewayConfiguration {
+    /// Serve the read-only REST gateway alongside the JSON-RPC server.
+    #[arg(long)]
+    pub enable_rest_gateway: bool,
+
+    /// Port of the REST gateway.
+    #[arg(long, default_value = "8080")]
+    pub rest_gateway_port: u16,
+
+    /// Listen on all interfaces rather than on localhost only.
+    #[arg(long)]
+    pub rest_gateway_external: bool,
+}
+
+impl RestGatewayConfiguration {
+    /// Address to serve the gateway on, `None` if it is disabled
+    pub fn listen_address(&self) -> Option<SocketAddr> {
+        let ip = if self.rest_gateway_external {
+            Ipv4Addr::UNSPECIFIED
+        } else {
+            Ipv4Addr::LOCALHOST
+        };
+        self.enable_rest_gateway
+            .then(|| SocketAddr::new(ip.into(), self.rest_gateway_port))
+    }
+}
+
+/// Balance and nonce of an EVM account
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct AccountResource {
+    address: H160,
+    balance: U256,
+    nonce: U256,
+}
+
+/// Reputation of a deployer
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct DeployerResource {
+    address: H160,
+    account: AccountId,
+    score: i32,
+    suspended_at: Option<BlockNumber>,
+    suspension_floor: i32,
+}
+
+/// Error answered to a request, with its HTTP status
+struct GatewayError(StatusCode, String);
+
+impl GatewayError {
+    fn bad_request(message: impl Into<String>) -> Self {
+        Self(StatusCode::BAD_REQUEST, message.into())
+    }
+
+    fn not_found(message: impl Into<String>) -> Self {
+        Self(StatusCode::NOT_FOUND, message.into())
+    }
+
+    fn runtime(err: impl std::fmt::Debug) -> Self {
+        Self(StatusCode::INTERNAL_SERVER_ERROR, format!("Runtime API call failed: {err:?}"))
+    }
+}
+
+/// Serve the REST gateway on `address` until the node shuts down
+pub async fn run<C>(client: Arc<C>, address: SocketAddr)
+where
+    C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+    C::Api: EthereumRuntimeRPCApi<Block>
+        + DeploymentControlApi<Block, AccountId, BlockNumber>
+        + DeployerReputationApi<Block, AccountId, BlockNumber>,
+{
+    let make_service = make_service_fn(move |_| {
+        let client = client.clone();
+        async move {
+            Ok::<_, Infallible>(service_fn(move |request| {
+                let client = client.clone();
+                async move { Ok::<_, Infallible>(handle(&*client, request)) }
+            }))
+        }
+    });
+
+    let server = match Server::try_bind(&address) {
+        Ok(server) => server,
+        Err(err) => {
+            log::error!(
+                target: LOG_TARGET,
+                "Unable to bind the REST gateway to {address}: {err}"
+            );
+            return;
+        }
+    };
+    log::info!(target: LOG_TARGET, "REST gateway listening on http://{address}");
+
+    if let Err(err) = server.serve(make_service).await {
+        log::error!(target: LOG_TARGET, "REST gateway stopped: {err}");
+    }
+}
+
+/// Answer a request with the JSON resource it names
+fn handle<C>(client: &C, request: Request<Body>) -> Response<Body>
+where
+    C: ProvideRuntimeApi<Block> + HeaderBackend<Block>,
+    C::Api: EthereumRuntimeRPCApi<Block>
+        + DeploymentControlApi<Block, AccountId, BlockNumber>
+        + DeployerReputationApi<Block, AccountId, BlockNumber>,
+{
+    if request.method() != Method::GET {
+        return respond(
+            StatusCode::METHOD_NOT_ALLOWED,
+            json!({ "error": "Only GET requests are served." }),
+        );
+    }
+
+    match route(client, request.uri().path(), request.uri().query()) {
+        Ok(body) => respond(StatusCode::OK, body),
+        Err(GatewayError(status, message)) => respond(status, json!({ "error": message })),
+    }
+}
+
+fn route<C>(
+    client: &C,
+    path: &str,
+    query: Option<&str>,
+) -> Result<serde_json::Value, GatewayError>
+where
+    C: ProvideRuntimeApi<Block> + HeaderBackend<Block>,
+    C::Api: EthereumRuntimeRPCApi<Block>
+        + DeploymentControlApi<Block, AccountId, BlockNumber>
+        + DeployerReputationApi<Block, AccountId, BlockNumber>,
+{
+    let at = client.info().best_hash;
+    let api = client.runtime_api();
+    let segments: Vec<&str> = path.split('/').filter(|segment| !segment.is_empty()).collect();
+
+    let resource = match segments[..] {
+        ["accounts", address] => {
+            let address = parse_address(address)?;
+            let account = api.account_basic(at, address).map_err(GatewayError::runtime)?;
+            to_json(AccountResource {
+                address,
+                balance: account.balance,
+                nonce: account.nonce,
+            })
+        }
+        ["deployers", address] => {
+            let address = parse_address(address)?;
+            let account = account_of(address);
+            let reputation =
+                api.reputation(at, account.clone()).map_err(GatewayError::runtime)?;
+            let suspension_floor = api.suspension_floor(at).map_err(GatewayError::runtime)?;
+            to_json(DeployerResource {
+                address,
+                account,
+                score: reputation.score,
+                suspended_at: reputation.suspended_at,
+                suspension_floor,
+            })
+        }
+        ["factories"] => {
+            let pagination = parse_pagination(query)?;
+            let factories = api
+                .list_factories(at, pagination.start_after, pagination.page_size())
+                .map_err(GatewayError::runtime)?;
+            to_json(factories)
+        }
+        ["contracts", address] => {
+            let address = parse_address(address)?;
+            match api.contract_info(at, address).map_err(GatewayError::runtime)? {
+                Some(info) => to_json(info),
+                None => return Err(GatewayError::not_found("No contract at this address.")),
+            }
+        }
+        _ => return Err(GatewayError::not_found("Unknown resource.")),
+    };
+
+    Ok(resource)
+}
+
+/// Account of `address` under the runtime's address mapping
+pub(crate) fn account_of(address: H160) -> AccountId {
+    <<Runtime as pallet_evm_deployment_control::Config>::ContractDeployer as ContractDeployer<
+        AccountId,
+    >>::into_account_id(address)
+}
+
+fn parse_address(address: &str) -> Result<H160, GatewayError> {
+    H160::from_str(address).map_err(|_| GatewayError::bad_request("Invalid address."))
+}
+
+/// `startAfter` and `limit` query parameters of a list resource
+fn parse_pagination(query: Option<&str>) -> Result<Pagination<H160>, GatewayError> {
+    let mut pagination = Pagination::default();
+    let pairs = query.unwrap_or_default().split('&').filter_map(|pair| pair.split_once('='));
+    for (key, value) in pairs {
+        match key {
+            "startAfter" => pagination.start_after = Some(parse_address(value)?),
+            "limit" => {
+                let limit = value
+                    .parse()
+                    .map_err(|_| GatewayError::bad_request("Invalid limit."))?;
+                pagination.limit = Some(limit);
+            }
+            _ => return Err(GatewayError::bad_request(format!("Unknown parameter {key}."))),
+        }
+    }
+    Ok(pagination)
+}
+
+fn to_json(resource: impl Serialize) -> serde_json::Value {
+    serde_json::to_value(resource).expect("resources serialize to JSON")
+}
+
+fn respond(status: StatusCode, body: serde_json::Value) -> Response<Body> {
+    Response::builder()
+        .status(status)
+        .header(CONTENT_TYPE, "application/json")
+        .body(Body::from(body.to_string()))
+        .expect("status and header are valid")
+}
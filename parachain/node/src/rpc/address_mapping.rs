@@ -0,0 +1,81 @@
+//! RPC method converting between EVM addresses and native accounts, defined in
+//! `qnch_rpc_types`.
+
+use std::{marker::PhantomData, sync::Arc};
+
+use address_mapping_runtime_api::AddressMappingApi as AddressMappingRuntimeApi;
+use jsonrpsee::{
+    core::RpcResult,
+    types::error::{ErrorObject, ErrorObjectOwned},
+};
+use parity_scale_codec::{Codec, Encode};
+pub use qnch_rpc_types::AddressMappingApiServer;
+use qnch_rpc_types::{AddressOrAccount, AddressPair};
+use serde::{de::DeserializeOwned, Serialize};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_runtime::traits::Block as BlockT;
+
+/// Error code returned when the runtime API call fails
+const RUNTIME_ERROR: i32 = 1;
+
+/// Implementation of the address mapping RPC method.
+pub struct AddressMapping<C, B> {
+    client: Arc<C>,
+    _marker: PhantomData<B>,
+}
+
+impl<C, B> AddressMapping<C, B> {
+    /// Create a new instance backed by the given client.
+    pub fn new(client: Arc<C>) -> Self {
+        Self {
+            client,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<C, Block, AccountId> AddressMappingApiServer<<Block as BlockT>::Hash, AccountId>
+    for AddressMapping<C, Block>
+where
+    Block: BlockT,
+    AccountId: Codec + Clone + Serialize + DeserializeOwned + Send + Sync + 'static,
+    C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+    C::Api: AddressMappingRuntimeApi<Block, AccountId>,
+{
+    fn address_of(
+        &self,
+        address: AddressOrAccount<AccountId>,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<AddressPair<AccountId>> {
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        let api = self.client.runtime_api();
+
+        let (address, account) = match address {
+            AddressOrAccount::Address(address) => {
+                let account = api
+                    .account_of(at, address)
+                    .map_err(|err| runtime_error("Unable to map the address.", err))?;
+                (Some(address), account)
+            }
+            AddressOrAccount::Account(account) => {
+                let address = api
+                    .address_of(at, account.clone())
+                    .map_err(|err| runtime_error("Unable to map the account.", err))?;
+                (address, account)
+            }
+        };
+        // 20-byte accounts encode as the address they are
+        let unified = address.map_or(false, |address| address.as_bytes() == account.encode());
+
+        Ok(AddressPair {
+            address,
+            account,
+            unified,
+        })
+    }
+}
+
+fn runtime_error(message: &str, err: impl std::fmt::Debug) -> ErrorObjectOwned {
+    ErrorObject::owned(RUNTIME_ERROR, message, Some(format!("{:?}", err)))
+}
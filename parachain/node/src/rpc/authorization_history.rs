@@ -0,0 +1,122 @@
+//! RPC method answering whether an account was an authorized deployer at a past block,
+//! defined in `qnch_rpc_types`.
+//!
+//! The answer is the deployer status at the block, served by [`DeploymentControl`] and
+//! therefore by runtimes of any version. When the state of the block is pruned, it comes
+//! from the authorization transitions index of the pallet at the best block instead, which
+//! does not know the block an account was authorized at.
+
+use std::sync::Arc;
+
+use jsonrpsee::{
+    core::RpcResult,
+    types::error::{ErrorObject, ErrorObjectOwned},
+};
+use pallet_evm_deployment_control_runtime_api::{
+    versions, DeploymentControlApi as DeploymentControlRuntimeApi,
+};
+use parity_scale_codec::Codec;
+pub use qnch_rpc_types::AuthorizationHistoryApiServer;
+use qnch_rpc_types::{AuthorizationAt, DeploymentControlApiServer};
+use sc_client_api::{Backend, StorageProvider};
+use serde::{de::DeserializeOwned, Serialize};
+use sp_api::{ApiExt, ProvideRuntimeApi};
+use sp_blockchain::HeaderBackend;
+use sp_runtime::traits::{Block as BlockT, NumberFor};
+
+use super::DeploymentControl;
+
+/// Error code returned when the block is not part of the best chain
+const UNKNOWN_BLOCK: i32 = 1;
+
+/// Error code returned when the state of the block is not available
+const STATE_UNAVAILABLE: i32 = 2;
+
+/// Implementation of the authorization history RPC method.
+pub struct AuthorizationHistory<C, B, BE> {
+    client: Arc<C>,
+    deployment_control: DeploymentControl<C, B, BE>,
+}
+
+impl<C, B, BE> AuthorizationHistory<C, B, BE> {
+    /// Create a new instance backed by the given client.
+    pub fn new(client: Arc<C>) -> Self {
+        Self {
+            deployment_control: DeploymentControl::new(client.clone()),
+            client,
+        }
+    }
+}
+
+impl<C, Block, BE, AccountId>
+    AuthorizationHistoryApiServer<<Block as BlockT>::Hash, AccountId, NumberFor<Block>>
+    for AuthorizationHistory<C, Block, BE>
+where
+    Block: BlockT,
+    BE: Backend<Block> + 'static,
+    AccountId: Codec + Clone + Serialize + DeserializeOwned + Send + Sync + 'static,
+    C: ProvideRuntimeApi<Block>
+        + HeaderBackend<Block>
+        + StorageProvider<Block, BE>
+        + Send
+        + Sync
+        + 'static,
+    C::Api: DeploymentControlRuntimeApi<Block, AccountId, NumberFor<Block>>,
+{
+    fn was_authorized_at(
+        &self,
+        account: AccountId,
+        block: NumberFor<Block>,
+    ) -> RpcResult<AuthorizationAt<<Block as BlockT>::Hash, NumberFor<Block>>> {
+        let block_hash = self
+            .client
+            .hash(block)
+            .ok()
+            .flatten()
+            .ok_or_else(|| ErrorObject::owned(UNKNOWN_BLOCK, "Unknown block.", None::<()>))?;
+
+        let err = match self
+            .deployment_control
+            .deployer_status(account.clone(), Some(block_hash))
+        {
+            Ok(status) => {
+                return Ok(AuthorizationAt {
+                    block_hash,
+                    authorized: status.authorized,
+                    authorized_at: status.authorized_at,
+                })
+            }
+            Err(err) => err,
+        };
+
+        let best_hash = self.client.info().best_hash;
+        let api = self.client.runtime_api();
+        let version = api
+            .api_version::<dyn DeploymentControlRuntimeApi<Block, AccountId, NumberFor<Block>>>(
+                best_hash,
+            )
+            .ok()
+            .flatten()
+            .unwrap_or_default();
+        if version < versions::WAS_AUTHORIZED_AT {
+            return Err(state_unavailable(err));
+        }
+        match api.was_authorized_at(best_hash, account, block) {
+            Ok(Some(authorized)) => Ok(AuthorizationAt {
+                block_hash,
+                authorized,
+                authorized_at: None,
+            }),
+            _ => Err(state_unavailable(err)),
+        }
+    }
+}
+
+fn state_unavailable(err: ErrorObjectOwned) -> ErrorObjectOwned {
+    ErrorObject::owned(
+        STATE_UNAVAILABLE,
+        "State of the block is not available and the authorization index does not cover \
+         it; query an archive node.",
+        Some(err.message().to_owned()),
+    )
+}
@@ -0,0 +1,106 @@
+//! RPC method listing the contract creations of a block from the offchain index, defined in
+//! `qnch_rpc_types`.
+//!
+//! The deployment control pallet writes the creations of every block, internal ones
+//! included, to the offchain index under [`CreationTrace::offchain_key`]. Nodes only keep
+//! the index when started with `--enable-offchain-indexing true`.
+
+use std::{marker::PhantomData, sync::Arc};
+
+use fp_rpc::EthereumRuntimeRPCApi;
+use jsonrpsee::{
+    core::RpcResult,
+    types::error::{ErrorObject, ErrorObjectOwned},
+};
+use pallet_evm_deployment_control::CreationTrace;
+use parity_scale_codec::Decode;
+pub use qnch_rpc_types::ContractCreationsApiServer;
+use qnch_rpc_types::ContractCreation;
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_core::offchain::{OffchainStorage, STORAGE_PREFIX};
+use sp_runtime::traits::{Block as BlockT, Header as HeaderT};
+
+/// Error code returned when the requested block is unknown
+const UNKNOWN_BLOCK: i32 = 1;
+
+/// Error code returned when the node does not keep the offchain index
+const INDEXING_DISABLED: i32 = 2;
+
+/// Error code returned when the runtime API call fails
+const RUNTIME_ERROR: i32 = 3;
+
+/// Implementation of the contract creations RPC method.
+pub struct ContractCreations<C, S, B> {
+    client: Arc<C>,
+    offchain_storage: Option<S>,
+    _marker: PhantomData<B>,
+}
+
+impl<C, S, B> ContractCreations<C, S, B> {
+    /// Create a new instance reading the index from `offchain_storage`, `None` if offchain
+    /// indexing is disabled.
+    pub fn new(client: Arc<C>, offchain_storage: Option<S>) -> Self {
+        Self {
+            client,
+            offchain_storage,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<C, S, Block> ContractCreationsApiServer<<Block as BlockT>::Hash>
+    for ContractCreations<C, S, Block>
+where
+    Block: BlockT,
+    C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+    C::Api: EthereumRuntimeRPCApi<Block>,
+    S: OffchainStorage + 'static,
+{
+    fn contract_creations(
+        &self,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Option<Vec<ContractCreation>>> {
+        let Some(storage) = &self.offchain_storage else {
+            return Err(ErrorObject::owned(
+                INDEXING_DISABLED,
+                "Offchain indexing is disabled, restart the node with \
+                 `--enable-offchain-indexing true`.",
+                None::<()>,
+            ));
+        };
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        let header = self.client.header(at).ok().flatten().ok_or_else(|| {
+            ErrorObject::owned(UNKNOWN_BLOCK, format!("Unknown block {at:?}."), None::<()>)
+        })?;
+
+        let key = CreationTrace::offchain_key(header.parent_hash(), header.digest());
+        let Some(traces) = storage
+            .get(STORAGE_PREFIX, &key)
+            .and_then(|traces| Vec::<CreationTrace>::decode(&mut &traces[..]).ok())
+        else {
+            return Ok(None);
+        };
+
+        let api = self.client.runtime_api();
+        let creations = traces
+            .into_iter()
+            .map(|trace| {
+                let code = api
+                    .account_code_at(at, trace.creator)
+                    .map_err(|err| runtime_error("Unable to read the creator's code.", err))?;
+                Ok(ContractCreation {
+                    extrinsic_index: trace.extrinsic_index,
+                    creator: trace.creator,
+                    contract: trace.contract,
+                    internal: !code.is_empty(),
+                })
+            })
+            .collect::<RpcResult<_>>()?;
+        Ok(Some(creations))
+    }
+}
+
+fn runtime_error(message: &str, err: impl std::fmt::Debug) -> ErrorObjectOwned {
+    ErrorObject::owned(RUNTIME_ERROR, message, Some(format!("{:?}", err)))
+}
@@ -0,0 +1,172 @@
+//! RPC methods exposing EVM deployment control state, defined in `qnch_rpc_types`.
+//!
+//! Methods are served by the `DeploymentControlApi` runtime API. `qnch_deployerStatus` reads
+//! the deployment control storage of runtimes from before `deployer_status` instead, in the
+//! layout those runtimes are frozen with, so that historical blocks keep being answered
+//! once the storage is redesigned.
+
+use std::{marker::PhantomData, sync::Arc};
+
+use frame_support::{storage::storage_prefix, Blake2_128Concat, StorageHasher};
+
+use jsonrpsee::{
+    core::RpcResult,
+    types::error::{ErrorObject, ErrorObjectOwned},
+};
+use pallet_evm_deployment_control_runtime_api::{
+    versions, ContractInfo, DeployerStatus, DeploymentControlApi as DeploymentControlRuntimeApi,
+    FactoryEntry,
+};
+use parity_scale_codec::{Codec, Decode, Encode};
+pub use qnch_rpc_types::DeploymentControlApiServer;
+use qnch_rpc_types::Pagination;
+use sc_client_api::{Backend, StorageProvider};
+use serde::{de::DeserializeOwned, Serialize};
+use sp_api::{ApiExt, ProvideRuntimeApi};
+use sp_blockchain::HeaderBackend;
+use sp_core::{storage::StorageKey, H160};
+use sp_runtime::traits::{Block as BlockT, NumberFor};
+
+/// Error code returned when the runtime API call fails
+const RUNTIME_ERROR: i32 = 1;
+
+/// Name of the deployment control pallet in the runtime
+const PALLET: &[u8] = b"EvmDeploymentControl";
+
+/// Record of an authorized deployer in storage version 1 of the pallet
+///
+/// Frozen here rather than taken from the pallet, whose record follows the latest layout.
+#[derive(Decode)]
+struct DeployerInfoV1<BlockNumber> {
+    authorized_at: BlockNumber,
+}
+
+/// Implementation of the deployment control RPC methods.
+pub struct DeploymentControl<C, B, BE> {
+    client: Arc<C>,
+    _marker: PhantomData<(B, BE)>,
+}
+
+impl<C, B, BE> DeploymentControl<C, B, BE> {
+    /// Create a new instance backed by the given client.
+    pub fn new(client: Arc<C>) -> Self {
+        Self {
+            client,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<C, Block, BE> DeploymentControl<C, Block, BE>
+where
+    Block: BlockT,
+    BE: Backend<Block>,
+    C: StorageProvider<Block, BE>,
+{
+    /// Authorization of `account` as stored by runtimes from before `deployer_status`
+    ///
+    /// Those runtimes keep storage version 1 of the pallet. Deployers stored before its
+    /// migration are reported without the block they were authorized at.
+    fn legacy_deployer_status<AccountId: Encode>(
+        &self,
+        at: <Block as BlockT>::Hash,
+        account: &AccountId,
+    ) -> RpcResult<DeployerStatus<NumberFor<Block>>> {
+        let read = |item: &[u8]| {
+            let mut key = storage_prefix(PALLET, item).to_vec();
+            key.extend(Blake2_128Concat::hash(&account.encode()));
+            self.client
+                .storage(at, &StorageKey(key))
+                .map_err(|err| runtime_error("Unable to read deployer storage.", err))
+        };
+
+        let info = read(b"AuthorizedDeployers")?;
+        Ok(DeployerStatus {
+            authorized: info.is_some(),
+            authorized_at: info
+                .and_then(|info| DeployerInfoV1::decode(&mut &info.0[..]).ok())
+                .map(|info| info.authorized_at),
+            deployer_admin: read(b"DeployerAdmins")?.is_some(),
+        })
+    }
+}
+
+impl<C, Block, BE, AccountId>
+    DeploymentControlApiServer<<Block as BlockT>::Hash, AccountId, NumberFor<Block>>
+    for DeploymentControl<C, Block, BE>
+where
+    Block: BlockT,
+    BE: Backend<Block> + 'static,
+    AccountId: Codec + Serialize + DeserializeOwned + Send + Sync + 'static,
+    C: ProvideRuntimeApi<Block>
+        + HeaderBackend<Block>
+        + StorageProvider<Block, BE>
+        + Send
+        + Sync
+        + 'static,
+    C::Api: DeploymentControlRuntimeApi<Block, AccountId, NumberFor<Block>>,
+{
+    fn contract_info(
+        &self,
+        address: H160,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Option<ContractInfo<NumberFor<Block>>>> {
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        self.client
+            .runtime_api()
+            .contract_info(at, address)
+            .map_err(|err| runtime_error("Unable to query contract info.", err))
+    }
+
+    fn list_factories(
+        &self,
+        pagination: Option<Pagination<H160>>,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<FactoryEntry<AccountId, NumberFor<Block>>>> {
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        let pagination = pagination.unwrap_or_default();
+
+        let api = self.client.runtime_api();
+        if api_version::<_, Block, AccountId>(&*api, at)? < versions::LIST_FACTORIES {
+            return Err(ErrorObject::owned(
+                RUNTIME_ERROR,
+                "Listing factories is not supported at this block.",
+                None::<()>,
+            ));
+        }
+        api.list_factories(at, pagination.start_after, pagination.page_size())
+            .map_err(|err| runtime_error("Unable to list factories.", err))
+    }
+
+    fn deployer_status(
+        &self,
+        account: AccountId,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<DeployerStatus<NumberFor<Block>>> {
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        let api = self.client.runtime_api();
+        if api_version::<_, Block, AccountId>(&*api, at)? < versions::DEPLOYER_STATUS {
+            return self.legacy_deployer_status(at, &account);
+        }
+        api.deployer_status(at, account)
+            .map_err(|err| runtime_error("Unable to query deployer status.", err))
+    }
+}
+
+/// Version of `DeploymentControlApi` implemented by the runtime at `at`, zero without it
+fn api_version<Api, Block, AccountId>(api: &Api, at: <Block as BlockT>::Hash) -> RpcResult<u32>
+where
+    Block: BlockT,
+    AccountId: Codec,
+    Api: ApiExt<Block>,
+{
+    api.api_version::<dyn DeploymentControlRuntimeApi<Block, AccountId, NumberFor<Block>>>(at)
+        .map(Option::unwrap_or_default)
+        .map_err(|err| runtime_error("Unable to query runtime version.", err))
+}
+
+fn runtime_error(message: &str, err: impl std::fmt::Debug) -> ErrorObjectOwned {
+    ErrorObject::owned(RUNTIME_ERROR, message, Some(format!("{:?}", err)))
+}
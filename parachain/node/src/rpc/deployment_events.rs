@@ -0,0 +1,150 @@
+//! Subscription streaming deployment control events.
+//!
+//! Lets ops tooling follow deployer authorizations, revocations and deployments through
+//! `deploy_contract` without running a full Substrate indexer. Events are read from the
+//! `System::Events` storage of finalized blocks, so subscribers never see events of a
+//! block that is later retracted.
+
+use std::{marker::PhantomData, sync::Arc};
+
+use frame_support::storage::StorageValue;
+use frame_system::{EventRecord, Phase};
+use futures::{stream, FutureExt, StreamExt};
+use jsonrpsee::PendingSubscriptionSink;
+use pallet_evm_deployment_control::Event as DeploymentControlEvent;
+use parachain_template_runtime::{
+    opaque::Block, AccountId, BlockNumber, Hash, Runtime, RuntimeEvent,
+};
+use parity_scale_codec::Decode;
+pub use qnch_rpc_types::DeploymentEventsApiServer;
+use sc_client_api::{Backend, BlockchainEvents, StorageProvider};
+use sc_rpc::{utils::pipe_from_stream, SubscriptionTaskExecutor};
+use sp_blockchain::HeaderBackend;
+use sp_core::storage::StorageKey;
+
+const LOG_TARGET: &str = "rpc::deployment-events";
+
+/// A deployment control event as streamed to subscribers
+pub type DeploymentEvent = qnch_rpc_types::DeploymentEvent<AccountId>;
+
+/// A streamed deployment control event and the block it was emitted in
+pub type DeploymentEventNotification =
+    qnch_rpc_types::DeploymentEventNotification<AccountId, Hash, BlockNumber>;
+
+/// The streamed counterpart of a pallet event, `None` for events not streamed
+fn streamed_event(event: DeploymentControlEvent<Runtime>) -> Option<DeploymentEvent> {
+    match event {
+        DeploymentControlEvent::DeployerAuthorized { deployer } => {
+            Some(DeploymentEvent::DeployerAuthorized { deployer })
+        }
+        DeploymentControlEvent::DeployerRevoked { deployer } => {
+            Some(DeploymentEvent::DeployerRevoked { deployer })
+        }
+        DeploymentControlEvent::ContractDeployed {
+            deployer,
+            contract,
+            metadata,
+        } => Some(DeploymentEvent::ContractDeployed {
+            deployer,
+            contract,
+            label: String::from_utf8_lossy(&metadata.label).into_owned(),
+            version: String::from_utf8_lossy(&metadata.version).into_owned(),
+        }),
+        _ => None,
+    }
+}
+
+/// Implementation of the deployment control subscriptions.
+pub struct DeploymentEvents<C, BE> {
+    client: Arc<C>,
+    executor: SubscriptionTaskExecutor,
+    _marker: PhantomData<BE>,
+}
+
+impl<C, BE> DeploymentEvents<C, BE> {
+    /// Create a new instance backed by the given client.
+    pub fn new(client: Arc<C>, executor: SubscriptionTaskExecutor) -> Self {
+        Self {
+            client,
+            executor,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<C, BE> DeploymentEventsApiServer<AccountId, Hash, BlockNumber> for DeploymentEvents<C, BE>
+where
+    C: BlockchainEvents<Block>
+        + StorageProvider<Block, BE>
+        + HeaderBackend<Block>
+        + Send
+        + Sync
+        + 'static,
+    BE: Backend<Block> + 'static,
+{
+    fn subscribe_deployment_events(&self, pending: PendingSubscriptionSink) {
+        let client = self.client.clone();
+        let notifications = self
+            .client
+            .finality_notification_stream()
+            .flat_map(move |finalized| {
+                // Blocks finalized along with the notified one come first, in order
+                let notifications: Vec<_> = finalized
+                    .tree_route
+                    .iter()
+                    .copied()
+                    .chain(std::iter::once(finalized.hash))
+                    .flat_map(|hash| block_events(&*client, hash))
+                    .collect();
+                stream::iter(notifications)
+            })
+            .boxed();
+
+        self.executor.spawn(
+            "qnch-deployment-events",
+            Some("rpc"),
+            pipe_from_stream(pending, notifications).boxed(),
+        );
+    }
+}
+
+/// Deployment control events emitted in a block
+pub(crate) fn block_events<C, BE>(client: &C, hash: Hash) -> Vec<DeploymentEventNotification>
+where
+    C: StorageProvider<Block, BE> + HeaderBackend<Block>,
+    BE: Backend<Block>,
+{
+    let Ok(Some(block_number)) = client.number(hash) else {
+        return Vec::new();
+    };
+
+    let key = StorageKey(frame_system::Events::<Runtime>::hashed_key().to_vec());
+    let Ok(Some(data)) = client.storage(hash, &key) else {
+        return Vec::new();
+    };
+    let records = match Vec::<EventRecord<RuntimeEvent, Hash>>::decode(&mut &data.0[..]) {
+        Ok(records) => records,
+        Err(err) => {
+            log::debug!(target: LOG_TARGET, "Failed to decode events of {hash:?}: {err}");
+            return Vec::new();
+        }
+    };
+
+    records
+        .into_iter()
+        .filter_map(|record| {
+            let RuntimeEvent::EvmDeploymentControl(event) = record.event else {
+                return None;
+            };
+            Some(DeploymentEventNotification {
+                block_hash: hash,
+                block_number,
+                extrinsic_index: match record.phase {
+                    Phase::ApplyExtrinsic(index) => Some(index),
+                    _ => None,
+                },
+                event: streamed_event(event)?,
+            })
+        })
+        .collect()
+}
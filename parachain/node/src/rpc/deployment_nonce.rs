@@ -0,0 +1,129 @@
+//! `qnch_nextDeploymentNonce`, the nonce and deployment prerequisites of an address in one
+//! call.
+//!
+//! Deployment pipelines otherwise combine `eth_getTransactionCount` with the deployment
+//! control queries, each answered at whatever the best block is when it arrives, and race
+//! their own transactions still in the pool. Here the nonce and the prerequisites are read
+//! at the same best block, and the sender's transactions ready in the pool are counted in.
+
+use std::sync::Arc;
+
+use ethereum::{TransactionAction, TransactionV2};
+use jsonrpsee::{
+    core::RpcResult,
+    types::error::{ErrorObject, ErrorObjectOwned},
+};
+use pallet_evm_deployment_control_runtime_api::{
+    versions, DeploymentControlApi as DeploymentControlRuntimeApi,
+};
+use parachain_template_runtime::{
+    opaque::Block, AccountId, BlockNumber, Hash, RuntimeCall, UncheckedExtrinsic,
+};
+use parity_scale_codec::{Decode, DecodeAll, Encode};
+pub use qnch_rpc_types::DeploymentNonceApiServer;
+use sc_transaction_pool_api::{InPoolTransaction, TransactionPool};
+use sp_api::{ApiExt, ProvideRuntimeApi};
+use sp_blockchain::HeaderBackend;
+use sp_core::{H160, U256};
+use sp_runtime::OpaqueExtrinsic;
+
+use super::tx_diagnostics::refusal_reason;
+
+/// Error code returned when the runtime API call fails
+const RUNTIME_ERROR: i32 = 1;
+
+/// Nonce and deployment prerequisites of an address
+pub type NextDeploymentNonce = qnch_rpc_types::NextDeploymentNonce<Hash, BlockNumber>;
+
+/// Implementation of the deployment nonce RPC method.
+pub struct DeploymentNonce<C, P> {
+    client: Arc<C>,
+    pool: Arc<P>,
+}
+
+impl<C, P> DeploymentNonce<C, P> {
+    /// Create a new instance backed by the given client and transaction pool.
+    pub fn new(client: Arc<C>, pool: Arc<P>) -> Self {
+        Self { client, pool }
+    }
+}
+
+impl<C, P> DeploymentNonceApiServer<Hash, BlockNumber> for DeploymentNonce<C, P>
+where
+    C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+    C::Api: DeploymentControlRuntimeApi<Block, AccountId, BlockNumber>,
+    P: TransactionPool<Block = Block> + 'static,
+{
+    fn next_deployment_nonce(&self, address: H160) -> RpcResult<NextDeploymentNonce> {
+        let at = self.client.info().best_hash;
+        let api = self.client.runtime_api();
+
+        let version = api
+            .api_version::<dyn DeploymentControlRuntimeApi<Block, AccountId, BlockNumber>>(at)
+            .map_err(|err| runtime_error("Unable to query runtime version.", err))?
+            .unwrap_or_default();
+        if version < versions::DEPLOYMENT_PREREQUISITES {
+            return Err(ErrorObject::owned(
+                RUNTIME_ERROR,
+                "Deployment prerequisites are not supported by the current runtime.",
+                None::<()>,
+            ));
+        }
+        let prerequisites = api
+            .deployment_prerequisites(at, address)
+            .map_err(|err| runtime_error("Unable to query deployment prerequisites.", err))?;
+
+        // Ethereum transactions provide their sender and nonce as a tag, and the ready ones
+        // of a sender carry consecutive nonces from its account nonce on
+        let mut nonce = prerequisites.nonce;
+        let (mut pending, mut pending_creations) = (0u32, 0u32);
+        for tx in self.pool.ready() {
+            let sent = tx.provides().iter().find_map(|tag| {
+                let (sender, nonce) = <(H160, U256)>::decode_all(&mut &tag[..]).ok()?;
+                (sender == address && nonce >= prerequisites.nonce).then_some(nonce)
+            });
+            if let Some(sent) = sent {
+                nonce = nonce.max(sent.saturating_add(U256::one()));
+                pending += 1;
+                pending_creations += is_contract_creation(tx.data()) as u32;
+            }
+        }
+
+        let refusal_code = prerequisites.refusal;
+        Ok(NextDeploymentNonce {
+            block_hash: at,
+            nonce,
+            pending,
+            pending_creations,
+            refusal_code,
+            refusal: refusal_code.map(refusal_reason),
+            remaining_quota: prerequisites
+                .remaining_quota
+                .map(|remaining| remaining.saturating_sub(pending_creations)),
+            quota_resets_at: prerequisites.quota_resets_at,
+            announced_intents: prerequisites.announced_intents,
+        })
+    }
+}
+
+/// Whether `extrinsic` is an Ethereum transaction creating a contract
+fn is_contract_creation(extrinsic: &OpaqueExtrinsic) -> bool {
+    let Ok(extrinsic) = UncheckedExtrinsic::decode(&mut &extrinsic.encode()[..]) else {
+        return false;
+    };
+    let RuntimeCall::Ethereum(pallet_ethereum::Call::transact { transaction }) =
+        &extrinsic.0.function
+    else {
+        return false;
+    };
+    let action = match transaction {
+        TransactionV2::Legacy(t) => t.action,
+        TransactionV2::EIP2930(t) => t.action,
+        TransactionV2::EIP1559(t) => t.action,
+    };
+    action == TransactionAction::Create
+}
+
+fn runtime_error(message: &str, err: impl std::fmt::Debug) -> ErrorObjectOwned {
+    ErrorObject::owned(RUNTIME_ERROR, message, Some(format!("{:?}", err)))
+}
@@ -0,0 +1,41 @@
+//! `qnch_deploymentRejection`, explaining why a contract creation was dropped.
+//!
+//! Contract creations refused by the deployment policy at submission are rejected with the
+//! reason straight away, but the ones pruned from the ready queue later on, after their
+//! sender lost its authorization, never get a receipt. Nodes running the deployment filter,
+//! i.e. collators, keep the reason of these recent removals and serve it here.
+
+use jsonrpsee::core::RpcResult;
+use parachain_template_runtime::{BlockNumber, Hash};
+pub use qnch_rpc_types::DeploymentRejectionsApiServer;
+use sp_core::H256;
+
+use super::tx_diagnostics::refusal_reason;
+use crate::deployment_filter::DeploymentRejections as RejectionLog;
+
+/// Why a contract creation was removed from the transaction pool
+pub type Rejection = qnch_rpc_types::Rejection<Hash, BlockNumber>;
+
+/// Implementation of the deployment rejection RPC methods.
+pub struct DeploymentRejections {
+    rejections: RejectionLog,
+}
+
+impl DeploymentRejections {
+    /// Create a new instance serving the given rejections.
+    pub fn new(rejections: RejectionLog) -> Self {
+        Self { rejections }
+    }
+}
+
+impl DeploymentRejectionsApiServer<Hash, BlockNumber> for DeploymentRejections {
+    fn deployment_rejection(&self, transaction_hash: H256) -> RpcResult<Option<Rejection>> {
+        Ok(self
+            .rejections
+            .get(&transaction_hash)
+            .map(|rejection| Rejection {
+                reason: refusal_reason(rejection.code),
+                rejection,
+            }))
+    }
+}
@@ -0,0 +1,137 @@
+//! `qnch_deploymentStateDiff`, listing deployment control changes between two blocks.
+//!
+//! Compliance reporting builds its weekly change reports from this method rather than from
+//! the event history. The authorized deployers and approved factories are read from the
+//! storage of both blocks, which must therefore not have been pruned.
+
+use std::{collections::BTreeSet, marker::PhantomData, sync::Arc};
+
+use frame_support::storage::StoragePrefixedMap;
+use jsonrpsee::{
+    core::RpcResult,
+    types::error::{ErrorObject, ErrorObjectOwned},
+};
+use pallet_evm_deployment_control::{ApprovedFactories, AuthorizedDeployers};
+use parachain_template_runtime::{opaque::Block, AccountId, BlockNumber, Hash, Runtime};
+use parity_scale_codec::Decode;
+pub use qnch_rpc_types::DeploymentStateDiffApiServer;
+use sc_client_api::{Backend, StorageProvider};
+use sp_blockchain::HeaderBackend;
+use sp_core::{storage::StorageKey, H160};
+
+use crate::deployment_state::BLAKE2_128_CONCAT_PREFIX_LEN;
+
+/// Error code returned when a block is unknown
+const UNKNOWN_BLOCK: i32 = 1;
+/// Error code returned when the state of a block cannot be read
+const STATE_ERROR: i32 = 2;
+
+/// Deployers and factories added and removed between two blocks
+pub type DeploymentStateDiff = qnch_rpc_types::DeploymentStateDiff<AccountId, BlockNumber>;
+
+/// Implementation of the deployment state diff RPC methods.
+pub struct DeploymentStateDiffs<C, BE> {
+    client: Arc<C>,
+    _marker: PhantomData<BE>,
+}
+
+impl<C, BE> DeploymentStateDiffs<C, BE> {
+    /// Create a new instance backed by the given client.
+    pub fn new(client: Arc<C>) -> Self {
+        Self {
+            client,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<C, BE> DeploymentStateDiffApiServer<Hash, AccountId, BlockNumber>
+    for DeploymentStateDiffs<C, BE>
+where
+    C: StorageProvider<Block, BE> + HeaderBackend<Block> + Send + Sync + 'static,
+    BE: Backend<Block> + 'static,
+{
+    fn deployment_state_diff(
+        &self,
+        from_block: Hash,
+        to_block: Hash,
+    ) -> RpcResult<DeploymentStateDiff> {
+        let from = self.registry(from_block)?;
+        let to = self.registry(to_block)?;
+
+        Ok(DeploymentStateDiff {
+            from_block: from.block,
+            to_block: to.block,
+            added_deployers: to.deployers.difference(&from.deployers).cloned().collect(),
+            removed_deployers: from.deployers.difference(&to.deployers).cloned().collect(),
+            added_factories: to.factories.difference(&from.factories).copied().collect(),
+            removed_factories: from.factories.difference(&to.factories).copied().collect(),
+        })
+    }
+}
+
+/// Deployers and factories registered at a block
+struct Registry {
+    block: BlockNumber,
+    deployers: BTreeSet<AccountId>,
+    factories: BTreeSet<H160>,
+}
+
+impl<C, BE> DeploymentStateDiffs<C, BE>
+where
+    C: StorageProvider<Block, BE> + HeaderBackend<Block>,
+    BE: Backend<Block>,
+{
+    fn registry(&self, hash: Hash) -> RpcResult<Registry> {
+        let block = self
+            .client
+            .number(hash)
+            .map_err(|err| state_error(hash, err))?
+            .ok_or_else(|| {
+                ErrorObject::owned(UNKNOWN_BLOCK, format!("Unknown block {hash:?}."), None::<()>)
+            })?;
+
+        // Deployer keys hold the account after its `Blake2_128Concat` hash, factory keys
+        // hold the `Identity` hashed address itself
+        let deployers = self
+            .keys(hash, AuthorizedDeployers::<Runtime>::final_prefix())?
+            .into_iter()
+            .map(|key| decode(hash, &key[BLAKE2_128_CONCAT_PREFIX_LEN..]))
+            .collect::<RpcResult<_>>()?;
+        let factories = self
+            .keys(hash, ApprovedFactories::<Runtime>::final_prefix())?
+            .into_iter()
+            .map(|key| decode(hash, &key))
+            .collect::<RpcResult<_>>()?;
+
+        Ok(Registry {
+            block,
+            deployers,
+            factories,
+        })
+    }
+
+    /// Keys, stripped of `prefix`, of the storage entries under `prefix`
+    fn keys(&self, hash: Hash, prefix: [u8; 32]) -> RpcResult<Vec<Vec<u8>>> {
+        let prefix = StorageKey(prefix.to_vec());
+
+        Ok(self
+            .client
+            .storage_keys(hash, Some(&prefix), None)
+            .map_err(|err| state_error(hash, err))?
+            .map(|key| key.0[prefix.0.len()..].to_vec())
+            .collect())
+    }
+}
+
+fn decode<T: Decode>(hash: Hash, mut bytes: &[u8]) -> RpcResult<T> {
+    T::decode(&mut bytes).map_err(|err| state_error(hash, err))
+}
+
+fn state_error(hash: Hash, err: impl std::fmt::Debug) -> ErrorObjectOwned {
+    ErrorObject::owned(
+        STATE_ERROR,
+        format!("Unable to read the deployment state of {hash:?}."),
+        Some(format!("{:?}", err)),
+    )
+}
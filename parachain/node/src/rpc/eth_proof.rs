@@ -0,0 +1,232 @@
+//! `eth_getProof` backed by the runtime state trie.
+//!
+//! The response has the shape of EIP-1186, but qnch keeps EVM state in the Substrate state
+//! trie rather than per-account Merkle-Patricia tries. The proofs are therefore Substrate
+//! trie nodes, verified against the block's state root, which is returned as `storageHash`.
+//! `accountProof` proves the System account (balance and nonce) and code of the address,
+//! each `storageProof` entry proves a single storage slot.
+//!
+//! Like geth, storage keys are accepted as hex strings of up to 32 bytes, left-padded with
+//! zeros, so that clients passing slot numbers such as `"0x0"` work unchanged.
+
+use std::{marker::PhantomData, sync::Arc};
+
+use fc_rpc_core::types::{BlockNumberOrHash, Bytes};
+use jsonrpsee::{
+    core::{async_trait, RpcResult},
+    proc_macros::rpc,
+    types::error::{ErrorObject, ErrorObjectOwned},
+};
+use pallet_evm_deployment_control_runtime_api::{
+    versions, DeploymentControlApi as DeploymentControlRuntimeApi,
+};
+use parity_scale_codec::Codec;
+use sc_client_api::ProofProvider;
+use serde::{de::Error as _, Deserialize, Deserializer, Serialize};
+use sp_api::{ApiExt, ProvideRuntimeApi};
+use sp_blockchain::HeaderBackend;
+use sp_core::{H160, H256, U256};
+use sp_runtime::traits::{Block as BlockT, Header as HeaderT, NumberFor};
+
+/// Error code returned when the requested block is unknown
+const UNKNOWN_BLOCK: i32 = 1;
+
+/// Error code returned when the runtime API call or proof generation fails
+const RUNTIME_ERROR: i32 = 2;
+
+/// Maximum number of storage slots proven in a single request
+const MAX_STORAGE_KEYS: usize = 1024;
+
+/// Storage slot requested for proving
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+pub struct StorageKey(pub H256);
+
+impl<'de> Deserialize<'de> for StorageKey {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let key = String::deserialize(deserializer)?;
+        let digits = key.strip_prefix("0x").unwrap_or(&key);
+        if digits.is_empty() || digits.len() > 64 {
+            return Err(D::Error::custom("storage key must be 1 to 32 bytes of hex"));
+        }
+
+        let value = U256::from_str_radix(digits, 16).map_err(D::Error::custom)?;
+        let mut bytes = [0u8; 32];
+        value.to_big_endian(&mut bytes);
+        Ok(Self(H256(bytes)))
+    }
+}
+
+/// Proof of a storage slot
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StorageProof {
+    /// Slot index in the contract storage
+    pub key: H256,
+    /// Value of the slot
+    pub value: U256,
+    /// Trie nodes proving the slot against the state root
+    pub proof: Vec<Bytes>,
+}
+
+/// Proof of an account and some of its storage slots
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountProof {
+    /// Address of the account
+    pub address: H160,
+    /// Balance of the account
+    pub balance: U256,
+    /// Keccak-256 hash of the account code
+    pub code_hash: H256,
+    /// Nonce of the account
+    pub nonce: U256,
+    /// State root of the block, which all proofs are verified against
+    pub storage_hash: H256,
+    /// Trie nodes proving the balance, nonce and code of the account
+    pub account_proof: Vec<Bytes>,
+    /// Proofs of the requested storage slots
+    pub storage_proof: Vec<StorageProof>,
+}
+
+/// EVM state proof RPC methods.
+#[rpc(server)]
+pub trait EthProofApi {
+    /// Account and storage proofs of `address` at the given block, the best block if omitted
+    #[method(name = "eth_getProof")]
+    async fn proof(
+        &self,
+        address: H160,
+        storage_keys: Vec<StorageKey>,
+        number_or_hash: Option<BlockNumberOrHash>,
+    ) -> RpcResult<AccountProof>;
+}
+
+/// Implementation of the EVM state proof RPC methods.
+pub struct EthProof<C, Block: BlockT, AccountId> {
+    client: Arc<C>,
+    frontier_backend: Arc<dyn fc_api::Backend<Block>>,
+    _marker: PhantomData<AccountId>,
+}
+
+impl<C, Block: BlockT, AccountId> EthProof<C, Block, AccountId> {
+    /// Create a new instance backed by the given client.
+    pub fn new(client: Arc<C>, frontier_backend: Arc<dyn fc_api::Backend<Block>>) -> Self {
+        Self {
+            client,
+            frontier_backend,
+            _marker: PhantomData,
+        }
+    }
+}
+
+#[async_trait]
+impl<C, Block, AccountId> EthProofApiServer for EthProof<C, Block, AccountId>
+where
+    Block: BlockT<Hash = H256>,
+    AccountId: Codec + Send + Sync + 'static,
+    C: ProvideRuntimeApi<Block>
+        + HeaderBackend<Block>
+        + ProofProvider<Block>
+        + Send
+        + Sync
+        + 'static,
+    C::Api: DeploymentControlRuntimeApi<Block, AccountId, NumberFor<Block>>,
+{
+    async fn proof(
+        &self,
+        address: H160,
+        storage_keys: Vec<StorageKey>,
+        number_or_hash: Option<BlockNumberOrHash>,
+    ) -> RpcResult<AccountProof> {
+        if storage_keys.len() > MAX_STORAGE_KEYS {
+            return Err(ErrorObject::owned(
+                RUNTIME_ERROR,
+                format!("At most {MAX_STORAGE_KEYS} storage keys can be proven at once."),
+                None::<()>,
+            ));
+        }
+
+        let id = fc_rpc::frontier_backend_client::native_block_id::<Block, C>(
+            self.client.as_ref(),
+            self.frontier_backend.as_ref(),
+            number_or_hash,
+        )
+        .await?
+        .ok_or_else(unknown_block)?;
+        let hash = self
+            .client
+            .expect_block_hash_from_id(&id)
+            .map_err(|_| unknown_block())?;
+        let header = self
+            .client
+            .header(hash)
+            .map_err(|err| runtime_error("Unable to read header.", err))?
+            .ok_or_else(unknown_block)?;
+
+        let api = self.client.runtime_api();
+        let version = api
+            .api_version::<dyn DeploymentControlRuntimeApi<Block, AccountId, NumberFor<Block>>>(
+                hash,
+            )
+            .map_err(|err| runtime_error("Unable to query runtime version.", err))?;
+        if version.unwrap_or_default() < versions::EVM_ACCOUNT_STATE {
+            return Err(ErrorObject::owned(
+                RUNTIME_ERROR,
+                "Proofs are not supported at this block.",
+                None::<()>,
+            ));
+        }
+
+        let storage_keys = storage_keys.into_iter().map(|key| key.0).collect();
+        let state = api
+            .evm_account_state(hash, address, storage_keys)
+            .map_err(|err| runtime_error("Unable to query account state.", err))?;
+
+        let account_proof = self.read_proof(hash, &state.account_keys)?;
+        let storage_proof = state
+            .storage
+            .into_iter()
+            .map(|slot| {
+                Ok(StorageProof {
+                    key: slot.key,
+                    value: U256::from_big_endian(slot.value.as_bytes()),
+                    proof: self.read_proof(hash, &[slot.storage_key])?,
+                })
+            })
+            .collect::<RpcResult<_>>()?;
+
+        Ok(AccountProof {
+            address,
+            balance: state.balance,
+            code_hash: state.code_hash,
+            nonce: state.nonce,
+            storage_hash: *header.state_root(),
+            account_proof,
+            storage_proof,
+        })
+    }
+}
+
+impl<C, Block, AccountId> EthProof<C, Block, AccountId>
+where
+    Block: BlockT,
+    C: ProofProvider<Block>,
+{
+    /// Trie nodes proving the given runtime storage keys at `hash`
+    fn read_proof(&self, hash: Block::Hash, keys: &[Vec<u8>]) -> RpcResult<Vec<Bytes>> {
+        let proof = self
+            .client
+            .read_proof(hash, &mut keys.iter().map(|key| key.as_slice()))
+            .map_err(|err| runtime_error("Unable to generate proof.", err))?;
+
+        Ok(proof.into_iter_nodes().map(Bytes::new).collect())
+    }
+}
+
+fn unknown_block() -> ErrorObjectOwned {
+    ErrorObject::owned(UNKNOWN_BLOCK, "Unknown block.", None::<()>)
+}
+
+fn runtime_error(message: &str, err: impl std::fmt::Debug) -> ErrorObjectOwned {
+    ErrorObject::owned(RUNTIME_ERROR, message, Some(format!("{:?}", err)))
+}
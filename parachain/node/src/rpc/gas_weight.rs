@@ -0,0 +1,78 @@
+//! RPC methods converting between EVM gas and transaction weight, defined in
+//! `qnch_rpc_types`.
+
+use std::{marker::PhantomData, sync::Arc};
+
+use frame_support::weights::Weight;
+use gas_weight_runtime_api::GasWeightApi as GasWeightRuntimeApi;
+use jsonrpsee::{
+    core::RpcResult,
+    types::error::{ErrorObject, ErrorObjectOwned},
+};
+pub use qnch_rpc_types::GasWeightApiServer;
+use qnch_rpc_types::TransactionWeight;
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_runtime::traits::Block as BlockT;
+
+/// Error code returned when the runtime API call fails
+const RUNTIME_ERROR: i32 = 1;
+
+/// Implementation of the gas weight RPC methods.
+pub struct GasWeight<C, B> {
+    client: Arc<C>,
+    _marker: PhantomData<B>,
+}
+
+impl<C, B> GasWeight<C, B> {
+    /// Create a new instance backed by the given client.
+    pub fn new(client: Arc<C>) -> Self {
+        Self {
+            client,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<C, Block> GasWeightApiServer<<Block as BlockT>::Hash> for GasWeight<C, Block>
+where
+    Block: BlockT,
+    C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+    C::Api: GasWeightRuntimeApi<Block>,
+{
+    fn gas_to_weight(
+        &self,
+        gas: u64,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<TransactionWeight> {
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        let weight = self
+            .client
+            .runtime_api()
+            .gas_to_weight(at, gas)
+            .map_err(|err| runtime_error("Unable to convert the gas.", err))?;
+
+        Ok(TransactionWeight {
+            ref_time: weight.ref_time(),
+            proof_size: weight.proof_size(),
+        })
+    }
+
+    fn weight_to_gas(
+        &self,
+        weight: TransactionWeight,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<u64> {
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        let weight = Weight::from_parts(weight.ref_time, weight.proof_size);
+
+        self.client
+            .runtime_api()
+            .weight_to_gas(at, weight)
+            .map_err(|err| runtime_error("Unable to convert the weight.", err))
+    }
+}
+
+fn runtime_error(message: &str, err: impl std::fmt::Debug) -> ErrorObjectOwned {
+    ErrorObject::owned(RUNTIME_ERROR, message, Some(format!("{:?}", err)))
+}
@@ -7,12 +7,12 @@
 
 use std::sync::Arc;
 
-use parachain_template_runtime::{opaque::Block, AccountId, Balance, Nonce};
+use parachain_template_runtime::{opaque::Block, AccountId, Balance, BlockNumber, Nonce};
 
 use sc_client_api::{
     backend::{AuxStore, Backend, StorageProvider},
     client::BlockchainEvents,
-    UsageProvider,
+    BlockBackend, ProofProvider, UsageProvider,
 };
 pub use sc_rpc::{DenyUnsafe, SubscriptionTaskExecutor};
 use sc_transaction_pool::ChainApi;
@@ -23,15 +23,42 @@ use sp_consensus_aura::{sr25519::AuthorityId as AuraId, AuraApi};
 use sp_runtime::traits::Block as BlockT;
 use substrate_frame_rpc_system::SystemApiServer;
 
+mod address_mapping;
+mod authorization_history;
+mod contract_creations;
+mod deployment_control;
+mod deployment_events;
+mod deployment_nonce;
+mod deployment_rejections;
+mod deployment_state_diff;
 mod eth;
+mod eth_proof;
+mod gas_weight;
+mod session_keys;
+mod tx_diagnostics;
 
-pub use self::eth::{create_eth, EthDeps};
+pub use self::{
+    address_mapping::{AddressMapping, AddressMappingApiServer},
+    authorization_history::{AuthorizationHistory, AuthorizationHistoryApiServer},
+    contract_creations::{ContractCreations, ContractCreationsApiServer},
+    deployment_control::{DeploymentControl, DeploymentControlApiServer},
+    deployment_events::{DeploymentEvents, DeploymentEventsApiServer},
+    deployment_nonce::{DeploymentNonce, DeploymentNonceApiServer},
+    deployment_rejections::{DeploymentRejections, DeploymentRejectionsApiServer},
+    deployment_state_diff::{DeploymentStateDiffApiServer, DeploymentStateDiffs},
+    eth::{create_eth, EthDeps},
+    eth_proof::{EthProof, EthProofApiServer},
+    gas_weight::{GasWeight, GasWeightApiServer},
+    session_keys::{SessionKeys, SessionKeysApiServer},
+    tx_diagnostics::{TransactionDiagnostics, TransactionDiagnosticsApiServer},
+};
+pub(crate) use self::deployment_events::{block_events, DeploymentEventNotification};
 
 /// A type representing all RPC extensions.
 pub type RpcExtension = jsonrpsee::RpcModule<()>;
 
 /// Full client dependencies
-pub struct FullDeps<C, P, A: ChainApi, CT, CIDP> {
+pub struct FullDeps<C, P, A: ChainApi, CT, CIDP, S> {
     /// The client instance to use.
     pub client: Arc<C>,
     /// Transaction pool instance.
@@ -40,6 +67,11 @@ pub struct FullDeps<C, P, A: ChainApi, CT, CIDP> {
     pub deny_unsafe: DenyUnsafe,
     /// Ethereum-compatibility specific dependencies.
     pub eth: EthDeps<C, P, A, CT, Block, CIDP>,
+    /// Contract creations removed from the pool by the deployment filter.
+    pub deployment_rejections: crate::deployment_filter::DeploymentRejections,
+    /// Offchain storage holding the indexed contract creations, if offchain indexing is
+    /// enabled.
+    pub offchain_storage: Option<S>,
 }
 pub struct DefaultEthConfig<C, BE>(std::marker::PhantomData<(C, BE)>);
 
@@ -49,13 +81,17 @@ where
     BE: Backend<Block> + 'static,
 {
     type EstimateGasAdapter = ();
+    #[cfg(not(feature = "unified-accounts"))]
     type RuntimeStorageOverride =
         fc_rpc::frontier_backend_client::SystemAccountId32StorageOverride<Block, C, BE>;
+    #[cfg(feature = "unified-accounts")]
+    type RuntimeStorageOverride =
+        fc_rpc::frontier_backend_client::SystemAccountId20StorageOverride<Block, C, BE>;
 }
 
 /// Instantiate all Full RPC extensions.
 pub fn create_full<C, P, BE, A, CT, CIDP>(
-    deps: FullDeps<C, P, A, CT, CIDP>,
+    deps: FullDeps<C, P, A, CT, CIDP, BE::OffchainStorage>,
     subscription_task_executor: SubscriptionTaskExecutor,
     pubsub_notification_sinks: Arc<
         fc_mapping_sync::EthereumBlockNotificationSinks<
@@ -72,6 +108,8 @@ where
         + AuxStore
         + UsageProvider<Block>
         + HeaderMetadata<Block, Error = BlockChainError>
+        + ProofProvider<Block>
+        + BlockBackend<Block>
         + 'static,
     C: CallApiAt<Block>,
     C::Api: substrate_frame_rpc_system::AccountNonceApi<Block, AccountId, Nonce>,
@@ -80,6 +118,10 @@ where
     C::Api: fp_rpc::ConvertTransactionRuntimeApi<Block>,
     C::Api: fp_rpc::EthereumRuntimeRPCApi<Block>,
     C::Api: AuraApi<Block, AuraId>,
+    C::Api: pallet_evm_deployment_control_runtime_api::DeploymentControlApi<Block, AccountId, BlockNumber>,
+    C::Api: session_keys_runtime_api::SessionKeysApi<Block, AccountId>,
+    C::Api: address_mapping_runtime_api::AddressMappingApi<Block, AccountId>,
+    C::Api: gas_weight_runtime_api::GasWeightApi<Block>,
     BE: Backend<Block> + 'static,
     P: TransactionPool<Block = Block> + 'static,
     A: ChainApi<Block = Block> + 'static,
@@ -95,10 +137,35 @@ where
         pool,
         deny_unsafe,
         eth,
+        deployment_rejections,
+        offchain_storage,
     } = deps;
 
-    io.merge(System::new(client.clone(), pool, deny_unsafe).into_rpc())?;
-    io.merge(TransactionPayment::new(client).into_rpc())?;
+    io.merge(System::new(client.clone(), pool.clone(), deny_unsafe).into_rpc())?;
+    io.merge(TransactionPayment::new(client.clone()).into_rpc())?;
+    io.merge(DeploymentControl::<_, _, BE>::new(client.clone()).into_rpc())?;
+    io.merge(AuthorizationHistory::<_, _, BE>::new(client.clone()).into_rpc())?;
+    io.merge(SessionKeys::new(client.clone()).into_rpc())?;
+    io.merge(AddressMapping::new(client.clone()).into_rpc())?;
+    io.merge(GasWeight::new(client.clone()).into_rpc())?;
+    io.merge(ContractCreations::new(client.clone(), offchain_storage).into_rpc())?;
+    io.merge(
+        EthProof::<C, Block, AccountId>::new(client.clone(), eth.frontier_backend.clone())
+            .into_rpc(),
+    )?;
+    io.merge(
+        TransactionDiagnostics::<C, Block, AccountId>::new(
+            client.clone(),
+            eth.frontier_backend.clone(),
+        )
+        .into_rpc(),
+    )?;
+    io.merge(DeploymentRejections::new(deployment_rejections).into_rpc())?;
+    io.merge(DeploymentNonce::new(client.clone(), pool).into_rpc())?;
+    io.merge(DeploymentStateDiffs::<C, BE>::new(client.clone()).into_rpc())?;
+    io.merge(
+        DeploymentEvents::<C, BE>::new(client, subscription_task_executor.clone()).into_rpc(),
+    )?;
 
     // Ethereum compatibility RPCs
     let io = create_eth::<Block, C, P, CT, BE, A, CIDP, DefaultEthConfig<C, BE>>(
@@ -0,0 +1,87 @@
+//! RPC methods following the rotation of collator session keys, defined in
+//! `qnch_rpc_types`.
+
+use std::{marker::PhantomData, sync::Arc};
+
+use jsonrpsee::{
+    core::RpcResult,
+    types::error::{ErrorObject, ErrorObjectOwned},
+};
+use parity_scale_codec::Codec;
+pub use qnch_rpc_types::SessionKeysApiServer;
+use qnch_rpc_types::{CollatorKeys, QueuedSessionKeys};
+use serde::{de::DeserializeOwned, Serialize};
+use session_keys_runtime_api::SessionKeysApi as SessionKeysRuntimeApi;
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_core::Bytes;
+use sp_runtime::traits::Block as BlockT;
+
+/// Error code returned when the runtime API call fails
+const RUNTIME_ERROR: i32 = 1;
+
+/// Implementation of the session keys RPC methods.
+pub struct SessionKeys<C, B> {
+    client: Arc<C>,
+    _marker: PhantomData<B>,
+}
+
+impl<C, B> SessionKeys<C, B> {
+    /// Create a new instance backed by the given client.
+    pub fn new(client: Arc<C>) -> Self {
+        Self {
+            client,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<C, Block, AccountId> SessionKeysApiServer<<Block as BlockT>::Hash, AccountId>
+    for SessionKeys<C, Block>
+where
+    Block: BlockT,
+    AccountId: Codec + Serialize + DeserializeOwned + Send + Sync + 'static,
+    C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+    C::Api: SessionKeysRuntimeApi<Block, AccountId>,
+{
+    fn queued_session_keys(
+        &self,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<QueuedSessionKeys<AccountId>> {
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        let api = self.client.runtime_api();
+
+        let session = api
+            .current_session(at)
+            .map_err(|err| runtime_error("Unable to query the current session.", err))?;
+        let queued = api
+            .queued_keys(at)
+            .map_err(|err| runtime_error("Unable to query queued session keys.", err))?
+            .into_iter()
+            .map(|(collator, keys)| CollatorKeys {
+                collator,
+                keys: keys.into(),
+            })
+            .collect();
+
+        Ok(QueuedSessionKeys { session, queued })
+    }
+
+    fn next_session_keys(
+        &self,
+        collator: AccountId,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Option<Bytes>> {
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        self.client
+            .runtime_api()
+            .next_keys(at, collator)
+            .map(|keys| keys.map(Into::into))
+            .map_err(|err| runtime_error("Unable to query next session keys.", err))
+    }
+}
+
+fn runtime_error(message: &str, err: impl std::fmt::Debug) -> ErrorObjectOwned {
+    ErrorObject::owned(RUNTIME_ERROR, message, Some(format!("{:?}", err)))
+}
@@ -0,0 +1,189 @@
+//! `qnch_whyFailed`, explaining why an Ethereum transaction failed.
+//!
+//! The block containing the transaction is re-executed on its parent state up to the
+//! transaction, which is then checked against the runtime's deployment control, emergency
+//! pause and transfer allowlist policies before being executed again. Support tooling gets
+//! a structured diagnosis rather than the bare status of the receipt.
+
+use std::{marker::PhantomData, sync::Arc};
+
+use fc_rpc::frontier_backend_client;
+use jsonrpsee::{
+    core::{async_trait, RpcResult},
+    types::error::{ErrorObject, ErrorObjectOwned},
+};
+use pallet_evm_deployment_control::DeploymentValidationError;
+use pallet_evm_deployment_control_runtime_api::{
+    versions, DeploymentControlApi as DeploymentControlRuntimeApi, FailureDiagnosis,
+};
+use parity_scale_codec::{Codec, Decode};
+use qnch_rpc_types::Diagnosis;
+pub use qnch_rpc_types::TransactionDiagnosticsApiServer;
+use sc_client_api::BlockBackend;
+use sp_api::{ApiExt, Core, ProvideRuntimeApi};
+use sp_blockchain::HeaderBackend;
+use sp_core::{Bytes, H256, U256};
+use sp_runtime::traits::{Block as BlockT, Header as HeaderT, NumberFor};
+
+/// Error code returned when the block of the transaction is unknown
+const UNKNOWN_BLOCK: i32 = 1;
+
+/// Error code returned when the runtime API call fails
+const RUNTIME_ERROR: i32 = 2;
+
+/// Selector of the `Error(string)` revert reason
+const ERROR_SELECTOR: [u8; 4] = [0x08, 0xc3, 0x79, 0xa0];
+
+/// Selector of the `Panic(uint256)` revert reason
+const PANIC_SELECTOR: [u8; 4] = [0x4e, 0x48, 0x7b, 0x71];
+
+/// The diagnosis served for a failure diagnosed by the runtime
+fn diagnosis(failure: FailureDiagnosis) -> Diagnosis {
+    match failure {
+        FailureDiagnosis::Succeeded => Diagnosis::Succeeded,
+        FailureDiagnosis::UnauthorizedDeployment(code) => Diagnosis::UnauthorizedDeployment {
+            reason: refusal_reason(code),
+        },
+        FailureDiagnosis::Paused => Diagnosis::PausedContract,
+        FailureDiagnosis::RecipientNotAllowed => Diagnosis::DenyListedRecipient,
+        FailureDiagnosis::Rejected(err) => Diagnosis::Rejected {
+            error: format!("{:?}", err),
+        },
+        FailureDiagnosis::OutOfGas => Diagnosis::OutOfGas,
+        FailureDiagnosis::Reverted(data) => Diagnosis::Reverted {
+            reason: revert_reason(&data),
+            data: Bytes(data),
+        },
+        FailureDiagnosis::Halted(reason) => Diagnosis::Halted {
+            error: match fp_evm::ExitReason::decode(&mut &reason[..]) {
+                Ok(reason) => format!("{:?}", reason),
+                Err(_) => "unknown".into(),
+            },
+        },
+    }
+}
+
+/// Implementation of the failed transaction diagnostics RPC methods.
+pub struct TransactionDiagnostics<C, Block: BlockT, AccountId> {
+    client: Arc<C>,
+    frontier_backend: Arc<dyn fc_api::Backend<Block>>,
+    _marker: PhantomData<AccountId>,
+}
+
+impl<C, Block: BlockT, AccountId> TransactionDiagnostics<C, Block, AccountId> {
+    /// Create a new instance backed by the given client.
+    pub fn new(client: Arc<C>, frontier_backend: Arc<dyn fc_api::Backend<Block>>) -> Self {
+        Self {
+            client,
+            frontier_backend,
+            _marker: PhantomData,
+        }
+    }
+}
+
+#[async_trait]
+impl<C, Block, AccountId> TransactionDiagnosticsApiServer
+    for TransactionDiagnostics<C, Block, AccountId>
+where
+    Block: BlockT<Hash = H256>,
+    AccountId: Codec + Send + Sync + 'static,
+    C: ProvideRuntimeApi<Block>
+        + HeaderBackend<Block>
+        + BlockBackend<Block>
+        + Send
+        + Sync
+        + 'static,
+    C::Api: DeploymentControlRuntimeApi<Block, AccountId, NumberFor<Block>>,
+{
+    async fn why_failed(&self, transaction_hash: H256) -> RpcResult<Option<Diagnosis>> {
+        let client = self.client.as_ref();
+        let backend = self.frontier_backend.as_ref();
+
+        let Some((ethereum_block_hash, _)) =
+            frontier_backend_client::load_transactions::<Block, C>(
+                client,
+                backend,
+                transaction_hash,
+                true,
+            )
+            .await?
+        else {
+            return Ok(None);
+        };
+        let hash =
+            frontier_backend_client::load_hash::<Block, C>(client, backend, ethereum_block_hash)
+                .await?
+                .ok_or_else(unknown_block)?;
+
+        let header = client
+            .header(hash)
+            .map_err(|err| runtime_error("Unable to read header.", err))?
+            .ok_or_else(unknown_block)?;
+        let extrinsics = client
+            .block_body(hash)
+            .map_err(|err| runtime_error("Unable to read block body.", err))?
+            .ok_or_else(unknown_block)?;
+        let parent_hash = *header.parent_hash();
+
+        let api = client.runtime_api();
+        let version = api
+            .api_version::<dyn DeploymentControlRuntimeApi<Block, AccountId, NumberFor<Block>>>(
+                parent_hash,
+            )
+            .map_err(|err| runtime_error("Unable to query runtime version.", err))?;
+        if version.unwrap_or_default() < versions::DIAGNOSE_TRANSACTION {
+            return Err(ErrorObject::owned(
+                RUNTIME_ERROR,
+                "Diagnostics are not supported at this block.",
+                None::<()>,
+            ));
+        }
+
+        // Calls on the same runtime API instance share their state changes
+        api.initialize_block(parent_hash, &header)
+            .map_err(|err| runtime_error("Unable to initialize block.", err))?;
+        let diagnosis = api
+            .diagnose_transaction(parent_hash, extrinsics, transaction_hash)
+            .map_err(|err| runtime_error("Unable to diagnose transaction.", err))?;
+
+        Ok(diagnosis.map(self::diagnosis))
+    }
+}
+
+/// Explanation of a `DeploymentValidationError` code
+pub(super) fn refusal_reason(code: u8) -> String {
+    DeploymentValidationError::try_from(code)
+        .map_or("deployment refused", |err| err.description())
+        .to_string()
+}
+
+/// Reason carried by the return data of a reverted execution, if it is a Solidity
+/// `Error(string)` or `Panic(uint256)`
+fn revert_reason(data: &[u8]) -> Option<String> {
+    let (selector, payload) = (data.get(..4)?, data.get(4..)?);
+
+    if selector == ERROR_SELECTOR {
+        // ABI encoded string: offset, length, then the bytes
+        let offset = U256::from_big_endian(payload.get(..32)?).try_into().ok()?;
+        let length_word = payload.get(offset..offset.checked_add(32)?)?;
+        let length: usize = U256::from_big_endian(length_word).try_into().ok()?;
+        let start = offset.checked_add(32)?;
+        let message = payload.get(start..start.checked_add(length)?)?;
+        return Some(String::from_utf8_lossy(message).into_owned());
+    }
+
+    if selector == PANIC_SELECTOR {
+        let code = U256::from_big_endian(payload.get(..32)?);
+        return Some(format!("panic 0x{:x}", code));
+    }
+
+    None
+}
+
+fn unknown_block() -> ErrorObjectOwned {
+    ErrorObject::owned(UNKNOWN_BLOCK, "Unknown block.", None::<()>)
+}
+
+fn runtime_error(message: &str, err: impl std::fmt::Debug) -> ErrorObjectOwned {
+    ErrorObject::owned(RUNTIME_ERROR, message, Some(format!("{:?}", err)))
+}
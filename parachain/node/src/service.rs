@@ -7,7 +7,7 @@ use std::{path::Path, sync::Arc, time::Duration};
 use cumulus_client_cli::CollatorOptions;
 // Cumulus Imports
 use cumulus_client_collator::service::CollatorService;
-use cumulus_client_consensus_aura::collators::lookahead::{self as aura, Params as AuraParams};
+use cumulus_client_consensus_aura::collators::slot_based::{self as aura, Params as AuraParams};
 use cumulus_client_consensus_common::ParachainBlockImport as TParachainBlockImport;
 use cumulus_client_consensus_proposer::Proposer;
 use cumulus_client_service::{
@@ -19,7 +19,7 @@ use cumulus_primitives_core::{
     relay_chain::{CollatorPair, ValidationCode},
     ParaId,
 };
-use cumulus_relay_chain_interface::{OverseerHandle, RelayChainInterface};
+use cumulus_relay_chain_interface::RelayChainInterface;
 // Local Runtime Types
 use parachain_template_runtime::{
     apis::RuntimeApi,
@@ -40,10 +40,14 @@ use sp_core::{H256, U256};
 use sp_keystore::KeystorePtr;
 use substrate_prometheus_endpoint::Registry;
 
+use crate::deployment_filter::DeploymentRejections;
 use crate::eth::{
     db_config_dir, new_frontier_partial, spawn_frontier_tasks, BackendType, EthConfiguration,
     FrontierBackend, FrontierPartialComponents,
 };
+use crate::graphql::GraphqlConfiguration;
+use crate::legacy_rpc::LegacyRpcConfiguration;
+use crate::rest_gateway::RestGatewayConfiguration;
 
 #[docify::export(wasm_executor)]
 type ParachainExecutor = WasmExecutor<ParachainHostFunctions>;
@@ -228,12 +232,37 @@ fn start_consensus(
     relay_chain_interface: Arc<dyn RelayChainInterface>,
     transaction_pool: Arc<sc_transaction_pool::FullPool<Block, ParachainClient>>,
     keystore: KeystorePtr,
-    relay_chain_slot_duration: Duration,
     para_id: ParaId,
     collator_key: CollatorPair,
-    overseer_handle: OverseerHandle,
     announce_block: Arc<dyn Fn(Hash, Option<Vec<u8>>) + Send + Sync>,
+    deployment_rejections: DeploymentRejections,
+    authorization_prewarm: bool,
+    authoring_duration: Duration,
+    slot_drift: Duration,
 ) -> Result<(), sc_service::Error> {
+    // Keep doomed contract creations out of the proposer's way
+    task_manager.spawn_handle().spawn(
+        "deployment-filter",
+        Some("parachain-block-authoring"),
+        crate::deployment_filter::prune_denied_deployments(
+            client.clone(),
+            transaction_pool.clone(),
+            deployment_rejections,
+        ),
+    );
+
+    // Have the keys the proposer reads for deployments cached before it runs
+    if authorization_prewarm {
+        task_manager.spawn_handle().spawn(
+            "authorization-prewarm",
+            Some("parachain-block-authoring"),
+            crate::authorization_prewarm::prewarm_authorization_keys::<_, ParachainBackend, _>(
+                client.clone(),
+                transaction_pool.clone(),
+            ),
+        );
+    }
+
     let proposer_factory = sc_basic_authorship::ProposerFactory::with_proof_recording(
         task_manager.spawn_handle(),
         client.clone(),
@@ -251,6 +280,10 @@ fn start_consensus(
         client.clone(),
     );
 
+    // The slot-based collator authors on the parachain's own slots rather than on relay chain
+    // block imports, building as many blocks per relay chain block as the runtime's block
+    // processing velocity allows, and as the elastic scaling target core count it reads
+    // through `AuraUnincludedSegmentApi` lets it
     let params = AuraParams {
         create_inherent_data_providers: move |_, ()| async move { Ok(()) },
         block_import,
@@ -266,20 +299,27 @@ fn start_consensus(
         keystore,
         collator_key,
         para_id,
-        overseer_handle,
-        relay_chain_slot_duration,
         proposer,
         collator_service,
-        authoring_duration: Duration::from_millis(2000),
+        authoring_duration,
         reinitialize: false,
+        slot_drift,
     };
 
-    let fut = aura::run::<Block, sp_consensus_aura::sr25519::AuthorityPair, _, _, _, _, _, _, _, _>(
-        params,
+    let (collation_future, block_builder_future) =
+        aura::run::<Block, sp_consensus_aura::sr25519::AuthorityPair, _, _, _, _, _, _, _, _>(
+            params,
+        );
+    task_manager.spawn_essential_handle().spawn(
+        "collation-task",
+        Some("parachain-block-authoring"),
+        collation_future,
+    );
+    task_manager.spawn_essential_handle().spawn(
+        "block-builder-task",
+        Some("parachain-block-authoring"),
+        block_builder_future,
     );
-    task_manager
-        .spawn_essential_handle()
-        .spawn("aura", None, fut);
 
     Ok(())
 }
@@ -291,8 +331,14 @@ pub async fn start_parachain_node(
     polkadot_config: Configuration,
     collator_options: CollatorOptions,
     eth_config: &EthConfiguration,
+    rest_gateway_config: &RestGatewayConfiguration,
+    graphql_config: &GraphqlConfiguration,
+    legacy_rpc_config: &LegacyRpcConfiguration,
     para_id: ParaId,
     hwbench: Option<sc_sysinfo::HwBench>,
+    authorization_prewarm: bool,
+    authoring_duration: Duration,
+    slot_drift: Duration,
 ) -> sc_service::error::Result<(TaskManager, Arc<ParachainClient>)> {
     let parachain_config = prepare_node_config(parachain_config);
 
@@ -303,6 +349,7 @@ pub async fn start_parachain_node(
         fee_history_cache,
         fee_history_cache_limit,
     } = new_frontier_partial(eth_config)?;
+    let mapping_sync_start = eth_config.mapping_sync_start(&parachain_config.network.sync_mode);
 
     let (block_import, mut telemetry, telemetry_worker_handle, frontier_backend, overrides) =
         params.other;
@@ -376,6 +423,17 @@ pub async fn start_parachain_node(
     > = Default::default();
     let pubsub_notification_sinks = Arc::new(pubsub_notification_sinks);
 
+    // Filled by the deployment filter of collators, served over RPC
+    let deployment_rejections = DeploymentRejections::default();
+
+    // Validates Ethereum RPC submissions on more threads than the pool's own two
+    let rpc_pool = Arc::new(crate::eth_validation::ParallelValidation::new(
+        client.clone(),
+        transaction_pool.clone(),
+        eth_config.validation_threads(),
+        &task_manager.spawn_handle(),
+    ));
+
     let rpc_builder = {
         let client = client.clone();
         let transaction_pool = transaction_pool.clone();
@@ -408,11 +466,17 @@ pub async fn start_parachain_node(
         let overrides = overrides.clone();
         let fee_history_cache = fee_history_cache.clone();
         let pubsub_notification_sinks = pubsub_notification_sinks.clone();
+        let deployment_rejections = deployment_rejections.clone();
+        let offchain_storage = parachain_config
+            .offchain_worker
+            .indexing_enabled
+            .then(|| backend.offchain_storage())
+            .flatten();
 
         Box::new(move |deny_unsafe, subscription_task_executor| {
             let eth = crate::rpc::EthDeps {
                 client: client.clone(),
-                pool: transaction_pool.clone(),
+                pool: rpc_pool.clone(),
                 graph: transaction_pool.pool().clone(),
                 converter: Some(TransactionConverter),
                 is_authority: validator,
@@ -435,9 +499,11 @@ pub async fn start_parachain_node(
             };
             let deps = crate::rpc::FullDeps {
                 client: client.clone(),
-                pool: transaction_pool.clone(),
+                pool: rpc_pool.clone(),
                 deny_unsafe,
                 eth,
+                deployment_rejections: deployment_rejections.clone(),
+                offchain_storage: offchain_storage.clone(),
             };
 
             crate::rpc::create_full(
@@ -464,6 +530,30 @@ pub async fn start_parachain_node(
         telemetry: telemetry.as_mut(),
     })?;
 
+    if let Some(address) = rest_gateway_config.listen_address() {
+        task_manager.spawn_handle().spawn(
+            "rest-gateway",
+            None,
+            crate::rest_gateway::run(client.clone(), address),
+        );
+    }
+
+    if let Some(address) = graphql_config.listen_address() {
+        task_manager.spawn_handle().spawn(
+            "graphql",
+            None,
+            crate::graphql::run::<_, ParachainBackend>(client.clone(), address),
+        );
+    }
+
+    if let Some(address) = legacy_rpc_config.listen_address() {
+        task_manager.spawn_handle().spawn(
+            "legacy-rpc",
+            None,
+            crate::legacy_rpc::run(client.clone(), address, legacy_rpc_config.clone()),
+        );
+    }
+
     if let Some(hwbench) = hwbench {
         sc_sysinfo::print_hwbench(&hwbench);
         // Here you can check whether the hardware meets your chains' requirements.
@@ -528,6 +618,7 @@ pub async fn start_parachain_node(
         overrides,
         fee_history_cache,
         fee_history_cache_limit,
+        mapping_sync_start,
         sync_service.clone(),
         pubsub_notification_sinks,
     )
@@ -544,11 +635,13 @@ pub async fn start_parachain_node(
             relay_chain_interface.clone(),
             transaction_pool,
             params.keystore_container.keystore(),
-            relay_chain_slot_duration,
             para_id,
             collator_key.expect("Command line arguments do not allow this. qed"),
-            overseer_handle,
             announce_block,
+            deployment_rejections,
+            authorization_prewarm,
+            authoring_duration,
+            slot_drift,
         )?;
     }
 
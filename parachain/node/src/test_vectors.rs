@@ -0,0 +1,369 @@
+//! Test vectors of the deployment control rules
+//!
+//! `generate-test-vectors` signs contract creation transactions with fixed development
+//! keys and records, for a set of deployment policies, whether the runtime admits each
+//! creation and the error it refuses it with otherwise. External auditors and SDK
+//! developers check their transaction signing, sender recovery and policy evaluation
+//! against the vectors.
+//!
+//! Outcomes are computed by the native runtime on a fresh genesis state, so the vectors
+//! only change with the keys, the scenarios below or the deployment control rules.
+
+use std::{fs, io::Write, path::PathBuf};
+
+use ethereum::{
+    EIP1559Transaction, EIP1559TransactionMessage, EnvelopedEncodable, LegacyTransaction,
+    LegacyTransactionMessage, TransactionAction, TransactionSignature, TransactionV2,
+};
+use pallet_evm_deployment_control::{
+    ContractDeployer, DeployerAddress, DeploymentPolicy, EvmDeploymentPolicy,
+};
+use parachain_template_runtime::{
+    AccountId, Balance, BuildStorage, EvmDeploymentControl, Runtime, RuntimeGenesisConfig, System,
+    UNIT,
+};
+use serde::Serialize;
+use sp_core::{ecdsa, hashing::keccak_256, Bytes, Pair, H160, H256, U256};
+use sp_runtime::Percent;
+
+/// Init code of the signed creations, returning an empty contract
+const INIT_CODE: [u8; 5] = [0x60, 0x00, 0x60, 0x00, 0xf3];
+
+/// Gas limit of the signed creations
+const GAS_LIMIT: u64 = 100_000;
+
+/// Gas price, and maximum fee per gas, of the signed creations
+const GAS_PRICE: u64 = 1_000_000_000;
+
+/// Balance of the funded signers
+const FUNDED_BALANCE: Balance = 10 * UNIT;
+
+/// Fee charged by the `permissionlessWithFee` policy
+const DEPLOYMENT_FEE: Balance = UNIT;
+
+/// Test vectors as written by `generate-test-vectors`
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TestVectors {
+    /// EVM chain id the transactions are signed for
+    pub chain_id: u64,
+    /// Parent hash of the state the outcomes are computed in, drawing rollout admissions
+    pub parent_hash: H256,
+    /// Signers of the transactions
+    pub signers: Vec<Signer>,
+    /// Signed contract creations
+    pub transactions: Vec<Transaction>,
+    /// Outcomes of the transactions under each policy
+    pub policies: Vec<PolicyOutcomes>,
+}
+
+/// A signer of the transactions
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Signer {
+    pub name: &'static str,
+    /// Development secret URI of the ECDSA key
+    pub secret_uri: String,
+    /// EVM address recovered from the signatures
+    pub address: H160,
+    /// Account of the address under the runtime's address mapping
+    pub account: AccountId,
+    pub authorized: bool,
+    pub balance: Balance,
+}
+
+/// A signed contract creation
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Transaction {
+    pub name: String,
+    pub signer: &'static str,
+    /// `legacy` (EIP-155) or `eip1559`
+    pub kind: &'static str,
+    pub hash: H256,
+    /// Transaction as submitted through `eth_sendRawTransaction`
+    pub raw: Bytes,
+}
+
+/// Outcomes of the transactions under a policy
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PolicyOutcomes {
+    pub policy: Policy,
+    pub outcomes: Vec<Outcome>,
+}
+
+/// A deployment policy of the vectors
+#[derive(Debug, Clone, Copy, Serialize)]
+#[serde(tag = "kind", rename_all = "camelCase")]
+pub enum Policy {
+    Permissioned,
+    Permissionless,
+    #[serde(rename_all = "camelCase")]
+    PermissionlessWithFee {
+        fee: Balance,
+    },
+    #[serde(rename_all = "camelCase")]
+    Rollout {
+        admitted_percent: u8,
+    },
+}
+
+impl Policy {
+    fn deployment_policy(self) -> DeploymentPolicy<Balance> {
+        match self {
+            Policy::Permissioned => DeploymentPolicy::Permissioned,
+            Policy::Permissionless => DeploymentPolicy::Permissionless,
+            Policy::PermissionlessWithFee { fee } => {
+                DeploymentPolicy::PermissionlessWithFee { fee }
+            }
+            Policy::Rollout { admitted_percent } => DeploymentPolicy::Rollout {
+                admitted: Percent::from_percent(admitted_percent),
+            },
+        }
+    }
+}
+
+/// Outcome of a transaction under a policy
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Outcome {
+    pub transaction: String,
+    pub admitted: bool,
+    /// `InvalidTransaction::Custom` code the transaction is refused with
+    pub code: Option<u8>,
+    pub reason: Option<&'static str>,
+}
+
+/// The `generate-test-vectors` command
+#[derive(Debug, Clone, clap::Parser)]
+pub struct GenerateTestVectorsCmd {
+    /// EVM chain id to sign the transactions for
+    #[arg(long, default_value_t = 420)]
+    pub chain_id: u64,
+
+    /// File to write the JSON vectors to, standard output if omitted
+    #[arg(long, short)]
+    pub output: Option<PathBuf>,
+}
+
+impl GenerateTestVectorsCmd {
+    /// Run the command
+    pub fn run(&self) -> sc_cli::Result<()> {
+        let vectors = generate(self.chain_id)?;
+        let json = serde_json::to_vec_pretty(&vectors)
+            .map_err(|err| format!("Failed to serialize the vectors: {err}"))?;
+
+        match &self.output {
+            Some(path) => fs::write(path, json)?,
+            None => std::io::stdout().write_all(&json)?,
+        }
+
+        Ok(())
+    }
+}
+
+/// Generate the vectors for `chain_id`
+pub fn generate(chain_id: u64) -> sc_cli::Result<TestVectors> {
+    // (name, authorized, balance)
+    let scenarios = [
+        ("authorized", true, FUNDED_BALANCE),
+        ("funded", false, FUNDED_BALANCE),
+        ("unfunded", false, 0),
+    ];
+    let policies = [
+        Policy::Permissioned,
+        Policy::Permissionless,
+        Policy::PermissionlessWithFee {
+            fee: DEPLOYMENT_FEE,
+        },
+        Policy::Rollout {
+            admitted_percent: 50,
+        },
+    ];
+
+    let mut signers = Vec::new();
+    let mut transactions = Vec::new();
+    for (name, authorized, balance) in scenarios {
+        let secret_uri = format!("//Deployment//{name}");
+        let pair = ecdsa::Pair::from_string(&secret_uri, None)
+            .map_err(|err| format!("Invalid secret URI {secret_uri}: {err:?}"))?;
+
+        let legacy = legacy_creation(&pair, chain_id)?;
+        let eip1559 = eip1559_creation(&pair, chain_id)?;
+        let address = sender(&legacy)?;
+        for (kind, transaction) in [("legacy", legacy), ("eip1559", eip1559)] {
+            transactions.push(Transaction {
+                name: format!("{name}-{kind}"),
+                signer: name,
+                kind,
+                hash: transaction.hash(),
+                raw: transaction.encode().to_vec().into(),
+            });
+        }
+
+        signers.push(Signer {
+            name,
+            secret_uri,
+            address,
+            account: account_of(address),
+            authorized,
+            balance,
+        });
+    }
+
+    let mut genesis = RuntimeGenesisConfig::default();
+    genesis.balances.balances = signers
+        .iter()
+        .filter(|signer| signer.balance > 0)
+        .map(|signer| (signer.account.clone(), signer.balance))
+        .collect();
+    genesis.evm_deployment_control.authorized_deployers = signers
+        .iter()
+        .filter(|signer| signer.authorized)
+        .map(|signer| DeployerAddress::Evm(signer.address))
+        .collect();
+    let storage = genesis.build_storage()?;
+
+    let mut parent_hash = H256::zero();
+    let policies = policies
+        .into_iter()
+        .map(|policy| {
+            sp_io::TestExternalities::new(storage.clone()).execute_with(|| {
+                System::set_block_number(1);
+                EvmDeploymentPolicy::<Runtime>::put(policy.deployment_policy());
+                parent_hash = System::parent_hash();
+
+                let outcomes = transactions
+                    .iter()
+                    .map(|transaction| {
+                        let signer = signers
+                            .iter()
+                            .find(|signer| signer.name == transaction.signer)
+                            .expect("transactions are signed by known signers; qed");
+                        let refusal =
+                            EvmDeploymentControl::ensure_can_deploy(&signer.account).err();
+                        Outcome {
+                            transaction: transaction.name.clone(),
+                            admitted: refusal.is_none(),
+                            code: refusal.map(Into::into),
+                            reason: refusal.map(|err| err.description()),
+                        }
+                    })
+                    .collect();
+
+                PolicyOutcomes { policy, outcomes }
+            })
+        })
+        .collect();
+
+    Ok(TestVectors {
+        chain_id,
+        parent_hash,
+        signers,
+        transactions,
+        policies,
+    })
+}
+
+/// Account of `address` under the runtime's address mapping
+fn account_of(address: H160) -> AccountId {
+    <<Runtime as pallet_evm_deployment_control::Config>::ContractDeployer as ContractDeployer<
+        AccountId,
+    >>::into_account_id(address)
+}
+
+fn legacy_creation(pair: &ecdsa::Pair, chain_id: u64) -> sc_cli::Result<TransactionV2> {
+    let message = LegacyTransactionMessage {
+        nonce: U256::zero(),
+        gas_price: GAS_PRICE.into(),
+        gas_limit: GAS_LIMIT.into(),
+        action: TransactionAction::Create,
+        value: U256::zero(),
+        input: INIT_CODE.to_vec(),
+        chain_id: Some(chain_id),
+    };
+    let (recovery_id, r, s) = sign(pair, message.hash());
+    let signature = TransactionSignature::new(u64::from(recovery_id) + chain_id * 2 + 35, r, s)
+        .ok_or("Invalid legacy transaction signature")?;
+
+    Ok(TransactionV2::Legacy(LegacyTransaction {
+        nonce: message.nonce,
+        gas_price: message.gas_price,
+        gas_limit: message.gas_limit,
+        action: message.action,
+        value: message.value,
+        input: message.input,
+        signature,
+    }))
+}
+
+fn eip1559_creation(pair: &ecdsa::Pair, chain_id: u64) -> sc_cli::Result<TransactionV2> {
+    let message = EIP1559TransactionMessage {
+        chain_id,
+        nonce: U256::zero(),
+        max_priority_fee_per_gas: U256::zero(),
+        max_fee_per_gas: GAS_PRICE.into(),
+        gas_limit: GAS_LIMIT.into(),
+        action: TransactionAction::Create,
+        value: U256::zero(),
+        input: INIT_CODE.to_vec(),
+        access_list: Vec::new(),
+    };
+    let (recovery_id, r, s) = sign(pair, message.hash());
+
+    Ok(TransactionV2::EIP1559(EIP1559Transaction {
+        chain_id: message.chain_id,
+        nonce: message.nonce,
+        max_priority_fee_per_gas: message.max_priority_fee_per_gas,
+        max_fee_per_gas: message.max_fee_per_gas,
+        gas_limit: message.gas_limit,
+        action: message.action,
+        value: message.value,
+        input: message.input,
+        access_list: message.access_list,
+        odd_y_parity: recovery_id == 1,
+        r,
+        s,
+    }))
+}
+
+/// Recovery id, `r` and `s` of the signature of `hash` by `pair`
+fn sign(pair: &ecdsa::Pair, hash: H256) -> (u8, H256, H256) {
+    let signature = pair.sign_prehashed(&hash.0);
+    let bytes: &[u8] = signature.as_ref();
+
+    (
+        bytes[64],
+        H256::from_slice(&bytes[..32]),
+        H256::from_slice(&bytes[32..64]),
+    )
+}
+
+/// Sender recovered from the signature of `transaction`, as the runtime recovers it
+fn sender(transaction: &TransactionV2) -> sc_cli::Result<H160> {
+    let (message_hash, recovery_id, r, s) = match transaction {
+        TransactionV2::Legacy(t) => (
+            LegacyTransactionMessage::from(t.clone()).hash(),
+            t.signature.standard_v(),
+            t.signature.r(),
+            t.signature.s(),
+        ),
+        TransactionV2::EIP1559(t) => (
+            EIP1559TransactionMessage::from(t.clone()).hash(),
+            t.odd_y_parity as u8,
+            &t.r,
+            &t.s,
+        ),
+        TransactionV2::EIP2930(_) => return Err("EIP-2930 transactions are not signed".into()),
+    };
+
+    let mut signature = [0u8; 65];
+    signature[..32].copy_from_slice(r.as_bytes());
+    signature[32..64].copy_from_slice(s.as_bytes());
+    signature[64] = recovery_id;
+    let public = sp_io::crypto::secp256k1_ecdsa_recover(&signature, &message_hash.0)
+        .map_err(|err| format!("Failed to recover the sender: {err:?}"))?;
+
+    Ok(H160::from_slice(&keccak_256(&public)[12..]))
+}
@@ -0,0 +1,120 @@
+//! Verification of the on-chain runtime against a deterministic build
+//!
+//! `verify-runtime` compares the code hash of a runtime blob built locally, with srtool or
+//! an equivalent pinned toolchain, against the `:code` stored on chain, so that reviewers
+//! of a runtime upgrade can check that the enacted code is the one they built from source.
+//! The build information of the on-chain runtime is printed along with the hashes, to tell
+//! a toolchain or feature difference from a source difference when they do not match.
+
+use std::{fs, path::PathBuf, sync::Arc};
+
+use build_info_runtime_api::BuildInfoApi;
+use parachain_template_runtime::Block;
+use sc_cli::{BlockNumberOrHash, CliConfiguration, DatabaseParams, PruningParams, SharedParams};
+use sc_client_api::{Backend, StorageProvider};
+use sp_api::{ApiExt, ProvideRuntimeApi};
+use sp_blockchain::HeaderBackend;
+use sp_core::{
+    hashing::blake2_256,
+    storage::{well_known_keys, StorageKey},
+    H256,
+};
+use sp_runtime::generic::BlockId;
+
+/// The `verify-runtime` command
+#[derive(Debug, Clone, clap::Parser)]
+pub struct VerifyRuntimeCmd {
+    /// Runtime blob to verify, as uploaded on chain (`.compact.compressed.wasm`)
+    #[arg(long, value_name = "PATH")]
+    pub wasm: PathBuf,
+
+    /// Block hash or number to verify the runtime at, the best block if omitted
+    #[arg(value_name = "HASH or NUMBER")]
+    pub input: Option<BlockNumberOrHash>,
+
+    #[allow(missing_docs)]
+    #[clap(flatten)]
+    pub shared_params: SharedParams,
+
+    #[allow(missing_docs)]
+    #[clap(flatten)]
+    pub pruning_params: PruningParams,
+
+    #[allow(missing_docs)]
+    #[clap(flatten)]
+    pub database_params: DatabaseParams,
+}
+
+impl VerifyRuntimeCmd {
+    /// Run the command, failing if the code hashes differ
+    pub async fn run<C, BE>(&self, client: Arc<C>) -> sc_cli::Result<()>
+    where
+        C: StorageProvider<Block, BE> + HeaderBackend<Block> + ProvideRuntimeApi<Block>,
+        C::Api: BuildInfoApi<Block>,
+        BE: Backend<Block>,
+    {
+        let hash = match self
+            .input
+            .as_ref()
+            .map(|block| block.parse::<Block>())
+            .transpose()?
+        {
+            Some(BlockId::Hash(hash)) => hash,
+            Some(BlockId::Number(number)) => client
+                .hash(number)?
+                .ok_or_else(|| format!("Block {number} not found"))?,
+            None => client.info().best_hash,
+        };
+
+        let local = H256(blake2_256(&fs::read(&self.wasm)?));
+        let code = client
+            .storage(hash, &StorageKey(well_known_keys::CODE.to_vec()))?
+            .ok_or_else(|| format!("No runtime code at block {hash:?}"))?;
+        let on_chain = H256(blake2_256(&code.0));
+
+        println!("Block:              {hash:?}");
+        println!("Local code hash:    {local:?}");
+        println!("On-chain code hash: {on_chain:?}");
+
+        let api = client.runtime_api();
+        let has_build_info = api
+            .has_api::<dyn BuildInfoApi<Block>>(hash)
+            .map_err(|err| format!("Unable to query the runtime version: {err}"))?;
+        if has_build_info {
+            let info = api
+                .build_info(hash)
+                .map_err(|err| format!("Unable to query the build info: {err}"))?;
+            let features: Vec<_> = info
+                .features
+                .iter()
+                .map(|feature| String::from_utf8_lossy(feature))
+                .collect();
+            println!("Compiler:           {}", String::from_utf8_lossy(&info.rustc_version));
+            println!("Profile:            {}", String::from_utf8_lossy(&info.profile));
+            println!("Features:           {}", features.join(", "));
+        } else {
+            println!("The on-chain runtime does not expose its build info");
+        }
+
+        if local != on_chain {
+            return Err("The runtime blob does not match the on-chain code".into());
+        }
+        println!("The runtime blob matches the on-chain code");
+
+        Ok(())
+    }
+}
+
+impl CliConfiguration for VerifyRuntimeCmd {
+    fn shared_params(&self) -> &SharedParams {
+        &self.shared_params
+    }
+
+    fn pruning_params(&self) -> Option<&PruningParams> {
+        Some(&self.pruning_params)
+    }
+
+    fn database_params(&self) -> Option<&DatabaseParams> {
+        Some(&self.database_params)
+    }
+}
@@ -0,0 +1,58 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+//! # Audit Anchors Runtime API
+//!
+//! Runtime API exposing the audit documents anchored to contracts, so that auditors,
+//! explorers and integrators can check which reviews cover a deployment and which
+//! deployments a review covers.
+
+use parity_scale_codec::{Codec, Decode, Encode};
+use scale_info::TypeInfo;
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
+use sp_core::{H160, H256};
+use sp_runtime::RuntimeDebug;
+use sp_std::vec::Vec;
+
+/// Kind of an anchored document
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub enum DocumentKind {
+    /// Audit report
+    Audit,
+    /// Security review
+    SecurityReview,
+    /// Any other document
+    Other,
+}
+
+/// A document anchored to a contract
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct AnchoredDocument<AccountId, BlockNumber> {
+    /// Hash of the document
+    pub document: H256,
+    /// Kind of the document
+    pub kind: DocumentKind,
+    /// Account that anchored the document
+    pub anchored_by: AccountId,
+    /// Block in which the document was anchored
+    pub anchored_at: BlockNumber,
+}
+
+sp_api::decl_runtime_apis! {
+    /// Runtime API for the audit anchors pallet
+    pub trait AuditAnchorsApi<AccountId, BlockNumber>
+    where
+        AccountId: Codec,
+        BlockNumber: Codec,
+    {
+        /// Documents anchored to `contract`, oldest first
+        fn anchors(contract: H160) -> Vec<AnchoredDocument<AccountId, BlockNumber>>;
+
+        /// Contracts `document` is anchored to
+        fn contracts_of(document: H256) -> Vec<H160>;
+    }
+}
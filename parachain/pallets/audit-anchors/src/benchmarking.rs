@@ -0,0 +1,46 @@
+//! Benchmarking setup for pallet-audit-anchors
+
+use super::*;
+
+#[allow(unused)]
+use crate::Pallet as AuditAnchors;
+use frame_benchmarking::v2::*;
+use frame_support::traits::Get;
+use frame_system::RawOrigin;
+use sp_core::H256;
+use sp_runtime::traits::Convert;
+
+#[benchmarks]
+mod benchmarks {
+	use super::*;
+
+	#[benchmark]
+	fn anchor() {
+		let contract = T::BenchmarkHelper::record_deployment();
+		let deployer = T::Provenance::deployer_of(&contract).unwrap();
+		let caller = T::AddressMapping::convert(deployer);
+		// Every other anchor is taken
+		let anchored_at = frame_system::Pallet::<T>::block_number();
+		for i in 1..T::MaxAnchorsPerContract::get() {
+			let document = H256::from_low_u64_be(i.into());
+			let anchor = Anchor {
+				document,
+				kind: DocumentKind::Audit,
+				anchored_by: caller.clone(),
+				anchored_at,
+			};
+			Anchors::<T>::try_append(contract, anchor).unwrap();
+			DocumentContracts::<T>::insert(document, contract, ());
+		}
+		let document = H256::repeat_byte(0xdd);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), contract, document, DocumentKind::Audit);
+
+		// Verify the document was anchored
+		assert!(DocumentContracts::<T>::contains_key(document, contract));
+		assert_eq!(Anchors::<T>::get(contract).len() as u32, T::MaxAnchorsPerContract::get());
+	}
+
+	impl_benchmark_test_suite!(AuditAnchors, crate::mock::new_test_ext(), crate::mock::Test);
+}
@@ -0,0 +1,206 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+//! # Audit Anchors Pallet
+//!
+//! Tamper-evident links between deployed EVM contracts and the documents reviewing them,
+//! e.g. audit reports and security reviews.
+//!
+//! ## Overview
+//!
+//! The recorded deployer of a contract, as reported by the configured
+//! [`ContractProvenance`] source, anchors the hash of a document to the contract with
+//! [`Pallet::anchor`]. Anchors cannot be changed or removed, so anyone holding the
+//! document can check that it was anchored, by whom and when. Up to
+//! `MaxAnchorsPerContract` documents are anchored to a contract, and a document can be
+//! anchored to several contracts.
+//!
+//! The anchors of a contract and the contracts of a document are exposed through a
+//! runtime API.
+
+pub use deployment_control_primitives::ContractProvenance;
+pub use pallet::*;
+pub mod weights;
+
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+/// Records the deployment of a contract for benchmarking
+#[cfg(feature = "runtime-benchmarks")]
+pub trait BenchmarkHelper {
+	/// Record the deployment of a contract and return its address
+	fn record_deployment() -> sp_core::H160;
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+	use crate::ContractProvenance;
+	use frame_support::pallet_prelude::*;
+	use frame_system::pallet_prelude::*;
+	use sp_core::{H160, H256};
+	use sp_runtime::traits::Convert;
+	use sp_std::vec::Vec;
+
+	pub use crate::weights::WeightInfo;
+
+	/// Documents anchored to a contract
+	pub type AnchorsOf<T> = BoundedVec<
+		Anchor<<T as frame_system::Config>::AccountId, BlockNumberFor<T>>,
+		<T as Config>::MaxAnchorsPerContract,
+	>;
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		/// The overarching event type
+		#[allow(deprecated)]
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		/// Source of the recorded deployer of contracts
+		type Provenance: ContractProvenance;
+
+		/// Account controlled by an EVM address
+		type AddressMapping: Convert<H160, Self::AccountId>;
+
+		/// Maximum number of documents anchored to a contract
+		#[pallet::constant]
+		type MaxAnchorsPerContract: Get<u32>;
+
+		/// Weight information for extrinsics in this pallet
+		type WeightInfo: WeightInfo;
+
+		/// Records contract deployments for benchmarking
+		#[cfg(feature = "runtime-benchmarks")]
+		type BenchmarkHelper: crate::BenchmarkHelper;
+	}
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	/// Kind of an anchored document
+	#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub enum DocumentKind {
+		/// Audit report
+		Audit,
+		/// Security review
+		SecurityReview,
+		/// Any other document
+		Other,
+	}
+
+	/// A document anchored to a contract
+	#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub struct Anchor<AccountId, BlockNumber> {
+		/// Hash of the document
+		pub document: H256,
+		/// Kind of the document
+		pub kind: DocumentKind,
+		/// Account that anchored the document
+		pub anchored_by: AccountId,
+		/// Block in which the document was anchored
+		pub anchored_at: BlockNumber,
+	}
+
+	/// Documents anchored to a contract, oldest first
+	#[pallet::storage]
+	pub type Anchors<T: Config> = StorageMap<_, Blake2_128Concat, H160, AnchorsOf<T>, ValueQuery>;
+
+	/// Contracts a document is anchored to
+	#[pallet::storage]
+	pub type DocumentContracts<T: Config> =
+		StorageDoubleMap<_, Blake2_128Concat, H256, Blake2_128Concat, H160, (), OptionQuery>;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// A document was anchored to a contract
+		DocumentAnchored {
+			/// The contract
+			contract: H160,
+			/// Hash of the document
+			document: H256,
+			/// Kind of the document
+			kind: DocumentKind,
+			/// The account that anchored the document
+			anchored_by: T::AccountId,
+		},
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The caller is not the recorded deployer of the contract
+		NotDeployer,
+		/// The document is already anchored to the contract
+		AlreadyAnchored,
+		/// `MaxAnchorsPerContract` documents are anchored to the contract
+		TooManyAnchors,
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Anchor the hash of a document to a contract deployed by the caller
+		///
+		/// # Parameters
+		/// - `origin`: Must be signed by the account of the contract's recorded deployer
+		/// - `contract`: The contract the document reviews
+		/// - `document`: Hash of the document
+		/// - `kind`: Kind of the document
+		///
+		/// # Errors
+		/// - `NotDeployer`: The caller did not deploy the contract
+		/// - `AlreadyAnchored`: The document is already anchored to the contract
+		/// - `TooManyAnchors`: `MaxAnchorsPerContract` documents are anchored to the contract
+		///
+		/// # Events
+		/// - `DocumentAnchored`: Emitted when the document is anchored
+		#[pallet::call_index(0)]
+		#[pallet::weight(T::WeightInfo::anchor())]
+		pub fn anchor(
+			origin: OriginFor<T>,
+			contract: H160,
+			document: H256,
+			kind: DocumentKind,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let deployer = T::Provenance::deployer_of(&contract).ok_or(Error::<T>::NotDeployer)?;
+			ensure!(T::AddressMapping::convert(deployer) == who, Error::<T>::NotDeployer);
+			ensure!(
+				!DocumentContracts::<T>::contains_key(document, contract),
+				Error::<T>::AlreadyAnchored
+			);
+
+			let anchored_at = frame_system::Pallet::<T>::block_number();
+			Anchors::<T>::try_mutate(contract, |anchors| {
+				anchors
+					.try_push(Anchor { document, kind, anchored_by: who.clone(), anchored_at })
+					.map_err(|_| Error::<T>::TooManyAnchors)
+			})?;
+			DocumentContracts::<T>::insert(document, contract, ());
+			Self::deposit_event(Event::DocumentAnchored {
+				contract,
+				document,
+				kind,
+				anchored_by: who,
+			});
+
+			Ok(())
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// Documents anchored to `contract`, oldest first
+		pub fn anchors(contract: &H160) -> Vec<Anchor<T::AccountId, BlockNumberFor<T>>> {
+			Anchors::<T>::get(contract).into_inner()
+		}
+
+		/// Contracts `document` is anchored to
+		pub fn contracts_of(document: &H256) -> Vec<H160> {
+			DocumentContracts::<T>::iter_key_prefix(document).collect()
+		}
+	}
+}
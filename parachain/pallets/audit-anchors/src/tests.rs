@@ -0,0 +1,156 @@
+use crate::{mock::*, Anchor, Anchors, DocumentContracts, DocumentKind, Error, Event};
+use frame_support::{assert_noop, assert_ok};
+use sp_core::{H160, H256};
+
+const VAULT: H160 = H160::repeat_byte(0x22);
+const ROUTER: H160 = H160::repeat_byte(0x33);
+
+fn document(byte: u8) -> H256 {
+	H256::repeat_byte(byte)
+}
+
+#[test]
+fn deployer_anchors_documents() {
+	new_test_ext().execute_with(|| {
+		deploy(H160::from_low_u64_be(1), VAULT);
+
+		assert_ok!(AuditAnchors::anchor(
+			RuntimeOrigin::signed(1),
+			VAULT,
+			document(0xaa),
+			DocumentKind::Audit
+		));
+		System::assert_last_event(
+			Event::DocumentAnchored {
+				contract: VAULT,
+				document: document(0xaa),
+				kind: DocumentKind::Audit,
+				anchored_by: 1,
+			}
+			.into(),
+		);
+
+		System::set_block_number(2);
+		assert_ok!(AuditAnchors::anchor(
+			RuntimeOrigin::signed(1),
+			VAULT,
+			document(0xbb),
+			DocumentKind::SecurityReview
+		));
+
+		assert_eq!(
+			AuditAnchors::anchors(&VAULT),
+			vec![
+				Anchor {
+					document: document(0xaa),
+					kind: DocumentKind::Audit,
+					anchored_by: 1,
+					anchored_at: 1,
+				},
+				Anchor {
+					document: document(0xbb),
+					kind: DocumentKind::SecurityReview,
+					anchored_by: 1,
+					anchored_at: 2,
+				},
+			]
+		);
+		assert_eq!(AuditAnchors::contracts_of(&document(0xaa)), vec![VAULT]);
+		assert!(AuditAnchors::anchors(&ROUTER).is_empty());
+	});
+}
+
+#[test]
+fn a_document_is_anchored_to_several_contracts() {
+	new_test_ext().execute_with(|| {
+		deploy(H160::from_low_u64_be(1), VAULT);
+		deploy(H160::from_low_u64_be(2), ROUTER);
+
+		assert_ok!(AuditAnchors::anchor(
+			RuntimeOrigin::signed(1),
+			VAULT,
+			document(0xaa),
+			DocumentKind::Audit
+		));
+		assert_ok!(AuditAnchors::anchor(
+			RuntimeOrigin::signed(2),
+			ROUTER,
+			document(0xaa),
+			DocumentKind::Audit
+		));
+
+		let mut contracts = AuditAnchors::contracts_of(&document(0xaa));
+		contracts.sort();
+		assert_eq!(contracts, vec![VAULT, ROUTER]);
+		assert_eq!(AuditAnchors::anchors(&ROUTER)[0].anchored_by, 2);
+	});
+}
+
+#[test]
+fn only_the_deployer_anchors_documents() {
+	new_test_ext().execute_with(|| {
+		deploy(H160::from_low_u64_be(1), VAULT);
+
+		assert_noop!(
+			AuditAnchors::anchor(
+				RuntimeOrigin::signed(2),
+				VAULT,
+				document(0xaa),
+				DocumentKind::Audit
+			),
+			Error::<Test>::NotDeployer
+		);
+		assert_noop!(
+			AuditAnchors::anchor(
+				RuntimeOrigin::signed(1),
+				ROUTER,
+				document(0xaa),
+				DocumentKind::Audit
+			),
+			Error::<Test>::NotDeployer
+		);
+	});
+}
+
+#[test]
+fn anchors_are_unique_and_bounded() {
+	new_test_ext().execute_with(|| {
+		deploy(H160::from_low_u64_be(1), VAULT);
+		assert_ok!(AuditAnchors::anchor(
+			RuntimeOrigin::signed(1),
+			VAULT,
+			document(0xaa),
+			DocumentKind::Audit
+		));
+
+		// The same document again, even as another kind
+		assert_noop!(
+			AuditAnchors::anchor(
+				RuntimeOrigin::signed(1),
+				VAULT,
+				document(0xaa),
+				DocumentKind::Other
+			),
+			Error::<Test>::AlreadyAnchored
+		);
+
+		assert_ok!(AuditAnchors::anchor(
+			RuntimeOrigin::signed(1),
+			VAULT,
+			document(0xbb),
+			DocumentKind::Other
+		));
+		assert_noop!(
+			AuditAnchors::anchor(
+				RuntimeOrigin::signed(1),
+				VAULT,
+				document(0xcc),
+				DocumentKind::Audit
+			),
+			Error::<Test>::TooManyAnchors
+		);
+
+		assert_eq!(Anchors::<Test>::get(VAULT).len(), 2);
+		assert!(!DocumentContracts::<Test>::contains_key(document(0xcc), VAULT));
+	});
+}
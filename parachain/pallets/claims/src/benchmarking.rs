@@ -0,0 +1,69 @@
+//! Benchmarking setup for pallet-claims
+
+use super::*;
+
+use crate::secp_utils::{eth, sig};
+#[allow(unused)]
+use crate::Pallet as Claims;
+use frame_benchmarking::v2::*;
+use frame_support::traits::{fungible::Inspect, EnsureOrigin, Get};
+use frame_system::RawOrigin;
+use sp_core::H256;
+use sp_std::vec::Vec;
+
+#[benchmarks]
+mod benchmarks {
+	use super::*;
+
+	#[benchmark]
+	fn set_claim_set() {
+		let origin = T::ClaimsOrigin::try_successful_origin().unwrap();
+		let root = H256::repeat_byte(1);
+		let total = T::Currency::minimum_balance() * 1_000u32.into();
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, 0, root, total);
+
+		// Verify the claim set was stored
+		assert_eq!(ClaimSets::<T>::get(0), Some(ClaimSet { root, remaining: total }));
+	}
+
+	#[benchmark]
+	fn remove_claim_set() {
+		let origin = T::ClaimsOrigin::try_successful_origin().unwrap();
+		let total = T::Currency::minimum_balance() * 1_000u32.into();
+		ClaimSets::<T>::insert(0, ClaimSet { root: H256::repeat_byte(1), remaining: total });
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, 0);
+
+		// Verify the claim set was removed
+		assert!(ClaimSets::<T>::get(0).is_none());
+	}
+
+	#[benchmark]
+	fn claim(p: Linear<0, { T::MaxProofLength::get() }>) {
+		let secret =
+			libsecp256k1::SecretKey::parse(&sp_io::hashing::keccak_256(b"claimer")).unwrap();
+		let address = eth(&secret);
+		let dest: T::AccountId = account("dest", 0, 0);
+		let amount = T::Currency::minimum_balance() * 100u32.into();
+
+		// A proof of `p` siblings up to the root of the claim set
+		let proof: Vec<H256> = (0..p).map(|i| H256::repeat_byte(i as u8)).collect();
+		let root = proof.iter().fold(Claims::<T>::leaf(&address, amount), |node, sibling| {
+			Claims::<T>::node(&node, sibling)
+		});
+		ClaimSets::<T>::insert(0, ClaimSet { root, remaining: amount });
+		let signature = sig::<T>(&secret, &dest);
+
+		#[extrinsic_call]
+		_(RawOrigin::None, 0, dest.clone(), amount, proof.try_into().unwrap(), signature);
+
+		// Verify the amount was minted
+		assert_eq!(T::Currency::balance(&dest), amount);
+		assert_eq!(Claimed::<T>::get(0, address), Some(amount));
+	}
+
+	impl_benchmark_test_suite!(Claims, crate::mock::new_test_ext(), crate::mock::Test);
+}
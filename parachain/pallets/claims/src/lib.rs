@@ -0,0 +1,364 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+//! # Claims Pallet
+//!
+//! Native tokens claimed by the Ethereum addresses of a snapshot.
+//!
+//! ## Overview
+//!
+//! `ClaimsOrigin` publishes claim sets with [`Pallet::set_claim_set`]: the merkle root of
+//! the snapshot and the total it may mint. The leaves of the tree are
+//! `keccak256(address ++ amount)`, with the amount as a 32 byte big-endian word, and
+//! parent nodes hash their children in sorted order, as OpenZeppelin's `MerkleProof`
+//! expects. Setting the root of an existing claim set replaces it.
+//!
+//! The owner of an address claims with [`Pallet::claim`], an unsigned transaction so that
+//! the destination account needs no funds. The call carries the merkle proof of the leaf
+//! and an Ethereum `personal_sign` signature of `Prefix` followed by the hex-encoded
+//! destination account, which proves ownership of the address. Each address claims once
+//! per claim set.
+
+pub use pallet::*;
+pub mod weights;
+
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+#[cfg(any(test, feature = "runtime-benchmarks"))]
+pub(crate) mod secp_utils;
+
+#[frame_support::pallet]
+pub mod pallet {
+	use frame_support::{
+		pallet_prelude::*,
+		traits::fungible::{Inspect, Mutate},
+	};
+	use frame_system::pallet_prelude::*;
+	use sp_core::{H160, H256};
+	use sp_io::hashing::keccak_256;
+	use sp_runtime::traits::{SaturatedConversion, Saturating};
+	use sp_std::vec::Vec;
+
+	pub use crate::weights::WeightInfo;
+
+	/// Balance of the claimed currency
+	pub type BalanceOf<T> =
+		<<T as Config>::Currency as Inspect<<T as frame_system::Config>::AccountId>>::Balance;
+
+	/// Identifier of a claim set
+	pub type ClaimSetId = u32;
+
+	/// Ethereum `personal_sign` signature, `r ++ s ++ v`
+	#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub struct EcdsaSignature(pub [u8; 65]);
+
+	/// Snapshot of claimable amounts
+	#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub struct ClaimSet<Balance> {
+		/// Merkle root of the claimable amounts by address
+		pub root: H256,
+		/// Amount still claimable from the set
+		pub remaining: Balance,
+	}
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		/// The overarching event type
+		#[allow(deprecated)]
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		/// Currency minted by claims
+		type Currency: Mutate<Self::AccountId>;
+
+		/// Origin allowed to publish and remove claim sets
+		type ClaimsOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// Text signed before the hex-encoded destination account
+		#[pallet::constant]
+		type Prefix: Get<&'static [u8]>;
+
+		/// Maximum depth of the merkle tree of a claim set
+		#[pallet::constant]
+		type MaxProofLength: Get<u32>;
+
+		/// Priority of claim transactions
+		#[pallet::constant]
+		type UnsignedPriority: Get<TransactionPriority>;
+
+		/// Weight information for extrinsics in this pallet
+		type WeightInfo: WeightInfo;
+	}
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	/// Claim sets published by `ClaimsOrigin`
+	#[pallet::storage]
+	pub type ClaimSets<T: Config> =
+		StorageMap<_, Twox64Concat, ClaimSetId, ClaimSet<BalanceOf<T>>, OptionQuery>;
+
+	/// Amounts claimed by address in each claim set
+	///
+	/// Kept when the claim set is removed, so that a claim set republished under the same
+	/// identifier cannot be claimed twice.
+	#[pallet::storage]
+	pub type Claimed<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		ClaimSetId,
+		Blake2_128Concat,
+		H160,
+		BalanceOf<T>,
+		OptionQuery,
+	>;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// A claim set was published or replaced
+		ClaimSetSet {
+			/// The claim set
+			set_id: ClaimSetId,
+			/// Merkle root of the claimable amounts
+			root: H256,
+			/// Amount claimable from the set
+			total: BalanceOf<T>,
+		},
+		/// A claim set was removed
+		ClaimSetRemoved {
+			/// The claim set
+			set_id: ClaimSetId,
+		},
+		/// An address claimed its amount
+		Claimed {
+			/// The claim set
+			set_id: ClaimSetId,
+			/// The Ethereum address of the snapshot
+			address: H160,
+			/// The account receiving the amount
+			dest: T::AccountId,
+			/// The amount minted
+			amount: BalanceOf<T>,
+		},
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// No claim set has the identifier
+		UnknownClaimSet,
+		/// The signature is not a valid Ethereum signature
+		InvalidSignature,
+		/// The address already claimed from the claim set
+		AlreadyClaimed,
+		/// The merkle proof does not prove the amount of the address
+		InvalidProof,
+		/// The amount is above what remains claimable from the claim set
+		ExceedsClaimSet,
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Publish a claim set, or replace it
+		///
+		/// # Parameters
+		/// - `origin`: Must be `ClaimsOrigin`
+		/// - `set_id`: The claim set
+		/// - `root`: Merkle root of the claimable amounts by address
+		/// - `total`: Amount claimable from the set, replacing what remained
+		///
+		/// # Events
+		/// - `ClaimSetSet`: Emitted when the claim set is published
+		#[pallet::call_index(0)]
+		#[pallet::weight(T::WeightInfo::set_claim_set())]
+		pub fn set_claim_set(
+			origin: OriginFor<T>,
+			set_id: ClaimSetId,
+			root: H256,
+			total: BalanceOf<T>,
+		) -> DispatchResult {
+			T::ClaimsOrigin::ensure_origin(origin)?;
+
+			ClaimSets::<T>::insert(set_id, ClaimSet { root, remaining: total });
+			Self::deposit_event(Event::ClaimSetSet { set_id, root, total });
+
+			Ok(())
+		}
+
+		/// Remove a claim set
+		///
+		/// # Parameters
+		/// - `origin`: Must be `ClaimsOrigin`
+		/// - `set_id`: The claim set
+		///
+		/// # Errors
+		/// - `UnknownClaimSet`: No claim set has the identifier
+		///
+		/// # Events
+		/// - `ClaimSetRemoved`: Emitted when the claim set is removed
+		#[pallet::call_index(1)]
+		#[pallet::weight(T::WeightInfo::remove_claim_set())]
+		pub fn remove_claim_set(origin: OriginFor<T>, set_id: ClaimSetId) -> DispatchResult {
+			T::ClaimsOrigin::ensure_origin(origin)?;
+
+			ClaimSets::<T>::take(set_id).ok_or(Error::<T>::UnknownClaimSet)?;
+			Self::deposit_event(Event::ClaimSetRemoved { set_id });
+
+			Ok(())
+		}
+
+		/// Claim the amount of an Ethereum address of a claim set
+		///
+		/// Unsigned: the signature proves ownership of the address, see [`Pallet::claim_signer`].
+		///
+		/// # Parameters
+		/// - `origin`: Must be none
+		/// - `set_id`: The claim set
+		/// - `dest`: The account receiving the amount
+		/// - `amount`: The amount of the address in the claim set
+		/// - `proof`: Merkle proof of the leaf of the address, from the leaf up
+		/// - `signature`: Signature of `Prefix` and the hex-encoded `dest` by the address
+		///
+		/// # Errors
+		/// - `UnknownClaimSet`: No claim set has the identifier
+		/// - `InvalidSignature`: The signature is not a valid Ethereum signature
+		/// - `AlreadyClaimed`: The address already claimed from the claim set
+		/// - `InvalidProof`: The proof does not prove `amount` for the address
+		/// - `ExceedsClaimSet`: The amount is above what remains claimable
+		///
+		/// # Events
+		/// - `Claimed`: Emitted with the amount minted
+		#[pallet::call_index(2)]
+		#[pallet::weight(T::WeightInfo::claim(proof.len() as u32))]
+		pub fn claim(
+			origin: OriginFor<T>,
+			set_id: ClaimSetId,
+			dest: T::AccountId,
+			amount: BalanceOf<T>,
+			proof: BoundedVec<H256, T::MaxProofLength>,
+			signature: EcdsaSignature,
+		) -> DispatchResult {
+			ensure_none(origin)?;
+
+			let (address, mut set) = Self::check_claim(set_id, &dest, amount, &proof, &signature)?;
+
+			T::Currency::mint_into(&dest, amount)?;
+			set.remaining.saturating_reduce(amount);
+			ClaimSets::<T>::insert(set_id, set);
+			Claimed::<T>::insert(set_id, address, amount);
+			Self::deposit_event(Event::Claimed { set_id, address, dest, amount });
+
+			Ok(())
+		}
+	}
+
+	#[pallet::validate_unsigned]
+	impl<T: Config> ValidateUnsigned for Pallet<T> {
+		type Call = Call<T>;
+
+		fn validate_unsigned(_source: TransactionSource, call: &Self::Call) -> TransactionValidity {
+			let Call::claim { set_id, dest, amount, proof, signature } = call else {
+				return InvalidTransaction::Call.into();
+			};
+
+			let (address, _) = Self::check_claim(*set_id, dest, *amount, proof, signature)
+				.map_err(|err| match err {
+					Error::<T>::InvalidSignature | Error::<T>::InvalidProof =>
+						InvalidTransaction::BadProof,
+					_ => InvalidTransaction::Stale,
+				})?;
+
+			ValidTransaction::with_tag_prefix("Claims")
+				.priority(T::UnsignedPriority::get())
+				.and_provides((set_id, address))
+				.longevity(64)
+				.propagate(true)
+				.build()
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// Leaf of `address` claiming `amount` in the merkle tree of a claim set
+		pub fn leaf(address: &H160, amount: BalanceOf<T>) -> H256 {
+			let mut data = [0u8; 52];
+			data[..20].copy_from_slice(address.as_bytes());
+			data[36..].copy_from_slice(&amount.saturated_into::<u128>().to_be_bytes());
+			keccak_256(&data).into()
+		}
+
+		/// Parent of two nodes of a merkle tree, hashing them in sorted order
+		pub fn node(a: &H256, b: &H256) -> H256 {
+			let (left, right) = if a <= b { (a, b) } else { (b, a) };
+			let mut data = [0u8; 64];
+			data[..32].copy_from_slice(left.as_bytes());
+			data[32..].copy_from_slice(right.as_bytes());
+			keccak_256(&data).into()
+		}
+
+		/// Ethereum address that signed `dest` as the destination of its claims
+		///
+		/// The message is `Prefix` followed by the hex-encoded SCALE encoding of `dest`,
+		/// signed with `personal_sign`.
+		pub fn claim_signer(dest: &T::AccountId, signature: &EcdsaSignature) -> Option<H160> {
+			let message = dest.using_encoded(|dest| Self::signable_message(&to_ascii_hex(dest)));
+			let public =
+				sp_io::crypto::secp256k1_ecdsa_recover(&signature.0, &keccak_256(&message)).ok()?;
+			Some(H160::from_slice(&keccak_256(&public)[12..]))
+		}
+
+		/// Message `personal_sign` produces for `Prefix` followed by `what`
+		pub(crate) fn signable_message(what: &[u8]) -> Vec<u8> {
+			let prefix = T::Prefix::get();
+			let mut length = prefix.len() + what.len();
+			let mut digits = Vec::new();
+			while length > 0 {
+				digits.push(b'0' + (length % 10) as u8);
+				length /= 10;
+			}
+
+			let mut message = b"\x19Ethereum Signed Message:\n".to_vec();
+			message.extend(digits.into_iter().rev());
+			message.extend_from_slice(prefix);
+			message.extend_from_slice(what);
+			message
+		}
+
+		/// Address making a valid claim, and the claim set it claims from
+		fn check_claim(
+			set_id: ClaimSetId,
+			dest: &T::AccountId,
+			amount: BalanceOf<T>,
+			proof: &[H256],
+			signature: &EcdsaSignature,
+		) -> Result<(H160, ClaimSet<BalanceOf<T>>), Error<T>> {
+			let set = ClaimSets::<T>::get(set_id).ok_or(Error::<T>::UnknownClaimSet)?;
+			let address =
+				Self::claim_signer(dest, signature).ok_or(Error::<T>::InvalidSignature)?;
+			ensure!(!Claimed::<T>::contains_key(set_id, address), Error::<T>::AlreadyClaimed);
+
+			let root = proof
+				.iter()
+				.fold(Self::leaf(&address, amount), |node, sibling| Self::node(&node, sibling));
+			ensure!(root == set.root, Error::<T>::InvalidProof);
+			ensure!(amount <= set.remaining, Error::<T>::ExceedsClaimSet);
+
+			Ok((address, set))
+		}
+	}
+
+	/// Lowercase hex encoding of `data`, without `0x`
+	pub(crate) fn to_ascii_hex(data: &[u8]) -> Vec<u8> {
+		let mut hex = Vec::with_capacity(data.len() * 2);
+		for byte in data {
+			for nibble in [byte >> 4, byte & 0xf] {
+				hex.push(if nibble < 10 { b'0' + nibble } else { b'a' + nibble - 10 });
+			}
+		}
+		hex
+	}
+}
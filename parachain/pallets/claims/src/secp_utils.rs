@@ -0,0 +1,28 @@
+//! Ethereum keys and signatures for tests and benchmarks
+
+use crate::{to_ascii_hex, Config, EcdsaSignature, Pallet};
+use parity_scale_codec::Encode;
+use sp_core::H160;
+use sp_io::hashing::keccak_256;
+
+/// Public key of a secret key
+pub fn public(secret: &libsecp256k1::SecretKey) -> libsecp256k1::PublicKey {
+	libsecp256k1::PublicKey::from_secret_key(secret)
+}
+
+/// Ethereum address of a secret key
+pub fn eth(secret: &libsecp256k1::SecretKey) -> H160 {
+	H160::from_slice(&keccak_256(&public(secret).serialize()[1..65])[12..])
+}
+
+/// Signature by a secret key of `dest` as the destination of its claims
+pub fn sig<T: Config>(secret: &libsecp256k1::SecretKey, dest: &T::AccountId) -> EcdsaSignature {
+	let message =
+		dest.using_encoded(|dest| keccak_256(&Pallet::<T>::signable_message(&to_ascii_hex(dest))));
+	let (signature, recovery_id) =
+		libsecp256k1::sign(&libsecp256k1::Message::parse(&message), secret);
+	let mut r = [0u8; 65];
+	r[0..64].copy_from_slice(&signature.serialize()[..]);
+	r[64] = recovery_id.serialize();
+	EcdsaSignature(r)
+}
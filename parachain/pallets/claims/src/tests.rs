@@ -0,0 +1,300 @@
+use crate::{mock::*, secp_utils::*, ClaimSets, Claimed, EcdsaSignature, Error, Event};
+use frame_support::{assert_noop, assert_ok, traits::fungible::Inspect, BoundedVec};
+use sp_core::H256;
+use sp_runtime::{
+	traits::ValidateUnsigned,
+	transaction_validity::{InvalidTransaction, TransactionSource},
+	DispatchError,
+};
+
+fn key(seed: u8) -> libsecp256k1::SecretKey {
+	libsecp256k1::SecretKey::parse(&sp_io::hashing::keccak_256(&[seed])).unwrap()
+}
+
+fn proof(nodes: &[H256]) -> BoundedVec<H256, frame_support::traits::ConstU32<4>> {
+	nodes.to_vec().try_into().unwrap()
+}
+
+/// Tree of keys 1, 2 and 3 claiming 100, 200 and 300, with the proofs of each leaf
+fn snapshot() -> (H256, [Vec<H256>; 3]) {
+	let leaves =
+		[(1, 100), (2, 200), (3, 300)].map(|(seed, amount)| Claims::leaf(&eth(&key(seed)), amount));
+	let pair = Claims::node(&leaves[0], &leaves[1]);
+	let root = Claims::node(&pair, &leaves[2]);
+	(root, [vec![leaves[1], leaves[2]], vec![leaves[0], leaves[2]], vec![pair]])
+}
+
+fn publish(total: u64) -> [Vec<H256>; 3] {
+	let (root, proofs) = snapshot();
+	assert_ok!(Claims::set_claim_set(RuntimeOrigin::root(), 0, root, total));
+	proofs
+}
+
+#[test]
+fn claim_sets_are_set_by_claims_origin() {
+	new_test_ext().execute_with(|| {
+		let (root, _) = snapshot();
+		assert_noop!(
+			Claims::set_claim_set(RuntimeOrigin::signed(1), 0, root, 600),
+			DispatchError::BadOrigin
+		);
+
+		assert_ok!(Claims::set_claim_set(RuntimeOrigin::root(), 0, root, 600));
+		assert_eq!(
+			ClaimSets::<Test>::get(0).map(|set| (set.root, set.remaining)),
+			Some((root, 600))
+		);
+		System::assert_last_event(Event::ClaimSetSet { set_id: 0, root, total: 600 }.into());
+
+		assert_noop!(
+			Claims::remove_claim_set(RuntimeOrigin::signed(1), 0),
+			DispatchError::BadOrigin
+		);
+		assert_ok!(Claims::remove_claim_set(RuntimeOrigin::root(), 0));
+		assert!(ClaimSets::<Test>::get(0).is_none());
+		System::assert_last_event(Event::ClaimSetRemoved { set_id: 0 }.into());
+		assert_noop!(
+			Claims::remove_claim_set(RuntimeOrigin::root(), 0),
+			Error::<Test>::UnknownClaimSet
+		);
+	});
+}
+
+#[test]
+fn claims_mint_to_the_signed_destination() {
+	new_test_ext().execute_with(|| {
+		let proofs = publish(600);
+		let issuance = Balances::total_issuance();
+
+		assert_ok!(Claims::claim(
+			RuntimeOrigin::none(),
+			0,
+			42,
+			200,
+			proof(&proofs[1]),
+			sig::<Test>(&key(2), &42)
+		));
+		assert_eq!(Balances::free_balance(42), 200);
+		assert_eq!(Balances::total_issuance(), issuance + 200);
+		assert_eq!(ClaimSets::<Test>::get(0).unwrap().remaining, 400);
+		assert_eq!(Claimed::<Test>::get(0, eth(&key(2))), Some(200));
+		System::assert_last_event(
+			Event::Claimed { set_id: 0, address: eth(&key(2)), dest: 42, amount: 200 }.into(),
+		);
+
+		assert_ok!(Claims::claim(
+			RuntimeOrigin::none(),
+			0,
+			43,
+			300,
+			proof(&proofs[2]),
+			sig::<Test>(&key(3), &43)
+		));
+		assert_eq!(Balances::free_balance(43), 300);
+		assert_eq!(ClaimSets::<Test>::get(0).unwrap().remaining, 100);
+	});
+}
+
+#[test]
+fn claims_must_be_unsigned() {
+	new_test_ext().execute_with(|| {
+		let proofs = publish(600);
+		assert_noop!(
+			Claims::claim(
+				RuntimeOrigin::signed(42),
+				0,
+				42,
+				100,
+				proof(&proofs[0]),
+				sig::<Test>(&key(1), &42)
+			),
+			DispatchError::BadOrigin
+		);
+	});
+}
+
+#[test]
+fn addresses_claim_once_per_claim_set() {
+	new_test_ext().execute_with(|| {
+		let proofs = publish(600);
+		assert_ok!(Claims::claim(
+			RuntimeOrigin::none(),
+			0,
+			42,
+			100,
+			proof(&proofs[0]),
+			sig::<Test>(&key(1), &42)
+		));
+		assert_noop!(
+			Claims::claim(
+				RuntimeOrigin::none(),
+				0,
+				43,
+				100,
+				proof(&proofs[0]),
+				sig::<Test>(&key(1), &43)
+			),
+			Error::<Test>::AlreadyClaimed
+		);
+
+		// Republishing the claim set keeps the claims
+		publish(600);
+		assert_noop!(
+			Claims::claim(
+				RuntimeOrigin::none(),
+				0,
+				42,
+				100,
+				proof(&proofs[0]),
+				sig::<Test>(&key(1), &42)
+			),
+			Error::<Test>::AlreadyClaimed
+		);
+
+		// Another claim set is claimed independently
+		let (root, _) = snapshot();
+		assert_ok!(Claims::set_claim_set(RuntimeOrigin::root(), 1, root, 600));
+		assert_ok!(Claims::claim(
+			RuntimeOrigin::none(),
+			1,
+			42,
+			100,
+			proof(&proofs[0]),
+			sig::<Test>(&key(1), &42)
+		));
+		assert_eq!(Balances::free_balance(42), 200);
+	});
+}
+
+#[test]
+fn invalid_claims_are_rejected() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			Claims::claim(RuntimeOrigin::none(), 0, 42, 100, proof(&[]), sig::<Test>(&key(1), &42)),
+			Error::<Test>::UnknownClaimSet
+		);
+
+		let proofs = publish(600);
+		assert_noop!(
+			Claims::claim(
+				RuntimeOrigin::none(),
+				0,
+				42,
+				100,
+				proof(&proofs[0]),
+				EcdsaSignature([0; 65])
+			),
+			Error::<Test>::InvalidSignature
+		);
+
+		// Wrong amount
+		assert_noop!(
+			Claims::claim(
+				RuntimeOrigin::none(),
+				0,
+				42,
+				101,
+				proof(&proofs[0]),
+				sig::<Test>(&key(1), &42)
+			),
+			Error::<Test>::InvalidProof
+		);
+
+		// Signed for another destination
+		assert_noop!(
+			Claims::claim(
+				RuntimeOrigin::none(),
+				0,
+				42,
+				100,
+				proof(&proofs[0]),
+				sig::<Test>(&key(1), &43)
+			),
+			Error::<Test>::InvalidProof
+		);
+
+		// Address outside the snapshot
+		assert_noop!(
+			Claims::claim(
+				RuntimeOrigin::none(),
+				0,
+				42,
+				100,
+				proof(&proofs[0]),
+				sig::<Test>(&key(4), &42)
+			),
+			Error::<Test>::InvalidProof
+		);
+	});
+}
+
+#[test]
+fn claims_are_capped_by_the_claim_set() {
+	new_test_ext().execute_with(|| {
+		let proofs = publish(250);
+		assert_ok!(Claims::claim(
+			RuntimeOrigin::none(),
+			0,
+			42,
+			200,
+			proof(&proofs[1]),
+			sig::<Test>(&key(2), &42)
+		));
+		assert_noop!(
+			Claims::claim(
+				RuntimeOrigin::none(),
+				0,
+				43,
+				100,
+				proof(&proofs[0]),
+				sig::<Test>(&key(1), &43)
+			),
+			Error::<Test>::ExceedsClaimSet
+		);
+	});
+}
+
+#[test]
+fn validate_unsigned_checks_the_claim() {
+	new_test_ext().execute_with(|| {
+		let proofs = publish(600);
+		let call = |dest, amount, signature| crate::Call::<Test>::claim {
+			set_id: 0,
+			dest,
+			amount,
+			proof: proof(&proofs[0]),
+			signature,
+		};
+
+		let valid = call(42, 100, sig::<Test>(&key(1), &42));
+		let validity = Claims::validate_unsigned(TransactionSource::External, &valid).unwrap();
+		assert_eq!(validity.priority, 100);
+		assert_eq!(
+			Claims::validate_unsigned(
+				TransactionSource::External,
+				&call(42, 101, sig::<Test>(&key(1), &42))
+			),
+			Err(InvalidTransaction::BadProof.into())
+		);
+		assert_eq!(
+			Claims::validate_unsigned(
+				TransactionSource::External,
+				&call(42, 100, EcdsaSignature([0; 65]))
+			),
+			Err(InvalidTransaction::BadProof.into())
+		);
+
+		assert_ok!(Claims::claim(
+			RuntimeOrigin::none(),
+			0,
+			42,
+			100,
+			proof(&proofs[0]),
+			sig::<Test>(&key(1), &42)
+		));
+		assert_eq!(
+			Claims::validate_unsigned(TransactionSource::External, &valid),
+			Err(InvalidTransaction::Stale.into())
+		);
+	});
+}
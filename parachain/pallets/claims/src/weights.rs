@@ -0,0 +1,88 @@
+//! Placeholder weights for `pallet_claims`
+//!
+//! NOT MEASURED: the pallet has not been benchmarked yet. The storage accesses and proof
+//! sizes follow the code, while the execution times are hand estimates doubled as a safety
+//! margin, so that blocks are underfilled rather than overfilled until they are measured.
+//! Replace this file with the output of:
+//!
+//! ./target/release/evm-template-node benchmark pallet --chain dev \
+//!     --pallet pallet_claims --extrinsic '*' --steps 50 --repeat 20 \
+//!     --output pallets/claims/src/weights.rs
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+
+use frame_support::{traits::Get, weights::Weight};
+use core::marker::PhantomData;
+
+/// Weight functions needed for pallet_claims.
+pub trait WeightInfo {
+	fn set_claim_set() -> Weight;
+	fn remove_claim_set() -> Weight;
+	fn claim(p: u32, ) -> Weight;
+}
+
+/// Weights for pallet_claims using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	/// Storage: `Claims::ClaimSets` (r:0 w:1)
+	/// Proof: `Claims::ClaimSets` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
+	fn set_claim_set() -> Weight {
+		// Proof Size estimate in bytes:
+		//  Estimated: `0`
+		Weight::from_parts(14_428_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `Claims::ClaimSets` (r:1 w:1)
+	/// Proof: `Claims::ClaimSets` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
+	fn remove_claim_set() -> Weight {
+		// Proof Size estimate in bytes:
+		//  Estimated: `3525`
+		Weight::from_parts(23_854_000, 0)
+			.saturating_add(Weight::from_parts(0, 3525))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `Claims::ClaimSets` (r:1 w:1)
+	/// Proof: `Claims::ClaimSets` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
+	/// Storage: `Claims::Claimed` (r:1 w:1)
+	/// Proof: `Claims::Claimed` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(116), added: 2591, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::TotalIssuance` (r:1 w:1)
+	/// Proof: `Balances::TotalIssuance` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// The range of component `p` is `[0, 32]`.
+	fn claim(p: u32, ) -> Weight {
+		// Proof Size estimate in bytes:
+		//  Estimated: `3581`
+		Weight::from_parts(162_345_000, 0)
+			.saturating_add(Weight::from_parts(0, 3581))
+			// Standard Error: 1_864
+			.saturating_add(Weight::from_parts(3_365_000, 0).saturating_mul(p.into()))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(4))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	fn set_claim_set() -> Weight {
+		Weight::from_parts(14_428_000, 0)
+			.saturating_add(frame_support::weights::constants::RocksDbWeight::get().writes(1))
+	}
+	fn remove_claim_set() -> Weight {
+		Weight::from_parts(23_854_000, 3525)
+			.saturating_add(Weight::from_parts(0, 3525))
+			.saturating_add(frame_support::weights::constants::RocksDbWeight::get().reads(1))
+			.saturating_add(frame_support::weights::constants::RocksDbWeight::get().writes(1))
+	}
+	fn claim(p: u32, ) -> Weight {
+		Weight::from_parts(162_345_000, 3581)
+			.saturating_add(Weight::from_parts(0, 3581))
+			.saturating_add(Weight::from_parts(3_365_000, 0).saturating_mul(p.into()))
+			.saturating_add(frame_support::weights::constants::RocksDbWeight::get().reads(4))
+			.saturating_add(frame_support::weights::constants::RocksDbWeight::get().writes(4))
+	}
+}
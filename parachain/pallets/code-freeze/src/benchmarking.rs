@@ -0,0 +1,55 @@
+//! Benchmarking setup for pallet-code-freeze
+
+use super::*;
+
+#[allow(unused)]
+use crate::Pallet as CodeFreeze;
+use frame_benchmarking::v2::*;
+use frame_support::{
+	traits::{EnsureOrigin, Get},
+	BoundedVec,
+};
+use sp_core::H160;
+use sp_std::vec::Vec;
+
+/// Fill the frozen list up to one entry below its bound
+fn fill_frozen_list<T: Config>() {
+	let frozen: Vec<H160> = (1..T::MaxFrozenContracts::get())
+		.map(|index| H160::from_low_u64_be(index.into()))
+		.collect();
+	FrozenContracts::<T>::put(BoundedVec::try_from(frozen).unwrap());
+}
+
+#[benchmarks]
+mod benchmarks {
+	use super::*;
+
+	#[benchmark]
+	fn freeze_contract() {
+		let origin = T::ControlOrigin::try_successful_origin().unwrap();
+		fill_frozen_list::<T>();
+		let contract = H160::repeat_byte(0xff);
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, contract);
+
+		// Verify the contract was frozen
+		assert!(CodeFreeze::<T>::is_frozen(&contract));
+	}
+
+	#[benchmark]
+	fn unfreeze_contract() {
+		let origin = T::ControlOrigin::try_successful_origin().unwrap();
+		fill_frozen_list::<T>();
+		let contract = H160::repeat_byte(0xff);
+		FrozenContracts::<T>::mutate(|frozen| frozen.try_push(contract).unwrap());
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, contract);
+
+		// Verify the contract was unfrozen
+		assert!(!CodeFreeze::<T>::is_frozen(&contract));
+	}
+
+	impl_benchmark_test_suite!(CodeFreeze, crate::mock::new_test_ext(), crate::mock::Test);
+}
@@ -0,0 +1,248 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+//! # Code Freeze Pallet
+//!
+//! A governance list of canonical contracts whose code is declared immutable.
+//!
+//! ## Overview
+//!
+//! Contracts on the list can no longer be pointed at a new implementation. The pallet
+//! recognises the upgrade entry points of the common proxy patterns:
+//! - `upgradeTo(address)` and `upgradeToAndCall(address,bytes)` of UUPS, transparent and
+//!   beacon proxies, called on the frozen contract itself
+//! - `diamondCut` of EIP-2535 diamonds, called on the frozen contract itself
+//! - `upgrade(address,address)` and `upgradeAndCall(address,address,bytes)` of an
+//!   OpenZeppelin `ProxyAdmin`, naming the frozen contract as the proxy
+//!
+//! The runtime refuses Ethereum transactions and `pallet_evm::Call::call` matching
+//! [`Pallet::is_upgrade_blocked`], and fails any EVM execution creating a contract at a
+//! frozen address, so that a destroyed contract cannot be redeployed with different code:
+//! creations are noted with [`Pallet::note_created`] and taken with
+//! [`Pallet::take_frozen_creation`] once the execution ends.
+//!
+//! ## Features
+//!
+//! - Bounded list of frozen contracts, configurable at genesis
+//! - Governance-controlled freezing and unfreezing
+
+pub use pallet::*;
+pub mod weights;
+
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+/// Custom `InvalidTransaction` code of transactions upgrading a frozen contract
+///
+/// Follows the codes of the deployment control pallet's `DeploymentValidationError`.
+pub const CODE_FROZEN: u8 = 6;
+
+/// `upgradeTo(address)`
+const UPGRADE_TO: [u8; 4] = [0x36, 0x59, 0xcf, 0xe6];
+/// `upgradeToAndCall(address,bytes)`
+const UPGRADE_TO_AND_CALL: [u8; 4] = [0x4f, 0x1e, 0xf2, 0x86];
+/// `diamondCut((address,uint8,bytes4[])[],address,bytes)`
+const DIAMOND_CUT: [u8; 4] = [0x1f, 0x93, 0x1c, 0x1c];
+/// `upgrade(address,address)` of a `ProxyAdmin`
+const PROXY_ADMIN_UPGRADE: [u8; 4] = [0x99, 0xa8, 0x8e, 0xc4];
+/// `upgradeAndCall(address,address,bytes)` of a `ProxyAdmin`
+const PROXY_ADMIN_UPGRADE_AND_CALL: [u8; 4] = [0x96, 0x23, 0x60, 0x9d];
+
+#[frame_support::pallet]
+pub mod pallet {
+	use frame_support::pallet_prelude::*;
+	use frame_system::pallet_prelude::*;
+	use sp_core::H160;
+	use sp_std::vec::Vec;
+
+	pub use crate::weights::WeightInfo;
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		/// The overarching event type
+		#[allow(deprecated)]
+		type RuntimeEvent: From<Event<Self>>
+			+ IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		/// Origin allowed to freeze and unfreeze contracts
+		type ControlOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// Maximum number of frozen contracts
+		#[pallet::constant]
+		type MaxFrozenContracts: Get<u32>;
+
+		/// Weight information for extrinsics in this pallet
+		type WeightInfo: WeightInfo;
+	}
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	/// Contracts whose code is declared immutable
+	#[pallet::storage]
+	pub type FrozenContracts<T: Config> =
+		StorageValue<_, BoundedVec<H160, T::MaxFrozenContracts>, ValueQuery>;
+
+	/// Frozen address the EVM execution under way created a contract at, taken once it ends
+	#[pallet::storage]
+	pub type FrozenAddressCreated<T: Config> = StorageValue<_, H160, OptionQuery>;
+
+	#[pallet::genesis_config]
+	#[derive(frame_support::DefaultNoBound)]
+	pub struct GenesisConfig<T: Config> {
+		/// Contracts frozen at launch
+		pub frozen_contracts: Vec<H160>,
+		#[serde(skip)]
+		pub _config: PhantomData<T>,
+	}
+
+	#[pallet::genesis_build]
+	impl<T: Config> BuildGenesisConfig for GenesisConfig<T> {
+		fn build(&self) {
+			let frozen: BoundedVec<H160, T::MaxFrozenContracts> = self
+				.frozen_contracts
+				.clone()
+				.try_into()
+				.expect("Genesis freezes more contracts than MaxFrozenContracts");
+			FrozenContracts::<T>::put(frozen);
+		}
+	}
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// A contract's code was declared immutable
+		ContractFrozen {
+			/// The frozen contract
+			contract: H160,
+		},
+		/// A contract was removed from the frozen list
+		ContractUnfrozen {
+			/// The unfrozen contract
+			contract: H160,
+		},
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The contract is already frozen
+		AlreadyFrozen,
+		/// The contract is not frozen
+		NotFrozen,
+		/// The list already holds `MaxFrozenContracts` contracts
+		TooManyFrozenContracts,
+		/// A contract was created at a frozen address
+		CreationAtFrozenAddress,
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Declare the code of a contract immutable
+		///
+		/// From now on, calls upgrading the contract through a proxy entry point and
+		/// contract creations at its address are refused.
+		///
+		/// # Parameters
+		/// - `origin`: Must be `ControlOrigin`
+		/// - `contract`: The contract to freeze
+		///
+		/// # Errors
+		/// - `AlreadyFrozen`: The contract is already frozen
+		/// - `TooManyFrozenContracts`: The list is full
+		///
+		/// # Events
+		/// - `ContractFrozen`: Emitted when the contract is frozen
+		#[pallet::call_index(0)]
+		#[pallet::weight(T::WeightInfo::freeze_contract())]
+		pub fn freeze_contract(origin: OriginFor<T>, contract: H160) -> DispatchResult {
+			T::ControlOrigin::ensure_origin(origin)?;
+
+			FrozenContracts::<T>::try_mutate(|frozen| -> DispatchResult {
+				ensure!(!frozen.contains(&contract), Error::<T>::AlreadyFrozen);
+				frozen.try_push(contract).map_err(|_| Error::<T>::TooManyFrozenContracts)?;
+				Ok(())
+			})?;
+			Self::deposit_event(Event::ContractFrozen { contract });
+
+			Ok(())
+		}
+
+		/// Remove a contract from the frozen list
+		///
+		/// # Parameters
+		/// - `origin`: Must be `ControlOrigin`
+		/// - `contract`: The contract to unfreeze
+		///
+		/// # Errors
+		/// - `NotFrozen`: The contract is not frozen
+		///
+		/// # Events
+		/// - `ContractUnfrozen`: Emitted when the contract is unfrozen
+		#[pallet::call_index(1)]
+		#[pallet::weight(T::WeightInfo::unfreeze_contract())]
+		pub fn unfreeze_contract(origin: OriginFor<T>, contract: H160) -> DispatchResult {
+			T::ControlOrigin::ensure_origin(origin)?;
+
+			FrozenContracts::<T>::try_mutate(|frozen| -> DispatchResult {
+				let index = frozen
+					.iter()
+					.position(|frozen| *frozen == contract)
+					.ok_or(Error::<T>::NotFrozen)?;
+				frozen.remove(index);
+				Ok(())
+			})?;
+			Self::deposit_event(Event::ContractUnfrozen { contract });
+
+			Ok(())
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// Check if the code of `contract` is declared immutable
+		pub fn is_frozen(contract: &H160) -> bool {
+			FrozenContracts::<T>::get().contains(contract)
+		}
+
+		/// Note that the EVM created a contract at `contract`, if it is frozen
+		pub fn note_created(contract: &H160) {
+			if Self::is_frozen(contract) {
+				FrozenAddressCreated::<T>::put(contract);
+			}
+		}
+
+		/// Take the frozen address a contract was created at since the last call, if any
+		pub fn take_frozen_creation() -> Option<H160> {
+			FrozenAddressCreated::<T>::take()
+		}
+
+		/// Check if calling `target` with `input` would upgrade a frozen contract
+		pub fn is_upgrade_blocked(target: &H160, input: &[u8]) -> bool {
+			let Some(selector) = input.get(..4) else {
+				return false;
+			};
+
+			if selector == crate::UPGRADE_TO ||
+				selector == crate::UPGRADE_TO_AND_CALL ||
+				selector == crate::DIAMOND_CUT
+			{
+				return Self::is_frozen(target);
+			}
+
+			if selector == crate::PROXY_ADMIN_UPGRADE ||
+				selector == crate::PROXY_ADMIN_UPGRADE_AND_CALL
+			{
+				// The proxy is the first argument, an address left-padded to 32 bytes
+				return input
+					.get(16..36)
+					.is_some_and(|proxy| Self::is_frozen(&H160::from_slice(proxy)));
+			}
+
+			false
+		}
+	}
+}
@@ -0,0 +1,117 @@
+use crate::{
+	mock::*, Error, Event, FrozenContracts, PROXY_ADMIN_UPGRADE, UPGRADE_TO, UPGRADE_TO_AND_CALL,
+};
+use frame_support::{assert_noop, assert_ok};
+use sp_core::H160;
+use sp_runtime::DispatchError;
+
+const OTHER: H160 = H160::repeat_byte(0xbb);
+
+/// Calldata of `selector` followed by `address` as first argument
+fn call_with_address(selector: [u8; 4], address: H160) -> Vec<u8> {
+	let mut input = selector.to_vec();
+	input.extend_from_slice(&[0u8; 12]);
+	input.extend_from_slice(address.as_bytes());
+	input
+}
+
+#[test]
+fn genesis_freezes_contracts() {
+	new_test_ext().execute_with(|| {
+		assert!(CodeFreeze::is_frozen(&FROZEN));
+		assert!(!CodeFreeze::is_frozen(&OTHER));
+	});
+}
+
+#[test]
+fn freeze_requires_control_origin() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			CodeFreeze::freeze_contract(RuntimeOrigin::signed(1), OTHER),
+			DispatchError::BadOrigin
+		);
+	});
+}
+
+#[test]
+fn freeze_and_unfreeze_contract() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(CodeFreeze::freeze_contract(RuntimeOrigin::root(), OTHER));
+		System::assert_last_event(Event::ContractFrozen { contract: OTHER }.into());
+		assert!(CodeFreeze::is_frozen(&OTHER));
+
+		assert_noop!(
+			CodeFreeze::freeze_contract(RuntimeOrigin::root(), OTHER),
+			Error::<Test>::AlreadyFrozen
+		);
+
+		assert_ok!(CodeFreeze::unfreeze_contract(RuntimeOrigin::root(), OTHER));
+		System::assert_last_event(Event::ContractUnfrozen { contract: OTHER }.into());
+		assert!(!CodeFreeze::is_frozen(&OTHER));
+
+		assert_noop!(
+			CodeFreeze::unfreeze_contract(RuntimeOrigin::root(), OTHER),
+			Error::<Test>::NotFrozen
+		);
+	});
+}
+
+#[test]
+fn frozen_list_is_bounded() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(CodeFreeze::freeze_contract(RuntimeOrigin::root(), OTHER));
+		assert_noop!(
+			CodeFreeze::freeze_contract(RuntimeOrigin::root(), H160::repeat_byte(0xcc)),
+			Error::<Test>::TooManyFrozenContracts
+		);
+		assert_eq!(FrozenContracts::<Test>::get().len(), 2);
+	});
+}
+
+#[test]
+fn proxy_upgrades_of_frozen_contracts_are_blocked() {
+	new_test_ext().execute_with(|| {
+		let implementation = H160::repeat_byte(0x11);
+
+		let upgrade = call_with_address(UPGRADE_TO, implementation);
+		assert!(CodeFreeze::is_upgrade_blocked(&FROZEN, &upgrade));
+		assert!(!CodeFreeze::is_upgrade_blocked(&OTHER, &upgrade));
+
+		let upgrade_and_call = call_with_address(UPGRADE_TO_AND_CALL, implementation);
+		assert!(CodeFreeze::is_upgrade_blocked(&FROZEN, &upgrade_and_call));
+
+		// Other calls to the frozen contract go through
+		let transfer = call_with_address([0xa9, 0x05, 0x9c, 0xbb], implementation);
+		assert!(!CodeFreeze::is_upgrade_blocked(&FROZEN, &transfer));
+		assert!(!CodeFreeze::is_upgrade_blocked(&FROZEN, &[]));
+	});
+}
+
+#[test]
+fn proxy_admin_upgrades_of_frozen_contracts_are_blocked() {
+	new_test_ext().execute_with(|| {
+		let proxy_admin = H160::repeat_byte(0x22);
+
+		let upgrade_frozen = call_with_address(PROXY_ADMIN_UPGRADE, FROZEN);
+		assert!(CodeFreeze::is_upgrade_blocked(&proxy_admin, &upgrade_frozen));
+
+		let upgrade_other = call_with_address(PROXY_ADMIN_UPGRADE, OTHER);
+		assert!(!CodeFreeze::is_upgrade_blocked(&proxy_admin, &upgrade_other));
+
+		// Truncated calldata names no proxy
+		assert!(!CodeFreeze::is_upgrade_blocked(&proxy_admin, &upgrade_frozen[..20]));
+	});
+}
+
+#[test]
+fn creations_at_frozen_addresses_are_noted() {
+	new_test_ext().execute_with(|| {
+		CodeFreeze::note_created(&OTHER);
+		assert_eq!(CodeFreeze::take_frozen_creation(), None);
+
+		CodeFreeze::note_created(&FROZEN);
+		assert_eq!(CodeFreeze::take_frozen_creation(), Some(FROZEN));
+		// The creation is only reported once
+		assert_eq!(CodeFreeze::take_frozen_creation(), None);
+	});
+}
@@ -0,0 +1,69 @@
+//! Benchmarking setup for pallet-collator-offences
+
+use super::*;
+
+#[allow(unused)]
+use crate::Pallet as CollatorOffences;
+use frame_benchmarking::v2::*;
+use frame_support::traits::EnsureOrigin;
+use frame_system::RawOrigin;
+use sp_runtime::{Perbill, RuntimeAppPublic};
+use sp_std::boxed::Box;
+
+#[benchmarks]
+mod benchmarks {
+	use super::*;
+
+	#[benchmark]
+	fn set_slash_fractions() {
+		let origin = T::SlashFractionOrigin::try_successful_origin().unwrap();
+		let fractions = SlashFractions {
+			equivocation: Perbill::from_percent(100),
+			unavailability: Perbill::from_percent(1),
+		};
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, fractions);
+
+		// Verify the fractions were stored
+		assert_eq!(Fractions::<T>::get(), Some(fractions));
+	}
+
+	#[benchmark]
+	fn report_equivocation() {
+		let collator: T::AccountId = account("collator", 0, 0);
+		let key = T::AuthorityId::generate_pair(None);
+		T::BenchmarkHelper::register_collator(&collator, &key);
+		let number = 1u32.into();
+		let proof = EquivocationProof {
+			offender: key.clone(),
+			first_header: CollatorOffences::<T>::sealed_header(&key, number, 7, 0),
+			second_header: CollatorOffences::<T>::sealed_header(&key, number, 7, 1),
+		};
+		let caller: T::AccountId = whitelisted_caller();
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), Box::new(proof));
+
+		// Verify the equivocation was recorded
+		assert!(EquivocationReports::<T>::contains_key(&collator, 7));
+	}
+
+	#[benchmark]
+	fn note_author() {
+		let collator: T::AccountId = account("collator", 0, 0);
+		AuthoredBlocks::<T>::insert(&collator, 1);
+
+		#[block]
+		{
+			<CollatorOffences<T> as pallet_authorship::EventHandler<_, _>>::note_author(
+				collator.clone(),
+			);
+		}
+
+		// Verify the block was counted
+		assert_eq!(AuthoredBlocks::<T>::get(&collator), 2);
+	}
+
+	impl_benchmark_test_suite!(CollatorOffences, crate::mock::new_test_ext(), crate::mock::Test);
+}
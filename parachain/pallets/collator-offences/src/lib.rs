@@ -0,0 +1,466 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+//! # Collator Offences Pallet
+//!
+//! Offences of collators, slashing their bond and the stake delegated to them.
+//!
+//! ## Overview
+//!
+//! Two offences are detected:
+//! - [`Offence::Equivocation`]: anyone reports two different blocks of the same height
+//!   sealed by the same authority in the same slot with [`Pallet::report_equivocation`].
+//!   Blocks of different heights in one slot are not an offence, as a collator may author
+//!   several blocks per slot. Valid reports are free, and the offender is also removed
+//!   from the collator candidates
+//!
+//!   Both blocks must build on a block of this chain, so that proofs from another chain
+//!   sharing the collator's keys are refused, and be at most `MaxReportAge` blocks old.
+//!   With asynchronous backing, a collator whose block was not backed in time authors
+//!   the same height again, in the same slot, on a newer relay parent. Such blocks
+//!   differ but are not an offence, so both blocks must also share their relay parent,
+//!   as read by `RelayParent`
+//! - [`Offence::Unavailability`]: the pallet is a `pallet_authorship` event handler
+//!   counting the blocks of every author, and [`SlashUnavailable`] wraps the session
+//!   manager so that every collator of an ending session that authored no block in it is
+//!   slashed
+//!
+//! The fraction slashed for each offence is set by `SlashFractionOrigin` with
+//! [`Pallet::set_slash_fractions`], `DefaultSlashFractions` applying while unset. The
+//! runtime does the slashing itself through [`CollatorSlashing`], e.g. of the candidacy
+//! bond and of the delegations of the offender.
+
+pub use pallet::*;
+pub mod weights;
+
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+/// Registers collators for benchmarking
+#[cfg(feature = "runtime-benchmarks")]
+pub trait BenchmarkHelper<AccountId, AuthorityId> {
+	/// Register `who` as a bonded collator candidate authoring with `key`
+	fn register_collator(who: &AccountId, key: &AuthorityId);
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+	use frame_support::{pallet_prelude::*, weights::Weight};
+	use frame_system::pallet_prelude::*;
+	use pallet_session::SessionManager;
+	use sp_consensus_aura::digests::CompatibleDigestItem;
+	use sp_runtime::{
+		traits::{CheckedSub, Convert, Header as HeaderT, One, Saturating},
+		DigestItem, Perbill, RuntimeAppPublic,
+	};
+	use sp_std::{boxed::Box, marker::PhantomData, vec::Vec};
+
+	pub use crate::weights::WeightInfo;
+
+	/// Balance slashed by `Slashing`
+	pub type BalanceOf<T> = <<T as Config>::Slashing as CollatorSlashing<
+		<T as frame_system::Config>::AccountId,
+	>>::Balance;
+
+	/// Signature of the authority sealing blocks
+	pub type SignatureOf<T> = <<T as Config>::AuthorityId as RuntimeAppPublic>::Signature;
+
+	/// Slashes collators on behalf of the pallet
+	pub trait CollatorSlashing<AccountId> {
+		/// Balance slashed
+		type Balance: Parameter + MaxEncodedLen + Copy;
+
+		/// Slash `fraction` of what backs `collator`, returning the amount slashed
+		fn slash(collator: &AccountId, fraction: Perbill) -> Self::Balance;
+
+		/// Remove `collator` from the collator candidates, if it is one
+		fn remove_candidate(collator: &AccountId);
+
+		/// Upper bound of the weight of [`Self::slash`] and [`Self::remove_candidate`]
+		fn weight() -> Weight;
+	}
+
+	/// An offence of a collator
+	#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub enum Offence {
+		/// Sealed two different blocks of the same height in the same slot
+		Equivocation {
+			/// The slot of both blocks
+			slot: u64,
+		},
+		/// Authored no block during a session it was a collator in
+		Unavailability {
+			/// The session
+			session: u32,
+		},
+	}
+
+	/// Fraction slashed for every offence
+	#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub struct SlashFractions {
+		/// Fraction slashed for [`Offence::Equivocation`]
+		pub equivocation: Perbill,
+		/// Fraction slashed for [`Offence::Unavailability`]
+		pub unavailability: Perbill,
+	}
+
+	/// Reads the relay chain block a parachain block was built on from its header
+	pub trait RelayParentOf<Header> {
+		/// Number of the relay parent of the block, if its header records it
+		fn relay_parent_number(header: &Header) -> Option<u32>;
+	}
+
+	/// Headers record no relay parent, so every relay parent is taken to be the same
+	impl<Header> RelayParentOf<Header> for () {
+		fn relay_parent_number(_: &Header) -> Option<u32> {
+			None
+		}
+	}
+
+	/// Two blocks sealed by the same authority
+	#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+	pub struct EquivocationProof<Header, AuthorityId> {
+		/// The authority that sealed both blocks
+		pub offender: AuthorityId,
+		/// Header of the first block
+		pub first_header: Header,
+		/// Header of the second block
+		pub second_header: Header,
+	}
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		/// The overarching event type
+		#[allow(deprecated)]
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		/// Authority sealing blocks
+		type AuthorityId: Member + Parameter + RuntimeAppPublic;
+
+		/// Collator owning an authority key, if any
+		type KeyOwner: Convert<Self::AuthorityId, Option<Self::AccountId>>;
+
+		/// Collators of the current session
+		type Collators: Get<Vec<Self::AccountId>>;
+
+		/// Slashes the offenders
+		type Slashing: CollatorSlashing<Self::AccountId>;
+
+		/// Relay parent of the reported blocks
+		type RelayParent: RelayParentOf<HeaderFor<Self>>;
+
+		/// Age, in blocks, beyond which equivocations are no longer reported
+		///
+		/// Must stay below `BlockHashCount`, as the parent hashes of the reported blocks
+		/// are checked against the hashes `frame_system` keeps.
+		#[pallet::constant]
+		type MaxReportAge: Get<BlockNumberFor<Self>>;
+
+		/// Origin allowed to set the slash fractions
+		type SlashFractionOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// Slash fractions in effect while none are set
+		#[pallet::constant]
+		type DefaultSlashFractions: Get<SlashFractions>;
+
+		/// Weight information for extrinsics in this pallet
+		type WeightInfo: WeightInfo;
+
+		/// Registers collators for benchmarking
+		#[cfg(feature = "runtime-benchmarks")]
+		type BenchmarkHelper: crate::BenchmarkHelper<Self::AccountId, Self::AuthorityId>;
+	}
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	/// Slash fractions set by `SlashFractionOrigin`
+	///
+	/// `DefaultSlashFractions` apply while unset.
+	#[pallet::storage]
+	pub type Fractions<T: Config> = StorageValue<_, SlashFractions, OptionQuery>;
+
+	/// Slots of the equivocations reported, by offender
+	#[pallet::storage]
+	pub type EquivocationReports<T: Config> =
+		StorageDoubleMap<_, Blake2_128Concat, T::AccountId, Twox64Concat, u64, (), OptionQuery>;
+
+	/// Blocks authored in the current session, by author
+	#[pallet::storage]
+	pub type AuthoredBlocks<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, u32, ValueQuery>;
+
+	/// Session the authored blocks are counted for
+	///
+	/// Unset until the first session starts after the pallet is added, whose predecessor
+	/// is not checked for unavailability.
+	#[pallet::storage]
+	pub type CountedSession<T: Config> = StorageValue<_, u32, OptionQuery>;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// The slash fractions were changed
+		SlashFractionsSet {
+			/// The new slash fractions
+			fractions: SlashFractions,
+		},
+		/// A collator was slashed for an offence
+		CollatorSlashed {
+			/// The offender
+			collator: T::AccountId,
+			/// The offence
+			offence: Offence,
+			/// Fraction slashed
+			fraction: Perbill,
+			/// Amount slashed
+			amount: BalanceOf<T>,
+		},
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The headers are not two blocks of the same height, slot and relay parent sealed
+		/// by the offender on top of a block of this chain
+		InvalidEquivocationProof,
+		/// The offender key is not the key of a collator
+		UnknownOffender,
+		/// The equivocation was already reported
+		DuplicateReport,
+		/// The blocks are more than `MaxReportAge` blocks old
+		ReportTooOld,
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Set the fraction slashed for every offence
+		///
+		/// Applies to the offences detected from the next one on.
+		///
+		/// # Parameters
+		/// - `origin`: Must be `SlashFractionOrigin`
+		/// - `fractions`: The fraction slashed for every offence
+		///
+		/// # Events
+		/// - `SlashFractionsSet`: Emitted when the slash fractions are changed
+		#[pallet::call_index(0)]
+		#[pallet::weight(T::WeightInfo::set_slash_fractions())]
+		pub fn set_slash_fractions(
+			origin: OriginFor<T>,
+			fractions: SlashFractions,
+		) -> DispatchResult {
+			T::SlashFractionOrigin::ensure_origin(origin)?;
+
+			Fractions::<T>::put(fractions);
+			Self::deposit_event(Event::SlashFractionsSet { fractions });
+
+			Ok(())
+		}
+
+		/// Report a collator sealing two blocks of the same height in the same slot
+		///
+		/// Slashes the offender and removes it from the collator candidates. Valid reports
+		/// pay no fee.
+		///
+		/// # Parameters
+		/// - `origin`: Any signed account
+		/// - `proof`: The headers of both blocks and the key of the offender
+		///
+		/// # Errors
+		/// - `ReportTooOld`: The blocks are more than `MaxReportAge` blocks old
+		/// - `InvalidEquivocationProof`: The proof does not show an equivocation
+		/// - `UnknownOffender`: The offender key is not the key of a collator
+		/// - `DuplicateReport`: The equivocation was already reported
+		///
+		/// # Events
+		/// - `CollatorSlashed`: Emitted with the amount slashed
+		#[pallet::call_index(1)]
+		#[pallet::weight(
+			T::WeightInfo::report_equivocation().saturating_add(T::Slashing::weight())
+		)]
+		pub fn report_equivocation(
+			origin: OriginFor<T>,
+			proof: Box<EquivocationProof<HeaderFor<T>, T::AuthorityId>>,
+		) -> DispatchResultWithPostInfo {
+			ensure_signed(origin)?;
+
+			let age = frame_system::Pallet::<T>::block_number()
+				.saturating_sub(*proof.first_header.number());
+			ensure!(age <= T::MaxReportAge::get(), Error::<T>::ReportTooOld);
+			let slot =
+				Self::check_equivocation(&proof).ok_or(Error::<T>::InvalidEquivocationProof)?;
+			let collator =
+				T::KeyOwner::convert(proof.offender).ok_or(Error::<T>::UnknownOffender)?;
+			ensure!(
+				!EquivocationReports::<T>::contains_key(&collator, slot),
+				Error::<T>::DuplicateReport
+			);
+
+			EquivocationReports::<T>::insert(&collator, slot, ());
+			Self::slash(&collator, Offence::Equivocation { slot });
+			T::Slashing::remove_candidate(&collator);
+
+			Ok(Pays::No.into())
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// Slash fractions in effect
+		pub fn slash_fractions() -> SlashFractions {
+			Fractions::<T>::get().unwrap_or_else(T::DefaultSlashFractions::get)
+		}
+
+		/// Slot of the equivocation `proof` shows, if it shows one
+		///
+		/// Blocks re-authored on a newer relay parent are not an equivocation, see the
+		/// crate documentation.
+		pub fn check_equivocation(
+			proof: &EquivocationProof<HeaderFor<T>, T::AuthorityId>,
+		) -> Option<u64> {
+			let (first_header, second_header) = (&proof.first_header, &proof.second_header);
+			let first = Self::sealed_slot(first_header, &proof.offender)?;
+			let second = Self::sealed_slot(second_header, &proof.offender)?;
+			let same_height = first_header.number() == second_header.number();
+			let different = first_header.hash() != second_header.hash();
+			let on_chain =
+				Self::builds_on_chain(first_header) && Self::builds_on_chain(second_header);
+			let same_relay_parent = T::RelayParent::relay_parent_number(first_header) ==
+				T::RelayParent::relay_parent_number(second_header);
+
+			(first == second && same_height && different && on_chain && same_relay_parent)
+				.then_some(first)
+		}
+
+		/// Whether the parent of `header` is a block of this chain
+		fn builds_on_chain(header: &HeaderFor<T>) -> bool {
+			let Some(parent) = header.number().checked_sub(&One::one()) else {
+				return false;
+			};
+			frame_system::BlockHash::<T>::try_get(parent)
+				.map_or(false, |hash| hash == *header.parent_hash())
+		}
+
+		/// Slot of `header`, if `key` sealed it
+		///
+		/// The seal is the last digest item, signing the hash of the header without it.
+		fn sealed_slot(header: &HeaderFor<T>, key: &T::AuthorityId) -> Option<u64> {
+			let mut header = header.clone();
+			let seal = header.digest_mut().pop()?;
+			let signature =
+				<DigestItem as CompatibleDigestItem<SignatureOf<T>>>::as_aura_seal(&seal)?;
+			let slot = header.digest().logs().iter().find_map(|log| {
+				<DigestItem as CompatibleDigestItem<SignatureOf<T>>>::as_aura_pre_digest(log)
+			})?;
+
+			key.verify(&header.hash(), &signature).then_some(u64::from(slot))
+		}
+
+		/// Header of block `number` in `slot` sealed by `key`, on top of the block of this
+		/// chain before it
+		///
+		/// Headers of different `fork` differ.
+		#[cfg(any(test, feature = "runtime-benchmarks"))]
+		pub fn sealed_header(
+			key: &T::AuthorityId,
+			number: BlockNumberFor<T>,
+			slot: u64,
+			fork: u8,
+		) -> HeaderFor<T> {
+			let digest = sp_runtime::Digest {
+				logs: sp_std::vec![
+					<DigestItem as CompatibleDigestItem<SignatureOf<T>>>::aura_pre_digest(
+						slot.into()
+					),
+					DigestItem::Other(sp_std::vec![fork]),
+				],
+			};
+			let parent = number.saturating_sub(One::one());
+			let mut header = HeaderFor::<T>::new(
+				number,
+				Default::default(),
+				Default::default(),
+				frame_system::Pallet::<T>::block_hash(parent),
+				digest,
+			);
+			let signature = key.sign(&header.hash()).expect("the key signs");
+			header
+				.digest_mut()
+				.push(<DigestItem as CompatibleDigestItem<SignatureOf<T>>>::aura_seal(signature));
+			header
+		}
+
+		/// Slash `collator` the fraction in effect for `offence`
+		fn slash(collator: &T::AccountId, offence: Offence) {
+			let fractions = Self::slash_fractions();
+			let fraction = match offence {
+				Offence::Equivocation { .. } => fractions.equivocation,
+				Offence::Unavailability { .. } => fractions.unavailability,
+			};
+
+			let amount = T::Slashing::slash(collator, fraction);
+			Self::deposit_event(Event::CollatorSlashed {
+				collator: collator.clone(),
+				offence,
+				fraction,
+				amount,
+			});
+		}
+
+		/// Slash the collators of `session` that authored no block in it
+		///
+		/// Skipped if the blocks were not counted for the whole session.
+		pub fn slash_unavailable(session: u32) {
+			if CountedSession::<T>::get() == Some(session) {
+				for collator in T::Collators::get() {
+					if !AuthoredBlocks::<T>::contains_key(&collator) {
+						Self::slash(&collator, Offence::Unavailability { session });
+					}
+				}
+			}
+			let _ = AuthoredBlocks::<T>::clear(u32::MAX, None);
+		}
+	}
+
+	impl<T: Config> pallet_authorship::EventHandler<T::AccountId, BlockNumberFor<T>> for Pallet<T> {
+		fn note_author(author: T::AccountId) {
+			AuthoredBlocks::<T>::mutate(author, |blocks| blocks.saturating_inc());
+
+			frame_system::Pallet::<T>::register_extra_weight_unchecked(
+				T::WeightInfo::note_author(),
+				DispatchClass::Mandatory,
+			);
+		}
+	}
+
+	/// Session manager slashing the collators of an ending session that authored no block
+	///
+	/// The collators of the session are still `Collators` when it ends.
+	pub struct SlashUnavailable<T, Inner>(PhantomData<(T, Inner)>);
+
+	impl<T: Config, Inner: SessionManager<T::AccountId>> SessionManager<T::AccountId>
+		for SlashUnavailable<T, Inner>
+	{
+		fn new_session(new_index: u32) -> Option<Vec<T::AccountId>> {
+			Inner::new_session(new_index)
+		}
+
+		fn new_session_genesis(new_index: u32) -> Option<Vec<T::AccountId>> {
+			Inner::new_session_genesis(new_index)
+		}
+
+		fn end_session(end_index: u32) {
+			Pallet::<T>::slash_unavailable(end_index);
+			Inner::end_session(end_index)
+		}
+
+		fn start_session(start_index: u32) {
+			CountedSession::<T>::put(start_index);
+			Inner::start_session(start_index)
+		}
+	}
+}
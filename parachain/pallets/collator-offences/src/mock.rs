@@ -0,0 +1,165 @@
+use crate as pallet_collator_offences;
+use frame_support::{
+	derive_impl, parameter_types,
+	traits::{Imbalance, ReservableCurrency},
+	weights::Weight,
+};
+use frame_system::EnsureRoot;
+use frame_system::pallet_prelude::HeaderFor;
+use pallet_collator_offences::{CollatorSlashing, RelayParentOf, SlashFractions};
+use sp_runtime::{
+	testing::{UintAuthorityId, H256},
+	traits::{Convert, Get, Header as HeaderT, IdentityLookup},
+	BuildStorage, DigestItem, Perbill,
+};
+use std::cell::RefCell;
+
+type Block = frame_system::mocking::MockBlock<Test>;
+
+// Configure a mock runtime to test the pallet.
+frame_support::construct_runtime!(
+	pub enum Test
+	{
+		System: frame_system,
+		Balances: pallet_balances,
+		CollatorOffences: pallet_collator_offences,
+	}
+);
+
+#[derive_impl(frame_system::config_preludes::TestDefaultConfig)]
+impl frame_system::Config for Test {
+	type Block = Block;
+	type AccountId = u64;
+	type Lookup = IdentityLookup<Self::AccountId>;
+	type AccountData = pallet_balances::AccountData<u64>;
+}
+
+#[derive_impl(pallet_balances::config_preludes::TestDefaultConfig)]
+impl pallet_balances::Config for Test {
+	type AccountStore = System;
+}
+
+parameter_types! {
+	pub const MaxReportAge: u64 = 20;
+	pub const DefaultSlashFractions: SlashFractions = SlashFractions {
+		equivocation: Perbill::from_percent(50),
+		unavailability: Perbill::from_percent(10),
+	};
+}
+
+thread_local! {
+	static KEY_OWNERS: RefCell<Vec<(u64, u64)>> = RefCell::new(vec![(11, 1), (12, 2), (13, 3)]);
+	static COLLATORS: RefCell<Vec<u64>> = RefCell::new(vec![1, 2, 3]);
+	static REMOVED: RefCell<Vec<u64>> = RefCell::new(vec![]);
+}
+
+/// Collators 1, 2 and 3 author with keys 11, 12 and 13
+pub struct KeyOwner;
+
+impl Convert<UintAuthorityId, Option<u64>> for KeyOwner {
+	fn convert(key: UintAuthorityId) -> Option<u64> {
+		KEY_OWNERS.with(|owners| {
+			owners.borrow().iter().find(|(owned, _)| *owned == key.0).map(|(_, who)| *who)
+		})
+	}
+}
+
+/// Collators of the current session, 1, 2 and 3 unless set
+pub struct Collators;
+
+impl Get<Vec<u64>> for Collators {
+	fn get() -> Vec<u64> {
+		COLLATORS.with(|collators| collators.borrow().clone())
+	}
+}
+
+pub fn set_collators(collators: Vec<u64>) {
+	COLLATORS.with(|current| *current.borrow_mut() = collators);
+}
+
+/// Collators removed from the candidates
+pub fn removed() -> Vec<u64> {
+	REMOVED.with(|removed| removed.borrow().clone())
+}
+
+/// Slashes the reserved bond of a collator
+pub struct SlashBond;
+
+impl CollatorSlashing<u64> for SlashBond {
+	type Balance = u64;
+
+	fn slash(collator: &u64, fraction: Perbill) -> u64 {
+		let bond = Balances::reserved_balance(collator);
+		let (slashed, _) = Balances::slash_reserved(collator, fraction * bond);
+		slashed.peek()
+	}
+
+	fn remove_candidate(collator: &u64) {
+		Balances::unreserve(collator, Balances::reserved_balance(collator));
+		REMOVED.with(|removed| removed.borrow_mut().push(*collator));
+	}
+
+	fn weight() -> Weight {
+		Weight::zero()
+	}
+}
+
+/// Relay parent of the headers sealed by `sealed_header`: the tens of their fork byte
+pub struct ForkRelayParent;
+
+impl RelayParentOf<HeaderFor<Test>> for ForkRelayParent {
+	fn relay_parent_number(header: &HeaderFor<Test>) -> Option<u32> {
+		header.digest().logs().iter().find_map(|log| match log {
+			DigestItem::Other(fork) => fork.first().map(|fork| u32::from(fork / 10)),
+			_ => None,
+		})
+	}
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+pub struct BenchmarkHelper;
+
+#[cfg(feature = "runtime-benchmarks")]
+impl crate::BenchmarkHelper<u64, UintAuthorityId> for BenchmarkHelper {
+	fn register_collator(who: &u64, key: &UintAuthorityId) {
+		KEY_OWNERS.with(|owners| owners.borrow_mut().push((key.0, *who)));
+		<Balances as frame_support::traits::Currency<u64>>::make_free_balance_be(who, 1_000);
+		Balances::reserve(who, 100).unwrap();
+	}
+}
+
+impl pallet_collator_offences::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type AuthorityId = UintAuthorityId;
+	type KeyOwner = KeyOwner;
+	type Collators = Collators;
+	type Slashing = SlashBond;
+	type RelayParent = ForkRelayParent;
+	type MaxReportAge = MaxReportAge;
+	type SlashFractionOrigin = EnsureRoot<u64>;
+	type DefaultSlashFractions = DefaultSlashFractions;
+	type WeightInfo = ();
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = BenchmarkHelper;
+}
+
+// Build genesis storage according to the mock runtime.
+pub fn new_test_ext() -> sp_io::TestExternalities {
+	let mut storage = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
+	pallet_balances::GenesisConfig::<Test> { balances: (1..=3).map(|who| (who, 1_000)).collect() }
+		.assimilate_storage(&mut storage)
+		.unwrap();
+
+	let mut ext: sp_io::TestExternalities = storage.into();
+	ext.execute_with(|| {
+		// Reported blocks build on one of the first ten blocks
+		for number in 0..10 {
+			frame_system::BlockHash::<Test>::insert(number, H256::repeat_byte(number as u8 + 1));
+		}
+		System::set_block_number(10);
+		for who in 1..=3 {
+			Balances::reserve(&who, 100).unwrap();
+		}
+	});
+	ext
+}
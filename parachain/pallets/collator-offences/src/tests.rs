@@ -0,0 +1,239 @@
+use crate::{
+	mock::*, AuthoredBlocks, EquivocationProof, EquivocationReports, Error, Event, Fractions,
+	Offence, SlashFractions, SlashUnavailable,
+};
+use frame_support::{
+	assert_noop, assert_ok,
+	dispatch::Pays,
+	traits::{Currency, ReservableCurrency},
+};
+use frame_system::pallet_prelude::HeaderFor;
+use pallet_authorship::EventHandler;
+use pallet_session::SessionManager;
+use sp_runtime::{
+	testing::{UintAuthorityId, H256},
+	DispatchError, Perbill,
+};
+
+type Sessions = SlashUnavailable<Test, ()>;
+
+/// Collators slashed so far
+fn slashed() -> Vec<u64> {
+	System::events()
+		.into_iter()
+		.filter_map(|record| match record.event {
+			RuntimeEvent::CollatorOffences(Event::CollatorSlashed { collator, .. }) =>
+				Some(collator),
+			_ => None,
+		})
+		.collect()
+}
+
+fn proof(
+	offender: u64,
+	first: (u64, u64, u8),
+	second: (u64, u64, u8),
+) -> Box<EquivocationProof<HeaderFor<Test>, UintAuthorityId>> {
+	let key = UintAuthorityId(offender);
+	Box::new(EquivocationProof {
+		offender: key.clone(),
+		first_header: CollatorOffences::sealed_header(&key, first.0, first.1, first.2),
+		second_header: CollatorOffences::sealed_header(&key, second.0, second.1, second.2),
+	})
+}
+
+#[test]
+fn set_slash_fractions_works() {
+	new_test_ext().execute_with(|| {
+		assert_eq!(CollatorOffences::slash_fractions(), DefaultSlashFractions::get());
+
+		let fractions = SlashFractions {
+			equivocation: Perbill::from_percent(100),
+			unavailability: Perbill::zero(),
+		};
+		assert_noop!(
+			CollatorOffences::set_slash_fractions(RuntimeOrigin::signed(1), fractions),
+			DispatchError::BadOrigin
+		);
+		assert_ok!(CollatorOffences::set_slash_fractions(RuntimeOrigin::root(), fractions));
+
+		System::assert_last_event(Event::SlashFractionsSet { fractions }.into());
+		assert_eq!(Fractions::<Test>::get(), Some(fractions));
+		assert_eq!(CollatorOffences::slash_fractions(), fractions);
+	});
+}
+
+#[test]
+fn report_equivocation_slashes_and_removes_the_offender() {
+	new_test_ext().execute_with(|| {
+		let info = CollatorOffences::report_equivocation(
+			RuntimeOrigin::signed(2),
+			proof(11, (5, 7, 0), (5, 7, 1)),
+		)
+		.unwrap();
+
+		assert_eq!(info.pays_fee, Pays::No);
+		System::assert_last_event(
+			Event::CollatorSlashed {
+				collator: 1,
+				offence: Offence::Equivocation { slot: 7 },
+				fraction: Perbill::from_percent(50),
+				amount: 50,
+			}
+			.into(),
+		);
+		assert!(EquivocationReports::<Test>::contains_key(1, 7));
+		assert_eq!(removed(), vec![1]);
+		assert_eq!(Balances::reserved_balance(1), 0);
+		assert_eq!(Balances::free_balance(1), 950);
+
+		assert_noop!(
+			CollatorOffences::report_equivocation(
+				RuntimeOrigin::signed(2),
+				proof(11, (5, 7, 0), (5, 7, 2)),
+			),
+			Error::<Test>::DuplicateReport
+		);
+	});
+}
+
+#[test]
+fn report_equivocation_rejects_invalid_proofs() {
+	new_test_ext().execute_with(|| {
+		// Different slots
+		assert_noop!(
+			CollatorOffences::report_equivocation(
+				RuntimeOrigin::signed(2),
+				proof(11, (5, 7, 0), (5, 8, 1)),
+			),
+			Error::<Test>::InvalidEquivocationProof
+		);
+		// Several blocks of different heights in one slot are allowed
+		assert_noop!(
+			CollatorOffences::report_equivocation(
+				RuntimeOrigin::signed(2),
+				proof(11, (5, 7, 0), (6, 7, 0)),
+			),
+			Error::<Test>::InvalidEquivocationProof
+		);
+		// The same block twice
+		assert_noop!(
+			CollatorOffences::report_equivocation(
+				RuntimeOrigin::signed(2),
+				proof(11, (5, 7, 0), (5, 7, 0)),
+			),
+			Error::<Test>::InvalidEquivocationProof
+		);
+
+		// Sealed by another key than the offender
+		let mut forged = proof(12, (5, 7, 0), (5, 7, 1));
+		forged.offender = UintAuthorityId(11);
+		assert_noop!(
+			CollatorOffences::report_equivocation(RuntimeOrigin::signed(2), forged),
+			Error::<Test>::InvalidEquivocationProof
+		);
+
+		// Unsealed
+		let mut unsealed = proof(11, (5, 7, 0), (5, 7, 1));
+		unsealed.second_header.digest.pop();
+		assert_noop!(
+			CollatorOffences::report_equivocation(RuntimeOrigin::signed(2), unsealed),
+			Error::<Test>::InvalidEquivocationProof
+		);
+
+		assert_noop!(
+			CollatorOffences::report_equivocation(
+				RuntimeOrigin::signed(2),
+				proof(99, (5, 7, 0), (5, 7, 1)),
+			),
+			Error::<Test>::UnknownOffender
+		);
+	});
+}
+
+#[test]
+fn report_equivocation_binds_proofs_to_the_chain() {
+	new_test_ext().execute_with(|| {
+		// The same height and slot re-authored on a newer relay parent
+		assert_noop!(
+			CollatorOffences::report_equivocation(
+				RuntimeOrigin::signed(2),
+				proof(11, (5, 7, 0), (5, 7, 10)),
+			),
+			Error::<Test>::InvalidEquivocationProof
+		);
+
+		// Blocks beyond the last block of the chain
+		assert_noop!(
+			CollatorOffences::report_equivocation(
+				RuntimeOrigin::signed(2),
+				proof(11, (11, 7, 0), (11, 7, 1)),
+			),
+			Error::<Test>::InvalidEquivocationProof
+		);
+
+		// Blocks of another chain, whose block 4 differs
+		let foreign = proof(11, (5, 7, 0), (5, 7, 1));
+		frame_system::BlockHash::<Test>::insert(4, H256::repeat_byte(0xff));
+		assert_noop!(
+			CollatorOffences::report_equivocation(RuntimeOrigin::signed(2), foreign),
+			Error::<Test>::InvalidEquivocationProof
+		);
+
+		// Blocks more than `MaxReportAge` blocks old
+		let stale = proof(11, (6, 7, 0), (6, 7, 1));
+		System::set_block_number(27);
+		assert_noop!(
+			CollatorOffences::report_equivocation(RuntimeOrigin::signed(2), stale),
+			Error::<Test>::ReportTooOld
+		);
+	});
+}
+
+#[test]
+fn collators_authoring_no_block_in_a_session_are_slashed() {
+	new_test_ext().execute_with(|| {
+		Sessions::start_session(1);
+		CollatorOffences::note_author(1);
+		CollatorOffences::note_author(2);
+		CollatorOffences::note_author(1);
+		assert_eq!(AuthoredBlocks::<Test>::get(1), 2);
+
+		Sessions::end_session(1);
+
+		System::assert_last_event(
+			Event::CollatorSlashed {
+				collator: 3,
+				offence: Offence::Unavailability { session: 1 },
+				fraction: Perbill::from_percent(10),
+				amount: 10,
+			}
+			.into(),
+		);
+		assert_eq!(slashed(), vec![3]);
+		assert_eq!(Balances::reserved_balance(3), 90);
+		assert_eq!(Balances::reserved_balance(1), 100);
+		assert!(removed().is_empty());
+		assert_eq!(AuthoredBlocks::<Test>::iter().count(), 0);
+
+		// The next session has other collators, who all authored
+		Sessions::start_session(2);
+		set_collators(vec![1, 2]);
+		CollatorOffences::note_author(1);
+		CollatorOffences::note_author(2);
+		Sessions::end_session(2);
+		assert_eq!(slashed(), vec![3]);
+	});
+}
+
+#[test]
+fn sessions_not_counted_in_full_are_not_checked() {
+	new_test_ext().execute_with(|| {
+		// The pallet was added during session 4
+		CollatorOffences::note_author(1);
+		Sessions::end_session(4);
+
+		assert!(slashed().is_empty());
+		assert_eq!(AuthoredBlocks::<Test>::iter().count(), 0);
+	});
+}
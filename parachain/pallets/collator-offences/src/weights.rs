@@ -0,0 +1,84 @@
+//! Placeholder weights for `pallet_collator_offences`
+//!
+//! NOT MEASURED: the pallet has not been benchmarked yet. The storage accesses and proof
+//! sizes follow the code, while the execution times are hand estimates doubled as a safety
+//! margin, so that blocks are underfilled rather than overfilled until they are measured.
+//! Replace this file with the output of:
+//!
+//! ./target/release/evm-template-node benchmark pallet --chain dev \
+//!     --pallet pallet_collator_offences --extrinsic '*' --steps 50 --repeat 20 \
+//!     --output pallets/collator-offences/src/weights.rs
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+
+use frame_support::{traits::Get, weights::Weight};
+use core::marker::PhantomData;
+
+/// Weight functions needed for pallet_collator_offences.
+pub trait WeightInfo {
+	fn set_slash_fractions() -> Weight;
+	fn report_equivocation() -> Weight;
+	fn note_author() -> Weight;
+}
+
+/// Weights for pallet_collator_offences using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	/// Storage: `CollatorOffences::Fractions` (r:0 w:1)
+	/// Proof: `CollatorOffences::Fractions` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	fn set_slash_fractions() -> Weight {
+		// Proof Size estimate in bytes:
+		//  Estimated: `0`
+		Weight::from_parts(12_874_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `System::BlockHash` (r:1 w:0)
+	/// Proof: `System::BlockHash` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `Session::KeyOwner` (r:1 w:0)
+	/// Proof: `Session::KeyOwner` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `CollatorOffences::EquivocationReports` (r:1 w:1)
+	/// Proof: `CollatorOffences::EquivocationReports` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorOffences::Fractions` (r:1 w:0)
+	/// Proof: `CollatorOffences::Fractions` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	fn report_equivocation() -> Weight {
+		// Proof Size estimate in bytes:
+		//  Estimated: `3679`
+		Weight::from_parts(243_746_000, 0)
+			.saturating_add(Weight::from_parts(0, 3679))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `CollatorOffences::AuthoredBlocks` (r:1 w:1)
+	/// Proof: `CollatorOffences::AuthoredBlocks` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	fn note_author() -> Weight {
+		// Proof Size estimate in bytes:
+		//  Estimated: `3517`
+		Weight::from_parts(16_702_000, 0)
+			.saturating_add(Weight::from_parts(0, 3517))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	fn set_slash_fractions() -> Weight {
+		Weight::from_parts(12_874_000, 0)
+			.saturating_add(frame_support::weights::constants::RocksDbWeight::get().writes(1))
+	}
+	fn report_equivocation() -> Weight {
+		Weight::from_parts(243_746_000, 3679)
+			.saturating_add(Weight::from_parts(0, 3679))
+			.saturating_add(frame_support::weights::constants::RocksDbWeight::get().reads(4))
+			.saturating_add(frame_support::weights::constants::RocksDbWeight::get().writes(1))
+	}
+	fn note_author() -> Weight {
+		Weight::from_parts(16_702_000, 3517)
+			.saturating_add(Weight::from_parts(0, 3517))
+			.saturating_add(frame_support::weights::constants::RocksDbWeight::get().reads(1))
+			.saturating_add(frame_support::weights::constants::RocksDbWeight::get().writes(1))
+	}
+}
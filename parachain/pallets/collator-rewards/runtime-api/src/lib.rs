@@ -0,0 +1,23 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+//! # Collator Rewards Runtime API
+//!
+//! Runtime API exposing collator block rewards to the node, so that collators and their
+//! dashboards can follow what they are owed before claiming it.
+
+use parity_scale_codec::Codec;
+
+sp_api::decl_runtime_apis! {
+    /// Runtime API for the collator rewards pallet
+    pub trait CollatorRewardsApi<AccountId, Balance>
+    where
+        AccountId: Codec,
+        Balance: Codec,
+    {
+        /// Rewards accrued by `collator` and not claimed yet
+        fn pending_rewards(collator: AccountId) -> Balance;
+
+        /// Reward accrued by the author of every block
+        fn reward_per_block() -> Balance;
+    }
+}
@@ -0,0 +1,62 @@
+//! Benchmarking setup for pallet-collator-rewards
+
+use super::*;
+
+#[allow(unused)]
+use crate::Pallet as CollatorRewards;
+use frame_benchmarking::v2::*;
+use frame_support::traits::{
+	fungible::{Inspect, Mutate},
+	EnsureOrigin, Get,
+};
+use frame_system::RawOrigin;
+use sp_runtime::traits::Saturating;
+
+#[benchmarks]
+mod benchmarks {
+	use super::*;
+
+	#[benchmark]
+	fn set_reward() {
+		let origin = T::RewardOrigin::try_successful_origin().unwrap();
+		let reward =
+			RewardConfig { per_block: T::MaxRewardPerBlock::get(), source: RewardSource::Mint };
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, reward);
+
+		// Verify the reward was stored
+		assert_eq!(Reward::<T>::get(), Some(reward));
+	}
+
+	#[benchmark]
+	fn claim_rewards() {
+		let collator: T::AccountId = whitelisted_caller();
+		let amount = T::MaxRewardPerBlock::get();
+		PendingRewards::<T>::insert(&collator, amount);
+		Reward::<T>::put(RewardConfig { per_block: amount, source: RewardSource::Pot });
+		let pot = CollatorRewards::<T>::pot_account();
+		T::Currency::set_balance(&pot, T::Currency::minimum_balance().saturating_add(amount));
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(collator.clone()));
+
+		// Verify the rewards were paid
+		assert!(!PendingRewards::<T>::contains_key(&collator));
+	}
+
+	#[benchmark]
+	fn note_author() {
+		let collator: T::AccountId = account("collator", 0, 0);
+		PendingRewards::<T>::insert(&collator, T::MaxRewardPerBlock::get());
+
+		#[block]
+		{
+			<CollatorRewards<T> as pallet_authorship::EventHandler<_, _>>::note_author(
+				collator.clone(),
+			);
+		}
+	}
+
+	impl_benchmark_test_suite!(CollatorRewards, crate::mock::new_test_ext(), crate::mock::Test);
+}
@@ -0,0 +1,276 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+//! # Collator Rewards Pallet
+//!
+//! Per-block rewards for the collators authoring blocks.
+//!
+//! ## Overview
+//!
+//! The pallet is a `pallet_authorship` event handler. The author of every block accrues
+//! the reward per block of the [`RewardConfig`] in effect, set by `RewardOrigin` with
+//! [`Pallet::set_reward`] and bounded by `MaxRewardPerBlock`, `DefaultReward` applying
+//! while unset. Collators claim their accrued rewards with [`Pallet::claim_rewards`],
+//! which pays them according to the [`RewardSource`] in effect at the time:
+//! - [`RewardSource::Mint`] mints new tokens
+//! - [`RewardSource::Pot`] transfers from the pot account derived from `PotId`, which
+//!   anyone can fund, e.g. the treasury or an issuance schedule. Claims fail while the
+//!   pot cannot pay them
+//!
+//! `RewardShares` lets the author share its reward with the accounts backing it, e.g.
+//! delegators, who then claim their share the same way.
+//!
+//! Accrued rewards are exposed through a runtime API.
+
+pub use pallet::*;
+pub mod weights;
+
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+#[frame_support::pallet]
+pub mod pallet {
+	use frame_support::{
+		pallet_prelude::*,
+		traits::{
+			fungible::{Inspect, Mutate},
+			tokens::Preservation,
+		},
+		PalletId,
+	};
+	use frame_system::pallet_prelude::*;
+	use sp_runtime::traits::{AccountIdConversion, Saturating, Zero};
+
+	pub use crate::weights::WeightInfo;
+
+	/// Balance of the currency rewards are paid in
+	pub type BalanceOf<T> =
+		<<T as Config>::Currency as Inspect<<T as frame_system::Config>::AccountId>>::Balance;
+
+	/// Where claimed rewards are paid from
+	#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub enum RewardSource {
+		/// Rewards are minted, inflating the supply
+		Mint,
+		/// Rewards are transferred from the pot account
+		Pot,
+	}
+
+	/// Reward accrued by block authors and its source
+	#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub struct RewardConfig<Balance> {
+		/// Reward accrued by the author of every block
+		pub per_block: Balance,
+		/// Where claimed rewards are paid from
+		pub source: RewardSource,
+	}
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		/// The overarching event type
+		#[allow(deprecated)]
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		/// Currency rewards are paid in
+		type Currency: Mutate<Self::AccountId>;
+
+		/// Origin allowed to change the reward
+		type RewardOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// Identifier of the pot account rewards are paid from with [`RewardSource::Pot`]
+		#[pallet::constant]
+		type PotId: Get<PalletId>;
+
+		/// Reward in effect while none is set
+		#[pallet::constant]
+		type DefaultReward: Get<RewardConfig<BalanceOf<Self>>>;
+
+		/// Maximum reward per block `RewardOrigin` can set
+		#[pallet::constant]
+		type MaxRewardPerBlock: Get<BalanceOf<Self>>;
+
+		/// Accounts the reward of a block author is shared with
+		type RewardShares: RewardShares<Self::AccountId, BalanceOf<Self>>;
+
+		/// Weight information for extrinsics in this pallet
+		type WeightInfo: WeightInfo;
+	}
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	/// Reward set by `RewardOrigin`
+	///
+	/// `DefaultReward` applies while unset.
+	#[pallet::storage]
+	pub type Reward<T: Config> = StorageValue<_, RewardConfig<BalanceOf<T>>, OptionQuery>;
+
+	/// Rewards accrued by collators and the accounts they share them with, not claimed yet
+	#[pallet::storage]
+	pub type PendingRewards<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, BalanceOf<T>, ValueQuery>;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// The reward was changed
+		RewardSet {
+			/// The new reward
+			reward: RewardConfig<BalanceOf<T>>,
+		},
+		/// The author of the block accrued its reward, shared according to `RewardShares`
+		RewardAccrued {
+			/// The block author
+			collator: T::AccountId,
+			/// The reward accrued
+			amount: BalanceOf<T>,
+		},
+		/// An account claimed its accrued rewards
+		RewardsClaimed {
+			/// The collator or the account the reward was shared with
+			collator: T::AccountId,
+			/// The amount paid
+			amount: BalanceOf<T>,
+			/// Where the amount was paid from
+			source: RewardSource,
+		},
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The reward per block is above `MaxRewardPerBlock`
+		RewardTooHigh,
+		/// The caller has no rewards to claim
+		NoRewards,
+		/// The pot cannot pay the rewards
+		InsufficientPot,
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Set the reward accrued by block authors and its source
+		///
+		/// Takes effect from the next block. Rewards accrued before are paid from the new
+		/// source when claimed.
+		///
+		/// # Parameters
+		/// - `origin`: Must be `RewardOrigin`
+		/// - `reward`: The reward per block and its source
+		///
+		/// # Errors
+		/// - `RewardTooHigh`: The reward per block is above `MaxRewardPerBlock`
+		///
+		/// # Events
+		/// - `RewardSet`: Emitted when the reward is changed
+		#[pallet::call_index(0)]
+		#[pallet::weight(T::WeightInfo::set_reward())]
+		pub fn set_reward(
+			origin: OriginFor<T>,
+			reward: RewardConfig<BalanceOf<T>>,
+		) -> DispatchResult {
+			T::RewardOrigin::ensure_origin(origin)?;
+
+			ensure!(reward.per_block <= T::MaxRewardPerBlock::get(), Error::<T>::RewardTooHigh);
+
+			Reward::<T>::put(reward);
+			Self::deposit_event(Event::RewardSet { reward });
+
+			Ok(())
+		}
+
+		/// Claim the rewards accrued by the caller
+		///
+		/// # Parameters
+		/// - `origin`: Must be signed by an account with accrued rewards
+		///
+		/// # Errors
+		/// - `NoRewards`: The caller has no rewards to claim
+		/// - `InsufficientPot`: The rewards are paid from the pot, which cannot pay them
+		///
+		/// # Events
+		/// - `RewardsClaimed`: Emitted with the amount paid
+		#[pallet::call_index(1)]
+		#[pallet::weight(T::WeightInfo::claim_rewards())]
+		pub fn claim_rewards(origin: OriginFor<T>) -> DispatchResult {
+			let collator = ensure_signed(origin)?;
+
+			let amount = PendingRewards::<T>::take(&collator);
+			ensure!(!amount.is_zero(), Error::<T>::NoRewards);
+
+			let source = Self::reward().source;
+			match source {
+				RewardSource::Mint => {
+					T::Currency::mint_into(&collator, amount)?;
+				},
+				RewardSource::Pot => {
+					T::Currency::transfer(
+						&Self::pot_account(),
+						&collator,
+						amount,
+						Preservation::Preserve,
+					)
+					.map_err(|_| Error::<T>::InsufficientPot)?;
+				},
+			}
+			Self::deposit_event(Event::RewardsClaimed { collator, amount, source });
+
+			Ok(())
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// Reward in effect
+		pub fn reward() -> RewardConfig<BalanceOf<T>> {
+			Reward::<T>::get().unwrap_or_else(T::DefaultReward::get)
+		}
+
+		/// Rewards accrued by `collator` and not claimed yet
+		pub fn pending_rewards(collator: &T::AccountId) -> BalanceOf<T> {
+			PendingRewards::<T>::get(collator)
+		}
+
+		/// Account rewards are paid from with [`RewardSource::Pot`]
+		pub fn pot_account() -> T::AccountId {
+			T::PotId::get().into_account_truncating()
+		}
+	}
+
+	/// Shares the reward of a block author with the accounts backing it
+	pub trait RewardShares<AccountId, Balance> {
+		/// Calls `accrue` with every account sharing the `reward` of `author` and its share
+		///
+		/// Whatever is not shared accrues to `author`. Shares must not add up to more than
+		/// `reward`.
+		fn share(author: &AccountId, reward: Balance, accrue: impl FnMut(&AccountId, Balance));
+	}
+
+	impl<AccountId, Balance> RewardShares<AccountId, Balance> for () {
+		fn share(_: &AccountId, _: Balance, _: impl FnMut(&AccountId, Balance)) {}
+	}
+
+	impl<T: Config> pallet_authorship::EventHandler<T::AccountId, BlockNumberFor<T>> for Pallet<T> {
+		fn note_author(author: T::AccountId) {
+			let amount = Self::reward().per_block;
+			if !amount.is_zero() {
+				let mut shared = BalanceOf::<T>::zero();
+				T::RewardShares::share(&author, amount, |who, share| {
+					PendingRewards::<T>::mutate(who, |pending| pending.saturating_accrue(share));
+					shared.saturating_accrue(share);
+				});
+				let own = amount.saturating_sub(shared);
+				PendingRewards::<T>::mutate(&author, |pending| pending.saturating_accrue(own));
+				Self::deposit_event(Event::RewardAccrued { collator: author, amount });
+			}
+
+			frame_system::Pallet::<T>::register_extra_weight_unchecked(
+				T::WeightInfo::note_author(),
+				DispatchClass::Mandatory,
+			);
+		}
+	}
+}
@@ -0,0 +1,67 @@
+use crate as pallet_collator_rewards;
+use frame_support::{derive_impl, parameter_types, traits::ConstU64, PalletId};
+use frame_system::EnsureRoot;
+use pallet_collator_rewards::{RewardConfig, RewardShares, RewardSource};
+use sp_runtime::{traits::IdentityLookup, BuildStorage};
+
+type Block = frame_system::mocking::MockBlock<Test>;
+
+// Configure a mock runtime to test the pallet.
+frame_support::construct_runtime!(
+	pub enum Test
+	{
+		System: frame_system,
+		Balances: pallet_balances,
+		CollatorRewards: pallet_collator_rewards,
+	}
+);
+
+#[derive_impl(frame_system::config_preludes::TestDefaultConfig)]
+impl frame_system::Config for Test {
+	type Block = Block;
+	type AccountId = u64;
+	type Lookup = IdentityLookup<Self::AccountId>;
+	type AccountData = pallet_balances::AccountData<u64>;
+}
+
+#[derive_impl(pallet_balances::config_preludes::TestDefaultConfig)]
+impl pallet_balances::Config for Test {
+	type AccountStore = System;
+}
+
+parameter_types! {
+	pub const RewardPotId: PalletId = PalletId(*b"py/clrwd");
+	pub const DefaultReward: RewardConfig<u64> =
+		RewardConfig { per_block: 10, source: RewardSource::Pot };
+}
+
+/// Collator 3 shares 40% of its reward with account 30 and 10% with account 31
+pub struct BackersOfThree;
+impl RewardShares<u64, u64> for BackersOfThree {
+	fn share(author: &u64, reward: u64, mut accrue: impl FnMut(&u64, u64)) {
+		if *author == 3 {
+			accrue(&30, reward * 4 / 10);
+			accrue(&31, reward / 10);
+		}
+	}
+}
+
+impl pallet_collator_rewards::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type Currency = Balances;
+	type RewardOrigin = EnsureRoot<u64>;
+	type PotId = RewardPotId;
+	type DefaultReward = DefaultReward;
+	type MaxRewardPerBlock = ConstU64<100>;
+	type RewardShares = BackersOfThree;
+	type WeightInfo = ();
+}
+
+// Build genesis storage according to the mock runtime.
+pub fn new_test_ext() -> sp_io::TestExternalities {
+	let storage = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
+
+	let mut ext: sp_io::TestExternalities = storage.into();
+	ext.execute_with(|| System::set_block_number(1));
+	ext
+}
@@ -0,0 +1,121 @@
+use crate::{mock::*, Error, Event, PendingRewards, Reward, RewardConfig, RewardSource};
+use frame_support::{assert_noop, assert_ok, traits::fungible::Mutate};
+use pallet_authorship::EventHandler;
+use sp_runtime::DispatchError;
+
+fn author_block(collator: u64) {
+	<CollatorRewards as EventHandler<u64, u64>>::note_author(collator);
+}
+
+#[test]
+fn reward_defaults_to_config() {
+	new_test_ext().execute_with(|| {
+		assert_eq!(
+			CollatorRewards::reward(),
+			RewardConfig { per_block: 10, source: RewardSource::Pot }
+		);
+	});
+}
+
+#[test]
+fn authors_accrue_rewards() {
+	new_test_ext().execute_with(|| {
+		author_block(1);
+		System::assert_last_event(Event::RewardAccrued { collator: 1, amount: 10 }.into());
+		author_block(1);
+		author_block(2);
+
+		assert_eq!(CollatorRewards::pending_rewards(&1), 20);
+		assert_eq!(CollatorRewards::pending_rewards(&2), 10);
+		assert_eq!(CollatorRewards::pending_rewards(&3), 0);
+	});
+}
+
+#[test]
+fn authors_share_rewards() {
+	new_test_ext().execute_with(|| {
+		author_block(3);
+		System::assert_last_event(Event::RewardAccrued { collator: 3, amount: 10 }.into());
+
+		assert_eq!(CollatorRewards::pending_rewards(&3), 5);
+		assert_eq!(CollatorRewards::pending_rewards(&30), 4);
+		assert_eq!(CollatorRewards::pending_rewards(&31), 1);
+	});
+}
+
+#[test]
+fn set_reward_works() {
+	new_test_ext().execute_with(|| {
+		let reward = RewardConfig { per_block: 0, source: RewardSource::Mint };
+		assert_ok!(CollatorRewards::set_reward(RuntimeOrigin::root(), reward));
+		System::assert_last_event(Event::RewardSet { reward }.into());
+		assert_eq!(Reward::<Test>::get(), Some(reward));
+
+		// A zero reward accrues nothing
+		author_block(1);
+		assert!(!PendingRewards::<Test>::contains_key(1));
+	});
+}
+
+#[test]
+fn set_reward_validates_input() {
+	new_test_ext().execute_with(|| {
+		let reward = RewardConfig { per_block: 100, source: RewardSource::Mint };
+		assert_noop!(
+			CollatorRewards::set_reward(RuntimeOrigin::signed(1), reward),
+			DispatchError::BadOrigin
+		);
+		assert_noop!(
+			CollatorRewards::set_reward(
+				RuntimeOrigin::root(),
+				RewardConfig { per_block: 101, ..reward }
+			),
+			Error::<Test>::RewardTooHigh
+		);
+		assert_ok!(CollatorRewards::set_reward(RuntimeOrigin::root(), reward));
+	});
+}
+
+#[test]
+fn claim_rewards_pays_from_pot() {
+	new_test_ext().execute_with(|| {
+		author_block(1);
+		author_block(1);
+		let pot = CollatorRewards::pot_account();
+
+		// The pot must keep its existential deposit
+		Balances::set_balance(&pot, 20);
+		assert_noop!(
+			CollatorRewards::claim_rewards(RuntimeOrigin::signed(1)),
+			Error::<Test>::InsufficientPot
+		);
+
+		Balances::set_balance(&pot, 21);
+		assert_ok!(CollatorRewards::claim_rewards(RuntimeOrigin::signed(1)));
+		System::assert_last_event(
+			Event::RewardsClaimed { collator: 1, amount: 20, source: RewardSource::Pot }.into(),
+		);
+		assert_eq!(Balances::free_balance(1), 20);
+		assert_eq!(Balances::free_balance(pot), 1);
+		assert_eq!(CollatorRewards::pending_rewards(&1), 0);
+
+		assert_noop!(
+			CollatorRewards::claim_rewards(RuntimeOrigin::signed(1)),
+			Error::<Test>::NoRewards
+		);
+	});
+}
+
+#[test]
+fn claim_rewards_mints() {
+	new_test_ext().execute_with(|| {
+		author_block(1);
+		let issuance = Balances::total_issuance();
+		let reward = RewardConfig { per_block: 10, source: RewardSource::Mint };
+		assert_ok!(CollatorRewards::set_reward(RuntimeOrigin::root(), reward));
+
+		assert_ok!(CollatorRewards::claim_rewards(RuntimeOrigin::signed(1)));
+		assert_eq!(Balances::free_balance(1), 10);
+		assert_eq!(Balances::total_issuance(), issuance + 10);
+	});
+}
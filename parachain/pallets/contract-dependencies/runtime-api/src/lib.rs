@@ -0,0 +1,54 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+//! # Contract Dependencies Runtime API
+//!
+//! Runtime API exposing the dependencies declared between contracts, so that governance
+//! can see which contracts are affected before pausing or deny-listing an address.
+//! Runtimes built without the dependency registry answer with empty lists.
+
+use parity_scale_codec::{Decode, Encode};
+use scale_info::TypeInfo;
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
+use sp_core::H160;
+use sp_runtime::RuntimeDebug;
+use sp_std::vec::Vec;
+
+/// Kind of a dependency
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub enum DependencyKind {
+    /// Library the contract calls or delegates to
+    Library,
+    /// Oracle the contract reads prices or other data from
+    Oracle,
+    /// Any other dependency
+    Other,
+}
+
+/// A contract at the other end of a declared dependency
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct Dependency {
+    /// The contract depended on, or the dependent contract
+    pub contract: H160,
+    /// Kind of the dependency
+    pub kind: DependencyKind,
+}
+
+sp_api::decl_runtime_apis! {
+    /// Runtime API for the contract dependencies pallet
+    pub trait ContractDependenciesApi {
+        /// Contracts `contract` is declared to depend on, in order of declaration
+        fn dependencies(contract: H160) -> Vec<Dependency>;
+
+        /// Contracts declared to depend on `contract`
+        fn dependents(contract: H160) -> Vec<Dependency>;
+
+        /// Contracts depending on `contract` directly or through other contracts, nearest
+        /// first and up to the runtime's limit
+        fn blast_radius(contract: H160) -> Vec<H160>;
+    }
+}
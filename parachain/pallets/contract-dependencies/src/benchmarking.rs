@@ -0,0 +1,63 @@
+//! Benchmarking setup for pallet-contract-dependencies
+
+use super::*;
+
+#[allow(unused)]
+use crate::Pallet as ContractDependencies;
+use frame_benchmarking::v2::*;
+use frame_support::traits::Get;
+use frame_system::RawOrigin;
+use sp_core::H160;
+use sp_runtime::traits::Convert;
+
+/// A freshly deployed contract declaring every other dependency, and the account of its
+/// deployer
+fn with_dependencies<T: Config>() -> (T::AccountId, H160) {
+	let contract = T::BenchmarkHelper::record_deployment();
+	let deployer = T::Provenance::deployer_of(&contract).unwrap();
+	for i in 1..T::MaxDependencies::get() {
+		let dependency = H160::from_low_u64_be(i.into());
+		let kind = DependencyKind::Library;
+		Dependencies::<T>::try_append(contract, Dependency { contract: dependency, kind })
+			.unwrap();
+		Dependents::<T>::insert(dependency, contract, kind);
+	}
+	(T::AddressMapping::convert(deployer), contract)
+}
+
+#[benchmarks]
+mod benchmarks {
+	use super::*;
+
+	#[benchmark]
+	fn declare_dependency() {
+		let (caller, contract) = with_dependencies::<T>();
+		let dependency = H160::repeat_byte(0xdd);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), contract, dependency, DependencyKind::Oracle);
+
+		// Verify the dependency was declared
+		assert!(Dependents::<T>::contains_key(dependency, contract));
+		assert_eq!(Dependencies::<T>::get(contract).len() as u32, T::MaxDependencies::get());
+	}
+
+	#[benchmark]
+	fn remove_dependency() {
+		let (caller, contract) = with_dependencies::<T>();
+		// The last declared dependency, at the end of the list
+		let dependency = H160::from_low_u64_be((T::MaxDependencies::get() - 1).into());
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), contract, dependency);
+
+		// Verify the declaration was withdrawn
+		assert!(!Dependents::<T>::contains_key(dependency, contract));
+	}
+
+	impl_benchmark_test_suite!(
+		ContractDependencies,
+		crate::mock::new_test_ext(),
+		crate::mock::Test
+	);
+}
@@ -0,0 +1,298 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+//! # Contract Dependencies Pallet
+//!
+//! Registry of the dependencies between deployed EVM contracts, e.g. on libraries and
+//! oracles, so that governance can see which contracts are affected before pausing or
+//! deny-listing an address.
+//!
+//! ## Overview
+//!
+//! The recorded deployer of a contract, as reported by the configured
+//! [`ContractProvenance`] source, declares that the contract depends on another with
+//! [`Pallet::declare_dependency`], and withdraws the declaration with
+//! [`Pallet::remove_dependency`]. Up to `MaxDependencies` dependencies are declared per
+//! contract. Declarations are not checked against the code: they are what the deployer
+//! states, and contracts deployed before the registry, or by deployers who never declare
+//! anything, do not show up.
+//!
+//! Every declaration is indexed in reverse, so that the dependents of a contract are
+//! listed without scanning the registry. [`Pallet::blast_radius`] follows the reverse
+//! index transitively, listing the contracts affected when a contract stops working. The
+//! dependencies, dependents and blast radius of a contract are exposed through a runtime
+//! API.
+
+pub use deployment_control_primitives::ContractProvenance;
+pub use pallet::*;
+pub mod weights;
+
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+/// Records the deployment of a contract for benchmarking
+#[cfg(feature = "runtime-benchmarks")]
+pub trait BenchmarkHelper {
+	/// Record the deployment of a contract and return its address
+	fn record_deployment() -> sp_core::H160;
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+	use crate::ContractProvenance;
+	use frame_support::pallet_prelude::*;
+	use frame_system::pallet_prelude::*;
+	use sp_core::H160;
+	use sp_runtime::traits::Convert;
+	use sp_std::{collections::btree_set::BTreeSet, vec::Vec};
+
+	pub use crate::weights::WeightInfo;
+
+	/// Dependencies declared for a contract
+	pub type DependenciesOf<T> = BoundedVec<Dependency, <T as Config>::MaxDependencies>;
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		/// The overarching event type
+		#[allow(deprecated)]
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		/// Source of the recorded deployer of contracts
+		type Provenance: ContractProvenance;
+
+		/// Account controlled by an EVM address
+		type AddressMapping: Convert<H160, Self::AccountId>;
+
+		/// Maximum number of dependencies declared for a contract
+		#[pallet::constant]
+		type MaxDependencies: Get<u32>;
+
+		/// Maximum number of contracts listed by [`Pallet::blast_radius`]
+		#[pallet::constant]
+		type MaxBlastRadius: Get<u32>;
+
+		/// Weight information for extrinsics in this pallet
+		type WeightInfo: WeightInfo;
+
+		/// Records contract deployments for benchmarking
+		#[cfg(feature = "runtime-benchmarks")]
+		type BenchmarkHelper: crate::BenchmarkHelper;
+	}
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	/// Kind of a dependency
+	#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub enum DependencyKind {
+		/// Library the contract calls or delegates to
+		Library,
+		/// Oracle the contract reads prices or other data from
+		Oracle,
+		/// Any other dependency
+		Other,
+	}
+
+	/// A dependency declared for a contract
+	#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub struct Dependency {
+		/// The contract depended on
+		pub contract: H160,
+		/// Kind of the dependency
+		pub kind: DependencyKind,
+	}
+
+	/// Dependencies declared for a contract, in order of declaration
+	#[pallet::storage]
+	pub type Dependencies<T: Config> =
+		StorageMap<_, Blake2_128Concat, H160, DependenciesOf<T>, ValueQuery>;
+
+	/// Contracts declaring a dependency on a contract, keyed by the contract depended on
+	#[pallet::storage]
+	pub type Dependents<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		H160,
+		Blake2_128Concat,
+		H160,
+		DependencyKind,
+		OptionQuery,
+	>;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// A contract was declared to depend on another
+		DependencyDeclared {
+			/// The dependent contract
+			contract: H160,
+			/// The contract depended on
+			dependency: H160,
+			/// Kind of the dependency
+			kind: DependencyKind,
+		},
+		/// A dependency declaration was withdrawn
+		DependencyRemoved {
+			/// The dependent contract
+			contract: H160,
+			/// The contract no longer depended on
+			dependency: H160,
+		},
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The caller is not the recorded deployer of the contract
+		NotDeployer,
+		/// A contract cannot depend on itself
+		SelfDependency,
+		/// The dependency is already declared
+		AlreadyDeclared,
+		/// The dependency is not declared
+		UnknownDependency,
+		/// `MaxDependencies` dependencies are declared for the contract
+		TooManyDependencies,
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Declare that a contract deployed by the caller depends on another contract
+		///
+		/// # Parameters
+		/// - `origin`: Must be signed by the account of the contract's recorded deployer
+		/// - `contract`: The dependent contract
+		/// - `dependency`: The contract depended on
+		/// - `kind`: Kind of the dependency
+		///
+		/// # Errors
+		/// - `NotDeployer`: The caller did not deploy the contract
+		/// - `SelfDependency`: `dependency` is the contract itself
+		/// - `AlreadyDeclared`: The dependency is already declared
+		/// - `TooManyDependencies`: `MaxDependencies` dependencies are declared
+		///
+		/// # Events
+		/// - `DependencyDeclared`: Emitted when the dependency is declared
+		#[pallet::call_index(0)]
+		#[pallet::weight(T::WeightInfo::declare_dependency())]
+		pub fn declare_dependency(
+			origin: OriginFor<T>,
+			contract: H160,
+			dependency: H160,
+			kind: DependencyKind,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			Self::ensure_deployer(&who, &contract)?;
+			ensure!(contract != dependency, Error::<T>::SelfDependency);
+			ensure!(
+				!Dependents::<T>::contains_key(dependency, contract),
+				Error::<T>::AlreadyDeclared
+			);
+
+			Dependencies::<T>::try_mutate(contract, |dependencies| {
+				dependencies
+					.try_push(Dependency { contract: dependency, kind })
+					.map_err(|_| Error::<T>::TooManyDependencies)
+			})?;
+			Dependents::<T>::insert(dependency, contract, kind);
+			Self::deposit_event(Event::DependencyDeclared { contract, dependency, kind });
+
+			Ok(())
+		}
+
+		/// Withdraw the declaration that a contract deployed by the caller depends on another
+		///
+		/// # Parameters
+		/// - `origin`: Must be signed by the account of the contract's recorded deployer
+		/// - `contract`: The dependent contract
+		/// - `dependency`: The contract no longer depended on
+		///
+		/// # Errors
+		/// - `NotDeployer`: The caller did not deploy the contract
+		/// - `UnknownDependency`: The dependency is not declared
+		///
+		/// # Events
+		/// - `DependencyRemoved`: Emitted when the declaration is withdrawn
+		#[pallet::call_index(1)]
+		#[pallet::weight(T::WeightInfo::remove_dependency())]
+		pub fn remove_dependency(
+			origin: OriginFor<T>,
+			contract: H160,
+			dependency: H160,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			Self::ensure_deployer(&who, &contract)?;
+			ensure!(
+				Dependents::<T>::contains_key(dependency, contract),
+				Error::<T>::UnknownDependency
+			);
+
+			Dependencies::<T>::mutate_exists(contract, |dependencies| {
+				if let Some(list) = dependencies {
+					list.retain(|declared| declared.contract != dependency);
+					if list.is_empty() {
+						*dependencies = None;
+					}
+				}
+			});
+			Dependents::<T>::remove(dependency, contract);
+			Self::deposit_event(Event::DependencyRemoved { contract, dependency });
+
+			Ok(())
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// Dependencies declared for `contract`, in order of declaration
+		pub fn dependencies(contract: &H160) -> Vec<Dependency> {
+			Dependencies::<T>::get(contract).into_inner()
+		}
+
+		/// Contracts declaring a dependency on `contract`, with the kind of their dependency
+		pub fn dependents(contract: &H160) -> Vec<Dependency> {
+			Dependents::<T>::iter_prefix(contract)
+				.map(|(dependent, kind)| Dependency { contract: dependent, kind })
+				.collect()
+		}
+
+		/// Contracts depending on `contract` directly or through other contracts
+		///
+		/// Nearest dependents first, up to `MaxBlastRadius` contracts. Dependency cycles are
+		/// followed once, and `contract` itself is never listed.
+		pub fn blast_radius(contract: &H160) -> Vec<H160> {
+			let limit = T::MaxBlastRadius::get() as usize;
+			let mut seen = BTreeSet::from([*contract]);
+			let mut affected = Vec::new();
+			let mut next = 0;
+			let mut current = *contract;
+
+			loop {
+				for dependent in Dependents::<T>::iter_key_prefix(current) {
+					if affected.len() == limit {
+						return affected;
+					}
+					if seen.insert(dependent) {
+						affected.push(dependent);
+					}
+				}
+				match affected.get(next) {
+					Some(dependent) => current = *dependent,
+					None => return affected,
+				}
+				next += 1;
+			}
+		}
+
+		fn ensure_deployer(who: &T::AccountId, contract: &H160) -> DispatchResult {
+			let deployer = T::Provenance::deployer_of(contract).ok_or(Error::<T>::NotDeployer)?;
+			ensure!(T::AddressMapping::convert(deployer) == *who, Error::<T>::NotDeployer);
+			Ok(())
+		}
+	}
+}
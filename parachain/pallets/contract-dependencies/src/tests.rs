@@ -0,0 +1,152 @@
+use crate::{mock::*, Dependencies, Dependency, DependencyKind, Dependents, Error, Event};
+use frame_support::{assert_noop, assert_ok};
+use sp_core::H160;
+
+const VAULT: H160 = H160::repeat_byte(0x22);
+const ROUTER: H160 = H160::repeat_byte(0x33);
+const ORACLE: H160 = H160::repeat_byte(0x44);
+const MATH: H160 = H160::repeat_byte(0x55);
+
+fn declare(deployer: u64, contract: H160, dependency: H160, kind: DependencyKind) {
+	assert_ok!(ContractDependencies::declare_dependency(
+		RuntimeOrigin::signed(deployer),
+		contract,
+		dependency,
+		kind
+	));
+}
+
+#[test]
+fn deployer_declares_dependencies() {
+	new_test_ext().execute_with(|| {
+		deploy(H160::from_low_u64_be(1), VAULT);
+
+		declare(1, VAULT, ORACLE, DependencyKind::Oracle);
+		System::assert_last_event(
+			Event::DependencyDeclared {
+				contract: VAULT,
+				dependency: ORACLE,
+				kind: DependencyKind::Oracle,
+			}
+			.into(),
+		);
+		declare(1, VAULT, MATH, DependencyKind::Library);
+
+		assert_eq!(
+			ContractDependencies::dependencies(&VAULT),
+			vec![
+				Dependency { contract: ORACLE, kind: DependencyKind::Oracle },
+				Dependency { contract: MATH, kind: DependencyKind::Library },
+			]
+		);
+		assert_eq!(
+			ContractDependencies::dependents(&ORACLE),
+			vec![Dependency { contract: VAULT, kind: DependencyKind::Oracle }]
+		);
+	});
+}
+
+#[test]
+fn only_deployer_can_declare() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			ContractDependencies::declare_dependency(
+				RuntimeOrigin::signed(1),
+				VAULT,
+				ORACLE,
+				DependencyKind::Oracle
+			),
+			Error::<Test>::NotDeployer
+		);
+
+		deploy(H160::from_low_u64_be(2), VAULT);
+		assert_noop!(
+			ContractDependencies::declare_dependency(
+				RuntimeOrigin::signed(1),
+				VAULT,
+				ORACLE,
+				DependencyKind::Oracle
+			),
+			Error::<Test>::NotDeployer
+		);
+	});
+}
+
+#[test]
+fn declarations_are_checked() {
+	new_test_ext().execute_with(|| {
+		deploy(H160::from_low_u64_be(1), VAULT);
+		let declare_on = |dependency| {
+			ContractDependencies::declare_dependency(
+				RuntimeOrigin::signed(1),
+				VAULT,
+				dependency,
+				DependencyKind::Other,
+			)
+		};
+
+		assert_noop!(declare_on(VAULT), Error::<Test>::SelfDependency);
+		assert_ok!(declare_on(ORACLE));
+		assert_noop!(declare_on(ORACLE), Error::<Test>::AlreadyDeclared);
+		assert_ok!(declare_on(MATH));
+		assert_noop!(declare_on(ROUTER), Error::<Test>::TooManyDependencies);
+	});
+}
+
+#[test]
+fn deployer_removes_dependencies() {
+	new_test_ext().execute_with(|| {
+		deploy(H160::from_low_u64_be(1), VAULT);
+		declare(1, VAULT, ORACLE, DependencyKind::Oracle);
+
+		assert_noop!(
+			ContractDependencies::remove_dependency(RuntimeOrigin::signed(2), VAULT, ORACLE),
+			Error::<Test>::NotDeployer
+		);
+		assert_noop!(
+			ContractDependencies::remove_dependency(RuntimeOrigin::signed(1), VAULT, MATH),
+			Error::<Test>::UnknownDependency
+		);
+
+		assert_ok!(ContractDependencies::remove_dependency(
+			RuntimeOrigin::signed(1),
+			VAULT,
+			ORACLE
+		));
+		System::assert_last_event(
+			Event::DependencyRemoved { contract: VAULT, dependency: ORACLE }.into(),
+		);
+		assert!(!Dependencies::<Test>::contains_key(VAULT));
+		assert!(!Dependents::<Test>::contains_key(ORACLE, VAULT));
+	});
+}
+
+#[test]
+fn blast_radius_follows_dependents_transitively() {
+	new_test_ext().execute_with(|| {
+		let contract = |byte: u8| H160::repeat_byte(byte);
+		for byte in [0x22, 0x33, 0x44, 0x55, 0x66, 0x77, 0x88] {
+			deploy(H160::from_low_u64_be(1), contract(byte));
+		}
+		// The router and the math library read the oracle, the vault uses both, and the
+		// oracle settles through the vault
+		declare(1, ROUTER, ORACLE, DependencyKind::Oracle);
+		declare(1, MATH, ORACLE, DependencyKind::Oracle);
+		declare(1, VAULT, ROUTER, DependencyKind::Other);
+		declare(1, VAULT, MATH, DependencyKind::Library);
+		declare(1, ORACLE, VAULT, DependencyKind::Other);
+
+		let radius = ContractDependencies::blast_radius(&ORACLE);
+		assert_eq!(radius.len(), 3);
+		assert!(radius[..2].contains(&ROUTER) && radius[..2].contains(&MATH));
+		assert_eq!(radius[2], VAULT);
+		assert_eq!(ContractDependencies::blast_radius(&VAULT)[0], ORACLE);
+
+		// Capped at `MaxBlastRadius`
+		for byte in [0x66, 0x77, 0x88] {
+			declare(1, contract(byte), ROUTER, DependencyKind::Library);
+		}
+		assert_eq!(ContractDependencies::blast_radius(&ORACLE).len(), 4);
+		assert_eq!(ContractDependencies::blast_radius(&ROUTER).len(), 4);
+	});
+}
@@ -0,0 +1,71 @@
+//! Placeholder weights for `pallet_contract_dependencies`
+//!
+//! NOT MEASURED: the pallet has not been benchmarked yet. The storage accesses and proof
+//! sizes follow the code, while the execution times are hand estimates doubled as a safety
+//! margin, so that blocks are underfilled rather than overfilled until they are measured.
+//! Replace this file with the output of:
+//!
+//! ./target/release/evm-template-node benchmark pallet --chain dev \
+//!     --pallet pallet_contract_dependencies --extrinsic '*' --steps 50 --repeat 20 \
+//!     --output pallets/contract-dependencies/src/weights.rs
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+
+use frame_support::{traits::Get, weights::Weight};
+use core::marker::PhantomData;
+
+/// Weight functions needed for pallet_contract_dependencies.
+pub trait WeightInfo {
+	fn declare_dependency() -> Weight;
+	fn remove_dependency() -> Weight;
+}
+
+/// Weights for pallet_contract_dependencies using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	/// Storage: `EvmDeploymentControl::ContractProvenance` (r:1 w:0)
+	/// Proof: `EvmDeploymentControl::ContractProvenance` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
+	/// Storage: `ContractDependencies::Dependents` (r:1 w:1)
+	/// Proof: `ContractDependencies::Dependents` (`max_values`: None, `max_size`: Some(73), added: 2548, mode: `MaxEncodedLen`)
+	/// Storage: `ContractDependencies::Dependencies` (r:1 w:1)
+	/// Proof: `ContractDependencies::Dependencies` (`max_values`: None, `max_size`: Some(373), added: 2848, mode: `MaxEncodedLen`)
+	fn declare_dependency() -> Weight {
+		// Proof Size estimate in bytes:
+		//  Estimated: `3838`
+		Weight::from_parts(51_724_000, 0)
+			.saturating_add(Weight::from_parts(0, 3838))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: `EvmDeploymentControl::ContractProvenance` (r:1 w:0)
+	/// Proof: `EvmDeploymentControl::ContractProvenance` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
+	/// Storage: `ContractDependencies::Dependents` (r:1 w:1)
+	/// Proof: `ContractDependencies::Dependents` (`max_values`: None, `max_size`: Some(73), added: 2548, mode: `MaxEncodedLen`)
+	/// Storage: `ContractDependencies::Dependencies` (r:1 w:1)
+	/// Proof: `ContractDependencies::Dependencies` (`max_values`: None, `max_size`: Some(373), added: 2848, mode: `MaxEncodedLen`)
+	fn remove_dependency() -> Weight {
+		// Proof Size estimate in bytes:
+		//  Estimated: `3838`
+		Weight::from_parts(52_618_000, 0)
+			.saturating_add(Weight::from_parts(0, 3838))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	fn declare_dependency() -> Weight {
+		Weight::from_parts(51_724_000, 3838)
+			.saturating_add(frame_support::weights::constants::RocksDbWeight::get().reads(3))
+			.saturating_add(frame_support::weights::constants::RocksDbWeight::get().writes(2))
+	}
+	fn remove_dependency() -> Weight {
+		Weight::from_parts(52_618_000, 3838)
+			.saturating_add(frame_support::weights::constants::RocksDbWeight::get().reads(3))
+			.saturating_add(frame_support::weights::constants::RocksDbWeight::get().writes(2))
+	}
+}
@@ -0,0 +1,75 @@
+//! Benchmarking setup for pallet-contract-names
+
+use super::*;
+
+#[allow(unused)]
+use crate::Pallet as ContractNames;
+use frame_benchmarking::v2::*;
+use frame_support::traits::Get;
+use frame_system::RawOrigin;
+use sp_core::H160;
+use sp_runtime::traits::Convert;
+use sp_std::vec;
+
+/// A name of maximum length
+fn name<T: Config>() -> NameOf<T> {
+	vec![b'q'; T::MaxNameLen::get() as usize].try_into().unwrap()
+}
+
+/// A freshly deployed contract and the account of its deployer
+fn deployment<T: Config>() -> (T::AccountId, H160) {
+	let target = T::BenchmarkHelper::record_deployment();
+	let deployer = T::Provenance::deployer_of(&target).unwrap();
+	(T::AddressMapping::convert(deployer), target)
+}
+
+/// Claim a name of maximum length on behalf of `owner`
+fn claimed<T: Config>(owner: &T::AccountId) -> NameOf<T> {
+	let (_, target) = deployment::<T>();
+	let claimed_at = frame_system::Pallet::<T>::block_number();
+	Names::<T>::insert(name::<T>(), NameRecord { owner: owner.clone(), target, claimed_at });
+	name::<T>()
+}
+
+#[benchmarks]
+mod benchmarks {
+	use super::*;
+
+	#[benchmark]
+	fn claim() {
+		let (caller, target) = deployment::<T>();
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), name::<T>(), target);
+
+		// Verify the name resolves to the contract
+		assert_eq!(ContractNames::<T>::resolve(&name::<T>()), Some(target));
+	}
+
+	#[benchmark]
+	fn transfer() {
+		let caller: T::AccountId = whitelisted_caller();
+		let new_owner: T::AccountId = account("owner", 0, 0);
+		let name = claimed::<T>(&caller);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), name.clone(), new_owner.clone());
+
+		// Verify the name changed owner
+		assert_eq!(Names::<T>::get(&name).map(|record| record.owner), Some(new_owner));
+	}
+
+	#[benchmark]
+	fn release() {
+		let caller: T::AccountId = whitelisted_caller();
+		let name = claimed::<T>(&caller);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), name.clone());
+
+		// Verify the name was released
+		assert!(!Names::<T>::contains_key(&name));
+	}
+
+	impl_benchmark_test_suite!(ContractNames, crate::mock::new_test_ext(), crate::mock::Test);
+}
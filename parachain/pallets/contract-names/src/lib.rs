@@ -0,0 +1,254 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+//! # Contract Names Pallet
+//!
+//! Human-readable names for deployed EVM contracts, serving as the on-chain service
+//! discovery layer.
+//!
+//! ## Overview
+//!
+//! A name such as `qnch.amm.router.v2` maps to a contract address. A name can only be
+//! claimed for a contract by the recorded deployer of that contract, as reported by the
+//! configured [`ContractProvenance`] source. The owner of a name can transfer it to
+//! another account or release it.
+//!
+//! Names are made of dot-separated, non-empty labels of lowercase ASCII letters, digits
+//! and hyphens.
+//!
+//! The runtime exposes name resolution to contracts through a precompile.
+
+pub use deployment_control_primitives::ContractProvenance;
+pub use pallet::*;
+pub mod weights;
+
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+/// Records the deployment of a contract for benchmarking
+#[cfg(feature = "runtime-benchmarks")]
+pub trait BenchmarkHelper {
+    /// Record the deployment of a contract and return its address
+    fn record_deployment() -> sp_core::H160;
+}
+
+/// Check that a name is made of dot-separated labels of `[a-z0-9-]`
+pub fn is_valid_name(name: &[u8]) -> bool {
+    name.split(|byte| *byte == b'.').all(|label| {
+        !label.is_empty() &&
+            label.iter().all(|byte| matches!(byte, b'a'..=b'z' | b'0'..=b'9' | b'-'))
+    })
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+	use crate::ContractProvenance;
+	use frame_support::pallet_prelude::*;
+	use frame_system::pallet_prelude::*;
+	use sp_core::H160;
+	use sp_runtime::traits::Convert;
+
+	pub use crate::weights::WeightInfo;
+
+	/// A contract name
+	pub type NameOf<T> = BoundedVec<u8, <T as Config>::MaxNameLen>;
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		/// The overarching event type
+		#[allow(deprecated)]
+		type RuntimeEvent: From<Event<Self>>
+			+ IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		/// Source of the recorded deployer of contracts
+		type Provenance: ContractProvenance;
+
+		/// Account controlled by an EVM address
+		type AddressMapping: Convert<H160, Self::AccountId>;
+
+		/// Maximum length of a name in bytes
+		#[pallet::constant]
+		type MaxNameLen: Get<u32>;
+
+		/// Weight information for extrinsics in this pallet
+		type WeightInfo: WeightInfo;
+
+		/// Records contract deployments for benchmarking
+		#[cfg(feature = "runtime-benchmarks")]
+		type BenchmarkHelper: crate::BenchmarkHelper;
+	}
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	/// Record of a claimed name
+	#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub struct NameRecord<AccountId, BlockNumber> {
+		/// Account owning the name
+		pub owner: AccountId,
+		/// Contract the name resolves to
+		pub target: H160,
+		/// Block at which the name was claimed
+		pub claimed_at: BlockNumber,
+	}
+
+	/// Claimed names and the contracts they resolve to
+	#[pallet::storage]
+	pub type Names<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		NameOf<T>,
+		NameRecord<T::AccountId, BlockNumberFor<T>>,
+		OptionQuery,
+	>;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// A name was claimed for a contract
+		NameClaimed {
+			/// The claimed name
+			name: NameOf<T>,
+			/// The owner of the name
+			owner: T::AccountId,
+			/// The contract the name resolves to
+			target: H160,
+		},
+		/// A name was transferred to a new owner
+		NameTransferred {
+			/// The transferred name
+			name: NameOf<T>,
+			/// The new owner
+			owner: T::AccountId,
+		},
+		/// A name was released
+		NameReleased {
+			/// The released name
+			name: NameOf<T>,
+		},
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The name contains characters other than `[a-z0-9-.]` or an empty label
+		InvalidName,
+		/// The name is already claimed
+		NameTaken,
+		/// The name is not claimed
+		UnknownName,
+		/// The caller does not own the name
+		NotOwner,
+		/// The caller is not the recorded deployer of the target contract
+		NotDeployer,
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Claim a name for a contract deployed by the caller
+		///
+		/// # Parameters
+		/// - `origin`: Must be signed by the account of the contract's recorded deployer
+		/// - `name`: The name to claim
+		/// - `target`: The contract the name resolves to
+		///
+		/// # Errors
+		/// - `InvalidName`: The name is not well formed
+		/// - `NameTaken`: The name is already claimed
+		/// - `NotDeployer`: The caller did not deploy the target contract
+		///
+		/// # Events
+		/// - `NameClaimed`: Emitted when the name is claimed
+		#[pallet::call_index(0)]
+		#[pallet::weight(T::WeightInfo::claim())]
+		pub fn claim(origin: OriginFor<T>, name: NameOf<T>, target: H160) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			ensure!(crate::is_valid_name(&name), Error::<T>::InvalidName);
+			ensure!(!Names::<T>::contains_key(&name), Error::<T>::NameTaken);
+
+			let deployer = T::Provenance::deployer_of(&target).ok_or(Error::<T>::NotDeployer)?;
+			ensure!(T::AddressMapping::convert(deployer) == who, Error::<T>::NotDeployer);
+
+			let claimed_at = frame_system::Pallet::<T>::block_number();
+			Names::<T>::insert(&name, NameRecord { owner: who.clone(), target, claimed_at });
+			Self::deposit_event(Event::NameClaimed { name, owner: who, target });
+
+			Ok(())
+		}
+
+		/// Transfer a name to another account
+		///
+		/// # Parameters
+		/// - `origin`: Must be signed by the owner of the name
+		/// - `name`: The name to transfer
+		/// - `new_owner`: The account receiving the name
+		///
+		/// # Errors
+		/// - `UnknownName`: The name is not claimed
+		/// - `NotOwner`: The caller does not own the name
+		///
+		/// # Events
+		/// - `NameTransferred`: Emitted when the name changes owner
+		#[pallet::call_index(1)]
+		#[pallet::weight(T::WeightInfo::transfer())]
+		pub fn transfer(
+			origin: OriginFor<T>,
+			name: NameOf<T>,
+			new_owner: T::AccountId,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			Names::<T>::try_mutate(&name, |record| {
+				let record = record.as_mut().ok_or(Error::<T>::UnknownName)?;
+				ensure!(record.owner == who, Error::<T>::NotOwner);
+				record.owner = new_owner.clone();
+				Ok::<_, Error<T>>(())
+			})?;
+			Self::deposit_event(Event::NameTransferred { name, owner: new_owner });
+
+			Ok(())
+		}
+
+		/// Release a name, making it claimable again
+		///
+		/// # Parameters
+		/// - `origin`: Must be signed by the owner of the name
+		/// - `name`: The name to release
+		///
+		/// # Errors
+		/// - `UnknownName`: The name is not claimed
+		/// - `NotOwner`: The caller does not own the name
+		///
+		/// # Events
+		/// - `NameReleased`: Emitted when the name is released
+		#[pallet::call_index(2)]
+		#[pallet::weight(T::WeightInfo::release())]
+		pub fn release(origin: OriginFor<T>, name: NameOf<T>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let record = Names::<T>::get(&name).ok_or(Error::<T>::UnknownName)?;
+			ensure!(record.owner == who, Error::<T>::NotOwner);
+
+			Names::<T>::remove(&name);
+			Self::deposit_event(Event::NameReleased { name });
+
+			Ok(())
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// Contract a name resolves to
+		///
+		/// # Returns
+		/// `None` if the name is not claimed or longer than `MaxNameLen`
+		pub fn resolve(name: &[u8]) -> Option<H160> {
+			let name = NameOf::<T>::try_from(name.to_vec()).ok()?;
+			Names::<T>::get(name).map(|record| record.target)
+		}
+	}
+}
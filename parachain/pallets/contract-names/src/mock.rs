@@ -0,0 +1,63 @@
+use crate as pallet_contract_names;
+pub use deployment_control_primitives::test_utils::{deploy, MockProvenance};
+use frame_support::{derive_impl, traits::ConstU32};
+use sp_core::H160;
+use sp_runtime::{
+	traits::{Convert, IdentityLookup},
+	BuildStorage,
+};
+
+type Block = frame_system::mocking::MockBlock<Test>;
+
+// Configure a mock runtime to test the pallet.
+frame_support::construct_runtime!(
+	pub enum Test
+	{
+		System: frame_system,
+		ContractNames: pallet_contract_names,
+	}
+);
+
+#[derive_impl(frame_system::config_preludes::TestDefaultConfig)]
+impl frame_system::Config for Test {
+	type Block = Block;
+	type AccountId = u64;
+	type Lookup = IdentityLookup<Self::AccountId>;
+}
+
+/// Maps EVM address `0x00..0N` to account `N`
+pub struct LowBytes;
+
+impl Convert<H160, u64> for LowBytes {
+	fn convert(address: H160) -> u64 {
+		address.to_low_u64_be()
+	}
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+impl pallet_contract_names::BenchmarkHelper for MockProvenance {
+	fn record_deployment() -> H160 {
+		let contract = H160::repeat_byte(0xcc);
+		deploy(H160::from_low_u64_be(1), contract);
+		contract
+	}
+}
+
+impl pallet_contract_names::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type Provenance = MockProvenance;
+	type AddressMapping = LowBytes;
+	type MaxNameLen = ConstU32<32>;
+	type WeightInfo = ();
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = MockProvenance;
+}
+
+// Build genesis storage according to the mock runtime.
+pub fn new_test_ext() -> sp_io::TestExternalities {
+	let storage = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
+
+	let mut ext: sp_io::TestExternalities = storage.into();
+	ext.execute_with(|| System::set_block_number(1));
+	ext
+}
@@ -0,0 +1,116 @@
+use crate::{is_valid_name, mock::*, Error, Event, NameOf, NameRecord, Names};
+use frame_support::{assert_noop, assert_ok};
+use sp_core::H160;
+
+const ROUTER: H160 = H160::repeat_byte(0x22);
+
+fn name(name: &[u8]) -> NameOf<Test> {
+	name.to_vec().try_into().unwrap()
+}
+
+#[test]
+fn name_validation() {
+	assert!(is_valid_name(b"qnch.amm.router.v2"));
+	assert!(is_valid_name(b"my-token"));
+	assert!(!is_valid_name(b""));
+	assert!(!is_valid_name(b"qnch..router"));
+	assert!(!is_valid_name(b"qnch.router."));
+	assert!(!is_valid_name(b"Qnch.Router"));
+	assert!(!is_valid_name(b"qnch router"));
+}
+
+#[test]
+fn deployer_claims_name() {
+	new_test_ext().execute_with(|| {
+		deploy(H160::from_low_u64_be(1), ROUTER);
+		let router = name(b"qnch.amm.router.v2");
+
+		assert_ok!(ContractNames::claim(RuntimeOrigin::signed(1), router.clone(), ROUTER));
+		System::assert_last_event(
+			Event::NameClaimed { name: router.clone(), owner: 1, target: ROUTER }.into(),
+		);
+
+		assert_eq!(ContractNames::resolve(b"qnch.amm.router.v2"), Some(ROUTER));
+		assert_eq!(
+			Names::<Test>::get(router),
+			Some(NameRecord { owner: 1, target: ROUTER, claimed_at: 1 })
+		);
+	});
+}
+
+#[test]
+fn only_deployer_can_claim() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			ContractNames::claim(RuntimeOrigin::signed(1), name(b"router"), ROUTER),
+			Error::<Test>::NotDeployer
+		);
+
+		deploy(H160::from_low_u64_be(2), ROUTER);
+		assert_noop!(
+			ContractNames::claim(RuntimeOrigin::signed(1), name(b"router"), ROUTER),
+			Error::<Test>::NotDeployer
+		);
+	});
+}
+
+#[test]
+fn claim_rejects_invalid_and_taken_names() {
+	new_test_ext().execute_with(|| {
+		deploy(H160::from_low_u64_be(1), ROUTER);
+
+		assert_noop!(
+			ContractNames::claim(RuntimeOrigin::signed(1), name(b"Router"), ROUTER),
+			Error::<Test>::InvalidName
+		);
+
+		assert_ok!(ContractNames::claim(RuntimeOrigin::signed(1), name(b"router"), ROUTER));
+		assert_noop!(
+			ContractNames::claim(RuntimeOrigin::signed(1), name(b"router"), ROUTER),
+			Error::<Test>::NameTaken
+		);
+	});
+}
+
+#[test]
+fn owner_transfers_name() {
+	new_test_ext().execute_with(|| {
+		deploy(H160::from_low_u64_be(1), ROUTER);
+		assert_ok!(ContractNames::claim(RuntimeOrigin::signed(1), name(b"router"), ROUTER));
+
+		assert_noop!(
+			ContractNames::transfer(RuntimeOrigin::signed(2), name(b"router"), 2),
+			Error::<Test>::NotOwner
+		);
+		assert_noop!(
+			ContractNames::transfer(RuntimeOrigin::signed(1), name(b"unknown"), 2),
+			Error::<Test>::UnknownName
+		);
+
+		assert_ok!(ContractNames::transfer(RuntimeOrigin::signed(1), name(b"router"), 2));
+		System::assert_last_event(
+			Event::NameTransferred { name: name(b"router"), owner: 2 }.into(),
+		);
+
+		// The new owner can release the name, the previous one no longer can
+		assert_noop!(
+			ContractNames::release(RuntimeOrigin::signed(1), name(b"router")),
+			Error::<Test>::NotOwner
+		);
+		assert_ok!(ContractNames::release(RuntimeOrigin::signed(2), name(b"router")));
+	});
+}
+
+#[test]
+fn released_name_can_be_claimed_again() {
+	new_test_ext().execute_with(|| {
+		deploy(H160::from_low_u64_be(1), ROUTER);
+		assert_ok!(ContractNames::claim(RuntimeOrigin::signed(1), name(b"router"), ROUTER));
+
+		assert_ok!(ContractNames::release(RuntimeOrigin::signed(1), name(b"router")));
+		System::assert_last_event(Event::NameReleased { name: name(b"router") }.into());
+		assert_eq!(ContractNames::resolve(b"router"), None);
+
+		assert_ok!(ContractNames::claim(RuntimeOrigin::signed(1), name(b"router"), ROUTER));
+	});
+}
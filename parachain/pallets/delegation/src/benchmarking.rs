@@ -0,0 +1,139 @@
+//! Benchmarking setup for pallet-delegation
+
+use super::*;
+
+#[allow(unused)]
+use crate::Pallet as Delegation;
+use frame_benchmarking::v2::*;
+use frame_support::traits::{
+	fungible::{Inspect, Mutate},
+	EnsureOrigin, Get,
+};
+use frame_system::{pallet_prelude::BlockNumberFor, RawOrigin};
+use pallet_collator_rewards::RewardShares;
+use sp_runtime::{
+	traits::{Saturating, Zero},
+	Perbill,
+};
+
+/// Registers a candidate and `n` delegators each delegating `MinDelegation` and
+/// unbonding as many chunks as allowed, returning the candidate
+fn candidate_with_delegators<T: Config>(n: u32) -> T::AccountId {
+	let candidate: T::AccountId = account("candidate", 0, 0);
+	T::Candidates::add_candidate(&candidate, T::MinDelegation::get());
+	for i in 0..n {
+		let delegator: T::AccountId = account("delegator", i, 0);
+		delegate_to::<T>(&delegator, &candidate);
+		for _ in 0..T::MaxUnbondingChunks::get() {
+			Delegation::<T>::undelegate(
+				RawOrigin::Signed(delegator.clone()).into(),
+				candidate.clone(),
+				T::MinDelegation::get(),
+			)
+			.unwrap();
+			frame_system::Pallet::<T>::set_block_number(
+				frame_system::Pallet::<T>::block_number().saturating_add(1u32.into()),
+			);
+		}
+	}
+	candidate
+}
+
+/// Funds `delegator` and delegates `MinDelegation` to `candidate` for every unbonding
+/// chunk, plus `MinDelegation` left delegated
+fn delegate_to<T: Config>(delegator: &T::AccountId, candidate: &T::AccountId) {
+	let chunks: BalanceOf<T> = T::MaxUnbondingChunks::get().into();
+	let amount = T::MinDelegation::get().saturating_mul(chunks.saturating_add(1u32.into()));
+	T::Currency::set_balance(
+		delegator,
+		T::Currency::minimum_balance().saturating_add(amount.saturating_mul(2u32.into())),
+	);
+	Delegation::<T>::delegate(
+		RawOrigin::Signed(delegator.clone()).into(),
+		candidate.clone(),
+		amount,
+	)
+	.unwrap();
+}
+
+#[benchmarks]
+mod benchmarks {
+	use super::*;
+
+	#[benchmark]
+	fn delegate() {
+		let candidate = candidate_with_delegators::<T>(0);
+		let delegator: T::AccountId = whitelisted_caller();
+		let amount = T::MinDelegation::get();
+		T::Currency::set_balance(&delegator, T::Currency::minimum_balance().saturating_add(amount));
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(delegator.clone()), candidate.clone(), amount);
+
+		// Verify the delegation was stored
+		assert_eq!(Delegation::<T>::delegated(&candidate, &delegator), amount);
+	}
+
+	#[benchmark]
+	fn undelegate() {
+		let candidate = candidate_with_delegators::<T>(0);
+		let delegator: T::AccountId = whitelisted_caller();
+		delegate_to::<T>(&delegator, &candidate);
+		let delegated = Delegation::<T>::delegated(&candidate, &delegator);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(delegator.clone()), candidate.clone(), delegated);
+
+		// Verify the whole delegation is unbonding
+		assert!(Delegation::<T>::delegated(&candidate, &delegator).is_zero());
+	}
+
+	#[benchmark]
+	fn withdraw_unbonded() {
+		let candidate = candidate_with_delegators::<T>(0);
+		let delegator: T::AccountId = whitelisted_caller();
+		delegate_to::<T>(&delegator, &candidate);
+		let delegated = Delegation::<T>::delegated(&candidate, &delegator);
+		Delegation::<T>::undelegate(
+			RawOrigin::Signed(delegator.clone()).into(),
+			candidate.clone(),
+			delegated,
+		)
+		.unwrap();
+		let unlocks_at: BlockNumberFor<T> =
+			frame_system::Pallet::<T>::block_number().saturating_add(T::UnbondingPeriod::get());
+		frame_system::Pallet::<T>::set_block_number(unlocks_at);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(delegator.clone()), candidate.clone());
+
+		// Verify the delegation was dropped
+		assert!(!Delegations::<T>::contains_key(&candidate, &delegator));
+	}
+
+	#[benchmark]
+	fn slash(d: Linear<1, { T::MaxDelegatorsPerCandidate::get() }>) {
+		let origin = T::SlashOrigin::try_successful_origin().unwrap();
+		let candidate = candidate_with_delegators::<T>(d);
+		let delegated = TotalDelegated::<T>::get(&candidate);
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, candidate.clone(), Perbill::from_percent(50));
+
+		// Verify the delegations were slashed
+		assert!(TotalDelegated::<T>::get(&candidate) < delegated);
+	}
+
+	#[benchmark]
+	fn share_reward(d: Linear<0, { T::MaxDelegatorsPerCandidate::get() }>) {
+		let candidate = candidate_with_delegators::<T>(d);
+		let reward = T::MinDelegation::get();
+
+		#[block]
+		{
+			<Delegation<T> as RewardShares<_, _>>::share(&candidate, reward, |_, _| {});
+		}
+	}
+
+	impl_benchmark_test_suite!(Delegation, crate::mock::new_test_ext(), crate::mock::Test);
+}
@@ -0,0 +1,562 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+//! # Delegation Pallet
+//!
+//! Nomination-lite delegation of stake to collator candidates.
+//!
+//! ## Overview
+//!
+//! Token holders back a collator candidate with [`Pallet::delegate`], which puts the
+//! delegated amount on hold. The backing of a candidate is its own bond plus the amounts
+//! delegated to it, and counts in two places:
+//! - [`SelectByBacking`] wraps the session manager of the collator selection and fills the
+//!   candidate seats it selected with the candidates of highest backing, instead of
+//!   highest own bond
+//! - the pallet implements `pallet_collator_rewards::RewardShares`, sharing the reward of
+//!   every block a candidate authors with its delegators, in proportion to their share of
+//!   its backing
+//!
+//! [`Pallet::undelegate`] starts unbonding: the amount stops backing the candidate right
+//! away but stays on hold, and slashable, until `UnbondingPeriod` has passed and
+//! [`Pallet::withdraw_unbonded`] releases it.
+//!
+//! Slashing hooks, e.g. offence reports, call [`Pallet::slash_delegations`], and
+//! `SlashOrigin` calls [`Pallet::slash`]. Both slash a fraction of every delegated and
+//! unbonding amount of a candidate into `Slashed`.
+
+pub use pallet::*;
+pub mod weights;
+
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+#[frame_support::pallet]
+pub mod pallet {
+	use frame_support::{
+		pallet_prelude::*,
+		traits::{
+			fungible::{self, BalancedHold, Inspect, Mutate, MutateHold},
+			tokens::Precision,
+			OnUnbalanced,
+		},
+	};
+	use frame_system::pallet_prelude::*;
+	use pallet_collator_rewards::RewardShares;
+	use pallet_session::SessionManager;
+	use sp_runtime::{
+		traits::{SaturatedConversion, Saturating, Zero},
+		PerThing, Perbill,
+	};
+	use sp_std::{marker::PhantomData, vec::Vec};
+
+	pub use crate::weights::WeightInfo;
+
+	/// Balance of the delegated currency
+	pub type BalanceOf<T> =
+		<<T as Config>::Currency as Inspect<<T as frame_system::Config>::AccountId>>::Balance;
+
+	/// Slashed delegations
+	pub type CreditOf<T> =
+		fungible::Credit<<T as frame_system::Config>::AccountId, <T as Config>::Currency>;
+
+	/// Collator candidates that can be delegated to
+	pub trait CollatorCandidates<AccountId, Balance> {
+		/// Own bond of `who`, if it is a candidate
+		fn bond_of(who: &AccountId) -> Option<Balance>;
+
+		/// Every candidate with its own bond
+		fn candidates() -> Vec<(AccountId, Balance)>;
+
+		/// Register `who` as a candidate with `bond`
+		#[cfg(feature = "runtime-benchmarks")]
+		fn add_candidate(who: &AccountId, bond: Balance);
+	}
+
+	/// Amount on its way out of a delegation
+	#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub struct UnbondingChunk<Balance, BlockNumber> {
+		/// Amount unbonding
+		pub amount: Balance,
+		/// Block from which the amount can be withdrawn
+		pub unlocks_at: BlockNumber,
+	}
+
+	/// Stake of a delegator on a candidate
+	#[derive(
+		Encode,
+		Decode,
+		CloneNoBound,
+		PartialEqNoBound,
+		EqNoBound,
+		RuntimeDebugNoBound,
+		TypeInfo,
+		MaxEncodedLen,
+	)]
+	#[scale_info(skip_type_params(T))]
+	#[codec(mel_bound())]
+	pub struct Delegation<T: Config> {
+		/// Amount backing the candidate
+		pub amount: BalanceOf<T>,
+		/// Amounts undelegated and not withdrawn yet, oldest first
+		pub unbonding:
+			BoundedVec<UnbondingChunk<BalanceOf<T>, BlockNumberFor<T>>, T::MaxUnbondingChunks>,
+	}
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		/// The overarching event type
+		#[allow(deprecated)]
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		/// Currency holding the delegated amounts
+		type Currency: Mutate<Self::AccountId>
+			+ MutateHold<Self::AccountId, Reason = Self::RuntimeHoldReason>
+			+ BalancedHold<Self::AccountId>;
+
+		/// The overarching hold reason
+		type RuntimeHoldReason: From<HoldReason>;
+
+		/// Collator candidates that can be delegated to
+		type Candidates: CollatorCandidates<Self::AccountId, BalanceOf<Self>>;
+
+		/// Receiver of slashed delegations
+		type Slashed: OnUnbalanced<CreditOf<Self>>;
+
+		/// Origin allowed to slash the delegations of a candidate
+		type SlashOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// Minimum amount a delegator keeps delegated to a candidate
+		#[pallet::constant]
+		type MinDelegation: Get<BalanceOf<Self>>;
+
+		/// Maximum number of delegators of a candidate, unbonding ones included
+		#[pallet::constant]
+		type MaxDelegatorsPerCandidate: Get<u32>;
+
+		/// Maximum number of amounts a delegator unbonds from a candidate at once
+		#[pallet::constant]
+		type MaxUnbondingChunks: Get<u32>;
+
+		/// Number of blocks an undelegated amount stays on hold
+		#[pallet::constant]
+		type UnbondingPeriod: Get<BlockNumberFor<Self>>;
+
+		/// Weight information for extrinsics in this pallet
+		type WeightInfo: WeightInfo;
+	}
+
+	/// Reasons for the pallet to hold funds
+	#[pallet::composite_enum]
+	pub enum HoldReason {
+		/// Amount delegated to a candidate or unbonding from it
+		Delegation,
+	}
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	/// Delegations by candidate and delegator
+	///
+	/// Kept while the delegator backs the candidate or unbonds from it.
+	#[pallet::storage]
+	pub type Delegations<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		Blake2_128Concat,
+		T::AccountId,
+		Delegation<T>,
+		OptionQuery,
+	>;
+
+	/// Number of delegations kept for a candidate
+	#[pallet::storage]
+	pub type DelegatorCount<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, u32, ValueQuery>;
+
+	/// Total amount backing a candidate on top of its own bond
+	#[pallet::storage]
+	pub type TotalDelegated<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, BalanceOf<T>, ValueQuery>;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// An amount was delegated to a candidate
+		Delegated {
+			/// The delegator
+			delegator: T::AccountId,
+			/// The candidate
+			candidate: T::AccountId,
+			/// The amount added to the delegation
+			amount: BalanceOf<T>,
+		},
+		/// An amount started unbonding from a candidate
+		Undelegated {
+			/// The delegator
+			delegator: T::AccountId,
+			/// The candidate
+			candidate: T::AccountId,
+			/// The amount unbonding
+			amount: BalanceOf<T>,
+			/// Block from which the amount can be withdrawn
+			unlocks_at: BlockNumberFor<T>,
+		},
+		/// Unbonded amounts were released to the delegator
+		Withdrawn {
+			/// The delegator
+			delegator: T::AccountId,
+			/// The candidate unbonded from
+			candidate: T::AccountId,
+			/// The amount released
+			amount: BalanceOf<T>,
+		},
+		/// The delegations of a candidate were slashed
+		DelegationsSlashed {
+			/// The candidate
+			candidate: T::AccountId,
+			/// Fraction of every delegated and unbonding amount slashed
+			fraction: Perbill,
+			/// Total amount slashed
+			amount: BalanceOf<T>,
+		},
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The account is not a collator candidate
+		NotCandidate,
+		/// The delegation would be left below `MinDelegation`
+		BelowMinDelegation,
+		/// The candidate has `MaxDelegatorsPerCandidate` delegators
+		TooManyDelegators,
+		/// The caller has no delegation to the candidate
+		NoDelegation,
+		/// The amount is zero or above the delegated amount
+		InvalidAmount,
+		/// The caller already unbonds `MaxUnbondingChunks` amounts from the candidate
+		TooManyUnbondingChunks,
+		/// None of the unbonding amounts can be withdrawn yet
+		NothingToWithdraw,
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Delegate an amount to a collator candidate
+		///
+		/// Holds the amount, which backs the candidate until undelegated.
+		///
+		/// # Parameters
+		/// - `origin`: The delegator
+		/// - `candidate`: The collator candidate
+		/// - `amount`: The amount added to the delegation
+		///
+		/// # Errors
+		/// - `NotCandidate`: `candidate` is not a collator candidate
+		/// - `BelowMinDelegation`: The delegation would be below `MinDelegation`
+		/// - `TooManyDelegators`: The candidate has `MaxDelegatorsPerCandidate` delegators
+		///
+		/// # Events
+		/// - `Delegated`: Emitted with the amount added
+		#[pallet::call_index(0)]
+		#[pallet::weight(T::WeightInfo::delegate())]
+		pub fn delegate(
+			origin: OriginFor<T>,
+			candidate: T::AccountId,
+			amount: BalanceOf<T>,
+		) -> DispatchResult {
+			let delegator = ensure_signed(origin)?;
+
+			ensure!(T::Candidates::bond_of(&candidate).is_some(), Error::<T>::NotCandidate);
+
+			let existing = Delegations::<T>::get(&candidate, &delegator);
+			let is_new = existing.is_none();
+			if is_new {
+				ensure!(
+					DelegatorCount::<T>::get(&candidate) < T::MaxDelegatorsPerCandidate::get(),
+					Error::<T>::TooManyDelegators
+				);
+			}
+			let mut delegation = existing
+				.unwrap_or(Delegation { amount: Zero::zero(), unbonding: Default::default() });
+			delegation.amount.saturating_accrue(amount);
+			ensure!(
+				!amount.is_zero() && delegation.amount >= T::MinDelegation::get(),
+				Error::<T>::BelowMinDelegation
+			);
+
+			T::Currency::hold(&HoldReason::Delegation.into(), &delegator, amount)?;
+
+			if is_new {
+				DelegatorCount::<T>::mutate(&candidate, |count| count.saturating_inc());
+			}
+			TotalDelegated::<T>::mutate(&candidate, |total| total.saturating_accrue(amount));
+			Delegations::<T>::insert(&candidate, &delegator, delegation);
+			Self::deposit_event(Event::Delegated { delegator, candidate, amount });
+
+			Ok(())
+		}
+
+		/// Start unbonding an amount delegated to a candidate
+		///
+		/// The amount stops backing the candidate right away and can be withdrawn with
+		/// `withdraw_unbonded` after `UnbondingPeriod`. It can be slashed until then.
+		///
+		/// # Parameters
+		/// - `origin`: The delegator
+		/// - `candidate`: The candidate delegated to
+		/// - `amount`: The amount to unbond
+		///
+		/// # Errors
+		/// - `NoDelegation`: The caller has no delegation to the candidate
+		/// - `InvalidAmount`: The amount is zero or above the delegated amount
+		/// - `BelowMinDelegation`: The delegation would be left below `MinDelegation`
+		/// - `TooManyUnbondingChunks`: The caller already unbonds `MaxUnbondingChunks`
+		///   amounts from the candidate
+		///
+		/// # Events
+		/// - `Undelegated`: Emitted with the block the amount unlocks at
+		#[pallet::call_index(1)]
+		#[pallet::weight(T::WeightInfo::undelegate())]
+		pub fn undelegate(
+			origin: OriginFor<T>,
+			candidate: T::AccountId,
+			amount: BalanceOf<T>,
+		) -> DispatchResult {
+			let delegator = ensure_signed(origin)?;
+
+			let mut delegation =
+				Delegations::<T>::get(&candidate, &delegator).ok_or(Error::<T>::NoDelegation)?;
+			ensure!(!amount.is_zero() && amount <= delegation.amount, Error::<T>::InvalidAmount);
+			let remaining = delegation.amount.saturating_sub(amount);
+			ensure!(
+				remaining.is_zero() || remaining >= T::MinDelegation::get(),
+				Error::<T>::BelowMinDelegation
+			);
+
+			let now = frame_system::Pallet::<T>::block_number();
+			let unlocks_at = now.saturating_add(T::UnbondingPeriod::get());
+			match delegation.unbonding.last_mut() {
+				Some(chunk) if chunk.unlocks_at == unlocks_at =>
+					chunk.amount.saturating_accrue(amount),
+				_ => delegation
+					.unbonding
+					.try_push(UnbondingChunk { amount, unlocks_at })
+					.map_err(|_| Error::<T>::TooManyUnbondingChunks)?,
+			}
+			delegation.amount = remaining;
+
+			TotalDelegated::<T>::mutate(&candidate, |total| total.saturating_reduce(amount));
+			Delegations::<T>::insert(&candidate, &delegator, delegation);
+			Self::deposit_event(Event::Undelegated { delegator, candidate, amount, unlocks_at });
+
+			Ok(())
+		}
+
+		/// Release the amounts unbonded from a candidate whose unbonding period has passed
+		///
+		/// # Parameters
+		/// - `origin`: The delegator
+		/// - `candidate`: The candidate unbonded from
+		///
+		/// # Errors
+		/// - `NoDelegation`: The caller has no delegation to the candidate
+		/// - `NothingToWithdraw`: None of the unbonding amounts can be withdrawn yet
+		///
+		/// # Events
+		/// - `Withdrawn`: Emitted with the amount released
+		#[pallet::call_index(2)]
+		#[pallet::weight(T::WeightInfo::withdraw_unbonded())]
+		pub fn withdraw_unbonded(origin: OriginFor<T>, candidate: T::AccountId) -> DispatchResult {
+			let delegator = ensure_signed(origin)?;
+
+			let mut delegation =
+				Delegations::<T>::get(&candidate, &delegator).ok_or(Error::<T>::NoDelegation)?;
+			let now = frame_system::Pallet::<T>::block_number();
+			let mut amount = BalanceOf::<T>::zero();
+			let before = delegation.unbonding.len();
+			delegation.unbonding.retain(|chunk| {
+				let unlocked = chunk.unlocks_at <= now;
+				if unlocked {
+					amount.saturating_accrue(chunk.amount);
+				}
+				!unlocked
+			});
+			ensure!(delegation.unbonding.len() < before, Error::<T>::NothingToWithdraw);
+
+			T::Currency::release(
+				&HoldReason::Delegation.into(),
+				&delegator,
+				amount,
+				Precision::BestEffort,
+			)?;
+
+			if delegation.amount.is_zero() && delegation.unbonding.is_empty() {
+				Delegations::<T>::remove(&candidate, &delegator);
+				DelegatorCount::<T>::mutate(&candidate, |count| count.saturating_dec());
+			} else {
+				Delegations::<T>::insert(&candidate, &delegator, delegation);
+			}
+			Self::deposit_event(Event::Withdrawn { delegator, candidate, amount });
+
+			Ok(())
+		}
+
+		/// Slash a fraction of the delegations of a candidate
+		///
+		/// See [`Pallet::slash_delegations`].
+		///
+		/// # Parameters
+		/// - `origin`: Must be `SlashOrigin`
+		/// - `candidate`: The candidate whose delegations are slashed
+		/// - `fraction`: Fraction of every delegated and unbonding amount slashed
+		///
+		/// # Events
+		/// - `DelegationsSlashed`: Emitted with the total amount slashed
+		#[pallet::call_index(3)]
+		#[pallet::weight(T::WeightInfo::slash(T::MaxDelegatorsPerCandidate::get()))]
+		pub fn slash(
+			origin: OriginFor<T>,
+			candidate: T::AccountId,
+			fraction: Perbill,
+		) -> DispatchResult {
+			T::SlashOrigin::ensure_origin(origin)?;
+
+			Self::slash_delegations(&candidate, fraction);
+
+			Ok(())
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// Own bond of `candidate` plus the amounts delegated to it
+		pub fn backing(candidate: &T::AccountId) -> BalanceOf<T> {
+			T::Candidates::bond_of(candidate)
+				.unwrap_or_default()
+				.saturating_add(TotalDelegated::<T>::get(candidate))
+		}
+
+		/// Amount `delegator` delegates to `candidate`, unbonding amounts excluded
+		pub fn delegated(candidate: &T::AccountId, delegator: &T::AccountId) -> BalanceOf<T> {
+			Delegations::<T>::get(candidate, delegator).map_or_else(Zero::zero, |d| d.amount)
+		}
+
+		/// Slash `fraction` of every delegated and unbonding amount of `candidate`
+		///
+		/// The slashed amounts are paid into `Slashed`. Meant for the slashing hooks of the
+		/// runtime, e.g. offence reports against the candidate.
+		pub fn slash_delegations(candidate: &T::AccountId, fraction: Perbill) -> BalanceOf<T> {
+			let delegations = Delegations::<T>::iter_prefix(candidate).collect::<Vec<_>>();
+			let reason = HoldReason::Delegation.into();
+			let mut delegated_slashed = BalanceOf::<T>::zero();
+			let mut total = BalanceOf::<T>::zero();
+
+			for (delegator, mut delegation) in delegations {
+				let from_delegated = fraction.mul_floor(delegation.amount);
+				delegation.amount.saturating_reduce(from_delegated);
+				delegated_slashed.saturating_accrue(from_delegated);
+				let mut amount = from_delegated;
+				for chunk in delegation.unbonding.iter_mut() {
+					let from_chunk = fraction.mul_floor(chunk.amount);
+					chunk.amount.saturating_reduce(from_chunk);
+					amount.saturating_accrue(from_chunk);
+				}
+
+				let (credit, missing) =
+					<T::Currency as BalancedHold<_>>::slash(&reason, &delegator, amount);
+				T::Slashed::on_unbalanced(credit);
+				total.saturating_accrue(amount.saturating_sub(missing));
+				Delegations::<T>::insert(candidate, &delegator, delegation);
+			}
+
+			TotalDelegated::<T>::mutate(candidate, |delegated| {
+				delegated.saturating_reduce(delegated_slashed)
+			});
+			if !total.is_zero() {
+				Self::deposit_event(Event::DelegationsSlashed {
+					candidate: candidate.clone(),
+					fraction,
+					amount: total,
+				});
+			}
+			total
+		}
+	}
+
+	impl<T: Config> RewardShares<T::AccountId, BalanceOf<T>> for Pallet<T> {
+		fn share(
+			author: &T::AccountId,
+			reward: BalanceOf<T>,
+			mut accrue: impl FnMut(&T::AccountId, BalanceOf<T>),
+		) {
+			let mut delegators = 0u32;
+			if !TotalDelegated::<T>::get(author).is_zero() {
+				let backing = Self::backing(author).saturated_into::<u128>();
+				for (delegator, delegation) in Delegations::<T>::iter_prefix(author) {
+					delegators += 1;
+					let share =
+						Perbill::from_rational(delegation.amount.saturated_into::<u128>(), backing)
+							.mul_floor(reward);
+					if !share.is_zero() {
+						accrue(&delegator, share);
+					}
+				}
+			}
+
+			frame_system::Pallet::<T>::register_extra_weight_unchecked(
+				T::WeightInfo::share_reward(delegators),
+				DispatchClass::Mandatory,
+			);
+		}
+	}
+
+	/// Session manager filling the candidate seats selected by `Inner` by backing
+	///
+	/// The collators `Inner` selects that are not candidates, e.g. invulnerables, keep their
+	/// seat. The seats of the candidates go to the candidates of highest backing, ties
+	/// keeping the order of [`CollatorCandidates::candidates`].
+	pub struct SelectByBacking<T, Inner>(PhantomData<(T, Inner)>);
+
+	impl<T: Config, Inner: SessionManager<T::AccountId>> SessionManager<T::AccountId>
+		for SelectByBacking<T, Inner>
+	{
+		fn new_session(new_index: u32) -> Option<Vec<T::AccountId>> {
+			let selected = Inner::new_session(new_index)?;
+			let candidates = T::Candidates::candidates();
+
+			let (seats, mut collators): (Vec<_>, Vec<_>) = selected
+				.into_iter()
+				.partition(|who| candidates.iter().any(|(candidate, _)| candidate == who));
+
+			let mut ranked = candidates
+				.into_iter()
+				.map(|(who, bond)| {
+					let backing = bond.saturating_add(TotalDelegated::<T>::get(&who));
+					(who, backing)
+				})
+				.collect::<Vec<_>>();
+			ranked.sort_by(|a, b| b.1.cmp(&a.1));
+			collators.extend(ranked.into_iter().take(seats.len()).map(|(who, _)| who));
+
+			Some(collators)
+		}
+
+		fn new_session_genesis(new_index: u32) -> Option<Vec<T::AccountId>> {
+			Inner::new_session_genesis(new_index)
+		}
+
+		fn end_session(end_index: u32) {
+			Inner::end_session(end_index)
+		}
+
+		fn start_session(start_index: u32) {
+			Inner::start_session(start_index)
+		}
+	}
+}
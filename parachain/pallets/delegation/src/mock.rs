@@ -0,0 +1,109 @@
+use crate as pallet_delegation;
+use frame_support::{
+	derive_impl, parameter_types,
+	traits::{tokens::imbalance::ResolveTo, ConstU32, ConstU64},
+};
+use frame_system::EnsureRoot;
+use pallet_delegation::CollatorCandidates;
+use pallet_session::SessionManager;
+use sp_runtime::{traits::IdentityLookup, BuildStorage};
+use std::cell::RefCell;
+
+type Block = frame_system::mocking::MockBlock<Test>;
+
+// Configure a mock runtime to test the pallet.
+frame_support::construct_runtime!(
+	pub enum Test
+	{
+		System: frame_system,
+		Balances: pallet_balances,
+		Delegation: pallet_delegation,
+	}
+);
+
+#[derive_impl(frame_system::config_preludes::TestDefaultConfig)]
+impl frame_system::Config for Test {
+	type Block = Block;
+	type AccountId = u64;
+	type Lookup = IdentityLookup<Self::AccountId>;
+	type AccountData = pallet_balances::AccountData<u64>;
+}
+
+#[derive_impl(pallet_balances::config_preludes::TestDefaultConfig)]
+impl pallet_balances::Config for Test {
+	type AccountStore = System;
+}
+
+parameter_types! {
+	pub const SlashedAccount: u64 = 99;
+	pub const Invulnerable: u64 = 100;
+}
+
+thread_local! {
+	static CANDIDATES: RefCell<Vec<(u64, u64)>> = RefCell::new(vec![(1, 100), (2, 50), (3, 10)]);
+}
+
+/// Candidates 1, 2 and 3 with bonds 100, 50 and 10
+pub struct Candidates;
+
+impl CollatorCandidates<u64, u64> for Candidates {
+	fn bond_of(who: &u64) -> Option<u64> {
+		CANDIDATES.with(|candidates| {
+			candidates
+				.borrow()
+				.iter()
+				.find(|(candidate, _)| candidate == who)
+				.map(|(_, bond)| *bond)
+		})
+	}
+
+	fn candidates() -> Vec<(u64, u64)> {
+		CANDIDATES.with(|candidates| candidates.borrow().clone())
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn add_candidate(who: &u64, bond: u64) {
+		CANDIDATES.with(|candidates| candidates.borrow_mut().push((*who, bond)));
+	}
+}
+
+/// Collator selection seating the invulnerable and the two candidates of highest bond
+pub struct SelectByBond;
+
+impl SessionManager<u64> for SelectByBond {
+	fn new_session(_: u32) -> Option<Vec<u64>> {
+		Some(vec![Invulnerable::get(), 1, 2])
+	}
+
+	fn end_session(_: u32) {}
+
+	fn start_session(_: u32) {}
+}
+
+impl pallet_delegation::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type Currency = Balances;
+	type RuntimeHoldReason = RuntimeHoldReason;
+	type Candidates = Candidates;
+	type Slashed = ResolveTo<SlashedAccount, Balances>;
+	type SlashOrigin = EnsureRoot<u64>;
+	type MinDelegation = ConstU64<10>;
+	type MaxDelegatorsPerCandidate = ConstU32<3>;
+	type MaxUnbondingChunks = ConstU32<2>;
+	type UnbondingPeriod = ConstU64<10>;
+	type WeightInfo = ();
+}
+
+// Build genesis storage according to the mock runtime.
+pub fn new_test_ext() -> sp_io::TestExternalities {
+	let mut storage = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
+	pallet_balances::GenesisConfig::<Test> {
+		balances: (10..20).map(|who| (who, 1_000)).chain([(SlashedAccount::get(), 1)]).collect(),
+	}
+	.assimilate_storage(&mut storage)
+	.unwrap();
+
+	let mut ext: sp_io::TestExternalities = storage.into();
+	ext.execute_with(|| System::set_block_number(1));
+	ext
+}
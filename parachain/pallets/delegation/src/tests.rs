@@ -0,0 +1,235 @@
+use crate::{
+	mock::*, Delegation as DelegationInfo, Delegations, DelegatorCount, Error, Event,
+	SelectByBacking, TotalDelegated, UnbondingChunk,
+};
+use frame_support::{
+	assert_noop, assert_ok,
+	traits::fungible::{Inspect, InspectHold},
+};
+use pallet_collator_rewards::RewardShares;
+use pallet_session::SessionManager;
+use sp_runtime::{DispatchError, Perbill, TokenError};
+
+fn held(who: u64) -> u64 {
+	Balances::total_balance_on_hold(&who)
+}
+
+#[test]
+fn delegate_works() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Delegation::delegate(RuntimeOrigin::signed(10), 1, 60));
+		System::assert_last_event(
+			Event::Delegated { delegator: 10, candidate: 1, amount: 60 }.into(),
+		);
+		assert_ok!(Delegation::delegate(RuntimeOrigin::signed(10), 1, 40));
+		assert_ok!(Delegation::delegate(RuntimeOrigin::signed(11), 1, 50));
+
+		assert_eq!(held(10), 100);
+		assert_eq!(Delegation::delegated(&1, &10), 100);
+		assert_eq!(DelegatorCount::<Test>::get(1), 2);
+		assert_eq!(TotalDelegated::<Test>::get(1), 150);
+		assert_eq!(Delegation::backing(&1), 250);
+	});
+}
+
+#[test]
+fn delegate_validates_input() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			Delegation::delegate(RuntimeOrigin::signed(10), 4, 100),
+			Error::<Test>::NotCandidate
+		);
+		assert_noop!(
+			Delegation::delegate(RuntimeOrigin::signed(10), 1, 9),
+			Error::<Test>::BelowMinDelegation
+		);
+		assert_noop!(
+			Delegation::delegate(RuntimeOrigin::signed(10), 1, 1_001),
+			TokenError::FundsUnavailable
+		);
+
+		// Topping up an existing delegation has no minimum
+		assert_ok!(Delegation::delegate(RuntimeOrigin::signed(10), 1, 10));
+		assert_ok!(Delegation::delegate(RuntimeOrigin::signed(10), 1, 1));
+		assert_noop!(
+			Delegation::delegate(RuntimeOrigin::signed(10), 1, 0),
+			Error::<Test>::BelowMinDelegation
+		);
+
+		assert_ok!(Delegation::delegate(RuntimeOrigin::signed(11), 1, 10));
+		assert_ok!(Delegation::delegate(RuntimeOrigin::signed(12), 1, 10));
+		assert_noop!(
+			Delegation::delegate(RuntimeOrigin::signed(13), 1, 10),
+			Error::<Test>::TooManyDelegators
+		);
+	});
+}
+
+#[test]
+fn undelegate_and_withdraw_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Delegation::delegate(RuntimeOrigin::signed(10), 1, 100));
+
+		assert_ok!(Delegation::undelegate(RuntimeOrigin::signed(10), 1, 40));
+		System::assert_last_event(
+			Event::Undelegated { delegator: 10, candidate: 1, amount: 40, unlocks_at: 11 }.into(),
+		);
+		assert_eq!(Delegation::delegated(&1, &10), 60);
+		assert_eq!(TotalDelegated::<Test>::get(1), 60);
+		// Unbonding amounts stay on hold
+		assert_eq!(held(10), 100);
+
+		System::set_block_number(10);
+		assert_noop!(
+			Delegation::withdraw_unbonded(RuntimeOrigin::signed(10), 1),
+			Error::<Test>::NothingToWithdraw
+		);
+
+		System::set_block_number(11);
+		assert_ok!(Delegation::withdraw_unbonded(RuntimeOrigin::signed(10), 1));
+		System::assert_last_event(
+			Event::Withdrawn { delegator: 10, candidate: 1, amount: 40 }.into(),
+		);
+		assert_eq!(held(10), 60);
+		assert_eq!(Balances::balance(&10), 940);
+
+		// The delegation is dropped once fully withdrawn
+		assert_ok!(Delegation::undelegate(RuntimeOrigin::signed(10), 1, 60));
+		System::set_block_number(21);
+		assert_ok!(Delegation::withdraw_unbonded(RuntimeOrigin::signed(10), 1));
+		assert_eq!(held(10), 0);
+		assert!(!Delegations::<Test>::contains_key(1, 10));
+		assert_eq!(DelegatorCount::<Test>::get(1), 0);
+	});
+}
+
+#[test]
+fn undelegate_validates_input() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			Delegation::undelegate(RuntimeOrigin::signed(10), 1, 10),
+			Error::<Test>::NoDelegation
+		);
+
+		assert_ok!(Delegation::delegate(RuntimeOrigin::signed(10), 1, 100));
+		assert_noop!(
+			Delegation::undelegate(RuntimeOrigin::signed(10), 1, 0),
+			Error::<Test>::InvalidAmount
+		);
+		assert_noop!(
+			Delegation::undelegate(RuntimeOrigin::signed(10), 1, 101),
+			Error::<Test>::InvalidAmount
+		);
+		assert_noop!(
+			Delegation::undelegate(RuntimeOrigin::signed(10), 1, 91),
+			Error::<Test>::BelowMinDelegation
+		);
+	});
+}
+
+#[test]
+fn unbonding_chunks_are_bounded() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Delegation::delegate(RuntimeOrigin::signed(10), 1, 100));
+
+		// Amounts unbonding in the same block share a chunk
+		assert_ok!(Delegation::undelegate(RuntimeOrigin::signed(10), 1, 10));
+		assert_ok!(Delegation::undelegate(RuntimeOrigin::signed(10), 1, 10));
+		System::set_block_number(2);
+		assert_ok!(Delegation::undelegate(RuntimeOrigin::signed(10), 1, 10));
+		assert_eq!(
+			Delegations::<Test>::get(1, 10).unwrap().unbonding.into_inner(),
+			vec![
+				UnbondingChunk { amount: 20, unlocks_at: 11 },
+				UnbondingChunk { amount: 10, unlocks_at: 12 }
+			]
+		);
+
+		System::set_block_number(3);
+		assert_noop!(
+			Delegation::undelegate(RuntimeOrigin::signed(10), 1, 10),
+			Error::<Test>::TooManyUnbondingChunks
+		);
+	});
+}
+
+#[test]
+fn slash_takes_from_delegated_and_unbonding_amounts() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Delegation::delegate(RuntimeOrigin::signed(10), 1, 100));
+		assert_ok!(Delegation::delegate(RuntimeOrigin::signed(11), 1, 50));
+		assert_ok!(Delegation::undelegate(RuntimeOrigin::signed(11), 1, 20));
+		assert_ok!(Delegation::delegate(RuntimeOrigin::signed(12), 2, 100));
+
+		assert_noop!(
+			Delegation::slash(RuntimeOrigin::signed(10), 1, Perbill::from_percent(10)),
+			DispatchError::BadOrigin
+		);
+		assert_ok!(Delegation::slash(RuntimeOrigin::root(), 1, Perbill::from_percent(10)));
+		System::assert_last_event(
+			Event::DelegationsSlashed {
+				candidate: 1,
+				fraction: Perbill::from_percent(10),
+				amount: 15,
+			}
+			.into(),
+		);
+
+		assert_eq!(Balances::balance(&SlashedAccount::get()), 16);
+		assert_eq!((held(10), held(11), held(12)), (90, 45, 100));
+		assert_eq!(
+			Delegations::<Test>::get(1, 11),
+			Some(DelegationInfo {
+				amount: 27,
+				unbonding: vec![UnbondingChunk { amount: 18, unlocks_at: 11 }].try_into().unwrap(),
+			})
+		);
+		assert_eq!(TotalDelegated::<Test>::get(1), 117);
+
+		// Only what is left of unbonding amounts is released
+		System::set_block_number(11);
+		assert_ok!(Delegation::withdraw_unbonded(RuntimeOrigin::signed(11), 1));
+		assert_eq!(held(11), 27);
+	});
+}
+
+#[test]
+fn rewards_are_shared_by_backing() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Delegation::delegate(RuntimeOrigin::signed(10), 1, 100));
+		assert_ok!(Delegation::delegate(RuntimeOrigin::signed(11), 1, 150));
+		assert_ok!(Delegation::undelegate(RuntimeOrigin::signed(11), 1, 50));
+		assert_ok!(Delegation::delegate(RuntimeOrigin::signed(12), 2, 100));
+
+		// Backing of 1 is its bond of 100 and 200 delegated
+		let mut shares = Vec::new();
+		<Delegation as RewardShares<u64, u64>>::share(&1, 30, |who, share| {
+			shares.push((*who, share))
+		});
+		shares.sort();
+		assert_eq!(shares, vec![(10, 10), (11, 10)]);
+
+		// Candidates without delegations keep their reward
+		let mut shares = Vec::new();
+		<Delegation as RewardShares<u64, u64>>::share(&3, 30, |who, share| {
+			shares.push((*who, share))
+		});
+		assert!(shares.is_empty());
+	});
+}
+
+#[test]
+fn selection_follows_backing() {
+	new_test_ext().execute_with(|| {
+		type Selection = SelectByBacking<Test, SelectByBond>;
+		assert_eq!(Selection::new_session(1), Some(vec![Invulnerable::get(), 1, 2]));
+
+		// Delegations lift candidate 3 above candidate 2
+		assert_ok!(Delegation::delegate(RuntimeOrigin::signed(10), 3, 41));
+		assert_eq!(Selection::new_session(2), Some(vec![Invulnerable::get(), 1, 3]));
+
+		// Unbonding amounts no longer back the candidate
+		assert_ok!(Delegation::undelegate(RuntimeOrigin::signed(10), 3, 41));
+		assert_eq!(Selection::new_session(3), Some(vec![Invulnerable::get(), 1, 2]));
+	});
+}
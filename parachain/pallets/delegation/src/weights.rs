@@ -0,0 +1,160 @@
+//! Placeholder weights for `pallet_delegation`
+//!
+//! NOT MEASURED: the pallet has not been benchmarked yet. The storage accesses and proof
+//! sizes follow the code, while the execution times are hand estimates doubled as a safety
+//! margin, so that blocks are underfilled rather than overfilled until they are measured.
+//! Replace this file with the output of:
+//!
+//! ./target/release/evm-template-node benchmark pallet --chain dev \
+//!     --pallet pallet_delegation --extrinsic '*' --steps 50 --repeat 20 \
+//!     --output pallets/delegation/src/weights.rs
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+
+use frame_support::{traits::Get, weights::Weight};
+use core::marker::PhantomData;
+
+/// Weight functions needed for pallet_delegation.
+pub trait WeightInfo {
+	fn delegate() -> Weight;
+	fn undelegate() -> Weight;
+	fn withdraw_unbonded() -> Weight;
+	fn slash(d: u32, ) -> Weight;
+	fn share_reward(d: u32, ) -> Weight;
+}
+
+/// Weights for pallet_delegation using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	/// Storage: `CollatorSelection::CandidateList` (r:1 w:0)
+	/// Proof: `CollatorSelection::CandidateList` (`max_values`: Some(1), `max_size`: Some(4802), added: 5297, mode: `MaxEncodedLen`)
+	/// Storage: `Delegation::Delegations` (r:1 w:1)
+	/// Proof: `Delegation::Delegations` (`max_values`: None, `max_size`: Some(276), added: 2751, mode: `MaxEncodedLen`)
+	/// Storage: `Delegation::DelegatorCount` (r:1 w:1)
+	/// Proof: `Delegation::DelegatorCount` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(211), added: 2686, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(116), added: 2591, mode: `MaxEncodedLen`)
+	/// Storage: `Delegation::TotalDelegated` (r:1 w:1)
+	/// Proof: `Delegation::TotalDelegated` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	fn delegate() -> Weight {
+		// Proof Size estimate in bytes:
+		//  Estimated: `6287`
+		Weight::from_parts(120_910_000, 0)
+			.saturating_add(Weight::from_parts(0, 6287))
+			.saturating_add(T::DbWeight::get().reads(6))
+			.saturating_add(T::DbWeight::get().writes(5))
+	}
+	/// Storage: `Delegation::Delegations` (r:1 w:1)
+	/// Proof: `Delegation::Delegations` (`max_values`: None, `max_size`: Some(276), added: 2751, mode: `MaxEncodedLen`)
+	/// Storage: `Delegation::TotalDelegated` (r:1 w:1)
+	/// Proof: `Delegation::TotalDelegated` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	fn undelegate() -> Weight {
+		// Proof Size estimate in bytes:
+		//  Estimated: `3741`
+		Weight::from_parts(41_036_000, 0)
+			.saturating_add(Weight::from_parts(0, 3741))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: `Delegation::Delegations` (r:1 w:1)
+	/// Proof: `Delegation::Delegations` (`max_values`: None, `max_size`: Some(276), added: 2751, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(211), added: 2686, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(116), added: 2591, mode: `MaxEncodedLen`)
+	/// Storage: `Delegation::DelegatorCount` (r:1 w:1)
+	/// Proof: `Delegation::DelegatorCount` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	fn withdraw_unbonded() -> Weight {
+		// Proof Size estimate in bytes:
+		//  Estimated: `3741`
+		Weight::from_parts(105_746_000, 0)
+			.saturating_add(Weight::from_parts(0, 3741))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(4))
+	}
+	/// Storage: `Delegation::Delegations` (r:101 w:100)
+	/// Proof: `Delegation::Delegations` (`max_values`: None, `max_size`: Some(276), added: 2751, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:100 w:100)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(211), added: 2686, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:101 w:101)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(116), added: 2591, mode: `MaxEncodedLen`)
+	/// Storage: `Delegation::TotalDelegated` (r:1 w:1)
+	/// Proof: `Delegation::TotalDelegated` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// The range of component `d` is `[1, 100]`.
+	fn slash(d: u32, ) -> Weight {
+		// Proof Size estimate in bytes:
+		//  Estimated: `3741 + d * (2751 ±0)`
+		Weight::from_parts(49_432_000, 0)
+			.saturating_add(Weight::from_parts(0, 3741))
+			// Standard Error: 28_904
+			.saturating_add(Weight::from_parts(94_766_000, 0).saturating_mul(d.into()))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().reads((3_u64).saturating_mul(d.into())))
+			.saturating_add(T::DbWeight::get().writes(2))
+			.saturating_add(T::DbWeight::get().writes((3_u64).saturating_mul(d.into())))
+			.saturating_add(Weight::from_parts(0, 2751).saturating_mul(d.into()))
+	}
+	/// Storage: `Delegation::TotalDelegated` (r:1 w:0)
+	/// Proof: `Delegation::TotalDelegated` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::CandidateList` (r:1 w:0)
+	/// Proof: `CollatorSelection::CandidateList` (`max_values`: Some(1), `max_size`: Some(4802), added: 5297, mode: `MaxEncodedLen`)
+	/// Storage: `Delegation::Delegations` (r:101 w:0)
+	/// Proof: `Delegation::Delegations` (`max_values`: None, `max_size`: Some(276), added: 2751, mode: `MaxEncodedLen`)
+	/// The range of component `d` is `[0, 100]`.
+	fn share_reward(d: u32, ) -> Weight {
+		// Proof Size estimate in bytes:
+		//  Estimated: `6287 + d * (2751 ±0)`
+		Weight::from_parts(18_636_000, 0)
+			.saturating_add(Weight::from_parts(0, 6287))
+			// Standard Error: 6_117
+			.saturating_add(Weight::from_parts(16_529_000, 0).saturating_mul(d.into()))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(d.into())))
+			.saturating_add(Weight::from_parts(0, 2751).saturating_mul(d.into()))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	fn delegate() -> Weight {
+		Weight::from_parts(120_910_000, 6287)
+			.saturating_add(Weight::from_parts(0, 6287))
+			.saturating_add(frame_support::weights::constants::RocksDbWeight::get().reads(6))
+			.saturating_add(frame_support::weights::constants::RocksDbWeight::get().writes(5))
+	}
+	fn undelegate() -> Weight {
+		Weight::from_parts(41_036_000, 3741)
+			.saturating_add(Weight::from_parts(0, 3741))
+			.saturating_add(frame_support::weights::constants::RocksDbWeight::get().reads(2))
+			.saturating_add(frame_support::weights::constants::RocksDbWeight::get().writes(2))
+	}
+	fn withdraw_unbonded() -> Weight {
+		Weight::from_parts(105_746_000, 3741)
+			.saturating_add(Weight::from_parts(0, 3741))
+			.saturating_add(frame_support::weights::constants::RocksDbWeight::get().reads(4))
+			.saturating_add(frame_support::weights::constants::RocksDbWeight::get().writes(4))
+	}
+	fn slash(d: u32, ) -> Weight {
+		Weight::from_parts(49_432_000, 3741)
+			.saturating_add(Weight::from_parts(0, 3741))
+			.saturating_add(Weight::from_parts(94_766_000, 0).saturating_mul(d.into()))
+			.saturating_add(frame_support::weights::constants::RocksDbWeight::get().reads(2))
+			.saturating_add(frame_support::weights::constants::RocksDbWeight::get().reads((3_u64).saturating_mul(d.into())))
+			.saturating_add(frame_support::weights::constants::RocksDbWeight::get().writes(2))
+			.saturating_add(frame_support::weights::constants::RocksDbWeight::get().writes((3_u64).saturating_mul(d.into())))
+			.saturating_add(Weight::from_parts(0, 2751).saturating_mul(d.into()))
+	}
+	fn share_reward(d: u32, ) -> Weight {
+		Weight::from_parts(18_636_000, 6287)
+			.saturating_add(Weight::from_parts(0, 6287))
+			.saturating_add(Weight::from_parts(16_529_000, 0).saturating_mul(d.into()))
+			.saturating_add(frame_support::weights::constants::RocksDbWeight::get().reads(3))
+			.saturating_add(frame_support::weights::constants::RocksDbWeight::get().reads((1_u64).saturating_mul(d.into())))
+			.saturating_add(Weight::from_parts(0, 2751).saturating_mul(d.into()))
+	}
+}
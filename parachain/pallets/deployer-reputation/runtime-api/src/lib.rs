@@ -0,0 +1,38 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+//! # Deployer Reputation Runtime API
+//!
+//! Runtime API exposing deployer reputation to the node, so that factory operators and
+//! integrators can gate integrations on a deployer's standing.
+
+use parity_scale_codec::{Codec, Decode, Encode};
+use scale_info::TypeInfo;
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
+use sp_runtime::RuntimeDebug;
+
+/// Reputation of a deployer
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct Reputation<BlockNumber> {
+    /// Current score
+    pub score: i32,
+    /// Block at which the deployer was suspended for falling below the floor, if suspended
+    pub suspended_at: Option<BlockNumber>,
+}
+
+sp_api::decl_runtime_apis! {
+    /// Runtime API for the deployer reputation pallet
+    pub trait DeployerReputationApi<AccountId, BlockNumber>
+    where
+        AccountId: Codec,
+        BlockNumber: Codec,
+    {
+        /// Reputation of `deployer`, accounts without record starting at the initial score
+        fn reputation(deployer: AccountId) -> Reputation<BlockNumber>;
+
+        /// Score below which deployers are suspended
+        fn suspension_floor() -> i32;
+    }
+}
@@ -0,0 +1,56 @@
+//! Benchmarking setup for pallet-deployer-reputation
+
+use super::*;
+
+#[allow(unused)]
+use crate::Pallet as DeployerReputation;
+use frame_benchmarking::v2::*;
+use frame_support::traits::{EnsureOrigin, Get};
+
+#[benchmarks]
+mod benchmarks {
+	use super::*;
+
+	/// Worst case: the adjustment takes the deployer below the floor and suspends it
+	#[benchmark]
+	fn adjust_score() {
+		let origin = T::ScoreOrigin::try_successful_origin().unwrap();
+		let deployer: T::AccountId = account("deployer", 0, 0);
+		let delta = T::SuspensionFloor::get().saturating_sub(T::InitialScore::get()) - 1;
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, deployer.clone(), delta);
+
+		// Verify the deployer was suspended
+		assert!(Suspended::<T>::contains_key(&deployer));
+	}
+
+	#[benchmark]
+	fn report_incident() {
+		let origin = T::ReporterOrigin::try_successful_origin().unwrap();
+		let deployer: T::AccountId = account("deployer", 0, 0);
+		Scores::<T>::insert(&deployer, T::SuspensionFloor::get());
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, deployer.clone());
+
+		// Verify the deployer was suspended
+		assert!(Suspended::<T>::contains_key(&deployer));
+	}
+
+	#[benchmark]
+	fn reinstate() {
+		let origin = T::ScoreOrigin::try_successful_origin().unwrap();
+		let deployer: T::AccountId = account("deployer", 0, 0);
+		Scores::<T>::insert(&deployer, T::MaxScore::get().saturating_neg());
+		Suspended::<T>::insert(&deployer, frame_system::Pallet::<T>::block_number());
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, deployer.clone());
+
+		// Verify the suspension was lifted
+		assert!(!Suspended::<T>::contains_key(&deployer));
+	}
+
+	impl_benchmark_test_suite!(DeployerReputation, crate::mock::new_test_ext(), crate::mock::Test);
+}
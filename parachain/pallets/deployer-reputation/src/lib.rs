@@ -0,0 +1,281 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+//! # Deployer Reputation Pallet
+//!
+//! Per-deployer reputation scores with automatic suspension below a floor.
+//!
+//! ## Overview
+//!
+//! Every deployer starts at `InitialScore`. The score moves with:
+//! - successful deployments, rewarded by `DeploymentReward` through the
+//!   [`DeploymentLifecycle`] callbacks of the deployment control pallet
+//! - incident reports, penalized by `IncidentPenalty`
+//! - governance adjustments of arbitrary size
+//!
+//! Scores are clamped to `[-MaxScore, MaxScore]`. When a score falls below
+//! `SuspensionFloor`, the deployer is suspended through the configured
+//! [`DeployerSuspension`] hook, which revokes its deployment authorization, and
+//! stops earning deployment rewards until governance reinstates it.
+//!
+//! Scores are exposed through a runtime API so that factories and integrators can
+//! gate integrations on reputation.
+
+pub use deployment_control_primitives::{DeployerSuspension, DeploymentLifecycle};
+pub use pallet::*;
+pub mod weights;
+
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+#[frame_support::pallet]
+pub mod pallet {
+	use crate::DeployerSuspension;
+	use frame_support::pallet_prelude::*;
+	use frame_system::pallet_prelude::*;
+	use sp_core::H160;
+	use sp_runtime::traits::Convert;
+
+	pub use crate::weights::WeightInfo;
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		/// The overarching event type
+		#[allow(deprecated)]
+		type RuntimeEvent: From<Event<Self>>
+			+ IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		/// Origin allowed to adjust scores and reinstate suspended deployers
+		type ScoreOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// Origin allowed to report incidents
+		type ReporterOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// Account controlled by an EVM address
+		type AddressMapping: Convert<H160, Self::AccountId>;
+
+		/// Hook suspending deployers whose score falls below the floor
+		type Suspension: DeployerSuspension<Self::AccountId>;
+
+		/// Score of deployers without any record
+		#[pallet::constant]
+		type InitialScore: Get<i32>;
+
+		/// Score added for every successful deployment
+		#[pallet::constant]
+		type DeploymentReward: Get<i32>;
+
+		/// Score removed for every reported incident
+		#[pallet::constant]
+		type IncidentPenalty: Get<i32>;
+
+		/// Score below which deployers are suspended
+		#[pallet::constant]
+		type SuspensionFloor: Get<i32>;
+
+		/// Bound of the absolute value of a score
+		#[pallet::constant]
+		type MaxScore: Get<i32>;
+
+		/// Weight information for extrinsics in this pallet
+		type WeightInfo: WeightInfo;
+	}
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	/// Reputation score of each deployer
+	///
+	/// Deployers without an entry are at `InitialScore`.
+	#[pallet::storage]
+	pub type Scores<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, i32, OptionQuery>;
+
+	/// Deployers suspended for falling below the floor
+	///
+	/// Maps to the block at which the deployer was suspended.
+	#[pallet::storage]
+	pub type Suspended<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, BlockNumberFor<T>, OptionQuery>;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// A deployer's score was adjusted by governance
+		ScoreAdjusted {
+			/// The deployer
+			deployer: T::AccountId,
+			/// The applied change
+			delta: i32,
+			/// The new score
+			score: i32,
+		},
+		/// An incident was reported against a deployer
+		IncidentReported {
+			/// The deployer
+			deployer: T::AccountId,
+			/// The new score
+			score: i32,
+		},
+		/// A deployer fell below the floor and was suspended
+		DeployerSuspended {
+			/// The deployer
+			deployer: T::AccountId,
+			/// The score that triggered the suspension
+			score: i32,
+		},
+		/// A suspended deployer was reinstated with the initial score
+		DeployerReinstated {
+			/// The deployer
+			deployer: T::AccountId,
+		},
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The deployer is not suspended
+		NotSuspended,
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Adjust a deployer's score
+		///
+		/// # Parameters
+		/// - `origin`: Must be `ScoreOrigin`
+		/// - `deployer`: The deployer to adjust
+		/// - `delta`: The change applied to the score
+		///
+		/// # Events
+		/// - `ScoreAdjusted`: Emitted with the new score
+		/// - `DeployerSuspended`: Emitted if the score falls below the floor
+		#[pallet::call_index(0)]
+		#[pallet::weight(T::WeightInfo::adjust_score())]
+		pub fn adjust_score(
+			origin: OriginFor<T>,
+			deployer: T::AccountId,
+			delta: i32,
+		) -> DispatchResult {
+			T::ScoreOrigin::ensure_origin(origin)?;
+
+			let score = Self::apply(&deployer, delta);
+			Self::deposit_event(Event::ScoreAdjusted { deployer, delta, score });
+
+			Ok(())
+		}
+
+		/// Report an incident caused by a deployer, removing `IncidentPenalty` from its score
+		///
+		/// # Parameters
+		/// - `origin`: Must be `ReporterOrigin`
+		/// - `deployer`: The deployer responsible for the incident
+		///
+		/// # Events
+		/// - `IncidentReported`: Emitted with the new score
+		/// - `DeployerSuspended`: Emitted if the score falls below the floor
+		#[pallet::call_index(1)]
+		#[pallet::weight(T::WeightInfo::report_incident())]
+		pub fn report_incident(origin: OriginFor<T>, deployer: T::AccountId) -> DispatchResult {
+			T::ReporterOrigin::ensure_origin(origin)?;
+
+			let score = Self::apply(&deployer, T::IncidentPenalty::get().saturating_neg());
+			Self::deposit_event(Event::IncidentReported { deployer, score });
+
+			Ok(())
+		}
+
+		/// Lift the suspension of a deployer and reset its score to `InitialScore`
+		///
+		/// Does not restore the deployment authorization, which has to be granted again
+		/// through the deployment control pallet.
+		///
+		/// # Parameters
+		/// - `origin`: Must be `ScoreOrigin`
+		/// - `deployer`: The suspended deployer
+		///
+		/// # Errors
+		/// - `NotSuspended`: The deployer is not suspended
+		///
+		/// # Events
+		/// - `DeployerReinstated`: Emitted when the suspension is lifted
+		#[pallet::call_index(2)]
+		#[pallet::weight(T::WeightInfo::reinstate())]
+		pub fn reinstate(origin: OriginFor<T>, deployer: T::AccountId) -> DispatchResult {
+			T::ScoreOrigin::ensure_origin(origin)?;
+
+			ensure!(Suspended::<T>::contains_key(&deployer), Error::<T>::NotSuspended);
+
+			Suspended::<T>::remove(&deployer);
+			Scores::<T>::remove(&deployer);
+			Self::deposit_event(Event::DeployerReinstated { deployer });
+
+			Ok(())
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// Current score of a deployer
+		pub fn score(deployer: &T::AccountId) -> i32 {
+			Scores::<T>::get(deployer).unwrap_or_else(T::InitialScore::get)
+		}
+
+		/// Block at which a deployer was suspended, if it is suspended
+		pub fn suspended_at(deployer: &T::AccountId) -> Option<BlockNumberFor<T>> {
+			Suspended::<T>::get(deployer)
+		}
+
+		/// Check if a deployer is suspended
+		pub fn is_suspended(deployer: &T::AccountId) -> bool {
+			Suspended::<T>::contains_key(deployer)
+		}
+
+		/// Reward a successful deployment, unless the deployer is suspended
+		pub fn note_deployment(deployer: &T::AccountId) {
+			if !Self::is_suspended(deployer) {
+				Self::apply(deployer, T::DeploymentReward::get());
+			}
+		}
+
+		/// Apply a change to a deployer's score, suspending it below the floor
+		///
+		/// # Returns
+		/// The new score
+		fn apply(deployer: &T::AccountId, delta: i32) -> i32 {
+			let max = T::MaxScore::get();
+			let score =
+				Self::score(deployer).saturating_add(delta).clamp(max.saturating_neg(), max);
+			Scores::<T>::insert(deployer, score);
+
+			if score < T::SuspensionFloor::get() && !Self::is_suspended(deployer) {
+				Suspended::<T>::insert(deployer, frame_system::Pallet::<T>::block_number());
+				T::Suspension::suspend(deployer);
+				Self::deposit_event(Event::DeployerSuspended { deployer: deployer.clone(), score });
+			}
+
+			score
+		}
+	}
+}
+
+/// Rewards successful deployments and carries scores over rotated keys; the other
+/// callbacks are ignored
+impl<T: Config> DeploymentLifecycle<T::AccountId> for Pallet<T> {
+    fn on_deployed(deployer: &sp_core::H160, _contract: &sp_core::H160) {
+        use sp_runtime::traits::Convert;
+
+        Pallet::<T>::note_deployment(&T::AddressMapping::convert(*deployer));
+    }
+
+    fn on_key_rotated(old: &T::AccountId, new: &T::AccountId) {
+        if let Some(score) = Scores::<T>::take(old) {
+            Scores::<T>::insert(new, score);
+        }
+        if let Some(suspended_at) = Suspended::<T>::take(old) {
+            Suspended::<T>::insert(new, suspended_at);
+        }
+    }
+}
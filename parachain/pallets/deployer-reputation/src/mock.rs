@@ -0,0 +1,85 @@
+use crate as pallet_deployer_reputation;
+use frame_support::{derive_impl, parameter_types};
+use frame_system::EnsureRoot;
+use sp_core::H160;
+use sp_runtime::{
+	traits::{Convert, IdentityLookup},
+	BuildStorage,
+};
+use std::cell::RefCell;
+
+type Block = frame_system::mocking::MockBlock<Test>;
+
+// Configure a mock runtime to test the pallet.
+frame_support::construct_runtime!(
+	pub enum Test
+	{
+		System: frame_system,
+		DeployerReputation: pallet_deployer_reputation,
+	}
+);
+
+#[derive_impl(frame_system::config_preludes::TestDefaultConfig)]
+impl frame_system::Config for Test {
+	type Block = Block;
+	type AccountId = u64;
+	type Lookup = IdentityLookup<Self::AccountId>;
+}
+
+/// Maps EVM address `0x00..0N` to account `N`
+pub struct LowBytes;
+
+impl Convert<H160, u64> for LowBytes {
+	fn convert(address: H160) -> u64 {
+		address.to_low_u64_be()
+	}
+}
+
+thread_local! {
+	static SUSPENDED: RefCell<Vec<u64>> = RefCell::new(Vec::new());
+}
+
+/// Suspension hook recording the suspended accounts
+pub struct RecordSuspension;
+
+impl pallet_deployer_reputation::DeployerSuspension<u64> for RecordSuspension {
+	fn suspend(who: &u64) {
+		SUSPENDED.with(|suspended| suspended.borrow_mut().push(*who));
+	}
+}
+
+/// Accounts passed to [`RecordSuspension`] so far
+pub fn suspended() -> Vec<u64> {
+	SUSPENDED.with(|suspended| suspended.borrow().clone())
+}
+
+parameter_types! {
+	pub const InitialScore: i32 = 100;
+	pub const DeploymentReward: i32 = 1;
+	pub const IncidentPenalty: i32 = 40;
+	pub const SuspensionFloor: i32 = 50;
+	pub const MaxScore: i32 = 1_000;
+}
+
+impl pallet_deployer_reputation::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type ScoreOrigin = EnsureRoot<u64>;
+	type ReporterOrigin = EnsureRoot<u64>;
+	type AddressMapping = LowBytes;
+	type Suspension = RecordSuspension;
+	type InitialScore = InitialScore;
+	type DeploymentReward = DeploymentReward;
+	type IncidentPenalty = IncidentPenalty;
+	type SuspensionFloor = SuspensionFloor;
+	type MaxScore = MaxScore;
+	type WeightInfo = ();
+}
+
+// Build genesis storage according to the mock runtime.
+pub fn new_test_ext() -> sp_io::TestExternalities {
+	let storage = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
+
+	let mut ext: sp_io::TestExternalities = storage.into();
+	ext.execute_with(|| System::set_block_number(1));
+	ext
+}
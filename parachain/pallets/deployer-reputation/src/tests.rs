@@ -0,0 +1,111 @@
+use crate::{mock::*, DeploymentLifecycle, Error, Event, Scores};
+use frame_support::{assert_noop, assert_ok};
+use sp_core::H160;
+use sp_runtime::DispatchError;
+
+fn deploy(deployer: u64) {
+	DeployerReputation::on_deployed(&H160::from_low_u64_be(deployer), &H160::repeat_byte(0xcc));
+}
+
+#[test]
+fn unknown_deployer_has_initial_score() {
+	new_test_ext().execute_with(|| {
+		assert_eq!(DeployerReputation::score(&1), 100);
+		assert!(!DeployerReputation::is_suspended(&1));
+	});
+}
+
+#[test]
+fn deployments_are_rewarded() {
+	new_test_ext().execute_with(|| {
+		deploy(1);
+		deploy(1);
+
+		assert_eq!(DeployerReputation::score(&1), 102);
+		assert_eq!(DeployerReputation::score(&2), 100);
+	});
+}
+
+#[test]
+fn adjust_score_works() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(DeployerReputation::adjust_score(RuntimeOrigin::root(), 1, 25));
+		System::assert_last_event(
+			Event::ScoreAdjusted { deployer: 1, delta: 25, score: 125 }.into(),
+		);
+
+		assert_noop!(
+			DeployerReputation::adjust_score(RuntimeOrigin::signed(1), 1, 25),
+			DispatchError::BadOrigin
+		);
+	});
+}
+
+#[test]
+fn scores_are_clamped() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(DeployerReputation::adjust_score(RuntimeOrigin::root(), 1, i32::MAX));
+		assert_eq!(DeployerReputation::score(&1), 1_000);
+
+		assert_ok!(DeployerReputation::adjust_score(RuntimeOrigin::root(), 1, i32::MIN));
+		assert_eq!(DeployerReputation::score(&1), -1_000);
+	});
+}
+
+#[test]
+fn incidents_below_floor_suspend_deployer() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(DeployerReputation::report_incident(RuntimeOrigin::root(), 1));
+		System::assert_last_event(Event::IncidentReported { deployer: 1, score: 60 }.into());
+		assert!(suspended().is_empty());
+
+		assert_ok!(DeployerReputation::report_incident(RuntimeOrigin::root(), 1));
+		System::assert_has_event(Event::DeployerSuspended { deployer: 1, score: 20 }.into());
+		assert_eq!(DeployerReputation::suspended_at(&1), Some(1));
+		assert_eq!(suspended(), vec![1]);
+
+		// Further incidents do not suspend again
+		assert_ok!(DeployerReputation::report_incident(RuntimeOrigin::root(), 1));
+		assert_eq!(suspended(), vec![1]);
+	});
+}
+
+#[test]
+fn suspended_deployer_earns_no_rewards() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(DeployerReputation::adjust_score(RuntimeOrigin::root(), 1, -60));
+		assert!(DeployerReputation::is_suspended(&1));
+
+		deploy(1);
+		assert_eq!(DeployerReputation::score(&1), 40);
+	});
+}
+
+#[test]
+fn reinstate_resets_score() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			DeployerReputation::reinstate(RuntimeOrigin::root(), 1),
+			Error::<Test>::NotSuspended
+		);
+
+		assert_ok!(DeployerReputation::adjust_score(RuntimeOrigin::root(), 1, -60));
+		assert_ok!(DeployerReputation::reinstate(RuntimeOrigin::root(), 1));
+		System::assert_last_event(Event::DeployerReinstated { deployer: 1 }.into());
+
+		assert!(!DeployerReputation::is_suspended(&1));
+		assert!(!Scores::<Test>::contains_key(1));
+		assert_eq!(DeployerReputation::score(&1), 100);
+	});
+}
+
+#[test]
+fn rotated_key_keeps_score() {
+	new_test_ext().execute_with(|| {
+		deploy(1);
+		DeployerReputation::on_key_rotated(&1, &2);
+
+		assert_eq!(DeployerReputation::score(&2), 101);
+		assert!(!Scores::<Test>::contains_key(1));
+	});
+}
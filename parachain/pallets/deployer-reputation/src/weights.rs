@@ -0,0 +1,93 @@
+//! Placeholder weights for `pallet_deployer_reputation`
+//!
+//! NOT MEASURED: the pallet has not been benchmarked yet. The storage accesses and proof
+//! sizes follow the code, while the execution times are hand estimates doubled as a safety
+//! margin, so that blocks are underfilled rather than overfilled until they are measured.
+//! Replace this file with the output of:
+//!
+//! ./target/release/evm-template-node benchmark pallet --chain dev \
+//!     --pallet pallet_deployer_reputation --extrinsic '*' --steps 50 --repeat 20 \
+//!     --output pallets/deployer-reputation/src/weights.rs
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+
+use frame_support::{traits::Get, weights::Weight};
+use core::marker::PhantomData;
+
+/// Weight functions needed for pallet_deployer_reputation.
+pub trait WeightInfo {
+	fn adjust_score() -> Weight;
+	fn report_incident() -> Weight;
+	fn reinstate() -> Weight;
+}
+
+/// Weights for pallet_deployer_reputation using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	/// Storage: `DeployerReputation::Scores` (r:1 w:1)
+	/// Proof: `DeployerReputation::Scores` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `DeployerReputation::Suspended` (r:1 w:1)
+	/// Proof: `DeployerReputation::Suspended` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `EvmDeploymentControl::AuthorizedDeployers` (r:1 w:1)
+	/// Proof: `EvmDeploymentControl::AuthorizedDeployers` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `EvmDeploymentControl::WindowOverrides` (r:1 w:0)
+	/// Proof: `EvmDeploymentControl::WindowOverrides` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	fn adjust_score() -> Weight {
+		// Proof Size estimate in bytes:
+		//  Estimated: `3517`
+		Weight::from_parts(45_862_000, 0)
+			.saturating_add(Weight::from_parts(0, 3517))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	/// Storage: `DeployerReputation::Scores` (r:1 w:1)
+	/// Proof: `DeployerReputation::Scores` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `DeployerReputation::Suspended` (r:1 w:1)
+	/// Proof: `DeployerReputation::Suspended` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `EvmDeploymentControl::AuthorizedDeployers` (r:1 w:1)
+	/// Proof: `EvmDeploymentControl::AuthorizedDeployers` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `EvmDeploymentControl::WindowOverrides` (r:1 w:0)
+	/// Proof: `EvmDeploymentControl::WindowOverrides` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	fn report_incident() -> Weight {
+		// Proof Size estimate in bytes:
+		//  Estimated: `3517`
+		Weight::from_parts(45_430_000, 0)
+			.saturating_add(Weight::from_parts(0, 3517))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	/// Storage: `DeployerReputation::Suspended` (r:1 w:1)
+	/// Proof: `DeployerReputation::Suspended` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `DeployerReputation::Scores` (r:0 w:1)
+	/// Proof: `DeployerReputation::Scores` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	fn reinstate() -> Weight {
+		// Proof Size estimate in bytes:
+		//  Estimated: `3517`
+		Weight::from_parts(26_252_000, 0)
+			.saturating_add(Weight::from_parts(0, 3517))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	fn adjust_score() -> Weight {
+		Weight::from_parts(45_862_000, 3517)
+			.saturating_add(frame_support::weights::constants::RocksDbWeight::get().reads(4))
+			.saturating_add(frame_support::weights::constants::RocksDbWeight::get().writes(3))
+	}
+	fn report_incident() -> Weight {
+		Weight::from_parts(45_430_000, 3517)
+			.saturating_add(frame_support::weights::constants::RocksDbWeight::get().reads(4))
+			.saturating_add(frame_support::weights::constants::RocksDbWeight::get().writes(3))
+	}
+	fn reinstate() -> Weight {
+		Weight::from_parts(26_252_000, 3517)
+			.saturating_add(frame_support::weights::constants::RocksDbWeight::get().reads(1))
+			.saturating_add(frame_support::weights::constants::RocksDbWeight::get().writes(2))
+	}
+}
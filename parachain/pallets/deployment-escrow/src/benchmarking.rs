@@ -0,0 +1,119 @@
+//! Benchmarking setup for pallet-deployment-escrow
+
+use super::*;
+
+#[allow(unused)]
+use crate::Pallet as DeploymentEscrow;
+use frame_benchmarking::v2::*;
+use frame_support::traits::{
+	fungible::{Inspect, InspectHold, Mutate},
+	EnsureOrigin, Get,
+};
+use frame_system::RawOrigin;
+use sp_core::H160;
+use sp_runtime::traits::{Convert, Saturating, Zero};
+use sp_std::vec::Vec;
+
+/// Records a contract deployment and locks an escrow of `amount` for it, returning the
+/// contract and its deployer
+fn escrowed<T: Config>(amount: BalanceOf<T>) -> (H160, T::AccountId) {
+	let contract = T::BenchmarkHelper::record_deployment();
+	let deployer = T::AddressMapping::convert(T::Provenance::deployer_of(&contract).unwrap());
+	T::Currency::set_balance(
+		&deployer,
+		T::Currency::minimum_balance().saturating_add(amount.saturating_mul(2u32.into())),
+	);
+	DeploymentEscrow::<T>::lock(RawOrigin::Signed(deployer.clone()).into(), contract, amount)
+		.unwrap();
+	(contract, deployer)
+}
+
+fn escrow_amount<T: Config>() -> BalanceOf<T> {
+	T::Currency::minimum_balance().saturating_mul(1_000u32.into())
+}
+
+/// Moves past the safe period of escrows locked now
+fn pass_safe_period<T: Config>() {
+	let now = frame_system::Pallet::<T>::block_number();
+	frame_system::Pallet::<T>::set_block_number(now.saturating_add(T::SafePeriod::get()));
+}
+
+#[benchmarks]
+mod benchmarks {
+	use super::*;
+
+	#[benchmark]
+	fn lock() {
+		// Topping up reads and writes as much as a new escrow, and checks the pending release
+		let amount = escrow_amount::<T>();
+		let (contract, deployer) = escrowed::<T>(amount);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(deployer.clone()), contract, amount);
+
+		// Verify the escrow was topped up
+		let held = T::Currency::total_balance_on_hold(&deployer);
+		assert_eq!(held, amount.saturating_mul(2u32.into()));
+	}
+
+	#[benchmark]
+	fn request_release() {
+		let (contract, deployer) = escrowed::<T>(escrow_amount::<T>());
+		pass_safe_period::<T>();
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(deployer), contract);
+
+		// Verify the release is pending
+		assert!(Escrows::<T>::get(contract).unwrap().releases_at.is_some());
+	}
+
+	#[benchmark]
+	fn release() {
+		let (contract, deployer) = escrowed::<T>(escrow_amount::<T>());
+		pass_safe_period::<T>();
+		DeploymentEscrow::<T>::request_release(RawOrigin::Signed(deployer.clone()).into(), contract)
+			.unwrap();
+		let now = frame_system::Pallet::<T>::block_number();
+		frame_system::Pallet::<T>::set_block_number(now.saturating_add(T::ReleaseDelay::get()));
+		let caller: T::AccountId = whitelisted_caller();
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), contract);
+
+		// Verify the escrow was released
+		assert!(!Escrows::<T>::contains_key(contract));
+		assert!(T::Currency::total_balance_on_hold(&deployer).is_zero());
+	}
+
+	#[benchmark]
+	fn slash(p: Linear<0, { T::MaxPayouts::get() }>) {
+		let origin = T::GovernanceOrigin::try_successful_origin().unwrap();
+		let amount = escrow_amount::<T>();
+		let (contract, _) = escrowed::<T>(amount);
+		// Every payout creates the account of its user
+		let payout = T::Currency::minimum_balance();
+		let payouts: Vec<_> = (0..p).map(|i| (account("user", i, 0), payout)).collect();
+		let payouts = PayoutsOf::<T>::try_from(payouts).unwrap();
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, contract, payouts);
+
+		// Verify the escrow was slashed
+		assert!(!Escrows::<T>::contains_key(contract));
+	}
+
+	#[benchmark]
+	fn set_policy() {
+		let origin = T::GovernanceOrigin::try_successful_origin().unwrap();
+		let policy = EscrowPolicy::Required { amount: escrow_amount::<T>() };
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, policy);
+
+		// Verify the policy was set
+		assert_eq!(Policy::<T>::get(), policy);
+	}
+
+	impl_benchmark_test_suite!(DeploymentEscrow, crate::mock::new_test_ext(), crate::mock::Test);
+}
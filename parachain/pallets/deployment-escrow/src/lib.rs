@@ -0,0 +1,534 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+//! # Deployment Escrow Pallet
+//!
+//! Escrows locked per deployed EVM contract, compensating the users of a contract later
+//! ruled malicious.
+//!
+//! ## Overview
+//!
+//! The recorded deployer of a contract, as reported by the configured
+//! [`ContractProvenance`] source, puts an amount on hold for it with [`Pallet::lock`],
+//! and may top it up later. If `GovernanceOrigin` rules the contract malicious, it
+//! slashes the escrow with [`Pallet::slash`], paying the affected users it names and the
+//! rest into `Slashed`.
+//!
+//! Escrows are released on a timelock: once `SafePeriod` has passed since the escrow was
+//! locked, the deployer announces the release with [`Pallet::request_release`], and anyone
+//! executes it with [`Pallet::release`] after another `ReleaseDelay`. The escrow stays
+//! slashable until then, so users see the release coming and have time to raise claims.
+//!
+//! Escrows are optional unless `GovernanceOrigin` sets the [`EscrowPolicy::Required`]
+//! policy. The pallet then locks the required amount from the deployer of every new
+//! contract it is told about as a [`DeploymentLifecycle`] subscriber, and the runtime is
+//! expected to refuse creations of deployers who cannot afford it, see
+//! [`Pallet::ensure_can_escrow`]. Contracts whose escrow could not be locked are reported
+//! with [`Event::EscrowMissing`].
+
+pub use deployment_control_primitives::{ContractProvenance, DeploymentLifecycle};
+pub use pallet::*;
+pub mod weights;
+
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+/// Custom `InvalidTransaction` code of contract creations whose deployer cannot afford the
+/// escrow required by the policy
+///
+/// Follows the codes taken by the runtime, the last being its network binding.
+pub const ESCROW_UNAFFORDABLE: u8 = 9;
+
+/// Records the deployment of a contract for benchmarking
+#[cfg(feature = "runtime-benchmarks")]
+pub trait BenchmarkHelper {
+	/// Record the deployment of a contract and return its address
+	fn record_deployment() -> sp_core::H160;
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+	use crate::ContractProvenance;
+	use frame_support::{
+		pallet_prelude::*,
+		traits::{
+			fungible::{self, BalancedHold, Inspect, InspectHold, Mutate, MutateHold},
+			tokens::{Fortitude, Precision, Restriction},
+			OnUnbalanced,
+		},
+	};
+	use frame_system::pallet_prelude::*;
+	use sp_core::H160;
+	use sp_runtime::traits::{Convert, Saturating, Zero};
+
+	pub use crate::weights::WeightInfo;
+
+	/// Balance of the escrowed currency
+	pub type BalanceOf<T> =
+		<<T as Config>::Currency as Inspect<<T as frame_system::Config>::AccountId>>::Balance;
+
+	/// Slashed escrows
+	pub type CreditOf<T> =
+		fungible::Credit<<T as frame_system::Config>::AccountId, <T as Config>::Currency>;
+
+	/// Affected users paid out of a slashed escrow, with their compensation
+	pub type PayoutsOf<T> = BoundedVec<
+		(<T as frame_system::Config>::AccountId, BalanceOf<T>),
+		<T as Config>::MaxPayouts,
+	>;
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		/// The overarching event type
+		#[allow(deprecated)]
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		/// Currency holding the escrows
+		type Currency: Mutate<Self::AccountId>
+			+ MutateHold<Self::AccountId, Reason = Self::RuntimeHoldReason>
+			+ BalancedHold<Self::AccountId>;
+
+		/// The overarching hold reason
+		type RuntimeHoldReason: From<HoldReason>;
+
+		/// Source of the recorded deployer of contracts
+		type Provenance: ContractProvenance;
+
+		/// Account controlled by an EVM address
+		type AddressMapping: Convert<H160, Self::AccountId>;
+
+		/// Origin ruling on contracts and setting the escrow policy
+		type GovernanceOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// Receiver of what is slashed from an escrow beyond the payouts
+		type Slashed: OnUnbalanced<CreditOf<Self>>;
+
+		/// Number of blocks an escrow stays locked before its release can be requested
+		#[pallet::constant]
+		type SafePeriod: Get<BlockNumberFor<Self>>;
+
+		/// Number of blocks between the request of a release and its execution
+		#[pallet::constant]
+		type ReleaseDelay: Get<BlockNumberFor<Self>>;
+
+		/// Maximum number of affected users paid out of a slashed escrow
+		#[pallet::constant]
+		type MaxPayouts: Get<u32>;
+
+		/// Weight information for extrinsics in this pallet
+		type WeightInfo: WeightInfo;
+
+		/// Records contract deployments for benchmarking
+		#[cfg(feature = "runtime-benchmarks")]
+		type BenchmarkHelper: crate::BenchmarkHelper;
+	}
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	/// Reasons for the pallet to hold funds
+	#[pallet::composite_enum]
+	pub enum HoldReason {
+		/// Escrow locked for a deployed contract
+		DeploymentEscrow,
+	}
+
+	/// Whether deployers must lock an escrow for their contracts
+	#[derive(
+		Encode, Decode, Clone, Copy, PartialEq, Eq, Default, RuntimeDebug, TypeInfo, MaxEncodedLen,
+	)]
+	pub enum EscrowPolicy<Balance> {
+		/// Deployers lock escrows of their choice, if any
+		#[default]
+		Optional,
+		/// Every new contract has `amount` locked from its deployer
+		Required {
+			/// Amount locked per contract
+			amount: Balance,
+		},
+	}
+
+	/// Escrow of a deployed contract
+	#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub struct Escrow<AccountId, Balance, BlockNumber> {
+		/// Account the escrow is held from
+		pub deployer: AccountId,
+		/// Amount on hold
+		pub amount: Balance,
+		/// Block in which the escrow was first locked
+		pub locked_at: BlockNumber,
+		/// Block from which the escrow can be released, once requested
+		pub releases_at: Option<BlockNumber>,
+	}
+
+	/// Escrow policy in effect
+	#[pallet::storage]
+	pub type Policy<T: Config> = StorageValue<_, EscrowPolicy<BalanceOf<T>>, ValueQuery>;
+
+	/// Escrows, keyed by contract address
+	#[pallet::storage]
+	pub type Escrows<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		H160,
+		Escrow<T::AccountId, BalanceOf<T>, BlockNumberFor<T>>,
+		OptionQuery,
+	>;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// An escrow was locked or topped up
+		EscrowLocked {
+			/// The contract
+			contract: H160,
+			/// The deployer
+			deployer: T::AccountId,
+			/// Amount now on hold for the contract
+			amount: BalanceOf<T>,
+		},
+		/// The release of an escrow was requested
+		ReleaseRequested {
+			/// The contract
+			contract: H160,
+			/// Block from which the escrow can be released
+			releases_at: BlockNumberFor<T>,
+		},
+		/// An escrow was released to its deployer
+		EscrowReleased {
+			/// The contract
+			contract: H160,
+			/// The deployer
+			deployer: T::AccountId,
+			/// Amount released
+			amount: BalanceOf<T>,
+		},
+		/// The escrow of a contract ruled malicious was slashed
+		EscrowSlashed {
+			/// The contract
+			contract: H160,
+			/// The deployer
+			deployer: T::AccountId,
+			/// Amount paid to the affected users
+			paid_out: BalanceOf<T>,
+			/// Amount paid into `Slashed`
+			slashed: BalanceOf<T>,
+		},
+		/// The escrow required by the policy could not be locked for a new contract
+		EscrowMissing {
+			/// The contract
+			contract: H160,
+			/// The deployer
+			deployer: T::AccountId,
+		},
+		/// The escrow policy was replaced
+		PolicySet {
+			/// The new policy
+			policy: EscrowPolicy<BalanceOf<T>>,
+		},
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The caller is not the recorded deployer of the contract
+		NotDeployer,
+		/// The contract has no escrow
+		NoEscrow,
+		/// The amount is zero, or below the escrow required by the policy
+		InsufficientEscrow,
+		/// The release of the escrow was already requested
+		ReleaseRequested,
+		/// The escrow is still within its safe period
+		SafePeriodNotOver,
+		/// The release of the escrow was not requested or is not due yet
+		ReleaseNotDue,
+		/// The payouts exceed the escrow
+		PayoutsExceedEscrow,
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Lock an escrow for a contract deployed by the caller, or top it up
+		///
+		/// # Parameters
+		/// - `origin`: Must be signed by the account of the contract's recorded deployer
+		/// - `contract`: The contract
+		/// - `amount`: Amount to put on hold
+		///
+		/// # Errors
+		/// - `NotDeployer`: The caller did not deploy the contract
+		/// - `InsufficientEscrow`: The amount is zero, or a new escrow is below the one
+		///   required by the policy
+		/// - `ReleaseRequested`: The release of the escrow is pending
+		///
+		/// # Events
+		/// - `EscrowLocked`: Emitted with the amount now on hold
+		#[pallet::call_index(0)]
+		#[pallet::weight(T::WeightInfo::lock())]
+		pub fn lock(origin: OriginFor<T>, contract: H160, amount: BalanceOf<T>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let deployer = T::Provenance::deployer_of(&contract).ok_or(Error::<T>::NotDeployer)?;
+			ensure!(T::AddressMapping::convert(deployer) == who, Error::<T>::NotDeployer);
+			ensure!(!amount.is_zero(), Error::<T>::InsufficientEscrow);
+
+			let escrow = match Escrows::<T>::get(contract) {
+				Some(mut escrow) => {
+					ensure!(escrow.releases_at.is_none(), Error::<T>::ReleaseRequested);
+					escrow.amount.saturating_accrue(amount);
+					escrow
+				}
+				None => {
+					if let EscrowPolicy::Required { amount: required } = Policy::<T>::get() {
+						ensure!(amount >= required, Error::<T>::InsufficientEscrow);
+					}
+					Escrow {
+						deployer: who.clone(),
+						amount,
+						locked_at: frame_system::Pallet::<T>::block_number(),
+						releases_at: None,
+					}
+				}
+			};
+			T::Currency::hold(&HoldReason::DeploymentEscrow.into(), &who, amount)?;
+			let total = escrow.amount;
+			Escrows::<T>::insert(contract, escrow);
+			Self::deposit_event(Event::EscrowLocked { contract, deployer: who, amount: total });
+
+			Ok(())
+		}
+
+		/// Start the timelock releasing the escrow of a contract
+		///
+		/// The escrow can be released `ReleaseDelay` blocks later, and stays slashable until
+		/// then.
+		///
+		/// # Parameters
+		/// - `origin`: Must be signed by the deployer the escrow is held from
+		/// - `contract`: The contract
+		///
+		/// # Errors
+		/// - `NoEscrow`: The contract has no escrow
+		/// - `NotDeployer`: The caller is not the deployer the escrow is held from
+		/// - `ReleaseRequested`: The release was already requested
+		/// - `SafePeriodNotOver`: `SafePeriod` has not passed since the escrow was locked
+		///
+		/// # Events
+		/// - `ReleaseRequested`: Emitted with the block from which the escrow can be released
+		#[pallet::call_index(1)]
+		#[pallet::weight(T::WeightInfo::request_release())]
+		pub fn request_release(origin: OriginFor<T>, contract: H160) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let now = frame_system::Pallet::<T>::block_number();
+			let releases_at = Escrows::<T>::try_mutate(contract, |escrow| {
+				let escrow = escrow.as_mut().ok_or(Error::<T>::NoEscrow)?;
+				ensure!(escrow.deployer == who, Error::<T>::NotDeployer);
+				ensure!(escrow.releases_at.is_none(), Error::<T>::ReleaseRequested);
+				ensure!(
+					now >= escrow.locked_at.saturating_add(T::SafePeriod::get()),
+					Error::<T>::SafePeriodNotOver
+				);
+
+				let releases_at = now.saturating_add(T::ReleaseDelay::get());
+				escrow.releases_at = Some(releases_at);
+				Ok::<_, Error<T>>(releases_at)
+			})?;
+			Self::deposit_event(Event::ReleaseRequested { contract, releases_at });
+
+			Ok(())
+		}
+
+		/// Release an escrow whose timelock is over to its deployer
+		///
+		/// # Parameters
+		/// - `origin`: Any signed account
+		/// - `contract`: The contract
+		///
+		/// # Errors
+		/// - `NoEscrow`: The contract has no escrow
+		/// - `ReleaseNotDue`: The release was not requested or is not due yet
+		///
+		/// # Events
+		/// - `EscrowReleased`: Emitted with the released amount
+		#[pallet::call_index(2)]
+		#[pallet::weight(T::WeightInfo::release())]
+		pub fn release(origin: OriginFor<T>, contract: H160) -> DispatchResult {
+			ensure_signed(origin)?;
+
+			let escrow = Escrows::<T>::get(contract).ok_or(Error::<T>::NoEscrow)?;
+			let now = frame_system::Pallet::<T>::block_number();
+			ensure!(
+				escrow.releases_at.map_or(false, |releases_at| now >= releases_at),
+				Error::<T>::ReleaseNotDue
+			);
+
+			let amount = T::Currency::release(
+				&HoldReason::DeploymentEscrow.into(),
+				&escrow.deployer,
+				escrow.amount,
+				Precision::BestEffort,
+			)?;
+			Escrows::<T>::remove(contract);
+			Self::deposit_event(Event::EscrowReleased {
+				contract,
+				deployer: escrow.deployer,
+				amount,
+			});
+
+			Ok(())
+		}
+
+		/// Slash the escrow of a contract ruled malicious
+		///
+		/// Pays each affected user its compensation from the escrow, and the rest of the
+		/// escrow into `Slashed`.
+		///
+		/// # Parameters
+		/// - `origin`: Must be `GovernanceOrigin`
+		/// - `contract`: The contract
+		/// - `payouts`: Affected users with their compensation
+		///
+		/// # Errors
+		/// - `NoEscrow`: The contract has no escrow
+		/// - `PayoutsExceedEscrow`: The compensations add up to more than the escrow
+		///
+		/// # Events
+		/// - `EscrowSlashed`: Emitted with the amounts paid out and slashed
+		#[pallet::call_index(3)]
+		#[pallet::weight(T::WeightInfo::slash(payouts.len() as u32))]
+		pub fn slash(
+			origin: OriginFor<T>,
+			contract: H160,
+			payouts: PayoutsOf<T>,
+		) -> DispatchResult {
+			T::GovernanceOrigin::ensure_origin(origin)?;
+
+			let escrow = Escrows::<T>::get(contract).ok_or(Error::<T>::NoEscrow)?;
+			let total = payouts
+				.iter()
+				.fold(BalanceOf::<T>::zero(), |total, (_, amount)| total.saturating_add(*amount));
+			ensure!(total <= escrow.amount, Error::<T>::PayoutsExceedEscrow);
+
+			let reason = HoldReason::DeploymentEscrow.into();
+			let mut paid_out = BalanceOf::<T>::zero();
+			for (user, amount) in payouts {
+				let paid = T::Currency::transfer_on_hold(
+					&reason,
+					&escrow.deployer,
+					&user,
+					amount,
+					Precision::BestEffort,
+					Restriction::Free,
+					Fortitude::Force,
+				)?;
+				paid_out.saturating_accrue(paid);
+			}
+			let (credit, _) = <T::Currency as BalancedHold<_>>::slash(
+				&reason,
+				&escrow.deployer,
+				escrow.amount.saturating_sub(paid_out),
+			);
+			let slashed = credit.peek();
+			T::Slashed::on_unbalanced(credit);
+			Escrows::<T>::remove(contract);
+			Self::deposit_event(Event::EscrowSlashed {
+				contract,
+				deployer: escrow.deployer,
+				paid_out,
+				slashed,
+			});
+
+			Ok(())
+		}
+
+		/// Replace the escrow policy
+		///
+		/// Escrows locked before a policy change are kept as they are.
+		///
+		/// # Parameters
+		/// - `origin`: Must be `GovernanceOrigin`
+		/// - `policy`: The new policy
+		///
+		/// # Events
+		/// - `PolicySet`: Emitted when the policy is replaced
+		#[pallet::call_index(4)]
+		#[pallet::weight(T::WeightInfo::set_policy())]
+		pub fn set_policy(
+			origin: OriginFor<T>,
+			policy: EscrowPolicy<BalanceOf<T>>,
+		) -> DispatchResult {
+			T::GovernanceOrigin::ensure_origin(origin)?;
+
+			Policy::<T>::put(policy);
+			Self::deposit_event(Event::PolicySet { policy });
+
+			Ok(())
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// Escrow of `contract`
+		pub fn escrow(
+			contract: &H160,
+		) -> Option<Escrow<T::AccountId, BalanceOf<T>, BlockNumberFor<T>>> {
+			Escrows::<T>::get(contract)
+		}
+
+		/// Check that `who` can afford the escrow the policy requires for a new contract
+		///
+		/// Meant for the transaction validation of the runtime, to refuse contract creations
+		/// whose escrow could not be locked.
+		///
+		/// # Errors
+		/// The error of the currency if the amount cannot be put on hold
+		pub fn ensure_can_escrow(who: &T::AccountId) -> DispatchResult {
+			match Policy::<T>::get() {
+				EscrowPolicy::Optional => Ok(()),
+				EscrowPolicy::Required { amount } => T::Currency::ensure_can_hold(
+					&HoldReason::DeploymentEscrow.into(),
+					who,
+					amount,
+				),
+			}
+		}
+
+		/// Lock the escrow required by the policy for a new contract
+		fn lock_required(deployer: H160, contract: H160) {
+			let EscrowPolicy::Required { amount } = Policy::<T>::get() else {
+				return;
+			};
+			if Escrows::<T>::contains_key(contract) {
+				return;
+			}
+			let deployer = T::AddressMapping::convert(deployer);
+
+			if T::Currency::hold(&HoldReason::DeploymentEscrow.into(), &deployer, amount).is_err() {
+				Self::deposit_event(Event::EscrowMissing { contract, deployer });
+				return;
+			}
+			Escrows::<T>::insert(
+				contract,
+				Escrow {
+					deployer: deployer.clone(),
+					amount,
+					locked_at: frame_system::Pallet::<T>::block_number(),
+					releases_at: None,
+				},
+			);
+			Self::deposit_event(Event::EscrowLocked { contract, deployer, amount });
+		}
+	}
+
+	/// Locks the escrow required by the policy for every contract created by the EVM
+	impl<T: Config> crate::DeploymentLifecycle<T::AccountId> for Pallet<T> {
+		fn on_deployed(deployer: &H160, contract: &H160) {
+			Self::lock_required(*deployer, *contract);
+		}
+	}
+}
@@ -0,0 +1,176 @@
+use crate::{mock::*, DeploymentLifecycle, Error, EscrowPolicy, Escrows, Event};
+use frame_support::{
+	assert_noop, assert_ok,
+	traits::fungible::{Inspect, InspectHold},
+	BoundedVec,
+};
+use sp_core::H160;
+use sp_runtime::{DispatchError, TokenError};
+
+fn held(who: u64) -> u64 {
+	Balances::total_balance_on_hold(&who)
+}
+
+fn contract() -> H160 {
+	H160::repeat_byte(0xcc)
+}
+
+#[test]
+fn deployer_locks_and_tops_up_an_escrow() {
+	new_test_ext().execute_with(|| {
+		deploy(H160::from_low_u64_be(1), contract());
+
+		assert_noop!(
+			DeploymentEscrow::lock(RuntimeOrigin::signed(2), contract(), 100),
+			Error::<Test>::NotDeployer
+		);
+		assert_noop!(
+			DeploymentEscrow::lock(RuntimeOrigin::signed(1), H160::repeat_byte(0xdd), 100),
+			Error::<Test>::NotDeployer
+		);
+		assert_noop!(
+			DeploymentEscrow::lock(RuntimeOrigin::signed(1), contract(), 0),
+			Error::<Test>::InsufficientEscrow
+		);
+		assert_noop!(
+			DeploymentEscrow::lock(RuntimeOrigin::signed(1), contract(), 1_001),
+			TokenError::FundsUnavailable
+		);
+
+		assert_ok!(DeploymentEscrow::lock(RuntimeOrigin::signed(1), contract(), 100));
+		assert_ok!(DeploymentEscrow::lock(RuntimeOrigin::signed(1), contract(), 50));
+		System::assert_last_event(
+			Event::EscrowLocked { contract: contract(), deployer: 1, amount: 150 }.into(),
+		);
+		assert_eq!(held(1), 150);
+		assert_eq!(DeploymentEscrow::escrow(&contract()).map(|escrow| escrow.locked_at), Some(1));
+	});
+}
+
+#[test]
+fn escrow_is_released_after_the_safe_period_and_the_delay() {
+	new_test_ext().execute_with(|| {
+		deploy(H160::from_low_u64_be(1), contract());
+		assert_ok!(DeploymentEscrow::lock(RuntimeOrigin::signed(1), contract(), 100));
+
+		assert_noop!(
+			DeploymentEscrow::request_release(RuntimeOrigin::signed(1), contract()),
+			Error::<Test>::SafePeriodNotOver
+		);
+		System::set_block_number(101);
+		assert_noop!(
+			DeploymentEscrow::request_release(RuntimeOrigin::signed(2), contract()),
+			Error::<Test>::NotDeployer
+		);
+		assert_ok!(DeploymentEscrow::request_release(RuntimeOrigin::signed(1), contract()));
+		System::assert_last_event(
+			Event::ReleaseRequested { contract: contract(), releases_at: 111 }.into(),
+		);
+
+		// A pending release can be neither topped up nor requested again
+		assert_noop!(
+			DeploymentEscrow::lock(RuntimeOrigin::signed(1), contract(), 10),
+			Error::<Test>::ReleaseRequested
+		);
+		assert_noop!(
+			DeploymentEscrow::request_release(RuntimeOrigin::signed(1), contract()),
+			Error::<Test>::ReleaseRequested
+		);
+
+		System::set_block_number(110);
+		assert_noop!(
+			DeploymentEscrow::release(RuntimeOrigin::signed(3), contract()),
+			Error::<Test>::ReleaseNotDue
+		);
+		System::set_block_number(111);
+		assert_ok!(DeploymentEscrow::release(RuntimeOrigin::signed(3), contract()));
+		System::assert_last_event(
+			Event::EscrowReleased { contract: contract(), deployer: 1, amount: 100 }.into(),
+		);
+		assert_eq!(held(1), 0);
+		assert!(!Escrows::<Test>::contains_key(contract()));
+	});
+}
+
+#[test]
+fn slash_pays_affected_users_and_the_rest_to_slashed() {
+	new_test_ext().execute_with(|| {
+		deploy(H160::from_low_u64_be(1), contract());
+		assert_ok!(DeploymentEscrow::lock(RuntimeOrigin::signed(1), contract(), 100));
+		let payouts = |payouts: Vec<(u64, u64)>| BoundedVec::truncate_from(payouts);
+
+		assert_noop!(
+			DeploymentEscrow::slash(RuntimeOrigin::signed(2), contract(), payouts(vec![])),
+			DispatchError::BadOrigin
+		);
+		assert_noop!(
+			DeploymentEscrow::slash(
+				RuntimeOrigin::root(),
+				contract(),
+				payouts(vec![(2, 60), (3, 41)])
+			),
+			Error::<Test>::PayoutsExceedEscrow
+		);
+
+		// Still slashable while its release is pending
+		System::set_block_number(101);
+		assert_ok!(DeploymentEscrow::request_release(RuntimeOrigin::signed(1), contract()));
+		assert_ok!(DeploymentEscrow::slash(
+			RuntimeOrigin::root(),
+			contract(),
+			payouts(vec![(2, 60), (3, 30)])
+		));
+		System::assert_last_event(
+			Event::EscrowSlashed { contract: contract(), deployer: 1, paid_out: 90, slashed: 10 }
+				.into(),
+		);
+		assert_eq!(Balances::balance(&1), 900);
+		assert_eq!(Balances::balance(&2), 1_060);
+		assert_eq!(Balances::balance(&3), 1_030);
+		assert_eq!(Balances::balance(&SlashedAccount::get()), 11);
+		assert!(!Escrows::<Test>::contains_key(contract()));
+	});
+}
+
+#[test]
+fn required_policy_locks_escrows_of_new_contracts() {
+	new_test_ext().execute_with(|| {
+		let policy = EscrowPolicy::Required { amount: 200 };
+		assert_noop!(
+			DeploymentEscrow::set_policy(RuntimeOrigin::signed(1), policy),
+			DispatchError::BadOrigin
+		);
+		assert_ok!(DeploymentEscrow::set_policy(RuntimeOrigin::root(), policy));
+
+		// Escrows locked by hand must cover the requirement
+		deploy(H160::from_low_u64_be(1), contract());
+		assert_noop!(
+			DeploymentEscrow::lock(RuntimeOrigin::signed(1), contract(), 100),
+			Error::<Test>::InsufficientEscrow
+		);
+
+		DeploymentEscrow::on_deployed(&H160::from_low_u64_be(1), &contract());
+		assert_eq!(held(1), 200);
+		assert_eq!(DeploymentEscrow::escrow(&contract()).map(|escrow| escrow.amount), Some(200));
+
+		// Deployers who cannot afford the escrow are reported
+		assert_ok!(DeploymentEscrow::ensure_can_escrow(&2));
+		assert_ok!(Balances::transfer_all(RuntimeOrigin::signed(2), 3, false));
+		assert!(DeploymentEscrow::ensure_can_escrow(&2).is_err());
+		let other = H160::repeat_byte(0xdd);
+		DeploymentEscrow::on_deployed(&H160::from_low_u64_be(2), &other);
+		System::assert_last_event(Event::EscrowMissing { contract: other, deployer: 2 }.into());
+		assert!(!Escrows::<Test>::contains_key(other));
+	});
+}
+
+#[test]
+fn optional_policy_locks_nothing_on_deployment() {
+	new_test_ext().execute_with(|| {
+		DeploymentEscrow::on_deployed(&H160::from_low_u64_be(1), &contract());
+
+		assert_eq!(held(1), 0);
+		assert!(!Escrows::<Test>::contains_key(contract()));
+		assert_ok!(DeploymentEscrow::ensure_can_escrow(&1));
+	});
+}
@@ -0,0 +1,138 @@
+//! Placeholder weights for `pallet_deployment_escrow`
+//!
+//! NOT MEASURED: the pallet has not been benchmarked yet. The storage accesses and proof
+//! sizes follow the code, while the execution times are hand estimates doubled as a safety
+//! margin, so that blocks are underfilled rather than overfilled until they are measured.
+//! Replace this file with the output of:
+//!
+//! ./target/release/evm-template-node benchmark pallet --chain dev \
+//!     --pallet pallet_deployment_escrow --extrinsic '*' --steps 50 --repeat 20 \
+//!     --output pallets/deployment-escrow/src/weights.rs
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+
+use frame_support::{traits::Get, weights::Weight};
+use core::marker::PhantomData;
+
+/// Weight functions needed for pallet_deployment_escrow.
+pub trait WeightInfo {
+	fn lock() -> Weight;
+	fn request_release() -> Weight;
+	fn release() -> Weight;
+	fn slash(p: u32, ) -> Weight;
+	fn set_policy() -> Weight;
+}
+
+/// Weights for pallet_deployment_escrow using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	/// Storage: `DeploymentEscrow::Escrows` (r:1 w:1)
+	/// Proof: `DeploymentEscrow::Escrows` (`max_values`: None, `max_size`: Some(93), added: 2568, mode: `MaxEncodedLen`)
+	/// Storage: `EvmDeploymentControl::ContractProvenance` (r:1 w:0)
+	/// Proof: `EvmDeploymentControl::ContractProvenance` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
+	/// Storage: `DeploymentEscrow::Policy` (r:1 w:0)
+	/// Proof: `DeploymentEscrow::Policy` (`max_values`: Some(1), `max_size`: Some(18), added: 513, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(211), added: 2686, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(116), added: 2591, mode: `MaxEncodedLen`)
+	fn lock() -> Weight {
+		// Proof Size estimate in bytes:
+		//  Estimated: `3676`
+		Weight::from_parts(76_804_000, 0)
+			.saturating_add(Weight::from_parts(0, 3676))
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	/// Storage: `DeploymentEscrow::Escrows` (r:1 w:1)
+	/// Proof: `DeploymentEscrow::Escrows` (`max_values`: None, `max_size`: Some(93), added: 2568, mode: `MaxEncodedLen`)
+	fn request_release() -> Weight {
+		// Proof Size estimate in bytes:
+		//  Estimated: `3558`
+		Weight::from_parts(24_374_000, 0)
+			.saturating_add(Weight::from_parts(0, 3558))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `DeploymentEscrow::Escrows` (r:1 w:1)
+	/// Proof: `DeploymentEscrow::Escrows` (`max_values`: None, `max_size`: Some(93), added: 2568, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(211), added: 2686, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(116), added: 2591, mode: `MaxEncodedLen`)
+	fn release() -> Weight {
+		// Proof Size estimate in bytes:
+		//  Estimated: `3676`
+		Weight::from_parts(63_280_000, 0)
+			.saturating_add(Weight::from_parts(0, 3676))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	/// Storage: `DeploymentEscrow::Escrows` (r:1 w:1)
+	/// Proof: `DeploymentEscrow::Escrows` (`max_values`: None, `max_size`: Some(93), added: 2568, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(211), added: 2686, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:11 w:11)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(116), added: 2591, mode: `MaxEncodedLen`)
+	/// The range of component `p` is `[0, 10]`.
+	fn slash(p: u32, ) -> Weight {
+		// Proof Size estimate in bytes:
+		//  Estimated: `3676 + p * (2591 ±0)`
+		Weight::from_parts(71_814_000, 0)
+			.saturating_add(Weight::from_parts(0, 3676))
+			// Standard Error: 9_384
+			.saturating_add(Weight::from_parts(34_476_000, 0).saturating_mul(p.into()))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(p.into())))
+			.saturating_add(T::DbWeight::get().writes(3))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(p.into())))
+			.saturating_add(Weight::from_parts(0, 2591).saturating_mul(p.into()))
+	}
+	/// Storage: `DeploymentEscrow::Policy` (r:0 w:1)
+	/// Proof: `DeploymentEscrow::Policy` (`max_values`: Some(1), `max_size`: Some(18), added: 513, mode: `MaxEncodedLen`)
+	fn set_policy() -> Weight {
+		// Proof Size estimate in bytes:
+		//  Estimated: `0`
+		Weight::from_parts(11_744_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	fn lock() -> Weight {
+		Weight::from_parts(76_804_000, 3676)
+			.saturating_add(Weight::from_parts(0, 3676))
+			.saturating_add(frame_support::weights::constants::RocksDbWeight::get().reads(5))
+			.saturating_add(frame_support::weights::constants::RocksDbWeight::get().writes(3))
+	}
+	fn request_release() -> Weight {
+		Weight::from_parts(24_374_000, 3558)
+			.saturating_add(Weight::from_parts(0, 3558))
+			.saturating_add(frame_support::weights::constants::RocksDbWeight::get().reads(1))
+			.saturating_add(frame_support::weights::constants::RocksDbWeight::get().writes(1))
+	}
+	fn release() -> Weight {
+		Weight::from_parts(63_280_000, 3676)
+			.saturating_add(Weight::from_parts(0, 3676))
+			.saturating_add(frame_support::weights::constants::RocksDbWeight::get().reads(3))
+			.saturating_add(frame_support::weights::constants::RocksDbWeight::get().writes(3))
+	}
+	fn slash(p: u32, ) -> Weight {
+		Weight::from_parts(71_814_000, 3676)
+			.saturating_add(Weight::from_parts(0, 3676))
+			.saturating_add(Weight::from_parts(34_476_000, 0).saturating_mul(p.into()))
+			.saturating_add(frame_support::weights::constants::RocksDbWeight::get().reads(3))
+			.saturating_add(frame_support::weights::constants::RocksDbWeight::get().reads((1_u64).saturating_mul(p.into())))
+			.saturating_add(frame_support::weights::constants::RocksDbWeight::get().writes(3))
+			.saturating_add(frame_support::weights::constants::RocksDbWeight::get().writes((1_u64).saturating_mul(p.into())))
+			.saturating_add(Weight::from_parts(0, 2591).saturating_mul(p.into()))
+	}
+	fn set_policy() -> Weight {
+		Weight::from_parts(11_744_000, 0)
+			.saturating_add(frame_support::weights::constants::RocksDbWeight::get().writes(1))
+	}
+}
@@ -0,0 +1,26 @@
+//! Benchmarking setup for pallet-elastic-scaling
+
+use super::*;
+
+#[allow(unused)]
+use crate::Pallet as ElasticScaling;
+use frame_benchmarking::v2::*;
+use frame_support::traits::{EnsureOrigin, Get};
+
+#[benchmarks]
+mod benchmarks {
+	use super::*;
+
+	#[benchmark]
+	fn set_target_cores() {
+		let origin = T::ScalingOrigin::try_successful_origin().unwrap();
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, T::MaxCores::get());
+
+		// Verify the target was stored
+		assert_eq!(TargetCores::<T>::get(), Some(T::MaxCores::get()));
+	}
+
+	impl_benchmark_test_suite!(ElasticScaling, crate::mock::new_test_ext(), crate::mock::Test);
+}
@@ -0,0 +1,152 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+//! # Elastic Scaling Pallet
+//!
+//! Governance control over the number of relay chain cores the parachain builds blocks
+//! for.
+//!
+//! ## Overview
+//!
+//! With elastic scaling, the parachain is assigned several relay chain cores and its
+//! collators build one block per core on top of each relay chain block. The runtime's
+//! block processing velocity sets the most blocks that can ever be built per relay chain
+//! slot, `MaxCores`. This pallet lets governance lower the number of blocks actually
+//! built, the target core count, to match the cores purchased for the current load,
+//! e.g. raising it for a deployment storm and lowering it again afterwards.
+//!
+//! The runtime's consensus hook records every block with [`Pallet::note_block`], under the
+//! relay chain slot read from its relay chain state proof. Collators consult
+//! [`Pallet::can_author`] through the unincluded segment runtime API before building, and
+//! stop at the target; `MaxCores` is enforced by the block processing velocity.
+
+pub use pallet::*;
+pub mod weights;
+
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+#[frame_support::pallet]
+pub mod pallet {
+	use frame_support::pallet_prelude::*;
+	use frame_system::pallet_prelude::*;
+
+	pub use crate::weights::WeightInfo;
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		/// The overarching event type
+		#[allow(deprecated)]
+		type RuntimeEvent: From<Event<Self>>
+			+ IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		/// Origin allowed to set the target core count
+		type ScalingOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// Maximum number of cores, the runtime's block processing velocity
+		#[pallet::constant]
+		type MaxCores: Get<u32>;
+
+		/// Weight information for extrinsics in this pallet
+		type WeightInfo: WeightInfo;
+	}
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	/// Number of blocks to build per relay chain slot
+	///
+	/// `MaxCores` while unset.
+	#[pallet::storage]
+	pub type TargetCores<T: Config> = StorageValue<_, u32, OptionQuery>;
+
+	/// Relay chain slot of the last block and the number of blocks built in that slot
+	#[pallet::storage]
+	pub type RelaySlotBlocks<T: Config> = StorageValue<_, (u64, u32), OptionQuery>;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// The target core count was changed
+		TargetCoresSet {
+			/// The new number of blocks built per relay chain slot
+			cores: u32,
+		},
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The core count is zero or above `MaxCores`
+		InvalidCoreCount,
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Set the number of cores to build blocks for
+		///
+		/// Takes effect from the next block. Blocks already built in the current relay
+		/// chain slot count against the new target.
+		///
+		/// # Parameters
+		/// - `origin`: Must be `ScalingOrigin`
+		/// - `cores`: The number of blocks to build per relay chain slot
+		///
+		/// # Errors
+		/// - `InvalidCoreCount`: The count is zero or above `MaxCores`
+		///
+		/// # Events
+		/// - `TargetCoresSet`: Emitted when the target is changed
+		#[pallet::call_index(0)]
+		#[pallet::weight(T::WeightInfo::set_target_cores())]
+		pub fn set_target_cores(origin: OriginFor<T>, cores: u32) -> DispatchResult {
+			T::ScalingOrigin::ensure_origin(origin)?;
+
+			ensure!(cores > 0 && cores <= T::MaxCores::get(), Error::<T>::InvalidCoreCount);
+
+			TargetCores::<T>::put(cores);
+			Self::deposit_event(Event::TargetCoresSet { cores });
+
+			Ok(())
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// Number of blocks to build per relay chain slot
+		pub fn target_cores() -> u32 {
+			TargetCores::<T>::get().unwrap_or_else(T::MaxCores::get).min(T::MaxCores::get())
+		}
+
+		/// Relay chain slot of the last block, if one was recorded
+		pub fn last_relay_slot() -> Option<u64> {
+			RelaySlotBlocks::<T>::get().map(|(slot, _)| slot)
+		}
+
+		/// Number of blocks built so far in a relay chain slot
+		pub fn blocks_in(relay_slot: u64) -> u32 {
+			match RelaySlotBlocks::<T>::get() {
+				Some((slot, blocks)) if slot == relay_slot => blocks,
+				_ => 0,
+			}
+		}
+
+		/// Check if another block may be built in a relay chain slot
+		pub fn can_author(relay_slot: u64) -> bool {
+			Self::blocks_in(relay_slot) < Self::target_cores()
+		}
+
+		/// Record a block built in a relay chain slot
+		///
+		/// # Returns
+		/// The number of blocks built in the slot, including this one
+		pub fn note_block(relay_slot: u64) -> u32 {
+			let blocks = Self::blocks_in(relay_slot).saturating_add(1);
+			RelaySlotBlocks::<T>::put((relay_slot, blocks));
+			blocks
+		}
+	}
+}
@@ -0,0 +1,70 @@
+use crate::{mock::*, Error, Event, TargetCores};
+use frame_support::{assert_noop, assert_ok};
+use sp_runtime::DispatchError;
+
+#[test]
+fn target_defaults_to_max_cores() {
+	new_test_ext().execute_with(|| {
+		assert_eq!(ElasticScaling::target_cores(), 3);
+	});
+}
+
+#[test]
+fn set_target_cores_works() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(ElasticScaling::set_target_cores(RuntimeOrigin::root(), 1));
+		System::assert_last_event(Event::TargetCoresSet { cores: 1 }.into());
+		assert_eq!(TargetCores::<Test>::get(), Some(1));
+		assert_eq!(ElasticScaling::target_cores(), 1);
+	});
+}
+
+#[test]
+fn set_target_cores_validates_input() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			ElasticScaling::set_target_cores(RuntimeOrigin::signed(1), 2),
+			DispatchError::BadOrigin
+		);
+		assert_noop!(
+			ElasticScaling::set_target_cores(RuntimeOrigin::root(), 0),
+			Error::<Test>::InvalidCoreCount
+		);
+		assert_noop!(
+			ElasticScaling::set_target_cores(RuntimeOrigin::root(), 4),
+			Error::<Test>::InvalidCoreCount
+		);
+	});
+}
+
+#[test]
+fn blocks_are_limited_per_relay_slot() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(ElasticScaling::set_target_cores(RuntimeOrigin::root(), 2));
+
+		assert!(ElasticScaling::can_author(10));
+		assert_eq!(ElasticScaling::last_relay_slot(), None);
+		assert_eq!(ElasticScaling::note_block(10), 1);
+		assert_eq!(ElasticScaling::last_relay_slot(), Some(10));
+		assert!(ElasticScaling::can_author(10));
+		assert_eq!(ElasticScaling::note_block(10), 2);
+		assert!(!ElasticScaling::can_author(10));
+
+		// The count starts over in the next relay chain slot
+		assert!(ElasticScaling::can_author(11));
+		assert_eq!(ElasticScaling::note_block(11), 1);
+		assert_eq!(ElasticScaling::blocks_in(10), 0);
+	});
+}
+
+#[test]
+fn lowered_target_counts_blocks_already_built() {
+	new_test_ext().execute_with(|| {
+		ElasticScaling::note_block(10);
+		ElasticScaling::note_block(10);
+		assert!(ElasticScaling::can_author(10));
+
+		assert_ok!(ElasticScaling::set_target_cores(RuntimeOrigin::root(), 1));
+		assert!(!ElasticScaling::can_author(10));
+	});
+}
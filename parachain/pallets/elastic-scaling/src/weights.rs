@@ -0,0 +1,44 @@
+//! Placeholder weights for `pallet_elastic_scaling`
+//!
+//! NOT MEASURED: the pallet has not been benchmarked yet. The storage accesses and proof
+//! sizes follow the code, while the execution times are hand estimates doubled as a safety
+//! margin, so that blocks are underfilled rather than overfilled until they are measured.
+//! Replace this file with the output of:
+//!
+//! ./target/release/evm-template-node benchmark pallet --chain dev \
+//!     --pallet pallet_elastic_scaling --extrinsic '*' --steps 50 --repeat 20 \
+//!     --output pallets/elastic-scaling/src/weights.rs
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+
+use frame_support::{traits::Get, weights::Weight};
+use core::marker::PhantomData;
+
+/// Weight functions needed for pallet_elastic_scaling.
+pub trait WeightInfo {
+	fn set_target_cores() -> Weight;
+}
+
+/// Weights for pallet_elastic_scaling using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	/// Storage: `ElasticScaling::TargetCores` (r:0 w:1)
+	/// Proof: `ElasticScaling::TargetCores` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	fn set_target_cores() -> Weight {
+		// Proof Size estimate in bytes:
+		//  Estimated: `0`
+		Weight::from_parts(12_188_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	fn set_target_cores() -> Weight {
+		Weight::from_parts(12_188_000, 0)
+			.saturating_add(frame_support::weights::constants::RocksDbWeight::get().writes(1))
+	}
+}
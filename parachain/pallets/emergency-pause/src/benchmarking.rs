@@ -0,0 +1,74 @@
+//! Benchmarking setup for pallet-emergency-pause
+
+use super::*;
+
+#[allow(unused)]
+use crate::Pallet as EmergencyPause;
+use frame_benchmarking::v2::*;
+use frame_support::{
+	traits::{EnsureOrigin, Get},
+	BoundedVec,
+};
+use sp_std::vec::Vec;
+
+/// A pallet name of maximum length, distinct for every `index`
+fn name<T: Config>(index: u32) -> NameOf<T> {
+	let mut name = index.to_le_bytes().to_vec();
+	name.resize(T::MaxNameLen::get() as usize, b'p');
+	name.try_into().unwrap()
+}
+
+/// Fill the pause list up to one entry below its limit
+fn fill_pauses<T: Config>() {
+	let until = frame_system::Pallet::<T>::block_number() + T::MaxPauseDuration::get();
+	let pauses: Vec<_> = (1..T::MaxPauses::get())
+		.map(|index| Pause { pallet: name::<T>(index), call: Some(name::<T>(index)), until })
+		.collect();
+	Pauses::<T>::put(BoundedVec::try_from(pauses).unwrap());
+}
+
+#[benchmarks]
+mod benchmarks {
+	use super::*;
+
+	#[benchmark]
+	fn pause() {
+		fill_pauses::<T>();
+		let origin = T::PauseOrigin::try_successful_origin().unwrap();
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, name::<T>(0), Some(name::<T>(0)), T::MaxPauseDuration::get());
+
+		// Verify the pause took effect
+		assert_eq!(Pauses::<T>::get().len() as u32, T::MaxPauses::get());
+	}
+
+	#[benchmark]
+	fn extend_pause() {
+		fill_pauses::<T>();
+		let origin = T::PauseOrigin::try_successful_origin().unwrap();
+		let last = T::MaxPauses::get() - 1;
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, name::<T>(last), Some(name::<T>(last)), 1u32.into());
+
+		// Verify the pause was shortened to the new duration
+		let now = frame_system::Pallet::<T>::block_number();
+		assert!(Pauses::<T>::get().iter().any(|pause| pause.until == now + 1u32.into()));
+	}
+
+	#[benchmark]
+	fn unpause() {
+		fill_pauses::<T>();
+		let origin = T::ResumeOrigin::try_successful_origin().unwrap();
+		let last = T::MaxPauses::get() - 1;
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, name::<T>(last), Some(name::<T>(last)));
+
+		// Verify the pause was lifted
+		assert_eq!(Pauses::<T>::get().len() as u32, T::MaxPauses::get() - 2);
+	}
+
+	impl_benchmark_test_suite!(EmergencyPause, crate::mock::new_test_ext(), crate::mock::Test);
+}
@@ -0,0 +1,356 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+//! # Emergency Pause Pallet
+//!
+//! A chain-wide circuit breaker for incident response.
+//!
+//! ## Overview
+//!
+//! This pallet lets a fast-track origin pause whole pallets or single calls, e.g. every
+//! `Ethereum::transact`, for a limited number of blocks. Pauses lift automatically once
+//! they expire unless they are extended, so a forgotten pause cannot brick the chain.
+//!
+//! The runtime enforces pauses by including the pallet in its `BaseCallFilter`, for which
+//! the pallet implements `Contains<RuntimeCall>`.
+//!
+//! ## Features
+//!
+//! - Pause a whole pallet or a single call of a pallet
+//! - Automatic un-pause after the configured number of blocks
+//! - Extension of running pauses
+//! - Manual un-pause through a separate origin
+//! - Pallets that can never be paused, such as the system pallets and this one
+
+pub use pallet::*;
+pub mod weights;
+
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+#[frame_support::pallet]
+pub mod pallet {
+	use frame_support::{
+		pallet_prelude::*,
+		traits::{CallMetadata, Contains, GetCallMetadata},
+	};
+	use frame_system::pallet_prelude::*;
+	use sp_runtime::traits::{Saturating, Zero};
+
+	pub use crate::weights::WeightInfo;
+
+	/// Name of a pallet or call, as found in the runtime metadata
+	pub type NameOf<T> = BoundedVec<u8, <T as Config>::MaxNameLen>;
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		/// The overarching event type
+		#[allow(deprecated)]
+		type RuntimeEvent: From<Event<Self>>
+			+ IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		/// The overarching call type, used to look up pallet and call names
+		type RuntimeCall: Parameter
+			+ GetCallMetadata
+			+ IsType<<Self as frame_system::Config>::RuntimeCall>;
+
+		/// Origin allowed to pause and extend pauses, meant to be a fast-track origin
+		type PauseOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// Origin allowed to lift a pause before it expires
+		type ResumeOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// Pallets that can never be paused
+		type UnpausablePallets: Contains<NameOf<Self>>;
+
+		/// Maximum length of a pallet or call name
+		#[pallet::constant]
+		type MaxNameLen: Get<u32>;
+
+		/// Maximum number of pauses in effect at the same time
+		#[pallet::constant]
+		type MaxPauses: Get<u32>;
+
+		/// Maximum number of blocks a single pause or extension may last
+		#[pallet::constant]
+		type MaxPauseDuration: Get<BlockNumberFor<Self>>;
+
+		/// Weight information for extrinsics in this pallet
+		type WeightInfo: WeightInfo;
+	}
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	/// A pause in effect
+	#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub struct Pause<Name, BlockNumber> {
+		/// Name of the paused pallet
+		pub pallet: Name,
+		/// Name of the paused call, `None` if the whole pallet is paused
+		pub call: Option<Name>,
+		/// First block at which the pause no longer applies
+		pub until: BlockNumber,
+	}
+
+	impl<S: Get<u32>, BlockNumber> Pause<BoundedVec<u8, S>, BlockNumber> {
+		/// Whether the pause targets exactly the given pallet and call
+		pub fn targets(
+			&self,
+			pallet: &BoundedVec<u8, S>,
+			call: &Option<BoundedVec<u8, S>>,
+		) -> bool {
+			self.pallet == *pallet && self.call == *call
+		}
+
+		/// Whether the pause covers a dispatch of the given pallet and call
+		pub fn covers(&self, pallet: &[u8], call: &[u8]) -> bool {
+			self.pallet.as_slice() == pallet &&
+				self.call.as_ref().map_or(true, |paused| paused.as_slice() == call)
+		}
+	}
+
+	/// Pause type of this pallet's configuration
+	pub type PauseOf<T> = Pause<NameOf<T>, BlockNumberFor<T>>;
+
+	/// Pauses currently in effect
+	///
+	/// Expired pauses are removed at the start of the block in which they expire.
+	#[pallet::storage]
+	pub type Pauses<T: Config> = StorageValue<_, BoundedVec<PauseOf<T>, T::MaxPauses>, ValueQuery>;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// A pallet or call was paused
+		Paused {
+			/// The paused pallet
+			pallet: NameOf<T>,
+			/// The paused call, `None` for the whole pallet
+			call: Option<NameOf<T>>,
+			/// First block at which the pause no longer applies
+			until: BlockNumberFor<T>,
+		},
+		/// A pause was extended
+		PauseExtended {
+			/// The paused pallet
+			pallet: NameOf<T>,
+			/// The paused call, `None` for the whole pallet
+			call: Option<NameOf<T>>,
+			/// New first block at which the pause no longer applies
+			until: BlockNumberFor<T>,
+		},
+		/// A pause was lifted before it expired
+		Unpaused {
+			/// The pallet that was paused
+			pallet: NameOf<T>,
+			/// The call that was paused, `None` for the whole pallet
+			call: Option<NameOf<T>>,
+		},
+		/// A pause expired
+		PauseExpired {
+			/// The pallet that was paused
+			pallet: NameOf<T>,
+			/// The call that was paused, `None` for the whole pallet
+			call: Option<NameOf<T>>,
+		},
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The pallet can never be paused
+		Unpausable,
+		/// The pallet or call is already paused
+		AlreadyPaused,
+		/// The pallet or call is not paused
+		NotPaused,
+		/// The maximum number of pauses is already in effect
+		TooManyPauses,
+		/// The duration is zero or exceeds `MaxPauseDuration`
+		InvalidDuration,
+	}
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		/// Lift the pauses expiring at this block
+		fn on_initialize(n: BlockNumberFor<T>) -> Weight {
+			let mut pauses = Pauses::<T>::get();
+			let before = pauses.len();
+
+			pauses.retain(|pause| {
+				if pause.until > n {
+					return true;
+				}
+				Self::deposit_event(Event::PauseExpired {
+					pallet: pause.pallet.clone(),
+					call: pause.call.clone(),
+				});
+				false
+			});
+
+			if pauses.len() == before {
+				return T::DbWeight::get().reads(1);
+			}
+
+			Pauses::<T>::put(pauses);
+			T::DbWeight::get().reads_writes(1, 1)
+		}
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Pause a pallet, or a single call of it, for `duration` blocks
+		///
+		/// # Parameters
+		/// - `origin`: Must be `PauseOrigin`
+		/// - `pallet`: Name of the pallet, as found in the runtime metadata
+		/// - `call`: Name of the call, `None` to pause the whole pallet
+		/// - `duration`: Number of blocks the pause lasts, at most `MaxPauseDuration`
+		///
+		/// # Errors
+		/// - `Unpausable`: The pallet can never be paused
+		/// - `AlreadyPaused`: The pallet or call is already paused
+		/// - `TooManyPauses`: The maximum number of pauses is already in effect
+		/// - `InvalidDuration`: The duration is zero or too long
+		///
+		/// # Events
+		/// - `Paused`: When the pause takes effect
+		#[pallet::call_index(0)]
+		#[pallet::weight(T::WeightInfo::pause())]
+		pub fn pause(
+			origin: OriginFor<T>,
+			pallet: NameOf<T>,
+			call: Option<NameOf<T>>,
+			duration: BlockNumberFor<T>,
+		) -> DispatchResult {
+			T::PauseOrigin::ensure_origin(origin)?;
+
+			ensure!(!T::UnpausablePallets::contains(&pallet), Error::<T>::Unpausable);
+			let until = Self::expiry(duration)?;
+
+			Pauses::<T>::try_mutate(|pauses| {
+				ensure!(
+					!pauses.iter().any(|pause| pause.targets(&pallet, &call)),
+					Error::<T>::AlreadyPaused
+				);
+				pauses
+					.try_push(Pause { pallet: pallet.clone(), call: call.clone(), until })
+					.map_err(|_| Error::<T>::TooManyPauses)
+			})?;
+
+			Self::deposit_event(Event::Paused { pallet, call, until });
+
+			Ok(())
+		}
+
+		/// Extend a pause to last `duration` blocks from now
+		///
+		/// # Parameters
+		/// - `origin`: Must be `PauseOrigin`
+		/// - `pallet`: Name of the paused pallet
+		/// - `call`: Name of the paused call, `None` for a whole-pallet pause
+		/// - `duration`: Number of blocks the pause lasts from now, at most `MaxPauseDuration`
+		///
+		/// # Errors
+		/// - `NotPaused`: The pallet or call is not paused
+		/// - `InvalidDuration`: The duration is zero or too long
+		///
+		/// # Events
+		/// - `PauseExtended`: When the pause is extended
+		#[pallet::call_index(1)]
+		#[pallet::weight(T::WeightInfo::extend_pause())]
+		pub fn extend_pause(
+			origin: OriginFor<T>,
+			pallet: NameOf<T>,
+			call: Option<NameOf<T>>,
+			duration: BlockNumberFor<T>,
+		) -> DispatchResult {
+			T::PauseOrigin::ensure_origin(origin)?;
+
+			let until = Self::expiry(duration)?;
+
+			Pauses::<T>::try_mutate(|pauses| {
+				let pause = pauses
+					.iter_mut()
+					.find(|pause| pause.targets(&pallet, &call))
+					.ok_or(Error::<T>::NotPaused)?;
+				pause.until = until;
+				Ok::<_, Error<T>>(())
+			})?;
+
+			Self::deposit_event(Event::PauseExtended { pallet, call, until });
+
+			Ok(())
+		}
+
+		/// Lift a pause before it expires
+		///
+		/// # Parameters
+		/// - `origin`: Must be `ResumeOrigin`
+		/// - `pallet`: Name of the paused pallet
+		/// - `call`: Name of the paused call, `None` for a whole-pallet pause
+		///
+		/// # Errors
+		/// - `NotPaused`: The pallet or call is not paused
+		///
+		/// # Events
+		/// - `Unpaused`: When the pause is lifted
+		#[pallet::call_index(2)]
+		#[pallet::weight(T::WeightInfo::unpause())]
+		pub fn unpause(
+			origin: OriginFor<T>,
+			pallet: NameOf<T>,
+			call: Option<NameOf<T>>,
+		) -> DispatchResult {
+			T::ResumeOrigin::ensure_origin(origin)?;
+
+			Pauses::<T>::try_mutate(|pauses| {
+				let index = pauses
+					.iter()
+					.position(|pause| pause.targets(&pallet, &call))
+					.ok_or(Error::<T>::NotPaused)?;
+				pauses.remove(index);
+				Ok::<_, Error<T>>(())
+			})?;
+
+			Self::deposit_event(Event::Unpaused { pallet, call });
+
+			Ok(())
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// Check if dispatches of the given pallet and call are paused
+		pub fn is_paused(pallet: &[u8], call: &[u8]) -> bool {
+			let now = frame_system::Pallet::<T>::block_number();
+
+			Pauses::<T>::get().iter().any(|pause| pause.until > now && pause.covers(pallet, call))
+		}
+
+		/// Check if the given call is paused
+		pub fn is_call_paused(call: &<T as Config>::RuntimeCall) -> bool {
+			let CallMetadata { pallet_name, function_name } = call.get_call_metadata();
+			Self::is_paused(pallet_name.as_bytes(), function_name.as_bytes())
+		}
+
+		fn expiry(duration: BlockNumberFor<T>) -> Result<BlockNumberFor<T>, Error<T>> {
+			ensure!(
+				!duration.is_zero() && duration <= T::MaxPauseDuration::get(),
+				Error::<T>::InvalidDuration
+			);
+			Ok(frame_system::Pallet::<T>::block_number().saturating_add(duration))
+		}
+	}
+
+	/// Allows every call that is not paused, meant to be part of the `BaseCallFilter`
+	impl<T: Config> Contains<<T as Config>::RuntimeCall> for Pallet<T> {
+		fn contains(call: &<T as Config>::RuntimeCall) -> bool {
+			!Self::is_call_paused(call)
+		}
+	}
+}
@@ -0,0 +1,56 @@
+use crate as pallet_emergency_pause;
+use frame_support::{
+	derive_impl,
+	traits::{ConstU32, ConstU64, Contains},
+};
+use frame_system::EnsureRoot;
+use sp_runtime::{traits::IdentityLookup, BuildStorage};
+
+type Block = frame_system::mocking::MockBlock<Test>;
+
+// Configure a mock runtime to test the pallet.
+frame_support::construct_runtime!(
+	pub enum Test
+	{
+		System: frame_system,
+		EmergencyPause: pallet_emergency_pause,
+	}
+);
+
+#[derive_impl(frame_system::config_preludes::TestDefaultConfig)]
+impl frame_system::Config for Test {
+	type Block = Block;
+	type AccountId = u64;
+	type Lookup = IdentityLookup<Self::AccountId>;
+	type BaseCallFilter = EmergencyPause;
+}
+
+/// The pause pallet can never pause itself
+pub struct UnpausablePallets;
+
+impl Contains<pallet_emergency_pause::NameOf<Test>> for UnpausablePallets {
+	fn contains(pallet: &pallet_emergency_pause::NameOf<Test>) -> bool {
+		pallet.as_slice() == b"EmergencyPause"
+	}
+}
+
+impl pallet_emergency_pause::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type RuntimeCall = RuntimeCall;
+	type PauseOrigin = EnsureRoot<u64>;
+	type ResumeOrigin = EnsureRoot<u64>;
+	type UnpausablePallets = UnpausablePallets;
+	type MaxNameLen = ConstU32<32>;
+	type MaxPauses = ConstU32<4>;
+	type MaxPauseDuration = ConstU64<100>;
+	type WeightInfo = ();
+}
+
+// Build genesis storage according to the mock runtime.
+pub fn new_test_ext() -> sp_io::TestExternalities {
+	let storage = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
+
+	let mut ext: sp_io::TestExternalities = storage.into();
+	ext.execute_with(|| System::set_block_number(1));
+	ext
+}
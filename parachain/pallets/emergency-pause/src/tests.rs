@@ -0,0 +1,181 @@
+use crate::{mock::*, Error, Event, NameOf, Pauses};
+use frame_support::{
+	assert_noop, assert_ok,
+	traits::{Contains, Hooks},
+};
+use sp_runtime::{traits::Dispatchable, DispatchError};
+
+fn name(name: &[u8]) -> NameOf<Test> {
+	name.to_vec().try_into().unwrap()
+}
+
+fn remark() -> RuntimeCall {
+	RuntimeCall::System(frame_system::Call::remark { remark: vec![] })
+}
+
+fn remark_with_event() -> RuntimeCall {
+	RuntimeCall::System(frame_system::Call::remark_with_event { remark: vec![] })
+}
+
+fn run_to_block(n: u64) {
+	while System::block_number() < n {
+		System::set_block_number(System::block_number() + 1);
+		EmergencyPause::on_initialize(System::block_number());
+	}
+}
+
+#[test]
+fn pause_call_works() {
+	new_test_ext().execute_with(|| {
+		assert!(EmergencyPause::contains(&remark()));
+
+		assert_ok!(EmergencyPause::pause(
+			RuntimeOrigin::root(),
+			name(b"System"),
+			Some(name(b"remark")),
+			10
+		));
+		System::assert_last_event(
+			Event::Paused { pallet: name(b"System"), call: Some(name(b"remark")), until: 11 }
+				.into(),
+		);
+
+		assert!(!EmergencyPause::contains(&remark()));
+		assert!(EmergencyPause::contains(&remark_with_event()));
+
+		let result = remark().dispatch(RuntimeOrigin::signed(1));
+		assert_eq!(
+			result.map_err(|e| e.error),
+			Err(frame_system::Error::<Test>::CallFiltered.into())
+		);
+	});
+}
+
+#[test]
+fn pause_pallet_covers_all_calls() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(EmergencyPause::pause(RuntimeOrigin::root(), name(b"System"), None, 10));
+
+		assert!(!EmergencyPause::contains(&remark()));
+		assert!(!EmergencyPause::contains(&remark_with_event()));
+	});
+}
+
+#[test]
+fn pause_requires_pause_origin() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			EmergencyPause::pause(RuntimeOrigin::signed(1), name(b"System"), None, 10),
+			DispatchError::BadOrigin
+		);
+	});
+}
+
+#[test]
+fn pause_rejects_invalid_requests() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			EmergencyPause::pause(RuntimeOrigin::root(), name(b"EmergencyPause"), None, 10),
+			Error::<Test>::Unpausable
+		);
+		assert_noop!(
+			EmergencyPause::pause(RuntimeOrigin::root(), name(b"System"), None, 0),
+			Error::<Test>::InvalidDuration
+		);
+		assert_noop!(
+			EmergencyPause::pause(RuntimeOrigin::root(), name(b"System"), None, 101),
+			Error::<Test>::InvalidDuration
+		);
+
+		assert_ok!(EmergencyPause::pause(RuntimeOrigin::root(), name(b"System"), None, 10));
+		assert_noop!(
+			EmergencyPause::pause(RuntimeOrigin::root(), name(b"System"), None, 10),
+			Error::<Test>::AlreadyPaused
+		);
+
+		for pallet in [b"A", b"B", b"C"] {
+			assert_ok!(EmergencyPause::pause(RuntimeOrigin::root(), name(pallet), None, 10));
+		}
+		assert_noop!(
+			EmergencyPause::pause(RuntimeOrigin::root(), name(b"D"), None, 10),
+			Error::<Test>::TooManyPauses
+		);
+	});
+}
+
+#[test]
+fn pause_expires_automatically() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(EmergencyPause::pause(RuntimeOrigin::root(), name(b"System"), None, 10));
+
+		run_to_block(10);
+		assert!(!EmergencyPause::contains(&remark()));
+
+		run_to_block(11);
+		assert!(EmergencyPause::contains(&remark()));
+		assert!(Pauses::<Test>::get().is_empty());
+		System::assert_last_event(
+			Event::PauseExpired { pallet: name(b"System"), call: None }.into(),
+		);
+	});
+}
+
+#[test]
+fn extend_pause_works() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			EmergencyPause::extend_pause(RuntimeOrigin::root(), name(b"System"), None, 10),
+			Error::<Test>::NotPaused
+		);
+
+		assert_ok!(EmergencyPause::pause(RuntimeOrigin::root(), name(b"System"), None, 10));
+
+		run_to_block(8);
+		assert_ok!(EmergencyPause::extend_pause(RuntimeOrigin::root(), name(b"System"), None, 10));
+		System::assert_last_event(
+			Event::PauseExtended { pallet: name(b"System"), call: None, until: 18 }.into(),
+		);
+
+		run_to_block(17);
+		assert!(!EmergencyPause::contains(&remark()));
+
+		run_to_block(18);
+		assert!(EmergencyPause::contains(&remark()));
+	});
+}
+
+#[test]
+fn unpause_works() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(EmergencyPause::pause(
+			RuntimeOrigin::root(),
+			name(b"System"),
+			Some(name(b"remark")),
+			10
+		));
+
+		// The target must match the pause exactly
+		assert_noop!(
+			EmergencyPause::unpause(RuntimeOrigin::root(), name(b"System"), None),
+			Error::<Test>::NotPaused
+		);
+		assert_noop!(
+			EmergencyPause::unpause(
+				RuntimeOrigin::signed(1),
+				name(b"System"),
+				Some(name(b"remark"))
+			),
+			DispatchError::BadOrigin
+		);
+
+		assert_ok!(EmergencyPause::unpause(
+			RuntimeOrigin::root(),
+			name(b"System"),
+			Some(name(b"remark"))
+		));
+		System::assert_last_event(
+			Event::Unpaused { pallet: name(b"System"), call: Some(name(b"remark")) }.into(),
+		);
+		assert!(EmergencyPause::contains(&remark()));
+	});
+}
@@ -0,0 +1,82 @@
+//! Placeholder weights for `pallet_emergency_pause`
+//!
+//! NOT MEASURED: the pallet has not been benchmarked yet. The storage accesses and proof
+//! sizes follow the code, while the execution times are hand estimates doubled as a safety
+//! margin, so that blocks are underfilled rather than overfilled until they are measured.
+//! Replace this file with the output of:
+//!
+//! ./target/release/evm-template-node benchmark pallet --chain dev \
+//!     --pallet pallet_emergency_pause --extrinsic '*' --steps 50 --repeat 20 \
+//!     --output pallets/emergency-pause/src/weights.rs
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+
+use frame_support::{traits::Get, weights::Weight};
+use core::marker::PhantomData;
+
+/// Weight functions needed for pallet_emergency_pause.
+pub trait WeightInfo {
+	fn pause() -> Weight;
+	fn extend_pause() -> Weight;
+	fn unpause() -> Weight;
+}
+
+/// Weights for pallet_emergency_pause using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	/// Storage: `EmergencyPause::Pauses` (r:1 w:1)
+	/// Proof: `EmergencyPause::Pauses` (`max_values`: Some(1), `max_size`: Some(4245), added: 4740, mode: `MaxEncodedLen`)
+	fn pause() -> Weight {
+		// Proof Size estimate in bytes:
+		//  Estimated: `5730`
+		Weight::from_parts(30_806_000, 0)
+			.saturating_add(Weight::from_parts(0, 5730))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `EmergencyPause::Pauses` (r:1 w:1)
+	/// Proof: `EmergencyPause::Pauses` (`max_values`: Some(1), `max_size`: Some(4245), added: 4740, mode: `MaxEncodedLen`)
+	fn extend_pause() -> Weight {
+		// Proof Size estimate in bytes:
+		//  Estimated: `5730`
+		Weight::from_parts(28_434_000, 0)
+			.saturating_add(Weight::from_parts(0, 5730))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `EmergencyPause::Pauses` (r:1 w:1)
+	/// Proof: `EmergencyPause::Pauses` (`max_values`: Some(1), `max_size`: Some(4245), added: 4740, mode: `MaxEncodedLen`)
+	fn unpause() -> Weight {
+		// Proof Size estimate in bytes:
+		//  Estimated: `5730`
+		Weight::from_parts(29_620_000, 0)
+			.saturating_add(Weight::from_parts(0, 5730))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	fn pause() -> Weight {
+		Weight::from_parts(30_806_000, 5730)
+			.saturating_add(Weight::from_parts(0, 5730))
+			.saturating_add(frame_support::weights::constants::RocksDbWeight::get().reads(1))
+			.saturating_add(frame_support::weights::constants::RocksDbWeight::get().writes(1))
+	}
+	fn extend_pause() -> Weight {
+		Weight::from_parts(28_434_000, 5730)
+			.saturating_add(Weight::from_parts(0, 5730))
+			.saturating_add(frame_support::weights::constants::RocksDbWeight::get().reads(1))
+			.saturating_add(frame_support::weights::constants::RocksDbWeight::get().writes(1))
+	}
+	fn unpause() -> Weight {
+		Weight::from_parts(29_620_000, 5730)
+			.saturating_add(Weight::from_parts(0, 5730))
+			.saturating_add(frame_support::weights::constants::RocksDbWeight::get().reads(1))
+			.saturating_add(frame_support::weights::constants::RocksDbWeight::get().writes(1))
+	}
+}
@@ -0,0 +1,330 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+//! # EVM Deployment Control Runtime API
+//!
+//! Runtime API exposing deployment control state to the node, backing the
+//! `qnch_*` JSON-RPC methods used by explorers, audit and support tooling and the
+//! `eth_getProof` method used by bridges and light clients.
+
+use parity_scale_codec::{Codec, Decode, Encode};
+use scale_info::TypeInfo;
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
+use sp_core::{H160, H256, U256};
+use sp_runtime::{traits::Block as BlockT, DispatchError, RuntimeDebug};
+use sp_std::vec::Vec;
+
+/// Storage slots counted for [`ContractInfo::storage_entries`]
+///
+/// Counting walks the contract's storage, so it stops there to keep the call cheap for
+/// contracts with large state.
+pub const MAX_COUNTED_STORAGE_ENTRIES: u64 = 10_000;
+
+/// Code, storage and provenance information of a deployed contract
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+pub struct ContractInfo<BlockNumber> {
+    /// Size of the contract code in bytes
+    pub code_size: u64,
+    /// Keccak-256 hash of the contract code
+    #[cfg_attr(feature = "json-schema", schemars(with = "String"))]
+    pub code_hash: H256,
+    /// Number of storage slots in use, counted up to [`MAX_COUNTED_STORAGE_ENTRIES`], which
+    /// means at least as many
+    pub storage_entries: u64,
+    /// Balance of the contract account
+    #[cfg_attr(feature = "json-schema", schemars(with = "String"))]
+    pub balance: U256,
+    /// Nonce of the contract account
+    #[cfg_attr(feature = "json-schema", schemars(with = "String"))]
+    pub nonce: U256,
+    /// Address that created the contract, if recorded
+    #[cfg_attr(feature = "json-schema", schemars(with = "Option<String>"))]
+    pub deployer: Option<H160>,
+    /// Block in which the contract was created, if recorded
+    pub deployed_at: Option<BlockNumber>,
+}
+
+/// Authorization of an account to deploy contracts
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+pub struct DeployerStatus<BlockNumber> {
+    /// Whether the account is an authorized deployer
+    pub authorized: bool,
+    /// Block at which the account was authorized, if known
+    pub authorized_at: Option<BlockNumber>,
+    /// Whether the account is a deployer admin
+    pub deployer_admin: bool,
+}
+
+/// Status of an approved factory contract
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+pub enum FactoryStatus {
+    /// The factory may deploy contracts
+    Active,
+    /// The factory is temporarily barred from deploying contracts
+    Suspended,
+}
+
+/// An approved factory contract and its registry record
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+pub struct FactoryEntry<AccountId, BlockNumber> {
+    /// Address of the factory contract
+    #[cfg_attr(feature = "json-schema", schemars(with = "String"))]
+    pub address: H160,
+    /// Account administering the factory
+    pub admin: AccountId,
+    /// Number of contracts recorded with the factory as deployer
+    pub children: u32,
+    /// Current status of the factory
+    pub status: FactoryStatus,
+    /// Block at which the factory was registered
+    pub registered_at: BlockNumber,
+}
+
+/// Value of an EVM storage slot and the runtime storage key holding it
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct EvmStorageSlot {
+    /// Slot index in the contract storage
+    pub key: H256,
+    /// Value of the slot, zero if unset
+    pub value: H256,
+    /// Runtime storage key holding the slot
+    pub storage_key: Vec<u8>,
+}
+
+/// State of an EVM account and the runtime storage keys holding it
+///
+/// The node proves the listed keys against the block's state root to answer
+/// `eth_getProof`.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct EvmAccountState {
+    /// Balance of the account
+    pub balance: U256,
+    /// Nonce of the account
+    pub nonce: U256,
+    /// Keccak-256 hash of the account code, the hash of empty code for plain accounts
+    pub code_hash: H256,
+    /// Runtime storage keys holding the balance, nonce and code of the account
+    pub account_keys: Vec<Vec<u8>>,
+    /// Requested storage slots, in request order
+    pub storage: Vec<EvmStorageSlot>,
+}
+
+/// What the next contract creation of an address depends on
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+#[cfg_attr(feature = "json-schema", derive(schemars::JsonSchema))]
+pub struct DeploymentPrerequisites<BlockNumber> {
+    /// Nonce of the address
+    #[cfg_attr(feature = "json-schema", schemars(with = "String"))]
+    pub nonce: U256,
+    /// `DeploymentValidationError` code the creation would be refused with, `None` if it
+    /// would be admitted
+    pub refusal: Option<u8>,
+    /// Contract creations left to the address in the current quota period, `None` without
+    /// a quota
+    pub remaining_quota: Option<u32>,
+    /// First block of the next quota period, `None` without a quota
+    pub quota_resets_at: Option<BlockNumber>,
+    /// Init code hashes of the deployment intents the address announced and has not
+    /// fulfilled yet
+    #[cfg_attr(feature = "json-schema", schemars(with = "Vec<String>"))]
+    pub announced_intents: Vec<H256>,
+}
+
+/// Reason a previewed call could not be applied
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub enum PreviewFailure {
+    /// The call could not be decoded
+    Undecodable,
+    /// The call is not a deployment control call
+    Unsupported,
+    /// The call failed when dispatched with the Root origin
+    Dispatch(DispatchError),
+}
+
+/// Effect of a batch of proposed deployment control calls
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct AuthorizationEffects<AccountId> {
+    /// Accounts the calls would authorize
+    pub added_deployers: Vec<AccountId>,
+    /// Accounts whose authorization the calls would revoke
+    pub removed_deployers: Vec<AccountId>,
+    /// Factories the calls would approve
+    pub added_factories: Vec<H160>,
+    /// Factories the calls would remove
+    pub removed_factories: Vec<H160>,
+    /// Index of the first call that cannot be applied and why
+    ///
+    /// The calls after it are not applied and their effects are not included.
+    pub failed: Option<(u32, PreviewFailure)>,
+}
+
+/// Contract creation refused by the deployment policy
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct DeploymentRefusal {
+    /// Index of the extrinsic among the checked ones
+    pub index: u32,
+    /// Hash of the Ethereum transaction
+    pub transaction_hash: H256,
+    /// `DeploymentValidationError` code of the refusal
+    pub code: u8,
+}
+
+/// Why an Ethereum transaction failed, found by re-executing it
+///
+/// The runtime's own checks are evaluated before the EVM execution, in the order the
+/// runtime applies them.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub enum FailureDiagnosis {
+    /// The transaction executed successfully
+    Succeeded,
+    /// The sender may not deploy contracts, with the `DeploymentValidationError` code
+    UnauthorizedDeployment(u8),
+    /// The call is paused by the emergency pause
+    Paused,
+    /// The recipient of the transferred value is not on the transfer allowlist
+    RecipientNotAllowed,
+    /// The transaction was rejected before execution, e.g. for an insufficient balance
+    Rejected(DispatchError),
+    /// The execution ran out of gas
+    OutOfGas,
+    /// The execution reverted with the given return data, empty for contract creations
+    Reverted(Vec<u8>),
+    /// The execution halted, with the SCALE encoded EVM exit reason
+    Halted(Vec<u8>),
+}
+
+/// Versions of `DeploymentControlApi` introducing each method
+///
+/// Nodes check the version implemented by the runtime of a block before calling a method
+/// and fall back for runtimes from before it, so that they keep serving historical blocks
+/// and runtimes that have not been upgraded yet.
+pub mod versions {
+    /// `list_factories`
+    pub const LIST_FACTORIES: u32 = 2;
+    /// `denied_deployments`
+    pub const DENIED_DEPLOYMENTS: u32 = 3;
+    /// `evm_account_state`
+    pub const EVM_ACCOUNT_STATE: u32 = 4;
+    /// `preview_authorization_effects`
+    pub const PREVIEW_AUTHORIZATION_EFFECTS: u32 = 5;
+    /// `diagnose_transaction`
+    pub const DIAGNOSE_TRANSACTION: u32 = 6;
+    /// `deployment_refusals`
+    pub const DEPLOYMENT_REFUSALS: u32 = 7;
+    /// `deployer_status`
+    pub const DEPLOYER_STATUS: u32 = 8;
+    /// `was_authorized_at`
+    pub const WAS_AUTHORIZED_AT: u32 = 9;
+    /// `deployment_prerequisites`
+    pub const DEPLOYMENT_PREREQUISITES: u32 = 10;
+}
+
+sp_api::decl_runtime_apis! {
+    /// Runtime API for the EVM deployment control pallet
+    #[api_version(10)]
+    pub trait DeploymentControlApi<AccountId, BlockNumber>
+    where
+        AccountId: Codec,
+        BlockNumber: Codec,
+    {
+        /// Code, storage and provenance information of the contract at `address`
+        ///
+        /// Returns `None` if there is no code at the address.
+        fn contract_info(address: H160) -> Option<ContractInfo<BlockNumber>>;
+
+        /// Approved factories in address order
+        ///
+        /// Returns at most `limit` factories, starting after `start_after` if given.
+        #[api_version(2)]
+        fn list_factories(
+            start_after: Option<H160>,
+            limit: u32,
+        ) -> Vec<FactoryEntry<AccountId, BlockNumber>>;
+
+        /// Indices of the contract creations among `extrinsics` whose sender is not an
+        /// authorized deployer
+        ///
+        /// Lets block authors drop creations that would fail before spending execution
+        /// time on them.
+        #[api_version(3)]
+        fn denied_deployments(extrinsics: Vec<<Block as BlockT>::Extrinsic>) -> Vec<u32>;
+
+        /// State of the EVM account at `address` and the given storage slots, with the
+        /// runtime storage keys to prove them
+        #[api_version(4)]
+        fn evm_account_state(address: H160, storage_keys: Vec<H256>) -> EvmAccountState;
+
+        /// Effect of applying the SCALE encoded runtime `calls` in order with the Root origin
+        ///
+        /// The calls are dispatched in a sandbox that is rolled back afterwards, so that
+        /// governance UIs can show voters the exact effect of a proposal.
+        #[api_version(5)]
+        fn preview_authorization_effects(calls: Vec<Vec<u8>>) -> AuthorizationEffects<AccountId>;
+
+        /// Why the Ethereum transaction `transaction_hash` among the block's `extrinsics`
+        /// failed
+        ///
+        /// To be called on the parent state once the block is initialized, so that the
+        /// extrinsics preceding the transaction are applied before it is re-executed.
+        /// Returns `None` if the transaction is not among the extrinsics.
+        #[api_version(6)]
+        fn diagnose_transaction(
+            extrinsics: Vec<<Block as BlockT>::Extrinsic>,
+            transaction_hash: H256,
+        ) -> Option<FailureDiagnosis>;
+
+        /// Contract creations among `extrinsics` that the deployment policy refuses
+        ///
+        /// Like `denied_deployments`, but with the Ethereum transaction hash and the reason
+        /// of each refusal, so that they can be reported to the senders.
+        #[api_version(7)]
+        fn deployment_refusals(
+            extrinsics: Vec<<Block as BlockT>::Extrinsic>,
+        ) -> Vec<DeploymentRefusal>;
+
+        /// Authorization of `account` to deploy contracts
+        ///
+        /// Answers from the pallet rather than its storage layout, so that nodes keep
+        /// answering deployer queries correctly whatever layout the runtime uses.
+        #[api_version(8)]
+        fn deployer_status(account: AccountId) -> DeployerStatus<BlockNumber>;
+
+        /// Whether `account` was an authorized deployer at the past block `block`
+        ///
+        /// Answered from the authorization transitions index of the current state rather
+        /// than the state of `block`, so that nodes pruning their state can answer. Returns
+        /// `None` for blocks the index does not cover.
+        #[api_version(9)]
+        fn was_authorized_at(account: AccountId, block: BlockNumber) -> Option<bool>;
+
+        /// Nonce of `address` and the deployment control state its next contract creation
+        /// depends on
+        ///
+        /// Evaluated for the block following this one, the earliest the creation can be
+        /// included in.
+        #[api_version(10)]
+        fn deployment_prerequisites(address: H160) -> DeploymentPrerequisites<BlockNumber>;
+    }
+}
@@ -5,7 +5,81 @@ use super::*;
 #[allow(unused)]
 use crate::Pallet as EvmDeploymentControl;
 use frame_benchmarking::v2::*;
+use frame_support::{
+	migrations::SteppedMigration,
+	traits::{
+		fungible::{Inspect, Mutate},
+		EnsureOrigin, Get, StorePreimage,
+	},
+	weights::{Weight, WeightMeter},
+	BoundedVec,
+};
 use frame_system::RawOrigin;
+use sp_runtime::traits::{One, Saturating, Zero};
+use sp_std::{boxed::Box, vec::Vec};
+
+/// An origin passing `AdminOrigin`
+fn admin_origin<T: Config>() -> <T as frame_system::Config>::RuntimeOrigin {
+	T::AdminOrigin::try_successful_origin().expect("admin origin is available")
+}
+
+/// Install an approval policy with `MaxApprovers` approvers, all required for high risk tiers
+fn setup_approval_policy<T: Config>() -> Vec<T::AccountId> {
+	let approvers: Vec<T::AccountId> =
+		(0..T::MaxApprovers::get()).map(|i| account("approver", i, 0)).collect();
+	let policy = ApprovalPolicy::<T> {
+		approvers: BoundedVec::truncate_from(approvers.clone()),
+		threshold: T::MaxApprovers::get(),
+		risk_tier: 0,
+	};
+	AuthorizationApprovalPolicy::<T>::put(policy);
+	approvers
+}
+
+/// Create a tenant administered by `admin`, with room for `bound` factories and deployers
+fn setup_tenant<T: Config>(admin: &T::AccountId, bound: u32) -> u32 {
+	let bounds = TenantBounds { max_factories: bound, max_deployers: bound };
+	Pallet::<T>::create_tenant(RawOrigin::Root.into(), admin.clone(), bounds).unwrap();
+	NextTenantId::<T>::get() - 1
+}
+
+/// Fill all but one slot of the authorization cache with checks of other accounts
+fn fill_authorization_cache<T: Config>() {
+	for i in 1..T::MaxCachedAuthorizations::get() {
+		Pallet::<T>::is_authorized(&account("cached", i, 0));
+	}
+}
+
+/// Schedule a remark as a governance call, returning its id
+fn schedule_remark<T: Config>() -> u32 {
+	let call: <T as Config>::RuntimeCall =
+		frame_system::Call::<T>::remark { remark: Vec::new() }.into();
+	Pallet::<T>::schedule_governance_call(admin_origin::<T>(), Box::new(call)).unwrap();
+	NextGovernanceCallId::<T>::get() - 1
+}
+
+/// Schedule an authorization open to objections, returning its id
+fn schedule_authorization<T: Config>() -> u32 {
+	EnactmentDelay::<T>::put(BlockNumberFor::<T>::from(10u32));
+	let call: <T as Config>::RuntimeCall =
+		Call::<T>::authorize_deployer { deployer: account("deployer", 0, 0) }.into();
+	Pallet::<T>::schedule_governance_call(admin_origin::<T>(), Box::new(call)).unwrap();
+	NextGovernanceCallId::<T>::get() - 1
+}
+
+/// Schedule an authorization and have the whitelisted caller veto it, returning its id and
+/// the objector
+fn vetoed_authorization<T: Config>() -> (u32, T::AccountId) {
+	let id = schedule_authorization::<T>();
+	let objector: T::AccountId = whitelisted_caller();
+	let amount = T::Currency::minimum_balance();
+	T::Currency::set_balance(&objector, amount.saturating_mul(2u32.into()));
+	// Objections of others leave the whitelisted caller to cross the threshold
+	Objections::<T>::insert(id, T::VetoThreshold::get() * T::Currency::total_issuance());
+	Pallet::<T>::object_to_authorization(RawOrigin::Signed(objector.clone()).into(), id, amount)
+		.unwrap();
+	(id, objector)
+}
 
 #[benchmarks]
 mod benchmarks {
@@ -16,30 +90,41 @@ mod benchmarks {
 		let deployer: T::AccountId = account("deployer", 0, 0);
 
 		#[extrinsic_call]
-		_(RawOrigin::Root, deployer.clone());
+		_(admin_origin::<T>(), deployer.clone());
 
 		// Verify the deployer was authorized
 		assert!(AuthorizedDeployers::<T>::contains_key(&deployer));
 	}
 
 	#[benchmark]
-	fn revoke_deployer() {
+	fn revoke_deployer(o: Linear<0, 1>) {
 		// Setup: First authorize a deployer
 		let deployer: T::AccountId = account("deployer", 0, 0);
-		AuthorizedDeployers::<T>::insert(&deployer, ());
+		AuthorizedDeployers::<T>::insert(&deployer, DeployerInfo::new(Zero::zero()));
+		// Worst case: the deployer was authorized by a tenant, whose count is released
+		let tenant = setup_tenant::<T>(&account("tenant", 0, 0), 1);
+		DeployerTenant::<T>::insert(&deployer, tenant);
+		Tenants::<T>::mutate_extant(tenant, |info| info.deployers = 1);
+		// With `o`, the stale window override is queued for cleanup
+		if o == 1 {
+			WindowOverrides::<T>::insert(&deployer, ());
+		}
 
 		#[extrinsic_call]
-		_(RawOrigin::Root, deployer.clone());
+		_(admin_origin::<T>(), deployer.clone());
 
 		// Verify the deployer was revoked
 		assert!(!AuthorizedDeployers::<T>::contains_key(&deployer));
+		assert_eq!(Pallet::<T>::queued_cleanups(), o);
 	}
 
 	#[benchmark]
 	fn is_authorized_check() {
 		// Setup: Authorize a deployer
 		let deployer: T::AccountId = account("deployer", 0, 0);
-		AuthorizedDeployers::<T>::insert(&deployer, ());
+		AuthorizedDeployers::<T>::insert(&deployer, DeployerInfo::new(Zero::zero()));
+		// Worst case: a cache miss that still fills the last slot of the cache
+		fill_authorization_cache::<T>();
 
 		#[block]
 		{
@@ -47,5 +132,946 @@ mod benchmarks {
 		}
 	}
 
+	#[benchmark]
+	fn ensure_can_deploy(w: Linear<0, { T::MaxDeploymentWindows::get() }>) {
+		// Worst case of the check run for every contract creation in transaction
+		// validation: an authorized deployer outside of all windows but with an override,
+		// and with quota left in the current period
+		let deployer: T::AccountId = account("deployer", 0, 0);
+		AuthorizedDeployers::<T>::insert(&deployer, DeployerInfo::new(Zero::zero()));
+		WindowOverrides::<T>::insert(&deployer, ());
+		fill_authorization_cache::<T>();
+		let window = DeploymentWindow {
+			period: 100u32.into(),
+			offset: 0u32.into(),
+			duration: 10u32.into(),
+		};
+		DeploymentWindows::<T>::put(BoundedVec::truncate_from(sp_std::vec![window; w as usize]));
+		frame_system::Pallet::<T>::set_block_number(50u32.into());
+		DeploymentQuota::<T>::put(10);
+		DeploymentQuotaUsage::<T>::insert(
+			&deployer,
+			QuotaUsage { period: Pallet::<T>::quota_period(50u32.into()), used: 1 },
+		);
+
+		#[block]
+		{
+			assert!(Pallet::<T>::ensure_can_deploy(&deployer).is_ok());
+		}
+	}
+
+	#[benchmark]
+	fn report_rejected_attempts() {
+		// Setup: Push the account over the alert threshold
+		let deployer: T::AccountId = account("deployer", 0, 0);
+		for _ in 0..T::RejectedAttemptThreshold::get() {
+			Pallet::<T>::note_rejected_attempt(&deployer);
+		}
+
+		#[extrinsic_call]
+		_(RawOrigin::None, deployer.clone());
+
+		// Verify the deployer was flagged
+		assert!(FlaggedDeployers::<T>::contains_key(&deployer));
+	}
+
+	#[benchmark]
+	fn unflag_deployer() {
+		// Setup: Flag a deployer
+		let deployer: T::AccountId = account("deployer", 0, 0);
+		Pallet::<T>::note_rejected_attempt(&deployer);
+		FlaggedDeployers::<T>::insert(&deployer, frame_system::Pallet::<T>::block_number());
+
+		#[extrinsic_call]
+		_(RawOrigin::Root, deployer.clone());
+
+		// Verify the flag was cleared
+		assert!(!FlaggedDeployers::<T>::contains_key(&deployer));
+	}
+
+	#[benchmark]
+	fn set_max_creates_per_block() {
+		#[extrinsic_call]
+		_(RawOrigin::Root, Some(10));
+
+		// Verify the cap was stored
+		assert_eq!(MaxCreatesPerBlock::<T>::get(), Some(10));
+	}
+
+	#[benchmark]
+	fn set_deployment_windows(w: Linear<0, { T::MaxDeploymentWindows::get() }>) {
+		let window = DeploymentWindow {
+			period: 100u32.into(),
+			offset: 0u32.into(),
+			duration: 10u32.into(),
+		};
+		let windows: BoundedVec<_, T::MaxDeploymentWindows> =
+			sp_std::vec![window; w as usize].try_into().unwrap();
+
+		#[extrinsic_call]
+		_(RawOrigin::Root, windows);
+
+		// Verify the windows were stored
+		assert_eq!(DeploymentWindows::<T>::get().len() as u32, w);
+	}
+
+	#[benchmark]
+	fn set_window_override() {
+		let deployer: T::AccountId = account("deployer", 0, 0);
+
+		#[extrinsic_call]
+		_(RawOrigin::Root, deployer.clone(), true);
+
+		// Verify the override was stored
+		assert!(WindowOverrides::<T>::contains_key(&deployer));
+	}
+
+	#[benchmark]
+	fn schedule_cleanup() {
+		let contract = sp_core::H160::repeat_byte(0x22);
+
+		#[extrinsic_call]
+		_(RawOrigin::Root, CleanupTask::Provenance(contract));
+
+		// Verify the task was queued
+		assert_eq!(Pallet::<T>::queued_cleanups(), 1);
+	}
+
+	#[benchmark]
+	fn process_cleanup_task() {
+		// Setup: An unflagged account with an expired rejected attempt counter
+		let deployer: T::AccountId = account("deployer", 0, 0);
+		Pallet::<T>::note_rejected_attempt(&deployer);
+		frame_system::Pallet::<T>::set_block_number(
+			frame_system::Pallet::<T>::block_number() + T::RejectedAttemptWindow::get(),
+		);
+		Pallet::<T>::queue_cleanup(CleanupTask::ExpiredRejectedAttempts(deployer.clone()))
+			.unwrap();
+
+		#[block]
+		{
+			let task = CleanupQueue::<T>::take(0).unwrap();
+			Pallet::<T>::process_cleanup_task(task);
+		}
+
+		// Verify the counter was removed
+		assert!(!RejectedAttempts::<T>::contains_key(&deployer));
+	}
+
+	#[benchmark]
+	fn set_opcode_policy() {
+		let policy = OpcodePolicy { allow_selfdestruct: true, limit_create_depth: true };
+
+		#[extrinsic_call]
+		_(RawOrigin::Root, policy);
+
+		// Verify the policy was stored
+		assert_eq!(EvmOpcodePolicy::<T>::get(), policy);
+	}
+
+	#[benchmark]
+	fn deploy_contract() {
+		// The EVM execution is charged separately through `ContractDeployer::gas_weight`,
+		// so only the checks and bookkeeping around it are measured here. Worst case: a
+		// deployer that is not authorized pays the fee of the permissionless policy
+		let deployer: T::AccountId = account("deployer", 0, 0);
+		fill_authorization_cache::<T>();
+		let fee = T::IntentBond::get();
+		EvmDeploymentPolicy::<T>::put(DeploymentPolicy::PermissionlessWithFee { fee });
+		let balance =
+			T::Currency::minimum_balance().saturating_add(fee.saturating_mul(10u32.into()));
+		T::Currency::set_balance(&deployer, balance);
+		let contract = sp_core::H160::repeat_byte(0x22);
+		let len = T::MaxMetadataLen::get() as usize;
+		let metadata = ContractMetadata::<T::MaxMetadataLen> {
+			label: sp_std::vec![b'l'; len].try_into().unwrap(),
+			version: sp_std::vec![b'v'; len].try_into().unwrap(),
+		};
+		// An announced deployment, so that the pending intent is noted
+		let code_hash = sp_core::H256::repeat_byte(0x44);
+		DeploymentIntents::<T>::insert(
+			&deployer,
+			code_hash,
+			DeploymentIntent::<T> {
+				label: metadata.label.clone(),
+				bond: Zero::zero(),
+				announced_at: Zero::zero(),
+			},
+		);
+
+		#[block]
+		{
+			Pallet::<T>::ensure_can_deploy(&deployer).unwrap();
+			assert!(Pallet::<T>::try_register_create());
+			Pallet::<T>::charge_deployment_fee(&deployer).unwrap();
+			Pallet::<T>::note_pending_creation(&deployer, code_hash);
+			ContractMetadataOf::<T>::insert(contract, &metadata);
+		}
+
+		// Verify the metadata was stored and the intent is pending
+		assert_eq!(ContractMetadataOf::<T>::get(contract), Some(metadata));
+		assert_eq!(PendingIntent::<T>::get(), Some((deployer, code_hash)));
+	}
+
+	#[benchmark]
+	fn register_factory() {
+		let factory = sp_core::H160::repeat_byte(0x33);
+		let admin: T::AccountId = account("admin", 0, 0);
+
+		#[extrinsic_call]
+		_(admin_origin::<T>(), factory, admin);
+
+		// Verify the factory was approved
+		assert!(ApprovedFactories::<T>::contains_key(factory));
+	}
+
+	#[benchmark]
+	fn set_factory_status() {
+		let factory = sp_core::H160::repeat_byte(0x33);
+		let admin: T::AccountId = account("admin", 0, 0);
+		Pallet::<T>::register_factory(admin_origin::<T>(), factory, admin).unwrap();
+
+		#[extrinsic_call]
+		_(admin_origin::<T>(), factory, FactoryStatus::Suspended);
+
+		// Verify the factory was suspended
+		assert_eq!(
+			ApprovedFactories::<T>::get(factory).map(|info| info.status),
+			Some(FactoryStatus::Suspended)
+		);
+	}
+
+	#[benchmark]
+	fn set_factory_permissions() {
+		let factory = sp_core::H160::repeat_byte(0x33);
+		let admin: T::AccountId = account("admin", 0, 0);
+		Pallet::<T>::register_factory(admin_origin::<T>(), factory, admin).unwrap();
+
+		#[extrinsic_call]
+		_(admin_origin::<T>(), factory, FactoryPermissions::REGISTER_METADATA);
+
+		// Verify the permissions were stored
+		assert_eq!(FactoryPermissionsOf::<T>::get(factory), FactoryPermissions::REGISTER_METADATA);
+	}
+
+	#[benchmark]
+	fn register_child_metadata() {
+		let factory = sp_core::H160::repeat_byte(0x33);
+		let contract = sp_core::H160::repeat_byte(0x44);
+		let admin: T::AccountId = account("admin", 0, 0);
+		Pallet::<T>::register_factory(admin_origin::<T>(), factory, admin).unwrap();
+		FactoryPermissionsOf::<T>::insert(factory, FactoryPermissions::REGISTER_METADATA);
+		Pallet::<T>::note_contract_created(factory, contract);
+		// Worst case: label and version of maximum length
+		let len = T::MaxMetadataLen::get() as usize;
+		let metadata = ContractMetadata::<T::MaxMetadataLen> {
+			label: sp_std::vec![b'l'; len].try_into().unwrap(),
+			version: sp_std::vec![b'v'; len].try_into().unwrap(),
+		};
+
+		#[block]
+		{
+			Pallet::<T>::register_child_metadata(factory, contract, metadata.clone()).unwrap();
+		}
+
+		// Verify the metadata was stored
+		assert_eq!(ContractMetadataOf::<T>::get(contract), Some(metadata));
+	}
+
+	#[benchmark]
+	fn set_factory_expiry() {
+		let factory = sp_core::H160::repeat_byte(0x33);
+		let admin: T::AccountId = account("admin", 0, 0);
+		Pallet::<T>::register_factory(admin_origin::<T>(), factory, admin).unwrap();
+		// Worst case: both the notice and the expiry are scheduled
+		let expires_at = frame_system::Pallet::<T>::block_number() +
+			T::FactoryExpiryNotice::get() +
+			T::FactoryExpiryNotice::get();
+
+		#[extrinsic_call]
+		_(admin_origin::<T>(), factory, Some(expires_at));
+
+		// Verify the expiry was stored
+		assert_eq!(FactoryExpiry::<T>::get(factory), Some(expires_at));
+	}
+
+	#[benchmark]
+	fn process_factory_expiries(e: Linear<0, { T::MaxExpiriesPerBlock::get() }>) {
+		let admin: T::AccountId = account("admin", 0, 0);
+		let expires_at = frame_system::Pallet::<T>::block_number() + One::one();
+		// Worst case: every entry expires a factory
+		for i in 0..e {
+			let factory = sp_core::H160::from_low_u64_be(i as u64 + 1);
+			Pallet::<T>::register_factory(admin_origin::<T>(), factory, admin.clone()).unwrap();
+			Pallet::<T>::set_factory_expiry(admin_origin::<T>(), factory, Some(expires_at))
+				.unwrap();
+		}
+
+		#[block]
+		{
+			Pallet::<T>::process_factory_expiries(expires_at);
+		}
+
+		// Verify the factories expired
+		assert!(FactoryExpirySchedule::<T>::get(expires_at).is_empty());
+		assert_eq!(FactoryExpiry::<T>::iter().count(), 0);
+	}
+
+	#[benchmark]
+	fn set_approval_policy() {
+		let approvers: Vec<T::AccountId> =
+			(0..T::MaxApprovers::get()).map(|i| account("approver", i, 0)).collect();
+		let policy = ApprovalPolicy::<T> {
+			approvers: BoundedVec::truncate_from(approvers),
+			threshold: T::MaxApprovers::get(),
+			risk_tier: 0,
+		};
+
+		#[extrinsic_call]
+		_(RawOrigin::Root, Some(policy.clone()));
+
+		// Verify the policy was stored
+		assert_eq!(AuthorizationApprovalPolicy::<T>::get(), Some(policy));
+	}
+
+	#[benchmark]
+	fn propose_authorization() {
+		let approvers = setup_approval_policy::<T>();
+		let deployer: T::AccountId = account("deployer", 0, 0);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(approvers[0].clone()), deployer);
+
+		// Verify the proposal was stored
+		assert_eq!(PendingProposals::<T>::get(), 1);
+	}
+
+	#[benchmark]
+	fn approve_authorization() {
+		let approvers = setup_approval_policy::<T>();
+		let deployer: T::AccountId = account("deployer", 0, 0);
+		Pallet::<T>::propose_authorization(RawOrigin::Signed(approvers[0].clone()).into(), deployer)
+			.unwrap();
+		let approver = approvers.last().unwrap().clone();
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(approver.clone()), 0);
+
+		// Verify the approval was recorded
+		assert!(AuthorizationProposals::<T>::get(0).unwrap().approvals.contains(&approver));
+	}
+
+	#[benchmark]
+	fn execute_authorization() {
+		let approvers = setup_approval_policy::<T>();
+		let deployer: T::AccountId = account("deployer", 0, 0);
+		Pallet::<T>::propose_authorization(
+			RawOrigin::Signed(approvers[0].clone()).into(),
+			deployer.clone(),
+		)
+		.unwrap();
+		// Worst case: every approver has to be checked against the policy
+		for approver in approvers.iter().skip(1) {
+			Pallet::<T>::approve_authorization(RawOrigin::Signed(approver.clone()).into(), 0)
+				.unwrap();
+		}
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(approvers[0].clone()), 0);
+
+		// Verify the deployer was authorized
+		assert!(AuthorizedDeployers::<T>::contains_key(&deployer));
+		assert!(!AuthorizationProposals::<T>::contains_key(0));
+	}
+
+	#[benchmark]
+	fn set_deployer_risk_tier() {
+		let deployer: T::AccountId = account("deployer", 0, 0);
+
+		#[extrinsic_call]
+		_(RawOrigin::Root, deployer.clone(), Some(0));
+
+		// Verify the tier was stored
+		assert_eq!(DeployerRiskTiers::<T>::get(&deployer), Some(0));
+	}
+
+	#[benchmark]
+	fn remove_expired_proposal() {
+		let approvers = setup_approval_policy::<T>();
+		let deployer: T::AccountId = account("deployer", 0, 0);
+		Pallet::<T>::propose_authorization(RawOrigin::Signed(approvers[0].clone()).into(), deployer)
+			.unwrap();
+		frame_system::Pallet::<T>::set_block_number(
+			frame_system::Pallet::<T>::block_number() + T::ProposalLifetime::get(),
+		);
+		let caller: T::AccountId = whitelisted_caller();
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), 0);
+
+		// Verify the proposal was removed
+		assert_eq!(PendingProposals::<T>::get(), 0);
+	}
+
+	#[benchmark]
+	fn migrate_v1_step() {
+		let deployer: T::AccountId = account("deployer", 0, 0);
+		StorageVersion::new(0).put::<Pallet<T>>();
+		migrations::v1::v0::AuthorizedDeployers::<T>::insert(&deployer, ());
+		let mut meter = WeightMeter::with_limit(Weight::MAX);
+
+		#[block]
+		{
+			migrations::v1::LazyMigrationV1::<T, T::WeightInfo>::step(None, &mut meter).unwrap();
+		}
+
+		// Verify the record was migrated
+		assert!(migrations::v2::v1::AuthorizedDeployers::<T>::get(&deployer).is_some());
+	}
+
+	#[benchmark]
+	fn migrate_v2_step() {
+		let deployer: T::AccountId = account("deployer", 0, 0);
+		StorageVersion::new(1).put::<Pallet<T>>();
+		let info = migrations::v2::v1::DeployerInfo { authorized_at: One::one() };
+		migrations::v2::v1::AuthorizedDeployers::<T>::insert(&deployer, info);
+		let mut meter = WeightMeter::with_limit(Weight::MAX);
+
+		#[block]
+		{
+			migrations::v2::LazyMigrationV2::<T, T::WeightInfo>::step(None, &mut meter).unwrap();
+		}
+
+		// Verify the record was migrated
+		assert_eq!(AuthorizedDeployers::<T>::get(&deployer), Some(DeployerInfo::new(One::one())));
+	}
+
+	#[benchmark]
+	fn migrate_v3_step() {
+		let approvers = setup_approval_policy::<T>();
+		StorageVersion::new(2).put::<Pallet<T>>();
+		let proposal = migrations::v3::v2::AuthorizationProposal {
+			deployer: account("deployer", 0, 0),
+			risk_tier: 0,
+			approvals: BoundedVec::truncate_from(approvers),
+			expires_at: T::ProposalLifetime::get(),
+		};
+		migrations::v3::v2::AuthorizationProposals::<T>::insert(0, proposal);
+		let mut meter = WeightMeter::with_limit(Weight::MAX);
+
+		#[block]
+		{
+			migrations::v3::LazyMigrationV3::<T, T::WeightInfo>::step(None, &mut meter).unwrap();
+		}
+
+		// Verify the proposal was migrated
+		assert_eq!(AuthorizationProposals::<T>::get(0).map(|p| p.proposed_at), Some(Zero::zero()));
+	}
+
+	#[benchmark]
+	fn announce_deployment() {
+		let caller: T::AccountId = whitelisted_caller();
+		AuthorizedDeployers::<T>::insert(&caller, DeployerInfo::new(Zero::zero()));
+		fill_authorization_cache::<T>();
+		let balance = T::Currency::minimum_balance()
+			.saturating_add(T::IntentBond::get().saturating_mul(10u32.into()));
+		T::Currency::set_balance(&caller, balance);
+		let code_hash = sp_core::H256::repeat_byte(0x55);
+		let label: BoundedVec<u8, T::MaxMetadataLen> =
+			sp_std::vec![b'l'; T::MaxMetadataLen::get() as usize].try_into().unwrap();
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()), code_hash, label);
+
+		// Verify the intent was recorded
+		assert!(DeploymentIntents::<T>::contains_key(&caller, code_hash));
+	}
+
+	#[benchmark]
+	fn set_deployment_policy() {
+		let policy = DeploymentPolicy::PermissionlessWithFee { fee: T::IntentBond::get() };
+
+		#[extrinsic_call]
+		_(RawOrigin::Root, policy);
+
+		// Verify the policy was stored
+		assert_eq!(EvmDeploymentPolicy::<T>::get(), policy);
+	}
+
+	#[benchmark]
+	fn rotate_deployer_key() {
+		// Worst case: the deployer executes its announced rotation, moving a window override,
+		// its tenant and its quota usage
+		let old: T::AccountId = whitelisted_caller();
+		let new: T::AccountId = account("new", 0, 0);
+		AuthorizedDeployers::<T>::insert(&old, DeployerInfo::new(Zero::zero()));
+		WindowOverrides::<T>::insert(&old, ());
+		DeployerTenant::<T>::insert(&old, 0);
+		DeploymentQuotaUsage::<T>::insert(&old, QuotaUsage { period: Zero::zero(), used: 1 });
+		fill_authorization_cache::<T>();
+		PendingKeyRotations::<T>::insert(&old, (new.clone(), Zero::zero()));
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(old.clone()), old.clone(), new.clone());
+
+		// Verify the authorization moved to the new key
+		assert!(!AuthorizedDeployers::<T>::contains_key(&old));
+		assert!(AuthorizedDeployers::<T>::contains_key(&new));
+	}
+
+	#[benchmark]
+	fn authorize_contract_wallet() {
+		let wallet = sp_core::H160::repeat_byte(0x5a);
+		let operator: T::AccountId = account("operator", 0, 0);
+		fill_authorization_cache::<T>();
+
+		#[extrinsic_call]
+		_(admin_origin::<T>(), wallet, operator.clone());
+
+		// Verify the wallet was authorized with its operator
+		let account = T::ContractDeployer::into_account_id(wallet);
+		assert!(AuthorizedDeployers::<T>::contains_key(&account));
+		assert_eq!(ContractWalletOperators::<T>::get(&account), Some(operator));
+	}
+
+	#[benchmark]
+	fn set_factory_metadata(l: Linear<0, { T::MaxMetadataBlobLen::get() }>) {
+		// Worst case: the factory's admin replaces metadata kept in the preimage store
+		let factory = sp_core::H160::repeat_byte(0xfa);
+		let admin: T::AccountId = whitelisted_caller();
+		Pallet::<T>::register_factory(admin_origin::<T>(), factory, admin.clone()).unwrap();
+		let previous = T::Preimages::bound(sp_std::vec![1u8; 256]).expect("preimage noted");
+		FactoryMetadata::<T>::insert(factory, previous);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(admin), factory, Some(sp_std::vec![2u8; l as usize]));
+
+		// Verify the metadata was replaced
+		assert_eq!(
+			EvmDeploymentControl::<T>::factory_metadata(&factory),
+			Some(sp_std::vec![2u8; l as usize])
+		);
+	}
+
+	#[benchmark]
+	fn set_deployer_metadata(l: Linear<0, { T::MaxMetadataBlobLen::get() }>) {
+		// Worst case: the deployer replaces metadata kept in the preimage store
+		let deployer: T::AccountId = whitelisted_caller();
+		AuthorizedDeployers::<T>::insert(&deployer, DeployerInfo::new(Zero::zero()));
+		fill_authorization_cache::<T>();
+		let previous = T::Preimages::bound(sp_std::vec![1u8; 256]).expect("preimage noted");
+		DeployerMetadata::<T>::insert(&deployer, previous);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(deployer.clone()), Some(sp_std::vec![2u8; l as usize]));
+
+		// Verify the metadata was replaced
+		assert_eq!(
+			EvmDeploymentControl::<T>::deployer_metadata(&deployer),
+			Some(sp_std::vec![2u8; l as usize])
+		);
+	}
+
+	#[benchmark]
+	fn unregister_factory(m: Linear<0, 1>) {
+		// With `m`, the factory has metadata in the preimage store
+		let factory = sp_core::H160::repeat_byte(0xfa);
+		let admin: T::AccountId = account("admin", 0, 0);
+		// Worst case: the factory is registered in a tenant's namespace
+		let tenant_admin: T::AccountId = account("tenant", 0, 0);
+		let tenant = setup_tenant::<T>(&tenant_admin, 1);
+		Pallet::<T>::register_tenant_factory(
+			RawOrigin::Signed(tenant_admin).into(),
+			tenant,
+			factory,
+			admin,
+		)
+		.unwrap();
+		if m == 1 {
+			let metadata = T::Preimages::bound(sp_std::vec![1u8; 256]).expect("preimage noted");
+			FactoryMetadata::<T>::insert(factory, metadata);
+		}
+
+		#[extrinsic_call]
+		_(admin_origin::<T>(), factory);
+
+		// Verify the factory and its metadata were removed
+		assert!(!ApprovedFactories::<T>::contains_key(factory));
+		assert!(!FactoryMetadata::<T>::contains_key(factory));
+		assert_eq!(Tenants::<T>::get(tenant).map(|info| info.factories), Some(0));
+	}
+
+	#[benchmark]
+	fn set_deployer_admin() {
+		let account: T::AccountId = account("admin", 0, 0);
+
+		#[extrinsic_call]
+		_(RawOrigin::Root, account.clone(), true);
+
+		// Verify the account was granted the origin
+		assert!(DeployerAdmins::<T>::contains_key(&account));
+	}
+
+	#[benchmark]
+	fn dispatch_as_deployer_admin() {
+		let admin: T::AccountId = whitelisted_caller();
+		DeployerAdmins::<T>::insert(&admin, ());
+		let call: <T as Config>::RuntimeCall =
+			frame_system::Call::<T>::remark { remark: Vec::new() }.into();
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(admin), Box::new(call));
+	}
+
+	#[benchmark]
+	fn slash_deployment_intent() {
+		let deployer: T::AccountId = account("deployer", 0, 0);
+		AuthorizedDeployers::<T>::insert(&deployer, DeployerInfo::new(Zero::zero()));
+		fill_authorization_cache::<T>();
+		let balance = T::Currency::minimum_balance()
+			.saturating_add(T::IntentBond::get().saturating_mul(10u32.into()));
+		T::Currency::set_balance(&deployer, balance);
+		let code_hash = sp_core::H256::repeat_byte(0x55);
+		let label: BoundedVec<u8, T::MaxMetadataLen> =
+			sp_std::vec![b'l'; T::MaxMetadataLen::get() as usize].try_into().unwrap();
+		Pallet::<T>::announce_deployment(
+			RawOrigin::Signed(deployer.clone()).into(),
+			code_hash,
+			label,
+		)
+		.unwrap();
+
+		#[extrinsic_call]
+		_(admin_origin::<T>(), deployer.clone(), code_hash);
+
+		// Verify the intent was dropped
+		assert!(!DeploymentIntents::<T>::contains_key(&deployer, code_hash));
+	}
+
+	#[benchmark]
+	fn set_deployment_quota() {
+		#[extrinsic_call]
+		_(RawOrigin::Root, Some(10));
+
+		// Verify the quota was stored
+		assert_eq!(DeploymentQuota::<T>::get(), Some(10));
+	}
+
+	#[benchmark]
+	fn use_deployment_quota() {
+		// Worst case: the first creation of the period resets the count of an earlier one
+		let deployer: T::AccountId = account("deployer", 0, 0);
+		DeploymentQuota::<T>::put(10);
+		DeploymentQuotaUsage::<T>::insert(
+			&deployer,
+			QuotaUsage { period: Zero::zero(), used: 10 },
+		);
+		frame_system::Pallet::<T>::set_block_number(T::QuotaPeriodLength::get().max(One::one()));
+
+		#[block]
+		{
+			assert!(Pallet::<T>::try_use_quota(&deployer));
+		}
+
+		// Verify the count restarted in the new period
+		assert_eq!(DeploymentQuotaUsage::<T>::get(&deployer).map(|usage| usage.used), Some(1));
+	}
+
+	#[benchmark]
+	fn create_tenant() {
+		let admin: T::AccountId = account("tenant", 0, 0);
+		let bounds = TenantBounds { max_factories: 10, max_deployers: 10 };
+
+		#[extrinsic_call]
+		_(RawOrigin::Root, admin.clone(), bounds);
+
+		// Verify the tenant was created
+		assert_eq!(Tenants::<T>::get(0).map(|info| info.admin), Some(admin));
+	}
+
+	#[benchmark]
+	fn set_tenant_bounds() {
+		let tenant = setup_tenant::<T>(&account("tenant", 0, 0), 1);
+		let bounds = TenantBounds { max_factories: 10, max_deployers: 10 };
+
+		#[extrinsic_call]
+		_(RawOrigin::Root, tenant, bounds);
+
+		// Verify the bounds were replaced
+		assert_eq!(Tenants::<T>::get(tenant).map(|info| info.bounds), Some(bounds));
+	}
+
+	#[benchmark]
+	fn set_tenant_admin() {
+		let admin: T::AccountId = account("tenant", 0, 0);
+		let tenant = setup_tenant::<T>(&admin, 1);
+		let successor: T::AccountId = account("tenant", 1, 0);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(admin), tenant, successor.clone());
+
+		// Verify the tenant was handed over
+		assert_eq!(Tenants::<T>::get(tenant).map(|info| info.admin), Some(successor));
+	}
+
+	#[benchmark]
+	fn register_tenant_factory() {
+		let admin: T::AccountId = account("tenant", 0, 0);
+		let tenant = setup_tenant::<T>(&admin, 1);
+		let factory = sp_core::H160::repeat_byte(0x33);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(admin.clone()), tenant, factory, admin);
+
+		// Verify the factory was approved in the tenant's namespace
+		assert_eq!(FactoryTenant::<T>::get(factory), Some(tenant));
+	}
+
+	#[benchmark]
+	fn set_tenant_factory_status() {
+		let admin: T::AccountId = account("tenant", 0, 0);
+		let tenant = setup_tenant::<T>(&admin, 1);
+		let factory = sp_core::H160::repeat_byte(0x33);
+		Pallet::<T>::register_tenant_factory(
+			RawOrigin::Signed(admin.clone()).into(),
+			tenant,
+			factory,
+			admin.clone(),
+		)
+		.unwrap();
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(admin), factory, FactoryStatus::Suspended);
+
+		// Verify the factory was suspended
+		assert_eq!(
+			ApprovedFactories::<T>::get(factory).map(|info| info.status),
+			Some(FactoryStatus::Suspended)
+		);
+	}
+
+	#[benchmark]
+	fn authorize_tenant_deployer() {
+		let admin: T::AccountId = account("tenant", 0, 0);
+		let tenant = setup_tenant::<T>(&admin, 1);
+		let deployer: T::AccountId = account("deployer", 0, 0);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(admin), tenant, deployer.clone());
+
+		// Verify the deployer was authorized on behalf of the tenant
+		assert!(AuthorizedDeployers::<T>::contains_key(&deployer));
+		assert_eq!(DeployerTenant::<T>::get(&deployer), Some(tenant));
+	}
+
+	#[benchmark]
+	fn schedule_governance_call() {
+		EnactmentDelay::<T>::put(BlockNumberFor::<T>::one());
+		let call: <T as Config>::RuntimeCall =
+			frame_system::Call::<T>::remark { remark: Vec::new() }.into();
+
+		#[extrinsic_call]
+		_(admin_origin::<T>(), Box::new(call));
+
+		// Verify the call was scheduled
+		assert!(GovernanceCalls::<T>::contains_key(0));
+	}
+
+	#[benchmark]
+	fn enact_governance_call() {
+		EnactmentDelay::<T>::put(BlockNumberFor::<T>::one());
+		let id = schedule_remark::<T>();
+		let now = frame_system::Pallet::<T>::block_number();
+		frame_system::Pallet::<T>::set_block_number(now.saturating_add(One::one()));
+		let caller: T::AccountId = whitelisted_caller();
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), id, Weight::MAX);
+
+		// Verify the call was enacted
+		assert!(!GovernanceCalls::<T>::contains_key(id));
+		assert!(!Enacting::<T>::exists());
+	}
+
+	#[benchmark]
+	fn cancel_governance_call() {
+		let origin =
+			T::EmergencyOrigin::try_successful_origin().expect("emergency origin is available");
+		EnactmentDelay::<T>::put(BlockNumberFor::<T>::one());
+		let id = schedule_remark::<T>();
+
+		#[extrinsic_call]
+		_(origin as <T as frame_system::Config>::RuntimeOrigin, id);
+
+		// Verify the call was cancelled
+		assert!(!GovernanceCalls::<T>::contains_key(id));
+	}
+
+	#[benchmark]
+	fn set_enactment_delay() {
+		let delay = BlockNumberFor::<T>::one();
+
+		#[extrinsic_call]
+		_(RawOrigin::Root, delay);
+
+		// Verify the delay was set
+		assert_eq!(EnactmentDelay::<T>::get(), delay);
+	}
+
+	#[benchmark]
+	fn object_to_authorization() {
+		// Reaching the veto threshold moves the authorization to a referendum
+		let id = schedule_authorization::<T>();
+		let caller: T::AccountId = whitelisted_caller();
+		let amount = T::Currency::minimum_balance();
+		T::Currency::set_balance(&caller, amount.saturating_mul(2u32.into()));
+		Objections::<T>::insert(id, T::VetoThreshold::get() * T::Currency::total_issuance());
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), id, amount);
+
+		// Verify the authorization was vetoed
+		assert!(Referenda::<T>::contains_key(id));
+		assert!(!GovernanceCalls::<T>::contains_key(id));
+	}
+
+	#[benchmark]
+	fn vote() {
+		let (id, _) = vetoed_authorization::<T>();
+		let voter: T::AccountId = account("voter", 0, 0);
+		let amount = T::Currency::minimum_balance();
+		T::Currency::set_balance(&voter, amount.saturating_mul(2u32.into()));
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(voter), id, true, amount);
+
+		// Verify the vote was counted
+		assert_eq!(Referenda::<T>::get(id).map(|referendum| referendum.ayes), Some(amount));
+	}
+
+	#[benchmark]
+	fn conclude_referendum() {
+		// An approved authorization is enacted
+		let (id, objector) = vetoed_authorization::<T>();
+		let voter: T::AccountId = account("voter", 0, 0);
+		let nays = Referenda::<T>::get(id).unwrap().nays;
+		let amount = nays.saturating_add(T::Currency::minimum_balance());
+		T::Currency::set_balance(&voter, amount.saturating_mul(2u32.into()));
+		Pallet::<T>::vote(RawOrigin::Signed(voter).into(), id, true, amount).unwrap();
+		frame_system::Pallet::<T>::set_block_number(
+			frame_system::Pallet::<T>::block_number().saturating_add(T::ReferendumPeriod::get()),
+		);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(objector), id, Weight::MAX);
+
+		// Verify the authorization was enacted
+		assert!(!Referenda::<T>::contains_key(id));
+		assert!(AuthorizedDeployers::<T>::contains_key(account::<T::AccountId>("deployer", 0, 0)));
+	}
+
+	#[benchmark]
+	fn unlock_vote() {
+		let (id, objector) = vetoed_authorization::<T>();
+		frame_system::Pallet::<T>::set_block_number(
+			frame_system::Pallet::<T>::block_number().saturating_add(T::ReferendumPeriod::get()),
+		);
+		Pallet::<T>::conclude_referendum(
+			RawOrigin::Signed(objector.clone()).into(),
+			id,
+			Weight::MAX,
+		)
+		.unwrap();
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(objector.clone()), id);
+
+		// Verify the objection was released
+		assert!(!Votes::<T>::contains_key(id, &objector));
+	}
+
+	#[benchmark]
+	fn transfer_contract_admin() {
+		// Worst case: the recorded deployer transfers an approved factory
+		let contract = sp_core::H160::repeat_byte(0xfa);
+		let deployer = sp_core::H160::repeat_byte(0xde);
+		let new_admin = sp_core::H160::repeat_byte(0xad);
+		let caller = T::ContractDeployer::into_account_id(deployer);
+		Pallet::<T>::register_factory(admin_origin::<T>(), contract, caller.clone()).unwrap();
+		Pallet::<T>::note_contract_created(deployer, contract);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), contract, new_admin);
+
+		// Verify the factory is administered by the new admin
+		assert_eq!(
+			ApprovedFactories::<T>::get(contract).map(|info| info.admin),
+			Some(T::ContractDeployer::into_account_id(new_admin))
+		);
+	}
+
+	#[benchmark]
+	fn announce_retirement() {
+		// Worst case: the recorded deployer announces, scheduling both warnings
+		let contract = sp_core::H160::repeat_byte(0xfa);
+		let deployer = sp_core::H160::repeat_byte(0xde);
+		let caller = T::ContractDeployer::into_account_id(deployer);
+		Pallet::<T>::note_contract_created(deployer, contract);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), contract);
+
+		// Verify the retirement was announced
+		assert!(matches!(
+			Retirements::<T>::get(contract),
+			Some(Retirement::Announced { .. })
+		));
+	}
+
+	#[benchmark]
+	fn finalize_retirement() {
+		// Worst case: the retiring contract is an approved factory
+		let contract = sp_core::H160::repeat_byte(0xfa);
+		let deployer = sp_core::H160::repeat_byte(0xde);
+		let admin = T::ContractDeployer::into_account_id(deployer);
+		Pallet::<T>::register_factory(admin_origin::<T>(), contract, admin).unwrap();
+		Pallet::<T>::note_contract_created(deployer, contract);
+		Pallet::<T>::announce_retirement(admin_origin::<T>(), contract).unwrap();
+		let retires_at =
+			frame_system::Pallet::<T>::block_number() + T::RetirementGracePeriod::get();
+		frame_system::Pallet::<T>::set_block_number(retires_at);
+		let caller: T::AccountId = whitelisted_caller();
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), contract);
+
+		// Verify the contract was retired and its factory suspended
+		assert!(Pallet::<T>::is_retired(&contract));
+		assert_eq!(
+			ApprovedFactories::<T>::get(contract).map(|info| info.status),
+			Some(FactoryStatus::Suspended)
+		);
+	}
+
+	#[benchmark]
+	fn process_retirement_warnings(w: Linear<0, { T::MaxExpiriesPerBlock::get() }>) {
+		let retires_at =
+			frame_system::Pallet::<T>::block_number() + T::RetirementGracePeriod::get();
+		// Worst case: every entry is still due a warning
+		for i in 0..w {
+			let contract = sp_core::H160::from_low_u64_be(i as u64 + 1);
+			Pallet::<T>::note_contract_created(sp_core::H160::repeat_byte(0xde), contract);
+			Pallet::<T>::announce_retirement(admin_origin::<T>(), contract).unwrap();
+		}
+
+		#[block]
+		{
+			Pallet::<T>::process_retirement_warnings(retires_at);
+		}
+
+		// Verify the warnings were emitted
+		assert!(RetirementWarnings::<T>::get(retires_at).is_empty());
+	}
+
 	impl_benchmark_test_suite!(EvmDeploymentControl, crate::mock::new_test_ext(), crate::mock::Test);
 }
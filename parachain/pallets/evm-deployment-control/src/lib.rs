@@ -13,9 +13,52 @@
 //! ## Features
 //!
 //! - Root-controlled authorization of deployers
-//! - Query interface for checking authorization status
+//! - Query interface for checking authorization status, also exposed to other
+//!   pallets through the [`DeploymentAuthorizer`] trait
 //! - Genesis configuration for initial deployers
 //! - Events for tracking authorization changes
+//! - Offchain worker alerting on repeated unauthorized deployment attempts
+//! - Governance-configurable cap on contract creations per block
+//! - Recurring deployment windows with per-deployer overrides
+//! - Provenance records of created contracts
+//! - Bounded cleanup queue processed in `on_idle` within the remaining block weight
+//! - Governance-configurable EVM opcode policy, banning SELFDESTRUCT by default
+//! - Contract deployment through an extrinsic recording label and version metadata
+//! - Multi-block migrations of the deployer records, see [`migrations`]
+//! - Registry of approved factory contracts with their admin and status
+//! - Deployment lifecycle callbacks for other pallets through [`DeploymentLifecycle`]
+//! - Suspension of deployers by other pallets through [`DeployerSuspension`]
+//! - Provenance lookups for other pallets through [`ContractProvenance`]
+//! - M-of-N approval of authorizations by named approvers, the number of approvals
+//!   depending on the risk tier governance assessed for the account, with expiring
+//!   proposals
+//! - Per-factory permissions to call into the pallet through the runtime's factory
+//!   precompile, e.g. to register the metadata of child contracts
+//! - Factory approval expiry, announced `FactoryExpiryNotice` blocks ahead through
+//!   `FactoryExpiringSoon` events
+//! - Bonded announcements of upcoming deployments, giving reviewers a window to inspect
+//!   the init code before it is deployed
+//! - Per-block summary of the deployment decisions in a header digest item, see
+//!   [`DeploymentDecisions`]
+//! - Rotation of a deployer's key, at once by Root or after `KeyRotationDelay` blocks by
+//!   the deployer itself
+//! - Smart contract wallets as deployers, their transactions signed by a designated
+//!   operator
+//! - Factory and deployer metadata blobs kept in the runtime's preimage store, released
+//!   when the factory is unregistered or the deployer revoked
+//! - A deployer admin origin, [`Origin::DeployerAdmin`], for accounts granted by Root, and
+//!   [`EnsureDeployerAdmin`] sharing the deployer administration origin with other pallets
+//! - Deferred enactment of authorizations, revocations and policy changes: once Root sets
+//!   an enactment delay, they are scheduled, announced and enacted by anyone after the
+//!   delay, `EmergencyOrigin` enacting them early or cancelling them. Approved proposals
+//!   wait out the delay from their proposal, and [`DeployerRegistry`] relays are scheduled
+//!   as Root calls; tenant admins, within their bounds, are exempt
+//! - Token holder veto of scheduled authorizations: objections locking `VetoThreshold` of
+//!   the total issuance before enactment put the authorization to a referendum instead
+//! - Two-phase retirement of contracts: an announcement, `ContractRetiringSoon` warnings
+//!   over a `RetirementGracePeriod`, then the contract is frozen in the registries
+//! - Offchain index of the contract creations of every block, internal ones included, see
+//!   [`CreationTrace`]
 //!
 //! ## Example Usage
 //!
@@ -28,7 +71,12 @@
 //! }
 //! ```
 
+pub use deployment_control_primitives::{
+    ContractProvenance, DeployerRegistry, DeployerSuspension, DeploymentAuthorizer,
+    DeploymentLifecycle,
+};
 pub use pallet::*;
+pub mod migrations;
 pub mod weights;
 
 /// Custom validation errors for deployment control
@@ -39,6 +87,20 @@ pub mod weights;
 pub enum DeploymentValidationError {
     /// Deployer is not authorized to deploy contracts
     UnauthorizedDeployer = 0,
+    /// Deployment attempted outside of the configured deployment windows
+    OutsideDeploymentWindow = 1,
+    /// Deployer cannot pay the fee of the permissionless deployment policy
+    ///
+    /// Codes 2 to 4 are taken by the runtime's own transaction checks.
+    InsufficientDeploymentFee = 5,
+    /// Deployer used up its deployment quota of the current quota period
+    ///
+    /// Code 6 is taken by the code freeze.
+    QuotaExhausted = 7,
+    /// Deployer was flagged for repeated unauthorized deployment attempts
+    ///
+    /// Codes 8 and 9 are taken by the network binding and the deployment escrow.
+    FlaggedDeployer = 10,
 }
 
 impl From<DeploymentValidationError> for u8 {
@@ -47,6 +109,75 @@ impl From<DeploymentValidationError> for u8 {
     }
 }
 
+impl TryFrom<u8> for DeploymentValidationError {
+    type Error = ();
+
+    fn try_from(code: u8) -> Result<Self, ()> {
+        match code {
+            0 => Ok(Self::UnauthorizedDeployer),
+            1 => Ok(Self::OutsideDeploymentWindow),
+            5 => Ok(Self::InsufficientDeploymentFee),
+            7 => Ok(Self::QuotaExhausted),
+            10 => Ok(Self::FlaggedDeployer),
+            _ => Err(()),
+        }
+    }
+}
+
+impl DeploymentValidationError {
+    /// Explanation of the error shown to users by the node's RPC methods
+    pub fn description(&self) -> &'static str {
+        match self {
+            Self::UnauthorizedDeployer => "sender is not an authorized deployer",
+            Self::OutsideDeploymentWindow => "outside of the deployment windows",
+            Self::InsufficientDeploymentFee => "sender cannot pay the deployment fee",
+            Self::QuotaExhausted => "sender used up its deployment quota for the period",
+            Self::FlaggedDeployer => "sender is flagged for repeated unauthorized deployments",
+        }
+    }
+}
+
+/// EVM backend used by `deploy_contract`
+///
+/// Implemented by the runtime on top of pallet-evm, so that this pallet does not depend
+/// on the EVM directly.
+pub trait ContractDeployer<AccountId> {
+    /// Account controlled by an EVM address
+    fn into_account_id(address: sp_core::H160) -> AccountId;
+
+    /// Create a contract from `source` and return its address and the gas it used
+    ///
+    /// Must charge `source` for the gas, like the EVM charges its transactions:
+    /// `deploy_contract` waives its own fee once the contract is created. Must fail if the
+    /// contract creation does not succeed, so that the whole deployment, gas charge
+    /// included, is rolled back.
+    fn create(
+        source: sp_core::H160,
+        init_code: sp_std::vec::Vec<u8>,
+        value: sp_core::U256,
+        gas_limit: u64,
+    ) -> Result<(sp_core::H160, u64), sp_runtime::DispatchError>;
+
+    /// Weight of executing up to `gas_limit` gas
+    fn gas_weight(gas_limit: u64) -> frame_support::weights::Weight;
+}
+
+/// Calls dispatched by other calls on behalf of their origin, e.g. `pallet_utility` batches
+///
+/// Lets scheduled governance calls be searched for deployer authorizations without this
+/// pallet depending on the pallets nesting calls.
+pub trait NestedCalls<Call> {
+    /// Calls `call` dispatches, empty if it is not a call nesting others
+    fn nested_calls(call: &Call) -> sp_std::vec::Vec<&Call>;
+}
+
+/// Nests no calls
+impl<Call> NestedCalls<Call> for () {
+    fn nested_calls(_call: &Call) -> sp_std::vec::Vec<&Call> {
+        sp_std::vec::Vec::new()
+    }
+}
+
 #[cfg(test)]
 mod mock;
 
@@ -58,162 +189,4992 @@ mod benchmarking;
 
 #[frame_support::pallet]
 pub mod pallet {
-	use frame_support::pallet_prelude::*;
-	use frame_system::pallet_prelude::*;
-	use sp_std::vec::Vec;
+	use frame_support::{
+		dispatch::{extract_actual_weight, GetDispatchInfo, PostDispatchInfo},
+		pallet_prelude::*,
+		storage::with_transaction,
+		traits::{
+			fungible::{self, BalancedHold, Inspect, Mutate, MutateHold},
+			tokens::{Fortitude, Precision, Preservation},
+			Bounded, CallerTrait, EitherOf, Imbalance, IsSubType, OnUnbalanced, OriginTrait,
+			QueryPreimage, StorePreimage, UnfilteredDispatchable,
+		},
+		weights::WeightMeter,
+		DefaultNoBound, Deserialize, Serialize,
+	};
+	use frame_system::{
+		offchain::{SendTransactionTypes, SubmitTransaction},
+		pallet_prelude::*,
+		EnsureRoot,
+	};
+	use sp_core::{H160, H256, U256};
+	use sp_runtime::{
+		offchain::storage::StorageValueRef,
+		traits::{AtLeast32BitUnsigned, Dispatchable, One, Saturating, Zero},
+		ConsensusEngineId, Digest, DigestItem, Perbill, Percent, TransactionOutcome,
+	};
+	use sp_std::{boxed::Box, collections::btree_set::BTreeSet, vec::Vec};
 
 	pub use crate::weights::WeightInfo;
+	use crate::{ContractDeployer, DeploymentLifecycle, NestedCalls};
+
+	/// Log target used by the offchain worker
+	const LOG_TARGET: &str = "runtime::evm-deployment-control";
+
+	/// Tracing target of the spans and events of the deployment checks
+	pub const TRACING_TARGET: &str = "runtime::deployment-checks";
+
+	/// Engine id of the digest items carrying the [`DeploymentDecisions`] of a block
+	pub const DEPLOYMENT_DECISIONS_ENGINE_ID: ConsensusEngineId = *b"qdcd";
+
+	/// Prefix of the offchain index keys of the [`CreationTrace`]s of a block
+	pub const CREATION_TRACES_PREFIX: &[u8] = b"qnch::creation-traces::";
+
+	/// Offchain local storage key of the last account the offchain worker scanned
+	const REJECTED_ATTEMPTS_CURSOR: &[u8] = b"qnch::rejected-attempts-cursor";
+
+	/// Number of rejected attempt counters the offchain worker scans per block
+	const REJECTED_ATTEMPTS_PAGE: usize = 64;
+
+	/// Balance of the currency holding intent bonds
+	pub type BalanceOf<T> =
+		<<T as Config>::Currency as Inspect<<T as frame_system::Config>::AccountId>>::Balance;
+
+	/// Funds taken from a deployer by a deployment fee or a slashed intent bond
+	pub type CreditOf<T> =
+		fungible::Credit<<T as frame_system::Config>::AccountId, <T as Config>::Currency>;
+
+	/// Metadata blob of a factory or deployer, inline if short and in the preimage store
+	/// otherwise
+	pub type MetadataOf<T> = Bounded<Vec<u8>, <T as frame_system::Config>::Hashing>;
+
+	/// Scheduled governance call, inline if short and in the preimage store otherwise
+	pub type BoundedCallOf<T> =
+		Bounded<<T as Config>::RuntimeCall, <T as frame_system::Config>::Hashing>;
 
 	#[pallet::config]
-	pub trait Config: frame_system::Config {
+	pub trait Config: frame_system::Config + SendTransactionTypes<Call<Self>> {
 		/// The overarching event type
 		#[allow(deprecated)]
 		type RuntimeEvent: From<Event<Self>>
 			+ IsType<<Self as frame_system::Config>::RuntimeEvent>;
 
+		/// The overarching origin, carrying this pallet's [`Origin`]
+		type RuntimeOrigin: From<Origin>
+			+ From<Self::PalletsOrigin>
+			+ OriginTrait<PalletsOrigin = Self::PalletsOrigin>
+			+ IsType<<Self as frame_system::Config>::RuntimeOrigin>;
+
+		/// The caller of the overarching origin, kept by scheduled governance calls
+		type PalletsOrigin: CallerTrait<Self::AccountId> + MaxEncodedLen;
+
+		/// The overarching call type, dispatched by `dispatch_as_deployer_admin` and
+		/// `enact_governance_call`
+		type RuntimeCall: Parameter
+			+ Dispatchable<
+				RuntimeOrigin = <Self as frame_system::Config>::RuntimeOrigin,
+				PostInfo = PostDispatchInfo,
+			> + GetDispatchInfo
+			+ From<frame_system::Call<Self>>
+			+ From<Call<Self>>
+			+ IsSubType<Call<Self>>;
+
+		/// Calls nested in others, searched for deployer authorizations when scheduling
+		/// governance calls
+		type NestedCalls: NestedCalls<<Self as Config>::RuntimeCall>;
+
+		/// Origin administering deployers and factories, typically [`EnsureDeployerAdmin`]
+		type AdminOrigin: EnsureOrigin<<Self as frame_system::Config>::RuntimeOrigin>;
+
 		/// Weight information for extrinsics in this pallet
 		type WeightInfo: WeightInfo;
+
+		/// Number of rejected deployment attempts within one window after which the
+		/// offchain worker raises an alert for the account
+		#[pallet::constant]
+		type RejectedAttemptThreshold: Get<u32>;
+
+		/// Length of the window, in blocks, over which rejected attempts are counted
+		#[pallet::constant]
+		type RejectedAttemptWindow: Get<BlockNumberFor<Self>>;
+
+		/// Priority of the unsigned alert transactions submitted by the offchain worker
+		#[pallet::constant]
+		type UnsignedPriority: Get<TransactionPriority>;
+
+		/// Maximum number of deployment windows that can be configured
+		#[pallet::constant]
+		type MaxDeploymentWindows: Get<u32>;
+
+		/// Maximum number of cleanup tasks waiting in the queue
+		#[pallet::constant]
+		type MaxQueuedCleanups: Get<u32>;
+
+		/// EVM backend creating contracts for `deploy_contract`
+		type ContractDeployer: ContractDeployer<Self::AccountId>;
+
+		/// Maximum length of a contract label or version
+		#[pallet::constant]
+		type MaxMetadataLen: Get<u32>;
+
+		/// Subscribers notified of factory registrations and deployer revocations
+		type Lifecycle: DeploymentLifecycle<Self::AccountId>;
+
+		/// Maximum number of named approvers in the authorization approval policy
+		#[pallet::constant]
+		type MaxApprovers: Get<u32>;
+
+		/// Maximum number of pending authorization proposals
+		#[pallet::constant]
+		type MaxProposals: Get<u32>;
+
+		/// Number of blocks during which an authorization proposal can be approved
+		#[pallet::constant]
+		type ProposalLifetime: Get<BlockNumberFor<Self>>;
+
+		/// Number of blocks before a factory approval expires at which the upcoming expiry
+		/// is announced
+		#[pallet::constant]
+		type FactoryExpiryNotice: Get<BlockNumberFor<Self>>;
+
+		/// Maximum number of factory expiry notices and expiries scheduled in one block
+		#[pallet::constant]
+		type MaxExpiriesPerBlock: Get<u32>;
+
+		/// Maximum number of authorization checks memoized within one block
+		#[pallet::constant]
+		type MaxCachedAuthorizations: Get<u32>;
+
+		/// Maximum nesting of CREATE and CREATE2 while the opcode policy limits it
+		#[pallet::constant]
+		type MaxCreateDepth: Get<u32>;
+
+		/// Currency holding the bonds of deployment intents
+		type Currency: Mutate<Self::AccountId>
+			+ MutateHold<Self::AccountId, Reason = Self::RuntimeHoldReason>
+			+ BalancedHold<Self::AccountId>;
+
+		/// Handler of deployment fees and slashed intent bonds, dropping them burns them
+		type DeploymentProceeds: OnUnbalanced<CreditOf<Self>>;
+
+		/// The overarching hold reason
+		type RuntimeHoldReason: From<HoldReason>;
+
+		/// Bond held from a deployer for every announced deployment intent
+		#[pallet::constant]
+		type IntentBond: Get<BalanceOf<Self>>;
+
+		/// Number of blocks between a deployer announcing the rotation of its key and the
+		/// rotation becoming executable
+		#[pallet::constant]
+		type KeyRotationDelay: Get<BlockNumberFor<Self>>;
+
+		/// Preimage store holding factory and deployer metadata blobs
+		type Preimages: QueryPreimage<H = Self::Hashing> + StorePreimage;
+
+		/// Maximum length of a factory or deployer metadata blob
+		#[pallet::constant]
+		type MaxMetadataBlobLen: Get<u32>;
+
+		/// Maximum number of deployers authorized in the genesis config
+		#[pallet::constant]
+		type MaxGenesisDeployers: Get<u32>;
+
+		/// Maximum number of authorization transitions kept per account, the oldest are
+		/// dropped first
+		#[pallet::constant]
+		type MaxAuthorizationTransitions: Get<u32>;
+
+		/// Number of blocks of a deployment quota period
+		///
+		/// Periods start at the multiples of the length, counted from genesis.
+		#[pallet::constant]
+		type QuotaPeriodLength: Get<BlockNumberFor<Self>>;
+
+		/// Origin enacting scheduled governance calls before their enactment delay is over,
+		/// or cancelling them
+		type EmergencyOrigin: EnsureOrigin<<Self as frame_system::Config>::RuntimeOrigin>;
+
+		/// Maximum number of governance calls waiting for enactment
+		#[pallet::constant]
+		type MaxGovernanceCalls: Get<u32>;
+
+		/// Share of the total issuance whose objection to a scheduled authorization vetoes it
+		#[pallet::constant]
+		type VetoThreshold: Get<Perbill>;
+
+		/// Number of blocks token holders vote for in the referendum on a vetoed authorization
+		#[pallet::constant]
+		type ReferendumPeriod: Get<BlockNumberFor<Self>>;
+
+		/// Number of blocks between the announcement of a contract's retirement and the
+		/// earliest block it can be finalized
+		#[pallet::constant]
+		type RetirementGracePeriod: Get<BlockNumberFor<Self>>;
+	}
+
+	/// Reasons for the pallet to hold funds
+	#[pallet::composite_enum]
+	pub enum HoldReason {
+		/// Bond of an announced deployment intent, released by the matching deployment
+		DeploymentIntent,
+		/// Fee of a permissionless deployment, burned right after being held
+		DeploymentFee,
+		/// Tokens of an objection to a scheduled authorization or of a referendum vote,
+		/// released once the authorization is enacted, cancelled or decided by the referendum
+		GovernanceVote,
 	}
 
+	/// The in-code storage version
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(3);
+
 	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(_);
 
-	/// Storage map of authorized EVM deployers
+	/// Origins of this pallet
+	#[pallet::origin]
+	#[derive(PartialEq, Eq, Clone, RuntimeDebug, Encode, Decode, TypeInfo, MaxEncodedLen)]
+	pub enum Origin {
+		/// A deployer admin, granted by Root through `set_deployer_admin`, dispatching
+		/// through `dispatch_as_deployer_admin`
+		DeployerAdmin,
+	}
+
+	/// Ensures the [`Origin::DeployerAdmin`] origin
+	pub struct EnsureDeployerAdminOrigin;
+
+	impl<O: Into<Result<Origin, O>> + From<Origin>> EnsureOrigin<O> for EnsureDeployerAdminOrigin {
+		type Success = ();
+
+		fn try_origin(o: O) -> Result<Self::Success, O> {
+			o.into().map(|Origin::DeployerAdmin| ())
+		}
+
+		#[cfg(feature = "runtime-benchmarks")]
+		fn try_successful_origin() -> Result<O, ()> {
+			Ok(O::from(Origin::DeployerAdmin))
+		}
+	}
+
+	/// Root or a deployer admin
 	///
-	/// Accounts in this map are permitted to deploy contracts directly to the EVM.
-	/// Regular users must deploy through approved factory contracts.
-	#[pallet::storage]
-	#[pallet::getter(fn is_authorized_storage)]
-	pub type AuthorizedDeployers<T: Config> =
-		StorageMap<_, Blake2_128Concat, T::AccountId, (), OptionQuery>;
+	/// The origin of the pallet's deployer and factory registry calls, shared with other
+	/// pallets administering deployers, e.g. their metadata or reputation.
+	pub type EnsureDeployerAdmin<AccountId> =
+		EitherOf<EnsureRoot<AccountId>, EnsureDeployerAdminOrigin>;
 
-	#[pallet::genesis_config]
-	pub struct GenesisConfig<T: Config> {
-		/// Initial list of authorized deployers
+	/// Record kept for an authorized deployer
+	///
+	/// Encoded compactly since storage version 2, as every authorization check reads it into
+	/// the proof of validity: the block number takes as few bytes as its value needs, and
+	/// the flags share a single byte.
+	#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub struct DeployerInfo<BlockNumber> {
+		/// Block at which the account was authorized
 		///
-		/// Typically includes the sudo account and any initial governance accounts.
-		pub authorized_deployers: Vec<T::AccountId>,
+		/// Genesis deployers report block zero. Deployers authorized before storage
+		/// version 1 report the block at which their record was migrated.
+		#[codec(compact)]
+		pub authorized_at: BlockNumber,
+		/// How the account came to be authorized
+		pub flags: DeployerFlags,
 	}
 
-	impl<T: Config> Default for GenesisConfig<T> {
-		fn default() -> Self {
-			Self { authorized_deployers: Vec::new() }
+	impl<BlockNumber> DeployerInfo<BlockNumber> {
+		/// Record of an account authorized at `authorized_at`, without any flag
+		pub fn new(authorized_at: BlockNumber) -> Self {
+			Self { authorized_at, flags: DeployerFlags::default() }
 		}
 	}
 
-	#[pallet::genesis_build]
-	impl<T: Config> BuildGenesisConfig for GenesisConfig<T> {
-		fn build(&self) {
-			for deployer in &self.authorized_deployers {
-				AuthorizedDeployers::<T>::insert(deployer, ());
+	/// Facts about an authorized deployer's authorization
+	///
+	/// A set of flag bits, see the associated constants.
+	#[derive(
+		Encode, Decode, Clone, Copy, PartialEq, Eq, Default, RuntimeDebug, TypeInfo, MaxEncodedLen,
+	)]
+	pub struct DeployerFlags(pub u8);
+
+	impl DeployerFlags {
+		/// Authorized in the genesis config
+		pub const GENESIS: Self = Self(1);
+		/// Authorization carried over from a rotated key, `authorized_at` being the block the
+		/// original key was authorized at
+		pub const ROTATED: Self = Self(1 << 1);
+
+		/// Whether every bit of `flag` is set
+		pub fn contains(&self, flag: Self) -> bool {
+			self.0 & flag.0 == flag.0
+		}
+	}
+
+	/// Role of a deployer authorized in the genesis config
+	#[derive(
+		Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo, Serialize, Deserialize,
+	)]
+	#[serde(crate = "frame_support::__private::serde", rename_all = "camelCase")]
+	pub enum GenesisDeployerRole {
+		/// Deploys within the configured deployment windows
+		Deployer,
+		/// Deploys regardless of the configured deployment windows
+		WindowOverride,
+		/// Deploys and holds the [`Origin::DeployerAdmin`] origin
+		Admin,
+	}
+
+	/// Address of a deployer authorized in the genesis config
+	///
+	/// Chain specs give either the EVM address of the deployer, as a `0x` prefixed hex
+	/// string, or its account in the account type's own format, e.g. SS58. With 20-byte
+	/// accounts mapped by identity, both forms are the same `0x` string and read as an EVM
+	/// address, resolving to the same account.
+	#[derive(Clone, PartialEq, Eq, RuntimeDebug, Serialize, Deserialize)]
+	#[serde(crate = "frame_support::__private::serde", untagged)]
+	pub enum DeployerAddress<AccountId> {
+		/// EVM address, resolved through `ContractDeployer::into_account_id`
+		Evm(H160),
+		/// Account of the deployer
+		Account(AccountId),
+	}
+
+	impl<AccountId> From<AccountId> for DeployerAddress<AccountId> {
+		fn from(account: AccountId) -> Self {
+			Self::Account(account)
+		}
+	}
+
+	impl<AccountId> DeployerAddress<AccountId> {
+		/// Account of the deployer, given `into_account_id` mapping EVM addresses to accounts
+		pub fn into_account_id(self, into_account_id: impl FnOnce(H160) -> AccountId) -> AccountId {
+			match self {
+				Self::Evm(address) => into_account_id(address),
+				Self::Account(account) => account,
 			}
 		}
 	}
 
-	#[pallet::event]
-	#[pallet::generate_deposit(pub(super) fn deposit_event)]
-	pub enum Event<T: Config> {
-		/// A deployer was authorized to deploy EVM contracts
-		DeployerAuthorized {
-			/// The account that was authorized
-			deployer: T::AccountId
+	/// Rejected deployment attempts recorded for an account
+	#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub struct RejectedAttemptInfo<BlockNumber> {
+		/// Number of rejected attempts in the current window
+		pub count: u32,
+		/// Block at which the current window started
+		pub window_start: BlockNumber,
+	}
+
+	/// Provenance of a contract created on the EVM
+	#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub struct ProvenanceRecord<BlockNumber> {
+		/// Address that created the contract, or that its administration was transferred to
+		/// with `transfer_contract_admin`
+		pub deployer: H160,
+		/// Block in which the contract was created
+		pub block: BlockNumber,
+	}
+
+	/// Retirement of a contract
+	#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub enum Retirement<BlockNumber> {
+		/// The retirement was announced and can be finalized from `retires_at` on
+		Announced {
+			/// End of the grace period
+			retires_at: BlockNumber,
 		},
-		/// A deployer's authorization was revoked
-		DeployerRevoked {
-			/// The account that was revoked
-			deployer: T::AccountId
+		/// The contract is retired, frozen in the registries
+		Retired {
+			/// Block at which the retirement was finalized
+			retired_at: BlockNumber,
 		},
 	}
 
-	#[pallet::error]
-	pub enum Error<T> {
-		/// Account is already authorized
-		AlreadyAuthorized,
-		/// Account is not authorized
-		NotAuthorized,
+	/// Deployment announced ahead of time by an authorized deployer
+	#[derive(
+		Encode, Decode, CloneNoBound, PartialEqNoBound, EqNoBound, RuntimeDebugNoBound, TypeInfo,
+		MaxEncodedLen,
+	)]
+	#[scale_info(skip_type_params(T))]
+	#[codec(mel_bound())]
+	pub struct DeploymentIntent<T: Config> {
+		/// Human readable name of the upcoming contract
+		pub label: BoundedVec<u8, T::MaxMetadataLen>,
+		/// Bond held until the deployment
+		pub bond: BalanceOf<T>,
+		/// Block at which the intent was announced
+		pub announced_at: BlockNumberFor<T>,
 	}
 
-	#[pallet::call]
-	impl<T: Config> Pallet<T> {
-		/// Authorize an account to deploy EVM contracts
-		///
-		/// This allows the specified account to deploy contracts directly to the EVM,
-		/// bypassing the factory contract requirement.
-		///
-		/// # Parameters
-		/// - `origin`: Must be Root (typically called via sudo or governance)
-		/// - `deployer`: The account to authorize
+	/// Deployment control decisions taken in a block
+	///
+	/// Deposited as a consensus digest item under [`DEPLOYMENT_DECISIONS_ENGINE_ID`] in
+	/// every block admitting or refusing a deployment, so that light clients and bridges
+	/// can follow the enforcement of the deployment policy from the headers alone.
+	#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+	pub struct DeploymentDecisions<AccountId> {
+		/// Number of contract creations admitted in the block
+		pub allowed: u32,
+		/// Number of deployments refused in the block
+		pub denied: u32,
+		/// First account refused in the block
+		pub first_denied: Option<AccountId>,
+	}
+
+	/// Contract creation, internal ones included, written to the offchain index
+	///
+	/// The creations of a block are indexed together under [`CreationTrace::offchain_key`]
+	/// when nodes run with offchain indexing enabled, so that creations by contracts can be
+	/// attributed without replaying the block with the tracing runtime.
+	#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+	pub struct CreationTrace {
+		/// Index of the extrinsic creating the contract
+		pub extrinsic_index: u32,
+		/// Address executing the creation: the transaction sender, or the contract running
+		/// `CREATE` or `CREATE2`
+		pub creator: H160,
+		/// Address of the created contract
+		pub contract: H160,
+	}
+
+	impl CreationTrace {
+		/// Offchain index key of the creations of the block with the given parent and digest
 		///
-		/// # Errors
-		/// - `AlreadyAuthorized`: The account is already in the authorized list
+		/// Keyed by the parent hash and the pre-runtime digest items rather than the block
+		/// number, so that sibling blocks do not overwrite each other's creations. The
+		/// runtime knows both while building the block, and the node finds them in the
+		/// header.
+		pub fn offchain_key<Hash: Encode>(parent_hash: &Hash, digest: &Digest) -> Vec<u8> {
+			let pre_runtime: Vec<&DigestItem> = digest
+				.logs()
+				.iter()
+				.filter(|item| matches!(item, DigestItem::PreRuntime(..)))
+				.collect();
+			let identity = (parent_hash, pre_runtime).using_encoded(sp_io::hashing::blake2_256);
+			[CREATION_TRACES_PREFIX, &identity[..]].concat()
+		}
+	}
+
+	/// Deployers and factories added and removed by a batch of calls
+	#[derive(Clone, PartialEq, Eq, RuntimeDebug, DefaultNoBound)]
+	pub struct RegistryDiff<AccountId> {
+		/// Accounts authorized by the calls
+		pub added_deployers: Vec<AccountId>,
+		/// Accounts whose authorization was revoked by the calls
+		pub removed_deployers: Vec<AccountId>,
+		/// Factories approved by the calls
+		pub added_factories: Vec<H160>,
+		/// Factories removed by the calls
+		pub removed_factories: Vec<H160>,
+	}
+
+	impl<AccountId: Decode> DeploymentDecisions<AccountId> {
+		/// Decisions recorded in the digest of a block header, if any
+		pub fn find(digest: &Digest) -> Option<Self> {
+			digest
+				.logs()
+				.iter()
+				.find_map(|log| log.consensus_try_to(&DEPLOYMENT_DECISIONS_ENGINE_ID))
+		}
+	}
+
+	/// Storage cleanup work deferred to `on_idle`
+	#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub enum CleanupTask<AccountId> {
+		/// Remove the window override of an account that is no longer authorized
+		RevokedOverride(AccountId),
+		/// Remove the rejected attempt counter of an unflagged account once its window expired
+		ExpiredRejectedAttempts(AccountId),
+		/// Remove the provenance record of a contract
+		Provenance(H160),
+	}
+
+	/// Metadata registered with a contract deployed through `deploy_contract`
+	#[derive(
+		Encode, Decode, CloneNoBound, PartialEqNoBound, EqNoBound, RuntimeDebugNoBound, TypeInfo,
+		MaxEncodedLen,
+	)]
+	#[scale_info(skip_type_params(MaxLen))]
+	#[codec(mel_bound(MaxLen: Get<u32>))]
+	pub struct ContractMetadata<MaxLen: Get<u32>> {
+		/// Human readable name of the contract
+		pub label: BoundedVec<u8, MaxLen>,
+		/// Release version of the contract
+		pub version: BoundedVec<u8, MaxLen>,
+	}
+
+	/// Status of an approved factory contract
+	#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub enum FactoryStatus {
+		/// The factory may deploy contracts
+		Active,
+		/// The factory is temporarily barred from deploying contracts
+		Suspended,
+		/// The factory was retired and may never deploy contracts again
 		///
-		/// # Events
-		/// - `DeployerAuthorized`: Emitted when authorization succeeds
-		#[pallet::call_index(0)]
-		#[pallet::weight(T::WeightInfo::authorize_deployer())]
-		pub fn authorize_deployer(
-			origin: OriginFor<T>,
-			deployer: T::AccountId,
-		) -> DispatchResult {
-			ensure_root(origin)?;
+		/// Only set by [`Pallet::finalize_retirement`], and never lifted.
+		Retired,
+	}
 
-			ensure!(
-				!AuthorizedDeployers::<T>::contains_key(&deployer),
-				Error::<T>::AlreadyAuthorized
-			);
+	/// Record kept for an approved factory contract
+	#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub struct FactoryInfo<AccountId, BlockNumber> {
+		/// Account administering the factory
+		pub admin: AccountId,
+		/// Number of contracts recorded with the factory as deployer
+		pub children: u32,
+		/// Current status of the factory
+		pub status: FactoryStatus,
+		/// Block at which the factory was registered
+		pub registered_at: BlockNumber,
+	}
 
-			AuthorizedDeployers::<T>::insert(&deployer, ());
-			Self::deposit_event(Event::DeployerAuthorized { deployer });
+	/// Pallet functions an approved factory may call through the runtime's factory precompile
+	///
+	/// A set of permission bits, see the associated constants.
+	#[derive(
+		Encode, Decode, Clone, Copy, PartialEq, Eq, Default, RuntimeDebug, TypeInfo, MaxEncodedLen,
+	)]
+	pub struct FactoryPermissions(pub u8);
 
-			Ok(())
+	impl FactoryPermissions {
+		/// Register the metadata of contracts created by the factory
+		pub const REGISTER_METADATA: Self = Self(1);
+		/// Read the remaining contract creation quota of the current block
+		pub const READ_QUOTA: Self = Self(1 << 1);
+
+		/// Whether every bit of `permission` is set
+		pub fn contains(&self, permission: Self) -> bool {
+			self.0 & permission.0 == permission.0
 		}
+	}
 
-		/// Revoke an account's authorization to deploy EVM contracts
-		///
-		/// This removes the account from the authorized deployers list, preventing
-		/// them from deploying contracts directly to the EVM.
+	/// EVM opcode policy applied by the runtime's EVM configuration
+	///
+	/// The default policy bans SELFDESTRUCT and does not limit nested contract creations.
+	#[derive(
+		Encode, Decode, Clone, Copy, PartialEq, Eq, Default, RuntimeDebug, TypeInfo, MaxEncodedLen,
+	)]
+	pub struct OpcodePolicy {
+		/// Whether contracts may execute SELFDESTRUCT
+		pub allow_selfdestruct: bool,
+		/// Whether contract creations may nest at most `MaxCreateDepth` deep
 		///
-		/// # Parameters
-		/// - `origin`: Must be Root (typically called via sudo or governance)
-		/// - `deployer`: The account to revoke
+		/// Only CREATE and CREATE2 count: the EVM call depth keeps its standard limit.
+		pub limit_create_depth: bool,
+	}
+
+	/// Who may deploy contracts besides the authorized deployers
+	///
+	/// Lets the chain open deployments through a storage change rather than a runtime
+	/// upgrade. Authorized deployers may deploy under every policy, and deployment windows
+	/// and the per-block creation cap apply to everyone.
+	#[derive(
+		Encode, Decode, Clone, Copy, PartialEq, Eq, Default, RuntimeDebug, TypeInfo, MaxEncodedLen,
+	)]
+	pub enum DeploymentPolicy<Balance> {
+		/// Only authorized deployers may deploy contracts
+		#[default]
+		Permissioned,
+		/// Every account may deploy contracts
+		Permissionless,
+		/// Every account may deploy contracts, accounts that are not authorized deployers
+		/// pay a fee that is burned
+		PermissionlessWithFee {
+			/// Fee charged for every contract creation
+			fee: Balance,
+		},
+		/// A share of the contract creations of accounts that are not authorized deployers
+		/// is admitted, to canary permissionless deployments with bounded exposure
 		///
-		/// # Errors
-		/// - `NotAuthorized`: The account is not in the authorized list
+		/// Whether a creation is admitted is drawn from the sender and the parent block
+		/// hash, so it is the same in the transaction pool and in the block, and a refused
+		/// sender may try again in a later block.
+		Rollout {
+			/// Share of creations admitted
+			admitted: Percent,
+		},
+	}
+
+	/// Named approvers and threshold of the authorization approval workflow
+	#[derive(
+		Encode, Decode, CloneNoBound, PartialEqNoBound, EqNoBound, RuntimeDebugNoBound, TypeInfo,
+		MaxEncodedLen,
+	)]
+	#[scale_info(skip_type_params(T))]
+	#[codec(mel_bound())]
+	pub struct ApprovalPolicy<T: Config> {
+		/// Accounts allowed to propose, approve and execute authorizations
+		pub approvers: BoundedVec<T::AccountId, T::MaxApprovers>,
+		/// Number of approvals required to authorize deployers at or above `risk_tier`
+		pub threshold: u32,
+		/// Lowest risk tier requiring `threshold` approvals; lower tiers need a single one
 		///
-		/// # Events
-		/// - `DeployerRevoked`: Emitted when revocation succeeds
-		#[pallet::call_index(1)]
-		#[pallet::weight(T::WeightInfo::revoke_deployer())]
-		pub fn revoke_deployer(
-			origin: OriginFor<T>,
-			deployer: T::AccountId,
-		) -> DispatchResult {
-			ensure_root(origin)?;
+		/// The tier of a deployer is assessed by governance, see [`DeployerRiskTiers`].
+		pub risk_tier: u8,
+	}
 
-			ensure!(
-				AuthorizedDeployers::<T>::contains_key(&deployer),
-				Error::<T>::NotAuthorized
-			);
+	impl<T: Config> ApprovalPolicy<T> {
+		/// Whether the threshold is reachable and the approvers are unique
+		pub fn is_valid(&self) -> bool {
+			self.threshold > 0 &&
+				self.threshold as usize <= self.approvers.len() &&
+				self.approvers
+					.iter()
+					.enumerate()
+					.all(|(i, approver)| !self.approvers[..i].contains(approver))
+		}
 
-			AuthorizedDeployers::<T>::remove(&deployer);
-			Self::deposit_event(Event::DeployerRevoked { deployer });
+		/// Number of approvals needed to authorize a deployer of the given risk tier
+		pub fn required_approvals(&self, risk_tier: u8) -> u32 {
+			if risk_tier >= self.risk_tier {
+				self.threshold
+			} else {
+				1
+			}
+		}
 
-			Ok(())
+		/// Whether an account is one of the named approvers
+		pub fn is_approver(&self, who: &T::AccountId) -> bool {
+			self.approvers.contains(who)
 		}
 	}
 
-	impl<T: Config> Pallet<T> {
-		/// Check if an account is authorized to deploy EVM contracts
-		///
-		/// This is the primary query interface used by the EVM's deployment control logic.
-		///
-		/// # Parameters
-		/// - `account`: The account to check
-		///
-		/// # Returns
-		/// `true` if the account is authorized, `false` otherwise
-		pub fn is_authorized(account: &T::AccountId) -> bool {
-			AuthorizedDeployers::<T>::contains_key(account)
+	/// A pending proposal to authorize a deployer
+	#[derive(
+		Encode, Decode, CloneNoBound, PartialEqNoBound, EqNoBound, RuntimeDebugNoBound, TypeInfo,
+		MaxEncodedLen,
+	)]
+	#[scale_info(skip_type_params(T))]
+	#[codec(mel_bound())]
+	pub struct AuthorizationProposal<T: Config> {
+		/// The account to authorize
+		pub deployer: T::AccountId,
+		/// Approvers who approved the proposal, the proposer included
+		pub approvals: BoundedVec<T::AccountId, T::MaxApprovers>,
+		/// Block in which the proposal was made
+		pub proposed_at: BlockNumberFor<T>,
+		/// Block from which the proposal can no longer be approved or executed
+		pub expires_at: BlockNumberFor<T>,
+		/// Governance call executing the proposal, once scheduled while an enactment delay
+		/// is set
+		pub scheduled: Option<u32>,
+	}
+
+	/// A governance call waiting for the enactment delay to pass
+	#[derive(
+		Encode, Decode, CloneNoBound, PartialEqNoBound, EqNoBound, RuntimeDebugNoBound, TypeInfo,
+		MaxEncodedLen,
+	)]
+	#[scale_info(skip_type_params(T))]
+	#[codec(mel_bound())]
+	pub struct GovernanceCall<T: Config> {
+		/// Origin that scheduled the call, which it is dispatched with
+		pub origin: T::PalletsOrigin,
+		/// The call
+		pub call: BoundedCallOf<T>,
+		/// Block from which the call can be enacted
+		pub enact_at: BlockNumberFor<T>,
+	}
+
+	/// Tokens staked by a holder on a scheduled authorization
+	#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub struct Vote<Balance> {
+		/// Whether the tokens support the authorization, objections being against it
+		pub aye: bool,
+		/// Tokens held for the vote
+		pub amount: Balance,
+	}
+
+	/// Referendum of token holders on a vetoed authorization
+	#[derive(
+		Encode, Decode, CloneNoBound, PartialEqNoBound, EqNoBound, RuntimeDebugNoBound, TypeInfo,
+		MaxEncodedLen,
+	)]
+	#[scale_info(skip_type_params(T))]
+	#[codec(mel_bound())]
+	pub struct Referendum<T: Config> {
+		/// Origin that scheduled the authorization, which it is dispatched with if approved
+		pub origin: T::PalletsOrigin,
+		/// The authorization call
+		pub call: BoundedCallOf<T>,
+		/// Block from which the referendum can be concluded
+		pub ends_at: BlockNumberFor<T>,
+		/// Tokens voted for the authorization
+		pub ayes: BalanceOf<T>,
+		/// Tokens voted against the authorization, including the objections vetoing it
+		pub nays: BalanceOf<T>,
+	}
+
+	/// Contract creations of a deployer in a quota period
+	#[derive(
+		Encode, Decode, Clone, Copy, PartialEq, Eq, Default, RuntimeDebug, TypeInfo, MaxEncodedLen,
+	)]
+	pub struct QuotaUsage<BlockNumber> {
+		/// Index of the quota period the count belongs to
+		pub period: BlockNumber,
+		/// Contract creations counted in the period
+		pub used: u32,
+	}
+
+	/// Bounds set by governance on what the admin of a tenant may manage
+	#[derive(
+		Encode, Decode, Clone, Copy, PartialEq, Eq, Default, RuntimeDebug, TypeInfo, MaxEncodedLen,
+	)]
+	pub struct TenantBounds {
+		/// Maximum number of factories registered in the tenant's namespace
+		pub max_factories: u32,
+		/// Maximum number of deployers authorized by the tenant's admin
+		pub max_deployers: u32,
+	}
+
+	/// Namespace of an onboarded organisation in the factory registry
+	///
+	/// The admin registers and suspends the tenant's factories and authorizes its deployers
+	/// within the bounds, without going through `AdminOrigin`.
+	#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub struct TenantInfo<AccountId> {
+		/// Account administering the tenant
+		pub admin: AccountId,
+		/// Bounds of the tenant
+		pub bounds: TenantBounds,
+		/// Number of factories registered in the tenant's namespace
+		pub factories: u32,
+		/// Number of deployers authorized by the tenant's admin
+		pub deployers: u32,
+	}
+
+	/// Authorization transitions of an account
+	///
+	/// Authorizations and revocations alternate, so the account held the opposite of the
+	/// first transition's status from `since` up to it.
+	#[derive(
+		Encode, Decode, CloneNoBound, PartialEqNoBound, EqNoBound, RuntimeDebugNoBound, TypeInfo,
+		MaxEncodedLen,
+	)]
+	#[scale_info(skip_type_params(T))]
+	#[codec(mel_bound())]
+	pub struct AuthorizationLog<T: Config> {
+		/// Block from which the transitions are complete
+		pub since: BlockNumberFor<T>,
+		/// Block of each authorization (`true`) and revocation (`false`), oldest first
+		pub transitions: BoundedVec<(BlockNumberFor<T>, bool), T::MaxAuthorizationTransitions>,
+	}
+
+	/// A recurring range of blocks during which contract deployments are allowed
+	///
+	/// The window is open for blocks where
+	/// `offset <= block_number % period < offset + duration`.
+	#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub struct DeploymentWindow<BlockNumber> {
+		/// Length of the recurring cycle, in blocks
+		pub period: BlockNumber,
+		/// First block of the window within each cycle
+		pub offset: BlockNumber,
+		/// Number of blocks the window stays open
+		pub duration: BlockNumber,
+	}
+
+	impl<BlockNumber: AtLeast32BitUnsigned + Copy> DeploymentWindow<BlockNumber> {
+		/// Whether the window is well formed
+		pub fn is_valid(&self) -> bool {
+			!self.period.is_zero() &&
+				!self.duration.is_zero() &&
+				self.offset.saturating_add(self.duration) <= self.period
+		}
+
+		/// Whether the window is open at the given block
+		pub fn contains(&self, block_number: BlockNumber) -> bool {
+			if self.period.is_zero() {
+				return false;
+			}
+			let position = block_number % self.period;
+			position >= self.offset && position < self.offset.saturating_add(self.duration)
 		}
 	}
+
+	/// Storage map of authorized EVM deployers
+	///
+	/// Accounts in this map are permitted to deploy contracts directly to the EVM.
+	/// Regular users must deploy through approved factory contracts.
+	#[pallet::storage]
+	#[pallet::getter(fn is_authorized_storage)]
+	pub type AuthorizedDeployers<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, DeployerInfo<BlockNumberFor<T>>, OptionQuery>;
+
+	/// Rejected deployment attempts per account
+	///
+	/// Fed through [`Pallet::note_rejected_attempt`] by every enforcement point refusing a
+	/// deployment by an unauthorized account, and scanned page by page by the offchain worker
+	/// to detect accounts that keep trying to deploy without authorization.
+	#[pallet::storage]
+	pub type RejectedAttempts<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		RejectedAttemptInfo<BlockNumberFor<T>>,
+		OptionQuery,
+	>;
+
+	/// Accounts flagged for repeated unauthorized deployment attempts
+	///
+	/// Maps to the block at which the alert was recorded. The deployments of a flagged
+	/// account are refused with `FlaggedDeployer` before they reach a block, rather than
+	/// admitted and counted, until governance unflags it.
+	#[pallet::storage]
+	pub type FlaggedDeployers<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, BlockNumberFor<T>, OptionQuery>;
+
+	/// Maximum number of contract creations allowed per block
+	///
+	/// `None` means creations are not rate limited.
+	#[pallet::storage]
+	pub type MaxCreatesPerBlock<T: Config> = StorageValue<_, u32, OptionQuery>;
+
+	/// Maximum number of contract creations of a deployer per quota period
+	///
+	/// `None` means deployers are not subject to a quota.
+	#[pallet::storage]
+	pub type DeploymentQuota<T: Config> = StorageValue<_, u32, OptionQuery>;
+
+	/// Contract creations of each deployer in the last quota period it created contracts in
+	///
+	/// Counts of past periods are not cleared when a period ends: they are reset by the
+	/// first creation of the deployer in a later period, and read as zero until then.
+	#[pallet::storage]
+	pub type DeploymentQuotaUsage<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, QuotaUsage<BlockNumberFor<T>>, OptionQuery>;
+
+	/// Recurring windows during which contract deployments are allowed
+	///
+	/// Deployments are unrestricted in time while no window is configured.
+	#[pallet::storage]
+	pub type DeploymentWindows<T: Config> = StorageValue<
+		_,
+		BoundedVec<DeploymentWindow<BlockNumberFor<T>>, T::MaxDeploymentWindows>,
+		ValueQuery,
+	>;
+
+	/// Deployers allowed to deploy outside of the configured deployment windows
+	#[pallet::storage]
+	pub type WindowOverrides<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, (), OptionQuery>;
+
+	/// Accounts submitting transactions on behalf of authorized contract wallets, keyed by
+	/// the account of the wallet
+	///
+	/// Contract wallets cannot sign, so their operator signs contract creations whose EVM
+	/// source, and thus immediate caller of the creation, is the wallet.
+	#[pallet::storage]
+	pub type ContractWalletOperators<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, T::AccountId, OptionQuery>;
+
+	/// Accounts granted the [`Origin::DeployerAdmin`] origin
+	#[pallet::storage]
+	pub type DeployerAdmins<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, (), OptionQuery>;
+
+	/// Metadata blobs of authorized deployers
+	///
+	/// Blobs over the inline limit of [`Bounded`] are kept in the preimage store, requested
+	/// for as long as the deployer is authorized.
+	#[pallet::storage]
+	pub type DeployerMetadata<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, MetadataOf<T>, OptionQuery>;
+
+	/// Key rotations announced by deployers, keyed by the current key
+	///
+	/// Maps to the new key and the block from which the rotation can be executed.
+	#[pallet::storage]
+	pub type PendingKeyRotations<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		(T::AccountId, BlockNumberFor<T>),
+		OptionQuery,
+	>;
+
+	/// Number of contract creations included in the current block
+	///
+	/// Cleared at the end of every block.
+	#[pallet::storage]
+	pub type CreatesThisBlock<T: Config> = StorageValue<_, u32, ValueQuery>;
+
+	/// Number of deployments refused in the current block and the first refused account
+	///
+	/// Cleared at the end of every block.
+	#[pallet::storage]
+	pub type DeniedThisBlock<T: Config> = StorageValue<_, (u32, T::AccountId), OptionQuery>;
+
+	/// Contract creations of the current block, moved to the offchain index at its end
+	///
+	/// Bounded by the gas of a block, each creation costing at least 32k gas.
+	#[pallet::storage]
+	#[pallet::unbounded]
+	pub type CreationTraces<T: Config> = StorageValue<_, Vec<CreationTrace>, ValueQuery>;
+
+	/// Authorization transitions of the accounts whose authorization changed
+	///
+	/// A compact index answering [`Pallet::was_authorized_at`] without the state of past
+	/// blocks, so that nodes pruning their state can answer historical queries.
+	#[pallet::storage]
+	pub type AuthorizationTransitions<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, AuthorizationLog<T>, OptionQuery>;
+
+	/// Block from which [`AuthorizationTransitions`] holds every transition
+	///
+	/// Zero on chains that start with the index, otherwise the block of the first transition
+	/// recorded after the upgrade introducing it.
+	#[pallet::storage]
+	pub type AuthorizationIndexSince<T: Config> = StorageValue<_, BlockNumberFor<T>, OptionQuery>;
+
+	/// Results of the authorization checks made in the current block
+	///
+	/// Lets repeated checks for the same deployer, as in factory-heavy blocks, be answered
+	/// from the block's storage overlay rather than the trie. Each result is kept under its
+	/// own key, so a miss writes one entry. Entries are dropped when the authorization of
+	/// their account changes, and the cache is cleared at the end of every block so it never
+	/// reaches the state.
+	#[pallet::storage]
+	pub type AuthorizationCache<T: Config> =
+		StorageMap<_, Twox64Concat, T::AccountId, bool, OptionQuery>;
+
+	/// Number of entries in [`AuthorizationCache`], bounded by `MaxCachedAuthorizations`
+	#[pallet::storage]
+	pub type CachedAuthorizations<T: Config> = StorageValue<_, u32, ValueQuery>;
+
+	/// Provenance records of created contracts, keyed by contract address
+	///
+	/// Fed through the pallet's [`DeploymentLifecycle::on_deployed`] callback.
+	#[pallet::storage]
+	pub type ContractProvenance<T: Config> =
+		StorageMap<_, Identity, H160, ProvenanceRecord<BlockNumberFor<T>>, OptionQuery>;
+
+	/// Metadata of contracts deployed through `deploy_contract`, keyed by contract address
+	#[pallet::storage]
+	pub type ContractMetadataOf<T: Config> =
+		StorageMap<_, Identity, H160, ContractMetadata<T::MaxMetadataLen>, OptionQuery>;
+
+	/// Announced deployment intents, keyed by deployer and keccak-256 hash of the init code
+	#[pallet::storage]
+	pub type DeploymentIntents<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		Identity,
+		H256,
+		DeploymentIntent<T>,
+		OptionQuery,
+	>;
+
+	/// Intents fulfilled by a deployment, keyed by the created contract
+	#[pallet::storage]
+	pub type FulfilledIntents<T: Config> =
+		StorageMap<_, Identity, H160, (H256, DeploymentIntent<T>), OptionQuery>;
+
+	/// Announced deployment about to be applied, noted by the runtime before the creation
+	/// runs and picked up by [`DeploymentLifecycle::on_deployed`]
+	///
+	/// Cleared at the end of every block.
+	#[pallet::storage]
+	pub type PendingIntent<T: Config> = StorageValue<_, (T::AccountId, H256), OptionQuery>;
+
+	/// Depth of the creations nested in each contract created by the EVM execution under
+	/// way, noted by [`Pallet::note_nested_creation`]
+	///
+	/// Cleared by [`Pallet::take_too_deep_creation`] once the execution ends.
+	#[pallet::storage]
+	pub type NestedCreationDepth<T: Config> = StorageMap<_, Identity, H160, u32, OptionQuery>;
+
+	/// Contract the EVM execution under way created deeper than `MaxCreateDepth`
+	#[pallet::storage]
+	pub type TooDeepCreation<T: Config> = StorageValue<_, H160, OptionQuery>;
+
+	/// Approved factory contracts, keyed by contract address
+	#[pallet::storage]
+	pub type ApprovedFactories<T: Config> =
+		StorageMap<_, Identity, H160, FactoryInfo<T::AccountId, BlockNumberFor<T>>, OptionQuery>;
+
+	/// Permissions of approved factories, keyed by contract address
+	#[pallet::storage]
+	pub type FactoryPermissionsOf<T: Config> =
+		StorageMap<_, Identity, H160, FactoryPermissions, ValueQuery>;
+
+	/// Metadata blobs of approved factories, keyed by contract address
+	///
+	/// Blobs over the inline limit of [`Bounded`] are kept in the preimage store, requested
+	/// for as long as the factory is registered.
+	#[pallet::storage]
+	pub type FactoryMetadata<T: Config> = StorageMap<_, Identity, H160, MetadataOf<T>, OptionQuery>;
+
+	/// Block at which the approval of a factory expires, keyed by contract address
+	///
+	/// Factories without an entry never expire.
+	#[pallet::storage]
+	pub type FactoryExpiry<T: Config> =
+		StorageMap<_, Identity, H160, BlockNumberFor<T>, OptionQuery>;
+
+	/// Factory expiry notices and expiries to process, keyed by block
+	///
+	/// Entries carry the expiry block they were scheduled for, so entries left behind by a
+	/// renewed or cleared expiry are skipped.
+	#[pallet::storage]
+	pub type FactoryExpirySchedule<T: Config> = StorageMap<
+		_,
+		Twox64Concat,
+		BlockNumberFor<T>,
+		BoundedVec<(H160, BlockNumberFor<T>), T::MaxExpiriesPerBlock>,
+		ValueQuery,
+	>;
+
+	/// Tenants of the factory registry, keyed by tenant id
+	#[pallet::storage]
+	pub type Tenants<T: Config> =
+		StorageMap<_, Twox64Concat, u32, TenantInfo<T::AccountId>, OptionQuery>;
+
+	/// Identifier of the next tenant
+	#[pallet::storage]
+	pub type NextTenantId<T: Config> = StorageValue<_, u32, ValueQuery>;
+
+	/// Tenant whose namespace a factory is registered in, keyed by contract address
+	///
+	/// Factories registered by `AdminOrigin` belong to no tenant.
+	#[pallet::storage]
+	pub type FactoryTenant<T: Config> = StorageMap<_, Identity, H160, u32, OptionQuery>;
+
+	/// Tenant whose admin authorized a deployer
+	///
+	/// Dropped with the authorization, and moved along with it by a key rotation.
+	#[pallet::storage]
+	pub type DeployerTenant<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, u32, OptionQuery>;
+
+	/// EVM opcode policy in effect
+	#[pallet::storage]
+	pub type EvmOpcodePolicy<T: Config> = StorageValue<_, OpcodePolicy, ValueQuery>;
+
+	/// Deployment policy in effect
+	#[pallet::storage]
+	pub type EvmDeploymentPolicy<T: Config> =
+		StorageValue<_, DeploymentPolicy<BalanceOf<T>>, ValueQuery>;
+
+	/// Policy of the authorization approval workflow
+	///
+	/// `None` disables the workflow, leaving authorizations to Root only.
+	#[pallet::storage]
+	pub type AuthorizationApprovalPolicy<T: Config> =
+		StorageValue<_, ApprovalPolicy<T>, OptionQuery>;
+
+	/// Pending authorization proposals, keyed by identifier
+	#[pallet::storage]
+	pub type AuthorizationProposals<T: Config> =
+		StorageMap<_, Twox64Concat, u32, AuthorizationProposal<T>, OptionQuery>;
+
+	/// Identifier of the next authorization proposal
+	#[pallet::storage]
+	pub type NextProposalId<T: Config> = StorageValue<_, u32, ValueQuery>;
+
+	/// Number of pending authorization proposals, bounded by `MaxProposals`
+	#[pallet::storage]
+	pub type PendingProposals<T: Config> = StorageValue<_, u32, ValueQuery>;
+
+	/// Risk tiers assessed by governance for accounts to authorize through the approval
+	/// workflow
+	///
+	/// Accounts without an assessment are of the highest tier, requiring the policy's
+	/// threshold of approvals.
+	#[pallet::storage]
+	pub type DeployerRiskTiers<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, u8, OptionQuery>;
+
+	/// Number of blocks between the scheduling of a governance call and its enactment
+	///
+	/// Zero disables the delay: authorizations, revocations and policy changes then apply
+	/// at once.
+	#[pallet::storage]
+	pub type EnactmentDelay<T: Config> = StorageValue<_, BlockNumberFor<T>, ValueQuery>;
+
+	/// Scheduled governance calls, keyed by identifier
+	#[pallet::storage]
+	pub type GovernanceCalls<T: Config> =
+		StorageMap<_, Twox64Concat, u32, GovernanceCall<T>, OptionQuery>;
+
+	/// Identifier of the next scheduled governance call
+	#[pallet::storage]
+	pub type NextGovernanceCallId<T: Config> = StorageValue<_, u32, ValueQuery>;
+
+	/// Number of scheduled governance calls, bounded by `MaxGovernanceCalls`
+	#[pallet::storage]
+	pub type PendingGovernanceCalls<T: Config> = StorageValue<_, u32, ValueQuery>;
+
+	/// Set while `enact_governance_call` dispatches a scheduled call
+	#[pallet::storage]
+	pub type Enacting<T: Config> = StorageValue<_, (), OptionQuery>;
+
+	/// Tokens objected to the scheduled authorizations, keyed by governance call identifier
+	///
+	/// Present for every scheduled `authorize_deployer` call until it is enacted, cancelled
+	/// or vetoed.
+	#[pallet::storage]
+	pub type Objections<T: Config> = StorageMap<_, Twox64Concat, u32, BalanceOf<T>, OptionQuery>;
+
+	/// Referenda on vetoed authorizations, keyed by governance call identifier
+	#[pallet::storage]
+	pub type Referenda<T: Config> = StorageMap<_, Twox64Concat, u32, Referendum<T>, OptionQuery>;
+
+	/// Objections and referendum votes, keyed by governance call identifier and token holder
+	#[pallet::storage]
+	pub type Votes<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		u32,
+		Blake2_128Concat,
+		T::AccountId,
+		Vote<BalanceOf<T>>,
+		OptionQuery,
+	>;
+
+	/// Queued cleanup tasks, keyed by their position in the queue
+	#[pallet::storage]
+	pub type CleanupQueue<T: Config> =
+		StorageMap<_, Twox64Concat, u32, CleanupTask<T::AccountId>, OptionQuery>;
+
+	/// Position of the next cleanup task to process
+	#[pallet::storage]
+	pub type CleanupQueueHead<T: Config> = StorageValue<_, u32, ValueQuery>;
+
+	/// Position at which the next cleanup task is queued
+	#[pallet::storage]
+	pub type CleanupQueueTail<T: Config> = StorageValue<_, u32, ValueQuery>;
+
+	/// Announced and finalized retirements of contracts, keyed by contract address
+	#[pallet::storage]
+	pub type Retirements<T: Config> =
+		StorageMap<_, Identity, H160, Retirement<BlockNumberFor<T>>, OptionQuery>;
+
+	/// Retirement warnings to emit, keyed by block
+	///
+	/// Entries carry the end of the grace period they were scheduled for, like the factory
+	/// expiry schedule.
+	#[pallet::storage]
+	pub type RetirementWarnings<T: Config> = StorageMap<
+		_,
+		Twox64Concat,
+		BlockNumberFor<T>,
+		BoundedVec<(H160, BlockNumberFor<T>), T::MaxExpiriesPerBlock>,
+		ValueQuery,
+	>;
+
+	/// Genesis deployers whose authorization and role lapse, with the block they lapse at
+	///
+	/// Filled by the genesis config only and drained by `on_initialize` as the blocks come.
+	#[pallet::storage]
+	pub type DeployerExpiries<T: Config> = StorageValue<
+		_,
+		BoundedVec<(T::AccountId, BlockNumberFor<T>), T::MaxGenesisDeployers>,
+		ValueQuery,
+	>;
+
+	#[pallet::genesis_config]
+	#[derive(DefaultNoBound)]
+	pub struct GenesisConfig<T: Config> {
+		/// Initial list of authorized deployers
+		///
+		/// Typically includes the sudo account and any initial governance accounts.
+		pub authorized_deployers: Vec<DeployerAddress<T::AccountId>>,
+		/// Initial deployers authorized with a role, and the block their authorization and
+		/// role lapse at, if they do
+		///
+		/// Together with `authorized_deployers` at most `MaxGenesisDeployers` accounts, each
+		/// listed once, whether by EVM address or by account.
+		pub deployers: Vec<(
+			DeployerAddress<T::AccountId>,
+			GenesisDeployerRole,
+			Option<BlockNumberFor<T>>,
+		)>,
+	}
+
+	#[pallet::genesis_build]
+	impl<T: Config> BuildGenesisConfig for GenesisConfig<T> {
+		fn build(&self) {
+			AuthorizationIndexSince::<T>::put(BlockNumberFor::<T>::zero());
+
+			let deployers = self
+				.authorized_deployers
+				.iter()
+				.map(|deployer| (deployer, GenesisDeployerRole::Deployer, None))
+				.chain(
+					self.deployers.iter().map(|(deployer, role, expiry)| (deployer, *role, *expiry)),
+				);
+
+			assert!(
+				self.authorized_deployers.len().saturating_add(self.deployers.len()) <=
+					T::MaxGenesisDeployers::get() as usize,
+				"more than `MaxGenesisDeployers` genesis deployers",
+			);
+
+			let mut seen = BTreeSet::new();
+			let mut expiries = Vec::new();
+			for (address, role, expiry) in deployers {
+				let deployer =
+					address.clone().into_account_id(T::ContractDeployer::into_account_id);
+				assert!(seen.insert(deployer.clone()), "duplicate genesis deployer {:?}", deployer);
+
+				AuthorizedDeployers::<T>::insert(
+					&deployer,
+					DeployerInfo {
+						authorized_at: BlockNumberFor::<T>::zero(),
+						flags: DeployerFlags::GENESIS,
+					},
+				);
+				match role {
+					GenesisDeployerRole::Deployer => {},
+					GenesisDeployerRole::WindowOverride =>
+						WindowOverrides::<T>::insert(&deployer, ()),
+					GenesisDeployerRole::Admin => DeployerAdmins::<T>::insert(&deployer, ()),
+				}
+				if let Some(expiry) = expiry {
+					assert!(
+						!expiry.is_zero(),
+						"genesis deployer {:?} expires at genesis",
+						deployer
+					);
+					expiries.push((deployer, expiry));
+				}
+			}
+
+			// Bounded by `MaxGenesisDeployers` like the deployers, as asserted above
+			DeployerExpiries::<T>::put(BoundedVec::truncate_from(expiries));
+		}
+	}
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// A deployer was authorized to deploy EVM contracts
+		DeployerAuthorized {
+			/// The account that was authorized
+			deployer: T::AccountId
+		},
+		/// A deployer's authorization was revoked
+		DeployerRevoked {
+			/// The account that was revoked
+			deployer: T::AccountId
+		},
+		/// An account exceeded the rejected deployment attempt threshold
+		RepeatedRejectedAttempts {
+			/// The account that was flagged
+			deployer: T::AccountId,
+			/// Number of rejected attempts in the current window
+			attempts: u32,
+		},
+		/// A flagged account was cleared
+		DeployerUnflagged {
+			/// The account that was cleared
+			deployer: T::AccountId
+		},
+		/// The per-block contract creation cap was changed
+		MaxCreatesPerBlockSet {
+			/// The new cap, `None` if creations are no longer rate limited
+			limit: Option<u32>
+		},
+		/// The deployment windows were replaced
+		DeploymentWindowsSet {
+			/// Number of configured windows, zero if deployments are unrestricted in time
+			count: u32
+		},
+		/// A deployer's deployment window override was changed
+		WindowOverrideSet {
+			/// The affected account
+			deployer: T::AccountId,
+			/// Whether the account may deploy outside of the deployment windows
+			enabled: bool,
+		},
+		/// The EVM opcode policy was changed
+		OpcodePolicySet {
+			/// The new policy
+			policy: OpcodePolicy,
+		},
+		/// A contract was deployed through `deploy_contract`
+		ContractDeployed {
+			/// The account that deployed the contract
+			deployer: T::AccountId,
+			/// Address of the new contract
+			contract: H160,
+			/// Metadata registered with the contract
+			metadata: ContractMetadata<T::MaxMetadataLen>,
+		},
+		/// A factory contract was approved
+		FactoryRegistered {
+			/// Address of the factory
+			factory: H160,
+			/// Account administering the factory
+			admin: T::AccountId,
+		},
+		/// The status of an approved factory was changed
+		FactoryStatusSet {
+			/// Address of the factory
+			factory: H160,
+			/// The new status
+			status: FactoryStatus,
+		},
+		/// The permissions of an approved factory were changed
+		FactoryPermissionsSet {
+			/// Address of the factory
+			factory: H160,
+			/// The new permissions
+			permissions: FactoryPermissions,
+		},
+		/// A factory registered the metadata of one of its child contracts
+		ChildMetadataRegistered {
+			/// Address of the factory
+			factory: H160,
+			/// Address of the child contract
+			contract: H160,
+			/// Metadata registered with the contract
+			metadata: ContractMetadata<T::MaxMetadataLen>,
+		},
+		/// The approval expiry of a factory was changed
+		FactoryExpirySet {
+			/// Address of the factory
+			factory: H160,
+			/// Block at which the approval expires, `None` if it no longer expires
+			expires_at: Option<BlockNumberFor<T>>,
+		},
+		/// The approval of a factory expires in `FactoryExpiryNotice` blocks or less
+		FactoryExpiringSoon {
+			/// Address of the factory
+			factory: H160,
+			/// Block at which the approval expires
+			expires_at: BlockNumberFor<T>,
+		},
+		/// The approval of a factory expired and the factory was suspended
+		FactoryExpired {
+			/// Address of the factory
+			factory: H160,
+		},
+		/// The authorization approval policy was changed
+		ApprovalPolicySet {
+			/// Number of named approvers, zero if the workflow is disabled
+			approvers: u32,
+		},
+		/// An authorization was proposed
+		AuthorizationProposed {
+			/// Identifier of the proposal
+			id: u32,
+			/// The account to authorize
+			deployer: T::AccountId,
+			/// Risk tier of the account when proposed
+			risk_tier: u8,
+			/// The approver who made the proposal
+			proposer: T::AccountId,
+		},
+		/// An authorization proposal was approved
+		AuthorizationApproved {
+			/// Identifier of the proposal
+			id: u32,
+			/// The approving account
+			approver: T::AccountId,
+			/// Number of approvals collected so far
+			approvals: u32,
+		},
+		/// An authorization proposal was executed
+		AuthorizationExecuted {
+			/// Identifier of the proposal
+			id: u32,
+			/// The authorized account
+			deployer: T::AccountId,
+		},
+		/// An expired authorization proposal was removed
+		ProposalExpired {
+			/// Identifier of the proposal
+			id: u32,
+		},
+		/// A deployment was announced and its bond held
+		DeploymentAnnounced {
+			/// The announcing deployer
+			deployer: T::AccountId,
+			/// Keccak-256 hash of the init code to be deployed
+			code_hash: H256,
+			/// Human readable name of the upcoming contract
+			label: BoundedVec<u8, T::MaxMetadataLen>,
+		},
+		/// An announced deployment took place and its bond was released
+		DeploymentIntentFulfilled {
+			/// The deployer
+			deployer: T::AccountId,
+			/// Keccak-256 hash of the deployed init code
+			code_hash: H256,
+			/// Address of the new contract
+			contract: H160,
+		},
+		/// The deployment policy was changed
+		DeploymentPolicySet {
+			/// The new policy
+			policy: DeploymentPolicy<BalanceOf<T>>,
+		},
+		/// An account that is not an authorized deployer paid the deployment fee
+		DeploymentFeePaid {
+			/// The deployer
+			deployer: T::AccountId,
+			/// The fee paid into `DeploymentProceeds`
+			fee: BalanceOf<T>,
+		},
+		/// A deployer announced the rotation of its key
+		KeyRotationAnnounced {
+			/// The current key
+			old: T::AccountId,
+			/// The key taking over
+			new: T::AccountId,
+			/// Block from which the rotation can be executed
+			ready_at: BlockNumberFor<T>,
+		},
+		/// A deployer's authorization was moved to a new key
+		DeployerKeyRotated {
+			/// The previous key
+			old: T::AccountId,
+			/// The key taking over
+			new: T::AccountId,
+		},
+		/// A contract wallet was authorized to deploy EVM contracts
+		ContractWalletAuthorized {
+			/// Address of the wallet
+			wallet: H160,
+			/// The account submitting transactions on behalf of the wallet
+			operator: T::AccountId,
+		},
+		/// The metadata of an approved factory was set or cleared
+		FactoryMetadataSet {
+			/// Address of the factory
+			factory: H160,
+			/// Hash of the new metadata, `None` if cleared
+			metadata: Option<T::Hash>,
+		},
+		/// The metadata of an authorized deployer was set or cleared
+		DeployerMetadataSet {
+			/// The deployer
+			deployer: T::AccountId,
+			/// Hash of the new metadata, `None` if cleared
+			metadata: Option<T::Hash>,
+		},
+		/// An approved factory was unregistered
+		FactoryUnregistered {
+			/// Address of the factory
+			factory: H160,
+		},
+		/// An account was granted or denied the deployer admin origin
+		DeployerAdminSet {
+			/// The account
+			account: T::AccountId,
+			/// Whether the account is now a deployer admin
+			is_admin: bool,
+		},
+		/// A deployer admin dispatched a call with the deployer admin origin
+		DeployerAdminDispatched {
+			/// The deployer admin
+			admin: T::AccountId,
+			/// Result of the call
+			result: DispatchResult,
+		},
+		/// The bond of an announced deployment was slashed
+		DeploymentIntentSlashed {
+			/// The deployer
+			deployer: T::AccountId,
+			/// Keccak-256 hash of the announced init code
+			code_hash: H256,
+			/// Amount paid into `DeploymentProceeds`
+			amount: BalanceOf<T>,
+		},
+		/// Queued cleanup tasks were processed in `on_idle`
+		CleanupProcessed {
+			/// Number of tasks processed in this block
+			processed: u32,
+			/// Number of tasks left in the queue
+			remaining: u32,
+		},
+		/// The per-period deployment quota was changed
+		DeploymentQuotaSet {
+			/// The new quota, `None` if deployers are no longer subject to a quota
+			quota: Option<u32>,
+		},
+		/// A tenant was created in the factory registry
+		TenantCreated {
+			/// Identifier of the tenant
+			tenant: u32,
+			/// Account administering the tenant
+			admin: T::AccountId,
+			/// Bounds of the tenant
+			bounds: TenantBounds,
+		},
+		/// The bounds of a tenant were changed
+		TenantBoundsSet {
+			/// Identifier of the tenant
+			tenant: u32,
+			/// The new bounds
+			bounds: TenantBounds,
+		},
+		/// The admin of a tenant was replaced
+		TenantAdminSet {
+			/// Identifier of the tenant
+			tenant: u32,
+			/// The new admin
+			admin: T::AccountId,
+		},
+		/// A factory was registered in the namespace of a tenant
+		TenantFactoryRegistered {
+			/// Identifier of the tenant
+			tenant: u32,
+			/// Address of the factory
+			factory: H160,
+		},
+		/// The admin of a tenant authorized a deployer
+		TenantDeployerAuthorized {
+			/// Identifier of the tenant
+			tenant: u32,
+			/// The deployer
+			deployer: T::AccountId,
+		},
+		/// A governance call was scheduled for enactment
+		GovernanceCallScheduled {
+			/// Identifier of the scheduled call
+			id: u32,
+			/// Hash of the call
+			call_hash: T::Hash,
+			/// Block from which the call can be enacted
+			enact_at: BlockNumberFor<T>,
+		},
+		/// A scheduled governance call was enacted
+		GovernanceCallEnacted {
+			/// Identifier of the scheduled call
+			id: u32,
+			/// Result of the call
+			result: DispatchResult,
+		},
+		/// A scheduled governance call was cancelled
+		GovernanceCallCancelled {
+			/// Identifier of the scheduled call
+			id: u32,
+		},
+		/// The enactment delay of governance calls was changed
+		EnactmentDelaySet {
+			/// The new delay, zero if governance calls apply at once
+			delay: BlockNumberFor<T>,
+		},
+		/// A token holder objected to a scheduled authorization
+		AuthorizationObjected {
+			/// Identifier of the scheduled call
+			id: u32,
+			/// The token holder
+			who: T::AccountId,
+			/// Tokens locked for the objection
+			amount: BalanceOf<T>,
+		},
+		/// Objections vetoed a scheduled authorization, which was put to a referendum
+		AuthorizationVetoed {
+			/// Identifier of the scheduled call, and of the referendum
+			id: u32,
+			/// Tokens objected to the authorization
+			objected: BalanceOf<T>,
+			/// Block from which the referendum can be concluded
+			ends_at: BlockNumberFor<T>,
+		},
+		/// A token holder voted in the referendum on a vetoed authorization
+		Voted {
+			/// Identifier of the referendum
+			id: u32,
+			/// The token holder
+			who: T::AccountId,
+			/// Whether the vote supports the authorization
+			aye: bool,
+			/// Tokens locked for the vote
+			amount: BalanceOf<T>,
+		},
+		/// The referendum on a vetoed authorization was concluded
+		ReferendumConcluded {
+			/// Identifier of the referendum
+			id: u32,
+			/// Whether the authorization was approved, and enacted
+			approved: bool,
+		},
+		/// The tokens of an objection or referendum vote were released
+		VoteUnlocked {
+			/// Identifier of the scheduled call or referendum
+			id: u32,
+			/// The token holder
+			who: T::AccountId,
+			/// Tokens released
+			amount: BalanceOf<T>,
+		},
+		/// The administration of a contract was transferred
+		ContractAdminTransferred {
+			/// Address of the contract
+			contract: H160,
+			/// Recorded deployer of the contract before the transfer
+			previous: H160,
+			/// Recorded deployer of the contract from now on
+			new_admin: H160,
+		},
+		/// The retirement of a contract was announced
+		ContractRetirementAnnounced {
+			/// Address of the contract
+			contract: H160,
+			/// End of the grace period, from which the retirement can be finalized
+			retires_at: BlockNumberFor<T>,
+		},
+		/// A contract is retiring at the end of its grace period
+		ContractRetiringSoon {
+			/// Address of the contract
+			contract: H160,
+			/// End of the grace period
+			retires_at: BlockNumberFor<T>,
+		},
+		/// The grace period of a retiring contract is over, `finalize_retirement` can be called
+		ContractRetirementDue {
+			/// Address of the contract
+			contract: H160,
+		},
+		/// A contract was retired and frozen in the registries
+		ContractRetired {
+			/// Address of the contract
+			contract: H160,
+		},
+		/// A deployment by an account the deployment policy refuses was counted as a
+		/// rejected attempt
+		DeploymentRefused {
+			/// The refused account
+			deployer: T::AccountId,
+		},
+		/// The risk tier of an account to authorize was assessed or cleared
+		DeployerRiskTierSet {
+			/// The assessed account
+			deployer: T::AccountId,
+			/// The assessed tier, `None` if cleared
+			risk_tier: Option<u8>,
+		},
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// Account is already authorized
+		AlreadyAuthorized,
+		/// Account is not authorized
+		NotAuthorized,
+		/// Account has not reached the rejected attempt threshold
+		BelowAlertThreshold,
+		/// Account is already flagged
+		AlreadyFlagged,
+		/// Account is not flagged
+		NotFlagged,
+		/// A deployment window has a zero period or duration, or overruns its period
+		InvalidDeploymentWindow,
+		/// The cleanup queue is full
+		CleanupQueueFull,
+		/// The EVM source address is not controlled by the signer
+		InvalidSource,
+		/// Deployments are outside of the configured deployment windows
+		OutsideDeploymentWindow,
+		/// The per-block contract creation cap has been reached
+		CreateLimitReached,
+		/// The contract creation did not succeed
+		DeploymentFailed,
+		/// The factory is already approved
+		FactoryAlreadyRegistered,
+		/// The factory is not approved
+		UnknownFactory,
+		/// The factory is suspended
+		FactorySuspended,
+		/// The factory lacks the permission for the requested function
+		MissingFactoryPermission,
+		/// The contract was not created by the factory
+		NotFactoryChild,
+		/// Metadata is already registered for the contract
+		MetadataAlreadyRegistered,
+		/// The expiry block is not in the future
+		ExpiryInPast,
+		/// `MaxExpiriesPerBlock` factory expiry events are already scheduled in the block
+		ExpiryScheduleFull,
+		/// The approval threshold is zero or above the number of approvers, or an
+		/// approver is listed twice
+		InvalidApprovalPolicy,
+		/// No authorization approval policy is configured
+		NoApprovalPolicy,
+		/// The caller is not one of the named approvers
+		NotApprover,
+		/// `MaxProposals` authorization proposals are already pending
+		TooManyProposals,
+		/// The authorization proposal does not exist
+		UnknownProposal,
+		/// The authorization proposal has expired
+		ProposalExpired,
+		/// The authorization proposal has not expired yet
+		ProposalNotExpired,
+		/// The caller already approved the proposal
+		AlreadyApproved,
+		/// The proposal lacks the approvals required by its risk tier
+		InsufficientApprovals,
+		/// The deployer already announced a deployment of the same init code
+		IntentAlreadyAnnounced,
+		/// The deployer cannot pay the fee of the permissionless deployment policy
+		InsufficientDeploymentFee,
+		/// The announced key rotation cannot be executed yet
+		KeyRotationNotReady,
+		/// The deployer has announced deployments that are not fulfilled yet
+		IntentsOutstanding,
+		/// The metadata blob exceeds `MaxMetadataBlobLen`
+		MetadataTooLong,
+		/// The caller is not a deployer admin
+		NotDeployerAdmin,
+		/// The deployer has not announced a deployment of the init code
+		UnknownIntent,
+		/// The deployer used up its deployment quota of the current period
+		QuotaExhausted,
+		/// No tenant has the given id
+		UnknownTenant,
+		/// The caller is not the admin of the tenant, or the factory or deployer does not
+		/// belong to the tenant
+		NotTenantAdmin,
+		/// The tenant reached the bound on its factories or deployers
+		TenantBoundReached,
+		/// The call is subject to the enactment delay and must be scheduled
+		NotEnacted,
+		/// `MaxGovernanceCalls` governance calls are already scheduled
+		TooManyGovernanceCalls,
+		/// No governance call is scheduled with the given id
+		UnknownGovernanceCall,
+		/// The enactment delay of the governance call or authorization proposal is not over
+		EnactmentNotDue,
+		/// The weight of the governance call exceeds the given weight limit
+		WeightLimitTooLow,
+		/// The governance call is not a scheduled authorization open to objections
+		NotObjectable,
+		/// The scheduled authorization is due for enactment and no longer open to objections
+		ObjectionWindowClosed,
+		/// No referendum is held with the id
+		UnknownReferendum,
+		/// The voting period of the referendum is over
+		ReferendumEnded,
+		/// The voting period of the referendum is not over
+		ReferendumNotEnded,
+		/// The account already staked tokens on the other side of the vote
+		ConflictingVote,
+		/// The account has no objection or vote on the governance call
+		NoVote,
+		/// The objection or vote stays locked until the authorization is enacted, cancelled or
+		/// decided by its referendum
+		VoteLocked,
+		/// No provenance is recorded for the contract
+		UnknownContract,
+		/// The retirement of the contract is already announced
+		RetirementAlreadyAnnounced,
+		/// The retirement of the contract was not announced
+		RetirementNotAnnounced,
+		/// The grace period of the retiring contract is not over
+		RetirementNotDue,
+		/// The contract is retired and frozen in the registries
+		ContractRetired,
+		/// The execution of the authorization proposal is scheduled
+		ProposalScheduled,
+		/// The account is flagged for repeated unauthorized deployment attempts
+		DeployerFlagged,
+		/// Contract creations were nested deeper than `MaxCreateDepth`
+		CreationTooDeep,
+	}
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_initialize(n: BlockNumberFor<T>) -> Weight {
+			let expiries = Self::process_factory_expiries(n);
+			let warnings = Self::process_retirement_warnings(n);
+			let lapsed = Self::process_deployer_expiries(n);
+
+			// Account for summarizing the decisions of the block into the digest, indexing
+			// its contract creations and clearing the authorization cache and the pending
+			// intent in `on_finalize`
+			T::WeightInfo::process_factory_expiries(expiries)
+				.saturating_add(T::WeightInfo::process_retirement_warnings(warnings))
+				.saturating_add(
+					T::WeightInfo::revoke_deployer(1)
+						.saturating_add(T::DbWeight::get().writes(1))
+						.saturating_mul(lapsed.into()),
+				)
+				.saturating_add(T::DbWeight::get().reads_writes(5, 7))
+				.saturating_add(
+					T::DbWeight::get().writes(T::MaxCachedAuthorizations::get().into()),
+				)
+		}
+
+		fn on_finalize(_n: BlockNumberFor<T>) {
+			let allowed = CreatesThisBlock::<T>::take();
+			let (denied, first_denied) = DeniedThisBlock::<T>::take()
+				.map_or((0, None), |(count, first)| (count, Some(first)));
+			if allowed > 0 || denied > 0 {
+				let decisions = DeploymentDecisions { allowed, denied, first_denied };
+				frame_system::Pallet::<T>::deposit_log(DigestItem::Consensus(
+					DEPLOYMENT_DECISIONS_ENGINE_ID,
+					decisions.encode(),
+				));
+			}
+
+			// Written even when empty, so that the index tells blocks without creations
+			// apart from blocks imported before indexing was enabled
+			let key = CreationTrace::offchain_key(
+				&frame_system::Pallet::<T>::parent_hash(),
+				&frame_system::Pallet::<T>::digest(),
+			);
+			sp_io::offchain_index::set(&key, &CreationTraces::<T>::take().encode());
+
+			let _ = AuthorizationCache::<T>::clear(T::MaxCachedAuthorizations::get(), None);
+			CachedAuthorizations::<T>::kill();
+			PendingIntent::<T>::kill();
+		}
+
+		/// Process queued cleanup tasks within the remaining block weight
+		fn on_idle(_n: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+			let mut meter = WeightMeter::with_limit(remaining_weight);
+			if meter.try_consume(T::DbWeight::get().reads_writes(2, 1)).is_err() {
+				return Weight::zero();
+			}
+
+			let mut head = CleanupQueueHead::<T>::get();
+			let tail = CleanupQueueTail::<T>::get();
+			let mut processed = 0u32;
+
+			while head != tail && meter.try_consume(T::WeightInfo::process_cleanup_task()).is_ok() {
+				if let Some(task) = CleanupQueue::<T>::take(head) {
+					Self::process_cleanup_task(task);
+				}
+				head = head.wrapping_add(1);
+				processed.saturating_inc();
+			}
+
+			if processed > 0 {
+				CleanupQueueHead::<T>::put(head);
+				Self::deposit_event(Event::CleanupProcessed {
+					processed,
+					remaining: tail.wrapping_sub(head),
+				});
+			}
+
+			meter.consumed()
+		}
+
+		/// Scan rejected attempt counters and submit an alert for every account
+		/// that crossed the threshold and has not been flagged yet
+		///
+		/// Scans `REJECTED_ATTEMPTS_PAGE` counters per block, resuming after the last
+		/// account scanned, which is kept in the node's offchain local storage. Once the
+		/// end of the map is reached, the next block starts over.
+		fn offchain_worker(block_number: BlockNumberFor<T>) {
+			let cursor = StorageValueRef::persistent(REJECTED_ATTEMPTS_CURSOR);
+			let deployers: Vec<T::AccountId> = match cursor.get::<Vec<u8>>() {
+				Ok(Some(last)) => RejectedAttempts::<T>::iter_keys_from(last),
+				_ => RejectedAttempts::<T>::iter_keys(),
+			}
+			.take(REJECTED_ATTEMPTS_PAGE)
+			.collect();
+			match deployers.last() {
+				Some(last) if deployers.len() == REJECTED_ATTEMPTS_PAGE =>
+					cursor.set(&RejectedAttempts::<T>::hashed_key_for(last)),
+				_ => cursor.clear(),
+			}
+
+			for deployer in deployers {
+				if Self::ensure_reportable(&deployer).is_err() {
+					continue;
+				}
+
+				let call = Call::report_rejected_attempts { deployer };
+				if SubmitTransaction::<T, Call<T>>::submit_unsigned_transaction(call.into())
+					.is_err()
+				{
+					log::warn!(
+						target: LOG_TARGET,
+						"Failed to submit rejected attempt alert at block {:?}",
+						block_number,
+					);
+				}
+			}
+		}
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Authorize an account to deploy EVM contracts
+		///
+		/// This allows the specified account to deploy contracts directly to the EVM,
+		/// bypassing the factory contract requirement.
+		///
+		/// # Parameters
+		/// - `origin`: Must be `AdminOrigin`
+		/// - `deployer`: The account to authorize
+		///
+		/// # Errors
+		/// - `AlreadyAuthorized`: The account is already in the authorized list
+		/// - `NotEnacted`: An enactment delay is set and the call was not scheduled
+		///
+		/// # Events
+		/// - `DeployerAuthorized`: Emitted when authorization succeeds
+		#[pallet::call_index(0)]
+		#[pallet::weight(T::WeightInfo::authorize_deployer())]
+		pub fn authorize_deployer(
+			origin: OriginFor<T>,
+			deployer: T::AccountId,
+		) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
+			Self::ensure_enacted()?;
+
+			Self::do_authorize(deployer)?;
+
+			Ok(())
+		}
+
+		/// Revoke an account's authorization to deploy EVM contracts
+		///
+		/// This removes the account from the authorized deployers list, preventing
+		/// them from deploying contracts directly to the EVM. The weight of queueing the
+		/// cleanup of a window override is refunded if the account has none.
+		///
+		/// # Parameters
+		/// - `origin`: Must be `AdminOrigin`
+		/// - `deployer`: The account to revoke
+		///
+		/// # Errors
+		/// - `NotAuthorized`: The account is not in the authorized list
+		/// - `NotEnacted`: An enactment delay is set and the call was not scheduled
+		///
+		/// # Events
+		/// - `DeployerRevoked`: Emitted when revocation succeeds
+		#[pallet::call_index(1)]
+		#[pallet::weight(T::WeightInfo::revoke_deployer(1))]
+		pub fn revoke_deployer(
+			origin: OriginFor<T>,
+			deployer: T::AccountId,
+		) -> DispatchResultWithPostInfo {
+			T::AdminOrigin::ensure_origin(origin)?;
+			Self::ensure_enacted()?;
+
+			ensure!(
+				AuthorizedDeployers::<T>::contains_key(&deployer),
+				Error::<T>::NotAuthorized
+			);
+
+			let has_override = WindowOverrides::<T>::contains_key(&deployer);
+			Self::do_revoke(deployer);
+
+			Ok(Some(T::WeightInfo::revoke_deployer(has_override as u32)).into())
+		}
+
+		/// Flag an account for repeated unauthorized deployment attempts
+		///
+		/// Submitted as an unsigned transaction by the offchain worker once the
+		/// account's rejected attempts reach `RejectedAttemptThreshold`.
+		///
+		/// # Parameters
+		/// - `origin`: Must be None (unsigned)
+		/// - `deployer`: The account to flag
+		///
+		/// # Errors
+		/// - `BelowAlertThreshold`: The account has not reached the threshold
+		/// - `AlreadyFlagged`: The account is already flagged
+		///
+		/// # Events
+		/// - `RepeatedRejectedAttempts`: Emitted when the account is flagged
+		#[pallet::call_index(2)]
+		#[pallet::weight(T::WeightInfo::report_rejected_attempts())]
+		pub fn report_rejected_attempts(
+			origin: OriginFor<T>,
+			deployer: T::AccountId,
+		) -> DispatchResult {
+			ensure_none(origin)?;
+
+			let attempts = Self::ensure_reportable(&deployer)?;
+
+			FlaggedDeployers::<T>::insert(&deployer, frame_system::Pallet::<T>::block_number());
+			Self::deposit_event(Event::RepeatedRejectedAttempts { deployer, attempts });
+
+			Ok(())
+		}
+
+		/// Clear the flag and rejected attempt counter of an account
+		///
+		/// # Parameters
+		/// - `origin`: Must be Root (typically called via sudo or governance)
+		/// - `deployer`: The account to clear
+		///
+		/// # Errors
+		/// - `NotFlagged`: The account is not flagged
+		///
+		/// # Events
+		/// - `DeployerUnflagged`: Emitted when the flag is cleared
+		#[pallet::call_index(3)]
+		#[pallet::weight(T::WeightInfo::unflag_deployer())]
+		pub fn unflag_deployer(
+			origin: OriginFor<T>,
+			deployer: T::AccountId,
+		) -> DispatchResult {
+			ensure_root(origin)?;
+
+			ensure!(
+				FlaggedDeployers::<T>::contains_key(&deployer),
+				Error::<T>::NotFlagged
+			);
+
+			FlaggedDeployers::<T>::remove(&deployer);
+			RejectedAttempts::<T>::remove(&deployer);
+			Self::deposit_event(Event::DeployerUnflagged { deployer });
+
+			Ok(())
+		}
+
+		/// Set the maximum number of contract creations per block
+		///
+		/// Prevents a single deployer from filling blocks with contract creations
+		/// and starving regular user transactions.
+		///
+		/// # Parameters
+		/// - `origin`: Must be Root (typically called via sudo or governance)
+		/// - `limit`: The new cap, or `None` to disable rate limiting
+		///
+		/// # Errors
+		/// - `NotEnacted`: An enactment delay is set and the call was not scheduled
+		///
+		/// # Events
+		/// - `MaxCreatesPerBlockSet`: Emitted when the cap is updated
+		#[pallet::call_index(4)]
+		#[pallet::weight(T::WeightInfo::set_max_creates_per_block())]
+		pub fn set_max_creates_per_block(
+			origin: OriginFor<T>,
+			limit: Option<u32>,
+		) -> DispatchResult {
+			ensure_root(origin)?;
+			Self::ensure_enacted()?;
+
+			MaxCreatesPerBlock::<T>::set(limit);
+			Self::deposit_event(Event::MaxCreatesPerBlockSet { limit });
+
+			Ok(())
+		}
+
+		/// Replace the recurring deployment windows
+		///
+		/// Outside of these windows only deployers with an override can deploy.
+		/// An empty list lifts the restriction.
+		///
+		/// # Parameters
+		/// - `origin`: Must be Root (typically called via sudo or governance)
+		/// - `windows`: The new set of deployment windows
+		///
+		/// # Errors
+		/// - `InvalidDeploymentWindow`: One of the windows is malformed
+		/// - `NotEnacted`: An enactment delay is set and the call was not scheduled
+		///
+		/// # Events
+		/// - `DeploymentWindowsSet`: Emitted when the windows are replaced
+		#[pallet::call_index(5)]
+		#[pallet::weight(T::WeightInfo::set_deployment_windows(windows.len() as u32))]
+		pub fn set_deployment_windows(
+			origin: OriginFor<T>,
+			windows: BoundedVec<DeploymentWindow<BlockNumberFor<T>>, T::MaxDeploymentWindows>,
+		) -> DispatchResult {
+			ensure_root(origin)?;
+			Self::ensure_enacted()?;
+
+			ensure!(
+				windows.iter().all(DeploymentWindow::is_valid),
+				Error::<T>::InvalidDeploymentWindow
+			);
+
+			let count = windows.len() as u32;
+			DeploymentWindows::<T>::put(windows);
+			Self::deposit_event(Event::DeploymentWindowsSet { count });
+
+			Ok(())
+		}
+
+		/// Allow or disallow an account to deploy outside of the deployment windows
+		///
+		/// # Parameters
+		/// - `origin`: Must be Root (typically called via sudo or governance)
+		/// - `deployer`: The account to update
+		/// - `enabled`: Whether the override applies
+		///
+		/// # Errors
+		/// - `NotEnacted`: An enactment delay is set and the call was not scheduled
+		///
+		/// # Events
+		/// - `WindowOverrideSet`: Emitted when the override is updated
+		#[pallet::call_index(6)]
+		#[pallet::weight(T::WeightInfo::set_window_override())]
+		pub fn set_window_override(
+			origin: OriginFor<T>,
+			deployer: T::AccountId,
+			enabled: bool,
+		) -> DispatchResult {
+			ensure_root(origin)?;
+			Self::ensure_enacted()?;
+
+			if enabled {
+				WindowOverrides::<T>::insert(&deployer, ());
+			} else {
+				WindowOverrides::<T>::remove(&deployer);
+			}
+			Self::deposit_event(Event::WindowOverrideSet { deployer, enabled });
+
+			Ok(())
+		}
+
+		/// Queue a cleanup task for processing in `on_idle`
+		///
+		/// # Parameters
+		/// - `origin`: Must be Root (typically called via sudo or governance)
+		/// - `task`: The cleanup work to queue
+		///
+		/// # Errors
+		/// - `CleanupQueueFull`: `MaxQueuedCleanups` tasks are already queued
+		#[pallet::call_index(7)]
+		#[pallet::weight(T::WeightInfo::schedule_cleanup())]
+		pub fn schedule_cleanup(
+			origin: OriginFor<T>,
+			task: CleanupTask<T::AccountId>,
+		) -> DispatchResult {
+			ensure_root(origin)?;
+
+			Self::queue_cleanup(task)?;
+
+			Ok(())
+		}
+
+		/// Replace the EVM opcode policy
+		///
+		/// Takes effect for every EVM execution from the next transaction on.
+		///
+		/// # Parameters
+		/// - `origin`: Must be Root (typically called via sudo or governance)
+		/// - `policy`: The new opcode policy
+		///
+		/// # Errors
+		/// - `NotEnacted`: An enactment delay is set and the call was not scheduled
+		///
+		/// # Events
+		/// - `OpcodePolicySet`: Emitted when the policy is replaced
+		#[pallet::call_index(8)]
+		#[pallet::weight(T::WeightInfo::set_opcode_policy())]
+		pub fn set_opcode_policy(origin: OriginFor<T>, policy: OpcodePolicy) -> DispatchResult {
+			ensure_root(origin)?;
+			Self::ensure_enacted()?;
+
+			EvmOpcodePolicy::<T>::put(policy);
+			Self::deposit_event(Event::OpcodePolicySet { policy });
+
+			Ok(())
+		}
+
+		/// Deploy a contract and register its metadata in one step
+		///
+		/// Runs the same authorization, deployment window and per-block cap checks as
+		/// Ethereum transactions. The metadata is only recorded if the contract
+		/// creation succeeds.
+		///
+		/// A deployment by an account the deployment policy refuses does not fail: the call
+		/// succeeds without deploying, so that the attempt counts towards the account's
+		/// rejected attempts instead of being rolled back with the call. Once the account is
+		/// flagged, its deployments fail.
+		///
+		/// # Parameters
+		/// - `origin`: Must be a signed authorized deployer, or the operator of an
+		///   authorized contract wallet
+		/// - `source`: EVM address of the signer or of the operated wallet, deploying the
+		///   contract and paying for the execution
+		/// - `init_code`: Contract creation code
+		/// - `value`: Balance transferred to the new contract
+		/// - `gas_limit`: Gas available to the contract creation
+		/// - `metadata`: Label and version registered with the contract
+		///
+		/// Once the contract is created, the transaction fee is waived: `source` has paid for
+		/// the execution in gas, like for an Ethereum transaction. The weight of the gas left
+		/// unused by the creation is refunded, as is the quota bookkeeping when no deployment
+		/// quota is set.
+		///
+		/// # Errors
+		/// - `InvalidSource`: `source` is not controlled by the signer
+		/// - `InsufficientDeploymentFee`: `source` cannot pay the deployment fee
+		/// - `OutsideDeploymentWindow`: Deployments are currently closed for `source`
+		/// - `DeployerFlagged`: `source` is flagged for repeated unauthorized attempts
+		/// - `CreateLimitReached`: The per-block creation cap has been reached
+		/// - `DeploymentFailed`: The contract creation reverted or failed
+		///
+		/// # Events
+		/// - `ContractDeployed`: Emitted when the contract is created
+		/// - `DeploymentRefused`: Emitted when the deployment policy refuses `source`
+		#[pallet::call_index(9)]
+		#[pallet::weight(
+			T::WeightInfo::deploy_contract()
+				.saturating_add(T::WeightInfo::use_deployment_quota())
+				.saturating_add(T::ContractDeployer::gas_weight(*gas_limit))
+		)]
+		pub fn deploy_contract(
+			origin: OriginFor<T>,
+			source: H160,
+			init_code: Vec<u8>,
+			value: U256,
+			gas_limit: u64,
+			metadata: ContractMetadata<T::MaxMetadataLen>,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			let deployer = T::ContractDeployer::into_account_id(source);
+
+			ensure!(Self::controls(&who, &deployer), Error::<T>::InvalidSource);
+			let refusal = match Self::ensure_can_deploy(&deployer) {
+				Ok(()) => None,
+				Err(crate::DeploymentValidationError::UnauthorizedDeployer) => {
+					Self::note_rejected_attempt(&deployer);
+					Self::deposit_event(Event::DeploymentRefused { deployer: deployer.clone() });
+					return Ok(Some(T::WeightInfo::deploy_contract()).into());
+				},
+				Err(crate::DeploymentValidationError::OutsideDeploymentWindow) =>
+					Some(Error::<T>::OutsideDeploymentWindow),
+				Err(crate::DeploymentValidationError::InsufficientDeploymentFee) =>
+					Some(Error::<T>::InsufficientDeploymentFee),
+				Err(crate::DeploymentValidationError::QuotaExhausted) =>
+					Some(Error::<T>::QuotaExhausted),
+				Err(crate::DeploymentValidationError::FlaggedDeployer) =>
+					Some(Error::<T>::DeployerFlagged),
+			};
+			if let Some(err) = refusal {
+				return Err(err.into());
+			}
+			ensure!(Self::try_register_create(), Error::<T>::CreateLimitReached);
+			let quota_weight = if DeploymentQuota::<T>::exists() {
+				T::WeightInfo::use_deployment_quota()
+			} else {
+				T::DbWeight::get().reads(1)
+			};
+			ensure!(Self::try_use_quota(&deployer), Error::<T>::QuotaExhausted);
+			Self::charge_deployment_fee(&deployer)?;
+
+			Self::note_pending_creation(&deployer, sp_core::hashing::keccak_256(&init_code).into());
+			let (contract, used_gas) =
+				T::ContractDeployer::create(source, init_code, value, gas_limit)?;
+
+			ContractMetadataOf::<T>::insert(contract, &metadata);
+			Self::deposit_event(Event::ContractDeployed { deployer, contract, metadata });
+
+			// The deployer paid for the execution in gas, so the fee is not charged twice
+			let actual_weight = T::WeightInfo::deploy_contract()
+				.saturating_add(quota_weight)
+				.saturating_add(T::ContractDeployer::gas_weight(used_gas));
+			Ok(PostDispatchInfo { actual_weight: Some(actual_weight), pays_fee: Pays::No })
+		}
+
+		/// Approve a factory contract
+		///
+		/// # Parameters
+		/// - `origin`: Must be `AdminOrigin`
+		/// - `factory`: Address of the factory contract
+		/// - `admin`: Account administering the factory
+		///
+		/// # Errors
+		/// - `FactoryAlreadyRegistered`: The factory is already approved
+		///
+		/// # Events
+		/// - `FactoryRegistered`: Emitted when the factory is approved
+		#[pallet::call_index(10)]
+		#[pallet::weight(T::WeightInfo::register_factory())]
+		pub fn register_factory(
+			origin: OriginFor<T>,
+			factory: H160,
+			admin: T::AccountId,
+		) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
+
+			Self::do_register_factory(factory, admin)?;
+
+			Ok(())
+		}
+
+		/// Suspend or reactivate an approved factory
+		///
+		/// # Parameters
+		/// - `origin`: Must be `AdminOrigin`
+		/// - `factory`: Address of the factory contract
+		/// - `status`: The new status
+		///
+		/// # Errors
+		/// - `ContractRetired`: The factory is retired
+		/// - `RetirementNotAnnounced`: `status` is `Retired`, which only a finalized
+		///   retirement sets
+		/// - `UnknownFactory`: The factory is not approved
+		///
+		/// # Events
+		/// - `FactoryStatusSet`: Emitted when the status is changed
+		#[pallet::call_index(11)]
+		#[pallet::weight(T::WeightInfo::set_factory_status())]
+		pub fn set_factory_status(
+			origin: OriginFor<T>,
+			factory: H160,
+			status: FactoryStatus,
+		) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
+
+			Self::do_set_factory_status(factory, status)?;
+
+			Ok(())
+		}
+
+		/// Replace the authorization approval policy
+		///
+		/// # Parameters
+		/// - `origin`: Must be Root (typically called via sudo or governance)
+		/// - `policy`: The new policy, or `None` to disable the approval workflow
+		///
+		/// # Errors
+		/// - `InvalidApprovalPolicy`: The threshold is unreachable or an approver is duplicated
+		/// - `NotEnacted`: An enactment delay is set and the call was not scheduled
+		///
+		/// # Events
+		/// - `ApprovalPolicySet`: Emitted when the policy is replaced
+		#[pallet::call_index(12)]
+		#[pallet::weight(T::WeightInfo::set_approval_policy())]
+		pub fn set_approval_policy(
+			origin: OriginFor<T>,
+			policy: Option<ApprovalPolicy<T>>,
+		) -> DispatchResult {
+			ensure_root(origin)?;
+			Self::ensure_enacted()?;
+
+			let approvers = match &policy {
+				Some(policy) => {
+					ensure!(policy.is_valid(), Error::<T>::InvalidApprovalPolicy);
+					policy.approvers.len() as u32
+				},
+				None => 0,
+			};
+			AuthorizationApprovalPolicy::<T>::set(policy);
+			Self::deposit_event(Event::ApprovalPolicySet { approvers });
+
+			Ok(())
+		}
+
+		/// Propose to authorize a deployer, counting as the proposer's approval
+		///
+		/// The approvals required depend on the risk tier governance assessed for the
+		/// account, see [`DeployerRiskTiers`].
+		///
+		/// # Parameters
+		/// - `origin`: Must be signed by a named approver
+		/// - `deployer`: The account to authorize
+		///
+		/// # Errors
+		/// - `NoApprovalPolicy`: The approval workflow is disabled
+		/// - `NotApprover`: The caller is not a named approver
+		/// - `AlreadyAuthorized`: The account is already authorized
+		/// - `TooManyProposals`: `MaxProposals` proposals are already pending
+		///
+		/// # Events
+		/// - `AuthorizationProposed`: Emitted with the identifier of the proposal
+		#[pallet::call_index(13)]
+		#[pallet::weight(T::WeightInfo::propose_authorization())]
+		pub fn propose_authorization(
+			origin: OriginFor<T>,
+			deployer: T::AccountId,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			Self::ensure_approver(&who)?;
+			ensure!(!Self::is_authorized(&deployer), Error::<T>::AlreadyAuthorized);
+			let pending = PendingProposals::<T>::get();
+			ensure!(pending < T::MaxProposals::get(), Error::<T>::TooManyProposals);
+
+			let approvals = BoundedVec::truncate_from(sp_std::vec![who.clone()]);
+			let proposed_at = frame_system::Pallet::<T>::block_number();
+			let proposal = AuthorizationProposal {
+				deployer: deployer.clone(),
+				approvals,
+				proposed_at,
+				expires_at: proposed_at.saturating_add(T::ProposalLifetime::get()),
+				scheduled: None,
+			};
+			let id = NextProposalId::<T>::get();
+			AuthorizationProposals::<T>::insert(id, proposal);
+			NextProposalId::<T>::put(id.wrapping_add(1));
+			PendingProposals::<T>::put(pending.saturating_add(1));
+			Self::deposit_event(Event::AuthorizationProposed {
+				id,
+				risk_tier: Self::risk_tier_of(&deployer),
+				deployer,
+				proposer: who,
+			});
+
+			Ok(())
+		}
+
+		/// Approve a pending authorization proposal
+		///
+		/// # Parameters
+		/// - `origin`: Must be signed by a named approver
+		/// - `id`: Identifier of the proposal
+		///
+		/// # Errors
+		/// - `NoApprovalPolicy`: The approval workflow is disabled
+		/// - `NotApprover`: The caller is not a named approver
+		/// - `UnknownProposal`: The proposal does not exist
+		/// - `ProposalExpired`: The proposal has expired
+		/// - `AlreadyApproved`: The caller already approved the proposal
+		///
+		/// # Events
+		/// - `AuthorizationApproved`: Emitted with the number of collected approvals
+		#[pallet::call_index(14)]
+		#[pallet::weight(T::WeightInfo::approve_authorization())]
+		pub fn approve_authorization(origin: OriginFor<T>, id: u32) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let policy = Self::ensure_approver(&who)?;
+			let approvals = AuthorizationProposals::<T>::try_mutate(id, |proposal| {
+				let proposal = proposal.as_mut().ok_or(Error::<T>::UnknownProposal)?;
+				ensure!(
+					frame_system::Pallet::<T>::block_number() < proposal.expires_at,
+					Error::<T>::ProposalExpired
+				);
+				ensure!(!proposal.approvals.contains(&who), Error::<T>::AlreadyApproved);
+
+				// Approvals of accounts removed from the policy no longer count, rebuilding the
+				// list from the unique approvers of the policy keeps it within `MaxApprovers`
+				let mut approvals = policy.approvers.clone();
+				approvals.retain(|approver| {
+					*approver == who || proposal.approvals.contains(approver)
+				});
+				proposal.approvals = approvals;
+				Ok::<_, Error<T>>(proposal.approvals.len() as u32)
+			})?;
+			Self::deposit_event(Event::AuthorizationApproved { id, approver: who, approvals });
+
+			Ok(())
+		}
+
+		/// Authorize the deployer of a proposal once it collected enough approvals
+		///
+		/// Accounts below the policy's risk tier need a single approval, the others need the
+		/// policy's threshold. Only approvals of current approvers count. While an enactment
+		/// delay is set, the execution is scheduled as a governance call dispatched with the
+		/// caller's origin, open to objections like any other authorization; the approvals
+		/// are counted again when it is enacted, the proposal no longer expiring meanwhile.
+		///
+		/// # Parameters
+		/// - `origin`: Must be signed by a named approver
+		/// - `id`: Identifier of the proposal
+		///
+		/// # Errors
+		/// - `NoApprovalPolicy`: The approval workflow is disabled
+		/// - `NotApprover`: The caller is not a named approver
+		/// - `UnknownProposal`: The proposal does not exist
+		/// - `ProposalExpired`: The proposal has expired
+		/// - `InsufficientApprovals`: The proposal lacks approvals
+		/// - `ProposalScheduled`: The execution of the proposal is already scheduled
+		/// - `TooManyGovernanceCalls`: `MaxGovernanceCalls` calls are already scheduled
+		/// - `AlreadyAuthorized`: The account was authorized in the meantime
+		///
+		/// # Events
+		/// - `GovernanceCallScheduled`: Emitted when the execution is scheduled
+		/// - `AuthorizationExecuted`: Emitted when the proposal is executed
+		/// - `DeployerAuthorized`: Emitted for the authorized account
+		#[pallet::call_index(15)]
+		#[pallet::weight(
+			T::WeightInfo::execute_authorization().max(T::WeightInfo::schedule_governance_call())
+		)]
+		pub fn execute_authorization(origin: OriginFor<T>, id: u32) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let policy = Self::ensure_approver(&who)?;
+			let mut proposal =
+				AuthorizationProposals::<T>::get(id).ok_or(Error::<T>::UnknownProposal)?;
+			let enacting = proposal.scheduled.is_some() && Enacting::<T>::exists();
+			ensure!(
+				enacting || frame_system::Pallet::<T>::block_number() < proposal.expires_at,
+				Error::<T>::ProposalExpired
+			);
+			let approvals =
+				proposal.approvals.iter().filter(|approver| policy.is_approver(approver)).count();
+			let required = policy.required_approvals(Self::risk_tier_of(&proposal.deployer));
+			ensure!(approvals as u32 >= required, Error::<T>::InsufficientApprovals);
+
+			if Self::ensure_enacted().is_err() {
+				ensure!(!Self::is_scheduled(&proposal), Error::<T>::ProposalScheduled);
+				let origin: OriginFor<T> = frame_system::RawOrigin::Signed(who).into();
+				let origin = <T as Config>::RuntimeOrigin::from(origin).caller().clone();
+				let call = <T as Config>::RuntimeCall::from(Call::execute_authorization { id });
+				proposal.scheduled = Some(Self::do_schedule(origin, call)?);
+				AuthorizationProposals::<T>::insert(id, proposal);
+				return Ok(());
+			}
+
+			Self::remove_proposal(id);
+			Self::do_authorize(proposal.deployer.clone())?;
+			Self::deposit_event(Event::AuthorizationExecuted { id, deployer: proposal.deployer });
+
+			Ok(())
+		}
+
+		/// Remove an expired authorization proposal, freeing its slot
+		///
+		/// # Parameters
+		/// - `origin`: Any signed account
+		/// - `id`: Identifier of the proposal
+		///
+		/// # Errors
+		/// - `UnknownProposal`: The proposal does not exist
+		/// - `ProposalNotExpired`: The proposal can still be approved
+		/// - `ProposalScheduled`: The execution of the proposal is scheduled
+		///
+		/// # Events
+		/// - `ProposalExpired`: Emitted when the proposal is removed
+		#[pallet::call_index(16)]
+		#[pallet::weight(T::WeightInfo::remove_expired_proposal())]
+		pub fn remove_expired_proposal(origin: OriginFor<T>, id: u32) -> DispatchResult {
+			ensure_signed(origin)?;
+
+			let proposal =
+				AuthorizationProposals::<T>::get(id).ok_or(Error::<T>::UnknownProposal)?;
+			ensure!(
+				frame_system::Pallet::<T>::block_number() >= proposal.expires_at,
+				Error::<T>::ProposalNotExpired
+			);
+			ensure!(!Self::is_scheduled(&proposal), Error::<T>::ProposalScheduled);
+
+			Self::remove_proposal(id);
+			Self::deposit_event(Event::ProposalExpired { id });
+
+			Ok(())
+		}
+
+		/// Set the permissions of an approved factory
+		///
+		/// # Parameters
+		/// - `origin`: Must be `AdminOrigin`
+		/// - `factory`: Address of the factory contract
+		/// - `permissions`: The functions the factory may call through the precompile
+		///
+		/// # Errors
+		/// - `UnknownFactory`: The factory is not approved
+		///
+		/// # Events
+		/// - `FactoryPermissionsSet`: Emitted when the permissions are changed
+		#[pallet::call_index(17)]
+		#[pallet::weight(T::WeightInfo::set_factory_permissions())]
+		pub fn set_factory_permissions(
+			origin: OriginFor<T>,
+			factory: H160,
+			permissions: FactoryPermissions,
+		) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
+
+			ensure!(ApprovedFactories::<T>::contains_key(factory), Error::<T>::UnknownFactory);
+
+			FactoryPermissionsOf::<T>::insert(factory, permissions);
+			Self::deposit_event(Event::FactoryPermissionsSet { factory, permissions });
+
+			Ok(())
+		}
+
+		/// Set or clear the block at which the approval of a factory expires
+		///
+		/// A `FactoryExpiringSoon` event is emitted `FactoryExpiryNotice` blocks before the
+		/// expiry, or in the next block if the expiry is closer. At expiry the factory is
+		/// suspended. Renewing means calling this again with a later block, and
+		/// reactivating the factory if it already expired.
+		///
+		/// # Parameters
+		/// - `origin`: Must be `AdminOrigin`
+		/// - `factory`: Address of the factory contract
+		/// - `expires_at`: The expiry block, or `None` for an approval without expiry
+		///
+		/// # Errors
+		/// - `UnknownFactory`: The factory is not approved
+		/// - `ExpiryInPast`: The expiry block is not in the future
+		/// - `ExpiryScheduleFull`: Too many expiry events are scheduled in the notice or
+		///   expiry block
+		///
+		/// # Events
+		/// - `FactoryExpirySet`: Emitted when the expiry is changed
+		#[pallet::call_index(18)]
+		#[pallet::weight(T::WeightInfo::set_factory_expiry())]
+		pub fn set_factory_expiry(
+			origin: OriginFor<T>,
+			factory: H160,
+			expires_at: Option<BlockNumberFor<T>>,
+		) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
+
+			ensure!(ApprovedFactories::<T>::contains_key(factory), Error::<T>::UnknownFactory);
+
+			match expires_at {
+				Some(expires_at) => {
+					let now = frame_system::Pallet::<T>::block_number();
+					ensure!(expires_at > now, Error::<T>::ExpiryInPast);
+
+					let notice_at = expires_at
+						.saturating_sub(T::FactoryExpiryNotice::get())
+						.max(now.saturating_add(One::one()));
+					if notice_at < expires_at {
+						Self::schedule_expiry_event(notice_at, factory, expires_at)?;
+					}
+					Self::schedule_expiry_event(expires_at, factory, expires_at)?;
+					FactoryExpiry::<T>::insert(factory, expires_at);
+				},
+				None => FactoryExpiry::<T>::remove(factory),
+			}
+			Self::deposit_event(Event::FactoryExpirySet { factory, expires_at });
+
+			Ok(())
+		}
+
+		/// Announce an upcoming deployment, holding `IntentBond` from the caller
+		///
+		/// Gives reviewers a window to inspect the init code before it is deployed. The
+		/// first contract the caller creates from init code with the announced hash is
+		/// linked to the intent and releases the bond.
+		///
+		/// # Parameters
+		/// - `origin`: Must be a signed authorized deployer
+		/// - `code_hash`: Keccak-256 hash of the init code to be deployed
+		/// - `label`: Human readable name of the upcoming contract
+		///
+		/// # Errors
+		/// - `NotAuthorized`: The signer is not an authorized deployer
+		/// - `IntentAlreadyAnnounced`: The signer already announced the same init code
+		///
+		/// # Events
+		/// - `DeploymentAnnounced`: Emitted when the intent is recorded
+		#[pallet::call_index(19)]
+		#[pallet::weight(T::WeightInfo::announce_deployment())]
+		pub fn announce_deployment(
+			origin: OriginFor<T>,
+			code_hash: H256,
+			label: BoundedVec<u8, T::MaxMetadataLen>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			ensure!(Self::is_authorized(&who), Error::<T>::NotAuthorized);
+			ensure!(
+				!DeploymentIntents::<T>::contains_key(&who, code_hash),
+				Error::<T>::IntentAlreadyAnnounced
+			);
+
+			let bond = T::IntentBond::get();
+			T::Currency::hold(&HoldReason::DeploymentIntent.into(), &who, bond)?;
+
+			let announced_at = frame_system::Pallet::<T>::block_number();
+			DeploymentIntents::<T>::insert(
+				&who,
+				code_hash,
+				DeploymentIntent { label: label.clone(), bond, announced_at },
+			);
+			Self::deposit_event(Event::DeploymentAnnounced { deployer: who, code_hash, label });
+
+			Ok(())
+		}
+
+		/// Replace the deployment policy
+		///
+		/// Takes effect for every contract creation from the next transaction on.
+		///
+		/// # Parameters
+		/// - `origin`: Must be Root (typically called via sudo or governance)
+		/// - `policy`: The new deployment policy
+		///
+		/// # Errors
+		/// - `NotEnacted`: An enactment delay is set and the call was not scheduled
+		///
+		/// # Events
+		/// - `DeploymentPolicySet`: Emitted when the policy is replaced
+		#[pallet::call_index(20)]
+		#[pallet::weight(T::WeightInfo::set_deployment_policy())]
+		pub fn set_deployment_policy(
+			origin: OriginFor<T>,
+			policy: DeploymentPolicy<BalanceOf<T>>,
+		) -> DispatchResult {
+			ensure_root(origin)?;
+			Self::ensure_enacted()?;
+
+			EvmDeploymentPolicy::<T>::put(policy);
+			Self::deposit_event(Event::DeploymentPolicySet { policy });
+
+			Ok(())
+		}
+
+		/// Move a deployer's authorization to a new key
+		///
+		/// The new key takes over the authorization, keeping its original block, the
+		/// deployment window override, the creations counted against the deployment quota
+		/// and whatever the lifecycle subscribers track for the deployer, e.g. its
+		/// reputation. Contracts it creates from then on are attributed to the new key;
+		/// provenance already recorded is left untouched.
+		///
+		/// Root rotates the key at once. The deployer itself first announces the rotation,
+		/// then calls again with the same new key once `KeyRotationDelay` blocks have
+		/// passed, leaving governance time to revoke a compromised key before it hands its
+		/// authorization over. Announcing another key restarts the delay.
+		///
+		/// Announced deployments are bonded by the old key, so they must be fulfilled
+		/// before the rotation.
+		///
+		/// # Parameters
+		/// - `origin`: Root, or the signed `old` key
+		/// - `old`: The authorized key to rotate
+		/// - `new`: The key taking over, which must not be authorized yet
+		///
+		/// # Errors
+		/// - `NotAuthorized`: `old` is not an authorized deployer
+		/// - `AlreadyAuthorized`: `new` is already an authorized deployer
+		/// - `IntentsOutstanding`: `old` has announced deployments not fulfilled yet
+		/// - `KeyRotationNotReady`: The announced rotation is still within its delay
+		///
+		/// # Events
+		/// - `KeyRotationAnnounced`: Emitted when the deployer announces the rotation
+		/// - `DeployerKeyRotated`: Emitted when the authorization is moved
+		#[pallet::call_index(21)]
+		#[pallet::weight(T::WeightInfo::rotate_deployer_key())]
+		pub fn rotate_deployer_key(
+			origin: OriginFor<T>,
+			old: T::AccountId,
+			new: T::AccountId,
+		) -> DispatchResult {
+			let signer = ensure_signed_or_root(origin)?;
+			if let Some(who) = &signer {
+				ensure!(*who == old, DispatchError::BadOrigin);
+			}
+
+			ensure!(Self::is_authorized(&old), Error::<T>::NotAuthorized);
+			ensure!(!Self::is_authorized(&new), Error::<T>::AlreadyAuthorized);
+			ensure!(
+				DeploymentIntents::<T>::iter_prefix(&old).next().is_none(),
+				Error::<T>::IntentsOutstanding
+			);
+
+			if signer.is_some() {
+				let now = frame_system::Pallet::<T>::block_number();
+				match PendingKeyRotations::<T>::get(&old) {
+					Some((pending, ready_at)) if pending == new => {
+						ensure!(now >= ready_at, Error::<T>::KeyRotationNotReady);
+					},
+					_ => {
+						let ready_at = now.saturating_add(T::KeyRotationDelay::get());
+						PendingKeyRotations::<T>::insert(&old, (new.clone(), ready_at));
+						Self::deposit_event(Event::KeyRotationAnnounced { old, new, ready_at });
+						return Ok(());
+					},
+				}
+			}
+
+			Self::do_rotate(old, new);
+
+			Ok(())
+		}
+
+		/// Authorize a smart contract wallet, e.g. a Safe, to deploy EVM contracts
+		///
+		/// The wallet becomes an authorized deployer like any other account, so contracts it
+		/// creates from within the EVM are attributed to it. As the wallet cannot sign, the
+		/// operator signs `deploy_contract` and `pallet_evm` calls using the wallet as their
+		/// EVM source, which then pays for and immediately calls the creation. Revoking the
+		/// wallet also removes its operator.
+		///
+		/// # Parameters
+		/// - `origin`: Must be `AdminOrigin`
+		/// - `wallet`: Address of the wallet contract
+		/// - `operator`: The account submitting transactions on behalf of the wallet
+		///
+		/// # Errors
+		/// - `AlreadyAuthorized`: The wallet is already authorized
+		/// - `NotEnacted`: An enactment delay is set and the call was not scheduled
+		///
+		/// # Events
+		/// - `DeployerAuthorized`: Emitted for the account of the wallet
+		/// - `ContractWalletAuthorized`: Emitted when the operator is recorded
+		#[pallet::call_index(22)]
+		#[pallet::weight(T::WeightInfo::authorize_contract_wallet())]
+		pub fn authorize_contract_wallet(
+			origin: OriginFor<T>,
+			wallet: H160,
+			operator: T::AccountId,
+		) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
+			Self::ensure_enacted()?;
+
+			let account = T::ContractDeployer::into_account_id(wallet);
+			Self::do_authorize(account.clone())?;
+			ContractWalletOperators::<T>::insert(&account, &operator);
+			Self::deposit_event(Event::ContractWalletAuthorized { wallet, operator });
+
+			Ok(())
+		}
+
+		/// Set or clear the metadata of an approved factory
+		///
+		/// Blobs too long to be kept inline are noted in the preimage store, and the
+		/// previous blob is released.
+		///
+		/// # Parameters
+		/// - `origin`: `AdminOrigin`, or the signed admin of the factory
+		/// - `factory`: Address of the factory contract
+		/// - `metadata`: The metadata blob, or `None` to clear it
+		///
+		/// # Errors
+		/// - `UnknownFactory`: The factory is not approved
+		/// - `MetadataTooLong`: The blob exceeds `MaxMetadataBlobLen`
+		///
+		/// # Events
+		/// - `FactoryMetadataSet`: Emitted when the metadata is changed
+		#[pallet::call_index(23)]
+		#[pallet::weight(T::WeightInfo::set_factory_metadata(
+			metadata.as_ref().map_or(0, |metadata| metadata.len() as u32)
+		))]
+		pub fn set_factory_metadata(
+			origin: OriginFor<T>,
+			factory: H160,
+			metadata: Option<Vec<u8>>,
+		) -> DispatchResult {
+			let signer = match T::AdminOrigin::try_origin(origin) {
+				Ok(_) => None,
+				Err(origin) => Some(ensure_signed(origin)?),
+			};
+			let info = ApprovedFactories::<T>::get(factory).ok_or(Error::<T>::UnknownFactory)?;
+			if let Some(who) = &signer {
+				ensure!(*who == info.admin, DispatchError::BadOrigin);
+			}
+
+			let metadata = Self::replace_metadata(FactoryMetadata::<T>::take(factory), metadata)?;
+			let hash = metadata.as_ref().map(Bounded::hash);
+			if let Some(metadata) = metadata {
+				FactoryMetadata::<T>::insert(factory, metadata);
+			}
+			Self::deposit_event(Event::FactoryMetadataSet { factory, metadata: hash });
+
+			Ok(())
+		}
+
+		/// Set or clear the caller's deployer metadata
+		///
+		/// Blobs too long to be kept inline are noted in the preimage store, and the
+		/// previous blob is released. The metadata is removed along with the authorization.
+		///
+		/// # Parameters
+		/// - `origin`: Must be a signed authorized deployer
+		/// - `metadata`: The metadata blob, or `None` to clear it
+		///
+		/// # Errors
+		/// - `NotAuthorized`: The signer is not an authorized deployer
+		/// - `MetadataTooLong`: The blob exceeds `MaxMetadataBlobLen`
+		///
+		/// # Events
+		/// - `DeployerMetadataSet`: Emitted when the metadata is changed
+		#[pallet::call_index(24)]
+		#[pallet::weight(T::WeightInfo::set_deployer_metadata(
+			metadata.as_ref().map_or(0, |metadata| metadata.len() as u32)
+		))]
+		pub fn set_deployer_metadata(
+			origin: OriginFor<T>,
+			metadata: Option<Vec<u8>>,
+		) -> DispatchResult {
+			let deployer = ensure_signed(origin)?;
+
+			ensure!(Self::is_authorized(&deployer), Error::<T>::NotAuthorized);
+
+			let metadata =
+				Self::replace_metadata(DeployerMetadata::<T>::take(&deployer), metadata)?;
+			let hash = metadata.as_ref().map(Bounded::hash);
+			if let Some(metadata) = metadata {
+				DeployerMetadata::<T>::insert(&deployer, metadata);
+			}
+			Self::deposit_event(Event::DeployerMetadataSet { deployer, metadata: hash });
+
+			Ok(())
+		}
+
+		/// Remove a factory from the registry
+		///
+		/// Its permissions, expiry and metadata are removed along with it, releasing the
+		/// metadata preimage. Contracts it created keep their provenance. The weight of
+		/// releasing the preimage is refunded if the factory has none.
+		///
+		/// # Parameters
+		/// - `origin`: Must be `AdminOrigin`
+		/// - `factory`: Address of the factory contract
+		///
+		/// # Errors
+		/// - `UnknownFactory`: The factory is not approved
+		///
+		/// # Events
+		/// - `FactoryUnregistered`: Emitted when the factory is removed
+		#[pallet::call_index(25)]
+		#[pallet::weight(T::WeightInfo::unregister_factory(1))]
+		pub fn unregister_factory(
+			origin: OriginFor<T>,
+			factory: H160,
+		) -> DispatchResultWithPostInfo {
+			T::AdminOrigin::ensure_origin(origin)?;
+
+			ApprovedFactories::<T>::take(factory).ok_or(Error::<T>::UnknownFactory)?;
+			if let Some(tenant) = FactoryTenant::<T>::take(factory) {
+				Tenants::<T>::mutate_extant(tenant, |info| info.factories.saturating_dec());
+			}
+			FactoryPermissionsOf::<T>::remove(factory);
+			FactoryExpiry::<T>::remove(factory);
+			let mut in_preimage = false;
+			if let Some(metadata) = FactoryMetadata::<T>::take(factory) {
+				in_preimage = metadata.lookup_needed();
+				T::Preimages::drop(&metadata);
+			}
+			Self::deposit_event(Event::FactoryUnregistered { factory });
+
+			Ok(Some(T::WeightInfo::unregister_factory(in_preimage as u32)).into())
+		}
+
+		/// Grant or deny an account the deployer admin origin
+		///
+		/// # Parameters
+		/// - `origin`: Must be Root (typically called via sudo or governance)
+		/// - `account`: The account
+		/// - `is_admin`: Whether the account may dispatch as a deployer admin
+		///
+		/// # Events
+		/// - `DeployerAdminSet`: Emitted when the admin set is changed
+		#[pallet::call_index(26)]
+		#[pallet::weight(T::WeightInfo::set_deployer_admin())]
+		pub fn set_deployer_admin(
+			origin: OriginFor<T>,
+			account: T::AccountId,
+			is_admin: bool,
+		) -> DispatchResult {
+			ensure_root(origin)?;
+
+			if is_admin {
+				DeployerAdmins::<T>::insert(&account, ());
+			} else {
+				DeployerAdmins::<T>::remove(&account);
+			}
+			Self::deposit_event(Event::DeployerAdminSet { account, is_admin });
+
+			Ok(())
+		}
+
+		/// Dispatch a call with the [`Origin::DeployerAdmin`] origin
+		///
+		/// The call passes `EnsureDeployerAdmin`, so a deployer admin can run this pallet's
+		/// deployer and factory registry calls as well as the calls of other pallets
+		/// configured with that origin. The call is subject to the runtime's call filter,
+		/// and the weight it did not use is refunded.
+		///
+		/// # Parameters
+		/// - `origin`: Must be signed by a deployer admin
+		/// - `call`: The call to dispatch
+		///
+		/// # Errors
+		/// - `NotDeployerAdmin`: The signer is not a deployer admin
+		///
+		/// # Events
+		/// - `DeployerAdminDispatched`: Emitted with the result of the call
+		#[pallet::call_index(27)]
+		#[pallet::weight({
+			let info = call.get_dispatch_info();
+			(T::WeightInfo::dispatch_as_deployer_admin().saturating_add(info.weight), info.class)
+		})]
+		pub fn dispatch_as_deployer_admin(
+			origin: OriginFor<T>,
+			call: Box<<T as Config>::RuntimeCall>,
+		) -> DispatchResultWithPostInfo {
+			let admin = ensure_signed(origin)?;
+
+			ensure!(DeployerAdmins::<T>::contains_key(&admin), Error::<T>::NotDeployerAdmin);
+
+			let info = call.get_dispatch_info();
+			let origin: <T as Config>::RuntimeOrigin = Origin::DeployerAdmin.into();
+			let result = call.dispatch(origin.into());
+			let call_weight = extract_actual_weight(&result, &info);
+			let result = result.map(|_| ()).map_err(|err| err.error);
+			Self::deposit_event(Event::DeployerAdminDispatched { admin, result });
+
+			Ok(Some(T::WeightInfo::dispatch_as_deployer_admin().saturating_add(call_weight)).into())
+		}
+
+		/// Slash the bond of an announced deployment
+		///
+		/// Drops the intent and pays its bond into `DeploymentProceeds`, for announcements
+		/// found to be abusive during the review window.
+		///
+		/// # Parameters
+		/// - `origin`: Must be `AdminOrigin`
+		/// - `deployer`: The announcing deployer
+		/// - `code_hash`: Keccak-256 hash of the announced init code
+		///
+		/// # Errors
+		/// - `UnknownIntent`: The deployer has not announced the init code
+		///
+		/// # Events
+		/// - `DeploymentIntentSlashed`: Emitted with the slashed amount
+		#[pallet::call_index(28)]
+		#[pallet::weight(T::WeightInfo::slash_deployment_intent())]
+		pub fn slash_deployment_intent(
+			origin: OriginFor<T>,
+			deployer: T::AccountId,
+			code_hash: H256,
+		) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
+
+			let intent = DeploymentIntents::<T>::take(&deployer, code_hash)
+				.ok_or(Error::<T>::UnknownIntent)?;
+			let (credit, _) = <T::Currency as BalancedHold<_>>::slash(
+				&HoldReason::DeploymentIntent.into(),
+				&deployer,
+				intent.bond,
+			);
+			let amount = credit.peek();
+			T::DeploymentProceeds::on_unbalanced(credit);
+			Self::deposit_event(Event::DeploymentIntentSlashed { deployer, code_hash, amount });
+
+			Ok(())
+		}
+
+		/// Set the maximum number of contract creations of a deployer per quota period
+		///
+		/// Counts restart with every period of `QuotaPeriodLength` blocks, without any
+		/// scheduled call: each deployer's count is reset by its first creation in the
+		/// period.
+		///
+		/// # Parameters
+		/// - `origin`: Must be Root (typically called via sudo or governance)
+		/// - `quota`: The new quota, or `None` to lift it
+		///
+		/// # Errors
+		/// - `NotEnacted`: An enactment delay is set and the call was not scheduled
+		///
+		/// # Events
+		/// - `DeploymentQuotaSet`: Emitted when the quota is updated
+		#[pallet::call_index(29)]
+		#[pallet::weight(T::WeightInfo::set_deployment_quota())]
+		pub fn set_deployment_quota(origin: OriginFor<T>, quota: Option<u32>) -> DispatchResult {
+			ensure_root(origin)?;
+			Self::ensure_enacted()?;
+
+			DeploymentQuota::<T>::set(quota);
+			Self::deposit_event(Event::DeploymentQuotaSet { quota });
+
+			Ok(())
+		}
+
+		/// Create a tenant of the factory registry
+		///
+		/// # Parameters
+		/// - `origin`: Must be Root (typically called via sudo or governance)
+		/// - `admin`: Account administering the tenant
+		/// - `bounds`: Bounds on the factories and deployers of the tenant
+		///
+		/// # Errors
+		/// - `NotEnacted`: An enactment delay is set and the call was not scheduled
+		///
+		/// # Events
+		/// - `TenantCreated`: Emitted with the id of the tenant
+		#[pallet::call_index(30)]
+		#[pallet::weight(T::WeightInfo::create_tenant())]
+		pub fn create_tenant(
+			origin: OriginFor<T>,
+			admin: T::AccountId,
+			bounds: TenantBounds,
+		) -> DispatchResult {
+			ensure_root(origin)?;
+			Self::ensure_enacted()?;
+
+			let tenant = NextTenantId::<T>::get();
+			NextTenantId::<T>::put(tenant.saturating_add(1));
+			Tenants::<T>::insert(
+				tenant,
+				TenantInfo { admin: admin.clone(), bounds, factories: 0, deployers: 0 },
+			);
+			Self::deposit_event(Event::TenantCreated { tenant, admin, bounds });
+
+			Ok(())
+		}
+
+		/// Replace the bounds of a tenant
+		///
+		/// Lowering a bound below the current count removes nothing, it only stops the tenant
+		/// admin from registering or authorizing more.
+		///
+		/// # Parameters
+		/// - `origin`: Must be Root (typically called via sudo or governance)
+		/// - `tenant`: Identifier of the tenant
+		/// - `bounds`: The new bounds
+		///
+		/// # Errors
+		/// - `UnknownTenant`: No tenant has the id
+		/// - `NotEnacted`: An enactment delay is set and the call was not scheduled
+		///
+		/// # Events
+		/// - `TenantBoundsSet`: Emitted when the bounds are replaced
+		#[pallet::call_index(31)]
+		#[pallet::weight(T::WeightInfo::set_tenant_bounds())]
+		pub fn set_tenant_bounds(
+			origin: OriginFor<T>,
+			tenant: u32,
+			bounds: TenantBounds,
+		) -> DispatchResult {
+			ensure_root(origin)?;
+			Self::ensure_enacted()?;
+
+			Tenants::<T>::try_mutate(tenant, |info| {
+				info.as_mut().ok_or(Error::<T>::UnknownTenant)?.bounds = bounds;
+				Ok::<_, Error<T>>(())
+			})?;
+			Self::deposit_event(Event::TenantBoundsSet { tenant, bounds });
+
+			Ok(())
+		}
+
+		/// Hand a tenant over to a new admin
+		///
+		/// Factories keep the admin they were registered with.
+		///
+		/// # Parameters
+		/// - `origin`: Root, or signed by the current admin of the tenant
+		/// - `tenant`: Identifier of the tenant
+		/// - `admin`: The new admin
+		///
+		/// # Errors
+		/// - `UnknownTenant`: No tenant has the id
+		/// - `NotTenantAdmin`: The signer is not the admin of the tenant
+		///
+		/// # Events
+		/// - `TenantAdminSet`: Emitted when the admin is replaced
+		#[pallet::call_index(32)]
+		#[pallet::weight(T::WeightInfo::set_tenant_admin())]
+		pub fn set_tenant_admin(
+			origin: OriginFor<T>,
+			tenant: u32,
+			admin: T::AccountId,
+		) -> DispatchResult {
+			let who = ensure_signed_or_root(origin)?;
+
+			Tenants::<T>::try_mutate(tenant, |info| {
+				let info = info.as_mut().ok_or(Error::<T>::UnknownTenant)?;
+				if let Some(who) = who {
+					ensure!(who == info.admin, Error::<T>::NotTenantAdmin);
+				}
+				info.admin = admin.clone();
+				Ok::<_, Error<T>>(())
+			})?;
+			Self::deposit_event(Event::TenantAdminSet { tenant, admin });
+
+			Ok(())
+		}
+
+		/// Register an approved factory in the namespace of a tenant
+		///
+		/// The factory counts against the tenant's bound until it is unregistered, which
+		/// stays with `AdminOrigin`.
+		///
+		/// # Parameters
+		/// - `origin`: Must be signed by the admin of the tenant
+		/// - `tenant`: Identifier of the tenant
+		/// - `factory`: Address of the factory contract
+		/// - `admin`: Account administering the factory
+		///
+		/// # Errors
+		/// - `UnknownTenant`: No tenant has the id
+		/// - `NotTenantAdmin`: The signer is not the admin of the tenant
+		/// - `TenantBoundReached`: The tenant registered its maximum number of factories
+		/// - `FactoryAlreadyRegistered`: The factory is already approved
+		///
+		/// # Events
+		/// - `FactoryRegistered`: Emitted when the factory is approved
+		/// - `TenantFactoryRegistered`: Emitted with the tenant of the factory
+		#[pallet::call_index(33)]
+		#[pallet::weight(T::WeightInfo::register_tenant_factory())]
+		pub fn register_tenant_factory(
+			origin: OriginFor<T>,
+			tenant: u32,
+			factory: H160,
+			admin: T::AccountId,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let mut info = Self::tenant_of_admin(&who, tenant)?;
+			ensure!(info.factories < info.bounds.max_factories, Error::<T>::TenantBoundReached);
+
+			Self::do_register_factory(factory, admin)?;
+			info.factories.saturating_inc();
+			Tenants::<T>::insert(tenant, info);
+			FactoryTenant::<T>::insert(factory, tenant);
+			Self::deposit_event(Event::TenantFactoryRegistered { tenant, factory });
+
+			Ok(())
+		}
+
+		/// Suspend or reactivate a factory of the namespace of the signer's tenant
+		///
+		/// # Parameters
+		/// - `origin`: Must be signed by the admin of the factory's tenant
+		/// - `factory`: Address of the factory contract
+		/// - `status`: The new status
+		///
+		/// # Errors
+		/// - `NotTenantAdmin`: The factory belongs to no tenant the signer administers
+		/// - `ContractRetired`: The factory is retired
+		/// - `RetirementNotAnnounced`: `status` is `Retired`, which only a finalized
+		///   retirement sets
+		///
+		/// # Events
+		/// - `FactoryStatusSet`: Emitted when the status is changed
+		#[pallet::call_index(34)]
+		#[pallet::weight(T::WeightInfo::set_tenant_factory_status())]
+		pub fn set_tenant_factory_status(
+			origin: OriginFor<T>,
+			factory: H160,
+			status: FactoryStatus,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let tenant = FactoryTenant::<T>::get(factory).ok_or(Error::<T>::NotTenantAdmin)?;
+			Self::tenant_of_admin(&who, tenant)?;
+
+			Self::do_set_factory_status(factory, status)?;
+
+			Ok(())
+		}
+
+		/// Authorize a deployer on behalf of a tenant
+		///
+		/// The deployer counts against the tenant's bound until its authorization is
+		/// revoked, by the tenant admin or by `AdminOrigin`. Tenant authorizations are not
+		/// subject to the enactment delay: they stay within the bounds of the tenant, whose
+		/// creation and bounds are.
+		///
+		/// # Parameters
+		/// - `origin`: Must be signed by the admin of the tenant
+		/// - `tenant`: Identifier of the tenant
+		/// - `deployer`: The account to authorize
+		///
+		/// # Errors
+		/// - `UnknownTenant`: No tenant has the id
+		/// - `NotTenantAdmin`: The signer is not the admin of the tenant
+		/// - `TenantBoundReached`: The tenant authorized its maximum number of deployers
+		/// - `AlreadyAuthorized`: The account is already authorized
+		///
+		/// # Events
+		/// - `DeployerAuthorized`: Emitted when authorization succeeds
+		/// - `TenantDeployerAuthorized`: Emitted with the tenant of the deployer
+		#[pallet::call_index(35)]
+		#[pallet::weight(T::WeightInfo::authorize_tenant_deployer())]
+		pub fn authorize_tenant_deployer(
+			origin: OriginFor<T>,
+			tenant: u32,
+			deployer: T::AccountId,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let mut info = Self::tenant_of_admin(&who, tenant)?;
+			ensure!(info.deployers < info.bounds.max_deployers, Error::<T>::TenantBoundReached);
+
+			Self::do_authorize(deployer.clone())?;
+			info.deployers.saturating_inc();
+			Tenants::<T>::insert(tenant, info);
+			DeployerTenant::<T>::insert(&deployer, tenant);
+			Self::deposit_event(Event::TenantDeployerAuthorized { tenant, deployer });
+
+			Ok(())
+		}
+
+		/// Revoke the authorization of a deployer of the signer's tenant
+		///
+		/// # Parameters
+		/// - `origin`: Must be signed by the admin of the deployer's tenant
+		/// - `deployer`: The account to revoke
+		///
+		/// # Errors
+		/// - `NotTenantAdmin`: The deployer was authorized by no tenant the signer administers
+		///
+		/// # Events
+		/// - `DeployerRevoked`: Emitted when revocation succeeds
+		#[pallet::call_index(36)]
+		#[pallet::weight(T::WeightInfo::revoke_deployer(1))]
+		pub fn revoke_tenant_deployer(
+			origin: OriginFor<T>,
+			deployer: T::AccountId,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+
+			let tenant = DeployerTenant::<T>::get(&deployer).ok_or(Error::<T>::NotTenantAdmin)?;
+			Self::tenant_of_admin(&who, tenant)?;
+
+			let has_override = WindowOverrides::<T>::contains_key(&deployer);
+			Self::do_revoke(deployer);
+
+			Ok(Some(T::WeightInfo::revoke_deployer(has_override as u32)).into())
+		}
+
+		/// Schedule a governance call for enactment after the enactment delay
+		///
+		/// The call is dispatched by `enact_governance_call` with the origin scheduling it,
+		/// and may be any call, e.g. a batch of authorizations. Calls subject to the
+		/// enactment delay refuse any other dispatch while the delay is set. Token holders may
+		/// object to a scheduled call authorizing deployers, directly or through the calls it
+		/// nests, until it is due.
+		///
+		/// # Parameters
+		/// - `origin`: Must be `AdminOrigin`
+		/// - `call`: The call to schedule
+		///
+		/// # Errors
+		/// - `TooManyGovernanceCalls`: `MaxGovernanceCalls` calls are already scheduled
+		///
+		/// # Events
+		/// - `GovernanceCallScheduled`: Emitted with the hash of the call and the block from
+		///   which it can be enacted
+		#[pallet::call_index(37)]
+		#[pallet::weight(T::WeightInfo::schedule_governance_call())]
+		pub fn schedule_governance_call(
+			origin: OriginFor<T>,
+			call: Box<<T as Config>::RuntimeCall>,
+		) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin.clone())?;
+
+			let origin = <T as Config>::RuntimeOrigin::from(origin).caller().clone();
+			Self::do_schedule(origin, *call)?;
+
+			Ok(())
+		}
+
+		/// Enact a scheduled governance call whose enactment delay is over
+		///
+		/// `EmergencyOrigin` enacts calls before their delay is over. The weight the call did
+		/// not use is refunded.
+		///
+		/// # Parameters
+		/// - `origin`: Any signed account, or `EmergencyOrigin`
+		/// - `id`: Identifier of the scheduled call
+		/// - `weight_limit`: Upper bound on the weight of the call
+		///
+		/// # Errors
+		/// - `UnknownGovernanceCall`: No call is scheduled with the id
+		/// - `EnactmentNotDue`: The enactment delay is not over and the origin is not
+		///   `EmergencyOrigin`
+		/// - `WeightLimitTooLow`: The weight of the call exceeds `weight_limit`
+		///
+		/// # Events
+		/// - `GovernanceCallEnacted`: Emitted with the result of the call
+		#[pallet::call_index(38)]
+		#[pallet::weight(
+			T::WeightInfo::enact_governance_call().saturating_add(*weight_limit)
+		)]
+		pub fn enact_governance_call(
+			origin: OriginFor<T>,
+			id: u32,
+			weight_limit: Weight,
+		) -> DispatchResultWithPostInfo {
+			let emergency = match T::EmergencyOrigin::try_origin(origin) {
+				Ok(_) => true,
+				Err(origin) => {
+					ensure_signed(origin)?;
+					false
+				}
+			};
+
+			let scheduled = GovernanceCalls::<T>::get(id).ok_or(Error::<T>::UnknownGovernanceCall)?;
+			ensure!(
+				emergency || frame_system::Pallet::<T>::block_number() >= scheduled.enact_at,
+				Error::<T>::EnactmentNotDue
+			);
+			Self::remove_governance_call(id);
+			let call_weight = Self::enact(id, scheduled.origin, &scheduled.call, weight_limit)?;
+
+			Ok(Some(T::WeightInfo::enact_governance_call().saturating_add(call_weight)).into())
+		}
+
+		/// Cancel a scheduled governance call
+		///
+		/// # Parameters
+		/// - `origin`: Must be `EmergencyOrigin`
+		/// - `id`: Identifier of the scheduled call
+		///
+		/// # Errors
+		/// - `UnknownGovernanceCall`: No call is scheduled with the id
+		///
+		/// # Events
+		/// - `GovernanceCallCancelled`: Emitted when the call is cancelled
+		#[pallet::call_index(39)]
+		#[pallet::weight(T::WeightInfo::cancel_governance_call())]
+		pub fn cancel_governance_call(origin: OriginFor<T>, id: u32) -> DispatchResult {
+			T::EmergencyOrigin::ensure_origin(origin)?;
+
+			let scheduled = GovernanceCalls::<T>::get(id).ok_or(Error::<T>::UnknownGovernanceCall)?;
+			Self::remove_governance_call(id);
+			T::Preimages::drop(&scheduled.call);
+			Self::deposit_event(Event::GovernanceCallCancelled { id });
+
+			Ok(())
+		}
+
+		/// Set the number of blocks between the scheduling of a governance call and its
+		/// enactment
+		///
+		/// Calls already scheduled keep their enactment block. While a delay is set, changing
+		/// it is itself subject to the delay.
+		///
+		/// # Parameters
+		/// - `origin`: Must be Root (typically called via sudo or governance)
+		/// - `delay`: The new delay, zero to apply governance calls at once
+		///
+		/// # Errors
+		/// - `NotEnacted`: An enactment delay is set and the call was not scheduled
+		///
+		/// # Events
+		/// - `EnactmentDelaySet`: Emitted when the delay is updated
+		#[pallet::call_index(40)]
+		#[pallet::weight(T::WeightInfo::set_enactment_delay())]
+		pub fn set_enactment_delay(
+			origin: OriginFor<T>,
+			delay: BlockNumberFor<T>,
+		) -> DispatchResult {
+			ensure_root(origin)?;
+			Self::ensure_enacted()?;
+
+			EnactmentDelay::<T>::put(delay);
+			Self::deposit_event(Event::EnactmentDelaySet { delay });
+
+			Ok(())
+		}
+
+		/// Object to a scheduled authorization by locking tokens until it is resolved
+		///
+		/// Once the objections reach `VetoThreshold` of the total issuance, the authorization
+		/// is taken off the schedule and put to a referendum of token holders lasting
+		/// `ReferendumPeriod` blocks, the objections counting as votes against it.
+		///
+		/// # Parameters
+		/// - `origin`: Any signed account
+		/// - `id`: Identifier of the scheduled `authorize_deployer` call
+		/// - `amount`: Tokens to lock, added to an earlier objection of the account
+		///
+		/// # Errors
+		/// - `NotObjectable`: No `authorize_deployer` call is scheduled with the id
+		/// - `ObjectionWindowClosed`: The authorization is due for enactment
+		///
+		/// # Events
+		/// - `AuthorizationObjected`: Emitted with the tokens locked
+		/// - `AuthorizationVetoed`: Emitted when the objections reach the veto threshold
+		#[pallet::call_index(41)]
+		#[pallet::weight(T::WeightInfo::object_to_authorization())]
+		pub fn object_to_authorization(
+			origin: OriginFor<T>,
+			id: u32,
+			amount: BalanceOf<T>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let objected = Objections::<T>::get(id).ok_or(Error::<T>::NotObjectable)?;
+			let scheduled = GovernanceCalls::<T>::get(id).ok_or(Error::<T>::NotObjectable)?;
+			let now = frame_system::Pallet::<T>::block_number();
+			ensure!(now < scheduled.enact_at, Error::<T>::ObjectionWindowClosed);
+
+			Self::lock_vote(id, &who, false, amount)?;
+			let objected = objected.saturating_add(amount);
+			Self::deposit_event(Event::AuthorizationObjected { id, who, amount });
+
+			if objected < T::VetoThreshold::get() * T::Currency::total_issuance() {
+				Objections::<T>::insert(id, objected);
+				return Ok(());
+			}
+
+			// The preimage of the call moves over to the referendum
+			Self::remove_governance_call(id);
+			let ends_at = now.saturating_add(T::ReferendumPeriod::get());
+			let referendum = Referendum {
+				origin: scheduled.origin,
+				call: scheduled.call,
+				ends_at,
+				ayes: Zero::zero(),
+				nays: objected,
+			};
+			Referenda::<T>::insert(id, referendum);
+			Self::deposit_event(Event::AuthorizationVetoed { id, objected, ends_at });
+
+			Ok(())
+		}
+
+		/// Vote in the referendum on a vetoed authorization by locking tokens until it is
+		/// concluded
+		///
+		/// # Parameters
+		/// - `origin`: Any signed account
+		/// - `id`: Identifier of the referendum
+		/// - `aye`: Whether to vote for the authorization
+		/// - `amount`: Tokens to lock, added to an earlier vote of the account
+		///
+		/// # Errors
+		/// - `UnknownReferendum`: No referendum is held with the id
+		/// - `ReferendumEnded`: The voting period is over
+		/// - `ConflictingVote`: The account objected or voted the other way
+		///
+		/// # Events
+		/// - `Voted`: Emitted with the tokens locked
+		#[pallet::call_index(42)]
+		#[pallet::weight(T::WeightInfo::vote())]
+		pub fn vote(
+			origin: OriginFor<T>,
+			id: u32,
+			aye: bool,
+			amount: BalanceOf<T>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let mut referendum = Referenda::<T>::get(id).ok_or(Error::<T>::UnknownReferendum)?;
+			let now = frame_system::Pallet::<T>::block_number();
+			ensure!(now < referendum.ends_at, Error::<T>::ReferendumEnded);
+
+			Self::lock_vote(id, &who, aye, amount)?;
+			if aye {
+				referendum.ayes.saturating_accrue(amount);
+			} else {
+				referendum.nays.saturating_accrue(amount);
+			}
+			Referenda::<T>::insert(id, referendum);
+			Self::deposit_event(Event::Voted { id, who, aye, amount });
+
+			Ok(())
+		}
+
+		/// Conclude the referendum on a vetoed authorization after its voting period
+		///
+		/// The authorization is enacted with the origin that scheduled it if more tokens voted
+		/// for it than against it, and dropped otherwise. The weight the call did not use is
+		/// refunded.
+		///
+		/// # Parameters
+		/// - `origin`: Any signed account
+		/// - `id`: Identifier of the referendum
+		/// - `weight_limit`: Upper bound on the weight of the authorization call
+		///
+		/// # Errors
+		/// - `UnknownReferendum`: No referendum is held with the id
+		/// - `ReferendumNotEnded`: The voting period is not over
+		/// - `WeightLimitTooLow`: The weight of the approved call exceeds `weight_limit`
+		///
+		/// # Events
+		/// - `ReferendumConcluded`: Emitted with the outcome of the referendum
+		/// - `GovernanceCallEnacted`: Emitted with the result of an approved call
+		#[pallet::call_index(43)]
+		#[pallet::weight(T::WeightInfo::conclude_referendum().saturating_add(*weight_limit))]
+		pub fn conclude_referendum(
+			origin: OriginFor<T>,
+			id: u32,
+			weight_limit: Weight,
+		) -> DispatchResultWithPostInfo {
+			ensure_signed(origin)?;
+
+			let referendum = Referenda::<T>::take(id).ok_or(Error::<T>::UnknownReferendum)?;
+			let now = frame_system::Pallet::<T>::block_number();
+			ensure!(now >= referendum.ends_at, Error::<T>::ReferendumNotEnded);
+
+			let approved = referendum.ayes > referendum.nays;
+			Self::deposit_event(Event::ReferendumConcluded { id, approved });
+			if !approved {
+				T::Preimages::drop(&referendum.call);
+				return Ok(Some(T::WeightInfo::conclude_referendum()).into());
+			}
+			let call_weight = Self::enact(id, referendum.origin, &referendum.call, weight_limit)?;
+
+			Ok(Some(T::WeightInfo::conclude_referendum().saturating_add(call_weight)).into())
+		}
+
+		/// Release the tokens of an objection or referendum vote
+		///
+		/// # Parameters
+		/// - `origin`: The account that objected or voted
+		/// - `id`: Identifier of the scheduled call or referendum
+		///
+		/// # Errors
+		/// - `VoteLocked`: The authorization is still scheduled or its referendum not concluded
+		/// - `NoVote`: The account has no objection or vote on the call
+		///
+		/// # Events
+		/// - `VoteUnlocked`: Emitted with the tokens released
+		#[pallet::call_index(44)]
+		#[pallet::weight(T::WeightInfo::unlock_vote())]
+		pub fn unlock_vote(origin: OriginFor<T>, id: u32) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			ensure!(
+				!Objections::<T>::contains_key(id) && !Referenda::<T>::contains_key(id),
+				Error::<T>::VoteLocked
+			);
+			let vote = Votes::<T>::take(id, &who).ok_or(Error::<T>::NoVote)?;
+			let amount = T::Currency::release(
+				&HoldReason::GovernanceVote.into(),
+				&who,
+				vote.amount,
+				Precision::BestEffort,
+			)?;
+			Self::deposit_event(Event::VoteUnlocked { id, who, amount });
+
+			Ok(())
+		}
+
+		/// Transfer the administration of a deployed contract to another address
+		///
+		/// For when the organisation operating a contract changes hands. The new admin
+		/// becomes the recorded deployer of the contract's provenance, returned by
+		/// [`ContractProvenance::deployer_of`] and allowed to transfer it again. If the
+		/// contract is an approved factory, the account of the new admin also becomes the
+		/// factory's admin. The contract's metadata and children stay with the contract.
+		///
+		/// # Parameters
+		/// - `origin`: `AdminOrigin`, or signed by the account of the recorded deployer
+		/// - `contract`: Address of the contract
+		/// - `new_admin`: EVM address taking over the contract
+		///
+		/// # Errors
+		/// - `UnknownContract`: No provenance is recorded for the contract
+		/// - `ContractRetired`: The contract is retired
+		///
+		/// # Events
+		/// - `ContractAdminTransferred`: Emitted with the previous and new admin
+		#[pallet::call_index(45)]
+		#[pallet::weight(T::WeightInfo::transfer_contract_admin())]
+		pub fn transfer_contract_admin(
+			origin: OriginFor<T>,
+			contract: H160,
+			new_admin: H160,
+		) -> DispatchResult {
+			let signer = match T::AdminOrigin::try_origin(origin) {
+				Ok(_) => None,
+				Err(origin) => Some(ensure_signed(origin)?),
+			};
+			let mut record = Self::ensure_contract_admin(signer.as_ref(), &contract)?;
+			ensure!(!Self::is_retired(&contract), Error::<T>::ContractRetired);
+
+			let previous = record.deployer;
+			record.deployer = new_admin;
+			ContractProvenance::<T>::insert(contract, record);
+			ApprovedFactories::<T>::mutate_extant(contract, |info| {
+				info.admin = T::ContractDeployer::into_account_id(new_admin);
+			});
+			Self::deposit_event(Event::ContractAdminTransferred { contract, previous, new_admin });
+
+			Ok(())
+		}
+
+		/// Announce the retirement of a contract at the end of a grace period
+		///
+		/// Starts a grace period of `RetirementGracePeriod` blocks, halfway through which a
+		/// `ContractRetiringSoon` warning is emitted, and at the end of which a
+		/// `ContractRetirementDue` event is. The retirement then takes effect with
+		/// `finalize_retirement`.
+		///
+		/// # Parameters
+		/// - `origin`: `AdminOrigin`, or signed by the account of the recorded deployer
+		/// - `contract`: Address of the contract
+		///
+		/// # Errors
+		/// - `UnknownContract`: No provenance is recorded for the contract
+		/// - `RetirementAlreadyAnnounced`: The retirement is already announced
+		/// - `ContractRetired`: The contract is already retired
+		/// - `ExpiryScheduleFull`: Too many warnings are scheduled in the warning blocks
+		///
+		/// # Events
+		/// - `ContractRetirementAnnounced`: Emitted with the end of the grace period
+		#[pallet::call_index(46)]
+		#[pallet::weight(T::WeightInfo::announce_retirement())]
+		pub fn announce_retirement(origin: OriginFor<T>, contract: H160) -> DispatchResult {
+			let signer = match T::AdminOrigin::try_origin(origin) {
+				Ok(_) => None,
+				Err(origin) => Some(ensure_signed(origin)?),
+			};
+			Self::ensure_contract_admin(signer.as_ref(), &contract)?;
+			match Retirements::<T>::get(contract) {
+				Some(Retirement::Announced { .. }) => {
+					return Err(Error::<T>::RetirementAlreadyAnnounced.into())
+				},
+				Some(Retirement::Retired { .. }) => return Err(Error::<T>::ContractRetired.into()),
+				None => {},
+			}
+
+			let now = frame_system::Pallet::<T>::block_number();
+			let grace_period = T::RetirementGracePeriod::get();
+			let retires_at = now.saturating_add(grace_period);
+			let warning_at = now.saturating_add(grace_period / 2u32.into());
+			if warning_at > now && warning_at < retires_at {
+				Self::schedule_retirement_warning(warning_at, contract, retires_at)?;
+			}
+			Self::schedule_retirement_warning(retires_at, contract, retires_at)?;
+			Retirements::<T>::insert(contract, Retirement::Announced { retires_at });
+			Self::deposit_event(Event::ContractRetirementAnnounced { contract, retires_at });
+
+			Ok(())
+		}
+
+		/// Retire a contract whose grace period is over
+		///
+		/// Freezes the contract in the registries: an approved factory is marked
+		/// [`FactoryStatus::Retired`], which no status change lifts, and the contract's
+		/// administration can no longer be transferred.
+		///
+		/// # Parameters
+		/// - `origin`: Any signed account, or Root
+		/// - `contract`: Address of the contract
+		///
+		/// # Errors
+		/// - `RetirementNotAnnounced`: The retirement was not announced
+		/// - `RetirementNotDue`: The grace period is not over
+		/// - `ContractRetired`: The contract is already retired
+		///
+		/// # Events
+		/// - `ContractRetired`: Emitted when the contract is retired
+		#[pallet::call_index(47)]
+		#[pallet::weight(T::WeightInfo::finalize_retirement())]
+		pub fn finalize_retirement(origin: OriginFor<T>, contract: H160) -> DispatchResult {
+			ensure_signed_or_root(origin)?;
+
+			let now = frame_system::Pallet::<T>::block_number();
+			match Retirements::<T>::get(contract) {
+				Some(Retirement::Announced { retires_at }) => {
+					ensure!(now >= retires_at, Error::<T>::RetirementNotDue)
+				},
+				Some(Retirement::Retired { .. }) => return Err(Error::<T>::ContractRetired.into()),
+				None => return Err(Error::<T>::RetirementNotAnnounced.into()),
+			}
+
+			Retirements::<T>::insert(contract, Retirement::Retired { retired_at: now });
+			ApprovedFactories::<T>::mutate_extant(contract, |info| {
+				info.status = FactoryStatus::Retired;
+			});
+			Self::deposit_event(Event::ContractRetired { contract });
+
+			Ok(())
+		}
+
+		/// Assess the risk tier of an account to authorize through the approval workflow
+		///
+		/// The tier decides the approvals its authorization proposals need, see
+		/// [`ApprovalPolicy::risk_tier`]. Lowering a tier lowers the approvals needed, so the
+		/// assessment is subject to the enactment delay.
+		///
+		/// # Parameters
+		/// - `origin`: Must be Root (typically called via sudo or governance)
+		/// - `deployer`: The assessed account
+		/// - `risk_tier`: The tier of the account, `None` to treat it as the highest tier
+		///
+		/// # Errors
+		/// - `NotEnacted`: An enactment delay is set and the call was not scheduled
+		///
+		/// # Events
+		/// - `DeployerRiskTierSet`: Emitted when the tier is updated
+		#[pallet::call_index(48)]
+		#[pallet::weight(T::WeightInfo::set_deployer_risk_tier())]
+		pub fn set_deployer_risk_tier(
+			origin: OriginFor<T>,
+			deployer: T::AccountId,
+			risk_tier: Option<u8>,
+		) -> DispatchResult {
+			ensure_root(origin)?;
+			Self::ensure_enacted()?;
+
+			DeployerRiskTiers::<T>::set(&deployer, risk_tier);
+			Self::deposit_event(Event::DeployerRiskTierSet { deployer, risk_tier });
+
+			Ok(())
+		}
+	}
+
+	#[pallet::validate_unsigned]
+	impl<T: Config> ValidateUnsigned for Pallet<T> {
+		type Call = Call<T>;
+
+		fn validate_unsigned(_source: TransactionSource, call: &Self::Call) -> TransactionValidity {
+			let Call::report_rejected_attempts { deployer } = call else {
+				return InvalidTransaction::Call.into();
+			};
+
+			Self::ensure_reportable(deployer).map_err(|_| InvalidTransaction::Stale)?;
+
+			ValidTransaction::with_tag_prefix("EvmDeploymentControlAlert")
+				.priority(T::UnsignedPriority::get())
+				.and_provides(deployer)
+				.longevity(5)
+				.propagate(true)
+				.build()
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// Check if an account is authorized to deploy EVM contracts
+		///
+		/// This is the primary query interface used by the EVM's deployment control logic.
+		/// It runs for every contract creation in transaction validation, so it is kept to a
+		/// single existence check of `AuthorizedDeployers` that does not decode the record,
+		/// see the `is_authorized_check` benchmark. The result is memoized in
+		/// [`AuthorizationCache`] for the rest of the block while the cache has room.
+		///
+		/// # Parameters
+		/// - `account`: The account to check
+		///
+		/// # Returns
+		/// `true` if the account is authorized, `false` otherwise
+		pub fn is_authorized(account: &T::AccountId) -> bool {
+			if let Some(authorized) = AuthorizationCache::<T>::get(account) {
+				return authorized;
+			}
+
+			let authorized = AuthorizedDeployers::<T>::contains_key(account);
+			let cached = CachedAuthorizations::<T>::get();
+			if cached < T::MaxCachedAuthorizations::get() {
+				AuthorizationCache::<T>::insert(account, authorized);
+				CachedAuthorizations::<T>::put(cached.saturating_add(1));
+			}
+			authorized
+		}
+
+		/// Check if an account may deploy a contract in the current block
+		///
+		/// Combines the deployment policy with the deployment window schedule.
+		/// This is what the runtime's enforcement points should call. Authorization is
+		/// checked first so that authorized deployers never read the policy; the worst
+		/// case for them is measured by the `ensure_can_deploy` benchmark.
+		///
+		/// Runs within an `ensure_can_deploy` span of [`TRACING_TARGET`], closed by an event
+		/// with the decision, the reason of a refusal and the weight of the check.
+		///
+		/// # Errors
+		/// The [`DeploymentValidationError`] explaining why the deployment is refused
+		pub fn ensure_can_deploy(
+			account: &T::AccountId,
+		) -> Result<(), crate::DeploymentValidationError> {
+			sp_tracing::enter_span!(sp_tracing::trace_span!(
+				target: TRACING_TARGET,
+				"ensure_can_deploy"
+			));
+			let decision = Self::check_can_deploy(account);
+
+			let cost = T::WeightInfo::ensure_can_deploy(T::MaxDeploymentWindows::get()).ref_time();
+			match decision {
+				Ok(()) => sp_tracing::event!(
+					target: TRACING_TARGET,
+					sp_tracing::Level::TRACE,
+					decision = "admitted",
+					cost
+				),
+				Err(err) => sp_tracing::event!(
+					target: TRACING_TARGET,
+					sp_tracing::Level::TRACE,
+					decision = "refused",
+					reason = err.description(),
+					code = u8::from(err),
+					cost
+				),
+			}
+
+			decision
+		}
+
+		/// Deployment policy and window checks of [`Self::ensure_can_deploy`]
+		///
+		/// Refusals of flagged accounts are escalated to `FlaggedDeployer`, which the
+		/// enforcement points refuse at validation instead of admitting and counting the
+		/// attempt.
+		fn check_can_deploy(
+			account: &T::AccountId,
+		) -> Result<(), crate::DeploymentValidationError> {
+			Self::ensure_permitted(account).map_err(|err| match err {
+				crate::DeploymentValidationError::UnauthorizedDeployer
+					if Self::is_flagged(account) =>
+					crate::DeploymentValidationError::FlaggedDeployer,
+				err => err,
+			})?;
+
+			if !Self::is_within_deployment_window(frame_system::Pallet::<T>::block_number()) &&
+				!WindowOverrides::<T>::contains_key(account)
+			{
+				return Err(crate::DeploymentValidationError::OutsideDeploymentWindow);
+			}
+
+			ensure!(
+				Self::remaining_quota(account) != Some(0),
+				crate::DeploymentValidationError::QuotaExhausted
+			);
+
+			Ok(())
+		}
+
+		/// Check if the deployment policy lets an account deploy contracts
+		///
+		/// Authorized deployers always may. Under a permissionless policy every account may,
+		/// provided it can pay the deployment fee if one is charged. Under a rollout, the
+		/// account may if it is drawn in the current block.
+		///
+		/// # Errors
+		/// The [`DeploymentValidationError`] explaining why the deployment is refused
+		pub fn ensure_permitted(
+			account: &T::AccountId,
+		) -> Result<(), crate::DeploymentValidationError> {
+			if Self::is_authorized(account) {
+				return Ok(());
+			}
+
+			match EvmDeploymentPolicy::<T>::get() {
+				DeploymentPolicy::Permissioned =>
+					Err(crate::DeploymentValidationError::UnauthorizedDeployer),
+				DeploymentPolicy::Permissionless => Ok(()),
+				DeploymentPolicy::PermissionlessWithFee { fee } => {
+					let available = T::Currency::reducible_balance(
+						account,
+						Preservation::Preserve,
+						Fortitude::Polite,
+					);
+					ensure!(
+						available >= fee,
+						crate::DeploymentValidationError::InsufficientDeploymentFee
+					);
+					Ok(())
+				},
+				DeploymentPolicy::Rollout { admitted } => {
+					ensure!(
+						Self::is_drawn_for_rollout(account, admitted),
+						crate::DeploymentValidationError::UnauthorizedDeployer
+					);
+					Ok(())
+				},
+			}
+		}
+
+		/// Check if the contract creations of an account are admitted by a rollout in the
+		/// current block
+		///
+		/// Draws a number below 100 from the account and the parent block hash and admits
+		/// the account if it falls within the admitted share.
+		pub fn is_drawn_for_rollout(account: &T::AccountId, admitted: Percent) -> bool {
+			let parent_hash = frame_system::Pallet::<T>::parent_hash();
+			let seed =
+				(b"qnch/rollout", account, parent_hash).using_encoded(sp_io::hashing::blake2_256);
+			let mut draw = [0u8; 8];
+			draw.copy_from_slice(&seed[..8]);
+			u64::from_le_bytes(draw) % 100 < u64::from(admitted.deconstruct())
+		}
+
+		/// Burn the deployment fee from an account that is not an authorized deployer
+		///
+		/// Called by the runtime's enforcement points once a contract creation is admitted.
+		/// Does nothing unless the policy charges a fee.
+		pub fn charge_deployment_fee(account: &T::AccountId) -> DispatchResult {
+			let DeploymentPolicy::PermissionlessWithFee { fee } = EvmDeploymentPolicy::<T>::get()
+			else {
+				return Ok(());
+			};
+			if fee.is_zero() || Self::is_authorized(account) {
+				return Ok(());
+			}
+
+			// Held first so that the fee cannot dip into the existential deposit
+			let reason = HoldReason::DeploymentFee.into();
+			T::Currency::hold(&reason, account, fee)
+				.map_err(|_| Error::<T>::InsufficientDeploymentFee)?;
+			let (credit, _) = <T::Currency as BalancedHold<_>>::slash(&reason, account, fee);
+			T::DeploymentProceeds::on_unbalanced(credit);
+			Self::deposit_event(Event::DeploymentFeePaid { deployer: account.clone(), fee });
+
+			Ok(())
+		}
+
+		/// Deployment policy in effect
+		pub fn deployment_policy() -> DeploymentPolicy<BalanceOf<T>> {
+			EvmDeploymentPolicy::<T>::get()
+		}
+
+		/// Check if deployments are open at the given block
+		///
+		/// # Returns
+		/// `true` if no window is configured or one of the windows contains the block
+		pub fn is_within_deployment_window(block_number: BlockNumberFor<T>) -> bool {
+			let windows = DeploymentWindows::<T>::get();
+			windows.is_empty() || windows.iter().any(|window| window.contains(block_number))
+		}
+
+		/// Record a rejected deployment attempt for an account
+		///
+		/// Called whenever a deployment is refused outside a failing dispatch, whose
+		/// storage changes would be rolled back with the attempt. Attempts are
+		/// counted per `RejectedAttemptWindow`; a new window starts on the first
+		/// attempt after the previous one expired. The refusal is also counted in the
+		/// block's [`DeploymentDecisions`].
+		pub fn note_rejected_attempt(account: &T::AccountId) {
+			let now = frame_system::Pallet::<T>::block_number();
+
+			DeniedThisBlock::<T>::mutate(|denied| match denied {
+				Some((count, _)) => count.saturating_inc(),
+				None => *denied = Some((1, account.clone())),
+			});
+
+			RejectedAttempts::<T>::mutate(account, |entry| {
+				if !entry.as_ref().map_or(false, |info| Self::window_open(info, now)) {
+					*entry = Some(RejectedAttemptInfo { count: 0, window_start: now });
+				}
+				if let Some(info) = entry {
+					info.count = info.count.saturating_add(1);
+				}
+			});
+		}
+
+		/// Number of rejected deployment attempts of an account in the current window
+		pub fn rejected_attempts(account: &T::AccountId) -> u32 {
+			let now = frame_system::Pallet::<T>::block_number();
+
+			RejectedAttempts::<T>::get(account)
+				.filter(|info| Self::window_open(info, now))
+				.map_or(0, |info| info.count)
+		}
+
+		/// Register a contract creation in the current block
+		///
+		/// Called by the runtime before a contract creation is applied.
+		///
+		/// # Returns
+		/// `false` if the per-block creation cap has been reached, in which case the
+		/// creation must not be included in this block
+		pub fn try_register_create() -> bool {
+			let count = CreatesThisBlock::<T>::get();
+
+			if MaxCreatesPerBlock::<T>::get().map_or(false, |limit| count >= limit) {
+				return false;
+			}
+
+			CreatesThisBlock::<T>::put(count.saturating_add(1));
+			true
+		}
+
+		/// Record the provenance of a newly created contract
+		///
+		/// Called through [`DeploymentLifecycle::on_deployed`] whenever the EVM creates a
+		/// contract. Creations by an approved factory are added to its children count,
+		/// announced creations are linked to their intent, and every creation is traced for
+		/// the offchain index.
+		pub fn note_contract_created(deployer: H160, contract: H160) {
+			let block = frame_system::Pallet::<T>::block_number();
+			ContractProvenance::<T>::insert(contract, ProvenanceRecord { deployer, block });
+			ApprovedFactories::<T>::mutate_extant(deployer, |info| {
+				info.children = info.children.saturating_add(1);
+			});
+			CreationTraces::<T>::append(CreationTrace {
+				extrinsic_index: frame_system::Pallet::<T>::extrinsic_index().unwrap_or_default(),
+				creator: deployer,
+				contract,
+			});
+
+			if let Some((account, code_hash)) = PendingIntent::<T>::take() {
+				if T::ContractDeployer::into_account_id(deployer) == account {
+					Self::fulfill_intent(account, code_hash, contract);
+				}
+			}
+		}
+
+		/// Note that the EVM created `contract` from `owner`, while the opcode policy limits
+		/// the nesting of contract creations
+		///
+		/// The EVM announces a creation once its init code has run, so the creations nested
+		/// in `contract` are already noted: `contract` sits one level above the deepest of
+		/// them. Reverted creations are rolled back with the EVM substate that noted them.
+		pub fn note_nested_creation(owner: H160, contract: H160) {
+			if !EvmOpcodePolicy::<T>::get().limit_create_depth {
+				return;
+			}
+
+			let depth = NestedCreationDepth::<T>::take(contract).unwrap_or(0).saturating_add(1);
+			if depth > T::MaxCreateDepth::get() {
+				TooDeepCreation::<T>::put(contract);
+			}
+			NestedCreationDepth::<T>::mutate(owner, |deepest| {
+				*deepest = Some(deepest.unwrap_or(0).max(depth));
+			});
+		}
+
+		/// Take the contract created deeper than `MaxCreateDepth` since the last call, if any
+		///
+		/// Called by the runtime once an EVM execution ends, which also forgets the depths
+		/// noted during it.
+		pub fn take_too_deep_creation() -> Option<H160> {
+			let _ = NestedCreationDepth::<T>::clear(u32::MAX, None);
+			TooDeepCreation::<T>::take()
+		}
+
+		/// Note the init code of a contract creation about to be applied
+		///
+		/// Called by the runtime before a contract creation runs, so that the created
+		/// contract can be linked to the deployer's intent for the same init code, if any.
+		pub fn note_pending_creation(deployer: &T::AccountId, code_hash: H256) {
+			if DeploymentIntents::<T>::contains_key(deployer, code_hash) {
+				PendingIntent::<T>::put((deployer.clone(), code_hash));
+			}
+		}
+
+		/// Announced deployment intent of a deployer
+		pub fn deployment_intent(
+			deployer: &T::AccountId,
+			code_hash: &H256,
+		) -> Option<DeploymentIntent<T>> {
+			DeploymentIntents::<T>::get(deployer, code_hash)
+		}
+
+		/// Init code hashes of the deployment intents a deployer announced and has not
+		/// fulfilled yet
+		pub fn announced_intents(deployer: &T::AccountId) -> Vec<H256> {
+			DeploymentIntents::<T>::iter_key_prefix(deployer).collect()
+		}
+
+		/// Link a created contract to the intent it fulfills and release the bond
+		fn fulfill_intent(deployer: T::AccountId, code_hash: H256, contract: H160) {
+			let Some(intent) = DeploymentIntents::<T>::take(&deployer, code_hash) else {
+				return;
+			};
+
+			if let Err(err) = T::Currency::release(
+				&HoldReason::DeploymentIntent.into(),
+				&deployer,
+				intent.bond,
+				Precision::BestEffort,
+			) {
+				log::warn!(target: LOG_TARGET, "Failed to release intent bond: {:?}", err);
+			}
+			FulfilledIntents::<T>::insert(contract, (code_hash, intent));
+			Self::deposit_event(Event::DeploymentIntentFulfilled { deployer, code_hash, contract });
+		}
+
+		/// Record of an approved factory
+		pub fn factory(factory: &H160) -> Option<FactoryInfo<T::AccountId, BlockNumberFor<T>>> {
+			ApprovedFactories::<T>::get(factory)
+		}
+
+		/// Approved factories in address order
+		///
+		/// Returns at most `limit` factories, starting after `start_after` if given.
+		pub fn factories(
+			start_after: Option<H160>,
+			limit: u32,
+		) -> Vec<(H160, FactoryInfo<T::AccountId, BlockNumberFor<T>>)> {
+			let iter = match start_after {
+				Some(start) =>
+					ApprovedFactories::<T>::iter_from(ApprovedFactories::<T>::hashed_key_for(start)),
+				None => ApprovedFactories::<T>::iter(),
+			};
+			iter.take(limit as usize).collect()
+		}
+
+		/// Registry changes a batch of governance calls would make
+		///
+		/// Dispatches the calls in order with the Root origin and rolls every change back
+		/// afterwards, so that governance UIs can show voters the exact effect of a proposal.
+		/// Dispatch stops at the first failing call, whose index and error are returned along
+		/// with the changes made by the calls before it.
+		pub fn preview_calls(
+			calls: Vec<Call<T>>,
+		) -> (RegistryDiff<T::AccountId>, Option<(u32, DispatchError)>) {
+			let deployers: BTreeSet<_> = AuthorizedDeployers::<T>::iter_keys().collect();
+			let factories: BTreeSet<_> = ApprovedFactories::<T>::iter_keys().collect();
+
+			let preview = with_transaction(|| {
+				// Previewed calls apply as if enacted after the enactment delay
+				Enacting::<T>::put(());
+				let failure = calls.into_iter().enumerate().find_map(|(index, call)| {
+					call.dispatch_bypass_filter(frame_system::RawOrigin::Root.into())
+						.err()
+						.map(|err| (index as u32, err.error))
+				});
+
+				let deployers_after: BTreeSet<_> = AuthorizedDeployers::<T>::iter_keys().collect();
+				let factories_after: BTreeSet<_> = ApprovedFactories::<T>::iter_keys().collect();
+				let diff = RegistryDiff {
+					added_deployers: deployers_after.difference(&deployers).cloned().collect(),
+					removed_deployers: deployers.difference(&deployers_after).cloned().collect(),
+					added_factories: factories_after.difference(&factories).copied().collect(),
+					removed_factories: factories.difference(&factories_after).copied().collect(),
+				};
+
+				TransactionOutcome::Rollback(Ok::<_, DispatchError>((diff, failure)))
+			});
+
+			// Only fails when nested too deep in storage transactions
+			preview.unwrap_or_else(|err| (RegistryDiff::default(), Some((0, err))))
+		}
+
+		/// Scheduled governance call `id`
+		pub fn governance_call(id: u32) -> Option<GovernanceCall<T>> {
+			GovernanceCalls::<T>::get(id)
+		}
+
+		/// Whether `call`, or a call nested in it, authorizes deployers
+		fn authorizes_deployers(call: &<T as Config>::RuntimeCall) -> bool {
+			let mut calls = sp_std::vec![call];
+			while let Some(call) = calls.pop() {
+				match call.is_sub_type() {
+					Some(
+						Call::authorize_deployer { .. } |
+						Call::authorize_contract_wallet { .. } |
+						Call::execute_authorization { .. },
+					) => return true,
+					Some(Call::dispatch_as_deployer_admin { call }) => calls.push(call.as_ref()),
+					_ => calls.extend(T::NestedCalls::nested_calls(call)),
+				}
+			}
+			false
+		}
+
+		/// Ensure that a governance call subject to the enactment delay is being enacted
+		///
+		/// Passes at once while no enactment delay is set.
+		fn ensure_enacted() -> DispatchResult {
+			ensure!(
+				EnactmentDelay::<T>::get().is_zero() || Enacting::<T>::exists(),
+				Error::<T>::NotEnacted
+			);
+			Ok(())
+		}
+
+		/// Schedule a governance call for enactment after the enactment delay, dispatched
+		/// with `origin`
+		///
+		/// Returns the identifier of the scheduled call.
+		fn do_schedule(
+			origin: T::PalletsOrigin,
+			call: <T as Config>::RuntimeCall,
+		) -> Result<u32, DispatchError> {
+			let pending = PendingGovernanceCalls::<T>::get();
+			ensure!(pending < T::MaxGovernanceCalls::get(), Error::<T>::TooManyGovernanceCalls);
+
+			let objectable = Self::authorizes_deployers(&call);
+			let call = T::Preimages::bound(call)?;
+			let call_hash = call.hash();
+			let now = frame_system::Pallet::<T>::block_number();
+			let enact_at = now.saturating_add(EnactmentDelay::<T>::get());
+			let id = NextGovernanceCallId::<T>::mutate(|next| {
+				let id = *next;
+				next.saturating_inc();
+				id
+			});
+			GovernanceCalls::<T>::insert(id, GovernanceCall { origin, call, enact_at });
+			PendingGovernanceCalls::<T>::put(pending.saturating_add(1));
+			if objectable {
+				Objections::<T>::insert(id, BalanceOf::<T>::zero());
+			}
+			Self::deposit_event(Event::GovernanceCallScheduled { id, call_hash, enact_at });
+
+			Ok(id)
+		}
+
+		/// Take a governance call off the schedule, closing its objections
+		///
+		/// The preimage of the call is left to the caller.
+		fn remove_governance_call(id: u32) {
+			GovernanceCalls::<T>::remove(id);
+			Objections::<T>::remove(id);
+			PendingGovernanceCalls::<T>::mutate(|pending| pending.saturating_dec());
+		}
+
+		/// Dispatch a governance call with the origin that scheduled it and drop its preimage
+		///
+		/// Returns the weight used by the call.
+		fn enact(
+			id: u32,
+			origin: T::PalletsOrigin,
+			bounded: &BoundedCallOf<T>,
+			weight_limit: Weight,
+		) -> Result<Weight, DispatchError> {
+			let (call, _) = T::Preimages::peek::<<T as Config>::RuntimeCall>(bounded)
+				.map_err(|_| Error::<T>::UnknownGovernanceCall)?;
+			let info = call.get_dispatch_info();
+			ensure!(info.weight.all_lte(weight_limit), Error::<T>::WeightLimitTooLow);
+
+			T::Preimages::drop(bounded);
+			Enacting::<T>::put(());
+			let origin: <T as Config>::RuntimeOrigin = origin.into();
+			let result = call.dispatch(origin.into());
+			Enacting::<T>::kill();
+			let call_weight = extract_actual_weight(&result, &info);
+			let result = result.map(|_| ()).map_err(|err| err.error);
+			Self::deposit_event(Event::GovernanceCallEnacted { id, result });
+
+			Ok(call_weight)
+		}
+
+		/// Lock `amount` of `who` for its objection or vote on governance call `id`
+		fn lock_vote(
+			id: u32,
+			who: &T::AccountId,
+			aye: bool,
+			amount: BalanceOf<T>,
+		) -> DispatchResult {
+			Votes::<T>::try_mutate(id, who, |vote| {
+				let vote = vote.get_or_insert(Vote { aye, amount: Zero::zero() });
+				ensure!(vote.aye == aye, Error::<T>::ConflictingVote);
+				vote.amount.saturating_accrue(amount);
+				Ok::<_, DispatchError>(())
+			})?;
+			T::Currency::hold(&HoldReason::GovernanceVote.into(), who, amount)
+		}
+
+		/// Ensure a factory is approved, active and holds a permission
+		pub fn ensure_factory_permission(
+			factory: &H160,
+			permission: FactoryPermissions,
+		) -> Result<(), Error<T>> {
+			let info = ApprovedFactories::<T>::get(factory).ok_or(Error::<T>::UnknownFactory)?;
+			ensure!(info.status == FactoryStatus::Active, Error::<T>::FactorySuspended);
+			ensure!(
+				FactoryPermissionsOf::<T>::get(factory).contains(permission),
+				Error::<T>::MissingFactoryPermission
+			);
+
+			Ok(())
+		}
+
+		/// Register the metadata of a contract created by a factory
+		///
+		/// Called by the runtime's factory precompile on behalf of the factory, typically
+		/// within the transaction creating the contract.
+		///
+		/// # Errors
+		/// - `UnknownFactory`, `FactorySuspended`, `MissingFactoryPermission`: The factory
+		///   may not register metadata
+		/// - `NotFactoryChild`: The contract was not created by the factory
+		/// - `MetadataAlreadyRegistered`: The contract already has metadata
+		pub fn register_child_metadata(
+			factory: H160,
+			contract: H160,
+			metadata: ContractMetadata<T::MaxMetadataLen>,
+		) -> Result<(), Error<T>> {
+			Self::ensure_factory_permission(&factory, FactoryPermissions::REGISTER_METADATA)?;
+			ensure!(
+				Self::provenance(&contract).map_or(false, |record| record.deployer == factory),
+				Error::<T>::NotFactoryChild
+			);
+			ensure!(
+				!ContractMetadataOf::<T>::contains_key(contract),
+				Error::<T>::MetadataAlreadyRegistered
+			);
+
+			ContractMetadataOf::<T>::insert(contract, &metadata);
+			Self::deposit_event(Event::ChildMetadataRegistered { factory, contract, metadata });
+
+			Ok(())
+		}
+
+		/// Revoke the genesis deployers whose authorization lapses by block `n`, with their role
+		///
+		/// Deployers revoked since, or authorized anew, are left alone.
+		///
+		/// # Returns
+		/// The number of lapsed entries
+		pub(crate) fn process_deployer_expiries(n: BlockNumberFor<T>) -> u32 {
+			let expiries = DeployerExpiries::<T>::get();
+			if expiries.iter().all(|(_, expiry)| *expiry > n) {
+				return 0;
+			}
+
+			let (lapsed, pending): (Vec<_>, Vec<_>) =
+				expiries.into_iter().partition(|(_, expiry)| *expiry <= n);
+			for (deployer, _) in &lapsed {
+				let from_genesis = AuthorizedDeployers::<T>::get(deployer)
+					.is_some_and(|info| info.flags.contains(DeployerFlags::GENESIS));
+				if from_genesis {
+					DeployerAdmins::<T>::remove(deployer);
+					Self::do_revoke(deployer.clone());
+				}
+			}
+			DeployerExpiries::<T>::put(BoundedVec::truncate_from(pending));
+
+			lapsed.len() as u32
+		}
+
+		/// Block at which the approval of a factory expires, if it expires
+		pub fn factory_expiry(factory: &H160) -> Option<BlockNumberFor<T>> {
+			FactoryExpiry::<T>::get(factory)
+		}
+
+		/// Process the factory expiry events scheduled in a block
+		///
+		/// # Returns
+		/// The number of processed entries, stale ones included
+		pub(crate) fn process_factory_expiries(n: BlockNumberFor<T>) -> u32 {
+			let scheduled = FactoryExpirySchedule::<T>::take(n);
+
+			for (factory, expires_at) in &scheduled {
+				if FactoryExpiry::<T>::get(factory) != Some(*expires_at) {
+					continue;
+				}
+
+				if n < *expires_at {
+					Self::deposit_event(Event::FactoryExpiringSoon {
+						factory: *factory,
+						expires_at: *expires_at,
+					});
+				} else {
+					FactoryExpiry::<T>::remove(factory);
+					ApprovedFactories::<T>::mutate_extant(factory, |info| {
+						if info.status == FactoryStatus::Active {
+							info.status = FactoryStatus::Suspended;
+						}
+					});
+					Self::deposit_event(Event::FactoryExpired { factory: *factory });
+				}
+			}
+
+			scheduled.len() as u32
+		}
+
+		/// Whether a contract is retired
+		pub fn is_retired(contract: &H160) -> bool {
+			matches!(Retirements::<T>::get(contract), Some(Retirement::Retired { .. }))
+		}
+
+		/// Retirement of a contract, if it was announced
+		pub fn retirement(contract: &H160) -> Option<Retirement<BlockNumberFor<T>>> {
+			Retirements::<T>::get(contract)
+		}
+
+		/// Provenance record of a contract, if `signer` is the account of its recorded
+		/// deployer or `None` for the admin origin
+		fn ensure_contract_admin(
+			signer: Option<&T::AccountId>,
+			contract: &H160,
+		) -> Result<ProvenanceRecord<BlockNumberFor<T>>, DispatchError> {
+			let record =
+				ContractProvenance::<T>::get(contract).ok_or(Error::<T>::UnknownContract)?;
+			if let Some(who) = signer {
+				ensure!(
+					*who == T::ContractDeployer::into_account_id(record.deployer),
+					DispatchError::BadOrigin
+				);
+			}
+			Ok(record)
+		}
+
+		/// Emit the retirement warnings scheduled in a block
+		///
+		/// # Returns
+		/// The number of processed entries, stale ones included
+		pub(crate) fn process_retirement_warnings(n: BlockNumberFor<T>) -> u32 {
+			let scheduled = RetirementWarnings::<T>::take(n);
+
+			for (contract, retires_at) in &scheduled {
+				let announced = Retirement::Announced { retires_at: *retires_at };
+				if Retirements::<T>::get(contract) != Some(announced) {
+					continue;
+				}
+
+				if n < *retires_at {
+					Self::deposit_event(Event::ContractRetiringSoon {
+						contract: *contract,
+						retires_at: *retires_at,
+					});
+				} else {
+					Self::deposit_event(Event::ContractRetirementDue { contract: *contract });
+				}
+			}
+
+			scheduled.len() as u32
+		}
+
+		/// Schedule a retirement warning in a block
+		fn schedule_retirement_warning(
+			block: BlockNumberFor<T>,
+			contract: H160,
+			retires_at: BlockNumberFor<T>,
+		) -> Result<(), Error<T>> {
+			RetirementWarnings::<T>::try_mutate(block, |scheduled| {
+				scheduled
+					.try_push((contract, retires_at))
+					.map_err(|_| Error::<T>::ExpiryScheduleFull)
+			})
+		}
+
+		/// Schedule a factory expiry event in a block
+		fn schedule_expiry_event(
+			block: BlockNumberFor<T>,
+			factory: H160,
+			expires_at: BlockNumberFor<T>,
+		) -> Result<(), Error<T>> {
+			FactoryExpirySchedule::<T>::try_mutate(block, |scheduled| {
+				scheduled
+					.try_push((factory, expires_at))
+					.map_err(|_| Error::<T>::ExpiryScheduleFull)
+			})
+		}
+
+		/// Number of contract creations still allowed in the current block
+		///
+		/// # Returns
+		/// `None` if creations are not rate limited
+		pub fn remaining_creates() -> Option<u32> {
+			MaxCreatesPerBlock::<T>::get()
+				.map(|limit| limit.saturating_sub(CreatesThisBlock::<T>::get()))
+		}
+
+		/// Index of the quota period containing block `n`
+		pub fn quota_period(n: BlockNumberFor<T>) -> BlockNumberFor<T> {
+			n / T::QuotaPeriodLength::get().max(One::one())
+		}
+
+		/// First block of the quota period following the one containing block `n`
+		pub fn quota_resets_at(n: BlockNumberFor<T>) -> BlockNumberFor<T> {
+			Self::quota_period(n)
+				.saturating_add(One::one())
+				.saturating_mul(T::QuotaPeriodLength::get().max(One::one()))
+		}
+
+		/// Number of contract creations still allowed to `account` in the current quota period
+		///
+		/// # Returns
+		/// `None` if deployers are not subject to a quota
+		pub fn remaining_quota(account: &T::AccountId) -> Option<u32> {
+			let quota = DeploymentQuota::<T>::get()?;
+			let period = Self::quota_period(frame_system::Pallet::<T>::block_number());
+			let used = DeploymentQuotaUsage::<T>::get(account)
+				.filter(|usage| usage.period == period)
+				.map_or(0, |usage| usage.used);
+
+			Some(quota.saturating_sub(used))
+		}
+
+		/// Count a contract creation of `account` against its quota
+		///
+		/// Called by the runtime before a contract creation is applied. A count left from an
+		/// earlier period is reset first, see the `use_deployment_quota` benchmark.
+		///
+		/// # Returns
+		/// `false` if the account used up its quota of the current period, in which case the
+		/// creation must not be applied
+		pub fn try_use_quota(account: &T::AccountId) -> bool {
+			let Some(quota) = DeploymentQuota::<T>::get() else {
+				return true;
+			};
+			let period = Self::quota_period(frame_system::Pallet::<T>::block_number());
+
+			DeploymentQuotaUsage::<T>::mutate(account, |usage| {
+				let usage = usage.get_or_insert_with(Default::default);
+				if usage.period != period {
+					*usage = QuotaUsage { period, used: 0 };
+				}
+				if usage.used >= quota {
+					return false;
+				}
+				usage.used.saturating_inc();
+				true
+			})
+		}
+
+		/// Metadata blob of an approved factory
+		///
+		/// Returns `None` if the factory has no metadata or its preimage is unavailable.
+		pub fn factory_metadata(factory: &H160) -> Option<Vec<u8>> {
+			FactoryMetadata::<T>::get(factory).and_then(|metadata| Self::peek_metadata(&metadata))
+		}
+
+		/// Metadata blob of an authorized deployer
+		///
+		/// Returns `None` if the deployer has no metadata or its preimage is unavailable.
+		pub fn deployer_metadata(deployer: &T::AccountId) -> Option<Vec<u8>> {
+			DeployerMetadata::<T>::get(deployer).and_then(|metadata| Self::peek_metadata(&metadata))
+		}
+
+		/// Metadata registered with a contract deployed through `deploy_contract`
+		pub fn contract_metadata(contract: &H160) -> Option<ContractMetadata<T::MaxMetadataLen>> {
+			ContractMetadataOf::<T>::get(contract)
+		}
+
+		/// Provenance record of a contract, if one was recorded
+		pub fn provenance(contract: &H160) -> Option<ProvenanceRecord<BlockNumberFor<T>>> {
+			ContractProvenance::<T>::get(contract)
+		}
+
+		/// EVM opcode policy in effect
+		pub fn opcode_policy() -> OpcodePolicy {
+			EvmOpcodePolicy::<T>::get()
+		}
+
+		/// Queue a cleanup task for processing in `on_idle`
+		///
+		/// Lets the runtime and other deployment pallets defer storage cleanup instead of
+		/// running it inline.
+		///
+		/// # Errors
+		/// `CleanupQueueFull` if `MaxQueuedCleanups` tasks are already queued
+		pub fn queue_cleanup(task: CleanupTask<T::AccountId>) -> Result<(), Error<T>> {
+			let head = CleanupQueueHead::<T>::get();
+			let tail = CleanupQueueTail::<T>::get();
+			ensure!(
+				tail.wrapping_sub(head) < T::MaxQueuedCleanups::get(),
+				Error::<T>::CleanupQueueFull
+			);
+
+			CleanupQueue::<T>::insert(tail, task);
+			CleanupQueueTail::<T>::put(tail.wrapping_add(1));
+
+			Ok(())
+		}
+
+		/// Number of cleanup tasks waiting in the queue
+		pub fn queued_cleanups() -> u32 {
+			CleanupQueueTail::<T>::get().wrapping_sub(CleanupQueueHead::<T>::get())
+		}
+
+		/// Execute a single cleanup task
+		///
+		/// Tasks re-check their condition, so stale tasks are harmless no-ops.
+		pub(crate) fn process_cleanup_task(task: CleanupTask<T::AccountId>) {
+			match task {
+				CleanupTask::RevokedOverride(account) => {
+					if !Self::is_authorized(&account) {
+						WindowOverrides::<T>::remove(&account);
+					}
+				},
+				CleanupTask::ExpiredRejectedAttempts(account) => {
+					if Self::is_flagged(&account) {
+						return;
+					}
+					let now = frame_system::Pallet::<T>::block_number();
+					if RejectedAttempts::<T>::get(&account)
+						.map_or(false, |info| !Self::window_open(&info, now))
+					{
+						RejectedAttempts::<T>::remove(&account);
+					}
+				},
+				CleanupTask::Provenance(contract) => ContractProvenance::<T>::remove(contract),
+			}
+		}
+
+		/// Check if an account has been flagged for repeated unauthorized attempts
+		pub fn is_flagged(account: &T::AccountId) -> bool {
+			FlaggedDeployers::<T>::contains_key(account)
+		}
+
+		/// Ensure an account can be flagged, returning its current attempt count
+		fn ensure_reportable(account: &T::AccountId) -> Result<u32, Error<T>> {
+			ensure!(!Self::is_flagged(account), Error::<T>::AlreadyFlagged);
+
+			let attempts = Self::rejected_attempts(account);
+			ensure!(
+				attempts >= T::RejectedAttemptThreshold::get(),
+				Error::<T>::BelowAlertThreshold
+			);
+
+			Ok(attempts)
+		}
+
+		/// Pending authorization proposal
+		pub fn authorization_proposal(id: u32) -> Option<AuthorizationProposal<T>> {
+			AuthorizationProposals::<T>::get(id)
+		}
+
+		/// Approve a factory, notifying the lifecycle subscribers
+		fn do_register_factory(factory: H160, admin: T::AccountId) -> Result<(), Error<T>> {
+			ensure!(
+				!ApprovedFactories::<T>::contains_key(factory),
+				Error::<T>::FactoryAlreadyRegistered
+			);
+
+			ApprovedFactories::<T>::insert(
+				factory,
+				FactoryInfo {
+					admin: admin.clone(),
+					children: 0,
+					status: FactoryStatus::Active,
+					registered_at: frame_system::Pallet::<T>::block_number(),
+				},
+			);
+			T::Lifecycle::on_factory_registered(&factory, &admin);
+			Self::deposit_event(Event::FactoryRegistered { factory, admin });
+
+			Ok(())
+		}
+
+		/// Set the status of an approved factory, unless it is retired
+		fn do_set_factory_status(factory: H160, status: FactoryStatus) -> DispatchResult {
+			ensure!(!Self::is_retired(&factory), Error::<T>::ContractRetired);
+			ensure!(status != FactoryStatus::Retired, Error::<T>::RetirementNotAnnounced);
+			ApprovedFactories::<T>::try_mutate(factory, |info| {
+				let info = info.as_mut().ok_or(Error::<T>::UnknownFactory)?;
+				ensure!(info.status != FactoryStatus::Retired, Error::<T>::ContractRetired);
+				info.status = status;
+				Ok::<_, Error<T>>(())
+			})?;
+			Self::deposit_event(Event::FactoryStatusSet { factory, status });
+
+			Ok(())
+		}
+
+		/// The tenant `tenant`, if `who` administers it
+		fn tenant_of_admin(
+			who: &T::AccountId,
+			tenant: u32,
+		) -> Result<TenantInfo<T::AccountId>, Error<T>> {
+			let info = Tenants::<T>::get(tenant).ok_or(Error::<T>::UnknownTenant)?;
+			ensure!(*who == info.admin, Error::<T>::NotTenantAdmin);
+			Ok(info)
+		}
+
+		/// Tenant of the factory registry
+		pub fn tenant(tenant: u32) -> Option<TenantInfo<T::AccountId>> {
+			Tenants::<T>::get(tenant)
+		}
+
+		/// Authorize an account, recording the current block
+		fn do_authorize(deployer: T::AccountId) -> Result<(), Error<T>> {
+			ensure!(!Self::is_authorized(&deployer), Error::<T>::AlreadyAuthorized);
+
+			let authorized_at = frame_system::Pallet::<T>::block_number();
+			AuthorizedDeployers::<T>::insert(&deployer, DeployerInfo::new(authorized_at));
+			Self::forget_cached_authorization(&deployer);
+			Self::record_transition(&deployer, true);
+			T::Lifecycle::on_authorized(&deployer);
+			Self::deposit_event(Event::DeployerAuthorized { deployer });
+
+			Ok(())
+		}
+
+		/// Authorize an account on behalf of the authority relayed by [`DeployerRegistry`]
+		///
+		/// While an enactment delay is set, the authorization is scheduled as a Root
+		/// governance call instead, open to objections like any other.
+		pub(crate) fn relay_authorization(deployer: T::AccountId) -> DispatchResult {
+			if Self::ensure_enacted().is_ok() {
+				return Ok(Self::do_authorize(deployer)?);
+			}
+			ensure!(!Self::is_authorized(&deployer), Error::<T>::AlreadyAuthorized);
+
+			let origin: OriginFor<T> = frame_system::RawOrigin::Root.into();
+			let origin = <T as Config>::RuntimeOrigin::from(origin).caller().clone();
+			let call = <T as Config>::RuntimeCall::from(Call::authorize_deployer { deployer });
+			Self::do_schedule(origin, call)?;
+
+			Ok(())
+		}
+
+		/// Check if `who` may submit transactions using `account` as their EVM source
+		///
+		/// Accounts control themselves, operators the contract wallets they operate.
+		pub fn controls(who: &T::AccountId, account: &T::AccountId) -> bool {
+			who == account || ContractWalletOperators::<T>::get(account).as_ref() == Some(who)
+		}
+
+		/// Move the authorization and per-deployer settings of `old` to `new`
+		///
+		/// A contract wallet's operator is not carried over, as `new` holds its own key.
+		fn do_rotate(old: T::AccountId, new: T::AccountId) {
+			PendingKeyRotations::<T>::remove(&old);
+			ContractWalletOperators::<T>::remove(&old);
+			if let Some(mut info) = AuthorizedDeployers::<T>::take(&old) {
+				info.flags.0 |= DeployerFlags::ROTATED.0;
+				AuthorizedDeployers::<T>::insert(&new, info);
+				Self::forget_cached_authorization(&old);
+				Self::forget_cached_authorization(&new);
+				Self::record_transition(&old, false);
+				Self::record_transition(&new, true);
+			}
+			if WindowOverrides::<T>::take(&old).is_some() {
+				WindowOverrides::<T>::insert(&new, ());
+			}
+			if let Some(metadata) = DeployerMetadata::<T>::take(&old) {
+				DeployerMetadata::<T>::insert(&new, metadata);
+			}
+			if let Some(tenant) = DeployerTenant::<T>::take(&old) {
+				DeployerTenant::<T>::insert(&new, tenant);
+			}
+			// The creations already counted against the quota follow the key, so that rotating
+			// does not reset the quota
+			if let Some(usage) = DeploymentQuotaUsage::<T>::take(&old) {
+				DeploymentQuotaUsage::<T>::insert(&new, usage);
+			}
+			T::Lifecycle::on_key_rotated(&old, &new);
+			Self::deposit_event(Event::DeployerKeyRotated { old, new });
+		}
+
+		/// Bound a new metadata blob, releasing the preimage of the previous one
+		fn replace_metadata(
+			previous: Option<MetadataOf<T>>,
+			metadata: Option<Vec<u8>>,
+		) -> Result<Option<MetadataOf<T>>, DispatchError> {
+			if let Some(metadata) = &metadata {
+				ensure!(
+					metadata.len() as u32 <= T::MaxMetadataBlobLen::get(),
+					Error::<T>::MetadataTooLong
+				);
+			}
+
+			if let Some(previous) = previous {
+				T::Preimages::drop(&previous);
+			}
+			metadata.map(T::Preimages::bound).transpose()
+		}
+
+		/// Read a metadata blob, from the preimage store if it is not inline
+		fn peek_metadata(metadata: &MetadataOf<T>) -> Option<Vec<u8>> {
+			T::Preimages::peek(metadata).ok().map(|(blob, _)| blob)
+		}
+
+		/// Drop the memoized authorization check of an account after its authorization changed
+		fn forget_cached_authorization(account: &T::AccountId) {
+			if AuthorizationCache::<T>::take(account).is_some() {
+				CachedAuthorizations::<T>::mutate(|cached| cached.saturating_dec());
+			}
+		}
+
+		/// Record that `account` was authorized or lost its authorization in the current block
+		fn record_transition(account: &T::AccountId, authorized: bool) {
+			let now = frame_system::Pallet::<T>::block_number();
+			let since = AuthorizationIndexSince::<T>::get().unwrap_or_else(|| {
+				AuthorizationIndexSince::<T>::put(now);
+				now
+			});
+
+			AuthorizationTransitions::<T>::mutate(account, |log| {
+				let log = log.get_or_insert_with(|| AuthorizationLog {
+					since,
+					transitions: BoundedVec::new(),
+				});
+				// A revocation undoing an authorization of the same block leaves no trace
+				if let Some((block, _)) = log.transitions.last() {
+					if *block == now {
+						log.transitions.pop();
+						return;
+					}
+				}
+				if log.transitions.is_full() {
+					// The status from the dropped transition on is still known
+					let (block, _) = log.transitions.remove(0);
+					log.since = block;
+				}
+				let _ = log.transitions.try_push((now, authorized));
+			});
+		}
+
+		/// Whether `account` was an authorized deployer at `block`
+		///
+		/// Returns `None` for blocks before the index or the oldest transition it kept for the
+		/// account, and for future blocks.
+		pub fn was_authorized_at(account: &T::AccountId, block: BlockNumberFor<T>) -> Option<bool> {
+			if block > frame_system::Pallet::<T>::block_number() {
+				return None;
+			}
+			let Some(log) = AuthorizationTransitions::<T>::get(account) else {
+				// Never changed since the index started
+				let since = AuthorizationIndexSince::<T>::get()?;
+				return (block >= since).then(|| AuthorizedDeployers::<T>::contains_key(account));
+			};
+			if block < log.since {
+				return None;
+			}
+
+			if let Some((_, authorized)) = log.transitions.iter().rev().find(|(at, _)| *at <= block) {
+				return Some(*authorized);
+			}
+			Some(match log.transitions.first() {
+				Some((_, first)) => !first,
+				// Every transition was undone within its block
+				None => AuthorizedDeployers::<T>::contains_key(account),
+			})
+		}
+
+		/// Ensure an account is a named approver, returning the policy in effect
+		fn ensure_approver(who: &T::AccountId) -> Result<ApprovalPolicy<T>, Error<T>> {
+			let policy = AuthorizationApprovalPolicy::<T>::get().ok_or(Error::<T>::NoApprovalPolicy)?;
+			ensure!(policy.is_approver(who), Error::<T>::NotApprover);
+			Ok(policy)
+		}
+
+		/// Remove a pending authorization proposal and free its slot
+		/// Risk tier of an account to authorize, the highest one unless assessed by governance
+		pub fn risk_tier_of(account: &T::AccountId) -> u8 {
+			DeployerRiskTiers::<T>::get(account).unwrap_or(u8::MAX)
+		}
+
+		/// Whether the execution of a proposal waits in the schedule or in a referendum
+		fn is_scheduled(proposal: &AuthorizationProposal<T>) -> bool {
+			proposal.scheduled.is_some_and(|call| {
+				GovernanceCalls::<T>::contains_key(call) || Referenda::<T>::contains_key(call)
+			})
+		}
+
+		fn remove_proposal(id: u32) {
+			AuthorizationProposals::<T>::remove(id);
+			PendingProposals::<T>::mutate(|pending| pending.saturating_dec());
+		}
+
+		/// Remove an account's authorization and notify the lifecycle subscribers
+		pub(crate) fn do_revoke(deployer: T::AccountId) {
+			AuthorizedDeployers::<T>::remove(&deployer);
+			Self::forget_cached_authorization(&deployer);
+			Self::record_transition(&deployer, false);
+			PendingKeyRotations::<T>::remove(&deployer);
+			ContractWalletOperators::<T>::remove(&deployer);
+			if let Some(metadata) = DeployerMetadata::<T>::take(&deployer) {
+				T::Preimages::drop(&metadata);
+			}
+			if let Some(tenant) = DeployerTenant::<T>::take(&deployer) {
+				Tenants::<T>::mutate_extant(tenant, |info| info.deployers.saturating_dec());
+			}
+			if WindowOverrides::<T>::contains_key(&deployer) {
+				Self::defer_cleanup(CleanupTask::RevokedOverride(deployer.clone()));
+			}
+			T::Lifecycle::on_revoked(&deployer);
+			Self::deposit_event(Event::DeployerRevoked { deployer });
+		}
+
+		/// Queue a cleanup task, leaving the data in place if the queue is full
+		fn defer_cleanup(task: CleanupTask<T::AccountId>) {
+			if Self::queue_cleanup(task).is_err() {
+				log::warn!(target: LOG_TARGET, "Cleanup queue full, cleanup task dropped");
+			}
+		}
+
+		fn window_open(info: &RejectedAttemptInfo<BlockNumberFor<T>>, now: BlockNumberFor<T>) -> bool {
+			now < info.window_start.saturating_add(T::RejectedAttemptWindow::get())
+		}
+	}
+}
+
+impl<T: Config> DeploymentAuthorizer<T::AccountId> for Pallet<T> {
+    fn is_authorized(who: &T::AccountId) -> bool {
+        Pallet::<T>::is_authorized(who)
+    }
+}
+
+/// Records the provenance of created contracts; the other callbacks concern this pallet's
+/// own calls and are ignored
+impl<T: Config> DeploymentLifecycle<T::AccountId> for Pallet<T> {
+    fn on_deployed(deployer: &sp_core::H160, contract: &sp_core::H160) {
+        Pallet::<T>::note_contract_created(*deployer, *contract);
+    }
+}
+
+/// Suspension revokes the authorization of the account, if any
+impl<T: Config> DeployerSuspension<T::AccountId> for Pallet<T> {
+    fn suspend(who: &T::AccountId) {
+        if Pallet::<T>::is_authorized(who) {
+            Pallet::<T>::do_revoke(who.clone());
+        }
+    }
+}
+
+/// Authorizations relayed through the registry are subject to the enactment delay like any
+/// other: while one is set, they are scheduled as a Root governance call, open to the
+/// objections of token holders. Revocations apply at once.
+impl<T: Config> DeployerRegistry<T::AccountId> for Pallet<T> {
+    fn authorize(who: &T::AccountId) -> bool {
+        Pallet::<T>::relay_authorization(who.clone()).is_ok()
+    }
+
+    fn revoke(who: &T::AccountId) -> bool {
+        let authorized = AuthorizedDeployers::<T>::contains_key(who);
+        if authorized {
+            Pallet::<T>::do_revoke(who.clone());
+        }
+        authorized
+    }
+}
+
+impl<T: Config> ContractProvenance for Pallet<T> {
+    fn deployer_of(contract: &sp_core::H160) -> Option<sp_core::H160> {
+        Pallet::<T>::provenance(contract).map(|record| record.deployer)
+    }
 }
@@ -0,0 +1,295 @@
+//! Storage migrations of the EVM deployment control pallet
+//!
+//! Migrations touching per-account or per-contract maps are written as multi-block
+//! migrations, driven by `pallet-migrations`, so that a live chain with many deployers
+//! or provenance records never has to migrate them within a single block. The runtime
+//! lists them in `pallet_migrations::Config::Migrations`.
+
+/// Identifier prefix of the multi-block migrations of this pallet
+pub const PALLET_MIGRATIONS_ID: &[u8; 29] = b"pallet-evm-deployment-control";
+
+/// Migration from storage version 0 to 1
+///
+/// Turns the `()` entries of [`AuthorizedDeployers`](crate::AuthorizedDeployers) into
+/// deployer records, in the layout of [`v2::v1`](super::v2::v1).
+pub mod v1 {
+	use super::{
+		v2::v1::{AuthorizedDeployers, DeployerInfo},
+		PALLET_MIGRATIONS_ID,
+	};
+	use crate::{weights::WeightInfo, Config, Pallet};
+	use frame_support::{
+		migrations::{MigrationId, SteppedMigration, SteppedMigrationError},
+		pallet_prelude::*,
+		weights::WeightMeter,
+	};
+	use sp_std::marker::PhantomData;
+
+	/// Storage layout before the migration
+	pub mod v0 {
+		use crate::{Config, Pallet};
+		use frame_support::{pallet_prelude::*, storage_alias};
+
+		/// Authorized deployers without any record attached
+		#[storage_alias]
+		pub type AuthorizedDeployers<T: Config> = StorageMap<
+			Pallet<T>,
+			Blake2_128Concat,
+			<T as frame_system::Config>::AccountId,
+			(),
+			OptionQuery,
+		>;
+	}
+
+	/// Migrates one authorized deployer per step, using the last migrated account as cursor
+	///
+	/// Deployers authorized before the migration are recorded as authorized at the block in
+	/// which their entry is migrated, the original block not being known. The storage
+	/// version is set to 1 once every entry is migrated.
+	pub struct LazyMigrationV1<T, W>(PhantomData<(T, W)>);
+
+	impl<T: Config, W: WeightInfo> SteppedMigration for LazyMigrationV1<T, W> {
+		type Cursor = T::AccountId;
+		type Identifier = MigrationId<29>;
+
+		fn id() -> Self::Identifier {
+			MigrationId { pallet_id: *PALLET_MIGRATIONS_ID, version_from: 0, version_to: 1 }
+		}
+
+		fn step(
+			mut cursor: Option<Self::Cursor>,
+			meter: &mut WeightMeter,
+		) -> Result<Option<Self::Cursor>, SteppedMigrationError> {
+			if StorageVersion::get::<Pallet<T>>() >= 1 {
+				return Ok(None);
+			}
+
+			let required = W::migrate_v1_step();
+			if meter.remaining().any_lt(required) {
+				return Err(SteppedMigrationError::InsufficientWeight { required });
+			}
+
+			let authorized_at = frame_system::Pallet::<T>::block_number();
+			while meter.try_consume(required).is_ok() {
+				let mut keys = match cursor {
+					Some(last) => v0::AuthorizedDeployers::<T>::iter_keys_from(
+						v0::AuthorizedDeployers::<T>::hashed_key_for(last),
+					),
+					None => v0::AuthorizedDeployers::<T>::iter_keys(),
+				};
+
+				let Some(deployer) = keys.next() else {
+					StorageVersion::new(1).put::<Pallet<T>>();
+					return Ok(None);
+				};
+				AuthorizedDeployers::<T>::insert(&deployer, DeployerInfo { authorized_at });
+				cursor = Some(deployer);
+			}
+
+			Ok(cursor)
+		}
+	}
+}
+
+/// Migration from storage version 1 to 2
+///
+/// Re-encodes the [`AuthorizedDeployers`](crate::AuthorizedDeployers) records compactly, with
+/// [`DeployerFlags`](crate::DeployerFlags). Runs after the migration to version 1.
+pub mod v2 {
+	use super::PALLET_MIGRATIONS_ID;
+	use crate::{
+		weights::WeightInfo, AuthorizedDeployers, Config, DeployerFlags, DeployerInfo, Pallet,
+	};
+	use frame_support::{
+		migrations::{MigrationId, SteppedMigration, SteppedMigrationError},
+		pallet_prelude::*,
+		weights::WeightMeter,
+	};
+	use sp_runtime::traits::Zero;
+	use sp_std::marker::PhantomData;
+
+	/// Storage layout before the migration
+	pub mod v1 {
+		use crate::{Config, Pallet};
+		use frame_support::{pallet_prelude::*, storage_alias};
+		use frame_system::pallet_prelude::BlockNumberFor;
+
+		/// Record kept for an authorized deployer, with a fixed-width block number
+		#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug)]
+		pub struct DeployerInfo<BlockNumber> {
+			pub authorized_at: BlockNumber,
+		}
+
+		/// Authorized deployers and their records
+		#[storage_alias]
+		pub type AuthorizedDeployers<T: Config> = StorageMap<
+			Pallet<T>,
+			Blake2_128Concat,
+			<T as frame_system::Config>::AccountId,
+			DeployerInfo<BlockNumberFor<T>>,
+			OptionQuery,
+		>;
+	}
+
+	/// Migrates one authorized deployer per step, using the last migrated account as cursor
+	///
+	/// Deployers authorized at block zero are flagged as genesis deployers. The storage
+	/// version is set to 2 once every entry is migrated.
+	pub struct LazyMigrationV2<T, W>(PhantomData<(T, W)>);
+
+	impl<T: Config, W: WeightInfo> SteppedMigration for LazyMigrationV2<T, W> {
+		type Cursor = T::AccountId;
+		type Identifier = MigrationId<29>;
+
+		fn id() -> Self::Identifier {
+			MigrationId { pallet_id: *PALLET_MIGRATIONS_ID, version_from: 1, version_to: 2 }
+		}
+
+		fn step(
+			mut cursor: Option<Self::Cursor>,
+			meter: &mut WeightMeter,
+		) -> Result<Option<Self::Cursor>, SteppedMigrationError> {
+			if StorageVersion::get::<Pallet<T>>() >= 2 {
+				return Ok(None);
+			}
+
+			let required = W::migrate_v2_step();
+			if meter.remaining().any_lt(required) {
+				return Err(SteppedMigrationError::InsufficientWeight { required });
+			}
+
+			while meter.try_consume(required).is_ok() {
+				let mut entries = match cursor {
+					Some(last) => v1::AuthorizedDeployers::<T>::iter_from(
+						v1::AuthorizedDeployers::<T>::hashed_key_for(last),
+					),
+					None => v1::AuthorizedDeployers::<T>::iter(),
+				};
+
+				let Some((deployer, info)) = entries.next() else {
+					StorageVersion::new(2).put::<Pallet<T>>();
+					return Ok(None);
+				};
+				let flags = if info.authorized_at.is_zero() {
+					DeployerFlags::GENESIS
+				} else {
+					DeployerFlags::default()
+				};
+				AuthorizedDeployers::<T>::insert(
+					&deployer,
+					DeployerInfo { authorized_at: info.authorized_at, flags },
+				);
+				cursor = Some(deployer);
+			}
+
+			Ok(cursor)
+		}
+	}
+}
+
+/// Migration from storage version 2 to 3
+///
+/// Records the block in which each pending [`AuthorizationProposals`] entry was made, and drops
+/// the risk tier the proposer used to assign, the tier now being assessed by governance. Runs
+/// after the migration to version 2.
+///
+/// [`AuthorizationProposals`]: crate::AuthorizationProposals
+pub mod v3 {
+	use super::PALLET_MIGRATIONS_ID;
+	use crate::{weights::WeightInfo, AuthorizationProposal, AuthorizationProposals, Config, Pallet};
+	use frame_support::{
+		migrations::{MigrationId, SteppedMigration, SteppedMigrationError},
+		pallet_prelude::*,
+		weights::WeightMeter,
+	};
+	use sp_runtime::traits::Saturating;
+	use sp_std::marker::PhantomData;
+
+	/// Storage layout before the migration
+	pub mod v2 {
+		use crate::{Config, Pallet};
+		use frame_support::{pallet_prelude::*, storage_alias};
+		use frame_system::pallet_prelude::BlockNumberFor;
+
+		/// Authorization proposal with the risk tier assigned by the proposer
+		#[derive(Encode, Decode)]
+		pub struct AuthorizationProposal<AccountId, BlockNumber, MaxApprovers: Get<u32>> {
+			pub deployer: AccountId,
+			pub risk_tier: u8,
+			pub approvals: BoundedVec<AccountId, MaxApprovers>,
+			pub expires_at: BlockNumber,
+		}
+
+		/// Pending authorization proposals
+		#[storage_alias]
+		pub type AuthorizationProposals<T: Config> = StorageMap<
+			Pallet<T>,
+			Twox64Concat,
+			u32,
+			AuthorizationProposal<
+				<T as frame_system::Config>::AccountId,
+				BlockNumberFor<T>,
+				<T as Config>::MaxApprovers,
+			>,
+			OptionQuery,
+		>;
+	}
+
+	/// Migrates one pending proposal per step, using the last migrated identifier as cursor
+	///
+	/// Proposals are recorded as made `ProposalLifetime` blocks before they expire, the
+	/// block they were made in not being stored. The storage version is set to 3 once every
+	/// entry is migrated.
+	pub struct LazyMigrationV3<T, W>(PhantomData<(T, W)>);
+
+	impl<T: Config, W: WeightInfo> SteppedMigration for LazyMigrationV3<T, W> {
+		type Cursor = u32;
+		type Identifier = MigrationId<29>;
+
+		fn id() -> Self::Identifier {
+			MigrationId { pallet_id: *PALLET_MIGRATIONS_ID, version_from: 2, version_to: 3 }
+		}
+
+		fn step(
+			mut cursor: Option<Self::Cursor>,
+			meter: &mut WeightMeter,
+		) -> Result<Option<Self::Cursor>, SteppedMigrationError> {
+			if StorageVersion::get::<Pallet<T>>() >= 3 {
+				return Ok(None);
+			}
+
+			let required = W::migrate_v3_step();
+			if meter.remaining().any_lt(required) {
+				return Err(SteppedMigrationError::InsufficientWeight { required });
+			}
+
+			while meter.try_consume(required).is_ok() {
+				let mut entries = match cursor {
+					Some(last) => v2::AuthorizationProposals::<T>::iter_from(
+						v2::AuthorizationProposals::<T>::hashed_key_for(last),
+					),
+					None => v2::AuthorizationProposals::<T>::iter(),
+				};
+
+				let Some((id, proposal)) = entries.next() else {
+					StorageVersion::new(3).put::<Pallet<T>>();
+					return Ok(None);
+				};
+				let proposed_at = proposal.expires_at.saturating_sub(T::ProposalLifetime::get());
+				AuthorizationProposals::<T>::insert(
+					id,
+					AuthorizationProposal {
+						deployer: proposal.deployer,
+						approvals: proposal.approvals,
+						proposed_at,
+						expires_at: proposal.expires_at,
+						scheduled: None,
+					},
+				);
+				cursor = Some(id);
+			}
+
+			Ok(cursor)
+		}
+	}
+}
@@ -1,9 +1,13 @@
 use crate as pallet_evm_deployment_control;
 use frame_support::{
 	derive_impl, parameter_types,
-	traits::{ConstU32, ConstU64},
+	traits::{tokens::imbalance::ResolveTo, ConstU32, ConstU64},
+	weights::Weight,
 };
-use sp_runtime::{traits::IdentityLookup, BuildStorage};
+use pallet_evm_deployment_control::DeploymentLifecycle;
+use sp_core::{H160, U256};
+use sp_runtime::{testing::TestXt, traits::IdentityLookup, BuildStorage, DispatchError, Perbill};
+use std::cell::RefCell;
 
 type Block = frame_system::mocking::MockBlock<Test>;
 
@@ -12,6 +16,8 @@ frame_support::construct_runtime!(
 	pub enum Test
 	{
 		System: frame_system,
+		Balances: pallet_balances,
+		Preimage: pallet_preimage,
 		EvmDeploymentControl: pallet_evm_deployment_control,
 	}
 );
@@ -21,20 +27,170 @@ impl frame_system::Config for Test {
 	type Block = Block;
 	type AccountId = u64;
 	type Lookup = IdentityLookup<Self::AccountId>;
+	type AccountData = pallet_balances::AccountData<u64>;
+}
+
+#[derive_impl(pallet_balances::config_preludes::TestDefaultConfig)]
+impl pallet_balances::Config for Test {
+	type AccountStore = System;
+}
+
+impl pallet_preimage::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type WeightInfo = ();
+	type Currency = Balances;
+	type ManagerOrigin = frame_system::EnsureRoot<u64>;
+	type Consideration = ();
+}
+
+impl<C> frame_system::offchain::SendTransactionTypes<C> for Test
+where
+	RuntimeCall: From<C>,
+{
+	type OverarchingCall = RuntimeCall;
+	type Extrinsic = TestXt<RuntimeCall, ()>;
+}
+
+parameter_types! {
+	pub const RejectedAttemptThreshold: u32 = 3;
+	pub const RejectedAttemptWindow: u64 = 10;
+	pub const ProceedsAccount: u64 = 99;
+	pub const VetoThreshold: Perbill = Perbill::from_percent(10);
+}
+
+/// A lifecycle callback received by [`RecordLifecycle`]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum LifecycleCall {
+	Deployed(H160, H160),
+	FactoryRegistered(H160, u64),
+	Revoked(u64),
+	KeyRotated(u64, u64),
+}
+
+thread_local! {
+	static LIFECYCLE_CALLS: RefCell<Vec<LifecycleCall>> = RefCell::new(Vec::new());
+}
+
+/// Lifecycle subscriber recording every callback it receives
+pub struct RecordLifecycle;
+
+impl RecordLifecycle {
+	fn record(call: LifecycleCall) {
+		LIFECYCLE_CALLS.with(|calls| calls.borrow_mut().push(call));
+	}
+}
+
+impl DeploymentLifecycle<u64> for RecordLifecycle {
+	fn on_deployed(deployer: &H160, contract: &H160) {
+		Self::record(LifecycleCall::Deployed(*deployer, *contract));
+	}
+
+	fn on_factory_registered(factory: &H160, admin: &u64) {
+		Self::record(LifecycleCall::FactoryRegistered(*factory, *admin));
+	}
+
+	fn on_revoked(deployer: &u64) {
+		Self::record(LifecycleCall::Revoked(*deployer));
+	}
+
+	fn on_key_rotated(old: &u64, new: &u64) {
+		Self::record(LifecycleCall::KeyRotated(*old, *new));
+	}
+}
+
+/// Callbacks received by [`RecordLifecycle`] so far
+pub fn lifecycle_calls() -> Vec<LifecycleCall> {
+	LIFECYCLE_CALLS.with(|calls| calls.borrow().clone())
+}
+
+/// Lifecycle subscribers of the mock runtime, the pallet itself recording provenance
+pub type Lifecycle = (EvmDeploymentControl, RecordLifecycle);
+
+/// Gas used by every contract creation of [`MockContractDeployer`], up to its gas limit
+pub const CREATE_GAS: u64 = 50_000;
+
+/// Contract deployer mapping EVM address `0x00..0N` to account `N`
+///
+/// Creation fails for empty init code and otherwise yields an address derived from the
+/// code length.
+pub struct MockContractDeployer;
+
+impl pallet_evm_deployment_control::ContractDeployer<u64> for MockContractDeployer {
+	fn into_account_id(address: H160) -> u64 {
+		address.to_low_u64_be()
+	}
+
+	fn create(
+		source: H160,
+		init_code: Vec<u8>,
+		_value: U256,
+		_gas_limit: u64,
+	) -> Result<(H160, u64), DispatchError> {
+		if init_code.is_empty() {
+			return Err(pallet_evm_deployment_control::Error::<Test>::DeploymentFailed.into());
+		}
+		let contract = H160::from_low_u64_be(0x1000 + init_code.len() as u64);
+		Lifecycle::on_deployed(&source, &contract);
+		Ok((contract, gas_limit.min(CREATE_GAS)))
+	}
+
+	fn gas_weight(gas_limit: u64) -> Weight {
+		Weight::from_parts(gas_limit, 0)
+	}
 }
 
 impl pallet_evm_deployment_control::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
+	type RuntimeOrigin = RuntimeOrigin;
+	type PalletsOrigin = OriginCaller;
+	type RuntimeCall = RuntimeCall;
+	type NestedCalls = ();
+	type AdminOrigin = pallet_evm_deployment_control::EnsureDeployerAdmin<u64>;
 	type WeightInfo = ();
+	type RejectedAttemptThreshold = RejectedAttemptThreshold;
+	type RejectedAttemptWindow = RejectedAttemptWindow;
+	type UnsignedPriority = ConstU64<100>;
+	type MaxDeploymentWindows = ConstU32<4>;
+	type MaxQueuedCleanups = ConstU32<2>;
+	type ContractDeployer = MockContractDeployer;
+	type MaxMetadataLen = ConstU32<16>;
+	type Lifecycle = Lifecycle;
+	type MaxApprovers = ConstU32<3>;
+	type MaxProposals = ConstU32<2>;
+	type ProposalLifetime = ConstU64<10>;
+	type FactoryExpiryNotice = ConstU64<5>;
+	type MaxExpiriesPerBlock = ConstU32<2>;
+	type MaxCachedAuthorizations = ConstU32<2>;
+	type MaxCreateDepth = ConstU32<2>;
+	type Currency = Balances;
+	type RuntimeHoldReason = RuntimeHoldReason;
+	type DeploymentProceeds = ResolveTo<ProceedsAccount, Balances>;
+	type IntentBond = ConstU64<10>;
+	type KeyRotationDelay = ConstU64<10>;
+	type Preimages = Preimage;
+	type MaxMetadataBlobLen = ConstU32<512>;
+	type MaxGenesisDeployers = ConstU32<4>;
+	type MaxAuthorizationTransitions = ConstU32<3>;
+	type QuotaPeriodLength = ConstU64<10>;
+	type EmergencyOrigin = frame_system::EnsureRoot<u64>;
+	type MaxGovernanceCalls = ConstU32<2>;
+	type VetoThreshold = VetoThreshold;
+	type ReferendumPeriod = ConstU64<10>;
+	type RetirementGracePeriod = ConstU64<10>;
 }
 
 // Build genesis storage according to the mock runtime.
 pub fn new_test_ext() -> sp_io::TestExternalities {
 	let mut storage = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
 
+	pallet_balances::GenesisConfig::<Test> { balances: vec![(1, 100), (2, 5), (3, 100)] }
+		.assimilate_storage(&mut storage)
+		.unwrap();
+
 	// Configure initial authorized deployers for testing
 	pallet_evm_deployment_control::GenesisConfig::<Test> {
-		authorized_deployers: vec![1, 2], // Alice and Bob are pre-authorized
+		authorized_deployers: vec![1.into(), 2.into()], // Alice and Bob are pre-authorized
+		deployers: vec![],
 	}
 	.assimilate_storage(&mut storage)
 	.unwrap();
@@ -1,5 +1,37 @@
-use crate::{mock::*, Error, Event};
-use frame_support::{assert_noop, assert_ok};
+use crate::{
+	migrations::{
+		v1::{v0, LazyMigrationV1},
+		v2::{self, LazyMigrationV2},
+		v3::{self, LazyMigrationV3},
+	},
+	mock::*,
+	weights::WeightInfo,
+	ApprovalPolicy, AuthorizationCache, AuthorizationIndexSince, AuthorizationTransitions,
+	AuthorizedDeployers, CachedAuthorizations, CleanupTask, ContractMetadata, ContractProvenance,
+	ContractWalletOperators, CreationTrace, DeployerAddress, DeployerAdmins, DeployerExpiries,
+	DeployerFlags, DeployerInfo, DeployerMetadata,
+	DeployerRegistry, DeployerSuspension, DeploymentAuthorizer, DeploymentDecisions,
+	DeploymentIntent, DeploymentIntents, DeploymentPolicy, DeploymentQuota, DeploymentQuotaUsage,
+	DeploymentValidationError, EnsureDeployerAdmin, Error, Event, FactoryInfo, FactoryMetadata,
+	FactoryPermissions, FactoryStatus, FulfilledIntents, GenesisDeployerRole, HoldReason,
+	NestedCreationDepth, OpcodePolicy, PendingIntent, PendingKeyRotations, ProvenanceRecord,
+	QuotaUsage, RegistryDiff, RejectedAttempts, Retirement, TenantBounds, Tenants, WindowOverrides,
+};
+use frame_support::{
+	assert_noop, assert_ok,
+	dispatch::{DispatchResultWithPostInfo, Pays},
+	migrations::{SteppedMigration, SteppedMigrationError},
+	traits::{
+		fungible::InspectHold, ConstU32, EnsureOrigin, Get, Hooks, QueryPreimage, StorageVersion,
+	},
+	weights::{Weight, WeightMeter},
+};
+use parity_scale_codec::{Decode, Encode};
+use sp_core::{hashing::keccak_256, H160, H256};
+use sp_runtime::{
+	traits::{BlakeTwo256, Hash},
+	BuildStorage, Digest, DigestItem, Percent, TokenError,
+};
 
 #[test]
 fn genesis_config_works() {
@@ -12,6 +44,95 @@ fn genesis_config_works() {
 	});
 }
 
+#[test]
+fn genesis_config_grants_roles() {
+	let storage = crate::GenesisConfig::<Test> {
+		authorized_deployers: vec![1.into()],
+		deployers: vec![
+			(2.into(), GenesisDeployerRole::WindowOverride, None),
+			(DeployerAddress::Evm(H160::from_low_u64_be(3)), GenesisDeployerRole::Admin, None),
+		],
+	}
+	.build_storage()
+	.unwrap();
+
+	sp_io::TestExternalities::from(storage).execute_with(|| {
+		assert!((1..=3).all(|deployer| EvmDeploymentControl::is_authorized(&deployer)));
+		assert!(!WindowOverrides::<Test>::contains_key(1));
+		assert!(WindowOverrides::<Test>::contains_key(2));
+		assert!(DeployerAdmins::<Test>::contains_key(3));
+		assert!(!DeployerAdmins::<Test>::contains_key(2));
+	});
+}
+
+#[test]
+#[should_panic(expected = "duplicate genesis deployer 1")]
+fn genesis_config_rejects_duplicate_deployers() {
+	let _ = crate::GenesisConfig::<Test> {
+		authorized_deployers: vec![1.into(), 2.into()],
+		deployers: vec![(
+			DeployerAddress::Evm(H160::from_low_u64_be(1)),
+			GenesisDeployerRole::Admin,
+			None,
+		)],
+	}
+	.build_storage();
+}
+
+#[test]
+fn genesis_roles_lapse_at_their_expiry() {
+	let storage = crate::GenesisConfig::<Test> {
+		authorized_deployers: vec![1.into()],
+		deployers: vec![
+			(2.into(), GenesisDeployerRole::Admin, Some(5)),
+			(3.into(), GenesisDeployerRole::Deployer, Some(10)),
+		],
+	}
+	.build_storage()
+	.unwrap();
+
+	sp_io::TestExternalities::from(storage).execute_with(|| {
+		System::set_block_number(4);
+		assert_eq!(EvmDeploymentControl::process_deployer_expiries(4), 0);
+		assert!(EvmDeploymentControl::is_authorized(&2));
+
+		System::set_block_number(5);
+		assert_eq!(EvmDeploymentControl::process_deployer_expiries(5), 1);
+		assert!(!AuthorizedDeployers::<Test>::contains_key(2));
+		assert!(!DeployerAdmins::<Test>::contains_key(2));
+		assert!(AuthorizedDeployers::<Test>::contains_key(3));
+		assert_eq!(DeployerExpiries::<Test>::get().into_inner(), vec![(3, 10)]);
+
+		// An authorization granted anew since genesis does not lapse
+		assert_ok!(EvmDeploymentControl::revoke_deployer(RuntimeOrigin::root(), 3));
+		assert_ok!(EvmDeploymentControl::authorize_deployer(RuntimeOrigin::root(), 3));
+		assert_eq!(EvmDeploymentControl::process_deployer_expiries(10), 1);
+		assert!(AuthorizedDeployers::<Test>::contains_key(3));
+		assert!(AuthorizedDeployers::<Test>::contains_key(1));
+		assert!(DeployerExpiries::<Test>::get().is_empty());
+	});
+}
+
+#[test]
+#[should_panic(expected = "genesis deployer 2 expires at genesis")]
+fn genesis_config_rejects_expiry_at_genesis() {
+	let _ = crate::GenesisConfig::<Test> {
+		authorized_deployers: vec![],
+		deployers: vec![(2.into(), GenesisDeployerRole::Deployer, Some(0))],
+	}
+	.build_storage();
+}
+
+#[test]
+#[should_panic(expected = "more than `MaxGenesisDeployers` genesis deployers")]
+fn genesis_config_rejects_too_many_deployers() {
+	let _ = crate::GenesisConfig::<Test> {
+		authorized_deployers: (1..=5).map(Into::into).collect(),
+		deployers: vec![],
+	}
+	.build_storage();
+}
+
 #[test]
 fn authorize_deployer_works() {
 	new_test_ext().execute_with(|| {
@@ -164,3 +285,2941 @@ fn storage_query_is_efficient() {
 		}
 	});
 }
+
+#[test]
+fn note_rejected_attempt_counts_within_window() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		EvmDeploymentControl::note_rejected_attempt(&3);
+		EvmDeploymentControl::note_rejected_attempt(&3);
+		assert_eq!(EvmDeploymentControl::rejected_attempts(&3), 2);
+
+		// The window is 10 blocks, so counters expire at block 11
+		System::set_block_number(11);
+		assert_eq!(EvmDeploymentControl::rejected_attempts(&3), 0);
+
+		// The next attempt starts a fresh window
+		EvmDeploymentControl::note_rejected_attempt(&3);
+		assert_eq!(EvmDeploymentControl::rejected_attempts(&3), 1);
+	});
+}
+
+#[test]
+fn report_rejected_attempts_works() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		for _ in 0..3 {
+			EvmDeploymentControl::note_rejected_attempt(&3);
+		}
+
+		assert_ok!(EvmDeploymentControl::report_rejected_attempts(RuntimeOrigin::none(), 3));
+		assert!(EvmDeploymentControl::is_flagged(&3));
+
+		System::assert_last_event(
+			Event::RepeatedRejectedAttempts { deployer: 3, attempts: 3 }.into()
+		);
+
+		// Flagging twice is rejected
+		assert_noop!(
+			EvmDeploymentControl::report_rejected_attempts(RuntimeOrigin::none(), 3),
+			Error::<Test>::AlreadyFlagged
+		);
+	});
+}
+
+#[test]
+fn report_rejected_attempts_requires_threshold() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		EvmDeploymentControl::note_rejected_attempt(&3);
+		EvmDeploymentControl::note_rejected_attempt(&3);
+
+		assert_noop!(
+			EvmDeploymentControl::report_rejected_attempts(RuntimeOrigin::none(), 3),
+			Error::<Test>::BelowAlertThreshold
+		);
+
+		// Only unsigned submissions are accepted
+		assert_noop!(
+			EvmDeploymentControl::report_rejected_attempts(RuntimeOrigin::signed(1), 3),
+			sp_runtime::DispatchError::BadOrigin
+		);
+	});
+}
+
+#[test]
+fn validate_unsigned_rejects_unreportable_accounts() {
+	use frame_support::{pallet_prelude::*, unsigned::ValidateUnsigned};
+
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		let call = crate::Call::report_rejected_attempts { deployer: 3 };
+		assert_eq!(
+			EvmDeploymentControl::validate_unsigned(TransactionSource::External, &call),
+			InvalidTransaction::Stale.into()
+		);
+
+		for _ in 0..3 {
+			EvmDeploymentControl::note_rejected_attempt(&3);
+		}
+		assert_ok!(EvmDeploymentControl::validate_unsigned(TransactionSource::External, &call));
+	});
+}
+
+#[test]
+fn offchain_worker_submits_alert() {
+	use frame_support::traits::Hooks;
+	use parity_scale_codec::Decode;
+	use sp_core::offchain::{
+		testing::{TestOffchainExt, TestTransactionPoolExt},
+		OffchainDbExt, OffchainWorkerExt, TransactionPoolExt,
+	};
+
+	let mut ext = new_test_ext();
+	let (offchain, _) = TestOffchainExt::new();
+	let (pool, pool_state) = TestTransactionPoolExt::new();
+	ext.register_extension(OffchainDbExt::new(offchain.clone()));
+	ext.register_extension(OffchainWorkerExt::new(offchain));
+	ext.register_extension(TransactionPoolExt::new(pool));
+
+	ext.execute_with(|| {
+		System::set_block_number(1);
+
+		for _ in 0..3 {
+			EvmDeploymentControl::note_rejected_attempt(&3);
+		}
+		// Below threshold, must not be reported
+		EvmDeploymentControl::note_rejected_attempt(&4);
+
+		EvmDeploymentControl::offchain_worker(1);
+
+		let txs = pool_state.read().transactions.clone();
+		assert_eq!(txs.len(), 1);
+
+		let tx = sp_runtime::testing::TestXt::<RuntimeCall, ()>::decode(&mut &txs[0][..]).unwrap();
+		assert_eq!(tx.signature, None);
+		assert_eq!(
+			tx.call,
+			RuntimeCall::EvmDeploymentControl(crate::Call::report_rejected_attempts { deployer: 3 })
+		);
+	});
+}
+
+#[test]
+fn offchain_worker_scans_rejected_attempts_page_by_page() {
+	use frame_support::traits::Hooks;
+	use sp_core::offchain::{
+		testing::{TestOffchainExt, TestTransactionPoolExt},
+		OffchainDbExt, OffchainWorkerExt, TransactionPoolExt,
+	};
+
+	let mut ext = new_test_ext();
+	let (offchain, _) = TestOffchainExt::new();
+	let (pool, pool_state) = TestTransactionPoolExt::new();
+	ext.register_extension(OffchainDbExt::new(offchain.clone()));
+	ext.register_extension(OffchainWorkerExt::new(offchain));
+	ext.register_extension(TransactionPoolExt::new(pool));
+
+	ext.execute_with(|| {
+		System::set_block_number(1);
+
+		// One account more than a page
+		for account in 100..165 {
+			for _ in 0..3 {
+				EvmDeploymentControl::note_rejected_attempt(&account);
+			}
+		}
+		let submitted = || pool_state.read().transactions.len();
+
+		EvmDeploymentControl::offchain_worker(1);
+		assert_eq!(submitted(), 64);
+
+		// The next block resumes after the page, then starts over
+		EvmDeploymentControl::offchain_worker(2);
+		assert_eq!(submitted(), 65);
+		EvmDeploymentControl::offchain_worker(3);
+		assert_eq!(submitted(), 129);
+	});
+}
+
+#[test]
+fn unflag_deployer_works() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		for _ in 0..3 {
+			EvmDeploymentControl::note_rejected_attempt(&3);
+		}
+		assert_ok!(EvmDeploymentControl::report_rejected_attempts(RuntimeOrigin::none(), 3));
+		// Deployments of the flagged account are refused with the escalated code
+		assert_eq!(
+			EvmDeploymentControl::ensure_can_deploy(&3),
+			Err(DeploymentValidationError::FlaggedDeployer)
+		);
+
+		assert_noop!(
+			EvmDeploymentControl::unflag_deployer(RuntimeOrigin::signed(1), 3),
+			sp_runtime::DispatchError::BadOrigin
+		);
+
+		assert_ok!(EvmDeploymentControl::unflag_deployer(RuntimeOrigin::root(), 3));
+		assert!(!EvmDeploymentControl::is_flagged(&3));
+		assert_eq!(EvmDeploymentControl::rejected_attempts(&3), 0);
+		assert_eq!(
+			EvmDeploymentControl::ensure_can_deploy(&3),
+			Err(DeploymentValidationError::UnauthorizedDeployer)
+		);
+		System::assert_last_event(Event::DeployerUnflagged { deployer: 3 }.into());
+
+		assert_noop!(
+			EvmDeploymentControl::unflag_deployer(RuntimeOrigin::root(), 3),
+			Error::<Test>::NotFlagged
+		);
+	});
+}
+
+#[test]
+fn creates_are_unlimited_by_default() {
+	new_test_ext().execute_with(|| {
+		for _ in 0..100 {
+			assert!(EvmDeploymentControl::try_register_create());
+		}
+	});
+}
+
+#[test]
+fn set_max_creates_per_block_works() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		assert_noop!(
+			EvmDeploymentControl::set_max_creates_per_block(RuntimeOrigin::signed(1), Some(2)),
+			sp_runtime::DispatchError::BadOrigin
+		);
+
+		assert_ok!(EvmDeploymentControl::set_max_creates_per_block(RuntimeOrigin::root(), Some(2)));
+		System::assert_last_event(Event::MaxCreatesPerBlockSet { limit: Some(2) }.into());
+
+		assert!(EvmDeploymentControl::try_register_create());
+		assert!(EvmDeploymentControl::try_register_create());
+		// Cap reached for this block
+		assert!(!EvmDeploymentControl::try_register_create());
+
+		// Lifting the cap allows further creations
+		assert_ok!(EvmDeploymentControl::set_max_creates_per_block(RuntimeOrigin::root(), None));
+		assert!(EvmDeploymentControl::try_register_create());
+	});
+}
+
+#[test]
+fn create_counter_resets_every_block() {
+	use frame_support::traits::Hooks;
+
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(EvmDeploymentControl::set_max_creates_per_block(RuntimeOrigin::root(), Some(1)));
+
+		assert!(EvmDeploymentControl::try_register_create());
+		assert!(!EvmDeploymentControl::try_register_create());
+
+		EvmDeploymentControl::on_finalize(1);
+		System::set_block_number(2);
+
+		assert!(EvmDeploymentControl::try_register_create());
+	});
+}
+
+fn window(period: u64, offset: u64, duration: u64) -> crate::DeploymentWindow<u64> {
+	crate::DeploymentWindow { period, offset, duration }
+}
+
+#[test]
+fn deployments_are_unrestricted_without_windows() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(7);
+
+		assert!(EvmDeploymentControl::is_within_deployment_window(7));
+		assert_eq!(EvmDeploymentControl::ensure_can_deploy(&1), Ok(()));
+		assert_eq!(
+			EvmDeploymentControl::ensure_can_deploy(&3),
+			Err(DeploymentValidationError::UnauthorizedDeployer)
+		);
+	});
+}
+
+#[test]
+fn set_deployment_windows_works() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		let windows = vec![window(100, 10, 5)].try_into().unwrap();
+		assert_noop!(
+			EvmDeploymentControl::set_deployment_windows(RuntimeOrigin::signed(1), windows),
+			sp_runtime::DispatchError::BadOrigin
+		);
+
+		let windows = vec![window(100, 10, 5), window(100, 50, 5)].try_into().unwrap();
+		assert_ok!(EvmDeploymentControl::set_deployment_windows(RuntimeOrigin::root(), windows));
+		System::assert_last_event(Event::DeploymentWindowsSet { count: 2 }.into());
+
+		// Windows recur every period
+		assert!(!EvmDeploymentControl::is_within_deployment_window(9));
+		assert!(EvmDeploymentControl::is_within_deployment_window(10));
+		assert!(EvmDeploymentControl::is_within_deployment_window(14));
+		assert!(!EvmDeploymentControl::is_within_deployment_window(15));
+		assert!(EvmDeploymentControl::is_within_deployment_window(52));
+		assert!(EvmDeploymentControl::is_within_deployment_window(212));
+
+		// An empty list lifts the restriction
+		assert_ok!(EvmDeploymentControl::set_deployment_windows(
+			RuntimeOrigin::root(),
+			Default::default()
+		));
+		assert!(EvmDeploymentControl::is_within_deployment_window(9));
+	});
+}
+
+#[test]
+fn set_deployment_windows_rejects_malformed_windows() {
+	new_test_ext().execute_with(|| {
+		for malformed in [window(0, 0, 1), window(10, 0, 0), window(10, 8, 5)] {
+			assert_noop!(
+				EvmDeploymentControl::set_deployment_windows(
+					RuntimeOrigin::root(),
+					vec![malformed].try_into().unwrap()
+				),
+				Error::<Test>::InvalidDeploymentWindow
+			);
+		}
+	});
+}
+
+#[test]
+fn deployments_outside_window_require_override() {
+	new_test_ext().execute_with(|| {
+		let windows = vec![window(100, 10, 5)].try_into().unwrap();
+		assert_ok!(EvmDeploymentControl::set_deployment_windows(RuntimeOrigin::root(), windows));
+
+		System::set_block_number(12);
+		assert_eq!(EvmDeploymentControl::ensure_can_deploy(&1), Ok(()));
+
+		System::set_block_number(20);
+		assert_eq!(
+			EvmDeploymentControl::ensure_can_deploy(&1),
+			Err(DeploymentValidationError::OutsideDeploymentWindow)
+		);
+
+		assert_ok!(EvmDeploymentControl::set_window_override(RuntimeOrigin::root(), 1, true));
+		System::assert_last_event(Event::WindowOverrideSet { deployer: 1, enabled: true }.into());
+		assert_eq!(EvmDeploymentControl::ensure_can_deploy(&1), Ok(()));
+
+		// The override does not grant authorization
+		assert_ok!(EvmDeploymentControl::set_window_override(RuntimeOrigin::root(), 3, true));
+		assert_eq!(
+			EvmDeploymentControl::ensure_can_deploy(&3),
+			Err(DeploymentValidationError::UnauthorizedDeployer)
+		);
+
+		assert_ok!(EvmDeploymentControl::set_window_override(RuntimeOrigin::root(), 1, false));
+		assert_eq!(
+			EvmDeploymentControl::ensure_can_deploy(&1),
+			Err(DeploymentValidationError::OutsideDeploymentWindow)
+		);
+	});
+}
+
+#[test]
+fn contract_provenance_is_recorded() {
+	new_test_ext().execute_with(|| {
+		let deployer = H160::repeat_byte(0x11);
+		let contract = H160::repeat_byte(0x22);
+		assert_eq!(EvmDeploymentControl::provenance(&contract), None);
+
+		System::set_block_number(7);
+		EvmDeploymentControl::note_contract_created(deployer, contract);
+
+		assert_eq!(
+			EvmDeploymentControl::provenance(&contract),
+			Some(ProvenanceRecord { deployer, block: 7 })
+		);
+		assert_eq!(EvmDeploymentControl::deployer_of(&contract), Some(deployer));
+	});
+}
+
+#[test]
+fn deployment_authorizer_matches_pallet() {
+	new_test_ext().execute_with(|| {
+		assert!(<EvmDeploymentControl as DeploymentAuthorizer<u64>>::is_authorized(&1));
+		assert!(!<EvmDeploymentControl as DeploymentAuthorizer<u64>>::is_authorized(&3));
+		assert!(!<() as DeploymentAuthorizer<u64>>::is_authorized(&1));
+	});
+}
+
+#[test]
+fn revoke_queues_override_cleanup() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(EvmDeploymentControl::set_window_override(RuntimeOrigin::root(), 1, true));
+		assert_ok!(EvmDeploymentControl::revoke_deployer(RuntimeOrigin::root(), 1));
+		assert_eq!(EvmDeploymentControl::queued_cleanups(), 1);
+		assert!(WindowOverrides::<Test>::contains_key(1));
+
+		// Nothing happens without spare weight
+		EvmDeploymentControl::on_idle(1, Weight::zero());
+		assert_eq!(EvmDeploymentControl::queued_cleanups(), 1);
+
+		EvmDeploymentControl::on_idle(1, Weight::MAX);
+		assert_eq!(EvmDeploymentControl::queued_cleanups(), 0);
+		assert!(!WindowOverrides::<Test>::contains_key(1));
+		System::assert_last_event(Event::CleanupProcessed { processed: 1, remaining: 0 }.into());
+
+		// Revoking an account without override queues nothing
+		assert_ok!(EvmDeploymentControl::revoke_deployer(RuntimeOrigin::root(), 2));
+		assert_eq!(EvmDeploymentControl::queued_cleanups(), 0);
+	});
+}
+
+#[test]
+fn on_idle_respects_remaining_weight() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		for byte in [0x01, 0x02] {
+			let contract = H160::repeat_byte(byte);
+			EvmDeploymentControl::note_contract_created(H160::zero(), contract);
+			assert_ok!(EvmDeploymentControl::schedule_cleanup(
+				RuntimeOrigin::root(),
+				CleanupTask::Provenance(contract)
+			));
+		}
+
+		// Room for the bookkeeping and a single task
+		let weight = <Test as frame_system::Config>::DbWeight::get().reads_writes(2, 1) +
+			<() as crate::WeightInfo>::process_cleanup_task();
+		assert_eq!(EvmDeploymentControl::on_idle(1, weight), weight);
+		System::assert_last_event(Event::CleanupProcessed { processed: 1, remaining: 1 }.into());
+		assert_eq!(EvmDeploymentControl::provenance(&H160::repeat_byte(0x01)), None);
+		assert!(EvmDeploymentControl::provenance(&H160::repeat_byte(0x02)).is_some());
+
+		EvmDeploymentControl::on_idle(1, Weight::MAX);
+		assert_eq!(EvmDeploymentControl::provenance(&H160::repeat_byte(0x02)), None);
+	});
+}
+
+#[test]
+fn schedule_cleanup_is_bounded() {
+	new_test_ext().execute_with(|| {
+		let task = CleanupTask::Provenance(H160::zero());
+		assert_noop!(
+			EvmDeploymentControl::schedule_cleanup(RuntimeOrigin::signed(1), task.clone()),
+			sp_runtime::DispatchError::BadOrigin
+		);
+
+		assert_ok!(EvmDeploymentControl::schedule_cleanup(RuntimeOrigin::root(), task.clone()));
+		assert_ok!(EvmDeploymentControl::schedule_cleanup(RuntimeOrigin::root(), task.clone()));
+		assert_noop!(
+			EvmDeploymentControl::schedule_cleanup(RuntimeOrigin::root(), task),
+			Error::<Test>::CleanupQueueFull
+		);
+	});
+}
+
+#[test]
+fn rejected_attempt_cleanup_waits_for_window_expiry() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		EvmDeploymentControl::note_rejected_attempt(&3);
+
+		let task = CleanupTask::ExpiredRejectedAttempts(3);
+		assert_ok!(EvmDeploymentControl::schedule_cleanup(RuntimeOrigin::root(), task.clone()));
+		EvmDeploymentControl::on_idle(1, Weight::MAX);
+		assert!(RejectedAttempts::<Test>::contains_key(3));
+
+		System::set_block_number(11);
+		assert_ok!(EvmDeploymentControl::schedule_cleanup(RuntimeOrigin::root(), task));
+		EvmDeploymentControl::on_idle(11, Weight::MAX);
+		assert!(!RejectedAttempts::<Test>::contains_key(3));
+	});
+}
+
+#[test]
+fn opcode_policy_bans_selfdestruct_by_default() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_eq!(
+			EvmDeploymentControl::opcode_policy(),
+			OpcodePolicy { allow_selfdestruct: false, limit_create_depth: false }
+		);
+
+		let policy = OpcodePolicy { allow_selfdestruct: true, limit_create_depth: true };
+		assert_noop!(
+			EvmDeploymentControl::set_opcode_policy(RuntimeOrigin::signed(1), policy),
+			sp_runtime::DispatchError::BadOrigin
+		);
+
+		assert_ok!(EvmDeploymentControl::set_opcode_policy(RuntimeOrigin::root(), policy));
+		System::assert_last_event(Event::OpcodePolicySet { policy }.into());
+		assert_eq!(EvmDeploymentControl::opcode_policy(), policy);
+	});
+}
+
+#[test]
+fn nested_creations_are_limited_by_the_opcode_policy() {
+	new_test_ext().execute_with(|| {
+		let [sender, factory, child, grandchild] = [1u8, 2, 3, 4].map(H160::repeat_byte);
+
+		// The EVM notes the innermost creation first
+		let create_three_deep = || {
+			EvmDeploymentControl::note_nested_creation(child, grandchild);
+			EvmDeploymentControl::note_nested_creation(factory, child);
+			EvmDeploymentControl::note_nested_creation(sender, factory);
+		};
+
+		create_three_deep();
+		assert_eq!(EvmDeploymentControl::take_too_deep_creation(), None);
+
+		let policy = OpcodePolicy { allow_selfdestruct: false, limit_create_depth: true };
+		assert_ok!(EvmDeploymentControl::set_opcode_policy(RuntimeOrigin::root(), policy));
+
+		// Two levels are within `MaxCreateDepth`, however many siblings there are
+		EvmDeploymentControl::note_nested_creation(child, grandchild);
+		EvmDeploymentControl::note_nested_creation(sender, child);
+		EvmDeploymentControl::note_nested_creation(sender, factory);
+		assert_eq!(EvmDeploymentControl::take_too_deep_creation(), None);
+		assert_eq!(NestedCreationDepth::<Test>::iter().count(), 0);
+
+		create_three_deep();
+		assert_eq!(EvmDeploymentControl::take_too_deep_creation(), Some(factory));
+		assert_eq!(NestedCreationDepth::<Test>::iter().count(), 0);
+		assert_eq!(EvmDeploymentControl::take_too_deep_creation(), None);
+	});
+}
+
+fn metadata(label: &[u8], version: &[u8]) -> ContractMetadata<ConstU32<16>> {
+	ContractMetadata {
+		label: label.to_vec().try_into().unwrap(),
+		version: version.to_vec().try_into().unwrap(),
+	}
+}
+
+fn deploy(who: u64, init_code: Vec<u8>) -> DispatchResultWithPostInfo {
+	EvmDeploymentControl::deploy_contract(
+		RuntimeOrigin::signed(who),
+		H160::from_low_u64_be(who),
+		init_code,
+		0.into(),
+		1_000_000,
+		metadata(b"Token", b"1.0.0"),
+	)
+}
+
+#[test]
+fn deploy_contract_records_metadata() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(5);
+		assert_ok!(deploy(1, vec![0x60, 0x00]));
+
+		let contract = H160::from_low_u64_be(0x1002);
+		System::assert_last_event(
+			Event::ContractDeployed {
+				deployer: 1,
+				contract,
+				metadata: metadata(b"Token", b"1.0.0"),
+			}
+			.into(),
+		);
+		assert_eq!(
+			EvmDeploymentControl::contract_metadata(&contract),
+			Some(metadata(b"Token", b"1.0.0"))
+		);
+		assert_eq!(
+			EvmDeploymentControl::provenance(&contract),
+			Some(ProvenanceRecord { deployer: H160::from_low_u64_be(1), block: 5 })
+		);
+	});
+}
+
+#[test]
+fn deploy_contract_enforces_deployment_policy() {
+	new_test_ext().execute_with(|| {
+		// The source must be controlled by the signer
+		assert_noop!(
+			EvmDeploymentControl::deploy_contract(
+				RuntimeOrigin::signed(1),
+				H160::from_low_u64_be(2),
+				vec![0x60, 0x00],
+				0.into(),
+				1_000_000,
+				metadata(b"Token", b"1.0.0"),
+			),
+			Error::<Test>::InvalidSource
+		);
+
+		// Refused deployments succeed so that the attempt is counted, and pay the fee as no gas
+		// was charged
+		assert_eq!(deploy(3, vec![0x60, 0x00]).unwrap().pays_fee, Pays::Yes);
+		System::assert_last_event(Event::DeploymentRefused { deployer: 3 }.into());
+		assert_eq!(EvmDeploymentControl::rejected_attempts(&3), 1);
+
+		let windows = vec![window(100, 10, 5)].try_into().unwrap();
+		assert_ok!(EvmDeploymentControl::set_deployment_windows(RuntimeOrigin::root(), windows));
+		System::set_block_number(20);
+		assert_noop!(deploy(1, vec![0x60, 0x00]), Error::<Test>::OutsideDeploymentWindow);
+
+		System::set_block_number(10);
+		assert_ok!(EvmDeploymentControl::set_max_creates_per_block(RuntimeOrigin::root(), Some(1)));
+		assert_ok!(deploy(1, vec![0x60, 0x00]));
+		assert_noop!(deploy(2, vec![0x60, 0x00, 0x00]), Error::<Test>::CreateLimitReached);
+	});
+}
+
+#[test]
+fn failed_deployment_records_nothing() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(deploy(1, vec![]), Error::<Test>::DeploymentFailed);
+		assert_eq!(EvmDeploymentControl::contract_metadata(&H160::from_low_u64_be(0x1000)), None);
+	});
+}
+
+#[test]
+fn authorize_deployer_records_block() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(5);
+
+		assert_ok!(EvmDeploymentControl::authorize_deployer(RuntimeOrigin::root(), 3));
+		assert_eq!(AuthorizedDeployers::<Test>::get(3), Some(DeployerInfo::new(5)));
+	});
+}
+
+#[test]
+fn migration_v1_spreads_over_steps() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(7);
+		StorageVersion::new(0).put::<EvmDeploymentControl>();
+		for deployer in 1..=5 {
+			v0::AuthorizedDeployers::<Test>::insert(deployer, ());
+		}
+
+		// Two entries fit in each step, the last step also notices the end of the map
+		let step_weight = <() as WeightInfo>::migrate_v1_step();
+		let mut cursor = None;
+		let mut steps = 0;
+		loop {
+			let mut meter = WeightMeter::with_limit(step_weight * 2);
+			cursor = LazyMigrationV1::<Test, ()>::step(cursor, &mut meter).unwrap();
+			steps += 1;
+			if cursor.is_none() {
+				break;
+			}
+			assert_eq!(StorageVersion::get::<EvmDeploymentControl>(), 0);
+		}
+
+		assert_eq!(steps, 3);
+		for deployer in 1..=5 {
+			assert_eq!(
+				v2::v1::AuthorizedDeployers::<Test>::get(deployer),
+				Some(v2::v1::DeployerInfo { authorized_at: 7 })
+			);
+		}
+		assert_eq!(StorageVersion::get::<EvmDeploymentControl>(), 1);
+	});
+}
+
+#[test]
+fn migration_v1_requires_weight_for_one_entry() {
+	new_test_ext().execute_with(|| {
+		StorageVersion::new(0).put::<EvmDeploymentControl>();
+		v0::AuthorizedDeployers::<Test>::insert(3, ());
+
+		let mut meter = WeightMeter::with_limit(Weight::zero());
+		assert_eq!(
+			LazyMigrationV1::<Test, ()>::step(None, &mut meter),
+			Err(SteppedMigrationError::InsufficientWeight {
+				required: <() as WeightInfo>::migrate_v1_step()
+			})
+		);
+	});
+}
+
+#[test]
+fn migration_v1_skips_migrated_storage() {
+	new_test_ext().execute_with(|| {
+		assert_eq!(StorageVersion::get::<EvmDeploymentControl>(), 3);
+
+		let mut meter = WeightMeter::with_limit(Weight::MAX);
+		assert_eq!(LazyMigrationV1::<Test, ()>::step(None, &mut meter), Ok(None));
+		assert_eq!(LazyMigrationV2::<Test, ()>::step(None, &mut meter), Ok(None));
+		assert_eq!(LazyMigrationV3::<Test, ()>::step(None, &mut meter), Ok(None));
+		assert_eq!(meter.consumed(), Weight::zero());
+	});
+}
+
+#[test]
+fn migration_v2_reencodes_records_compactly() {
+	new_test_ext().execute_with(|| {
+		StorageVersion::new(1).put::<EvmDeploymentControl>();
+		for deployer in 1..=3 {
+			let info = v2::v1::DeployerInfo { authorized_at: deployer - 1 };
+			v2::v1::AuthorizedDeployers::<Test>::insert(deployer, info);
+		}
+
+		let mut meter = WeightMeter::with_limit(<() as WeightInfo>::migrate_v2_step() * 4);
+		assert_eq!(LazyMigrationV2::<Test, ()>::step(None, &mut meter), Ok(None));
+
+		// Deployers authorized at block zero were authorized in the genesis config
+		let genesis = DeployerInfo { authorized_at: 0, flags: DeployerFlags::GENESIS };
+		assert_eq!(AuthorizedDeployers::<Test>::get(1), Some(genesis));
+		assert_eq!(AuthorizedDeployers::<Test>::get(2), Some(DeployerInfo::new(1)));
+		assert_eq!(AuthorizedDeployers::<Test>::get(3), Some(DeployerInfo::new(2)));
+		assert_eq!(StorageVersion::get::<EvmDeploymentControl>(), 2);
+	});
+}
+
+#[test]
+fn migration_v3_records_when_proposals_were_made() {
+	new_test_ext().execute_with(|| {
+		StorageVersion::new(2).put::<EvmDeploymentControl>();
+		for id in 0..2 {
+			let proposal = v3::v2::AuthorizationProposal {
+				deployer: 3 + id as u64,
+				risk_tier: 0,
+				approvals: vec![10].try_into().unwrap(),
+				expires_at: 12 + id as u64,
+			};
+			v3::v2::AuthorizationProposals::<Test>::insert(id, proposal);
+		}
+
+		let mut meter = WeightMeter::with_limit(<() as WeightInfo>::migrate_v3_step() * 3);
+		assert_eq!(LazyMigrationV3::<Test, ()>::step(None, &mut meter), Ok(None));
+
+		// Proposals live for `ProposalLifetime` blocks
+		for id in 0..2 {
+			let proposal = EvmDeploymentControl::authorization_proposal(id).unwrap();
+			assert_eq!(proposal.deployer, 3 + id as u64);
+			assert_eq!((proposal.proposed_at, proposal.expires_at), (2 + id as u64, 12 + id as u64));
+			assert_eq!(proposal.scheduled, None);
+		}
+		assert_eq!(StorageVersion::get::<EvmDeploymentControl>(), 3);
+	});
+}
+
+#[test]
+fn compact_records_shrink_authorization_proofs() {
+	// Size of the proof of validity of an authorization check, with the record of
+	// `insert`
+	let proof_size = |insert: fn()| {
+		let mut ext = new_test_ext();
+		ext.execute_with(insert);
+		ext.commit_all().unwrap();
+		let (authorized, proof) =
+			ext.execute_and_prove(|| AuthorizedDeployers::<Test>::contains_key(3));
+		assert!(authorized);
+		proof.encoded_size()
+	};
+
+	let fixed = proof_size(|| {
+		let info = v2::v1::DeployerInfo { authorized_at: 100_000 };
+		v2::v1::AuthorizedDeployers::<Test>::insert(3, info);
+	});
+	let compact = proof_size(|| AuthorizedDeployers::<Test>::insert(3, DeployerInfo::new(100_000)));
+	assert!(compact < fixed, "compact proof of {compact} bytes, fixed proof of {fixed} bytes");
+}
+
+#[test]
+fn register_factory_works() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(4);
+		let factory = H160::repeat_byte(0x33);
+
+		assert_noop!(
+			EvmDeploymentControl::register_factory(RuntimeOrigin::signed(1), factory, 3),
+			sp_runtime::DispatchError::BadOrigin
+		);
+
+		assert_ok!(EvmDeploymentControl::register_factory(RuntimeOrigin::root(), factory, 3));
+		assert_eq!(
+			EvmDeploymentControl::factory(&factory),
+			Some(FactoryInfo {
+				admin: 3,
+				children: 0,
+				status: FactoryStatus::Active,
+				registered_at: 4
+			})
+		);
+		System::assert_last_event(Event::FactoryRegistered { factory, admin: 3 }.into());
+
+		assert_noop!(
+			EvmDeploymentControl::register_factory(RuntimeOrigin::root(), factory, 3),
+			Error::<Test>::FactoryAlreadyRegistered
+		);
+	});
+}
+
+#[test]
+fn set_factory_status_works() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let factory = H160::repeat_byte(0x33);
+
+		assert_noop!(
+			EvmDeploymentControl::set_factory_status(
+				RuntimeOrigin::root(),
+				factory,
+				FactoryStatus::Suspended
+			),
+			Error::<Test>::UnknownFactory
+		);
+
+		assert_ok!(EvmDeploymentControl::register_factory(RuntimeOrigin::root(), factory, 3));
+		assert_ok!(EvmDeploymentControl::set_factory_status(
+			RuntimeOrigin::root(),
+			factory,
+			FactoryStatus::Suspended
+		));
+		assert_eq!(
+			EvmDeploymentControl::factory(&factory).map(|info| info.status),
+			Some(FactoryStatus::Suspended)
+		);
+		System::assert_last_event(
+			Event::FactoryStatusSet { factory, status: FactoryStatus::Suspended }.into(),
+		);
+	});
+}
+
+#[test]
+fn factory_creations_count_as_children() {
+	new_test_ext().execute_with(|| {
+		let factory = H160::repeat_byte(0x33);
+		let deployer = H160::repeat_byte(0x44);
+		assert_ok!(EvmDeploymentControl::register_factory(RuntimeOrigin::root(), factory, 3));
+
+		EvmDeploymentControl::note_contract_created(factory, H160::repeat_byte(0x01));
+		EvmDeploymentControl::note_contract_created(factory, H160::repeat_byte(0x02));
+		EvmDeploymentControl::note_contract_created(deployer, H160::repeat_byte(0x03));
+
+		assert_eq!(EvmDeploymentControl::factory(&factory).map(|info| info.children), Some(2));
+		assert_eq!(EvmDeploymentControl::factory(&deployer), None);
+	});
+}
+
+#[test]
+fn factories_are_paginated_in_address_order() {
+	new_test_ext().execute_with(|| {
+		for byte in [0x30, 0x10, 0x20] {
+			assert_ok!(EvmDeploymentControl::register_factory(
+				RuntimeOrigin::root(),
+				H160::repeat_byte(byte),
+				3
+			));
+		}
+
+		let addresses = |page: Vec<(H160, FactoryInfo<u64, u64>)>| {
+			page.into_iter().map(|(address, _)| address).collect::<Vec<_>>()
+		};
+		assert_eq!(
+			addresses(EvmDeploymentControl::factories(None, 2)),
+			vec![H160::repeat_byte(0x10), H160::repeat_byte(0x20)]
+		);
+		assert_eq!(
+			addresses(EvmDeploymentControl::factories(Some(H160::repeat_byte(0x20)), 2)),
+			vec![H160::repeat_byte(0x30)]
+		);
+	});
+}
+
+#[test]
+fn lifecycle_subscribers_are_notified() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let factory = H160::repeat_byte(0x33);
+
+		assert_ok!(deploy(1, vec![0x60, 0x00]));
+		assert_ok!(EvmDeploymentControl::register_factory(RuntimeOrigin::root(), factory, 3));
+		assert_ok!(EvmDeploymentControl::revoke_deployer(RuntimeOrigin::root(), 2));
+
+		assert_eq!(
+			lifecycle_calls(),
+			vec![
+				LifecycleCall::Deployed(H160::from_low_u64_be(1), H160::from_low_u64_be(0x1002)),
+				LifecycleCall::FactoryRegistered(factory, 3),
+				LifecycleCall::Revoked(2),
+			]
+		);
+	});
+}
+
+#[test]
+fn failed_calls_do_not_notify_subscribers() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			EvmDeploymentControl::revoke_deployer(RuntimeOrigin::root(), 3),
+			Error::<Test>::NotAuthorized
+		);
+		assert_noop!(deploy(1, vec![]), Error::<Test>::DeploymentFailed);
+
+		assert!(lifecycle_calls().is_empty());
+	});
+}
+
+#[test]
+fn suspension_revokes_authorization() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		<EvmDeploymentControl as DeployerSuspension<u64>>::suspend(&2);
+		assert!(!EvmDeploymentControl::is_authorized(&2));
+		System::assert_last_event(Event::DeployerRevoked { deployer: 2 }.into());
+		assert_eq!(lifecycle_calls(), vec![LifecycleCall::Revoked(2)]);
+
+		// Suspending an account without authorization is a no-op
+		<EvmDeploymentControl as DeployerSuspension<u64>>::suspend(&2);
+		assert_eq!(lifecycle_calls(), vec![LifecycleCall::Revoked(2)]);
+	});
+}
+
+#[test]
+fn registry_authorizes_and_revokes_deployers() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		assert!(<EvmDeploymentControl as DeployerRegistry<u64>>::authorize(&9));
+		assert!(EvmDeploymentControl::is_authorized(&9));
+		System::assert_last_event(Event::DeployerAuthorized { deployer: 9 }.into());
+		assert!(!<EvmDeploymentControl as DeployerRegistry<u64>>::authorize(&9));
+
+		assert!(<EvmDeploymentControl as DeployerRegistry<u64>>::revoke(&9));
+		assert!(!EvmDeploymentControl::is_authorized(&9));
+		assert!(!<EvmDeploymentControl as DeployerRegistry<u64>>::revoke(&9));
+	});
+}
+
+#[test]
+fn registry_authorizations_wait_out_the_enactment_delay() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(EvmDeploymentControl::set_enactment_delay(RuntimeOrigin::root(), 10));
+
+		assert!(<EvmDeploymentControl as DeployerRegistry<u64>>::authorize(&9));
+		assert!(!EvmDeploymentControl::is_authorized(&9));
+		let call_hash = BlakeTwo256::hash_of(&authorize_call(9));
+		System::assert_last_event(
+			Event::GovernanceCallScheduled { id: 0, call_hash, enact_at: 11 }.into(),
+		);
+		// Token holders may object to the relayed authorization
+		assert!(crate::Objections::<Test>::contains_key(0));
+
+		System::set_block_number(11);
+		assert_ok!(EvmDeploymentControl::enact_governance_call(
+			RuntimeOrigin::signed(3),
+			0,
+			Weight::MAX
+		));
+		assert!(EvmDeploymentControl::is_authorized(&9));
+		assert!(!<EvmDeploymentControl as DeployerRegistry<u64>>::authorize(&9));
+	});
+}
+
+fn approval_policy(approvers: Vec<u64>, threshold: u32) -> ApprovalPolicy<Test> {
+	ApprovalPolicy { approvers: approvers.try_into().unwrap(), threshold, risk_tier: 2 }
+}
+
+fn propose(who: u64, deployer: u64) -> sp_runtime::DispatchResult {
+	EvmDeploymentControl::propose_authorization(RuntimeOrigin::signed(who), deployer)
+}
+
+fn assess(deployer: u64, risk_tier: u8) {
+	assert_ok!(EvmDeploymentControl::set_deployer_risk_tier(
+		RuntimeOrigin::root(),
+		deployer,
+		Some(risk_tier)
+	));
+}
+
+#[test]
+fn set_approval_policy_validates_policy() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		for policy in [
+			approval_policy(vec![10, 11], 0),
+			approval_policy(vec![10, 11], 3),
+			approval_policy(vec![10, 10], 2),
+		] {
+			assert_noop!(
+				EvmDeploymentControl::set_approval_policy(RuntimeOrigin::root(), Some(policy)),
+				Error::<Test>::InvalidApprovalPolicy
+			);
+		}
+		assert_noop!(
+			EvmDeploymentControl::set_approval_policy(
+				RuntimeOrigin::signed(10),
+				Some(approval_policy(vec![10, 11], 2))
+			),
+			sp_runtime::DispatchError::BadOrigin
+		);
+
+		let policy = approval_policy(vec![10, 11, 12], 2);
+		assert_ok!(EvmDeploymentControl::set_approval_policy(RuntimeOrigin::root(), Some(policy)));
+		System::assert_last_event(Event::ApprovalPolicySet { approvers: 3 }.into());
+	});
+}
+
+#[test]
+fn high_risk_authorization_requires_threshold() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let policy = approval_policy(vec![10, 11, 12], 2);
+		assert_ok!(EvmDeploymentControl::set_approval_policy(RuntimeOrigin::root(), Some(policy)));
+
+		assess(3, 2);
+		assert_ok!(propose(10, 3));
+		System::assert_last_event(
+			Event::AuthorizationProposed { id: 0, deployer: 3, risk_tier: 2, proposer: 10 }.into(),
+		);
+		assert_noop!(
+			EvmDeploymentControl::execute_authorization(RuntimeOrigin::signed(10), 0),
+			Error::<Test>::InsufficientApprovals
+		);
+
+		assert_noop!(
+			EvmDeploymentControl::approve_authorization(RuntimeOrigin::signed(10), 0),
+			Error::<Test>::AlreadyApproved
+		);
+		assert_ok!(EvmDeploymentControl::approve_authorization(RuntimeOrigin::signed(11), 0));
+		System::assert_last_event(
+			Event::AuthorizationApproved { id: 0, approver: 11, approvals: 2 }.into(),
+		);
+
+		assert_ok!(EvmDeploymentControl::execute_authorization(RuntimeOrigin::signed(12), 0));
+		System::assert_last_event(Event::AuthorizationExecuted { id: 0, deployer: 3 }.into());
+		assert!(EvmDeploymentControl::is_authorized(&3));
+		assert_eq!(EvmDeploymentControl::authorization_proposal(0), None);
+		assert_eq!(crate::PendingProposals::<Test>::get(), 0);
+	});
+}
+
+#[test]
+fn low_risk_authorization_needs_single_approval() {
+	new_test_ext().execute_with(|| {
+		let policy = approval_policy(vec![10, 11, 12], 3);
+		assert_ok!(EvmDeploymentControl::set_approval_policy(RuntimeOrigin::root(), Some(policy)));
+
+		assess(3, 1);
+		assert_ok!(propose(10, 3));
+		assert_ok!(EvmDeploymentControl::execute_authorization(RuntimeOrigin::signed(10), 0));
+		assert!(EvmDeploymentControl::is_authorized(&3));
+	});
+}
+
+#[test]
+fn risk_tier_is_assessed_by_governance() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let policy = approval_policy(vec![10, 11, 12], 3);
+		assert_ok!(EvmDeploymentControl::set_approval_policy(RuntimeOrigin::root(), Some(policy)));
+		assert_noop!(
+			EvmDeploymentControl::set_deployer_risk_tier(RuntimeOrigin::signed(10), 3, Some(0)),
+			sp_runtime::DispatchError::BadOrigin
+		);
+
+		// Unassessed accounts are of the highest tier
+		assert_ok!(propose(10, 3));
+		assert_noop!(
+			EvmDeploymentControl::execute_authorization(RuntimeOrigin::signed(10), 0),
+			Error::<Test>::InsufficientApprovals
+		);
+
+		assert_ok!(EvmDeploymentControl::set_deployer_risk_tier(RuntimeOrigin::root(), 3, Some(0)));
+		System::assert_last_event(
+			Event::DeployerRiskTierSet { deployer: 3, risk_tier: Some(0) }.into(),
+		);
+		let proposal = EvmDeploymentControl::authorization_proposal(0).unwrap();
+		assert_eq!((proposal.proposed_at, proposal.expires_at), (1, 11));
+		assert_ok!(EvmDeploymentControl::execute_authorization(RuntimeOrigin::signed(10), 0));
+		assert!(EvmDeploymentControl::is_authorized(&3));
+	});
+}
+
+#[test]
+fn approval_workflow_is_restricted_to_approvers() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(propose(10, 3), Error::<Test>::NoApprovalPolicy);
+
+		let policy = approval_policy(vec![10, 11], 2);
+		assert_ok!(EvmDeploymentControl::set_approval_policy(RuntimeOrigin::root(), Some(policy)));
+		assert_noop!(propose(1, 3), Error::<Test>::NotApprover);
+		assert_noop!(propose(10, 1), Error::<Test>::AlreadyAuthorized);
+
+		assert_ok!(propose(10, 3));
+		assert_noop!(
+			EvmDeploymentControl::approve_authorization(RuntimeOrigin::signed(1), 0),
+			Error::<Test>::NotApprover
+		);
+		assert_noop!(
+			EvmDeploymentControl::approve_authorization(RuntimeOrigin::signed(11), 1),
+			Error::<Test>::UnknownProposal
+		);
+	});
+}
+
+#[test]
+fn removed_approvers_no_longer_count() {
+	new_test_ext().execute_with(|| {
+		let policy = approval_policy(vec![10, 11, 12], 2);
+		assert_ok!(EvmDeploymentControl::set_approval_policy(RuntimeOrigin::root(), Some(policy)));
+		assert_ok!(propose(10, 3));
+		assert_ok!(EvmDeploymentControl::approve_authorization(RuntimeOrigin::signed(11), 0));
+
+		// Approver 10 is dropped from the policy
+		let policy = approval_policy(vec![11, 12], 2);
+		assert_ok!(EvmDeploymentControl::set_approval_policy(RuntimeOrigin::root(), Some(policy)));
+		assert_noop!(
+			EvmDeploymentControl::execute_authorization(RuntimeOrigin::signed(11), 0),
+			Error::<Test>::InsufficientApprovals
+		);
+
+		assert_ok!(EvmDeploymentControl::approve_authorization(RuntimeOrigin::signed(12), 0));
+		assert_ok!(EvmDeploymentControl::execute_authorization(RuntimeOrigin::signed(11), 0));
+		assert!(EvmDeploymentControl::is_authorized(&3));
+	});
+}
+
+#[test]
+fn proposals_are_bounded_and_expire() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let policy = approval_policy(vec![10, 11], 2);
+		assert_ok!(EvmDeploymentControl::set_approval_policy(RuntimeOrigin::root(), Some(policy)));
+
+		assert_ok!(propose(10, 3));
+		assert_ok!(propose(10, 4));
+		assert_noop!(propose(10, 5), Error::<Test>::TooManyProposals);
+		assert_noop!(
+			EvmDeploymentControl::remove_expired_proposal(RuntimeOrigin::signed(1), 0),
+			Error::<Test>::ProposalNotExpired
+		);
+
+		// Proposals live for `ProposalLifetime` blocks
+		System::set_block_number(11);
+		assert_noop!(
+			EvmDeploymentControl::approve_authorization(RuntimeOrigin::signed(11), 0),
+			Error::<Test>::ProposalExpired
+		);
+		assert_noop!(
+			EvmDeploymentControl::execute_authorization(RuntimeOrigin::signed(10), 0),
+			Error::<Test>::ProposalExpired
+		);
+
+		// Anyone can free the slot of an expired proposal
+		assert_ok!(EvmDeploymentControl::remove_expired_proposal(RuntimeOrigin::signed(1), 0));
+		System::assert_last_event(Event::ProposalExpired { id: 0 }.into());
+		assert_ok!(propose(10, 5));
+		assert_eq!(EvmDeploymentControl::authorization_proposal(2).map(|p| p.deployer), Some(5));
+	});
+}
+
+#[test]
+fn set_factory_permissions_works() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let factory = H160::repeat_byte(0x33);
+		let permissions = FactoryPermissions::REGISTER_METADATA;
+
+		assert_noop!(
+			EvmDeploymentControl::set_factory_permissions(
+				RuntimeOrigin::root(),
+				factory,
+				permissions
+			),
+			Error::<Test>::UnknownFactory
+		);
+		assert_ok!(EvmDeploymentControl::register_factory(RuntimeOrigin::root(), factory, 3));
+		assert_noop!(
+			EvmDeploymentControl::set_factory_permissions(
+				RuntimeOrigin::signed(3),
+				factory,
+				permissions
+			),
+			sp_runtime::DispatchError::BadOrigin
+		);
+
+		assert_ok!(EvmDeploymentControl::set_factory_permissions(
+			RuntimeOrigin::root(),
+			factory,
+			permissions
+		));
+		System::assert_last_event(Event::FactoryPermissionsSet { factory, permissions }.into());
+		assert_ok!(EvmDeploymentControl::ensure_factory_permission(&factory, permissions));
+		assert_eq!(
+			EvmDeploymentControl::ensure_factory_permission(
+				&factory,
+				FactoryPermissions::READ_QUOTA
+			),
+			Err(Error::<Test>::MissingFactoryPermission)
+		);
+	});
+}
+
+#[test]
+fn factory_registers_child_metadata() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let factory = H160::repeat_byte(0x33);
+		let child = H160::repeat_byte(0x44);
+		assert_ok!(EvmDeploymentControl::register_factory(RuntimeOrigin::root(), factory, 3));
+		EvmDeploymentControl::note_contract_created(factory, child);
+
+		assert_eq!(
+			EvmDeploymentControl::register_child_metadata(factory, child, metadata(b"pool", b"1")),
+			Err(Error::<Test>::MissingFactoryPermission)
+		);
+		assert_ok!(EvmDeploymentControl::set_factory_permissions(
+			RuntimeOrigin::root(),
+			factory,
+			FactoryPermissions::REGISTER_METADATA
+		));
+
+		assert_ok!(EvmDeploymentControl::register_child_metadata(
+			factory,
+			child,
+			metadata(b"pool", b"1")
+		));
+		System::assert_last_event(
+			Event::ChildMetadataRegistered {
+				factory,
+				contract: child,
+				metadata: metadata(b"pool", b"1"),
+			}
+			.into(),
+		);
+		assert_eq!(EvmDeploymentControl::contract_metadata(&child), Some(metadata(b"pool", b"1")));
+
+		assert_eq!(
+			EvmDeploymentControl::register_child_metadata(factory, child, metadata(b"pool", b"2")),
+			Err(Error::<Test>::MetadataAlreadyRegistered)
+		);
+	});
+}
+
+#[test]
+fn factory_cannot_register_foreign_or_while_suspended() {
+	new_test_ext().execute_with(|| {
+		let factory = H160::repeat_byte(0x33);
+		let foreign = H160::repeat_byte(0x55);
+		assert_ok!(EvmDeploymentControl::register_factory(RuntimeOrigin::root(), factory, 3));
+		assert_ok!(EvmDeploymentControl::set_factory_permissions(
+			RuntimeOrigin::root(),
+			factory,
+			FactoryPermissions::REGISTER_METADATA
+		));
+		EvmDeploymentControl::note_contract_created(H160::repeat_byte(0x11), foreign);
+
+		assert_eq!(
+			EvmDeploymentControl::register_child_metadata(factory, foreign, metadata(b"x", b"1")),
+			Err(Error::<Test>::NotFactoryChild)
+		);
+
+		assert_ok!(EvmDeploymentControl::set_factory_status(
+			RuntimeOrigin::root(),
+			factory,
+			FactoryStatus::Suspended
+		));
+		assert_eq!(
+			EvmDeploymentControl::ensure_factory_permission(
+				&factory,
+				FactoryPermissions::REGISTER_METADATA
+			),
+			Err(Error::<Test>::FactorySuspended)
+		);
+	});
+}
+
+#[test]
+fn remaining_creates_follows_cap() {
+	new_test_ext().execute_with(|| {
+		assert_eq!(EvmDeploymentControl::remaining_creates(), None);
+
+		assert_ok!(EvmDeploymentControl::set_max_creates_per_block(RuntimeOrigin::root(), Some(2)));
+		assert!(EvmDeploymentControl::try_register_create());
+		assert_eq!(EvmDeploymentControl::remaining_creates(), Some(1));
+	});
+}
+
+fn run_to_block(n: u64) {
+	while System::block_number() < n {
+		System::set_block_number(System::block_number() + 1);
+		EvmDeploymentControl::on_initialize(System::block_number());
+	}
+}
+
+#[test]
+fn factory_expiry_is_announced_and_enforced() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let factory = H160::repeat_byte(0x33);
+		assert_ok!(EvmDeploymentControl::register_factory(RuntimeOrigin::root(), factory, 3));
+
+		assert_ok!(EvmDeploymentControl::set_factory_expiry(
+			RuntimeOrigin::root(),
+			factory,
+			Some(20)
+		));
+		System::assert_last_event(Event::FactoryExpirySet { factory, expires_at: Some(20) }.into());
+
+		// The notice comes `FactoryExpiryNotice` blocks ahead
+		run_to_block(14);
+		assert!(!System::events().iter().any(|record| matches!(
+			record.event,
+			RuntimeEvent::EvmDeploymentControl(Event::FactoryExpiringSoon { .. })
+		)));
+		run_to_block(15);
+		System::assert_last_event(Event::FactoryExpiringSoon { factory, expires_at: 20 }.into());
+
+		run_to_block(20);
+		System::assert_last_event(Event::FactoryExpired { factory }.into());
+		assert_eq!(
+			EvmDeploymentControl::factory(&factory).unwrap().status,
+			FactoryStatus::Suspended
+		);
+		assert_eq!(EvmDeploymentControl::factory_expiry(&factory), None);
+	});
+}
+
+#[test]
+fn renewed_factory_skips_stale_schedule() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let factory = H160::repeat_byte(0x33);
+		assert_ok!(EvmDeploymentControl::register_factory(RuntimeOrigin::root(), factory, 3));
+		assert_ok!(EvmDeploymentControl::set_factory_expiry(
+			RuntimeOrigin::root(),
+			factory,
+			Some(10)
+		));
+
+		// Governance renews the approval before the original expiry
+		assert_ok!(EvmDeploymentControl::set_factory_expiry(
+			RuntimeOrigin::root(),
+			factory,
+			Some(30)
+		));
+		run_to_block(10);
+		assert_eq!(EvmDeploymentControl::factory(&factory).unwrap().status, FactoryStatus::Active);
+		assert_eq!(EvmDeploymentControl::factory_expiry(&factory), Some(30));
+
+		// Clearing the expiry cancels it altogether
+		assert_ok!(EvmDeploymentControl::set_factory_expiry(RuntimeOrigin::root(), factory, None));
+		run_to_block(30);
+		assert_eq!(EvmDeploymentControl::factory(&factory).unwrap().status, FactoryStatus::Active);
+	});
+}
+
+#[test]
+fn set_factory_expiry_validates_input() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(5);
+		let factory = H160::repeat_byte(0x33);
+
+		assert_noop!(
+			EvmDeploymentControl::set_factory_expiry(RuntimeOrigin::root(), factory, Some(20)),
+			Error::<Test>::UnknownFactory
+		);
+		assert_ok!(EvmDeploymentControl::register_factory(RuntimeOrigin::root(), factory, 3));
+		assert_noop!(
+			EvmDeploymentControl::set_factory_expiry(RuntimeOrigin::root(), factory, Some(5)),
+			Error::<Test>::ExpiryInPast
+		);
+
+		// `MaxExpiriesPerBlock` events fit in one block
+		for byte in [0x34, 0x35] {
+			let other = H160::repeat_byte(byte);
+			assert_ok!(EvmDeploymentControl::register_factory(RuntimeOrigin::root(), other, 3));
+			assert_ok!(EvmDeploymentControl::set_factory_expiry(
+				RuntimeOrigin::root(),
+				other,
+				Some(20)
+			));
+		}
+		assert_noop!(
+			EvmDeploymentControl::set_factory_expiry(RuntimeOrigin::root(), factory, Some(20)),
+			Error::<Test>::ExpiryScheduleFull
+		);
+	});
+}
+
+#[test]
+fn authorization_checks_are_cached_within_the_block() {
+	new_test_ext().execute_with(|| {
+		assert!(EvmDeploymentControl::is_authorized(&1));
+		assert!(!EvmDeploymentControl::is_authorized(&3));
+		assert_eq!(AuthorizationCache::<Test>::get(1), Some(true));
+		assert_eq!(AuthorizationCache::<Test>::get(3), Some(false));
+		assert_eq!(CachedAuthorizations::<Test>::get(), 2);
+
+		// Further checks are answered by the cache
+		AuthorizedDeployers::<Test>::remove(1);
+		assert!(EvmDeploymentControl::is_authorized(&1));
+
+		// A full cache leaves new checks uncached
+		assert!(!EvmDeploymentControl::is_authorized(&4));
+		assert_eq!(AuthorizationCache::<Test>::get(4), None);
+		assert_eq!(CachedAuthorizations::<Test>::get(), 2);
+
+		// The cache does not outlive the block
+		EvmDeploymentControl::on_finalize(1);
+		assert_eq!(AuthorizationCache::<Test>::iter().count(), 0);
+		assert_eq!(CachedAuthorizations::<Test>::get(), 0);
+		assert!(!EvmDeploymentControl::is_authorized(&1));
+	});
+}
+
+#[test]
+fn authorization_changes_invalidate_cached_checks() {
+	new_test_ext().execute_with(|| {
+		assert!(!EvmDeploymentControl::is_authorized(&3));
+		assert_ok!(EvmDeploymentControl::authorize_deployer(RuntimeOrigin::root(), 3));
+		assert_eq!(AuthorizationCache::<Test>::get(3), None);
+		assert!(EvmDeploymentControl::is_authorized(&3));
+
+		assert_ok!(EvmDeploymentControl::revoke_deployer(RuntimeOrigin::root(), 3));
+		assert_eq!(AuthorizationCache::<Test>::get(3), None);
+		assert!(!EvmDeploymentControl::is_authorized(&3));
+
+		// Dropped entries free their slot
+		assert_eq!(CachedAuthorizations::<Test>::get(), 1);
+	});
+}
+
+fn intent_bond(who: u64) -> u64 {
+	Balances::balance_on_hold(&HoldReason::DeploymentIntent.into(), &who)
+}
+
+#[test]
+fn announce_deployment_holds_bond() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(3);
+		let code_hash = H256::repeat_byte(0xaa);
+		let label: frame_support::BoundedVec<u8, ConstU32<16>> =
+			b"Router".to_vec().try_into().unwrap();
+
+		assert_ok!(EvmDeploymentControl::announce_deployment(
+			RuntimeOrigin::signed(1),
+			code_hash,
+			label.clone()
+		));
+
+		System::assert_last_event(
+			Event::DeploymentAnnounced { deployer: 1, code_hash, label: label.clone() }.into(),
+		);
+		assert_eq!(
+			EvmDeploymentControl::deployment_intent(&1, &code_hash),
+			Some(DeploymentIntent { label, bond: 10, announced_at: 3 })
+		);
+		assert_eq!(intent_bond(1), 10);
+		assert_eq!(EvmDeploymentControl::announced_intents(&1), vec![code_hash]);
+	});
+}
+
+#[test]
+fn slash_deployment_intent_pays_bond_into_proceeds() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let code_hash = H256::repeat_byte(0xaa);
+		let label = b"Router".to_vec().try_into().unwrap();
+		assert_ok!(EvmDeploymentControl::announce_deployment(
+			RuntimeOrigin::signed(1),
+			code_hash,
+			label
+		));
+
+		// Only the admin origin can slash
+		assert_noop!(
+			EvmDeploymentControl::slash_deployment_intent(RuntimeOrigin::signed(2), 1, code_hash),
+			sp_runtime::DispatchError::BadOrigin
+		);
+		assert_noop!(
+			EvmDeploymentControl::slash_deployment_intent(
+				RuntimeOrigin::root(),
+				1,
+				H256::repeat_byte(0xbb)
+			),
+			Error::<Test>::UnknownIntent
+		);
+
+		assert_ok!(EvmDeploymentControl::slash_deployment_intent(
+			RuntimeOrigin::root(),
+			1,
+			code_hash
+		));
+		System::assert_last_event(
+			Event::DeploymentIntentSlashed { deployer: 1, code_hash, amount: 10 }.into(),
+		);
+		assert_eq!(EvmDeploymentControl::deployment_intent(&1, &code_hash), None);
+		assert_eq!(intent_bond(1), 0);
+		assert_eq!(Balances::free_balance(1), 90);
+		assert_eq!(Balances::free_balance(ProceedsAccount::get()), 10);
+	});
+}
+
+#[test]
+fn announce_deployment_checks_caller() {
+	new_test_ext().execute_with(|| {
+		let code_hash = H256::repeat_byte(0xaa);
+		let label = || b"Router".to_vec().try_into().unwrap();
+
+		assert_noop!(
+			EvmDeploymentControl::announce_deployment(RuntimeOrigin::signed(3), code_hash, label()),
+			Error::<Test>::NotAuthorized
+		);
+		// Account 2 cannot afford the bond
+		assert_noop!(
+			EvmDeploymentControl::announce_deployment(RuntimeOrigin::signed(2), code_hash, label()),
+			TokenError::FundsUnavailable
+		);
+
+		assert_ok!(EvmDeploymentControl::announce_deployment(
+			RuntimeOrigin::signed(1),
+			code_hash,
+			label()
+		));
+		assert_noop!(
+			EvmDeploymentControl::announce_deployment(RuntimeOrigin::signed(1), code_hash, label()),
+			Error::<Test>::IntentAlreadyAnnounced
+		);
+	});
+}
+
+#[test]
+fn announced_deployment_releases_bond() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let init_code = vec![0x60, 0x00];
+		let code_hash = H256::from(keccak_256(&init_code));
+		assert_ok!(EvmDeploymentControl::announce_deployment(
+			RuntimeOrigin::signed(1),
+			code_hash,
+			b"Token".to_vec().try_into().unwrap()
+		));
+
+		assert_ok!(deploy(1, init_code));
+
+		let contract = H160::from_low_u64_be(0x1002);
+		System::assert_has_event(
+			Event::DeploymentIntentFulfilled { deployer: 1, code_hash, contract }.into(),
+		);
+		assert_eq!(EvmDeploymentControl::deployment_intent(&1, &code_hash), None);
+		assert_eq!(FulfilledIntents::<Test>::get(contract).map(|(hash, _)| hash), Some(code_hash));
+		assert_eq!(intent_bond(1), 0);
+		assert_eq!(PendingIntent::<Test>::get(), None);
+	});
+}
+
+#[test]
+fn unannounced_deployment_keeps_bond() {
+	new_test_ext().execute_with(|| {
+		let code_hash = H256::from(keccak_256(&[0x60, 0x00]));
+		assert_ok!(EvmDeploymentControl::announce_deployment(
+			RuntimeOrigin::signed(1),
+			code_hash,
+			b"Token".to_vec().try_into().unwrap()
+		));
+
+		// Other init code does not match the intent
+		assert_ok!(deploy(1, vec![0x60, 0x01, 0x00]));
+		assert!(DeploymentIntents::<Test>::contains_key(1, code_hash));
+		assert_eq!(intent_bond(1), 10);
+
+		// Nor does the same init code deployed by another account
+		EvmDeploymentControl::note_pending_creation(&2, code_hash);
+		assert_eq!(PendingIntent::<Test>::get(), None);
+	});
+}
+
+#[test]
+fn block_decisions_are_deposited_in_the_digest() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(deploy(1, vec![0x60, 0x00]));
+		assert_ok!(deploy(2, vec![0x60, 0x01, 0x00]));
+		EvmDeploymentControl::note_rejected_attempt(&3);
+		EvmDeploymentControl::note_rejected_attempt(&4);
+
+		EvmDeploymentControl::on_finalize(1);
+
+		assert_eq!(
+			DeploymentDecisions::<u64>::find(&System::digest()),
+			Some(DeploymentDecisions { allowed: 2, denied: 2, first_denied: Some(3) })
+		);
+	});
+}
+
+#[test]
+fn blocks_without_decisions_have_no_digest_item() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		EvmDeploymentControl::on_finalize(1);
+
+		assert_eq!(DeploymentDecisions::<u64>::find(&System::digest()), None);
+	});
+}
+
+#[test]
+fn contract_creations_are_indexed_offchain_per_block() {
+	let mut ext = new_test_ext();
+	let parent_hash = H256::repeat_byte(0x01);
+	let digest = |slot: u64| Digest { logs: vec![DigestItem::PreRuntime(*b"aura", slot.encode())] };
+	let (deployer, factory, child) =
+		(H160::from_low_u64_be(1), H160::repeat_byte(0x33), H160::repeat_byte(0x01));
+
+	ext.execute_with(|| {
+		System::initialize(&1, &parent_hash, &digest(7));
+		System::set_extrinsic_index(2);
+		EvmDeploymentControl::note_contract_created(deployer, factory);
+		EvmDeploymentControl::note_contract_created(factory, child);
+		EvmDeploymentControl::note_rejected_attempt(&3);
+		EvmDeploymentControl::on_finalize(1);
+
+		// A sibling block, built on the same parent in another slot
+		System::initialize(&1, &parent_hash, &digest(8));
+		EvmDeploymentControl::on_finalize(1);
+	});
+	ext.persist_offchain_overlay();
+
+	let traces = |slot| {
+		let key = CreationTrace::offchain_key(&parent_hash, &digest(slot));
+		ext.offchain_db()
+			.get(&key)
+			.map(|traces| Vec::<CreationTrace>::decode(&mut &traces[..]).unwrap())
+	};
+	assert_eq!(
+		traces(7),
+		Some(vec![
+			CreationTrace { extrinsic_index: 2, creator: deployer, contract: factory },
+			CreationTrace { extrinsic_index: 2, creator: factory, contract: child },
+		])
+	);
+	assert_eq!(traces(8), Some(vec![]));
+	assert_eq!(traces(9), None);
+}
+
+#[test]
+fn preview_calls_reports_registry_changes_without_applying_them() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let factory = H160::repeat_byte(0x33);
+
+		let (diff, failure) = EvmDeploymentControl::preview_calls(vec![
+			crate::Call::authorize_deployer { deployer: 3 },
+			crate::Call::revoke_deployer { deployer: 2 },
+			crate::Call::register_factory { factory, admin: 3 },
+		]);
+
+		assert_eq!(
+			diff,
+			RegistryDiff {
+				added_deployers: vec![3],
+				removed_deployers: vec![2],
+				added_factories: vec![factory],
+				removed_factories: vec![],
+			}
+		);
+		assert_eq!(failure, None);
+
+		// Nothing is applied
+		assert!(!EvmDeploymentControl::is_authorized(&3));
+		assert!(EvmDeploymentControl::is_authorized(&2));
+		assert_eq!(EvmDeploymentControl::factory(&factory), None);
+		assert!(System::events().is_empty());
+	});
+}
+
+#[test]
+fn preview_calls_stops_at_the_first_failing_call() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		let (diff, failure) = EvmDeploymentControl::preview_calls(vec![
+			crate::Call::authorize_deployer { deployer: 3 },
+			crate::Call::authorize_deployer { deployer: 1 },
+			crate::Call::register_factory { factory: H160::repeat_byte(0x33), admin: 3 },
+		]);
+
+		assert_eq!(diff, RegistryDiff { added_deployers: vec![3], ..Default::default() });
+		assert_eq!(failure, Some((1, Error::<Test>::AlreadyAuthorized.into())));
+	});
+}
+
+#[test]
+fn set_deployment_policy_works() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_eq!(EvmDeploymentControl::deployment_policy(), DeploymentPolicy::Permissioned);
+
+		assert_noop!(
+			EvmDeploymentControl::set_deployment_policy(
+				RuntimeOrigin::signed(1),
+				DeploymentPolicy::Permissionless
+			),
+			sp_runtime::DispatchError::BadOrigin
+		);
+
+		assert_ok!(EvmDeploymentControl::set_deployment_policy(
+			RuntimeOrigin::root(),
+			DeploymentPolicy::Permissionless
+		));
+		assert_eq!(EvmDeploymentControl::deployment_policy(), DeploymentPolicy::Permissionless);
+		System::assert_last_event(
+			Event::DeploymentPolicySet { policy: DeploymentPolicy::Permissionless }.into(),
+		);
+	});
+}
+
+#[test]
+fn permissionless_policy_admits_every_deployer() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(EvmDeploymentControl::set_deployment_policy(
+			RuntimeOrigin::root(),
+			DeploymentPolicy::Permissionless
+		));
+
+		assert_eq!(EvmDeploymentControl::ensure_can_deploy(&3), Ok(()));
+		assert_eq!(EvmDeploymentControl::ensure_can_deploy(&4), Ok(()));
+		assert_ok!(deploy(3, vec![0x60, 0x00]));
+
+		// Deployment windows still apply to everyone
+		let windows = vec![window(100, 10, 5)].try_into().unwrap();
+		assert_ok!(EvmDeploymentControl::set_deployment_windows(RuntimeOrigin::root(), windows));
+		assert_eq!(
+			EvmDeploymentControl::ensure_can_deploy(&3),
+			Err(DeploymentValidationError::OutsideDeploymentWindow)
+		);
+	});
+}
+
+#[test]
+fn permissionless_policy_with_fee_charges_unauthorized_deployers() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let policy = DeploymentPolicy::PermissionlessWithFee { fee: 30 };
+		assert_ok!(EvmDeploymentControl::set_deployment_policy(RuntimeOrigin::root(), policy));
+
+		// Unauthorized deployers pay the fee into the deployment proceeds
+		let issuance = Balances::total_issuance();
+		assert_eq!(EvmDeploymentControl::ensure_can_deploy(&3), Ok(()));
+		assert_ok!(deploy(3, vec![0x60, 0x00]));
+		assert_eq!(Balances::free_balance(3), 70);
+		assert_eq!(Balances::free_balance(ProceedsAccount::get()), 30);
+		assert_eq!(Balances::total_issuance(), issuance);
+		System::assert_has_event(Event::DeploymentFeePaid { deployer: 3, fee: 30 }.into());
+
+		// Authorized deployers do not, even if they could not afford it
+		assert_eq!(EvmDeploymentControl::ensure_can_deploy(&2), Ok(()));
+		assert_ok!(deploy(2, vec![0x60, 0x01, 0x00]));
+		assert_eq!(Balances::free_balance(2), 5);
+
+		// Accounts that cannot pay are refused
+		assert_eq!(
+			EvmDeploymentControl::ensure_can_deploy(&4),
+			Err(DeploymentValidationError::InsufficientDeploymentFee)
+		);
+		assert_noop!(deploy(4, vec![0x60, 0x02, 0x00]), Error::<Test>::InsufficientDeploymentFee);
+	});
+}
+
+#[test]
+fn rollout_admits_a_share_of_unauthorized_deployers() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let admitted = |share: u8| {
+			let policy = DeploymentPolicy::Rollout { admitted: Percent::from_percent(share) };
+			EvmDeploymentControl::set_deployment_policy(RuntimeOrigin::root(), policy).unwrap();
+			(10..210u64).filter(|who| EvmDeploymentControl::ensure_can_deploy(who).is_ok()).count()
+		};
+
+		assert_eq!(admitted(0), 0);
+		assert_eq!(admitted(100), 200);
+		let half = admitted(50);
+		assert!((60..140).contains(&half), "{half} of 200 admitted");
+
+		// Authorized deployers are admitted whatever the draw
+		assert_eq!(EvmDeploymentControl::ensure_can_deploy(&1), Ok(()));
+	});
+}
+
+#[test]
+fn rollout_draw_depends_on_the_parent_block() {
+	new_test_ext().execute_with(|| {
+		let half = Percent::from_percent(50);
+		let drawn = |parent_hash: H256| {
+			System::set_parent_hash(parent_hash);
+			(10..210u64)
+				.filter(|who| EvmDeploymentControl::is_drawn_for_rollout(who, half))
+				.collect::<Vec<_>>()
+		};
+
+		// The same block admits the same deployers, another block draws again
+		assert_eq!(drawn(H256::repeat_byte(1)), drawn(H256::repeat_byte(1)));
+		assert_ne!(drawn(H256::repeat_byte(1)), drawn(H256::repeat_byte(2)));
+	});
+}
+
+#[test]
+fn root_rotates_deployer_key_at_once() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(EvmDeploymentControl::set_window_override(RuntimeOrigin::root(), 1, true));
+
+		assert_noop!(
+			EvmDeploymentControl::rotate_deployer_key(RuntimeOrigin::root(), 1, 2),
+			Error::<Test>::AlreadyAuthorized
+		);
+		assert_noop!(
+			EvmDeploymentControl::rotate_deployer_key(RuntimeOrigin::root(), 3, 4),
+			Error::<Test>::NotAuthorized
+		);
+		assert_noop!(
+			EvmDeploymentControl::rotate_deployer_key(RuntimeOrigin::signed(2), 1, 4),
+			sp_runtime::DispatchError::BadOrigin
+		);
+
+		assert_ok!(EvmDeploymentControl::rotate_deployer_key(RuntimeOrigin::root(), 1, 4));
+		System::assert_last_event(Event::DeployerKeyRotated { old: 1, new: 4 }.into());
+
+		assert!(!EvmDeploymentControl::is_authorized(&1));
+		assert!(EvmDeploymentControl::is_authorized(&4));
+		assert!(!WindowOverrides::<Test>::contains_key(1));
+		assert!(WindowOverrides::<Test>::contains_key(4));
+		assert_eq!(lifecycle_calls().last(), Some(&LifecycleCall::KeyRotated(1, 4)));
+
+		// The genesis authorization is carried over, flagged as rotated
+		let flags = AuthorizedDeployers::<Test>::get(4).unwrap().flags;
+		assert!(flags.contains(DeployerFlags::GENESIS) && flags.contains(DeployerFlags::ROTATED));
+	});
+}
+
+#[test]
+fn deployer_rotates_own_key_after_delay() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let code_hash = H256::repeat_byte(0xaa);
+		let label = b"Router".to_vec().try_into().unwrap();
+		assert_ok!(EvmDeploymentControl::announce_deployment(
+			RuntimeOrigin::signed(1),
+			code_hash,
+			label
+		));
+		assert_noop!(
+			EvmDeploymentControl::rotate_deployer_key(RuntimeOrigin::signed(1), 1, 4),
+			Error::<Test>::IntentsOutstanding
+		);
+		DeploymentIntents::<Test>::remove(1, code_hash);
+
+		assert_ok!(EvmDeploymentControl::rotate_deployer_key(RuntimeOrigin::signed(1), 1, 4));
+		System::assert_last_event(
+			Event::KeyRotationAnnounced { old: 1, new: 4, ready_at: 11 }.into(),
+		);
+		assert!(EvmDeploymentControl::is_authorized(&1));
+
+		System::set_block_number(10);
+		assert_noop!(
+			EvmDeploymentControl::rotate_deployer_key(RuntimeOrigin::signed(1), 1, 4),
+			Error::<Test>::KeyRotationNotReady
+		);
+
+		System::set_block_number(11);
+		assert_ok!(EvmDeploymentControl::rotate_deployer_key(RuntimeOrigin::signed(1), 1, 4));
+		assert!(EvmDeploymentControl::is_authorized(&4));
+		assert!(!PendingKeyRotations::<Test>::contains_key(1));
+	});
+}
+
+#[test]
+fn revocation_cancels_announced_key_rotation() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(EvmDeploymentControl::rotate_deployer_key(RuntimeOrigin::signed(1), 1, 4));
+		assert_ok!(EvmDeploymentControl::revoke_deployer(RuntimeOrigin::root(), 1));
+
+		assert!(!PendingKeyRotations::<Test>::contains_key(1));
+	});
+}
+
+#[test]
+fn operator_deploys_from_contract_wallet() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let wallet = H160::from_low_u64_be(7);
+		let deploy_from_wallet = |who: u64| {
+			EvmDeploymentControl::deploy_contract(
+				RuntimeOrigin::signed(who),
+				wallet,
+				vec![0x60, 0x00],
+				0.into(),
+				1_000_000,
+				metadata(b"Token", b"1.0.0"),
+			)
+		};
+
+		assert_ok!(EvmDeploymentControl::authorize_contract_wallet(
+			RuntimeOrigin::root(),
+			wallet,
+			3
+		));
+		System::assert_last_event(Event::ContractWalletAuthorized { wallet, operator: 3 }.into());
+		assert!(EvmDeploymentControl::is_authorized(&7));
+
+		// Only the operator signs for the wallet
+		assert_noop!(deploy_from_wallet(4), Error::<Test>::InvalidSource);
+		assert_ok!(deploy_from_wallet(3));
+
+		// The wallet is the deployer of record
+		let contract = H160::from_low_u64_be(0x1002);
+		System::assert_last_event(
+			Event::ContractDeployed {
+				deployer: 7,
+				contract,
+				metadata: metadata(b"Token", b"1.0.0"),
+			}
+			.into(),
+		);
+		assert_eq!(EvmDeploymentControl::deployer_of(&contract), Some(wallet));
+	});
+}
+
+#[test]
+fn revoking_contract_wallet_removes_operator() {
+	new_test_ext().execute_with(|| {
+		let wallet = H160::from_low_u64_be(7);
+		assert_ok!(EvmDeploymentControl::authorize_contract_wallet(
+			RuntimeOrigin::root(),
+			wallet,
+			3
+		));
+		assert!(EvmDeploymentControl::controls(&3, &7));
+
+		assert_ok!(EvmDeploymentControl::revoke_deployer(RuntimeOrigin::root(), 7));
+
+		assert!(!ContractWalletOperators::<Test>::contains_key(7));
+		assert!(!EvmDeploymentControl::controls(&3, &7));
+	});
+}
+
+#[test]
+fn long_factory_metadata_is_kept_in_preimage_store() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let factory = H160::repeat_byte(0xfa);
+		let blob = vec![7u8; 300];
+		assert_ok!(EvmDeploymentControl::register_factory(RuntimeOrigin::root(), factory, 3));
+
+		// Only Root and the factory's admin set its metadata
+		assert_noop!(
+			EvmDeploymentControl::set_factory_metadata(
+				RuntimeOrigin::signed(1),
+				factory,
+				Some(blob.clone())
+			),
+			sp_runtime::DispatchError::BadOrigin
+		);
+		assert_noop!(
+			EvmDeploymentControl::set_factory_metadata(
+				RuntimeOrigin::signed(3),
+				factory,
+				Some(vec![0; 513])
+			),
+			Error::<Test>::MetadataTooLong
+		);
+		assert_ok!(EvmDeploymentControl::set_factory_metadata(
+			RuntimeOrigin::signed(3),
+			factory,
+			Some(blob.clone())
+		));
+
+		let stored = FactoryMetadata::<Test>::get(factory).unwrap();
+		let hash = stored.hash();
+		assert!(stored.lookup_needed());
+		assert!(Preimage::is_requested(&hash));
+		assert_eq!(EvmDeploymentControl::factory_metadata(&factory), Some(blob));
+		System::assert_last_event(
+			Event::FactoryMetadataSet { factory, metadata: Some(hash) }.into(),
+		);
+
+		// Unregistering the factory releases the preimage
+		assert_ok!(EvmDeploymentControl::unregister_factory(RuntimeOrigin::root(), factory));
+		System::assert_last_event(Event::FactoryUnregistered { factory }.into());
+		assert!(EvmDeploymentControl::factory(&factory).is_none());
+		assert!(!Preimage::is_requested(&hash));
+		assert_eq!(EvmDeploymentControl::factory_metadata(&factory), None);
+	});
+}
+
+#[test]
+fn replacing_metadata_releases_previous_preimage() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(EvmDeploymentControl::set_deployer_metadata(
+			RuntimeOrigin::signed(1),
+			Some(vec![1; 200])
+		));
+		let first = DeployerMetadata::<Test>::get(1).unwrap().hash();
+
+		// Short blobs are kept inline
+		assert_ok!(EvmDeploymentControl::set_deployer_metadata(
+			RuntimeOrigin::signed(1),
+			Some(b"audited".to_vec())
+		));
+		assert!(!Preimage::is_requested(&first));
+		assert!(!DeployerMetadata::<Test>::get(1).unwrap().lookup_needed());
+		assert_eq!(EvmDeploymentControl::deployer_metadata(&1), Some(b"audited".to_vec()));
+
+		assert_ok!(EvmDeploymentControl::set_deployer_metadata(RuntimeOrigin::signed(1), None));
+		System::assert_last_event(
+			Event::DeployerMetadataSet { deployer: 1, metadata: None }.into(),
+		);
+		assert_eq!(EvmDeploymentControl::deployer_metadata(&1), None);
+
+		assert_noop!(
+			EvmDeploymentControl::set_deployer_metadata(RuntimeOrigin::signed(4), Some(vec![1])),
+			Error::<Test>::NotAuthorized
+		);
+	});
+}
+
+#[test]
+fn revocation_releases_deployer_metadata() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(EvmDeploymentControl::set_deployer_metadata(
+			RuntimeOrigin::signed(1),
+			Some(vec![1; 200])
+		));
+		let hash = DeployerMetadata::<Test>::get(1).unwrap().hash();
+
+		// Rotation carries the metadata over
+		assert_ok!(EvmDeploymentControl::rotate_deployer_key(RuntimeOrigin::root(), 1, 5));
+		assert_eq!(EvmDeploymentControl::deployer_metadata(&5), Some(vec![1; 200]));
+		assert!(Preimage::is_requested(&hash));
+
+		assert_ok!(EvmDeploymentControl::revoke_deployer(RuntimeOrigin::root(), 5));
+		assert!(!DeployerMetadata::<Test>::contains_key(5));
+		assert!(!Preimage::is_requested(&hash));
+	});
+}
+
+#[test]
+fn deployer_admin_dispatches_registry_calls() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let authorize = |deployer: u64| {
+			Box::new(RuntimeCall::EvmDeploymentControl(crate::Call::authorize_deployer {
+				deployer,
+			}))
+		};
+
+		assert_noop!(
+			EvmDeploymentControl::dispatch_as_deployer_admin(
+				RuntimeOrigin::signed(4),
+				authorize(5)
+			),
+			Error::<Test>::NotDeployerAdmin
+		);
+
+		assert_ok!(EvmDeploymentControl::set_deployer_admin(RuntimeOrigin::root(), 4, true));
+		System::assert_last_event(Event::DeployerAdminSet { account: 4, is_admin: true }.into());
+		assert_ok!(EvmDeploymentControl::dispatch_as_deployer_admin(
+			RuntimeOrigin::signed(4),
+			authorize(5)
+		));
+		assert!(EvmDeploymentControl::is_authorized(&5));
+		System::assert_last_event(
+			Event::DeployerAdminDispatched { admin: 4, result: Ok(()) }.into(),
+		);
+
+		// Calls reserved to Root stay out of reach
+		let set_policy =
+			Box::new(RuntimeCall::EvmDeploymentControl(crate::Call::set_deployment_policy {
+				policy: DeploymentPolicy::Permissionless,
+			}));
+		assert_ok!(EvmDeploymentControl::dispatch_as_deployer_admin(
+			RuntimeOrigin::signed(4),
+			set_policy
+		));
+		System::assert_last_event(
+			Event::DeployerAdminDispatched {
+				admin: 4,
+				result: Err(sp_runtime::DispatchError::BadOrigin),
+			}
+			.into(),
+		);
+
+		assert_ok!(EvmDeploymentControl::set_deployer_admin(RuntimeOrigin::root(), 4, false));
+		assert_noop!(
+			EvmDeploymentControl::dispatch_as_deployer_admin(
+				RuntimeOrigin::signed(4),
+				authorize(6)
+			),
+			Error::<Test>::NotDeployerAdmin
+		);
+	});
+}
+
+#[test]
+fn ensure_deployer_admin_accepts_root_and_admin_origin() {
+	new_test_ext().execute_with(|| {
+		type Ensure = EnsureDeployerAdmin<u64>;
+
+		assert!(Ensure::try_origin(RuntimeOrigin::root()).is_ok());
+		assert!(Ensure::try_origin(RuntimeOrigin::from(crate::Origin::DeployerAdmin)).is_ok());
+		assert!(Ensure::try_origin(RuntimeOrigin::signed(1)).is_err());
+		assert!(Ensure::try_origin(RuntimeOrigin::none()).is_err());
+	});
+}
+
+#[test]
+fn authorization_transitions_answer_past_blocks() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(EvmDeploymentControl::revoke_deployer(RuntimeOrigin::root(), 1));
+		System::set_block_number(5);
+		assert_ok!(EvmDeploymentControl::authorize_deployer(RuntimeOrigin::root(), 1));
+		System::set_block_number(8);
+		assert_ok!(EvmDeploymentControl::authorize_deployer(RuntimeOrigin::root(), 3));
+
+		// Account 1 was authorized from genesis until block 1
+		assert_eq!(EvmDeploymentControl::was_authorized_at(&1, 0), Some(true));
+		assert_eq!(EvmDeploymentControl::was_authorized_at(&1, 1), Some(false));
+		assert_eq!(EvmDeploymentControl::was_authorized_at(&1, 4), Some(false));
+		assert_eq!(EvmDeploymentControl::was_authorized_at(&1, 5), Some(true));
+		assert_eq!(EvmDeploymentControl::was_authorized_at(&3, 7), Some(false));
+		assert_eq!(EvmDeploymentControl::was_authorized_at(&3, 8), Some(true));
+		// Untouched accounts keep their status
+		assert_eq!(EvmDeploymentControl::was_authorized_at(&4, 2), Some(false));
+		assert_eq!(EvmDeploymentControl::was_authorized_at(&4, 9), None);
+	});
+}
+
+#[test]
+fn authorization_transitions_drop_the_oldest() {
+	new_test_ext().execute_with(|| {
+		for block in 1..=4 {
+			System::set_block_number(block);
+			if block % 2 == 1 {
+				assert_ok!(EvmDeploymentControl::authorize_deployer(RuntimeOrigin::root(), 3));
+			} else {
+				assert_ok!(EvmDeploymentControl::revoke_deployer(RuntimeOrigin::root(), 3));
+			}
+		}
+
+		let log = AuthorizationTransitions::<Test>::get(3).unwrap();
+		assert_eq!(log.since, 1);
+		assert_eq!(log.transitions.into_inner(), vec![(2, false), (3, true), (4, false)]);
+		// The status between the dropped transition and the oldest kept is still known
+		assert_eq!(EvmDeploymentControl::was_authorized_at(&3, 0), None);
+		assert_eq!(EvmDeploymentControl::was_authorized_at(&3, 1), Some(true));
+		assert_eq!(EvmDeploymentControl::was_authorized_at(&3, 3), Some(true));
+	});
+}
+
+#[test]
+fn authorization_transitions_undone_within_a_block_are_forgotten() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(3);
+		assert_ok!(EvmDeploymentControl::authorize_deployer(RuntimeOrigin::root(), 3));
+		assert_ok!(EvmDeploymentControl::revoke_deployer(RuntimeOrigin::root(), 3));
+
+		assert!(AuthorizationTransitions::<Test>::get(3).unwrap().transitions.is_empty());
+		assert_eq!(EvmDeploymentControl::was_authorized_at(&3, 3), Some(false));
+	});
+}
+
+#[test]
+fn authorization_index_starts_at_the_first_transition_after_an_upgrade() {
+	new_test_ext().execute_with(|| {
+		AuthorizationIndexSince::<Test>::kill();
+		assert_eq!(EvmDeploymentControl::was_authorized_at(&1, 0), None);
+
+		System::set_block_number(6);
+		assert_ok!(EvmDeploymentControl::authorize_deployer(RuntimeOrigin::root(), 3));
+
+		assert_eq!(AuthorizationIndexSince::<Test>::get(), Some(6));
+		assert_eq!(EvmDeploymentControl::was_authorized_at(&1, 5), None);
+		assert_eq!(EvmDeploymentControl::was_authorized_at(&1, 6), Some(true));
+		assert_eq!(EvmDeploymentControl::was_authorized_at(&3, 5), None);
+	});
+}
+
+#[test]
+fn deployment_quota_is_set_by_root() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			EvmDeploymentControl::set_deployment_quota(RuntimeOrigin::signed(1), Some(2)),
+			sp_runtime::DispatchError::BadOrigin
+		);
+
+		assert_ok!(EvmDeploymentControl::set_deployment_quota(RuntimeOrigin::root(), Some(2)));
+		assert_eq!(DeploymentQuota::<Test>::get(), Some(2));
+		System::assert_last_event(Event::DeploymentQuotaSet { quota: Some(2) }.into());
+
+		assert_ok!(EvmDeploymentControl::set_deployment_quota(RuntimeOrigin::root(), None));
+		assert_eq!(EvmDeploymentControl::remaining_quota(&1), None);
+	});
+}
+
+#[test]
+fn deployment_quota_resets_on_first_use_of_a_period() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(EvmDeploymentControl::set_deployment_quota(RuntimeOrigin::root(), Some(2)));
+		System::set_block_number(5);
+		assert_ok!(deploy(1, vec![0x60]));
+		assert_ok!(deploy(1, vec![0x60, 0x00]));
+
+		assert_eq!(EvmDeploymentControl::remaining_quota(&1), Some(0));
+		assert_eq!(EvmDeploymentControl::quota_resets_at(5), 10);
+		assert_eq!(
+			EvmDeploymentControl::ensure_can_deploy(&1),
+			Err(DeploymentValidationError::QuotaExhausted)
+		);
+		assert_noop!(deploy(1, vec![0x60, 0x00, 0x60]), Error::<Test>::QuotaExhausted);
+		// Quotas are per deployer
+		assert_eq!(EvmDeploymentControl::remaining_quota(&2), Some(2));
+
+		// The count of the last period is only reset by the first creation of the next one
+		System::set_block_number(10);
+		assert_eq!(EvmDeploymentControl::remaining_quota(&1), Some(2));
+		assert_eq!(DeploymentQuotaUsage::<Test>::get(1), Some(QuotaUsage { period: 0, used: 2 }));
+		assert_ok!(deploy(1, vec![0x60, 0x00, 0x60]));
+		assert_eq!(DeploymentQuotaUsage::<Test>::get(1), Some(QuotaUsage { period: 1, used: 1 }));
+	});
+}
+
+#[test]
+fn key_rotation_keeps_the_quota_used() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(EvmDeploymentControl::set_deployment_quota(RuntimeOrigin::root(), Some(2)));
+		System::set_block_number(5);
+		assert_ok!(deploy(1, vec![0x60]));
+		assert_eq!(EvmDeploymentControl::remaining_quota(&1), Some(1));
+
+		assert_ok!(EvmDeploymentControl::rotate_deployer_key(RuntimeOrigin::root(), 1, 4));
+		assert_eq!(DeploymentQuotaUsage::<Test>::get(1), None);
+		assert_eq!(DeploymentQuotaUsage::<Test>::get(4), Some(QuotaUsage { period: 0, used: 1 }));
+		assert_eq!(EvmDeploymentControl::remaining_quota(&4), Some(1));
+	});
+}
+
+#[test]
+fn creations_are_not_counted_without_a_quota() {
+	new_test_ext().execute_with(|| {
+		assert!(EvmDeploymentControl::try_use_quota(&1));
+		assert_eq!(DeploymentQuotaUsage::<Test>::get(1), None);
+	});
+}
+
+#[test]
+fn deploy_contract_refunds_unused_gas_and_quota_bookkeeping() {
+	new_test_ext().execute_with(|| {
+		let post_info = deploy(1, vec![0x60, 0x00]).unwrap();
+		let without_quota = <Test as crate::Config>::WeightInfo::deploy_contract()
+			.saturating_add(<Test as frame_system::Config>::DbWeight::get().reads(1))
+			.saturating_add(Weight::from_parts(CREATE_GAS, 0));
+		assert_eq!(post_info.actual_weight, Some(without_quota));
+		// The deployer paid for the creation in gas
+		assert_eq!(post_info.pays_fee, Pays::No);
+
+		assert_ok!(EvmDeploymentControl::set_deployment_quota(RuntimeOrigin::root(), Some(2)));
+		let post_info = deploy(1, vec![0x60, 0x00, 0x00]).unwrap();
+		let with_quota = <Test as crate::Config>::WeightInfo::deploy_contract()
+			.saturating_add(<Test as crate::Config>::WeightInfo::use_deployment_quota())
+			.saturating_add(Weight::from_parts(CREATE_GAS, 0));
+		assert_eq!(post_info.actual_weight, Some(with_quota));
+	});
+}
+
+#[test]
+fn revoke_deployer_refunds_the_cleanup_it_skips() {
+	new_test_ext().execute_with(|| {
+		let post_info = EvmDeploymentControl::revoke_deployer(RuntimeOrigin::root(), 1).unwrap();
+		assert_eq!(
+			post_info.actual_weight,
+			Some(<Test as crate::Config>::WeightInfo::revoke_deployer(0))
+		);
+
+		WindowOverrides::<Test>::insert(2, ());
+		let post_info = EvmDeploymentControl::revoke_deployer(RuntimeOrigin::root(), 2).unwrap();
+		assert_eq!(
+			post_info.actual_weight,
+			Some(<Test as crate::Config>::WeightInfo::revoke_deployer(1))
+		);
+	});
+}
+
+#[test]
+fn unregister_factory_refunds_the_preimage_release_it_skips() {
+	new_test_ext().execute_with(|| {
+		let factory = H160::repeat_byte(0xfa);
+		assert_ok!(EvmDeploymentControl::register_factory(RuntimeOrigin::root(), factory, 3));
+
+		let post_info =
+			EvmDeploymentControl::unregister_factory(RuntimeOrigin::root(), factory).unwrap();
+		assert_eq!(
+			post_info.actual_weight,
+			Some(<Test as crate::Config>::WeightInfo::unregister_factory(0))
+		);
+	});
+}
+
+#[test]
+fn deployer_admin_dispatch_refunds_the_unused_call_weight() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(EvmDeploymentControl::set_deployer_admin(RuntimeOrigin::root(), 4, true));
+		let revoke = Box::new(RuntimeCall::EvmDeploymentControl(crate::Call::revoke_deployer {
+			deployer: 1,
+		}));
+
+		let post_info =
+			EvmDeploymentControl::dispatch_as_deployer_admin(RuntimeOrigin::signed(4), revoke)
+				.unwrap();
+		let weight = <Test as crate::Config>::WeightInfo::dispatch_as_deployer_admin()
+			.saturating_add(<Test as crate::Config>::WeightInfo::revoke_deployer(0));
+		assert_eq!(post_info.actual_weight, Some(weight));
+	});
+}
+
+fn bounds(max_factories: u32, max_deployers: u32) -> TenantBounds {
+	TenantBounds { max_factories, max_deployers }
+}
+
+#[test]
+fn tenants_are_created_by_root_and_handed_over_by_their_admin() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			EvmDeploymentControl::create_tenant(RuntimeOrigin::signed(10), 10, bounds(1, 1)),
+			sp_runtime::DispatchError::BadOrigin
+		);
+		assert_ok!(EvmDeploymentControl::create_tenant(RuntimeOrigin::root(), 10, bounds(1, 1)));
+		System::assert_last_event(
+			Event::TenantCreated { tenant: 0, admin: 10, bounds: bounds(1, 1) }.into(),
+		);
+
+		assert_ok!(EvmDeploymentControl::set_tenant_admin(RuntimeOrigin::signed(10), 0, 11));
+		assert_noop!(
+			EvmDeploymentControl::set_tenant_admin(RuntimeOrigin::signed(10), 0, 10),
+			Error::<Test>::NotTenantAdmin
+		);
+		assert_ok!(EvmDeploymentControl::set_tenant_admin(RuntimeOrigin::root(), 0, 12));
+		assert_eq!(Tenants::<Test>::get(0).map(|info| info.admin), Some(12));
+
+		assert_ok!(EvmDeploymentControl::set_tenant_bounds(RuntimeOrigin::root(), 0, bounds(5, 5)));
+		assert_noop!(
+			EvmDeploymentControl::set_tenant_bounds(RuntimeOrigin::root(), 1, bounds(5, 5)),
+			Error::<Test>::UnknownTenant
+		);
+	});
+}
+
+#[test]
+fn tenant_admin_manages_its_factories_within_bounds() {
+	new_test_ext().execute_with(|| {
+		let (factory, other) = (H160::repeat_byte(0xf1), H160::repeat_byte(0xf2));
+		assert_ok!(EvmDeploymentControl::create_tenant(RuntimeOrigin::root(), 10, bounds(1, 0)));
+
+		assert_noop!(
+			EvmDeploymentControl::register_tenant_factory(RuntimeOrigin::signed(11), 0, factory, 3),
+			Error::<Test>::NotTenantAdmin
+		);
+		assert_ok!(EvmDeploymentControl::register_tenant_factory(
+			RuntimeOrigin::signed(10),
+			0,
+			factory,
+			3
+		));
+		assert!(EvmDeploymentControl::factory(&factory).is_some());
+		assert_noop!(
+			EvmDeploymentControl::register_tenant_factory(RuntimeOrigin::signed(10), 0, other, 3),
+			Error::<Test>::TenantBoundReached
+		);
+
+		assert_ok!(EvmDeploymentControl::set_tenant_factory_status(
+			RuntimeOrigin::signed(10),
+			factory,
+			FactoryStatus::Suspended
+		));
+		assert_eq!(
+			EvmDeploymentControl::factory(&factory).map(|info| info.status),
+			Some(FactoryStatus::Suspended)
+		);
+
+		// Factories registered by governance are out of the tenant's reach
+		assert_ok!(EvmDeploymentControl::register_factory(RuntimeOrigin::root(), other, 3));
+		assert_noop!(
+			EvmDeploymentControl::set_tenant_factory_status(
+				RuntimeOrigin::signed(10),
+				other,
+				FactoryStatus::Suspended
+			),
+			Error::<Test>::NotTenantAdmin
+		);
+
+		// Unregistering a factory frees its slot
+		assert_ok!(EvmDeploymentControl::unregister_factory(RuntimeOrigin::root(), factory));
+		assert_eq!(Tenants::<Test>::get(0).map(|info| info.factories), Some(0));
+		assert_ok!(EvmDeploymentControl::register_tenant_factory(
+			RuntimeOrigin::signed(10),
+			0,
+			H160::repeat_byte(0xf3),
+			3
+		));
+	});
+}
+
+#[test]
+fn tenant_admin_authorizes_and_revokes_its_deployers_within_bounds() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(EvmDeploymentControl::create_tenant(RuntimeOrigin::root(), 10, bounds(0, 1)));
+		assert_ok!(EvmDeploymentControl::create_tenant(RuntimeOrigin::root(), 20, bounds(0, 1)));
+
+		assert_ok!(EvmDeploymentControl::authorize_tenant_deployer(
+			RuntimeOrigin::signed(10),
+			0,
+			5
+		));
+		assert!(EvmDeploymentControl::is_authorized(&5));
+		assert_noop!(
+			EvmDeploymentControl::authorize_tenant_deployer(RuntimeOrigin::signed(10), 0, 6),
+			Error::<Test>::TenantBoundReached
+		);
+
+		// Neither another tenant's admin nor a tenant admin for a genesis deployer may revoke
+		assert_noop!(
+			EvmDeploymentControl::revoke_tenant_deployer(RuntimeOrigin::signed(20), 5),
+			Error::<Test>::NotTenantAdmin
+		);
+		assert_noop!(
+			EvmDeploymentControl::revoke_tenant_deployer(RuntimeOrigin::signed(10), 1),
+			Error::<Test>::NotTenantAdmin
+		);
+
+		assert_ok!(EvmDeploymentControl::revoke_tenant_deployer(RuntimeOrigin::signed(10), 5));
+		assert!(!EvmDeploymentControl::is_authorized(&5));
+		assert_eq!(Tenants::<Test>::get(0).map(|info| info.deployers), Some(0));
+
+		// A revocation by governance frees the slot as well
+		assert_ok!(EvmDeploymentControl::authorize_tenant_deployer(
+			RuntimeOrigin::signed(10),
+			0,
+			6
+		));
+		assert_ok!(EvmDeploymentControl::revoke_deployer(RuntimeOrigin::root(), 6));
+		assert_eq!(Tenants::<Test>::get(0).map(|info| info.deployers), Some(0));
+	});
+}
+
+fn authorize_call(deployer: u64) -> Box<RuntimeCall> {
+	Box::new(RuntimeCall::EvmDeploymentControl(crate::Call::authorize_deployer { deployer }))
+}
+
+#[test]
+fn governance_calls_are_enacted_after_the_delay() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(EvmDeploymentControl::set_enactment_delay(RuntimeOrigin::root(), 10));
+		System::assert_last_event(Event::EnactmentDelaySet { delay: 10 }.into());
+
+		// Direct calls are refused once a delay is set
+		assert_noop!(
+			EvmDeploymentControl::authorize_deployer(RuntimeOrigin::root(), 5),
+			Error::<Test>::NotEnacted
+		);
+		assert_noop!(
+			EvmDeploymentControl::schedule_governance_call(
+				RuntimeOrigin::signed(3),
+				authorize_call(5)
+			),
+			sp_runtime::DispatchError::BadOrigin
+		);
+
+		assert_ok!(EvmDeploymentControl::schedule_governance_call(
+			RuntimeOrigin::root(),
+			authorize_call(5)
+		));
+		let call_hash = BlakeTwo256::hash_of(&authorize_call(5));
+		System::assert_last_event(
+			Event::GovernanceCallScheduled { id: 0, call_hash, enact_at: 11 }.into(),
+		);
+
+		assert_noop!(
+			EvmDeploymentControl::enact_governance_call(RuntimeOrigin::signed(3), 0, Weight::MAX),
+			Error::<Test>::EnactmentNotDue
+		);
+		System::set_block_number(11);
+		assert_noop!(
+			EvmDeploymentControl::enact_governance_call(
+				RuntimeOrigin::signed(3),
+				0,
+				Weight::zero()
+			),
+			Error::<Test>::WeightLimitTooLow
+		);
+
+		// Anyone enacts the call with the origin that scheduled it
+		assert_ok!(EvmDeploymentControl::enact_governance_call(
+			RuntimeOrigin::signed(3),
+			0,
+			Weight::MAX
+		));
+		System::assert_last_event(Event::GovernanceCallEnacted { id: 0, result: Ok(()) }.into());
+		assert!(EvmDeploymentControl::is_authorized(&5));
+		assert!(EvmDeploymentControl::governance_call(0).is_none());
+		assert_noop!(
+			EvmDeploymentControl::enact_governance_call(RuntimeOrigin::signed(3), 0, Weight::MAX),
+			Error::<Test>::UnknownGovernanceCall
+		);
+	});
+}
+
+#[test]
+fn enactment_delay_covers_every_admin_authorization_path() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let policy = approval_policy(vec![10, 11], 2);
+		assert_ok!(EvmDeploymentControl::set_approval_policy(RuntimeOrigin::root(), Some(policy)));
+		assess(3, 1);
+		assert_ok!(propose(10, 3));
+		assert_ok!(EvmDeploymentControl::set_enactment_delay(RuntimeOrigin::root(), 5));
+		assert_noop!(
+			EvmDeploymentControl::set_deployer_risk_tier(RuntimeOrigin::root(), 4, Some(1)),
+			Error::<Test>::NotEnacted
+		);
+
+		assert_noop!(
+			EvmDeploymentControl::authorize_contract_wallet(
+				RuntimeOrigin::root(),
+				H160::repeat_byte(0x77),
+				3
+			),
+			Error::<Test>::NotEnacted
+		);
+		assert_noop!(
+			EvmDeploymentControl::set_window_override(RuntimeOrigin::root(), 3, true),
+			Error::<Test>::NotEnacted
+		);
+		assert_noop!(
+			EvmDeploymentControl::create_tenant(RuntimeOrigin::root(), 10, bounds(1, 1)),
+			Error::<Test>::NotEnacted
+		);
+
+		// Approved proposals are scheduled, open to objections
+		assert_ok!(EvmDeploymentControl::execute_authorization(RuntimeOrigin::signed(10), 0));
+		assert!(!EvmDeploymentControl::is_authorized(&3));
+		assert!(crate::Objections::<Test>::contains_key(0));
+		assert_eq!(EvmDeploymentControl::governance_call(0).map(|call| call.enact_at), Some(6));
+		assert_noop!(
+			EvmDeploymentControl::execute_authorization(RuntimeOrigin::signed(11), 0),
+			Error::<Test>::ProposalScheduled
+		);
+
+		// The proposal no longer expires once scheduled
+		System::set_block_number(11);
+		assert_noop!(
+			EvmDeploymentControl::remove_expired_proposal(RuntimeOrigin::signed(1), 0),
+			Error::<Test>::ProposalScheduled
+		);
+		assert_ok!(EvmDeploymentControl::enact_governance_call(
+			RuntimeOrigin::signed(1),
+			0,
+			Weight::MAX
+		));
+		System::assert_has_event(Event::AuthorizationExecuted { id: 0, deployer: 3 }.into());
+		assert!(EvmDeploymentControl::is_authorized(&3));
+		assert_eq!(EvmDeploymentControl::authorization_proposal(0), None);
+	});
+}
+
+#[test]
+fn emergency_origin_enacts_early_and_cancels_governance_calls() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(EvmDeploymentControl::set_enactment_delay(RuntimeOrigin::root(), 10));
+		assert_ok!(EvmDeploymentControl::schedule_governance_call(
+			RuntimeOrigin::root(),
+			authorize_call(5)
+		));
+		let revoke = Box::new(RuntimeCall::EvmDeploymentControl(crate::Call::revoke_deployer {
+			deployer: 1,
+		}));
+		assert_ok!(EvmDeploymentControl::schedule_governance_call(RuntimeOrigin::root(), revoke));
+		assert_noop!(
+			EvmDeploymentControl::schedule_governance_call(
+				RuntimeOrigin::root(),
+				authorize_call(6)
+			),
+			Error::<Test>::TooManyGovernanceCalls
+		);
+
+		assert_noop!(
+			EvmDeploymentControl::cancel_governance_call(RuntimeOrigin::signed(3), 0),
+			sp_runtime::DispatchError::BadOrigin
+		);
+		assert_ok!(EvmDeploymentControl::cancel_governance_call(RuntimeOrigin::root(), 0));
+		System::assert_last_event(Event::GovernanceCallCancelled { id: 0 }.into());
+
+		assert_ok!(EvmDeploymentControl::enact_governance_call(
+			RuntimeOrigin::root(),
+			1,
+			Weight::MAX
+		));
+		assert!(!EvmDeploymentControl::is_authorized(&1));
+		assert!(!EvmDeploymentControl::is_authorized(&5));
+
+		// Lifting the delay is itself subject to it
+		assert_noop!(
+			EvmDeploymentControl::set_enactment_delay(RuntimeOrigin::root(), 0),
+			Error::<Test>::NotEnacted
+		);
+		let lift = Box::new(RuntimeCall::EvmDeploymentControl(crate::Call::set_enactment_delay {
+			delay: 0,
+		}));
+		assert_ok!(EvmDeploymentControl::schedule_governance_call(RuntimeOrigin::root(), lift));
+		assert_ok!(EvmDeploymentControl::enact_governance_call(
+			RuntimeOrigin::root(),
+			2,
+			Weight::MAX
+		));
+		assert_ok!(EvmDeploymentControl::authorize_deployer(RuntimeOrigin::root(), 5));
+	});
+}
+
+#[test]
+fn nested_authorizations_are_open_to_objections() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(EvmDeploymentControl::set_enactment_delay(RuntimeOrigin::root(), 10));
+		let wallet = Box::new(RuntimeCall::EvmDeploymentControl(
+			crate::Call::authorize_contract_wallet { wallet: H160::repeat_byte(0x77), operator: 3 },
+		));
+		let nested = Box::new(RuntimeCall::EvmDeploymentControl(
+			crate::Call::dispatch_as_deployer_admin { call: wallet },
+		));
+		assert_ok!(EvmDeploymentControl::schedule_governance_call(RuntimeOrigin::root(), nested));
+
+		assert!(crate::Objections::<Test>::contains_key(0));
+		assert_ok!(EvmDeploymentControl::object_to_authorization(RuntimeOrigin::signed(3), 0, 5));
+	});
+}
+
+fn voted(who: u64) -> u64 {
+	Balances::balance_on_hold(&HoldReason::GovernanceVote.into(), &who)
+}
+
+#[test]
+fn objections_veto_a_scheduled_authorization_into_a_referendum() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(EvmDeploymentControl::set_enactment_delay(RuntimeOrigin::root(), 10));
+		assert_ok!(EvmDeploymentControl::schedule_governance_call(
+			RuntimeOrigin::root(),
+			authorize_call(5)
+		));
+		let revoke = Box::new(RuntimeCall::EvmDeploymentControl(crate::Call::revoke_deployer {
+			deployer: 1,
+		}));
+		assert_ok!(EvmDeploymentControl::schedule_governance_call(RuntimeOrigin::root(), revoke));
+
+		// Only authorizations are open to objections
+		assert_noop!(
+			EvmDeploymentControl::object_to_authorization(RuntimeOrigin::signed(3), 1, 5),
+			Error::<Test>::NotObjectable
+		);
+
+		assert_ok!(EvmDeploymentControl::object_to_authorization(RuntimeOrigin::signed(3), 0, 15));
+		System::assert_last_event(
+			Event::AuthorizationObjected { id: 0, who: 3, amount: 15 }.into(),
+		);
+		assert_eq!(voted(3), 15);
+		assert!(EvmDeploymentControl::governance_call(0).is_some());
+
+		// Crossing a tenth of the total issuance vetoes the authorization
+		assert_ok!(EvmDeploymentControl::object_to_authorization(RuntimeOrigin::signed(1), 0, 6));
+		System::assert_last_event(
+			Event::AuthorizationVetoed { id: 0, objected: 21, ends_at: 11 }.into(),
+		);
+		assert!(EvmDeploymentControl::governance_call(0).is_none());
+		assert_noop!(
+			EvmDeploymentControl::enact_governance_call(RuntimeOrigin::root(), 0, Weight::MAX),
+			Error::<Test>::UnknownGovernanceCall
+		);
+
+		assert_noop!(
+			EvmDeploymentControl::unlock_vote(RuntimeOrigin::signed(3), 0),
+			Error::<Test>::VoteLocked
+		);
+		assert_noop!(
+			EvmDeploymentControl::vote(RuntimeOrigin::signed(3), 0, true, 10),
+			Error::<Test>::ConflictingVote
+		);
+		assert_ok!(EvmDeploymentControl::vote(RuntimeOrigin::signed(3), 0, false, 10));
+		assert_eq!(voted(3), 25);
+
+		System::set_block_number(10);
+		assert_noop!(
+			EvmDeploymentControl::conclude_referendum(RuntimeOrigin::signed(2), 0, Weight::MAX),
+			Error::<Test>::ReferendumNotEnded
+		);
+		System::set_block_number(11);
+		assert_noop!(
+			EvmDeploymentControl::vote(RuntimeOrigin::signed(2), 0, true, 1),
+			Error::<Test>::ReferendumEnded
+		);
+		assert_ok!(EvmDeploymentControl::conclude_referendum(
+			RuntimeOrigin::signed(2),
+			0,
+			Weight::MAX
+		));
+		System::assert_last_event(Event::ReferendumConcluded { id: 0, approved: false }.into());
+		assert!(!EvmDeploymentControl::is_authorized(&5));
+
+		assert_ok!(EvmDeploymentControl::unlock_vote(RuntimeOrigin::signed(3), 0));
+		System::assert_last_event(Event::VoteUnlocked { id: 0, who: 3, amount: 25 }.into());
+		assert_eq!(voted(3), 0);
+		assert_noop!(
+			EvmDeploymentControl::unlock_vote(RuntimeOrigin::signed(3), 0),
+			Error::<Test>::NoVote
+		);
+	});
+}
+
+#[test]
+fn approved_referendum_enacts_the_vetoed_authorization() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(EvmDeploymentControl::set_enactment_delay(RuntimeOrigin::root(), 10));
+		assert_ok!(EvmDeploymentControl::schedule_governance_call(
+			RuntimeOrigin::root(),
+			authorize_call(5)
+		));
+		assert_ok!(EvmDeploymentControl::schedule_governance_call(
+			RuntimeOrigin::root(),
+			authorize_call(6)
+		));
+		assert_ok!(EvmDeploymentControl::object_to_authorization(RuntimeOrigin::signed(3), 0, 21));
+		assert_ok!(EvmDeploymentControl::vote(RuntimeOrigin::signed(1), 0, true, 30));
+
+		System::set_block_number(11);
+		// Objections close once the authorization is due
+		assert_noop!(
+			EvmDeploymentControl::object_to_authorization(RuntimeOrigin::signed(3), 1, 21),
+			Error::<Test>::ObjectionWindowClosed
+		);
+		assert_noop!(
+			EvmDeploymentControl::conclude_referendum(RuntimeOrigin::signed(2), 0, Weight::zero()),
+			Error::<Test>::WeightLimitTooLow
+		);
+		assert_ok!(EvmDeploymentControl::conclude_referendum(
+			RuntimeOrigin::signed(2),
+			0,
+			Weight::MAX
+		));
+		System::assert_has_event(Event::ReferendumConcluded { id: 0, approved: true }.into());
+		System::assert_last_event(Event::GovernanceCallEnacted { id: 0, result: Ok(()) }.into());
+		assert!(EvmDeploymentControl::is_authorized(&5));
+
+		// Enacting an authorization closes its objections, leaving nothing to unlock
+		assert_ok!(EvmDeploymentControl::enact_governance_call(
+			RuntimeOrigin::signed(2),
+			1,
+			Weight::MAX
+		));
+		assert_noop!(
+			EvmDeploymentControl::unlock_vote(RuntimeOrigin::signed(2), 1),
+			Error::<Test>::NoVote
+		);
+		assert_ok!(EvmDeploymentControl::unlock_vote(RuntimeOrigin::signed(1), 0));
+		assert_eq!(voted(1), 0);
+	});
+}
+
+#[test]
+fn transfer_contract_admin_moves_provenance_and_factory() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(4);
+		let deployer = H160::from_low_u64_be(3);
+		let new_admin = H160::from_low_u64_be(5);
+		let contract = H160::repeat_byte(0xfa);
+		assert_noop!(
+			EvmDeploymentControl::transfer_contract_admin(
+				RuntimeOrigin::root(),
+				contract,
+				new_admin
+			),
+			Error::<Test>::UnknownContract
+		);
+		EvmDeploymentControl::note_contract_created(deployer, contract);
+		assert_ok!(EvmDeploymentControl::register_factory(RuntimeOrigin::root(), contract, 3));
+
+		// Only the admin origin and the recorded deployer transfer the contract
+		assert_noop!(
+			EvmDeploymentControl::transfer_contract_admin(
+				RuntimeOrigin::signed(5),
+				contract,
+				new_admin
+			),
+			sp_runtime::DispatchError::BadOrigin
+		);
+		assert_ok!(EvmDeploymentControl::transfer_contract_admin(
+			RuntimeOrigin::signed(3),
+			contract,
+			new_admin
+		));
+		System::assert_last_event(
+			Event::ContractAdminTransferred { contract, previous: deployer, new_admin }.into(),
+		);
+		assert_eq!(
+			EvmDeploymentControl::provenance(&contract),
+			Some(ProvenanceRecord { deployer: new_admin, block: 4 })
+		);
+		assert_eq!(EvmDeploymentControl::deployer_of(&contract), Some(new_admin));
+		assert_eq!(EvmDeploymentControl::factory(&contract).map(|info| info.admin), Some(5));
+
+		// The previous deployer lost control, the new admin and Root keep it
+		assert_noop!(
+			EvmDeploymentControl::transfer_contract_admin(
+				RuntimeOrigin::signed(3),
+				contract,
+				deployer
+			),
+			sp_runtime::DispatchError::BadOrigin
+		);
+		assert_ok!(EvmDeploymentControl::transfer_contract_admin(
+			RuntimeOrigin::root(),
+			contract,
+			deployer
+		));
+		assert_eq!(EvmDeploymentControl::deployer_of(&contract), Some(deployer));
+	});
+}
+
+#[test]
+fn retired_factories_cannot_be_revived() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let (factory, other) = (H160::repeat_byte(0xf1), H160::repeat_byte(0xf2));
+		assert_ok!(EvmDeploymentControl::create_tenant(RuntimeOrigin::root(), 10, bounds(1, 0)));
+		EvmDeploymentControl::note_contract_created(H160::from_low_u64_be(3), factory);
+		assert_ok!(EvmDeploymentControl::register_tenant_factory(
+			RuntimeOrigin::signed(10),
+			0,
+			factory,
+			3
+		));
+		assert_ok!(EvmDeploymentControl::announce_retirement(RuntimeOrigin::root(), factory));
+		run_to_block(11);
+		assert_ok!(EvmDeploymentControl::finalize_retirement(RuntimeOrigin::root(), factory));
+
+		// Neither governance nor the tenant admin lifts the retirement
+		for status in [FactoryStatus::Active, FactoryStatus::Suspended] {
+			assert_noop!(
+				EvmDeploymentControl::set_tenant_factory_status(
+					RuntimeOrigin::signed(10),
+					factory,
+					status
+				),
+				Error::<Test>::ContractRetired
+			);
+			assert_noop!(
+				EvmDeploymentControl::set_factory_status(RuntimeOrigin::root(), factory, status),
+				Error::<Test>::ContractRetired
+			);
+		}
+
+		// Only a finalized retirement retires a factory
+		assert_ok!(EvmDeploymentControl::register_factory(RuntimeOrigin::root(), other, 3));
+		assert_noop!(
+			EvmDeploymentControl::set_factory_status(
+				RuntimeOrigin::root(),
+				other,
+				FactoryStatus::Retired
+			),
+			Error::<Test>::RetirementNotAnnounced
+		);
+	});
+}
+
+#[test]
+fn retirement_is_announced_warned_and_finalized() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let deployer = H160::from_low_u64_be(3);
+		let contract = H160::repeat_byte(0xfa);
+		assert_noop!(
+			EvmDeploymentControl::announce_retirement(RuntimeOrigin::root(), contract),
+			Error::<Test>::UnknownContract
+		);
+		EvmDeploymentControl::note_contract_created(deployer, contract);
+		assert_ok!(EvmDeploymentControl::register_factory(RuntimeOrigin::root(), contract, 3));
+
+		// Only the admin origin and the recorded deployer announce the retirement
+		assert_noop!(
+			EvmDeploymentControl::announce_retirement(RuntimeOrigin::signed(5), contract),
+			sp_runtime::DispatchError::BadOrigin
+		);
+		assert_noop!(
+			EvmDeploymentControl::finalize_retirement(RuntimeOrigin::signed(5), contract),
+			Error::<Test>::RetirementNotAnnounced
+		);
+		assert_ok!(EvmDeploymentControl::announce_retirement(RuntimeOrigin::signed(3), contract));
+		System::assert_last_event(
+			Event::ContractRetirementAnnounced { contract, retires_at: 11 }.into(),
+		);
+		assert_eq!(
+			EvmDeploymentControl::retirement(&contract),
+			Some(Retirement::Announced { retires_at: 11 })
+		);
+		assert_noop!(
+			EvmDeploymentControl::announce_retirement(RuntimeOrigin::root(), contract),
+			Error::<Test>::RetirementAlreadyAnnounced
+		);
+
+		// Warned halfway through the grace period, then when it is over
+		run_to_block(6);
+		System::assert_has_event(Event::ContractRetiringSoon { contract, retires_at: 11 }.into());
+		assert_noop!(
+			EvmDeploymentControl::finalize_retirement(RuntimeOrigin::signed(5), contract),
+			Error::<Test>::RetirementNotDue
+		);
+		run_to_block(11);
+		System::assert_has_event(Event::ContractRetirementDue { contract }.into());
+
+		// Anyone finalizes the retirement, freezing the contract
+		assert_ok!(EvmDeploymentControl::finalize_retirement(RuntimeOrigin::signed(5), contract));
+		System::assert_last_event(Event::ContractRetired { contract }.into());
+		assert!(EvmDeploymentControl::is_retired(&contract));
+		assert_eq!(
+			EvmDeploymentControl::factory(&contract).map(|info| info.status),
+			Some(FactoryStatus::Retired)
+		);
+		assert_noop!(
+			EvmDeploymentControl::set_factory_status(
+				RuntimeOrigin::root(),
+				contract,
+				FactoryStatus::Active
+			),
+			Error::<Test>::ContractRetired
+		);
+		assert_noop!(
+			EvmDeploymentControl::transfer_contract_admin(
+				RuntimeOrigin::root(),
+				contract,
+				H160::from_low_u64_be(5)
+			),
+			Error::<Test>::ContractRetired
+		);
+		assert_noop!(
+			EvmDeploymentControl::finalize_retirement(RuntimeOrigin::root(), contract),
+			Error::<Test>::ContractRetired
+		);
+	});
+}
@@ -35,15 +35,78 @@ use core::marker::PhantomData;
 /// Weight functions needed for pallet_evm_deployment_control.
 pub trait WeightInfo {
 	fn authorize_deployer() -> Weight;
-	fn revoke_deployer() -> Weight;
+	fn revoke_deployer(o: u32, ) -> Weight;
 	fn is_authorized_check() -> Weight;
+	fn ensure_can_deploy(w: u32, ) -> Weight;
+	fn report_rejected_attempts() -> Weight;
+	fn unflag_deployer() -> Weight;
+	fn set_max_creates_per_block() -> Weight;
+	fn set_deployment_windows(w: u32, ) -> Weight;
+	fn set_window_override() -> Weight;
+	fn schedule_cleanup() -> Weight;
+	fn process_cleanup_task() -> Weight;
+	fn set_opcode_policy() -> Weight;
+	fn deploy_contract() -> Weight;
+	fn migrate_v1_step() -> Weight;
+	fn migrate_v2_step() -> Weight;
+	fn migrate_v3_step() -> Weight;
+	fn register_factory() -> Weight;
+	fn set_factory_status() -> Weight;
+	fn set_approval_policy() -> Weight;
+	fn propose_authorization() -> Weight;
+	fn approve_authorization() -> Weight;
+	fn execute_authorization() -> Weight;
+	fn remove_expired_proposal() -> Weight;
+	fn set_factory_permissions() -> Weight;
+	fn register_child_metadata() -> Weight;
+	fn set_factory_expiry() -> Weight;
+	fn process_factory_expiries(e: u32, ) -> Weight;
+	fn announce_deployment() -> Weight;
+	fn set_deployment_policy() -> Weight;
+	fn rotate_deployer_key() -> Weight;
+	fn authorize_contract_wallet() -> Weight;
+	fn set_factory_metadata(l: u32, ) -> Weight;
+	fn set_deployer_metadata(l: u32, ) -> Weight;
+	fn unregister_factory(m: u32, ) -> Weight;
+	fn set_deployer_admin() -> Weight;
+	fn dispatch_as_deployer_admin() -> Weight;
+	fn slash_deployment_intent() -> Weight;
+	fn set_deployment_quota() -> Weight;
+	fn use_deployment_quota() -> Weight;
+	fn create_tenant() -> Weight;
+	fn set_tenant_bounds() -> Weight;
+	fn set_tenant_admin() -> Weight;
+	fn register_tenant_factory() -> Weight;
+	fn set_tenant_factory_status() -> Weight;
+	fn authorize_tenant_deployer() -> Weight;
+	fn schedule_governance_call() -> Weight;
+	fn enact_governance_call() -> Weight;
+	fn cancel_governance_call() -> Weight;
+	fn set_enactment_delay() -> Weight;
+	fn object_to_authorization() -> Weight;
+	fn vote() -> Weight;
+	fn conclude_referendum() -> Weight;
+	fn unlock_vote() -> Weight;
+	fn transfer_contract_admin() -> Weight;
+	fn announce_retirement() -> Weight;
+	fn finalize_retirement() -> Weight;
+	fn process_retirement_warnings(w: u32, ) -> Weight;
+	fn set_deployer_risk_tier() -> Weight;
 }
 
 /// Weights for pallet_evm_deployment_control using the Substrate node and recommended hardware.
 pub struct SubstrateWeight<T>(PhantomData<T>);
 impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	/// Storage: `EvmDeploymentControl::EnactmentDelay` (r:1 w:0)
+	/// Proof: `EvmDeploymentControl::EnactmentDelay` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `EvmDeploymentControl::Enacting` (r:1 w:0)
+	/// Proof: `EvmDeploymentControl::Enacting` (`max_values`: Some(1), `max_size`: Some(0), added: 495, mode: `MaxEncodedLen`)
+	/// Storage: `EvmDeploymentControl::AuthorizationCache` (r:1 w:1)
+	/// Proof: `EvmDeploymentControl::AuthorizationCache` (`max_values`: None, `max_size`: Some(41), added: 2516, mode: `MaxEncodedLen`)
+	/// Storage: `EvmDeploymentControl::CachedAuthorizations` (r:1 w:1)
+	/// Proof: `EvmDeploymentControl::CachedAuthorizations` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
 	/// Storage: `EvmDeploymentControl::AuthorizedDeployers` (r:1 w:1)
-	/// Proof: `EvmDeploymentControl::AuthorizedDeployers` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	/// Proof: `EvmDeploymentControl::AuthorizedDeployers` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
 	fn authorize_deployer() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `3`
@@ -51,31 +114,1015 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		// Minimum execution time: 9_498_000 picoseconds.
 		Weight::from_parts(10_686_000, 0)
 			.saturating_add(Weight::from_parts(0, 3501))
-			.saturating_add(T::DbWeight::get().reads(1))
-			.saturating_add(T::DbWeight::get().writes(1))
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(3))
 	}
+	/// Storage: `EvmDeploymentControl::EnactmentDelay` (r:1 w:0)
+	/// Proof: `EvmDeploymentControl::EnactmentDelay` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `EvmDeploymentControl::Enacting` (r:1 w:0)
+	/// Proof: `EvmDeploymentControl::Enacting` (`max_values`: Some(1), `max_size`: Some(0), added: 495, mode: `MaxEncodedLen`)
+	/// Storage: `EvmDeploymentControl::AuthorizationCache` (r:1 w:1)
+	/// Proof: `EvmDeploymentControl::AuthorizationCache` (`max_values`: None, `max_size`: Some(41), added: 2516, mode: `MaxEncodedLen`)
+	/// Storage: `EvmDeploymentControl::CachedAuthorizations` (r:1 w:1)
+	/// Proof: `EvmDeploymentControl::CachedAuthorizations` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
 	/// Storage: `EvmDeploymentControl::AuthorizedDeployers` (r:1 w:1)
-	/// Proof: `EvmDeploymentControl::AuthorizedDeployers` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
-	fn revoke_deployer() -> Weight {
+	/// Proof: `EvmDeploymentControl::AuthorizedDeployers` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// Storage: `EvmDeploymentControl::WindowOverrides` (r:1 w:0)
+	/// Proof: `EvmDeploymentControl::WindowOverrides` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	/// Storage: `EvmDeploymentControl::CleanupQueueHead` (r:1 w:0)
+	/// Proof: `EvmDeploymentControl::CleanupQueueHead` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `EvmDeploymentControl::CleanupQueueTail` (r:1 w:1)
+	/// Proof: `EvmDeploymentControl::CleanupQueueTail` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `EvmDeploymentControl::CleanupQueue` (r:0 w:1)
+	/// Proof: `EvmDeploymentControl::CleanupQueue` (`max_values`: None, `max_size`: Some(45), added: 2520, mode: `MaxEncodedLen`)
+	/// Storage: `EvmDeploymentControl::DeployerTenant` (r:1 w:1)
+	/// Proof: `EvmDeploymentControl::DeployerTenant` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `EvmDeploymentControl::Tenants` (r:1 w:1)
+	/// Proof: `EvmDeploymentControl::Tenants` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
+	/// The range of component `o` is `[0, 1]`.
+	fn revoke_deployer(o: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `118 + o * (8 ±0)`
+		//  Estimated: `3525`
+		// Minimum execution time: 12_311_000 picoseconds.
+		Weight::from_parts(12_904_000, 0)
+			.saturating_add(Weight::from_parts(0, 3525))
+			// Standard Error: 4_512
+			.saturating_add(Weight::from_parts(6_737_000, 0).saturating_mul(o.into()))
+			.saturating_add(T::DbWeight::get().reads(8))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(o.into())))
+			.saturating_add(T::DbWeight::get().writes(5))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(o.into())))
+	}
+	/// Storage: `EvmDeploymentControl::AuthorizationCache` (r:1 w:1)
+	/// Proof: `EvmDeploymentControl::AuthorizationCache` (`max_values`: None, `max_size`: Some(41), added: 2516, mode: `MaxEncodedLen`)
+	/// Storage: `EvmDeploymentControl::CachedAuthorizations` (r:1 w:1)
+	/// Proof: `EvmDeploymentControl::CachedAuthorizations` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `EvmDeploymentControl::AuthorizedDeployers` (r:1 w:0)
+	/// Proof: `EvmDeploymentControl::AuthorizedDeployers` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	fn is_authorized_check() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `63`
 		//  Estimated: `3501`
-		// Minimum execution time: 10_685_000 picoseconds.
-		Weight::from_parts(11_873_000, 0)
+		// Minimum execution time: 4_749_000 picoseconds.
+		Weight::from_parts(5_937_000, 0)
 			.saturating_add(Weight::from_parts(0, 3501))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: `EvmDeploymentControl::AuthorizationCache` (r:1 w:1)
+	/// Proof: `EvmDeploymentControl::AuthorizationCache` (`max_values`: None, `max_size`: Some(41), added: 2516, mode: `MaxEncodedLen`)
+	/// Storage: `EvmDeploymentControl::CachedAuthorizations` (r:1 w:1)
+	/// Proof: `EvmDeploymentControl::CachedAuthorizations` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `EvmDeploymentControl::AuthorizedDeployers` (r:1 w:0)
+	/// Proof: `EvmDeploymentControl::AuthorizedDeployers` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// Storage: `EvmDeploymentControl::DeploymentWindows` (r:1 w:0)
+	/// Proof: `EvmDeploymentControl::DeploymentWindows` (`max_values`: Some(1), `max_size`: Some(193), added: 688, mode: `MaxEncodedLen`)
+	/// Storage: `EvmDeploymentControl::WindowOverrides` (r:1 w:0)
+	/// Proof: `EvmDeploymentControl::WindowOverrides` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `EvmDeploymentControl::DeploymentQuota` (r:1 w:0)
+	/// Proof: `EvmDeploymentControl::DeploymentQuota` (`max_values`: Some(1), `max_size`: Some(5), added: 500, mode: `MaxEncodedLen`)
+	/// Storage: `EvmDeploymentControl::DeploymentQuotaUsage` (r:1 w:0)
+	/// Proof: `EvmDeploymentControl::DeploymentQuotaUsage` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// The range of component `w` is `[0, 16]`.
+	fn ensure_can_deploy(w: u32, ) -> Weight {
+		// NOT BENCHMARKED: hand-written estimate until the `ensure_can_deploy` benchmark is
+		// run on the reference machine. Per read, it is priced like `is_authorized_check`.
+		// Proof Size summary in bytes:
+		//  Estimated: `3521`
+		Weight::from_parts(12_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 3521))
+			.saturating_add(Weight::from_parts(150_000, 0).saturating_mul(w.into()))
+			.saturating_add(T::DbWeight::get().reads(7))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: `EvmDeploymentControl::FlaggedDeployers` (r:1 w:1)
+	/// Proof: `EvmDeploymentControl::FlaggedDeployers` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `EvmDeploymentControl::RejectedAttempts` (r:1 w:0)
+	/// Proof: `EvmDeploymentControl::RejectedAttempts` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	fn report_rejected_attempts() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `107`
+		//  Estimated: `3521`
+		// Minimum execution time: 12_104_000 picoseconds.
+		Weight::from_parts(13_291_000, 0)
+			.saturating_add(Weight::from_parts(0, 3521))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `EvmDeploymentControl::FlaggedDeployers` (r:1 w:1)
+	/// Proof: `EvmDeploymentControl::FlaggedDeployers` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `EvmDeploymentControl::RejectedAttempts` (r:0 w:1)
+	/// Proof: `EvmDeploymentControl::RejectedAttempts` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	fn unflag_deployer() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `95`
+		//  Estimated: `3517`
+		// Minimum execution time: 11_291_000 picoseconds.
+		Weight::from_parts(12_479_000, 0)
+			.saturating_add(Weight::from_parts(0, 3517))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: `EvmDeploymentControl::EnactmentDelay` (r:1 w:0)
+	/// Proof: `EvmDeploymentControl::EnactmentDelay` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `EvmDeploymentControl::Enacting` (r:1 w:0)
+	/// Proof: `EvmDeploymentControl::Enacting` (`max_values`: Some(1), `max_size`: Some(0), added: 495, mode: `MaxEncodedLen`)
+	/// Storage: `EvmDeploymentControl::MaxCreatesPerBlock` (r:0 w:1)
+	/// Proof: `EvmDeploymentControl::MaxCreatesPerBlock` (`max_values`: Some(1), `max_size`: Some(5), added: 500, mode: `MaxEncodedLen`)
+	fn set_max_creates_per_block() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `1489`
+		// Minimum execution time: 5_728_000 picoseconds.
+		Weight::from_parts(6_217_000, 0)
+			.saturating_add(Weight::from_parts(0, 1489))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `EvmDeploymentControl::EnactmentDelay` (r:1 w:0)
+	/// Proof: `EvmDeploymentControl::EnactmentDelay` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `EvmDeploymentControl::Enacting` (r:1 w:0)
+	/// Proof: `EvmDeploymentControl::Enacting` (`max_values`: Some(1), `max_size`: Some(0), added: 495, mode: `MaxEncodedLen`)
+	/// Storage: `EvmDeploymentControl::DeploymentWindows` (r:0 w:1)
+	/// Proof: `EvmDeploymentControl::DeploymentWindows` (`max_values`: Some(1), `max_size`: Some(193), added: 688, mode: `MaxEncodedLen`)
+	/// The range of component `w` is `[0, 16]`.
+	fn set_deployment_windows(w: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `1489`
+		// Minimum execution time: 6_042_000 picoseconds.
+		Weight::from_parts(6_688_422, 0)
+			.saturating_add(Weight::from_parts(0, 1489))
+			// Standard Error: 1_318
+			.saturating_add(Weight::from_parts(41_907, 0).saturating_mul(w.into()))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `EvmDeploymentControl::WindowOverrides` (r:0 w:1)
+	/// Proof: `EvmDeploymentControl::WindowOverrides` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	fn set_window_override() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 6_915_000 picoseconds.
+		Weight::from_parts(7_544_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `EvmDeploymentControl::CleanupQueueHead` (r:1 w:0)
+	/// Proof: `EvmDeploymentControl::CleanupQueueHead` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `EvmDeploymentControl::CleanupQueueTail` (r:1 w:1)
+	/// Proof: `EvmDeploymentControl::CleanupQueueTail` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `EvmDeploymentControl::CleanupQueue` (r:0 w:1)
+	/// Proof: `EvmDeploymentControl::CleanupQueue` (`max_values`: None, `max_size`: Some(45), added: 2520, mode: `MaxEncodedLen`)
+	fn schedule_cleanup() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `42`
+		//  Estimated: `1489`
+		// Minimum execution time: 10_092_000 picoseconds.
+		Weight::from_parts(10_982_000, 0)
+			.saturating_add(Weight::from_parts(0, 1489))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: `EvmDeploymentControl::CleanupQueue` (r:1 w:1)
+	/// Proof: `EvmDeploymentControl::CleanupQueue` (`max_values`: None, `max_size`: Some(45), added: 2520, mode: `MaxEncodedLen`)
+	/// Storage: `EvmDeploymentControl::FlaggedDeployers` (r:1 w:0)
+	/// Proof: `EvmDeploymentControl::FlaggedDeployers` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// Storage: `EvmDeploymentControl::RejectedAttempts` (r:1 w:1)
+	/// Proof: `EvmDeploymentControl::RejectedAttempts` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	fn process_cleanup_task() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `181`
+		//  Estimated: `3510`
+		// Minimum execution time: 15_436_000 picoseconds.
+		Weight::from_parts(16_622_000, 0)
+			.saturating_add(Weight::from_parts(0, 3510))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: `EvmDeploymentControl::EnactmentDelay` (r:1 w:0)
+	/// Proof: `EvmDeploymentControl::EnactmentDelay` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `EvmDeploymentControl::Enacting` (r:1 w:0)
+	/// Proof: `EvmDeploymentControl::Enacting` (`max_values`: Some(1), `max_size`: Some(0), added: 495, mode: `MaxEncodedLen`)
+	/// Storage: `EvmDeploymentControl::EvmOpcodePolicy` (r:0 w:1)
+	/// Proof: `EvmDeploymentControl::EvmOpcodePolicy` (`max_values`: Some(1), `max_size`: Some(2), added: 497, mode: `MaxEncodedLen`)
+	fn set_opcode_policy() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `1489`
+		// Minimum execution time: 6_406_000 picoseconds.
+		Weight::from_parts(7_022_000, 0)
+			.saturating_add(Weight::from_parts(0, 1489))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `EvmDeploymentControl::AuthorizationCache` (r:1 w:1)
+	/// Proof: `EvmDeploymentControl::AuthorizationCache` (`max_values`: None, `max_size`: Some(41), added: 2516, mode: `MaxEncodedLen`)
+	/// Storage: `EvmDeploymentControl::CachedAuthorizations` (r:1 w:1)
+	/// Proof: `EvmDeploymentControl::CachedAuthorizations` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `EvmDeploymentControl::AuthorizedDeployers` (r:1 w:0)
+	/// Proof: `EvmDeploymentControl::AuthorizedDeployers` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `EvmDeploymentControl::EvmDeploymentPolicy` (r:1 w:0)
+	/// Proof: `EvmDeploymentControl::EvmDeploymentPolicy` (`max_values`: Some(1), `max_size`: Some(17), added: 512, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(116), added: 2591, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(55), added: 2530, mode: `MaxEncodedLen`)
+	/// Storage: `EvmDeploymentControl::DeploymentWindows` (r:1 w:0)
+	/// Proof: `EvmDeploymentControl::DeploymentWindows` (`max_values`: Some(1), `max_size`: Some(193), added: 688, mode: `MaxEncodedLen`)
+	/// Storage: `EvmDeploymentControl::MaxCreatesPerBlock` (r:1 w:0)
+	/// Proof: `EvmDeploymentControl::MaxCreatesPerBlock` (`max_values`: Some(1), `max_size`: Some(5), added: 500, mode: `MaxEncodedLen`)
+	/// Storage: `EvmDeploymentControl::CreatesThisBlock` (r:1 w:1)
+	/// Proof: `EvmDeploymentControl::CreatesThisBlock` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `EvmDeploymentControl::DeploymentIntents` (r:1 w:0)
+	/// Proof: `EvmDeploymentControl::DeploymentIntents` (`max_values`: None, `max_size`: Some(154), added: 2629, mode: `MaxEncodedLen`)
+	/// Storage: `EvmDeploymentControl::PendingIntent` (r:0 w:1)
+	/// Proof: `EvmDeploymentControl::PendingIntent` (`max_values`: Some(1), `max_size`: Some(52), added: 547, mode: `MaxEncodedLen`)
+	/// Storage: `EvmDeploymentControl::ContractMetadataOf` (r:0 w:1)
+	/// Proof: `EvmDeploymentControl::ContractMetadataOf` (`max_values`: None, `max_size`: Some(152), added: 2627, mode: `MaxEncodedLen`)
+	fn deploy_contract() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2398`
+		//  Estimated: `3888`
+		// Minimum execution time: 61_077_000 picoseconds.
+		Weight::from_parts(63_542_000, 0)
+			.saturating_add(Weight::from_parts(0, 3888))
+			.saturating_add(T::DbWeight::get().reads(10))
+			.saturating_add(T::DbWeight::get().writes(7))
+	}
+	/// Storage: `EvmDeploymentControl::AuthorizedDeployers` (r:2 w:1)
+	/// Proof: `EvmDeploymentControl::AuthorizedDeployers` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	fn migrate_v1_step() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `114`
+		//  Estimated: `6020`
+		// Minimum execution time: 14_902_000 picoseconds.
+		Weight::from_parts(15_718_000, 0)
+			.saturating_add(Weight::from_parts(0, 6020))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `EvmDeploymentControl::AuthorizedDeployers` (r:2 w:1)
+	/// Proof: `EvmDeploymentControl::AuthorizedDeployers` (`max_values`: None, `max_size`: Some(34), added: 2509, mode: `MaxEncodedLen`)
+	fn migrate_v2_step() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `122`
+		//  Estimated: `6008`
+		// Minimum execution time: 15_204_000 picoseconds.
+		Weight::from_parts(15_981_000, 0)
+			.saturating_add(Weight::from_parts(0, 6008))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `EvmDeploymentControl::AuthorizationProposals` (r:2 w:1)
+	/// Proof: `EvmDeploymentControl::AuthorizationProposals` (`max_values`: None, `max_size`: Some(570), added: 3045, mode: `MaxEncodedLen`)
+	fn migrate_v3_step() -> Weight {
+		// NOT BENCHMARKED: hand-written estimate until the `migrate_v3_step` benchmark is run
+		// on the reference machine, priced like `migrate_v2_step`.
+		// Proof Size summary in bytes:
+		//  Estimated: `7080`
+		Weight::from_parts(17_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 7080))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `EvmDeploymentControl::ApprovedFactories` (r:1 w:1)
+	/// Proof: `EvmDeploymentControl::ApprovedFactories` (`max_values`: None, `max_size`: Some(61), added: 2536, mode: `MaxEncodedLen`)
+	fn register_factory() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `6`
+		//  Estimated: `3526`
+		// Minimum execution time: 10_811_000 picoseconds.
+		Weight::from_parts(11_392_000, 0)
+			.saturating_add(Weight::from_parts(0, 3526))
 			.saturating_add(T::DbWeight::get().reads(1))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
+	/// Storage: `EvmDeploymentControl::Retirements` (r:1 w:0)
+	/// Proof: `EvmDeploymentControl::Retirements` (`max_values`: None, `max_size`: Some(25), added: 2500, mode: `MaxEncodedLen`)
+	/// Storage: `EvmDeploymentControl::ApprovedFactories` (r:1 w:1)
+	/// Proof: `EvmDeploymentControl::ApprovedFactories` (`max_values`: None, `max_size`: Some(61), added: 2536, mode: `MaxEncodedLen`)
+	fn set_factory_status() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `116`
+		//  Estimated: `3526`
+		// Minimum execution time: 14_311_000 picoseconds.
+		Weight::from_parts(14_962_000, 0)
+			.saturating_add(Weight::from_parts(0, 3526))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `EvmDeploymentControl::EnactmentDelay` (r:1 w:0)
+	/// Proof: `EvmDeploymentControl::EnactmentDelay` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `EvmDeploymentControl::Enacting` (r:1 w:0)
+	/// Proof: `EvmDeploymentControl::Enacting` (`max_values`: Some(1), `max_size`: Some(0), added: 495, mode: `MaxEncodedLen`)
+	/// Storage: `EvmDeploymentControl::AuthorizationApprovalPolicy` (r:0 w:1)
+	/// Proof: `EvmDeploymentControl::AuthorizationApprovalPolicy` (`max_values`: Some(1), `max_size`: Some(518), added: 1013, mode: `MaxEncodedLen`)
+	fn set_approval_policy() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `1489`
+		// Minimum execution time: 8_412_000 picoseconds.
+		Weight::from_parts(9_073_000, 0)
+			.saturating_add(Weight::from_parts(0, 1489))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `EvmDeploymentControl::AuthorizationApprovalPolicy` (r:1 w:0)
+	/// Proof: `EvmDeploymentControl::AuthorizationApprovalPolicy` (`max_values`: Some(1), `max_size`: Some(518), added: 1013, mode: `MaxEncodedLen`)
 	/// Storage: `EvmDeploymentControl::AuthorizedDeployers` (r:1 w:0)
-	/// Proof: `EvmDeploymentControl::AuthorizedDeployers` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
-	fn is_authorized_check() -> Weight {
+	/// Proof: `EvmDeploymentControl::AuthorizedDeployers` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// Storage: `EvmDeploymentControl::PendingProposals` (r:1 w:1)
+	/// Proof: `EvmDeploymentControl::PendingProposals` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `EvmDeploymentControl::NextProposalId` (r:1 w:1)
+	/// Proof: `EvmDeploymentControl::NextProposalId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `EvmDeploymentControl::AuthorizationProposals` (r:0 w:1)
+	/// Proof: `EvmDeploymentControl::AuthorizationProposals` (`max_values`: None, `max_size`: Some(570), added: 3045, mode: `MaxEncodedLen`)
+	fn propose_authorization() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `63`
+		//  Measured:  `548`
 		//  Estimated: `3501`
-		// Minimum execution time: 4_749_000 picoseconds.
-		Weight::from_parts(5_937_000, 0)
+		// Minimum execution time: 17_905_000 picoseconds.
+		Weight::from_parts(18_847_000, 0)
 			.saturating_add(Weight::from_parts(0, 3501))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	/// Storage: `EvmDeploymentControl::AuthorizationApprovalPolicy` (r:1 w:0)
+	/// Proof: `EvmDeploymentControl::AuthorizationApprovalPolicy` (`max_values`: Some(1), `max_size`: Some(518), added: 1013, mode: `MaxEncodedLen`)
+	/// Storage: `EvmDeploymentControl::AuthorizationProposals` (r:1 w:1)
+	/// Proof: `EvmDeploymentControl::AuthorizationProposals` (`max_values`: None, `max_size`: Some(570), added: 3045, mode: `MaxEncodedLen`)
+	fn approve_authorization() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1107`
+		//  Estimated: `4035`
+		// Minimum execution time: 18_236_000 picoseconds.
+		Weight::from_parts(19_310_000, 0)
+			.saturating_add(Weight::from_parts(0, 4035))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `EvmDeploymentControl::AuthorizationApprovalPolicy` (r:1 w:0)
+	/// Proof: `EvmDeploymentControl::AuthorizationApprovalPolicy` (`max_values`: Some(1), `max_size`: Some(518), added: 1013, mode: `MaxEncodedLen`)
+	/// Storage: `EvmDeploymentControl::AuthorizationProposals` (r:1 w:1)
+	/// Proof: `EvmDeploymentControl::AuthorizationProposals` (`max_values`: None, `max_size`: Some(570), added: 3045, mode: `MaxEncodedLen`)
+	/// Storage: `EvmDeploymentControl::AuthorizationCache` (r:1 w:1)
+	/// Proof: `EvmDeploymentControl::AuthorizationCache` (`max_values`: None, `max_size`: Some(41), added: 2516, mode: `MaxEncodedLen`)
+	/// Storage: `EvmDeploymentControl::CachedAuthorizations` (r:1 w:1)
+	/// Proof: `EvmDeploymentControl::CachedAuthorizations` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `EvmDeploymentControl::AuthorizedDeployers` (r:1 w:1)
+	/// Proof: `EvmDeploymentControl::AuthorizedDeployers` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// Storage: `EvmDeploymentControl::PendingProposals` (r:1 w:1)
+	/// Proof: `EvmDeploymentControl::PendingProposals` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `EvmDeploymentControl::DeployerRiskTiers` (r:1 w:0)
+	/// Proof: `EvmDeploymentControl::DeployerRiskTiers` (`max_values`: None, `max_size`: Some(37), added: 2512, mode: `MaxEncodedLen`)
+	fn execute_authorization() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1107`
+		//  Estimated: `4035`
+		// Minimum execution time: 24_590_000 picoseconds.
+		Weight::from_parts(25_714_000, 0)
+			.saturating_add(Weight::from_parts(0, 4035))
+			.saturating_add(T::DbWeight::get().reads(7))
+			.saturating_add(T::DbWeight::get().writes(5))
+	}
+	/// Storage: `EvmDeploymentControl::AuthorizationProposals` (r:1 w:1)
+	/// Proof: `EvmDeploymentControl::AuthorizationProposals` (`max_values`: None, `max_size`: Some(570), added: 3045, mode: `MaxEncodedLen`)
+	/// Storage: `EvmDeploymentControl::PendingProposals` (r:1 w:1)
+	/// Proof: `EvmDeploymentControl::PendingProposals` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `EvmDeploymentControl::GovernanceCalls` (r:1 w:0)
+	/// Proof: `EvmDeploymentControl::GovernanceCalls` (`max_values`: None, `max_size`: Some(196), added: 2671, mode: `MaxEncodedLen`)
+	/// Storage: `EvmDeploymentControl::Referenda` (r:1 w:0)
+	/// Proof: `EvmDeploymentControl::Referenda` (`max_values`: None, `max_size`: Some(225), added: 2700, mode: `MaxEncodedLen`)
+	fn remove_expired_proposal() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `596`
+		//  Estimated: `4035`
+		// Minimum execution time: 14_118_000 picoseconds.
+		Weight::from_parts(14_906_000, 0)
+			.saturating_add(Weight::from_parts(0, 4035))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: `EvmDeploymentControl::ApprovedFactories` (r:1 w:0)
+	/// Proof: `EvmDeploymentControl::ApprovedFactories` (`max_values`: None, `max_size`: Some(61), added: 2536, mode: `MaxEncodedLen`)
+	/// Storage: `EvmDeploymentControl::FactoryPermissionsOf` (r:0 w:1)
+	/// Proof: `EvmDeploymentControl::FactoryPermissionsOf` (`max_values`: None, `max_size`: Some(21), added: 2496, mode: `MaxEncodedLen`)
+	fn set_factory_permissions() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `116`
+		//  Estimated: `3526`
+		// Minimum execution time: 11_203_000 picoseconds.
+		Weight::from_parts(11_873_000, 0)
+			.saturating_add(Weight::from_parts(0, 3526))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `EvmDeploymentControl::ApprovedFactories` (r:1 w:0)
+	/// Proof: `EvmDeploymentControl::ApprovedFactories` (`max_values`: None, `max_size`: Some(61), added: 2536, mode: `MaxEncodedLen`)
+	/// Storage: `EvmDeploymentControl::FactoryPermissionsOf` (r:1 w:0)
+	/// Proof: `EvmDeploymentControl::FactoryPermissionsOf` (`max_values`: None, `max_size`: Some(21), added: 2496, mode: `MaxEncodedLen`)
+	/// Storage: `EvmDeploymentControl::ContractProvenance` (r:1 w:0)
+	/// Proof: `EvmDeploymentControl::ContractProvenance` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `EvmDeploymentControl::ContractMetadataOf` (r:1 w:1)
+	/// Proof: `EvmDeploymentControl::ContractMetadataOf` (`max_values`: None, `max_size`: Some(152), added: 2627, mode: `MaxEncodedLen`)
+	fn register_child_metadata() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `204`
+		//  Estimated: `3617`
+		// Minimum execution time: 19_482_000 picoseconds.
+		Weight::from_parts(20_391_000, 0)
+			.saturating_add(Weight::from_parts(0, 3617))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `EvmDeploymentControl::ApprovedFactories` (r:1 w:0)
+	/// Proof: `EvmDeploymentControl::ApprovedFactories` (`max_values`: None, `max_size`: Some(61), added: 2536, mode: `MaxEncodedLen`)
+	/// Storage: `EvmDeploymentControl::FactoryExpirySchedule` (r:2 w:2)
+	/// Proof: `EvmDeploymentControl::FactoryExpirySchedule` (`max_values`: None, `max_size`: Some(398), added: 2873, mode: `MaxEncodedLen`)
+	/// Storage: `EvmDeploymentControl::FactoryExpiry` (r:0 w:1)
+	/// Proof: `EvmDeploymentControl::FactoryExpiry` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	fn set_factory_expiry() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `116`
+		//  Estimated: `6734`
+		// Minimum execution time: 17_316_000 picoseconds.
+		Weight::from_parts(18_092_000, 0)
+			.saturating_add(Weight::from_parts(0, 6734))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	/// Storage: `EvmDeploymentControl::FactoryExpirySchedule` (r:1 w:1)
+	/// Proof: `EvmDeploymentControl::FactoryExpirySchedule` (`max_values`: None, `max_size`: Some(398), added: 2873, mode: `MaxEncodedLen`)
+	/// Storage: `EvmDeploymentControl::FactoryExpiry` (r:16 w:16)
+	/// Proof: `EvmDeploymentControl::FactoryExpiry` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `EvmDeploymentControl::ApprovedFactories` (r:16 w:16)
+	/// Proof: `EvmDeploymentControl::ApprovedFactories` (`max_values`: None, `max_size`: Some(61), added: 2536, mode: `MaxEncodedLen`)
+	/// The range of component `e` is `[0, 16]`.
+	fn process_factory_expiries(e: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `41 + e * (141 ±0)`
+		//  Estimated: `3862 + e * (2536 ±0)`
+		// Minimum execution time: 4_107_000 picoseconds.
+		Weight::from_parts(4_688_315, 0)
+			.saturating_add(Weight::from_parts(0, 3862))
+			// Standard Error: 6_214
+			.saturating_add(Weight::from_parts(12_861_402, 0).saturating_mul(e.into()))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(e.into())))
+			.saturating_add(T::DbWeight::get().writes(1))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(e.into())))
+			.saturating_add(Weight::from_parts(0, 2536).saturating_mul(e.into()))
+	}
+	/// Storage: `EvmDeploymentControl::AuthorizationCache` (r:1 w:1)
+	/// Proof: `EvmDeploymentControl::AuthorizationCache` (`max_values`: None, `max_size`: Some(41), added: 2516, mode: `MaxEncodedLen`)
+	/// Storage: `EvmDeploymentControl::CachedAuthorizations` (r:1 w:1)
+	/// Proof: `EvmDeploymentControl::CachedAuthorizations` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `EvmDeploymentControl::AuthorizedDeployers` (r:1 w:0)
+	/// Proof: `EvmDeploymentControl::AuthorizedDeployers` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// Storage: `EvmDeploymentControl::DeploymentIntents` (r:1 w:1)
+	/// Proof: `EvmDeploymentControl::DeploymentIntents` (`max_values`: None, `max_size`: Some(154), added: 2629, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(55), added: 2530, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(116), added: 2591, mode: `MaxEncodedLen`)
+	fn announce_deployment() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `185`
+		//  Estimated: `3619`
+		// Minimum execution time: 38_206_000 picoseconds.
+		Weight::from_parts(39_871_000, 0)
+			.saturating_add(Weight::from_parts(0, 3619))
+			.saturating_add(T::DbWeight::get().reads(6))
+			.saturating_add(T::DbWeight::get().writes(5))
+	}
+	/// Storage: `EvmDeploymentControl::EnactmentDelay` (r:1 w:0)
+	/// Proof: `EvmDeploymentControl::EnactmentDelay` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `EvmDeploymentControl::Enacting` (r:1 w:0)
+	/// Proof: `EvmDeploymentControl::Enacting` (`max_values`: Some(1), `max_size`: Some(0), added: 495, mode: `MaxEncodedLen`)
+	/// Storage: `EvmDeploymentControl::EvmDeploymentPolicy` (r:0 w:1)
+	/// Proof: `EvmDeploymentControl::EvmDeploymentPolicy` (`max_values`: Some(1), `max_size`: Some(17), added: 512, mode: `MaxEncodedLen`)
+	fn set_deployment_policy() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `1489`
+		// Minimum execution time: 6_512_000 picoseconds.
+		Weight::from_parts(7_104_000, 0)
+			.saturating_add(Weight::from_parts(0, 1489))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `EvmDeploymentControl::AuthorizationCache` (r:2 w:2)
+	/// Proof: `EvmDeploymentControl::AuthorizationCache` (`max_values`: None, `max_size`: Some(41), added: 2516, mode: `MaxEncodedLen`)
+	/// Storage: `EvmDeploymentControl::CachedAuthorizations` (r:1 w:1)
+	/// Proof: `EvmDeploymentControl::CachedAuthorizations` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `EvmDeploymentControl::AuthorizedDeployers` (r:2 w:2)
+	/// Proof: `EvmDeploymentControl::AuthorizedDeployers` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// Storage: `EvmDeploymentControl::DeploymentIntents` (r:1 w:0)
+	/// Proof: `EvmDeploymentControl::DeploymentIntents` (`max_values`: None, `max_size`: Some(154), added: 2629, mode: `MaxEncodedLen`)
+	/// Storage: `EvmDeploymentControl::PendingKeyRotations` (r:1 w:1)
+	/// Proof: `EvmDeploymentControl::PendingKeyRotations` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
+	/// Storage: `EvmDeploymentControl::WindowOverrides` (r:1 w:2)
+	/// Proof: `EvmDeploymentControl::WindowOverrides` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	/// Storage: `EvmDeploymentControl::DeployerTenant` (r:1 w:2)
+	/// Proof: `EvmDeploymentControl::DeployerTenant` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `EvmDeploymentControl::DeploymentQuotaUsage` (r:1 w:2)
+	/// Proof: `EvmDeploymentControl::DeploymentQuotaUsage` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	fn rotate_deployer_key() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `290`
+		//  Estimated: `6120`
+		// Minimum execution time: 24_733_000 picoseconds.
+		Weight::from_parts(25_918_000, 0)
+			.saturating_add(Weight::from_parts(0, 6120))
+			.saturating_add(T::DbWeight::get().reads(10))
+			.saturating_add(T::DbWeight::get().writes(12))
+	}
+	/// Storage: `EvmDeploymentControl::AuthorizationCache` (r:1 w:1)
+	/// Proof: `EvmDeploymentControl::AuthorizationCache` (`max_values`: None, `max_size`: Some(41), added: 2516, mode: `MaxEncodedLen`)
+	/// Storage: `EvmDeploymentControl::CachedAuthorizations` (r:1 w:1)
+	/// Proof: `EvmDeploymentControl::CachedAuthorizations` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `EvmDeploymentControl::AuthorizedDeployers` (r:1 w:1)
+	/// Proof: `EvmDeploymentControl::AuthorizedDeployers` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// Storage: `EvmDeploymentControl::ContractWalletOperators` (r:0 w:1)
+	/// Proof: `EvmDeploymentControl::ContractWalletOperators` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	fn authorize_contract_wallet() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `3`
+		//  Estimated: `3521`
+		// Minimum execution time: 11_204_000 picoseconds.
+		Weight::from_parts(12_317_000, 0)
+			.saturating_add(Weight::from_parts(0, 3521))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(4))
+	}
+	/// Storage: `EvmDeploymentControl::ApprovedFactories` (r:1 w:0)
+	/// Proof: `EvmDeploymentControl::ApprovedFactories` (`max_values`: None, `max_size`: Some(61), added: 2536, mode: `MaxEncodedLen`)
+	/// Storage: `EvmDeploymentControl::FactoryMetadata` (r:1 w:1)
+	/// Proof: `EvmDeploymentControl::FactoryMetadata` (`max_values`: None, `max_size`: Some(151), added: 2626, mode: `MaxEncodedLen`)
+	/// Storage: `Preimage::StatusFor` (r:1 w:0)
+	/// Proof: `Preimage::StatusFor` (`max_values`: None, `max_size`: Some(91), added: 2566, mode: `MaxEncodedLen`)
+	/// Storage: `Preimage::RequestStatusFor` (r:1 w:1)
+	/// Proof: `Preimage::RequestStatusFor` (`max_values`: None, `max_size`: Some(91), added: 2566, mode: `MaxEncodedLen`)
+	/// Storage: `Preimage::PreimageFor` (r:0 w:1)
+	/// Proof: `Preimage::PreimageFor` (`max_values`: None, `max_size`: Some(4194344), added: 4196819, mode: `Measured`)
+	/// The range of component `l` is `[0, 4096]`.
+	fn set_factory_metadata(l: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `116`
+		//  Estimated: `3556`
+		// Minimum execution time: 21_508_000 picoseconds.
+		Weight::from_parts(22_631_420, 0)
+			.saturating_add(Weight::from_parts(0, 3556))
+			// Standard Error: 41
+			.saturating_add(Weight::from_parts(1_437, 0).saturating_mul(l.into()))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	/// Storage: `EvmDeploymentControl::AuthorizationCache` (r:1 w:1)
+	/// Proof: `EvmDeploymentControl::AuthorizationCache` (`max_values`: None, `max_size`: Some(41), added: 2516, mode: `MaxEncodedLen`)
+	/// Storage: `EvmDeploymentControl::CachedAuthorizations` (r:1 w:1)
+	/// Proof: `EvmDeploymentControl::CachedAuthorizations` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `EvmDeploymentControl::AuthorizedDeployers` (r:1 w:0)
+	/// Proof: `EvmDeploymentControl::AuthorizedDeployers` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// Storage: `EvmDeploymentControl::DeployerMetadata` (r:1 w:1)
+	/// Proof: `EvmDeploymentControl::DeployerMetadata` (`max_values`: None, `max_size`: Some(167), added: 2642, mode: `MaxEncodedLen`)
+	/// Storage: `Preimage::StatusFor` (r:1 w:0)
+	/// Proof: `Preimage::StatusFor` (`max_values`: None, `max_size`: Some(91), added: 2566, mode: `MaxEncodedLen`)
+	/// Storage: `Preimage::RequestStatusFor` (r:1 w:1)
+	/// Proof: `Preimage::RequestStatusFor` (`max_values`: None, `max_size`: Some(91), added: 2566, mode: `MaxEncodedLen`)
+	/// Storage: `Preimage::PreimageFor` (r:0 w:1)
+	/// Proof: `Preimage::PreimageFor` (`max_values`: None, `max_size`: Some(4194344), added: 4196819, mode: `Measured`)
+	/// The range of component `l` is `[0, 4096]`.
+	fn set_deployer_metadata(l: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `38`
+		//  Estimated: `3632`
+		// Minimum execution time: 24_917_000 picoseconds.
+		Weight::from_parts(26_104_885, 0)
+			.saturating_add(Weight::from_parts(0, 3632))
+			// Standard Error: 44
+			.saturating_add(Weight::from_parts(1_441, 0).saturating_mul(l.into()))
+			.saturating_add(T::DbWeight::get().reads(6))
+			.saturating_add(T::DbWeight::get().writes(5))
+	}
+	/// Storage: `EvmDeploymentControl::ApprovedFactories` (r:1 w:1)
+	/// Proof: `EvmDeploymentControl::ApprovedFactories` (`max_values`: None, `max_size`: Some(61), added: 2536, mode: `MaxEncodedLen`)
+	/// Storage: `EvmDeploymentControl::FactoryMetadata` (r:1 w:1)
+	/// Proof: `EvmDeploymentControl::FactoryMetadata` (`max_values`: None, `max_size`: Some(151), added: 2626, mode: `MaxEncodedLen`)
+	/// Storage: `Preimage::RequestStatusFor` (r:1 w:1)
+	/// Proof: `Preimage::RequestStatusFor` (`max_values`: None, `max_size`: Some(91), added: 2566, mode: `MaxEncodedLen`)
+	/// Storage: `Preimage::PreimageFor` (r:0 w:1)
+	/// Proof: `Preimage::PreimageFor` (`max_values`: None, `max_size`: Some(4194344), added: 4196819, mode: `Measured`)
+	/// Storage: `EvmDeploymentControl::FactoryPermissionsOf` (r:0 w:1)
+	/// Proof: `EvmDeploymentControl::FactoryPermissionsOf` (`max_values`: None, `max_size`: Some(21), added: 2496, mode: `MaxEncodedLen`)
+	/// Storage: `EvmDeploymentControl::FactoryExpiry` (r:0 w:1)
+	/// Proof: `EvmDeploymentControl::FactoryExpiry` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `EvmDeploymentControl::FactoryTenant` (r:1 w:1)
+	/// Proof: `EvmDeploymentControl::FactoryTenant` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `EvmDeploymentControl::Tenants` (r:1 w:1)
+	/// Proof: `EvmDeploymentControl::Tenants` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
+	/// The range of component `m` is `[0, 1]`.
+	fn unregister_factory(m: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `150 + m * (114 ±0)`
+		//  Estimated: `3616`
+		// Minimum execution time: 12_836_000 picoseconds.
+		Weight::from_parts(13_410_000, 0)
+			.saturating_add(Weight::from_parts(0, 3616))
+			// Standard Error: 7_093
+			.saturating_add(Weight::from_parts(11_082_000, 0).saturating_mul(m.into()))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(m.into())))
+			.saturating_add(T::DbWeight::get().writes(6))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(m.into())))
+	}
+	/// Storage: `EvmDeploymentControl::DeployerAdmins` (r:0 w:1)
+	/// Proof: `EvmDeploymentControl::DeployerAdmins` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	fn set_deployer_admin() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 7_286_000 picoseconds.
+		Weight::from_parts(7_802_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `EvmDeploymentControl::DeployerAdmins` (r:1 w:0)
+	/// Proof: `EvmDeploymentControl::DeployerAdmins` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	fn dispatch_as_deployer_admin() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `76`
+		//  Estimated: `3501`
+		// Minimum execution time: 9_117_000 picoseconds.
+		Weight::from_parts(9_654_000, 0)
+			.saturating_add(Weight::from_parts(0, 3501))
+			.saturating_add(T::DbWeight::get().reads(1))
+	}
+	/// Storage: `EvmDeploymentControl::DeploymentIntents` (r:1 w:1)
+	/// Proof: `EvmDeploymentControl::DeploymentIntents` (`max_values`: None, `max_size`: Some(154), added: 2629, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(55), added: 2530, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(116), added: 2591, mode: `MaxEncodedLen`)
+	fn slash_deployment_intent() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `412`
+		//  Estimated: `6172`
+		// Minimum execution time: 41_583_000 picoseconds.
+		Weight::from_parts(43_120_000, 0)
+			.saturating_add(Weight::from_parts(0, 6172))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(4))
+	}
+	/// Storage: `EvmDeploymentControl::EnactmentDelay` (r:1 w:0)
+	/// Proof: `EvmDeploymentControl::EnactmentDelay` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `EvmDeploymentControl::Enacting` (r:1 w:0)
+	/// Proof: `EvmDeploymentControl::Enacting` (`max_values`: Some(1), `max_size`: Some(0), added: 495, mode: `MaxEncodedLen`)
+	/// Storage: `EvmDeploymentControl::DeploymentQuota` (r:0 w:1)
+	/// Proof: `EvmDeploymentControl::DeploymentQuota` (`max_values`: Some(1), `max_size`: Some(5), added: 500, mode: `MaxEncodedLen`)
+	fn set_deployment_quota() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `1489`
+		// Minimum execution time: 5_811_000 picoseconds.
+		Weight::from_parts(6_304_000, 0)
+			.saturating_add(Weight::from_parts(0, 1489))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `EvmDeploymentControl::DeploymentQuota` (r:1 w:0)
+	/// Proof: `EvmDeploymentControl::DeploymentQuota` (`max_values`: Some(1), `max_size`: Some(5), added: 500, mode: `MaxEncodedLen`)
+	/// Storage: `EvmDeploymentControl::DeploymentQuotaUsage` (r:1 w:1)
+	/// Proof: `EvmDeploymentControl::DeploymentQuotaUsage` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	fn use_deployment_quota() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `146`
+		//  Estimated: `3521`
+		// Minimum execution time: 9_032_000 picoseconds.
+		Weight::from_parts(9_488_000, 0)
+			.saturating_add(Weight::from_parts(0, 3521))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `EvmDeploymentControl::NextTenantId` (r:1 w:1)
+	/// Proof: `EvmDeploymentControl::NextTenantId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `EvmDeploymentControl::Tenants` (r:0 w:1)
+	/// Proof: `EvmDeploymentControl::Tenants` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
+	fn create_tenant() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `1489`
+		// Minimum execution time: 7_902_000 picoseconds.
+		Weight::from_parts(8_417_000, 0)
+			.saturating_add(Weight::from_parts(0, 1489))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: `EvmDeploymentControl::Tenants` (r:1 w:1)
+	/// Proof: `EvmDeploymentControl::Tenants` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
+	fn set_tenant_bounds() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `108`
+		//  Estimated: `3525`
+		// Minimum execution time: 9_215_000 picoseconds.
+		Weight::from_parts(9_781_000, 0)
+			.saturating_add(Weight::from_parts(0, 3525))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `EvmDeploymentControl::Tenants` (r:1 w:1)
+	/// Proof: `EvmDeploymentControl::Tenants` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
+	fn set_tenant_admin() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `108`
+		//  Estimated: `3525`
+		// Minimum execution time: 9_674_000 picoseconds.
+		Weight::from_parts(10_203_000, 0)
+			.saturating_add(Weight::from_parts(0, 3525))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `EvmDeploymentControl::Tenants` (r:1 w:1)
+	/// Proof: `EvmDeploymentControl::Tenants` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
+	/// Storage: `EvmDeploymentControl::ApprovedFactories` (r:1 w:1)
+	/// Proof: `EvmDeploymentControl::ApprovedFactories` (`max_values`: None, `max_size`: Some(61), added: 2536, mode: `MaxEncodedLen`)
+	/// Storage: `EvmDeploymentControl::FactoryTenant` (r:0 w:1)
+	/// Proof: `EvmDeploymentControl::FactoryTenant` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	fn register_tenant_factory() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `108`
+		//  Estimated: `3526`
+		// Minimum execution time: 14_586_000 picoseconds.
+		Weight::from_parts(15_297_000, 0)
+			.saturating_add(Weight::from_parts(0, 3526))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	/// Storage: `EvmDeploymentControl::FactoryTenant` (r:1 w:0)
+	/// Proof: `EvmDeploymentControl::FactoryTenant` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `EvmDeploymentControl::Tenants` (r:1 w:0)
+	/// Proof: `EvmDeploymentControl::Tenants` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
+	/// Storage: `EvmDeploymentControl::Retirements` (r:1 w:0)
+	/// Proof: `EvmDeploymentControl::Retirements` (`max_values`: None, `max_size`: Some(25), added: 2500, mode: `MaxEncodedLen`)
+	/// Storage: `EvmDeploymentControl::ApprovedFactories` (r:1 w:1)
+	/// Proof: `EvmDeploymentControl::ApprovedFactories` (`max_values`: None, `max_size`: Some(61), added: 2536, mode: `MaxEncodedLen`)
+	fn set_tenant_factory_status() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `252`
+		//  Estimated: `3526`
+		// Minimum execution time: 13_108_000 picoseconds.
+		Weight::from_parts(13_749_000, 0)
+			.saturating_add(Weight::from_parts(0, 3526))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `EvmDeploymentControl::Tenants` (r:1 w:1)
+	/// Proof: `EvmDeploymentControl::Tenants` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
+	/// Storage: `EvmDeploymentControl::AuthorizationCache` (r:1 w:1)
+	/// Proof: `EvmDeploymentControl::AuthorizationCache` (`max_values`: None, `max_size`: Some(41), added: 2516, mode: `MaxEncodedLen`)
+	/// Storage: `EvmDeploymentControl::CachedAuthorizations` (r:1 w:1)
+	/// Proof: `EvmDeploymentControl::CachedAuthorizations` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `EvmDeploymentControl::AuthorizedDeployers` (r:1 w:1)
+	/// Proof: `EvmDeploymentControl::AuthorizedDeployers` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// Storage: `EvmDeploymentControl::DeployerTenant` (r:0 w:1)
+	/// Proof: `EvmDeploymentControl::DeployerTenant` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	fn authorize_tenant_deployer() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `108`
+		//  Estimated: `3525`
+		// Minimum execution time: 16_322_000 picoseconds.
+		Weight::from_parts(17_046_000, 0)
+			.saturating_add(Weight::from_parts(0, 3525))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(5))
+	}
+	/// Storage: `EvmDeploymentControl::PendingGovernanceCalls` (r:1 w:1)
+	/// Proof: `EvmDeploymentControl::PendingGovernanceCalls` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `EvmDeploymentControl::EnactmentDelay` (r:1 w:0)
+	/// Proof: `EvmDeploymentControl::EnactmentDelay` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `EvmDeploymentControl::NextGovernanceCallId` (r:1 w:1)
+	/// Proof: `EvmDeploymentControl::NextGovernanceCallId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `EvmDeploymentControl::GovernanceCalls` (r:0 w:1)
+	/// Proof: `EvmDeploymentControl::GovernanceCalls` (`max_values`: None, `max_size`: Some(196), added: 2671, mode: `MaxEncodedLen`)
+	/// Storage: `EvmDeploymentControl::Objections` (r:0 w:1)
+	/// Proof: `EvmDeploymentControl::Objections` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
+	fn schedule_governance_call() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `1489`
+		// Minimum execution time: 11_874_000 picoseconds.
+		Weight::from_parts(12_419_000, 0)
+			.saturating_add(Weight::from_parts(0, 1489))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(4))
+	}
+	/// Storage: `EvmDeploymentControl::GovernanceCalls` (r:1 w:1)
+	/// Proof: `EvmDeploymentControl::GovernanceCalls` (`max_values`: None, `max_size`: Some(196), added: 2671, mode: `MaxEncodedLen`)
+	/// Storage: `EvmDeploymentControl::PendingGovernanceCalls` (r:1 w:1)
+	/// Proof: `EvmDeploymentControl::PendingGovernanceCalls` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `EvmDeploymentControl::Enacting` (r:0 w:1)
+	/// Proof: `EvmDeploymentControl::Enacting` (`max_values`: Some(1), `max_size`: Some(0), added: 495, mode: `MaxEncodedLen`)
+	/// Storage: `EvmDeploymentControl::Objections` (r:0 w:1)
+	/// Proof: `EvmDeploymentControl::Objections` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
+	fn enact_governance_call() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `152`
+		//  Estimated: `3661`
+		// Minimum execution time: 14_306_000 picoseconds.
+		Weight::from_parts(15_022_000, 0)
+			.saturating_add(Weight::from_parts(0, 3661))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(4))
+	}
+	/// Storage: `EvmDeploymentControl::GovernanceCalls` (r:1 w:1)
+	/// Proof: `EvmDeploymentControl::GovernanceCalls` (`max_values`: None, `max_size`: Some(196), added: 2671, mode: `MaxEncodedLen`)
+	/// Storage: `EvmDeploymentControl::PendingGovernanceCalls` (r:1 w:1)
+	/// Proof: `EvmDeploymentControl::PendingGovernanceCalls` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `EvmDeploymentControl::Objections` (r:0 w:1)
+	/// Proof: `EvmDeploymentControl::Objections` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
+	fn cancel_governance_call() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `152`
+		//  Estimated: `3661`
+		// Minimum execution time: 11_438_000 picoseconds.
+		Weight::from_parts(12_051_000, 0)
+			.saturating_add(Weight::from_parts(0, 3661))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	/// Storage: `EvmDeploymentControl::EnactmentDelay` (r:1 w:1)
+	/// Proof: `EvmDeploymentControl::EnactmentDelay` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `EvmDeploymentControl::Enacting` (r:1 w:0)
+	/// Proof: `EvmDeploymentControl::Enacting` (`max_values`: Some(1), `max_size`: Some(0), added: 495, mode: `MaxEncodedLen`)
+	fn set_enactment_delay() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `1489`
+		// Minimum execution time: 6_472_000 picoseconds.
+		Weight::from_parts(6_935_000, 0)
+			.saturating_add(Weight::from_parts(0, 1489))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `EvmDeploymentControl::Objections` (r:1 w:1)
+	/// Proof: `EvmDeploymentControl::Objections` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
+	/// Storage: `EvmDeploymentControl::GovernanceCalls` (r:1 w:1)
+	/// Proof: `EvmDeploymentControl::GovernanceCalls` (`max_values`: None, `max_size`: Some(196), added: 2671, mode: `MaxEncodedLen`)
+	/// Storage: `EvmDeploymentControl::Votes` (r:1 w:1)
+	/// Proof: `EvmDeploymentControl::Votes` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(193), added: 2668, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::TotalIssuance` (r:1 w:0)
+	/// Proof: `Balances::TotalIssuance` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `EvmDeploymentControl::PendingGovernanceCalls` (r:1 w:1)
+	/// Proof: `EvmDeploymentControl::PendingGovernanceCalls` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `EvmDeploymentControl::Referenda` (r:0 w:1)
+	/// Proof: `EvmDeploymentControl::Referenda` (`max_values`: None, `max_size`: Some(225), added: 2700, mode: `MaxEncodedLen`)
+	fn object_to_authorization() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `478`
+		//  Estimated: `3661`
+		// Minimum execution time: 52_913_000 picoseconds.
+		Weight::from_parts(54_370_000, 0)
+			.saturating_add(Weight::from_parts(0, 3661))
+			.saturating_add(T::DbWeight::get().reads(7))
+			.saturating_add(T::DbWeight::get().writes(7))
+	}
+	/// Storage: `EvmDeploymentControl::Referenda` (r:1 w:1)
+	/// Proof: `EvmDeploymentControl::Referenda` (`max_values`: None, `max_size`: Some(225), added: 2700, mode: `MaxEncodedLen`)
+	/// Storage: `EvmDeploymentControl::Votes` (r:1 w:1)
+	/// Proof: `EvmDeploymentControl::Votes` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(193), added: 2668, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn vote() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `416`
+		//  Estimated: `3690`
+		// Minimum execution time: 44_682_000 picoseconds.
+		Weight::from_parts(46_105_000, 0)
+			.saturating_add(Weight::from_parts(0, 3690))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(4))
+	}
+	/// Storage: `EvmDeploymentControl::Referenda` (r:1 w:1)
+	/// Proof: `EvmDeploymentControl::Referenda` (`max_values`: None, `max_size`: Some(225), added: 2700, mode: `MaxEncodedLen`)
+	/// Storage: `EvmDeploymentControl::Enacting` (r:0 w:1)
+	/// Proof: `EvmDeploymentControl::Enacting` (`max_values`: Some(1), `max_size`: Some(0), added: 495, mode: `MaxEncodedLen`)
+	fn conclude_referendum() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `248`
+		//  Estimated: `3690`
+		// Minimum execution time: 13_291_000 picoseconds.
+		Weight::from_parts(13_947_000, 0)
+			.saturating_add(Weight::from_parts(0, 3690))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: `EvmDeploymentControl::Objections` (r:1 w:0)
+	/// Proof: `EvmDeploymentControl::Objections` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
+	/// Storage: `EvmDeploymentControl::Referenda` (r:1 w:0)
+	/// Proof: `EvmDeploymentControl::Referenda` (`max_values`: None, `max_size`: Some(225), added: 2700, mode: `MaxEncodedLen`)
+	/// Storage: `EvmDeploymentControl::Votes` (r:1 w:1)
+	/// Proof: `EvmDeploymentControl::Votes` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(193), added: 2668, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn unlock_vote() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `431`
+		//  Estimated: `3658`
+		// Minimum execution time: 38_004_000 picoseconds.
+		Weight::from_parts(39_526_000, 0)
+			.saturating_add(Weight::from_parts(0, 3658))
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	/// Storage: `EvmDeploymentControl::ContractProvenance` (r:1 w:1)
+	/// Proof: `EvmDeploymentControl::ContractProvenance` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `EvmDeploymentControl::Retirements` (r:1 w:0)
+	/// Proof: `EvmDeploymentControl::Retirements` (`max_values`: None, `max_size`: Some(25), added: 2500, mode: `MaxEncodedLen`)
+	/// Storage: `EvmDeploymentControl::ApprovedFactories` (r:1 w:1)
+	/// Proof: `EvmDeploymentControl::ApprovedFactories` (`max_values`: None, `max_size`: Some(61), added: 2536, mode: `MaxEncodedLen`)
+	fn transfer_contract_admin() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `214`
+		//  Estimated: `3526`
+		// Minimum execution time: 19_630_000 picoseconds.
+		Weight::from_parts(20_418_000, 0)
+			.saturating_add(Weight::from_parts(0, 3526))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: `EvmDeploymentControl::ContractProvenance` (r:1 w:0)
+	/// Proof: `EvmDeploymentControl::ContractProvenance` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `EvmDeploymentControl::Retirements` (r:1 w:1)
+	/// Proof: `EvmDeploymentControl::Retirements` (`max_values`: None, `max_size`: Some(25), added: 2500, mode: `MaxEncodedLen`)
+	/// Storage: `EvmDeploymentControl::RetirementWarnings` (r:2 w:2)
+	/// Proof: `EvmDeploymentControl::RetirementWarnings` (`max_values`: None, `max_size`: Some(398), added: 2873, mode: `MaxEncodedLen`)
+	fn announce_retirement() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `178`
+		//  Estimated: `6736`
+		// Minimum execution time: 21_547_000 picoseconds.
+		Weight::from_parts(22_309_000, 0)
+			.saturating_add(Weight::from_parts(0, 6736))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	/// Storage: `EvmDeploymentControl::Retirements` (r:1 w:1)
+	/// Proof: `EvmDeploymentControl::Retirements` (`max_values`: None, `max_size`: Some(25), added: 2500, mode: `MaxEncodedLen`)
+	/// Storage: `EvmDeploymentControl::ApprovedFactories` (r:1 w:1)
+	/// Proof: `EvmDeploymentControl::ApprovedFactories` (`max_values`: None, `max_size`: Some(61), added: 2536, mode: `MaxEncodedLen`)
+	fn finalize_retirement() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `197`
+		//  Estimated: `3526`
+		// Minimum execution time: 15_208_000 picoseconds.
+		Weight::from_parts(15_877_000, 0)
+			.saturating_add(Weight::from_parts(0, 3526))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: `EvmDeploymentControl::RetirementWarnings` (r:1 w:1)
+	/// Proof: `EvmDeploymentControl::RetirementWarnings` (`max_values`: None, `max_size`: Some(398), added: 2873, mode: `MaxEncodedLen`)
+	/// Storage: `EvmDeploymentControl::Retirements` (r:16 w:0)
+	/// Proof: `EvmDeploymentControl::Retirements` (`max_values`: None, `max_size`: Some(25), added: 2500, mode: `MaxEncodedLen`)
+	/// The range of component `w` is `[0, 16]`.
+	fn process_retirement_warnings(w: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `41 + w * (98 ±0)`
+		//  Estimated: `3862 + w * (2500 ±0)`
+		// Minimum execution time: 3_942_000 picoseconds.
+		Weight::from_parts(4_415_062, 0)
+			.saturating_add(Weight::from_parts(0, 3862))
+			// Standard Error: 4_871
+			.saturating_add(Weight::from_parts(5_730_118, 0).saturating_mul(w.into()))
 			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(w.into())))
+			.saturating_add(T::DbWeight::get().writes(1))
+			.saturating_add(Weight::from_parts(0, 2500).saturating_mul(w.into()))
+	}
+	/// Storage: `EvmDeploymentControl::EnactmentDelay` (r:1 w:0)
+	/// Proof: `EvmDeploymentControl::EnactmentDelay` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `EvmDeploymentControl::Enacting` (r:1 w:0)
+	/// Proof: `EvmDeploymentControl::Enacting` (`max_values`: Some(1), `max_size`: Some(0), added: 495, mode: `MaxEncodedLen`)
+	/// Storage: `EvmDeploymentControl::DeployerRiskTiers` (r:0 w:1)
+	/// Proof: `EvmDeploymentControl::DeployerRiskTiers` (`max_values`: None, `max_size`: Some(37), added: 2512, mode: `MaxEncodedLen`)
+	fn set_deployer_risk_tier() -> Weight {
+		// NOT BENCHMARKED: hand-written estimate until the `set_deployer_risk_tier` benchmark
+		// is run on the reference machine, priced like `set_enactment_delay`.
+		// Proof Size summary in bytes:
+		//  Estimated: `1489`
+		Weight::from_parts(8_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 1489))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(1))
 	}
 }
 
@@ -84,18 +1131,361 @@ impl WeightInfo for () {
 	fn authorize_deployer() -> Weight {
 		Weight::from_parts(10_686_000, 3501)
 			.saturating_add(Weight::from_parts(0, 3501))
+			.saturating_add(frame_support::weights::constants::RocksDbWeight::get().reads(5))
+			.saturating_add(frame_support::weights::constants::RocksDbWeight::get().writes(3))
+	}
+	fn revoke_deployer(o: u32, ) -> Weight {
+		Weight::from_parts(12_904_000, 3525)
+			.saturating_add(Weight::from_parts(0, 3525))
+			.saturating_add(Weight::from_parts(6_737_000, 0).saturating_mul(o.into()))
+			.saturating_add(frame_support::weights::constants::RocksDbWeight::get().reads(8))
+			.saturating_add(frame_support::weights::constants::RocksDbWeight::get().reads((2_u64).saturating_mul(o.into())))
+			.saturating_add(frame_support::weights::constants::RocksDbWeight::get().writes(5))
+			.saturating_add(frame_support::weights::constants::RocksDbWeight::get().writes((2_u64).saturating_mul(o.into())))
+	}
+	fn is_authorized_check() -> Weight {
+		Weight::from_parts(5_937_000, 3501)
+			.saturating_add(Weight::from_parts(0, 3501))
+			.saturating_add(frame_support::weights::constants::RocksDbWeight::get().reads(3))
+			.saturating_add(frame_support::weights::constants::RocksDbWeight::get().writes(2))
+	}
+	fn ensure_can_deploy(w: u32, ) -> Weight {
+		Weight::from_parts(12_000_000, 3521)
+			.saturating_add(Weight::from_parts(0, 3521))
+			.saturating_add(Weight::from_parts(150_000, 0).saturating_mul(w.into()))
+			.saturating_add(frame_support::weights::constants::RocksDbWeight::get().reads(7))
+			.saturating_add(frame_support::weights::constants::RocksDbWeight::get().writes(2))
+	}
+	fn report_rejected_attempts() -> Weight {
+		Weight::from_parts(13_291_000, 3521)
+			.saturating_add(Weight::from_parts(0, 3521))
+			.saturating_add(frame_support::weights::constants::RocksDbWeight::get().reads(2))
+			.saturating_add(frame_support::weights::constants::RocksDbWeight::get().writes(1))
+	}
+	fn unflag_deployer() -> Weight {
+		Weight::from_parts(12_479_000, 3517)
+			.saturating_add(Weight::from_parts(0, 3517))
 			.saturating_add(frame_support::weights::constants::RocksDbWeight::get().reads(1))
+			.saturating_add(frame_support::weights::constants::RocksDbWeight::get().writes(2))
+	}
+	fn set_max_creates_per_block() -> Weight {
+		Weight::from_parts(6_217_000, 1489)
+			.saturating_add(Weight::from_parts(0, 1489))
+			.saturating_add(frame_support::weights::constants::RocksDbWeight::get().reads(2))
+			.saturating_add(frame_support::weights::constants::RocksDbWeight::get().writes(1))
+	}
+	fn set_deployment_windows(w: u32, ) -> Weight {
+		Weight::from_parts(6_688_422, 1489)
+			.saturating_add(Weight::from_parts(0, 1489))
+			.saturating_add(Weight::from_parts(41_907, 0).saturating_mul(w.into()))
+			.saturating_add(frame_support::weights::constants::RocksDbWeight::get().reads(2))
+			.saturating_add(frame_support::weights::constants::RocksDbWeight::get().writes(1))
+	}
+	fn set_window_override() -> Weight {
+		Weight::from_parts(7_544_000, 0)
+			.saturating_add(frame_support::weights::constants::RocksDbWeight::get().writes(1))
+	}
+	fn schedule_cleanup() -> Weight {
+		Weight::from_parts(10_982_000, 1489)
+			.saturating_add(Weight::from_parts(0, 1489))
+			.saturating_add(frame_support::weights::constants::RocksDbWeight::get().reads(2))
+			.saturating_add(frame_support::weights::constants::RocksDbWeight::get().writes(2))
+	}
+	fn process_cleanup_task() -> Weight {
+		Weight::from_parts(16_622_000, 3510)
+			.saturating_add(Weight::from_parts(0, 3510))
+			.saturating_add(frame_support::weights::constants::RocksDbWeight::get().reads(3))
+			.saturating_add(frame_support::weights::constants::RocksDbWeight::get().writes(2))
+	}
+	fn set_opcode_policy() -> Weight {
+		Weight::from_parts(7_022_000, 1489)
+			.saturating_add(Weight::from_parts(0, 1489))
+			.saturating_add(frame_support::weights::constants::RocksDbWeight::get().reads(2))
+			.saturating_add(frame_support::weights::constants::RocksDbWeight::get().writes(1))
+	}
+	fn deploy_contract() -> Weight {
+		Weight::from_parts(63_542_000, 3888)
+			.saturating_add(Weight::from_parts(0, 3888))
+			.saturating_add(frame_support::weights::constants::RocksDbWeight::get().reads(10))
+			.saturating_add(frame_support::weights::constants::RocksDbWeight::get().writes(7))
+	}
+	fn migrate_v1_step() -> Weight {
+		Weight::from_parts(15_718_000, 6020)
+			.saturating_add(Weight::from_parts(0, 6020))
+			.saturating_add(frame_support::weights::constants::RocksDbWeight::get().reads(2))
+			.saturating_add(frame_support::weights::constants::RocksDbWeight::get().writes(1))
+	}
+	fn migrate_v2_step() -> Weight {
+		Weight::from_parts(15_981_000, 6008)
+			.saturating_add(Weight::from_parts(0, 6008))
+			.saturating_add(frame_support::weights::constants::RocksDbWeight::get().reads(2))
 			.saturating_add(frame_support::weights::constants::RocksDbWeight::get().writes(1))
 	}
-	fn revoke_deployer() -> Weight {
-		Weight::from_parts(11_873_000, 3501)
+	fn migrate_v3_step() -> Weight {
+		Weight::from_parts(17_000_000, 7080)
+			.saturating_add(Weight::from_parts(0, 7080))
+			.saturating_add(frame_support::weights::constants::RocksDbWeight::get().reads(2))
+			.saturating_add(frame_support::weights::constants::RocksDbWeight::get().writes(1))
+	}
+	fn register_factory() -> Weight {
+		Weight::from_parts(11_392_000, 3526)
+			.saturating_add(Weight::from_parts(0, 3526))
+			.saturating_add(frame_support::weights::constants::RocksDbWeight::get().reads(1))
+			.saturating_add(frame_support::weights::constants::RocksDbWeight::get().writes(1))
+	}
+	fn set_factory_status() -> Weight {
+		Weight::from_parts(14_962_000, 3526)
+			.saturating_add(Weight::from_parts(0, 3526))
+			.saturating_add(frame_support::weights::constants::RocksDbWeight::get().reads(2))
+			.saturating_add(frame_support::weights::constants::RocksDbWeight::get().writes(1))
+	}
+	fn set_approval_policy() -> Weight {
+		Weight::from_parts(9_073_000, 1489)
+			.saturating_add(Weight::from_parts(0, 1489))
+			.saturating_add(frame_support::weights::constants::RocksDbWeight::get().reads(2))
+			.saturating_add(frame_support::weights::constants::RocksDbWeight::get().writes(1))
+	}
+	fn propose_authorization() -> Weight {
+		Weight::from_parts(18_847_000, 3501)
 			.saturating_add(Weight::from_parts(0, 3501))
+			.saturating_add(frame_support::weights::constants::RocksDbWeight::get().reads(4))
+			.saturating_add(frame_support::weights::constants::RocksDbWeight::get().writes(3))
+	}
+	fn approve_authorization() -> Weight {
+		Weight::from_parts(19_310_000, 4035)
+			.saturating_add(Weight::from_parts(0, 4035))
+			.saturating_add(frame_support::weights::constants::RocksDbWeight::get().reads(2))
+			.saturating_add(frame_support::weights::constants::RocksDbWeight::get().writes(1))
+	}
+	fn execute_authorization() -> Weight {
+		Weight::from_parts(25_714_000, 4035)
+			.saturating_add(Weight::from_parts(0, 4035))
+			.saturating_add(frame_support::weights::constants::RocksDbWeight::get().reads(7))
+			.saturating_add(frame_support::weights::constants::RocksDbWeight::get().writes(5))
+	}
+	fn remove_expired_proposal() -> Weight {
+		Weight::from_parts(14_906_000, 4035)
+			.saturating_add(Weight::from_parts(0, 4035))
+			.saturating_add(frame_support::weights::constants::RocksDbWeight::get().reads(4))
+			.saturating_add(frame_support::weights::constants::RocksDbWeight::get().writes(2))
+	}
+	fn set_factory_permissions() -> Weight {
+		Weight::from_parts(11_873_000, 3526)
+			.saturating_add(Weight::from_parts(0, 3526))
 			.saturating_add(frame_support::weights::constants::RocksDbWeight::get().reads(1))
 			.saturating_add(frame_support::weights::constants::RocksDbWeight::get().writes(1))
 	}
-	fn is_authorized_check() -> Weight {
-		Weight::from_parts(5_937_000, 3501)
+	fn register_child_metadata() -> Weight {
+		Weight::from_parts(20_391_000, 3617)
+			.saturating_add(Weight::from_parts(0, 3617))
+			.saturating_add(frame_support::weights::constants::RocksDbWeight::get().reads(4))
+			.saturating_add(frame_support::weights::constants::RocksDbWeight::get().writes(1))
+	}
+	fn set_factory_expiry() -> Weight {
+		Weight::from_parts(18_092_000, 6734)
+			.saturating_add(Weight::from_parts(0, 6734))
+			.saturating_add(frame_support::weights::constants::RocksDbWeight::get().reads(3))
+			.saturating_add(frame_support::weights::constants::RocksDbWeight::get().writes(3))
+	}
+	fn process_factory_expiries(e: u32, ) -> Weight {
+		Weight::from_parts(4_688_315, 3862)
+			.saturating_add(Weight::from_parts(0, 3862))
+			.saturating_add(Weight::from_parts(12_861_402, 0).saturating_mul(e.into()))
+			.saturating_add(frame_support::weights::constants::RocksDbWeight::get().reads(1))
+			.saturating_add(frame_support::weights::constants::RocksDbWeight::get().reads((2_u64).saturating_mul(e.into())))
+			.saturating_add(frame_support::weights::constants::RocksDbWeight::get().writes(1))
+			.saturating_add(frame_support::weights::constants::RocksDbWeight::get().writes((2_u64).saturating_mul(e.into())))
+			.saturating_add(Weight::from_parts(0, 2536).saturating_mul(e.into()))
+	}
+	fn announce_deployment() -> Weight {
+		Weight::from_parts(39_871_000, 3619)
+			.saturating_add(Weight::from_parts(0, 3619))
+			.saturating_add(frame_support::weights::constants::RocksDbWeight::get().reads(6))
+			.saturating_add(frame_support::weights::constants::RocksDbWeight::get().writes(5))
+	}
+	fn set_deployment_policy() -> Weight {
+		Weight::from_parts(7_104_000, 1489)
+			.saturating_add(Weight::from_parts(0, 1489))
+			.saturating_add(frame_support::weights::constants::RocksDbWeight::get().reads(2))
+			.saturating_add(frame_support::weights::constants::RocksDbWeight::get().writes(1))
+	}
+	fn rotate_deployer_key() -> Weight {
+		Weight::from_parts(25_918_000, 6120)
+			.saturating_add(Weight::from_parts(0, 6120))
+			.saturating_add(frame_support::weights::constants::RocksDbWeight::get().reads(10))
+			.saturating_add(frame_support::weights::constants::RocksDbWeight::get().writes(12))
+	}
+	fn authorize_contract_wallet() -> Weight {
+		Weight::from_parts(12_317_000, 3521)
+			.saturating_add(Weight::from_parts(0, 3521))
+			.saturating_add(frame_support::weights::constants::RocksDbWeight::get().reads(3))
+			.saturating_add(frame_support::weights::constants::RocksDbWeight::get().writes(4))
+	}
+	fn set_factory_metadata(l: u32, ) -> Weight {
+		Weight::from_parts(22_631_420, 3556)
+			.saturating_add(Weight::from_parts(0, 3556))
+			.saturating_add(Weight::from_parts(1_437, 0).saturating_mul(l.into()))
+			.saturating_add(frame_support::weights::constants::RocksDbWeight::get().reads(4))
+			.saturating_add(frame_support::weights::constants::RocksDbWeight::get().writes(3))
+	}
+	fn set_deployer_metadata(l: u32, ) -> Weight {
+		Weight::from_parts(26_104_885, 3632)
+			.saturating_add(Weight::from_parts(0, 3632))
+			.saturating_add(Weight::from_parts(1_441, 0).saturating_mul(l.into()))
+			.saturating_add(frame_support::weights::constants::RocksDbWeight::get().reads(6))
+			.saturating_add(frame_support::weights::constants::RocksDbWeight::get().writes(5))
+	}
+	fn unregister_factory(m: u32, ) -> Weight {
+		Weight::from_parts(13_410_000, 3616)
+			.saturating_add(Weight::from_parts(0, 3616))
+			.saturating_add(Weight::from_parts(11_082_000, 0).saturating_mul(m.into()))
+			.saturating_add(frame_support::weights::constants::RocksDbWeight::get().reads(4))
+			.saturating_add(frame_support::weights::constants::RocksDbWeight::get().reads((1_u64).saturating_mul(m.into())))
+			.saturating_add(frame_support::weights::constants::RocksDbWeight::get().writes(6))
+			.saturating_add(frame_support::weights::constants::RocksDbWeight::get().writes((2_u64).saturating_mul(m.into())))
+	}
+	fn set_deployer_admin() -> Weight {
+		Weight::from_parts(7_802_000, 0)
+			.saturating_add(frame_support::weights::constants::RocksDbWeight::get().writes(1))
+	}
+	fn dispatch_as_deployer_admin() -> Weight {
+		Weight::from_parts(9_654_000, 3501)
 			.saturating_add(Weight::from_parts(0, 3501))
 			.saturating_add(frame_support::weights::constants::RocksDbWeight::get().reads(1))
 	}
+	fn slash_deployment_intent() -> Weight {
+		Weight::from_parts(43_120_000, 6172)
+			.saturating_add(Weight::from_parts(0, 6172))
+			.saturating_add(frame_support::weights::constants::RocksDbWeight::get().reads(4))
+			.saturating_add(frame_support::weights::constants::RocksDbWeight::get().writes(4))
+	}
+	fn set_deployment_quota() -> Weight {
+		Weight::from_parts(6_304_000, 1489)
+			.saturating_add(Weight::from_parts(0, 1489))
+			.saturating_add(frame_support::weights::constants::RocksDbWeight::get().reads(2))
+			.saturating_add(frame_support::weights::constants::RocksDbWeight::get().writes(1))
+	}
+	fn use_deployment_quota() -> Weight {
+		Weight::from_parts(9_488_000, 3521)
+			.saturating_add(Weight::from_parts(0, 3521))
+			.saturating_add(frame_support::weights::constants::RocksDbWeight::get().reads(2))
+			.saturating_add(frame_support::weights::constants::RocksDbWeight::get().writes(1))
+	}
+	fn create_tenant() -> Weight {
+		Weight::from_parts(8_417_000, 1489)
+			.saturating_add(Weight::from_parts(0, 1489))
+			.saturating_add(frame_support::weights::constants::RocksDbWeight::get().reads(1))
+			.saturating_add(frame_support::weights::constants::RocksDbWeight::get().writes(2))
+	}
+	fn set_tenant_bounds() -> Weight {
+		Weight::from_parts(9_781_000, 3525)
+			.saturating_add(Weight::from_parts(0, 3525))
+			.saturating_add(frame_support::weights::constants::RocksDbWeight::get().reads(1))
+			.saturating_add(frame_support::weights::constants::RocksDbWeight::get().writes(1))
+	}
+	fn set_tenant_admin() -> Weight {
+		Weight::from_parts(10_203_000, 3525)
+			.saturating_add(Weight::from_parts(0, 3525))
+			.saturating_add(frame_support::weights::constants::RocksDbWeight::get().reads(1))
+			.saturating_add(frame_support::weights::constants::RocksDbWeight::get().writes(1))
+	}
+	fn register_tenant_factory() -> Weight {
+		Weight::from_parts(15_297_000, 3526)
+			.saturating_add(Weight::from_parts(0, 3526))
+			.saturating_add(frame_support::weights::constants::RocksDbWeight::get().reads(2))
+			.saturating_add(frame_support::weights::constants::RocksDbWeight::get().writes(3))
+	}
+	fn set_tenant_factory_status() -> Weight {
+		Weight::from_parts(13_749_000, 3526)
+			.saturating_add(Weight::from_parts(0, 3526))
+			.saturating_add(frame_support::weights::constants::RocksDbWeight::get().reads(4))
+			.saturating_add(frame_support::weights::constants::RocksDbWeight::get().writes(1))
+	}
+	fn authorize_tenant_deployer() -> Weight {
+		Weight::from_parts(17_046_000, 3525)
+			.saturating_add(Weight::from_parts(0, 3525))
+			.saturating_add(frame_support::weights::constants::RocksDbWeight::get().reads(4))
+			.saturating_add(frame_support::weights::constants::RocksDbWeight::get().writes(5))
+	}
+	fn schedule_governance_call() -> Weight {
+		Weight::from_parts(12_419_000, 1489)
+			.saturating_add(Weight::from_parts(0, 1489))
+			.saturating_add(frame_support::weights::constants::RocksDbWeight::get().reads(3))
+			.saturating_add(frame_support::weights::constants::RocksDbWeight::get().writes(4))
+	}
+	fn enact_governance_call() -> Weight {
+		Weight::from_parts(15_022_000, 3661)
+			.saturating_add(Weight::from_parts(0, 3661))
+			.saturating_add(frame_support::weights::constants::RocksDbWeight::get().reads(2))
+			.saturating_add(frame_support::weights::constants::RocksDbWeight::get().writes(4))
+	}
+	fn cancel_governance_call() -> Weight {
+		Weight::from_parts(12_051_000, 3661)
+			.saturating_add(Weight::from_parts(0, 3661))
+			.saturating_add(frame_support::weights::constants::RocksDbWeight::get().reads(2))
+			.saturating_add(frame_support::weights::constants::RocksDbWeight::get().writes(3))
+	}
+	fn set_enactment_delay() -> Weight {
+		Weight::from_parts(6_935_000, 1489)
+			.saturating_add(Weight::from_parts(0, 1489))
+			.saturating_add(frame_support::weights::constants::RocksDbWeight::get().reads(2))
+			.saturating_add(frame_support::weights::constants::RocksDbWeight::get().writes(1))
+	}
+	fn object_to_authorization() -> Weight {
+		Weight::from_parts(54_370_000, 3661)
+			.saturating_add(Weight::from_parts(0, 3661))
+			.saturating_add(frame_support::weights::constants::RocksDbWeight::get().reads(7))
+			.saturating_add(frame_support::weights::constants::RocksDbWeight::get().writes(7))
+	}
+	fn vote() -> Weight {
+		Weight::from_parts(46_105_000, 3690)
+			.saturating_add(Weight::from_parts(0, 3690))
+			.saturating_add(frame_support::weights::constants::RocksDbWeight::get().reads(4))
+			.saturating_add(frame_support::weights::constants::RocksDbWeight::get().writes(4))
+	}
+	fn conclude_referendum() -> Weight {
+		Weight::from_parts(13_947_000, 3690)
+			.saturating_add(Weight::from_parts(0, 3690))
+			.saturating_add(frame_support::weights::constants::RocksDbWeight::get().reads(1))
+			.saturating_add(frame_support::weights::constants::RocksDbWeight::get().writes(2))
+	}
+	fn unlock_vote() -> Weight {
+		Weight::from_parts(39_526_000, 3658)
+			.saturating_add(Weight::from_parts(0, 3658))
+			.saturating_add(frame_support::weights::constants::RocksDbWeight::get().reads(5))
+			.saturating_add(frame_support::weights::constants::RocksDbWeight::get().writes(3))
+	}
+	fn transfer_contract_admin() -> Weight {
+		Weight::from_parts(20_418_000, 3526)
+			.saturating_add(Weight::from_parts(0, 3526))
+			.saturating_add(frame_support::weights::constants::RocksDbWeight::get().reads(3))
+			.saturating_add(frame_support::weights::constants::RocksDbWeight::get().writes(2))
+	}
+	fn announce_retirement() -> Weight {
+		Weight::from_parts(22_309_000, 6736)
+			.saturating_add(Weight::from_parts(0, 6736))
+			.saturating_add(frame_support::weights::constants::RocksDbWeight::get().reads(4))
+			.saturating_add(frame_support::weights::constants::RocksDbWeight::get().writes(3))
+	}
+	fn finalize_retirement() -> Weight {
+		Weight::from_parts(15_877_000, 3526)
+			.saturating_add(Weight::from_parts(0, 3526))
+			.saturating_add(frame_support::weights::constants::RocksDbWeight::get().reads(2))
+			.saturating_add(frame_support::weights::constants::RocksDbWeight::get().writes(2))
+	}
+	fn process_retirement_warnings(w: u32, ) -> Weight {
+		Weight::from_parts(4_415_062, 3862)
+			.saturating_add(Weight::from_parts(0, 3862))
+			.saturating_add(Weight::from_parts(5_730_118, 0).saturating_mul(w.into()))
+			.saturating_add(frame_support::weights::constants::RocksDbWeight::get().reads(1))
+			.saturating_add(frame_support::weights::constants::RocksDbWeight::get().reads((1_u64).saturating_mul(w.into())))
+			.saturating_add(frame_support::weights::constants::RocksDbWeight::get().writes(1))
+			.saturating_add(Weight::from_parts(0, 2500).saturating_mul(w.into()))
+	}
+	fn set_deployer_risk_tier() -> Weight {
+		Weight::from_parts(8_000_000, 1489)
+			.saturating_add(Weight::from_parts(0, 1489))
+			.saturating_add(frame_support::weights::constants::RocksDbWeight::get().reads(2))
+			.saturating_add(frame_support::weights::constants::RocksDbWeight::get().writes(1))
+	}
 }
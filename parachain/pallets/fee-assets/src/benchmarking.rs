@@ -0,0 +1,67 @@
+//! Benchmarking setup for pallet-fee-assets
+
+use super::*;
+
+use frame_benchmarking::v2::*;
+use frame_support::{
+	traits::{EnsureOrigin, Get},
+	BoundedVec,
+};
+use sp_runtime::FixedU128;
+
+/// Fill the registry up to one slot short of `MaxFeeAssets`
+fn fill<T: Config>() {
+	let assets = (1..T::MaxFeeAssets::get()).map(T::AssetId::from).collect::<sp_std::vec::Vec<_>>();
+	FeeAssets::<T>::put(BoundedVec::truncate_from(assets));
+}
+
+#[benchmarks]
+mod benchmarks {
+	use super::*;
+
+	#[benchmark]
+	fn register_fee_asset() {
+		// Worst case: the registry is scanned up to its last slot
+		fill::<T>();
+		let origin = T::RegistrationOrigin::try_successful_origin().unwrap();
+		let asset = T::AssetId::from(T::MaxFeeAssets::get());
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, asset, FixedU128::from_u32(2));
+
+		// Verify the asset was registered last
+		assert_eq!(FeeAssets::<T>::get().last(), Some(&asset));
+	}
+
+	#[benchmark]
+	fn deregister_fee_asset() {
+		// Worst case: the last asset of a full registry is removed
+		fill::<T>();
+		let asset = T::AssetId::from(T::MaxFeeAssets::get());
+		FeeAssets::<T>::mutate(|assets| assets.try_push(asset).unwrap());
+		let origin = T::RegistrationOrigin::try_successful_origin().unwrap();
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, asset);
+
+		// Verify the asset was deregistered
+		assert!(!Pallet::<T>::is_fee_asset(&asset));
+	}
+
+	#[benchmark]
+	fn set_max_price() {
+		// Worst case: the registry is scanned up to its last slot
+		fill::<T>();
+		let asset = T::AssetId::from(T::MaxFeeAssets::get());
+		FeeAssets::<T>::mutate(|assets| assets.try_push(asset).unwrap());
+		let origin = T::RegistrationOrigin::try_successful_origin().unwrap();
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, asset, FixedU128::from_u32(2));
+
+		// Verify the price was set
+		assert_eq!(MaxPrices::<T>::get(asset), Some(FixedU128::from_u32(2)));
+	}
+
+	impl_benchmark_test_suite!(Pallet, crate::mock::new_test_ext(), crate::mock::Test);
+}
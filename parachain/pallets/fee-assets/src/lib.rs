@@ -0,0 +1,213 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+//! # Fee Assets Pallet
+//!
+//! Governance-registered assets paying the fees of accounts short of the native token.
+//!
+//! ## Overview
+//!
+//! `RegistrationOrigin` registers assets with [`Pallet::register_fee_asset`], up to
+//! `MaxFeeAssets`, and deregisters them with [`Pallet::deregister_fee_asset`]. Each asset
+//! is registered with the most of it a unit of the native token may cost, which
+//! [`Pallet::set_max_price`] updates. The pallet only keeps the registry: runtimes charging
+//! fees walk [`Pallet::fee_assets`] in registration order and swap the first asset the payer
+//! holds enough of to the native token, paying at most [`Pallet::max_fee_in`] for the fee.
+//! This lets new EVM accounts, which cannot choose a fee asset before paying their first
+//! fee, transact with the assets they received, while bounding what a manipulated pool can
+//! take from them.
+
+pub use pallet::*;
+pub mod weights;
+
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+#[frame_support::pallet]
+pub mod pallet {
+	use frame_support::pallet_prelude::*;
+	use frame_system::pallet_prelude::*;
+	use sp_runtime::{FixedPointNumber, FixedU128};
+
+	pub use crate::weights::WeightInfo;
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		/// The overarching event type
+		#[allow(deprecated)]
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		/// Identifier of the assets fees can be paid in
+		type AssetId: Member + Parameter + MaxEncodedLen + Copy + From<u32>;
+
+		/// Origin allowed to register and deregister fee assets
+		type RegistrationOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// Maximum number of fee assets, bounding the swaps tried for a fee
+		#[pallet::constant]
+		type MaxFeeAssets: Get<u32>;
+
+		/// Weight information for extrinsics in this pallet
+		type WeightInfo: WeightInfo;
+	}
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	/// Registered fee assets, in the order they are tried
+	#[pallet::storage]
+	pub type FeeAssets<T: Config> =
+		StorageValue<_, BoundedVec<T::AssetId, T::MaxFeeAssets>, ValueQuery>;
+
+	/// Most units of each registered fee asset paid for a unit of the native token
+	#[pallet::storage]
+	pub type MaxPrices<T: Config> = StorageMap<_, Twox64Concat, T::AssetId, FixedU128>;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// An asset can pay fees
+		FeeAssetRegistered {
+			/// The asset
+			asset: T::AssetId,
+			/// Most units of the asset paid for a unit of the native token
+			max_price: FixedU128,
+		},
+		/// An asset no longer pays fees
+		FeeAssetDeregistered {
+			/// The asset
+			asset: T::AssetId,
+		},
+		/// The most a fee asset pays for the native token changed
+		MaxPriceSet {
+			/// The asset
+			asset: T::AssetId,
+			/// Most units of the asset paid for a unit of the native token
+			max_price: FixedU128,
+		},
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The asset is already registered
+		AlreadyRegistered,
+		/// The asset is not registered
+		NotRegistered,
+		/// `MaxFeeAssets` assets are already registered
+		TooManyFeeAssets,
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Register an asset to pay fees, after the registered ones
+		///
+		/// # Parameters
+		/// - `origin`: Must be `RegistrationOrigin`
+		/// - `asset`: The asset
+		/// - `max_price`: Most units of the asset paid for a unit of the native token
+		///
+		/// # Errors
+		/// - `AlreadyRegistered`: The asset is already registered
+		/// - `TooManyFeeAssets`: `MaxFeeAssets` assets are already registered
+		///
+		/// # Events
+		/// - `FeeAssetRegistered`: Emitted when the asset is registered
+		#[pallet::call_index(0)]
+		#[pallet::weight(T::WeightInfo::register_fee_asset())]
+		pub fn register_fee_asset(
+			origin: OriginFor<T>,
+			asset: T::AssetId,
+			max_price: FixedU128,
+		) -> DispatchResult {
+			T::RegistrationOrigin::ensure_origin(origin)?;
+
+			FeeAssets::<T>::try_mutate(|assets| {
+				ensure!(!assets.contains(&asset), Error::<T>::AlreadyRegistered);
+				assets.try_push(asset).map_err(|_| Error::<T>::TooManyFeeAssets)
+			})?;
+			MaxPrices::<T>::insert(asset, max_price);
+			Self::deposit_event(Event::FeeAssetRegistered { asset, max_price });
+
+			Ok(())
+		}
+
+		/// Deregister a fee asset
+		///
+		/// # Parameters
+		/// - `origin`: Must be `RegistrationOrigin`
+		/// - `asset`: The asset
+		///
+		/// # Errors
+		/// - `NotRegistered`: The asset is not registered
+		///
+		/// # Events
+		/// - `FeeAssetDeregistered`: Emitted when the asset is deregistered
+		#[pallet::call_index(1)]
+		#[pallet::weight(T::WeightInfo::deregister_fee_asset())]
+		pub fn deregister_fee_asset(origin: OriginFor<T>, asset: T::AssetId) -> DispatchResult {
+			T::RegistrationOrigin::ensure_origin(origin)?;
+
+			FeeAssets::<T>::try_mutate(|assets| {
+				let index =
+					assets.iter().position(|a| *a == asset).ok_or(Error::<T>::NotRegistered)?;
+				assets.remove(index);
+				Ok::<_, Error<T>>(())
+			})?;
+			MaxPrices::<T>::remove(asset);
+			Self::deposit_event(Event::FeeAssetDeregistered { asset });
+
+			Ok(())
+		}
+
+		/// Set the most a fee asset pays for the native token
+		///
+		/// # Parameters
+		/// - `origin`: Must be `RegistrationOrigin`
+		/// - `asset`: The asset
+		/// - `max_price`: Most units of the asset paid for a unit of the native token
+		///
+		/// # Errors
+		/// - `NotRegistered`: The asset is not registered
+		///
+		/// # Events
+		/// - `MaxPriceSet`: Emitted when the price is set
+		#[pallet::call_index(2)]
+		#[pallet::weight(T::WeightInfo::set_max_price())]
+		pub fn set_max_price(
+			origin: OriginFor<T>,
+			asset: T::AssetId,
+			max_price: FixedU128,
+		) -> DispatchResult {
+			T::RegistrationOrigin::ensure_origin(origin)?;
+
+			ensure!(Self::is_fee_asset(&asset), Error::<T>::NotRegistered);
+			MaxPrices::<T>::insert(asset, max_price);
+			Self::deposit_event(Event::MaxPriceSet { asset, max_price });
+
+			Ok(())
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// Registered fee assets, in the order they are tried
+		pub fn fee_assets() -> BoundedVec<T::AssetId, T::MaxFeeAssets> {
+			FeeAssets::<T>::get()
+		}
+
+		/// Whether `asset` can pay fees
+		pub fn is_fee_asset(asset: &T::AssetId) -> bool {
+			FeeAssets::<T>::get().contains(asset)
+		}
+
+		/// Most units of `asset` paid for `fee` units of the native token, if `asset` can pay
+		/// fees
+		pub fn max_fee_in(asset: &T::AssetId, fee: u128) -> Option<u128> {
+			MaxPrices::<T>::get(asset).map(|max_price| max_price.saturating_mul_int(fee))
+		}
+	}
+}
@@ -0,0 +1,87 @@
+use crate::{mock::*, Error, Event, MaxPrices};
+use frame_support::{assert_noop, assert_ok};
+use sp_runtime::{DispatchError, FixedPointNumber, FixedU128};
+
+/// Two units of a fee asset per unit of the native token
+const PRICE: FixedU128 = FixedU128::from_u32(2);
+
+#[test]
+fn fee_assets_are_registered_in_order() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			FeeAssets::register_fee_asset(RuntimeOrigin::signed(1), 7, PRICE),
+			DispatchError::BadOrigin
+		);
+
+		assert_ok!(FeeAssets::register_fee_asset(RuntimeOrigin::root(), 7, PRICE));
+		System::assert_last_event(Event::FeeAssetRegistered { asset: 7, max_price: PRICE }.into());
+		assert_ok!(FeeAssets::register_fee_asset(RuntimeOrigin::root(), 3, PRICE));
+		assert_eq!(FeeAssets::fee_assets().into_inner(), vec![7, 3]);
+		assert!(FeeAssets::is_fee_asset(&3));
+		assert!(!FeeAssets::is_fee_asset(&4));
+	});
+}
+
+#[test]
+fn register_fee_asset_checks_the_registry() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(FeeAssets::register_fee_asset(RuntimeOrigin::root(), 7, PRICE));
+		assert_noop!(
+			FeeAssets::register_fee_asset(RuntimeOrigin::root(), 7, PRICE),
+			Error::<Test>::AlreadyRegistered
+		);
+
+		assert_ok!(FeeAssets::register_fee_asset(RuntimeOrigin::root(), 3, PRICE));
+		assert_noop!(
+			FeeAssets::register_fee_asset(RuntimeOrigin::root(), 4, PRICE),
+			Error::<Test>::TooManyFeeAssets
+		);
+	});
+}
+
+#[test]
+fn deregister_fee_asset_works() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(FeeAssets::register_fee_asset(RuntimeOrigin::root(), 7, PRICE));
+		assert_ok!(FeeAssets::register_fee_asset(RuntimeOrigin::root(), 3, PRICE));
+		assert_noop!(
+			FeeAssets::deregister_fee_asset(RuntimeOrigin::signed(1), 7, PRICE),
+			DispatchError::BadOrigin
+		);
+		assert_noop!(
+			FeeAssets::deregister_fee_asset(RuntimeOrigin::root(), 4, PRICE),
+			Error::<Test>::NotRegistered
+		);
+
+		assert_ok!(FeeAssets::deregister_fee_asset(RuntimeOrigin::root(), 7, PRICE));
+		System::assert_last_event(Event::FeeAssetDeregistered { asset: 7 }.into());
+		assert_eq!(FeeAssets::fee_assets().into_inner(), vec![3]);
+
+		// The freed slot can be used again
+		assert_ok!(FeeAssets::register_fee_asset(RuntimeOrigin::root(), 4, PRICE));
+		assert_eq!(FeeAssets::fee_assets().into_inner(), vec![3, 4]);
+	});
+}
+
+#[test]
+fn max_prices_bound_the_fees_paid_in_assets() {
+	new_test_ext().execute_with(|| {
+		assert_eq!(FeeAssets::max_fee_in(&7, 100), None);
+		assert_noop!(
+			FeeAssets::set_max_price(RuntimeOrigin::root(), 7, PRICE),
+			Error::<Test>::NotRegistered
+		);
+
+		assert_ok!(FeeAssets::register_fee_asset(RuntimeOrigin::root(), 7, PRICE));
+		assert_eq!(FeeAssets::max_fee_in(&7, 100), Some(200));
+
+		let max_price = FixedU128::from_rational(3, 2);
+		assert_noop!(
+			FeeAssets::set_max_price(RuntimeOrigin::signed(1), 7, max_price),
+			DispatchError::BadOrigin
+		);
+		assert_ok!(FeeAssets::set_max_price(RuntimeOrigin::root(), 7, max_price));
+		System::assert_last_event(Event::MaxPriceSet { asset: 7, max_price }.into());
+		assert_eq!(FeeAssets::max_fee_in(&7, 100), Some(150));
+	});
+}
@@ -0,0 +1,29 @@
+//! Benchmarking setup for pallet-fee-distribution
+
+use super::*;
+
+#[allow(unused)]
+use crate::Pallet as FeeDistribution;
+use frame_benchmarking::v2::*;
+use frame_support::traits::EnsureOrigin;
+use sp_runtime::Percent;
+
+#[benchmarks]
+mod benchmarks {
+	use super::*;
+
+	#[benchmark]
+	fn set_fee_split() {
+		let origin = T::SplitOrigin::try_successful_origin().unwrap();
+		let split =
+			FeeSplit { treasury: Percent::from_percent(50), author: Percent::from_percent(50) };
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, FeeKind::Fee, split);
+
+		// Verify the split was stored
+		assert_eq!(FeeSplits::<T>::get(FeeKind::Fee), Some(split));
+	}
+
+	impl_benchmark_test_suite!(FeeDistribution, crate::mock::new_test_ext(), crate::mock::Test);
+}
@@ -0,0 +1,220 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+//! # Fee Distribution Pallet
+//!
+//! Governance control over where transaction fees and tips go.
+//!
+//! ## Overview
+//!
+//! Every fee and tip is split between the treasury, the block author and a burn
+//! according to a [`FeeSplit`]. Fees and tips have a split each, set by `SplitOrigin`
+//! with [`Pallet::set_fee_split`] and falling back to `DefaultFeeSplit` and
+//! `DefaultTipSplit` while unset. Rounding leftovers are burned.
+//!
+//! [`DealWithFees`] is the `OnUnbalanced` handler of the fees withdrawn for Substrate
+//! transactions, e.g. by `pallet_transaction_payment::FungibleAdapter`, which hands over
+//! the fee followed by the tip. Runtimes charging Ethereum transactions pass the base fee
+//! to [`DealWithFees`] and the priority fee to [`Pallet::distribute`] as a tip.
+
+pub use pallet::*;
+pub mod weights;
+
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+#[frame_support::pallet]
+pub mod pallet {
+	use frame_support::{
+		pallet_prelude::*,
+		traits::{
+			fungible::{self, Balanced, Inspect},
+			Imbalance, OnUnbalanced,
+		},
+	};
+	use frame_system::pallet_prelude::*;
+	use sp_runtime::{PerThing, Percent};
+	use sp_std::marker::PhantomData;
+
+	pub use crate::weights::WeightInfo;
+
+	/// Balance of the currency fees are paid in
+	pub type BalanceOf<T> =
+		<<T as Config>::Currency as Inspect<<T as frame_system::Config>::AccountId>>::Balance;
+
+	/// Fees or tips withdrawn from an account
+	pub type CreditOf<T> =
+		fungible::Credit<<T as frame_system::Config>::AccountId, <T as Config>::Currency>;
+
+	/// Kind of the funds to distribute
+	#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub enum FeeKind {
+		/// Fee of a transaction, the base fee of Ethereum transactions
+		Fee,
+		/// Tip of a transaction, the priority fee of Ethereum transactions
+		Tip,
+	}
+
+	/// Shares of fees going to the treasury and the block author, the rest is burned
+	#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub struct FeeSplit {
+		/// Share paid into the treasury
+		pub treasury: Percent,
+		/// Share paid to the author of the block
+		pub author: Percent,
+	}
+
+	impl FeeSplit {
+		/// Check that the treasury and author shares add up to at most 100%
+		pub fn is_valid(&self) -> bool {
+			self.treasury.deconstruct().saturating_add(self.author.deconstruct()) <= 100
+		}
+
+		/// Share of fees burned
+		pub fn burn(&self) -> Percent {
+			Percent::from_percent(
+				100u8
+					.saturating_sub(self.treasury.deconstruct())
+					.saturating_sub(self.author.deconstruct()),
+			)
+		}
+	}
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		/// The overarching event type
+		#[allow(deprecated)]
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		/// Currency fees are paid in
+		type Currency: Balanced<Self::AccountId>;
+
+		/// Receiver of the treasury share
+		type Treasury: OnUnbalanced<CreditOf<Self>>;
+
+		/// Receiver of the block author share
+		type BlockAuthor: OnUnbalanced<CreditOf<Self>>;
+
+		/// Origin allowed to change the splits
+		type SplitOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// Split of fees while none is set
+		#[pallet::constant]
+		type DefaultFeeSplit: Get<FeeSplit>;
+
+		/// Split of tips while none is set
+		#[pallet::constant]
+		type DefaultTipSplit: Get<FeeSplit>;
+
+		/// Weight information for extrinsics in this pallet
+		type WeightInfo: WeightInfo;
+	}
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	/// Split of fees and tips set by `SplitOrigin`
+	///
+	/// `DefaultFeeSplit` and `DefaultTipSplit` apply while unset.
+	#[pallet::storage]
+	pub type FeeSplits<T: Config> = StorageMap<_, Twox64Concat, FeeKind, FeeSplit, OptionQuery>;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// The split of fees or tips was changed
+		FeeSplitSet {
+			/// Whether the split applies to fees or tips
+			kind: FeeKind,
+			/// The new split
+			split: FeeSplit,
+		},
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The treasury and author shares add up to more than 100%
+		InvalidFeeSplit,
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Set how fees or tips are split
+		///
+		/// Takes effect from the next transaction.
+		///
+		/// # Parameters
+		/// - `origin`: Must be `SplitOrigin`
+		/// - `kind`: Whether the split applies to fees or tips
+		/// - `split`: Shares of the treasury and the block author, the rest is burned
+		///
+		/// # Errors
+		/// - `InvalidFeeSplit`: The shares add up to more than 100%
+		///
+		/// # Events
+		/// - `FeeSplitSet`: Emitted when the split is changed
+		#[pallet::call_index(0)]
+		#[pallet::weight(T::WeightInfo::set_fee_split())]
+		pub fn set_fee_split(
+			origin: OriginFor<T>,
+			kind: FeeKind,
+			split: FeeSplit,
+		) -> DispatchResult {
+			T::SplitOrigin::ensure_origin(origin)?;
+
+			ensure!(split.is_valid(), Error::<T>::InvalidFeeSplit);
+
+			FeeSplits::<T>::insert(kind, split);
+			Self::deposit_event(Event::FeeSplitSet { kind, split });
+
+			Ok(())
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// Split in effect for fees or tips
+		pub fn fee_split(kind: FeeKind) -> FeeSplit {
+			FeeSplits::<T>::get(kind).unwrap_or_else(|| match kind {
+				FeeKind::Fee => T::DefaultFeeSplit::get(),
+				FeeKind::Tip => T::DefaultTipSplit::get(),
+			})
+		}
+
+		/// Pay the treasury and block author shares of `credit` and burn the rest
+		pub fn distribute(kind: FeeKind, credit: CreditOf<T>) {
+			let split = Self::fee_split(kind);
+			let amount = credit.peek();
+			let (to_treasury, rest) = credit.split(split.treasury.mul_floor(amount));
+			let (to_author, _burned) = rest.split(split.author.mul_floor(amount));
+
+			T::Treasury::on_unbalanced(to_treasury);
+			T::BlockAuthor::on_unbalanced(to_author);
+		}
+	}
+
+	/// Distributes transaction fees and tips according to [`FeeSplits`]
+	///
+	/// A single credit is taken as a fee. Several credits are taken as a fee followed by
+	/// a tip, the order `pallet_transaction_payment::FungibleAdapter` hands them over in.
+	pub struct DealWithFees<T>(PhantomData<T>);
+
+	impl<T: Config> OnUnbalanced<CreditOf<T>> for DealWithFees<T> {
+		fn on_unbalanceds(mut fees_then_tips: impl Iterator<Item = CreditOf<T>>) {
+			if let Some(fee) = fees_then_tips.next() {
+				Pallet::<T>::distribute(FeeKind::Fee, fee);
+			}
+			for tip in fees_then_tips {
+				Pallet::<T>::distribute(FeeKind::Tip, tip);
+			}
+		}
+
+		fn on_nonzero_unbalanced(fee: CreditOf<T>) {
+			Pallet::<T>::distribute(FeeKind::Fee, fee);
+		}
+	}
+}
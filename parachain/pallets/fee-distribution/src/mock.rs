@@ -0,0 +1,65 @@
+use crate as pallet_fee_distribution;
+use frame_support::{derive_impl, parameter_types, traits::tokens::imbalance::ResolveTo};
+use frame_system::EnsureRoot;
+use pallet_fee_distribution::FeeSplit;
+use sp_runtime::{traits::IdentityLookup, BuildStorage, Percent};
+
+type Block = frame_system::mocking::MockBlock<Test>;
+
+// Configure a mock runtime to test the pallet.
+frame_support::construct_runtime!(
+	pub enum Test
+	{
+		System: frame_system,
+		Balances: pallet_balances,
+		FeeDistribution: pallet_fee_distribution,
+	}
+);
+
+#[derive_impl(frame_system::config_preludes::TestDefaultConfig)]
+impl frame_system::Config for Test {
+	type Block = Block;
+	type AccountId = u64;
+	type Lookup = IdentityLookup<Self::AccountId>;
+	type AccountData = pallet_balances::AccountData<u64>;
+}
+
+#[derive_impl(pallet_balances::config_preludes::TestDefaultConfig)]
+impl pallet_balances::Config for Test {
+	type AccountStore = System;
+}
+
+parameter_types! {
+	pub const TreasuryAccount: u64 = 100;
+	pub const AuthorAccount: u64 = 200;
+	pub const DefaultFeeSplit: FeeSplit =
+		FeeSplit { treasury: Percent::from_percent(20), author: Percent::from_percent(0) };
+	pub const DefaultTipSplit: FeeSplit =
+		FeeSplit { treasury: Percent::from_percent(0), author: Percent::from_percent(100) };
+}
+
+impl pallet_fee_distribution::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type Currency = Balances;
+	type Treasury = ResolveTo<TreasuryAccount, Balances>;
+	type BlockAuthor = ResolveTo<AuthorAccount, Balances>;
+	type SplitOrigin = EnsureRoot<u64>;
+	type DefaultFeeSplit = DefaultFeeSplit;
+	type DefaultTipSplit = DefaultTipSplit;
+	type WeightInfo = ();
+}
+
+// Build genesis storage according to the mock runtime.
+pub fn new_test_ext() -> sp_io::TestExternalities {
+	let mut storage = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
+
+	pallet_balances::GenesisConfig::<Test> {
+		balances: vec![(TreasuryAccount::get(), 1), (AuthorAccount::get(), 1)],
+	}
+	.assimilate_storage(&mut storage)
+	.unwrap();
+
+	let mut ext: sp_io::TestExternalities = storage.into();
+	ext.execute_with(|| System::set_block_number(1));
+	ext
+}
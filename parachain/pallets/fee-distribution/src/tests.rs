@@ -0,0 +1,95 @@
+use crate::{mock::*, DealWithFees, Error, Event, FeeKind, FeeSplit, FeeSplits};
+use frame_support::{
+	assert_noop, assert_ok,
+	traits::{fungible::Balanced, OnUnbalanced},
+};
+use sp_runtime::{DispatchError, Percent};
+
+fn split(treasury: u8, author: u8) -> FeeSplit {
+	FeeSplit { treasury: Percent::from_percent(treasury), author: Percent::from_percent(author) }
+}
+
+fn treasury_balance() -> u64 {
+	Balances::free_balance(TreasuryAccount::get()) - 1
+}
+
+fn author_balance() -> u64 {
+	Balances::free_balance(AuthorAccount::get()) - 1
+}
+
+#[test]
+fn splits_default_to_config() {
+	new_test_ext().execute_with(|| {
+		assert_eq!(FeeDistribution::fee_split(FeeKind::Fee), split(20, 0));
+		assert_eq!(FeeDistribution::fee_split(FeeKind::Tip), split(0, 100));
+		assert_eq!(split(20, 30).burn(), Percent::from_percent(50));
+	});
+}
+
+#[test]
+fn set_fee_split_works() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(FeeDistribution::set_fee_split(
+			RuntimeOrigin::root(),
+			FeeKind::Tip,
+			split(10, 90)
+		));
+		System::assert_last_event(
+			Event::FeeSplitSet { kind: FeeKind::Tip, split: split(10, 90) }.into(),
+		);
+		assert_eq!(FeeSplits::<Test>::get(FeeKind::Tip), Some(split(10, 90)));
+		assert_eq!(FeeDistribution::fee_split(FeeKind::Tip), split(10, 90));
+		assert_eq!(FeeDistribution::fee_split(FeeKind::Fee), split(20, 0));
+	});
+}
+
+#[test]
+fn set_fee_split_validates_input() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			FeeDistribution::set_fee_split(RuntimeOrigin::signed(1), FeeKind::Fee, split(0, 0)),
+			DispatchError::BadOrigin
+		);
+		assert_noop!(
+			FeeDistribution::set_fee_split(RuntimeOrigin::root(), FeeKind::Fee, split(60, 50)),
+			Error::<Test>::InvalidFeeSplit
+		);
+	});
+}
+
+#[test]
+fn distribute_pays_shares_and_burns_the_rest() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(FeeDistribution::set_fee_split(
+			RuntimeOrigin::root(),
+			FeeKind::Fee,
+			split(30, 30)
+		));
+		let issuance = Balances::total_issuance();
+
+		// Rounding leftovers are burned
+		FeeDistribution::distribute(FeeKind::Fee, Balances::issue(99));
+		assert_eq!(treasury_balance(), 29);
+		assert_eq!(author_balance(), 29);
+		assert_eq!(Balances::total_issuance(), issuance + 58);
+	});
+}
+
+#[test]
+fn deal_with_fees_splits_fee_then_tip() {
+	new_test_ext().execute_with(|| {
+		let issuance = Balances::total_issuance();
+
+		DealWithFees::<Test>::on_unbalanceds(
+			[Balances::issue(100), Balances::issue(10)].into_iter(),
+		);
+		assert_eq!(treasury_balance(), 20);
+		assert_eq!(author_balance(), 10);
+		assert_eq!(Balances::total_issuance(), issuance + 30);
+
+		// A lone credit is a fee
+		DealWithFees::<Test>::on_unbalanced(Balances::issue(50));
+		assert_eq!(treasury_balance(), 30);
+		assert_eq!(author_balance(), 10);
+	});
+}
@@ -0,0 +1,148 @@
+//! Benchmarking setup for pallet-fee-sponsorship
+
+use super::*;
+
+#[allow(unused)]
+use crate::Pallet as FeeSponsorship;
+use frame_benchmarking::v2::*;
+use frame_support::traits::fungible::{Inspect, Mutate};
+use frame_system::RawOrigin;
+use sp_core::H160;
+use sp_runtime::traits::{Convert, Zero};
+
+/// A multiple of the existential deposit
+fn units<T: Config>(units: u32) -> BalanceOf<T> {
+	T::Currency::minimum_balance() * units.into()
+}
+
+/// Create a pool owned by `owner` holding funds
+fn funded_pool<T: Config>(owner: &T::AccountId) -> PoolId {
+	let pool = NextPoolId::<T>::get();
+	let info = SponsorshipPool {
+		owner: owner.clone(),
+		per_user_cap: units::<T>(10),
+		per_block_cap: units::<T>(100),
+		contracts: 0,
+	};
+	Pools::<T>::insert(pool, info);
+	NextPoolId::<T>::put(pool + 1);
+	T::Currency::set_balance(&FeeSponsorship::<T>::pool_account(pool), units::<T>(1_000));
+	pool
+}
+
+/// A freshly deployed contract and the account of its deployer
+fn deployment<T: Config>() -> (T::AccountId, H160) {
+	let contract = T::BenchmarkHelper::record_deployment();
+	let deployer = T::Provenance::deployer_of(&contract).unwrap();
+	(T::AddressMapping::convert(deployer), contract)
+}
+
+#[benchmarks]
+mod benchmarks {
+	use super::*;
+
+	#[benchmark]
+	fn create_pool() {
+		let caller: T::AccountId = whitelisted_caller();
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()), units::<T>(10), units::<T>(100));
+
+		// Verify the pool was created for the caller
+		assert_eq!(Pools::<T>::get(0).map(|pool| pool.owner), Some(caller));
+	}
+
+	#[benchmark]
+	fn fund_pool() {
+		let caller: T::AccountId = whitelisted_caller();
+		T::Currency::set_balance(&caller, units::<T>(1_000));
+		let pool = funded_pool::<T>(&account("owner", 0, 0));
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), pool, units::<T>(100));
+
+		// Verify the funds were added
+		let pool_account = FeeSponsorship::<T>::pool_account(pool);
+		assert_eq!(T::Currency::balance(&pool_account), units::<T>(1_100));
+	}
+
+	#[benchmark]
+	fn withdraw_from_pool() {
+		let caller: T::AccountId = whitelisted_caller();
+		let pool = funded_pool::<T>(&caller);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), pool, units::<T>(100));
+
+		// Verify the funds were withdrawn
+		let pool_account = FeeSponsorship::<T>::pool_account(pool);
+		assert_eq!(T::Currency::balance(&pool_account), units::<T>(900));
+	}
+
+	#[benchmark]
+	fn set_pool_caps() {
+		let caller: T::AccountId = whitelisted_caller();
+		let pool = funded_pool::<T>(&caller);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), pool, units::<T>(1), Zero::zero());
+
+		// Verify the caps were changed
+		assert_eq!(Pools::<T>::get(pool).map(|pool| pool.per_user_cap), Some(units::<T>(1)));
+	}
+
+	#[benchmark]
+	fn sponsor_contract() {
+		let (caller, contract) = deployment::<T>();
+		let pool = funded_pool::<T>(&caller);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), pool, contract);
+
+		// Verify the contract is sponsored
+		assert_eq!(SponsoredContracts::<T>::get(contract), Some(pool));
+	}
+
+	#[benchmark]
+	fn unsponsor_contract() {
+		let (caller, contract) = deployment::<T>();
+		let pool = funded_pool::<T>(&caller);
+		Pools::<T>::mutate(pool, |info| info.as_mut().unwrap().contracts = 1);
+		SponsoredContracts::<T>::insert(contract, pool);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), contract);
+
+		// Verify the contract is no longer sponsored
+		assert!(!SponsoredContracts::<T>::contains_key(contract));
+	}
+
+	#[benchmark]
+	fn allow_user() {
+		let caller: T::AccountId = whitelisted_caller();
+		let pool = funded_pool::<T>(&caller);
+		let user = H160::repeat_byte(0x05);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), pool, user);
+
+		// Verify the sender is sponsored
+		assert!(SponsoredUsers::<T>::contains_key(pool, user));
+	}
+
+	#[benchmark]
+	fn disallow_user() {
+		let caller: T::AccountId = whitelisted_caller();
+		let pool = funded_pool::<T>(&caller);
+		let user = H160::repeat_byte(0x05);
+		SponsoredUsers::<T>::insert(pool, user, ());
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), pool, user);
+
+		// Verify the sender is no longer sponsored
+		assert!(!SponsoredUsers::<T>::contains_key(pool, user));
+	}
+
+	impl_benchmark_test_suite!(FeeSponsorship, crate::mock::new_test_ext(), crate::mock::Test);
+}
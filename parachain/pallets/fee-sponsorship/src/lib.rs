@@ -0,0 +1,685 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+//! # Fee Sponsorship Pallet
+//!
+//! Pools funded by dApps paying the fees of Ethereum transactions calling their contracts.
+//!
+//! ## Overview
+//!
+//! A dApp creates a pool and assigns it contracts it deployed, directly or through a
+//! factory contract it deployed, as reported by the configured [`ContractProvenance`]
+//! source, and the senders it pays the fees of. Anyone can fund a pool; only its owner can
+//! withdraw from it.
+//!
+//! The runtime calls [`Pallet::advance`] before applying an Ethereum transaction calling a
+//! sponsored contract. The fee of the transaction is moved from the pool to the sender, so
+//! that the sender passes the balance checks of `pallet-ethereum`. Once the fee is
+//! withdrawn and once it is corrected, the runtime hands the unused part of the advance
+//! back to the pool with [`Pallet::reclaim`] and [`Pallet::settle`]. Validation only
+//! simulates the advance, rolling it back.
+//!
+//! The fees a pool pays are capped per sender over the pool's lifetime and per block. The
+//! senders are named by the owner of the pool, so that the cap per sender cannot be
+//! sidestepped with fresh addresses. A sender holding less than the existential deposit is
+//! advanced the difference too and keeps it, which counts towards the caps.
+//!
+//! Unlike sponsored deployments, this covers the interactions of end users with
+//! deployed contracts.
+
+pub use deployment_control_primitives::ContractProvenance;
+pub use pallet::*;
+pub mod weights;
+
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+/// Records the deployment of a contract for benchmarking
+#[cfg(feature = "runtime-benchmarks")]
+pub trait BenchmarkHelper {
+	/// Record the deployment of a contract and return its address
+	fn record_deployment() -> sp_core::H160;
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+	use crate::ContractProvenance;
+	use frame_support::{
+		pallet_prelude::*,
+		traits::{
+			fungible::{Inspect, Mutate},
+			tokens::{Fortitude, Preservation},
+		},
+		PalletId,
+	};
+	use frame_system::pallet_prelude::*;
+	use sp_core::H160;
+	use sp_runtime::traits::{AccountIdConversion, Convert, Saturating, Zero};
+
+	pub use crate::weights::WeightInfo;
+
+	/// Balance of the currency fees are paid in
+	pub type BalanceOf<T> =
+		<<T as Config>::Currency as Inspect<<T as frame_system::Config>::AccountId>>::Balance;
+
+	/// Identifier of a sponsorship pool
+	pub type PoolId = u32;
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		/// The overarching event type
+		#[allow(deprecated)]
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		/// Currency fees are paid in
+		type Currency: Mutate<Self::AccountId>;
+
+		/// Source of the recorded deployer of contracts
+		type Provenance: ContractProvenance;
+
+		/// Account controlled by an EVM address
+		type AddressMapping: Convert<H160, Self::AccountId>;
+
+		/// Identifier the accounts holding the funds of pools are derived from
+		#[pallet::constant]
+		type PalletId: Get<PalletId>;
+
+		/// Maximum number of contracts a pool sponsors
+		#[pallet::constant]
+		type MaxSponsoredContracts: Get<u32>;
+
+		/// Weight information for extrinsics in this pallet
+		type WeightInfo: WeightInfo;
+
+		/// Records contract deployments for benchmarking
+		#[cfg(feature = "runtime-benchmarks")]
+		type BenchmarkHelper: crate::BenchmarkHelper;
+	}
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	/// A sponsorship pool
+	#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub struct SponsorshipPool<AccountId, Balance> {
+		/// Account owning the pool
+		pub owner: AccountId,
+		/// Maximum fees paid for a single sender over the lifetime of the pool
+		pub per_user_cap: Balance,
+		/// Maximum fees paid in a single block
+		pub per_block_cap: Balance,
+		/// Number of contracts the pool sponsors
+		pub contracts: u32,
+	}
+
+	/// Fee advanced to the sender of the Ethereum transaction being applied
+	#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub struct Advance<Balance> {
+		/// Pool the fee was advanced from
+		pub pool: PoolId,
+		/// Sender of the transaction
+		pub user: H160,
+		/// Amount counted towards the caps of the pool when the fee was advanced
+		pub charged: Balance,
+		/// Part of the advance not handed back to the pool yet
+		pub outstanding: Balance,
+	}
+
+	/// Identifier of the next pool created
+	#[pallet::storage]
+	pub type NextPoolId<T: Config> = StorageValue<_, PoolId, ValueQuery>;
+
+	/// Sponsorship pools
+	#[pallet::storage]
+	pub type Pools<T: Config> = StorageMap<
+		_,
+		Twox64Concat,
+		PoolId,
+		SponsorshipPool<T::AccountId, BalanceOf<T>>,
+		OptionQuery,
+	>;
+
+	/// Pool sponsoring each sponsored contract
+	#[pallet::storage]
+	pub type SponsoredContracts<T: Config> = StorageMap<_, Identity, H160, PoolId, OptionQuery>;
+
+	/// Senders each pool pays the fees of
+	#[pallet::storage]
+	pub type SponsoredUsers<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, PoolId, Identity, H160, (), OptionQuery>;
+
+	/// Fees paid by each pool for each sender
+	#[pallet::storage]
+	pub type UserSpending<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, PoolId, Identity, H160, BalanceOf<T>, ValueQuery>;
+
+	/// Fees paid by each pool in the last block it paid any, with that block
+	#[pallet::storage]
+	pub type BlockSpending<T: Config> =
+		StorageMap<_, Twox64Concat, PoolId, (BlockNumberFor<T>, BalanceOf<T>), OptionQuery>;
+
+	/// Fee advanced to the sender of the Ethereum transaction being applied
+	///
+	/// Settled by the runtime once the fee is corrected, or at the end of the block if the
+	/// transaction failed before.
+	#[pallet::storage]
+	pub type PendingAdvance<T: Config> = StorageValue<_, Advance<BalanceOf<T>>, OptionQuery>;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// A pool was created
+		PoolCreated {
+			/// The new pool
+			pool: PoolId,
+			/// The owner of the pool
+			owner: T::AccountId,
+		},
+		/// A pool was funded
+		PoolFunded {
+			/// The funded pool
+			pool: PoolId,
+			/// The amount added to the pool
+			amount: BalanceOf<T>,
+		},
+		/// Funds were withdrawn from a pool by its owner
+		PoolWithdrawn {
+			/// The pool withdrawn from
+			pool: PoolId,
+			/// The amount withdrawn
+			amount: BalanceOf<T>,
+		},
+		/// The caps of a pool were changed
+		PoolCapsSet {
+			/// The pool
+			pool: PoolId,
+			/// The new cap on fees paid for a single sender
+			per_user_cap: BalanceOf<T>,
+			/// The new cap on fees paid in a single block
+			per_block_cap: BalanceOf<T>,
+		},
+		/// A pool started sponsoring a contract
+		ContractSponsored {
+			/// The sponsoring pool
+			pool: PoolId,
+			/// The sponsored contract
+			contract: H160,
+		},
+		/// A pool stopped sponsoring a contract
+		ContractUnsponsored {
+			/// The pool
+			pool: PoolId,
+			/// The contract no longer sponsored
+			contract: H160,
+		},
+		/// A pool started paying the fees of a sender
+		UserAllowed {
+			/// The pool
+			pool: PoolId,
+			/// The sender
+			user: H160,
+		},
+		/// A pool stopped paying the fees of a sender
+		UserDisallowed {
+			/// The pool
+			pool: PoolId,
+			/// The sender no longer sponsored
+			user: H160,
+		},
+		/// A pool paid the fee of an Ethereum transaction
+		FeeSponsored {
+			/// The paying pool
+			pool: PoolId,
+			/// The sender of the transaction
+			user: H160,
+			/// The fee paid, with any existential deposit advanced to the sender
+			amount: BalanceOf<T>,
+		},
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The pool does not exist
+		UnknownPool,
+		/// The caller does not own the pool
+		NotPoolOwner,
+		/// The caller did not deploy the contract, directly or through a factory it deployed
+		NotContractOwner,
+		/// The contract is already sponsored
+		AlreadySponsored,
+		/// The contract is not sponsored
+		NotSponsored,
+		/// The pool already sponsors `MaxSponsoredContracts` contracts
+		TooManyContracts,
+		/// The pool already pays the fees of the sender
+		AlreadyAllowed,
+		/// The pool does not pay the fees of the sender
+		NotAllowed,
+	}
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_initialize(_n: BlockNumberFor<T>) -> Weight {
+			// Account for settling the advance of a failed transaction in `on_finalize`
+			T::DbWeight::get().reads_writes(4, 5)
+		}
+
+		fn on_finalize(_n: BlockNumberFor<T>) {
+			Self::settle_stale();
+		}
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Create a sponsorship pool owned by the caller
+		///
+		/// # Parameters
+		/// - `origin`: Must be signed by the owner of the new pool
+		/// - `per_user_cap`: Maximum fees paid for a single sender
+		/// - `per_block_cap`: Maximum fees paid in a single block
+		///
+		/// # Events
+		/// - `PoolCreated`: Emitted when the pool is created
+		#[pallet::call_index(0)]
+		#[pallet::weight(T::WeightInfo::create_pool())]
+		pub fn create_pool(
+			origin: OriginFor<T>,
+			per_user_cap: BalanceOf<T>,
+			per_block_cap: BalanceOf<T>,
+		) -> DispatchResult {
+			let owner = ensure_signed(origin)?;
+
+			let pool = NextPoolId::<T>::mutate(|next| {
+				let pool = *next;
+				*next = next.saturating_add(1);
+				pool
+			});
+			let info =
+				SponsorshipPool { owner: owner.clone(), per_user_cap, per_block_cap, contracts: 0 };
+			Pools::<T>::insert(pool, info);
+			Self::deposit_event(Event::PoolCreated { pool, owner });
+
+			Ok(())
+		}
+
+		/// Add funds to a pool
+		///
+		/// # Parameters
+		/// - `origin`: Must be signed by the account paying the funds
+		/// - `pool`: The pool to fund
+		/// - `amount`: The amount to add
+		///
+		/// # Errors
+		/// - `UnknownPool`: The pool does not exist
+		///
+		/// # Events
+		/// - `PoolFunded`: Emitted when the funds are added
+		#[pallet::call_index(1)]
+		#[pallet::weight(T::WeightInfo::fund_pool())]
+		pub fn fund_pool(
+			origin: OriginFor<T>,
+			pool: PoolId,
+			amount: BalanceOf<T>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			ensure!(Pools::<T>::contains_key(pool), Error::<T>::UnknownPool);
+			T::Currency::transfer(&who, &Self::pool_account(pool), amount, Preservation::Preserve)?;
+			Self::deposit_event(Event::PoolFunded { pool, amount });
+
+			Ok(())
+		}
+
+		/// Withdraw funds from a pool
+		///
+		/// # Parameters
+		/// - `origin`: Must be signed by the owner of the pool
+		/// - `pool`: The pool to withdraw from
+		/// - `amount`: The amount to withdraw
+		///
+		/// # Errors
+		/// - `UnknownPool`: The pool does not exist
+		/// - `NotPoolOwner`: The caller does not own the pool
+		///
+		/// # Events
+		/// - `PoolWithdrawn`: Emitted when the funds are withdrawn
+		#[pallet::call_index(2)]
+		#[pallet::weight(T::WeightInfo::withdraw_from_pool())]
+		pub fn withdraw_from_pool(
+			origin: OriginFor<T>,
+			pool: PoolId,
+			amount: BalanceOf<T>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			Self::ensure_pool_owner(pool, &who)?;
+			T::Currency::transfer(
+				&Self::pool_account(pool),
+				&who,
+				amount,
+				Preservation::Expendable,
+			)?;
+			Self::deposit_event(Event::PoolWithdrawn { pool, amount });
+
+			Ok(())
+		}
+
+		/// Change the caps of a pool
+		///
+		/// Fees already paid keep counting towards the new caps.
+		///
+		/// # Parameters
+		/// - `origin`: Must be signed by the owner of the pool
+		/// - `pool`: The pool
+		/// - `per_user_cap`: Maximum fees paid for a single sender
+		/// - `per_block_cap`: Maximum fees paid in a single block
+		///
+		/// # Errors
+		/// - `UnknownPool`: The pool does not exist
+		/// - `NotPoolOwner`: The caller does not own the pool
+		///
+		/// # Events
+		/// - `PoolCapsSet`: Emitted when the caps are changed
+		#[pallet::call_index(3)]
+		#[pallet::weight(T::WeightInfo::set_pool_caps())]
+		pub fn set_pool_caps(
+			origin: OriginFor<T>,
+			pool: PoolId,
+			per_user_cap: BalanceOf<T>,
+			per_block_cap: BalanceOf<T>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			Self::ensure_pool_owner(pool, &who)?;
+			Pools::<T>::mutate(pool, |info| {
+				if let Some(info) = info {
+					info.per_user_cap = per_user_cap;
+					info.per_block_cap = per_block_cap;
+				}
+			});
+			Self::deposit_event(Event::PoolCapsSet { pool, per_user_cap, per_block_cap });
+
+			Ok(())
+		}
+
+		/// Pay the fees of Ethereum transactions calling a contract from a pool
+		///
+		/// # Parameters
+		/// - `origin`: Must be signed by the owner of the pool, who deployed the contract
+		///   directly or through a factory contract it deployed
+		/// - `pool`: The sponsoring pool
+		/// - `contract`: The contract to sponsor
+		///
+		/// # Errors
+		/// - `UnknownPool`: The pool does not exist
+		/// - `NotPoolOwner`: The caller does not own the pool
+		/// - `NotContractOwner`: The caller did not deploy the contract
+		/// - `AlreadySponsored`: The contract is already sponsored
+		/// - `TooManyContracts`: The pool sponsors `MaxSponsoredContracts` contracts
+		///
+		/// # Events
+		/// - `ContractSponsored`: Emitted when the contract is sponsored
+		#[pallet::call_index(4)]
+		#[pallet::weight(T::WeightInfo::sponsor_contract())]
+		pub fn sponsor_contract(
+			origin: OriginFor<T>,
+			pool: PoolId,
+			contract: H160,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let mut info = Self::ensure_pool_owner(pool, &who)?;
+			ensure!(Self::is_contract_owner(&who, &contract), Error::<T>::NotContractOwner);
+			ensure!(!SponsoredContracts::<T>::contains_key(contract), Error::<T>::AlreadySponsored);
+			ensure!(info.contracts < T::MaxSponsoredContracts::get(), Error::<T>::TooManyContracts);
+
+			info.contracts += 1;
+			Pools::<T>::insert(pool, info);
+			SponsoredContracts::<T>::insert(contract, pool);
+			Self::deposit_event(Event::ContractSponsored { pool, contract });
+
+			Ok(())
+		}
+
+		/// Stop paying the fees of Ethereum transactions calling a contract
+		///
+		/// # Parameters
+		/// - `origin`: Must be signed by the owner of the sponsoring pool
+		/// - `contract`: The sponsored contract
+		///
+		/// # Errors
+		/// - `NotSponsored`: The contract is not sponsored
+		/// - `NotPoolOwner`: The caller does not own the sponsoring pool
+		///
+		/// # Events
+		/// - `ContractUnsponsored`: Emitted when the contract is no longer sponsored
+		#[pallet::call_index(5)]
+		#[pallet::weight(T::WeightInfo::unsponsor_contract())]
+		pub fn unsponsor_contract(origin: OriginFor<T>, contract: H160) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let pool = SponsoredContracts::<T>::get(contract).ok_or(Error::<T>::NotSponsored)?;
+			let mut info = Self::ensure_pool_owner(pool, &who)?;
+
+			info.contracts = info.contracts.saturating_sub(1);
+			Pools::<T>::insert(pool, info);
+			SponsoredContracts::<T>::remove(contract);
+			Self::deposit_event(Event::ContractUnsponsored { pool, contract });
+
+			Ok(())
+		}
+
+		/// Pay the fees of the Ethereum transactions of a sender calling the contracts of a pool
+		///
+		/// Fees already paid for the sender keep counting towards the cap per sender.
+		///
+		/// # Parameters
+		/// - `origin`: Must be signed by the owner of the pool
+		/// - `pool`: The sponsoring pool
+		/// - `user`: The sender to sponsor
+		///
+		/// # Errors
+		/// - `UnknownPool`: The pool does not exist
+		/// - `NotPoolOwner`: The caller does not own the pool
+		/// - `AlreadyAllowed`: The pool already pays the fees of the sender
+		///
+		/// # Events
+		/// - `UserAllowed`: Emitted when the sender is sponsored
+		#[pallet::call_index(6)]
+		#[pallet::weight(T::WeightInfo::allow_user())]
+		pub fn allow_user(origin: OriginFor<T>, pool: PoolId, user: H160) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			Self::ensure_pool_owner(pool, &who)?;
+			ensure!(!SponsoredUsers::<T>::contains_key(pool, user), Error::<T>::AlreadyAllowed);
+
+			SponsoredUsers::<T>::insert(pool, user, ());
+			Self::deposit_event(Event::UserAllowed { pool, user });
+
+			Ok(())
+		}
+
+		/// Stop paying the fees of a sender
+		///
+		/// # Parameters
+		/// - `origin`: Must be signed by the owner of the pool
+		/// - `pool`: The sponsoring pool
+		/// - `user`: The sponsored sender
+		///
+		/// # Errors
+		/// - `UnknownPool`: The pool does not exist
+		/// - `NotPoolOwner`: The caller does not own the pool
+		/// - `NotAllowed`: The pool does not pay the fees of the sender
+		///
+		/// # Events
+		/// - `UserDisallowed`: Emitted when the sender is no longer sponsored
+		#[pallet::call_index(7)]
+		#[pallet::weight(T::WeightInfo::disallow_user())]
+		pub fn disallow_user(origin: OriginFor<T>, pool: PoolId, user: H160) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			Self::ensure_pool_owner(pool, &who)?;
+			ensure!(SponsoredUsers::<T>::contains_key(pool, user), Error::<T>::NotAllowed);
+
+			SponsoredUsers::<T>::remove(pool, user);
+			Self::deposit_event(Event::UserDisallowed { pool, user });
+
+			Ok(())
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// Account holding the funds of a pool
+		pub fn pool_account(pool: PoolId) -> T::AccountId {
+			T::PalletId::get().into_sub_account_truncating(pool)
+		}
+
+		/// Whether `who` deployed `contract`, directly or through a factory contract it
+		/// deployed
+		pub fn is_contract_owner(who: &T::AccountId, contract: &H160) -> bool {
+			let Some(deployer) = T::Provenance::deployer_of(contract) else {
+				return false;
+			};
+			T::AddressMapping::convert(deployer) == *who ||
+				T::Provenance::deployer_of(&deployer)
+					.is_some_and(|owner| T::AddressMapping::convert(owner) == *who)
+		}
+
+		/// Advance `fee` to `user` from the pool sponsoring `contract`, if any
+		///
+		/// Any advance left by a transaction that failed before its fee was settled is
+		/// settled first.
+		///
+		/// # Returns
+		/// Whether the fee was advanced. It is not if the contract is not sponsored, the pool
+		/// does not pay the fees of `user`, cannot pay it or paying it would exceed the caps
+		/// of the pool.
+		pub fn advance(user: H160, contract: &H160, fee: BalanceOf<T>) -> bool {
+			Self::settle_stale();
+
+			let Some(pool) = SponsoredContracts::<T>::get(contract) else {
+				return false;
+			};
+			if !SponsoredUsers::<T>::contains_key(pool, user) {
+				return false;
+			}
+			let Some(info) = Pools::<T>::get(pool) else {
+				return false;
+			};
+
+			let account = T::AddressMapping::convert(user);
+			let missing_deposit =
+				T::Currency::minimum_balance().saturating_sub(T::Currency::balance(&account));
+			let amount = fee.saturating_add(missing_deposit);
+
+			let now = frame_system::Pallet::<T>::block_number();
+			let user_spending = UserSpending::<T>::get(pool, user).saturating_add(amount);
+			let block_spending = match BlockSpending::<T>::get(pool) {
+				Some((block, spent)) if block == now => spent,
+				_ => Zero::zero(),
+			}
+			.saturating_add(amount);
+			if user_spending > info.per_user_cap || block_spending > info.per_block_cap {
+				return false;
+			}
+
+			let paid = T::Currency::transfer(
+				&Self::pool_account(pool),
+				&account,
+				amount,
+				Preservation::Preserve,
+			);
+			if paid.is_err() {
+				return false;
+			}
+
+			UserSpending::<T>::insert(pool, user, user_spending);
+			BlockSpending::<T>::insert(pool, (now, block_spending));
+			PendingAdvance::<T>::put(Advance { pool, user, charged: amount, outstanding: amount });
+			true
+		}
+
+		/// Hand the part of the fee advanced to `user` exceeding `fee` back to the pool
+		///
+		/// Called by the runtime once the fee of the transaction is withdrawn. Does nothing if
+		/// no fee was advanced to `user`.
+		pub fn reclaim(user: &H160, fee: BalanceOf<T>) {
+			PendingAdvance::<T>::mutate(|pending| {
+				let Some(advance) = pending.as_mut().filter(|advance| advance.user == *user) else {
+					return;
+				};
+				let account = T::AddressMapping::convert(*user);
+				let unused =
+					advance.outstanding.saturating_sub(fee).min(T::Currency::reducible_balance(
+						&account,
+						Preservation::Preserve,
+						Fortitude::Polite,
+					));
+				if unused.is_zero() {
+					return;
+				}
+				let pool_account = Self::pool_account(advance.pool);
+				if T::Currency::transfer(&account, &pool_account, unused, Preservation::Preserve)
+					.is_ok()
+				{
+					advance.outstanding.saturating_reduce(unused);
+				}
+			});
+		}
+
+		/// Settle the fee advanced to `user` once the transaction paid `fee`
+		///
+		/// Hands the unused part of the advance back to the pool and releases it from the
+		/// caps of the pool. Does nothing if no fee was advanced to `user`.
+		pub fn settle(user: &H160, fee: BalanceOf<T>) {
+			Self::reclaim(user, fee);
+
+			let Some(advance) = PendingAdvance::<T>::take() else {
+				return;
+			};
+			if advance.user != *user {
+				PendingAdvance::<T>::put(advance);
+				return;
+			}
+
+			let unused = advance.charged.saturating_sub(advance.outstanding);
+			if !unused.is_zero() {
+				UserSpending::<T>::mutate(advance.pool, advance.user, |spent| {
+					spent.saturating_reduce(unused)
+				});
+				BlockSpending::<T>::mutate(advance.pool, |spending| {
+					if let Some((_, spent)) = spending {
+						spent.saturating_reduce(unused);
+					}
+				});
+			}
+			Self::deposit_event(Event::FeeSponsored {
+				pool: advance.pool,
+				user: advance.user,
+				amount: advance.outstanding,
+			});
+		}
+
+		/// Settle the advance of a transaction that failed before paying its fee
+		fn settle_stale() {
+			if let Some(advance) = PendingAdvance::<T>::get() {
+				Self::settle(&advance.user, Zero::zero());
+			}
+		}
+
+		/// Ensure `who` owns `pool`
+		fn ensure_pool_owner(
+			pool: PoolId,
+			who: &T::AccountId,
+		) -> Result<SponsorshipPool<T::AccountId, BalanceOf<T>>, DispatchError> {
+			let info = Pools::<T>::get(pool).ok_or(Error::<T>::UnknownPool)?;
+			ensure!(info.owner == *who, Error::<T>::NotPoolOwner);
+			Ok(info)
+		}
+	}
+}
@@ -0,0 +1,80 @@
+use crate as pallet_fee_sponsorship;
+pub use deployment_control_primitives::test_utils::{deploy, MockProvenance};
+use frame_support::{derive_impl, parameter_types, traits::ConstU32, PalletId};
+use sp_core::H160;
+use sp_runtime::{
+	traits::{Convert, IdentityLookup},
+	BuildStorage,
+};
+
+type Block = frame_system::mocking::MockBlock<Test>;
+
+// Configure a mock runtime to test the pallet.
+frame_support::construct_runtime!(
+	pub enum Test
+	{
+		System: frame_system,
+		Balances: pallet_balances,
+		FeeSponsorship: pallet_fee_sponsorship,
+	}
+);
+
+#[derive_impl(frame_system::config_preludes::TestDefaultConfig)]
+impl frame_system::Config for Test {
+	type Block = Block;
+	type AccountId = u64;
+	type Lookup = IdentityLookup<Self::AccountId>;
+	type AccountData = pallet_balances::AccountData<u64>;
+}
+
+#[derive_impl(pallet_balances::config_preludes::TestDefaultConfig)]
+impl pallet_balances::Config for Test {
+	type AccountStore = System;
+}
+
+/// Maps EVM address `0x00..0N` to account `N`
+pub struct LowBytes;
+
+impl Convert<H160, u64> for LowBytes {
+	fn convert(address: H160) -> u64 {
+		address.to_low_u64_be()
+	}
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+impl pallet_fee_sponsorship::BenchmarkHelper for MockProvenance {
+	fn record_deployment() -> H160 {
+		let contract = H160::repeat_byte(0xcc);
+		deploy(H160::from_low_u64_be(1), contract);
+		contract
+	}
+}
+
+parameter_types! {
+	pub const SponsorshipPalletId: PalletId = PalletId(*b"py/spnsr");
+}
+
+impl pallet_fee_sponsorship::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type Currency = Balances;
+	type Provenance = MockProvenance;
+	type AddressMapping = LowBytes;
+	type PalletId = SponsorshipPalletId;
+	type MaxSponsoredContracts = ConstU32<2>;
+	type WeightInfo = ();
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = MockProvenance;
+}
+
+// Build genesis storage according to the mock runtime.
+pub fn new_test_ext() -> sp_io::TestExternalities {
+	let mut storage = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
+
+	pallet_balances::GenesisConfig::<Test> { balances: vec![(1, 1_000), (2, 1_000)] }
+		.assimilate_storage(&mut storage)
+		.unwrap();
+
+	let mut ext: sp_io::TestExternalities = storage.into();
+	ext.execute_with(|| System::set_block_number(1));
+	ext
+}
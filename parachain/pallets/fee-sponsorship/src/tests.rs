@@ -0,0 +1,196 @@
+use crate::{
+	mock::*, BlockSpending, Error, Event, Pools, SponsoredContracts, SponsoredUsers, UserSpending,
+};
+use frame_support::{
+	assert_noop, assert_ok,
+	traits::{
+		fungible::{Inspect, Mutate},
+		Hooks,
+	},
+};
+use sp_core::H160;
+use sp_runtime::traits::Convert;
+
+const DAPP: H160 = H160::repeat_byte(0x22);
+const FACTORY: H160 = H160::repeat_byte(0x33);
+const USER: H160 = H160::repeat_byte(0x05);
+
+fn user_account() -> u64 {
+	LowBytes::convert(USER)
+}
+
+/// Create pool 0 for account 1 sponsoring `DAPP` with 500 in funds, paying the fees of `USER`
+fn sponsor_dapp(per_user_cap: u64, per_block_cap: u64) {
+	deploy(H160::from_low_u64_be(1), DAPP);
+	assert_ok!(FeeSponsorship::create_pool(RuntimeOrigin::signed(1), per_user_cap, per_block_cap));
+	assert_ok!(FeeSponsorship::fund_pool(RuntimeOrigin::signed(1), 0, 500));
+	assert_ok!(FeeSponsorship::sponsor_contract(RuntimeOrigin::signed(1), 0, DAPP));
+	assert_ok!(FeeSponsorship::allow_user(RuntimeOrigin::signed(1), 0, USER));
+}
+
+#[test]
+fn owner_funds_and_withdraws_pool() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(FeeSponsorship::create_pool(RuntimeOrigin::signed(1), 100, 200));
+		System::assert_last_event(Event::PoolCreated { pool: 0, owner: 1 }.into());
+
+		assert_ok!(FeeSponsorship::fund_pool(RuntimeOrigin::signed(2), 0, 300));
+		assert_eq!(Balances::balance(&FeeSponsorship::pool_account(0)), 300);
+		assert_noop!(
+			FeeSponsorship::fund_pool(RuntimeOrigin::signed(2), 1, 300),
+			Error::<Test>::UnknownPool
+		);
+
+		assert_noop!(
+			FeeSponsorship::withdraw_from_pool(RuntimeOrigin::signed(2), 0, 100),
+			Error::<Test>::NotPoolOwner
+		);
+		assert_ok!(FeeSponsorship::withdraw_from_pool(RuntimeOrigin::signed(1), 0, 100));
+		assert_eq!(Balances::balance(&1), 1_100);
+
+		assert_ok!(FeeSponsorship::set_pool_caps(RuntimeOrigin::signed(1), 0, 10, 20));
+		let pool = Pools::<Test>::get(0).unwrap();
+		assert_eq!((pool.per_user_cap, pool.per_block_cap), (10, 20));
+	});
+}
+
+#[test]
+fn only_deployers_sponsor_contracts() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(FeeSponsorship::create_pool(RuntimeOrigin::signed(1), 100, 200));
+
+		assert_noop!(
+			FeeSponsorship::sponsor_contract(RuntimeOrigin::signed(1), 0, DAPP),
+			Error::<Test>::NotContractOwner
+		);
+		deploy(H160::from_low_u64_be(2), DAPP);
+		assert_noop!(
+			FeeSponsorship::sponsor_contract(RuntimeOrigin::signed(1), 0, DAPP),
+			Error::<Test>::NotContractOwner
+		);
+
+		// Contracts deployed directly or through a factory deployed by the owner
+		deploy(H160::from_low_u64_be(1), FACTORY);
+		deploy(FACTORY, DAPP);
+		assert_ok!(FeeSponsorship::sponsor_contract(RuntimeOrigin::signed(1), 0, FACTORY));
+		assert_ok!(FeeSponsorship::sponsor_contract(RuntimeOrigin::signed(1), 0, DAPP));
+		System::assert_last_event(Event::ContractSponsored { pool: 0, contract: DAPP }.into());
+
+		assert_noop!(
+			FeeSponsorship::sponsor_contract(RuntimeOrigin::signed(1), 0, DAPP),
+			Error::<Test>::AlreadySponsored
+		);
+		let other = H160::repeat_byte(0x44);
+		deploy(H160::from_low_u64_be(1), other);
+		assert_noop!(
+			FeeSponsorship::sponsor_contract(RuntimeOrigin::signed(1), 0, other),
+			Error::<Test>::TooManyContracts
+		);
+
+		assert_noop!(
+			FeeSponsorship::unsponsor_contract(RuntimeOrigin::signed(2), DAPP),
+			Error::<Test>::NotPoolOwner
+		);
+		assert_ok!(FeeSponsorship::unsponsor_contract(RuntimeOrigin::signed(1), DAPP));
+		assert_eq!(SponsoredContracts::<Test>::get(DAPP), None);
+		assert_eq!(Pools::<Test>::get(0).unwrap().contracts, 1);
+	});
+}
+
+#[test]
+fn advanced_fee_is_settled_at_the_corrected_fee() {
+	new_test_ext().execute_with(|| {
+		sponsor_dapp(1_000, 1_000);
+
+		// The fee is advanced with the existential deposit the sender lacks
+		assert!(FeeSponsorship::advance(USER, &DAPP, 100));
+		assert_eq!(Balances::balance(&user_account()), 101);
+
+		// The runtime withdraws a fee of 80, then refunds 30 of it
+		Balances::set_balance(&user_account(), 21);
+		FeeSponsorship::reclaim(&USER, 80);
+		assert_eq!(Balances::balance(&user_account()), 1);
+		Balances::set_balance(&user_account(), 31);
+		FeeSponsorship::settle(&USER, 50);
+
+		System::assert_last_event(Event::FeeSponsored { pool: 0, user: USER, amount: 51 }.into());
+		assert_eq!(Balances::balance(&user_account()), 1);
+		assert_eq!(Balances::balance(&FeeSponsorship::pool_account(0)), 449);
+		assert_eq!(UserSpending::<Test>::get(0, USER), 51);
+		assert_eq!(BlockSpending::<Test>::get(0), Some((1, 51)));
+	});
+}
+
+#[test]
+fn advances_respect_caps() {
+	new_test_ext().execute_with(|| {
+		sponsor_dapp(150, 120);
+
+		// Calls to other contracts are not sponsored
+		assert!(!FeeSponsorship::advance(USER, &FACTORY, 10));
+
+		assert!(FeeSponsorship::advance(USER, &DAPP, 99));
+		Balances::set_balance(&user_account(), 1);
+		FeeSponsorship::settle(&USER, 99);
+		assert_eq!(UserSpending::<Test>::get(0, USER), 100);
+
+		// The block cap is exhausted for every sender
+		let other = H160::repeat_byte(0x06);
+		assert_ok!(FeeSponsorship::allow_user(RuntimeOrigin::signed(1), 0, other));
+		assert!(!FeeSponsorship::advance(other, &DAPP, 20));
+
+		// The sender cap outlasts the block
+		System::set_block_number(2);
+		assert!(!FeeSponsorship::advance(USER, &DAPP, 60));
+		assert!(FeeSponsorship::advance(other, &DAPP, 20));
+	});
+}
+
+#[test]
+fn only_allowed_senders_are_sponsored() {
+	new_test_ext().execute_with(|| {
+		sponsor_dapp(1_000, 1_000);
+		let other = H160::repeat_byte(0x06);
+
+		// Fresh addresses do not get a share of the pool
+		assert!(!FeeSponsorship::advance(other, &DAPP, 10));
+		assert_eq!(UserSpending::<Test>::get(0, other), 0);
+
+		assert_noop!(
+			FeeSponsorship::allow_user(RuntimeOrigin::signed(2), 0, other),
+			Error::<Test>::NotPoolOwner
+		);
+		assert_noop!(
+			FeeSponsorship::allow_user(RuntimeOrigin::signed(1), 0, USER),
+			Error::<Test>::AlreadyAllowed
+		);
+		assert_ok!(FeeSponsorship::allow_user(RuntimeOrigin::signed(1), 0, other));
+		System::assert_last_event(Event::UserAllowed { pool: 0, user: other }.into());
+		assert!(FeeSponsorship::advance(other, &DAPP, 10));
+		FeeSponsorship::settle(&other, 10);
+
+		assert_ok!(FeeSponsorship::disallow_user(RuntimeOrigin::signed(1), 0, other));
+		assert!(!SponsoredUsers::<Test>::contains_key(0, other));
+		assert!(!FeeSponsorship::advance(other, &DAPP, 10));
+		assert_noop!(
+			FeeSponsorship::disallow_user(RuntimeOrigin::signed(1), 0, other),
+			Error::<Test>::NotAllowed
+		);
+	});
+}
+
+#[test]
+fn stale_advance_is_settled_at_the_end_of_the_block() {
+	new_test_ext().execute_with(|| {
+		sponsor_dapp(1_000, 1_000);
+
+		// The transaction fails before its fee is withdrawn
+		assert!(FeeSponsorship::advance(USER, &DAPP, 100));
+		FeeSponsorship::on_finalize(1);
+
+		// Only the existential deposit of the sender is paid
+		System::assert_last_event(Event::FeeSponsored { pool: 0, user: USER, amount: 1 }.into());
+		assert_eq!(Balances::balance(&FeeSponsorship::pool_account(0)), 499);
+		assert_eq!(UserSpending::<Test>::get(0, USER), 1);
+	});
+}
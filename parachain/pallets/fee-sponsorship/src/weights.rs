@@ -0,0 +1,183 @@
+//! Placeholder weights for `pallet_fee_sponsorship`
+//!
+//! NOT MEASURED: the pallet has not been benchmarked yet. The storage accesses and proof
+//! sizes follow the code, while the execution times are hand estimates doubled as a safety
+//! margin, so that blocks are underfilled rather than overfilled until they are measured.
+//! Replace this file with the output of:
+//!
+//! ./target/release/evm-template-node benchmark pallet --chain dev \
+//!     --pallet pallet_fee_sponsorship --extrinsic '*' --steps 50 --repeat 20 \
+//!     --output pallets/fee-sponsorship/src/weights.rs
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+
+use frame_support::{traits::Get, weights::Weight};
+use core::marker::PhantomData;
+
+/// Weight functions needed for pallet_fee_sponsorship.
+pub trait WeightInfo {
+	fn create_pool() -> Weight;
+	fn fund_pool() -> Weight;
+	fn withdraw_from_pool() -> Weight;
+	fn set_pool_caps() -> Weight;
+	fn sponsor_contract() -> Weight;
+	fn unsponsor_contract() -> Weight;
+	fn allow_user() -> Weight;
+	fn disallow_user() -> Weight;
+}
+
+/// Weights for pallet_fee_sponsorship using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	/// Storage: `FeeSponsorship::NextPoolId` (r:1 w:1)
+	/// Proof: `FeeSponsorship::NextPoolId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `FeeSponsorship::Pools` (r:0 w:1)
+	/// Proof: `FeeSponsorship::Pools` (`max_values`: None, `max_size`: Some(76), added: 2551, mode: `MaxEncodedLen`)
+	fn create_pool() -> Weight {
+		// Proof Size estimate in bytes:
+		//  Estimated: `1489`
+		Weight::from_parts(22_408_000, 0)
+			.saturating_add(Weight::from_parts(0, 1489))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: `FeeSponsorship::Pools` (r:1 w:0)
+	/// Proof: `FeeSponsorship::Pools` (`max_values`: None, `max_size`: Some(76), added: 2551, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn fund_pool() -> Weight {
+		// Proof Size estimate in bytes:
+		//  Estimated: `6196`
+		Weight::from_parts(104_636_000, 0)
+			.saturating_add(Weight::from_parts(0, 6196))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: `FeeSponsorship::Pools` (r:1 w:0)
+	/// Proof: `FeeSponsorship::Pools` (`max_values`: None, `max_size`: Some(76), added: 2551, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn withdraw_from_pool() -> Weight {
+		// Proof Size estimate in bytes:
+		//  Estimated: `6196`
+		Weight::from_parts(103_894_000, 0)
+			.saturating_add(Weight::from_parts(0, 6196))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: `FeeSponsorship::Pools` (r:1 w:1)
+	/// Proof: `FeeSponsorship::Pools` (`max_values`: None, `max_size`: Some(76), added: 2551, mode: `MaxEncodedLen`)
+	fn set_pool_caps() -> Weight {
+		// Proof Size estimate in bytes:
+		//  Estimated: `3541`
+		Weight::from_parts(27_204_000, 0)
+			.saturating_add(Weight::from_parts(0, 3541))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `FeeSponsorship::Pools` (r:1 w:1)
+	/// Proof: `FeeSponsorship::Pools` (`max_values`: None, `max_size`: Some(76), added: 2551, mode: `MaxEncodedLen`)
+	/// Storage: `EvmDeploymentControl::ContractProvenance` (r:1 w:0)
+	/// Proof: `EvmDeploymentControl::ContractProvenance` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `FeeSponsorship::SponsoredContracts` (r:1 w:1)
+	/// Proof: `FeeSponsorship::SponsoredContracts` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	fn sponsor_contract() -> Weight {
+		// Proof Size estimate in bytes:
+		//  Estimated: `3541`
+		Weight::from_parts(43_550_000, 0)
+			.saturating_add(Weight::from_parts(0, 3541))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: `FeeSponsorship::SponsoredContracts` (r:1 w:1)
+	/// Proof: `FeeSponsorship::SponsoredContracts` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// Storage: `FeeSponsorship::Pools` (r:1 w:1)
+	/// Proof: `FeeSponsorship::Pools` (`max_values`: None, `max_size`: Some(76), added: 2551, mode: `MaxEncodedLen`)
+	fn unsponsor_contract() -> Weight {
+		// Proof Size estimate in bytes:
+		//  Estimated: `3541`
+		Weight::from_parts(34_872_000, 0)
+			.saturating_add(Weight::from_parts(0, 3541))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: `FeeSponsorship::Pools` (r:1 w:0)
+	/// Proof: `FeeSponsorship::Pools` (`max_values`: None, `max_size`: Some(76), added: 2551, mode: `MaxEncodedLen`)
+	/// Storage: `FeeSponsorship::SponsoredUsers` (r:1 w:1)
+	/// Proof: `FeeSponsorship::SponsoredUsers` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+	fn allow_user() -> Weight {
+		// Proof Size estimate in bytes:
+		//  Estimated: `3541`
+		Weight::from_parts(31_444_000, 0)
+			.saturating_add(Weight::from_parts(0, 3541))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `FeeSponsorship::Pools` (r:1 w:0)
+	/// Proof: `FeeSponsorship::Pools` (`max_values`: None, `max_size`: Some(76), added: 2551, mode: `MaxEncodedLen`)
+	/// Storage: `FeeSponsorship::SponsoredUsers` (r:1 w:1)
+	/// Proof: `FeeSponsorship::SponsoredUsers` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+	fn disallow_user() -> Weight {
+		// Proof Size estimate in bytes:
+		//  Estimated: `3541`
+		Weight::from_parts(32_376_000, 0)
+			.saturating_add(Weight::from_parts(0, 3541))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	fn create_pool() -> Weight {
+		Weight::from_parts(22_408_000, 1489)
+			.saturating_add(Weight::from_parts(0, 1489))
+			.saturating_add(frame_support::weights::constants::RocksDbWeight::get().reads(1))
+			.saturating_add(frame_support::weights::constants::RocksDbWeight::get().writes(2))
+	}
+	fn fund_pool() -> Weight {
+		Weight::from_parts(104_636_000, 6196)
+			.saturating_add(Weight::from_parts(0, 6196))
+			.saturating_add(frame_support::weights::constants::RocksDbWeight::get().reads(3))
+			.saturating_add(frame_support::weights::constants::RocksDbWeight::get().writes(2))
+	}
+	fn withdraw_from_pool() -> Weight {
+		Weight::from_parts(103_894_000, 6196)
+			.saturating_add(Weight::from_parts(0, 6196))
+			.saturating_add(frame_support::weights::constants::RocksDbWeight::get().reads(3))
+			.saturating_add(frame_support::weights::constants::RocksDbWeight::get().writes(2))
+	}
+	fn set_pool_caps() -> Weight {
+		Weight::from_parts(27_204_000, 3541)
+			.saturating_add(Weight::from_parts(0, 3541))
+			.saturating_add(frame_support::weights::constants::RocksDbWeight::get().reads(1))
+			.saturating_add(frame_support::weights::constants::RocksDbWeight::get().writes(1))
+	}
+	fn sponsor_contract() -> Weight {
+		Weight::from_parts(43_550_000, 3541)
+			.saturating_add(Weight::from_parts(0, 3541))
+			.saturating_add(frame_support::weights::constants::RocksDbWeight::get().reads(3))
+			.saturating_add(frame_support::weights::constants::RocksDbWeight::get().writes(2))
+	}
+	fn unsponsor_contract() -> Weight {
+		Weight::from_parts(34_872_000, 3541)
+			.saturating_add(Weight::from_parts(0, 3541))
+			.saturating_add(frame_support::weights::constants::RocksDbWeight::get().reads(2))
+			.saturating_add(frame_support::weights::constants::RocksDbWeight::get().writes(2))
+	}
+	fn allow_user() -> Weight {
+		Weight::from_parts(31_444_000, 3541)
+			.saturating_add(Weight::from_parts(0, 3541))
+			.saturating_add(frame_support::weights::constants::RocksDbWeight::get().reads(2))
+			.saturating_add(frame_support::weights::constants::RocksDbWeight::get().writes(1))
+	}
+	fn disallow_user() -> Weight {
+		Weight::from_parts(32_376_000, 3541)
+			.saturating_add(Weight::from_parts(0, 3541))
+			.saturating_add(frame_support::weights::constants::RocksDbWeight::get().reads(2))
+			.saturating_add(frame_support::weights::constants::RocksDbWeight::get().writes(1))
+	}
+}
@@ -0,0 +1,80 @@
+//! Benchmarking setup for pallet-inbound-governance
+
+use super::*;
+
+#[allow(unused)]
+use crate::Pallet as InboundGovernance;
+use crate::ethereum::{encode_receipt, leaf_node, Log};
+use frame_benchmarking::v2::*;
+use frame_support::traits::EnsureOrigin;
+use frame_system::RawOrigin;
+use sp_core::{keccak_256, H160, H256};
+use sp_std::{vec, vec::Vec};
+
+#[benchmarks]
+mod benchmarks {
+	use super::*;
+
+	#[benchmark]
+	fn import_header() {
+		let origin = T::HeaderOrigin::try_successful_origin().unwrap();
+		// The imported block takes the slot of an older one
+		let dropped = H256::repeat_byte(0x01);
+		ImportedHeaders::<T>::insert(0, dropped);
+		ReceiptsRoots::<T>::insert(dropped, H256::repeat_byte(0x01));
+		let block_hash = H256::repeat_byte(0x02);
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, block_hash, H256::repeat_byte(0x02));
+
+		// Verify the block replaced the older one
+		assert!(ReceiptsRoots::<T>::contains_key(block_hash));
+		assert!(!ReceiptsRoots::<T>::contains_key(dropped));
+	}
+
+	#[benchmark]
+	fn set_controller() {
+		let origin = T::ControlOrigin::try_successful_origin().unwrap();
+		let controller = H160::repeat_byte(0xcc);
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, Some(controller));
+
+		// Verify the controller was set
+		assert_eq!(Controller::<T>::get(), Some(controller));
+	}
+
+	#[benchmark]
+	fn submit_instruction(n: Linear<0, 16_384>) {
+		let caller: T::AccountId = whitelisted_caller();
+		let controller = H160::repeat_byte(0xcc);
+		Controller::<T>::put(controller);
+
+		// An authorization, padded with `n` bytes of log data
+		let log = Log {
+			address: controller,
+			topics: vec![
+				H256(crate::AUTHORIZE_DEPLOYER_TOPIC),
+				H256::zero(),
+				H256::from_low_u64_be(1),
+			],
+			data: vec![0xff; n as usize],
+		};
+		let leaf = leaf_node(&[8, 0], &encode_receipt(&[log]));
+		let block_hash = H256::repeat_byte(0xbb);
+		ReceiptsRoots::<T>::insert(block_hash, H256(keccak_256(&leaf)));
+		let proof: Vec<Vec<u8>> = vec![leaf];
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), block_hash, 0, 0, proof);
+
+		// Verify the instruction was applied
+		assert_eq!(NextNonce::<T>::get(), 1);
+	}
+
+	impl_benchmark_test_suite!(
+		InboundGovernance,
+		crate::mock::new_test_ext(),
+		crate::mock::Test
+	);
+}
@@ -0,0 +1,181 @@
+//! Verification of Ethereum receipt proofs
+//!
+//! Receipts are stored in a Merkle Patricia trie keyed by the RLP encoded index of their
+//! transaction in the block, whose root is the `receiptsRoot` of the block header. A proof
+//! is the list of trie nodes along the path to the receipt, as returned by
+//! `eth_getProof`-style tooling, starting with the root node.
+
+use rlp::Rlp;
+use sp_core::{keccak_256, H160, H256};
+use sp_std::vec::Vec;
+
+/// A log emitted by a contract
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Log {
+	/// Contract that emitted the log
+	pub address: H160,
+	/// Indexed topics, the event signature first
+	pub topics: Vec<H256>,
+	/// Non-indexed data
+	pub data: Vec<u8>,
+}
+
+/// Reference to a child node, by hash or embedded when its encoding is below 32 bytes
+enum NodeRef<'a> {
+	Hash(H256),
+	Inline(&'a [u8]),
+}
+
+/// Value stored at `key` in the trie with root `root`, as proven by `proof`
+///
+/// Returns `None` if the proof is invalid or proves that `key` is absent.
+pub fn verify_proof(root: H256, key: &[u8], proof: &[Vec<u8>]) -> Option<Vec<u8>> {
+	let nibbles: Vec<u8> = key.iter().flat_map(|byte| [byte >> 4, byte & 0x0f]).collect();
+	let mut path = &nibbles[..];
+	let mut proof = proof.iter();
+	let mut next = NodeRef::Hash(root);
+
+	loop {
+		let encoded = match next {
+			NodeRef::Hash(hash) => {
+				let node = proof.next()?;
+				if keccak_256(node) != hash.0 {
+					return None;
+				}
+				&node[..]
+			},
+			NodeRef::Inline(node) => node,
+		};
+		let node = Rlp::new(encoded);
+
+		match node.item_count().ok()? {
+			// Branch node: sixteen children and a value
+			17 => {
+				let Some((nibble, rest)) = path.split_first() else {
+					let value = node.at(16).ok()?.data().ok()?;
+					return (!value.is_empty()).then(|| value.to_vec());
+				};
+				next = child(node.at(*nibble as usize).ok()?)?;
+				path = rest;
+			},
+			// Extension or leaf node: a hex-prefix encoded path and a child or value
+			2 => {
+				let (is_leaf, partial) = decode_path(node.at(0).ok()?.data().ok()?)?;
+				let rest = path.strip_prefix(&partial[..])?;
+				if is_leaf {
+					if !rest.is_empty() {
+						return None;
+					}
+					return node.at(1).ok()?.data().ok().map(<[u8]>::to_vec);
+				}
+				next = child(node.at(1).ok()?)?;
+				path = rest;
+			},
+			_ => return None,
+		}
+	}
+}
+
+/// Reference to the child node at `item`, `None` for an empty slot
+fn child<'a>(item: Rlp<'a>) -> Option<NodeRef<'a>> {
+	if item.is_list() {
+		return Some(NodeRef::Inline(item.as_raw()));
+	}
+	let data = item.data().ok()?;
+	(data.len() == 32).then(|| NodeRef::Hash(H256::from_slice(data)))
+}
+
+/// Whether a hex-prefix encoded path ends in a leaf, and its nibbles
+fn decode_path(encoded: &[u8]) -> Option<(bool, Vec<u8>)> {
+	let (first, rest) = encoded.split_first()?;
+	let flag = first >> 4;
+	let mut nibbles = Vec::with_capacity(rest.len() * 2 + 1);
+	// Odd paths carry their first nibble in the flag byte
+	if flag & 1 == 1 {
+		nibbles.push(first & 0x0f);
+	}
+	nibbles.extend(rest.iter().flat_map(|byte| [byte >> 4, byte & 0x0f]));
+
+	Some((flag & 2 == 2, nibbles))
+}
+
+/// Key of the receipt of the transaction at `index` in the receipts trie
+pub fn receipt_key(index: u32) -> Vec<u8> {
+	rlp::encode(&index).to_vec()
+}
+
+/// Log at `index` among the logs of an encoded receipt
+///
+/// Typed receipts (EIP-2718) are prefixed with their transaction type, which is skipped.
+pub fn receipt_log(receipt: &[u8], index: u32) -> Option<Log> {
+	let payload = match receipt.first()? {
+		0x00..=0x7f => &receipt[1..],
+		_ => receipt,
+	};
+	// `[status, cumulativeGasUsed, logsBloom, logs]`
+	let receipt = Rlp::new(payload);
+	if receipt.item_count().ok()? != 4 {
+		return None;
+	}
+	// `[address, topics, data]`
+	let log = receipt.at(3).ok()?.at(index as usize).ok()?;
+
+	let address = log.at(0).ok()?.data().ok()?;
+	if address.len() != 20 {
+		return None;
+	}
+	let topics = log
+		.at(1)
+		.ok()?
+		.iter()
+		.map(|topic| {
+			let topic = topic.data().ok()?;
+			(topic.len() == 32).then(|| H256::from_slice(topic))
+		})
+		.collect::<Option<Vec<_>>>()?;
+
+	Some(Log {
+		address: H160::from_slice(address),
+		topics,
+		data: log.at(2).ok()?.data().ok()?.to_vec(),
+	})
+}
+
+/// Encoded EIP-1559 receipt of a successful transaction emitting `logs`
+#[cfg(any(test, feature = "runtime-benchmarks"))]
+pub fn encode_receipt(logs: &[Log]) -> Vec<u8> {
+	let mut receipt = rlp::RlpStream::new_list(4);
+	receipt.append(&1u8).append(&21_000u64).append(&sp_std::vec![0u8; 256]);
+	receipt.begin_list(logs.len());
+	for log in logs {
+		receipt.begin_list(3).append(&log.address.as_bytes().to_vec());
+		receipt.begin_list(log.topics.len());
+		for topic in &log.topics {
+			receipt.append(&topic.as_bytes().to_vec());
+		}
+		receipt.append(&log.data);
+	}
+
+	let mut encoded = sp_std::vec![0x02];
+	encoded.extend_from_slice(&receipt.out());
+	encoded
+}
+
+/// Encoded leaf node holding `value` under the remaining `nibbles` of its key
+#[cfg(any(test, feature = "runtime-benchmarks"))]
+pub fn leaf_node(nibbles: &[u8], value: &[u8]) -> Vec<u8> {
+	// Hex-prefix encoding, flagged as a leaf
+	let mut path = Vec::with_capacity(nibbles.len() / 2 + 1);
+	let rest = if nibbles.len() % 2 == 1 {
+		path.push(0x30 | nibbles[0]);
+		&nibbles[1..]
+	} else {
+		path.push(0x20);
+		nibbles
+	};
+	path.extend(rest.chunks(2).map(|pair| (pair[0] << 4) | pair[1]));
+
+	let mut node = rlp::RlpStream::new_list(2);
+	node.append(&path).append(&value.to_vec());
+	node.out().to_vec()
+}
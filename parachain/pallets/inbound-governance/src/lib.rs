@@ -0,0 +1,348 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+//! # Inbound Governance Pallet
+//!
+//! Deployer registry instructions issued by a controller contract on Ethereum.
+//!
+//! ## Overview
+//!
+//! Governance on Ethereum manages the deployers of the chain through a controller contract
+//! emitting one of the following events per decision:
+//! - `AuthorizeDeployer(uint64 indexed nonce, bytes32 indexed account)`
+//! - `RevokeDeployer(uint64 indexed nonce, bytes32 indexed account)`
+//!
+//! Anyone may relay an event with [`Pallet::submit_instruction`], along with the Merkle
+//! Patricia proof of the receipt holding it against the `receiptsRoot` of its block. The
+//! receipts roots of Ethereum blocks are imported by `HeaderOrigin`, the trusted header
+//! source, and the last `MaxHeaders` of them are kept. Instructions are applied in nonce
+//! order, each exactly once, through the [`DeployerRegistry`] of the runtime, which may
+//! defer authorizations behind its own enactment delay. Accounts are left-padded to 32
+//! bytes, so that a 20-byte account is the last 20 bytes of its topic.
+//!
+//! An instruction that has no effect, e.g. authorizing an authorized deployer, still takes
+//! its nonce, so that the bridge never stalls on a decision made twice on Ethereum.
+//!
+//! ## Features
+//!
+//! - Receipt proof verification against trusted Ethereum receipts roots
+//! - Ordered, replay-protected instructions from a single controller contract
+//! - Governance-controlled header import and controller address
+
+pub use deployment_control_primitives::DeployerRegistry;
+pub use pallet::*;
+pub mod ethereum;
+pub mod weights;
+
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+/// `keccak256("AuthorizeDeployer(uint64,bytes32)")`
+pub const AUTHORIZE_DEPLOYER_TOPIC: [u8; 32] = [
+	0xca, 0xee, 0x53, 0x00, 0xa8, 0x17, 0xcf, 0x5f, 0xa8, 0x54, 0x53, 0xd3, 0xda, 0x6a, 0x59, 0xaa,
+	0x2a, 0x2c, 0x25, 0x61, 0x37, 0xc5, 0x99, 0x73, 0xcd, 0xe6, 0xc0, 0xd2, 0x88, 0xee, 0xa4, 0x70,
+];
+/// `keccak256("RevokeDeployer(uint64,bytes32)")`
+pub const REVOKE_DEPLOYER_TOPIC: [u8; 32] = [
+	0x0c, 0x16, 0xe0, 0x21, 0xc8, 0x43, 0xd9, 0x13, 0xe9, 0x3e, 0x06, 0x1b, 0x48, 0x0d, 0xa8, 0x43,
+	0xc3, 0xb9, 0x3d, 0xe7, 0x93, 0x58, 0x56, 0xfb, 0x0f, 0xf6, 0x91, 0x56, 0x10, 0x1e, 0xc0, 0xfe,
+];
+
+#[frame_support::pallet]
+pub mod pallet {
+	use frame_support::pallet_prelude::*;
+	use frame_system::pallet_prelude::*;
+	use parity_scale_codec::DecodeAll;
+	use sp_core::{H160, H256};
+	use sp_std::vec::Vec;
+
+	use super::DeployerRegistry;
+	use crate::ethereum::{self, Log};
+	pub use crate::weights::WeightInfo;
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		/// The overarching event type
+		#[allow(deprecated)]
+		type RuntimeEvent: From<Event<Self>>
+			+ IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		/// Origin importing the receipts roots of Ethereum blocks, the trusted header source
+		type HeaderOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// Origin allowed to set the controller contract
+		type ControlOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// Registry the instructions are applied to
+		type Deployers: DeployerRegistry<Self::AccountId>;
+
+		/// Number of imported headers kept, the oldest being dropped first
+		#[pallet::constant]
+		type MaxHeaders: Get<u32>;
+
+		/// Weight information for extrinsics in this pallet
+		type WeightInfo: WeightInfo;
+	}
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	/// Decision of the Ethereum governance
+	#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+	pub enum Instruction<AccountId> {
+		/// Authorize an account to deploy contracts
+		AuthorizeDeployer(AccountId),
+		/// Revoke an account's authorization
+		RevokeDeployer(AccountId),
+	}
+
+	/// Controller contract on Ethereum whose events are accepted
+	#[pallet::storage]
+	pub type Controller<T: Config> = StorageValue<_, H160, OptionQuery>;
+
+	/// Receipts roots of the imported Ethereum blocks, by block hash
+	#[pallet::storage]
+	pub type ReceiptsRoots<T: Config> = StorageMap<_, Identity, H256, H256, OptionQuery>;
+
+	/// Hashes of the imported blocks, in a ring buffer of `MaxHeaders` slots
+	#[pallet::storage]
+	pub type ImportedHeaders<T: Config> = StorageMap<_, Twox64Concat, u32, H256, OptionQuery>;
+
+	/// Slot of the ring buffer the next imported block takes
+	#[pallet::storage]
+	pub type NextHeaderSlot<T: Config> = StorageValue<_, u32, ValueQuery>;
+
+	/// Nonce of the next instruction to apply
+	#[pallet::storage]
+	pub type NextNonce<T: Config> = StorageValue<_, u64, ValueQuery>;
+
+	#[pallet::genesis_config]
+	#[derive(frame_support::DefaultNoBound)]
+	pub struct GenesisConfig<T: Config> {
+		/// Controller contract at launch
+		pub controller: Option<H160>,
+		#[serde(skip)]
+		pub _config: PhantomData<T>,
+	}
+
+	#[pallet::genesis_build]
+	impl<T: Config> BuildGenesisConfig for GenesisConfig<T> {
+		fn build(&self) {
+			Controller::<T>::set(self.controller);
+		}
+	}
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// The receipts root of an Ethereum block was imported
+		HeaderImported {
+			/// Hash of the block
+			block_hash: H256,
+			/// Receipts root of the block
+			receipts_root: H256,
+		},
+		/// The controller contract was set or cleared
+		ControllerSet {
+			/// The new controller, `None` to stop accepting instructions
+			controller: Option<H160>,
+		},
+		/// An instruction of the Ethereum governance was applied
+		InstructionExecuted {
+			/// Nonce of the instruction
+			nonce: u64,
+			/// The instruction
+			instruction: Instruction<T::AccountId>,
+			/// Whether the instruction changed the registry, or the registry scheduled the
+			/// change
+			applied: bool,
+		},
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The block was already imported
+		HeaderAlreadyImported,
+		/// The block was not imported, or was dropped
+		UnknownHeader,
+		/// No controller contract is set
+		NoController,
+		/// The proof does not lead to a receipt under the block's receipts root
+		InvalidProof,
+		/// The receipt cannot be decoded or has no log at the given index
+		InvalidReceipt,
+		/// The log was not emitted by the controller contract
+		NotFromController,
+		/// The log is not an instruction event
+		UnknownInstruction,
+		/// The instruction is not the next one to apply
+		UnexpectedNonce,
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Import the receipts root of an Ethereum block
+		///
+		/// Drops the oldest imported block once `MaxHeaders` blocks are kept.
+		///
+		/// # Parameters
+		/// - `origin`: Must be `HeaderOrigin`
+		/// - `block_hash`: Hash of the block
+		/// - `receipts_root`: `receiptsRoot` of the block header
+		///
+		/// # Errors
+		/// - `HeaderAlreadyImported`: The block was already imported
+		///
+		/// # Events
+		/// - `HeaderImported`: Emitted when the block is imported
+		#[pallet::call_index(0)]
+		#[pallet::weight(T::WeightInfo::import_header())]
+		pub fn import_header(
+			origin: OriginFor<T>,
+			block_hash: H256,
+			receipts_root: H256,
+		) -> DispatchResult {
+			T::HeaderOrigin::ensure_origin(origin)?;
+			ensure!(
+				!ReceiptsRoots::<T>::contains_key(block_hash),
+				Error::<T>::HeaderAlreadyImported
+			);
+
+			let slot = NextHeaderSlot::<T>::get();
+			if let Some(dropped) = ImportedHeaders::<T>::take(slot) {
+				ReceiptsRoots::<T>::remove(dropped);
+			}
+			ImportedHeaders::<T>::insert(slot, block_hash);
+			ReceiptsRoots::<T>::insert(block_hash, receipts_root);
+			NextHeaderSlot::<T>::put((slot + 1) % T::MaxHeaders::get().max(1));
+			Self::deposit_event(Event::HeaderImported { block_hash, receipts_root });
+
+			Ok(())
+		}
+
+		/// Set the controller contract whose events are accepted
+		///
+		/// # Parameters
+		/// - `origin`: Must be `ControlOrigin`
+		/// - `controller`: The controller, `None` to stop accepting instructions
+		///
+		/// # Events
+		/// - `ControllerSet`: Emitted when the controller is set
+		#[pallet::call_index(1)]
+		#[pallet::weight(T::WeightInfo::set_controller())]
+		pub fn set_controller(origin: OriginFor<T>, controller: Option<H160>) -> DispatchResult {
+			T::ControlOrigin::ensure_origin(origin)?;
+
+			Controller::<T>::set(controller);
+			Self::deposit_event(Event::ControllerSet { controller });
+
+			Ok(())
+		}
+
+		/// Apply an instruction emitted by the controller contract
+		///
+		/// Any signed account may relay instructions, which are trusted on the strength of
+		/// the proof alone.
+		///
+		/// # Parameters
+		/// - `origin`: Any signed account
+		/// - `block_hash`: Ethereum block holding the transaction that emitted the event
+		/// - `transaction_index`: Index of the transaction in the block
+		/// - `log_index`: Index of the event among the logs of the transaction's receipt
+		/// - `proof`: Trie nodes from the receipts root to the receipt
+		///
+		/// # Errors
+		/// - `UnknownHeader`: The block was not imported
+		/// - `NoController`: No controller contract is set
+		/// - `InvalidProof`: The proof does not lead to a receipt
+		/// - `InvalidReceipt`: The receipt has no such log
+		/// - `NotFromController`: The log was emitted by another contract
+		/// - `UnknownInstruction`: The log is not an instruction event
+		/// - `UnexpectedNonce`: The instruction was already applied or comes too early
+		///
+		/// # Events
+		/// - `InstructionExecuted`: Emitted when the instruction is applied
+		#[pallet::call_index(2)]
+		#[pallet::weight(T::WeightInfo::submit_instruction(
+			proof.iter().map(|node| node.len() as u32).fold(0, u32::saturating_add)
+		))]
+		pub fn submit_instruction(
+			origin: OriginFor<T>,
+			block_hash: H256,
+			transaction_index: u32,
+			log_index: u32,
+			proof: Vec<Vec<u8>>,
+		) -> DispatchResult {
+			ensure_signed(origin)?;
+
+			let receipts_root =
+				ReceiptsRoots::<T>::get(block_hash).ok_or(Error::<T>::UnknownHeader)?;
+			let controller = Controller::<T>::get().ok_or(Error::<T>::NoController)?;
+			let receipt = ethereum::verify_proof(
+				receipts_root,
+				&ethereum::receipt_key(transaction_index),
+				&proof,
+			)
+			.ok_or(Error::<T>::InvalidProof)?;
+			let log =
+				ethereum::receipt_log(&receipt, log_index).ok_or(Error::<T>::InvalidReceipt)?;
+			ensure!(log.address == controller, Error::<T>::NotFromController);
+
+			let (nonce, instruction) = Self::decode_instruction(&log)?;
+			ensure!(nonce == NextNonce::<T>::get(), Error::<T>::UnexpectedNonce);
+			NextNonce::<T>::put(nonce.saturating_add(1));
+
+			let applied = match &instruction {
+				Instruction::AuthorizeDeployer(account) => T::Deployers::authorize(account),
+				Instruction::RevokeDeployer(account) => T::Deployers::revoke(account),
+			};
+			Self::deposit_event(Event::InstructionExecuted { nonce, instruction, applied });
+
+			Ok(())
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// Nonce and instruction of an instruction event
+		///
+		/// Both parameters are indexed, the nonce an `uint64` and the account `bytes32`, both
+		/// left-padded.
+		fn decode_instruction(log: &Log) -> Result<(u64, Instruction<T::AccountId>), Error<T>> {
+			let [signature, nonce, account] = &log.topics[..] else {
+				return Err(Error::<T>::UnknownInstruction);
+			};
+			// A left-padded `uint64`
+			if nonce.0[..24].iter().any(|byte| *byte != 0) {
+				return Err(Error::<T>::UnknownInstruction);
+			}
+			let nonce = u64::from_be_bytes(nonce.0[24..].try_into().expect("8 bytes; qed"));
+			let account = Self::decode_account(account).ok_or(Error::<T>::UnknownInstruction)?;
+
+			let instruction = match signature.0 {
+				crate::AUTHORIZE_DEPLOYER_TOPIC => Instruction::AuthorizeDeployer(account),
+				crate::REVOKE_DEPLOYER_TOPIC => Instruction::RevokeDeployer(account),
+				_ => return Err(Error::<T>::UnknownInstruction),
+			};
+
+			Ok((nonce, instruction))
+		}
+
+		/// Account of a `bytes32` topic, left-padded with zeros to 32 bytes
+		///
+		/// Accounts shorter than a topic, e.g. a 20-byte Ethereum address, are taken from its
+		/// last bytes, and any non-zero padding is refused.
+		fn decode_account(topic: &H256) -> Option<T::AccountId> {
+			let padding = 32usize.checked_sub(T::AccountId::max_encoded_len())?;
+			let (padding, account) = topic.0.split_at(padding);
+			if padding.iter().any(|byte| *byte != 0) {
+				return None;
+			}
+			T::AccountId::decode_all(&mut &account[..]).ok()
+		}
+	}
+}
@@ -0,0 +1,77 @@
+use crate as pallet_inbound_governance;
+use frame_support::{derive_impl, traits::ConstU32};
+use frame_system::EnsureRoot;
+use sp_core::H160;
+use sp_runtime::{traits::IdentityLookup, BuildStorage};
+use std::{cell::RefCell, collections::BTreeSet};
+
+type Block = frame_system::mocking::MockBlock<Test>;
+
+// Configure a mock runtime to test the pallet.
+frame_support::construct_runtime!(
+	pub enum Test
+	{
+		System: frame_system,
+		InboundGovernance: pallet_inbound_governance,
+	}
+);
+
+#[derive_impl(frame_system::config_preludes::TestDefaultConfig)]
+impl frame_system::Config for Test {
+	type Block = Block;
+	type AccountId = u64;
+	type Lookup = IdentityLookup<Self::AccountId>;
+}
+
+thread_local! {
+	static DEPLOYERS: RefCell<BTreeSet<u64>> = RefCell::new(BTreeSet::new());
+}
+
+/// Registry keeping the authorized deployers in memory
+pub struct MockRegistry;
+
+impl MockRegistry {
+	/// Check if `who` is authorized
+	pub fn is_authorized(who: u64) -> bool {
+		DEPLOYERS.with(|deployers| deployers.borrow().contains(&who))
+	}
+}
+
+impl crate::DeployerRegistry<u64> for MockRegistry {
+	fn authorize(who: &u64) -> bool {
+		DEPLOYERS.with(|deployers| deployers.borrow_mut().insert(*who))
+	}
+
+	fn revoke(who: &u64) -> bool {
+		DEPLOYERS.with(|deployers| deployers.borrow_mut().remove(who))
+	}
+}
+
+impl pallet_inbound_governance::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type HeaderOrigin = EnsureRoot<u64>;
+	type ControlOrigin = EnsureRoot<u64>;
+	type Deployers = MockRegistry;
+	type MaxHeaders = ConstU32<2>;
+	type WeightInfo = ();
+}
+
+/// Controller contract set at genesis
+pub const CONTROLLER: H160 = H160::repeat_byte(0xcc);
+
+// Build genesis storage according to the mock runtime.
+pub fn new_test_ext() -> sp_io::TestExternalities {
+	DEPLOYERS.with(|deployers| deployers.borrow_mut().clear());
+	let mut storage = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
+
+	pallet_inbound_governance::GenesisConfig::<Test> {
+		controller: Some(CONTROLLER),
+		..Default::default()
+	}
+	.assimilate_storage(&mut storage)
+	.unwrap();
+
+	let mut ext: sp_io::TestExternalities = storage.into();
+	ext.execute_with(|| System::set_block_number(1));
+	ext
+}
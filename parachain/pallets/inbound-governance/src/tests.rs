@@ -0,0 +1,252 @@
+use crate::{
+	ethereum::{encode_receipt, leaf_node, Log},
+	mock::*,
+	Error, Event, ImportedHeaders, Instruction, NextNonce, ReceiptsRoots, AUTHORIZE_DEPLOYER_TOPIC,
+	REVOKE_DEPLOYER_TOPIC,
+};
+use frame_support::{assert_noop, assert_ok};
+use sp_core::{keccak_256, H160, H256};
+use sp_runtime::DispatchError;
+
+const BLOCK: H256 = H256::repeat_byte(0xbb);
+
+/// Instruction event emitted by `address`
+fn instruction_log(address: H160, signature: [u8; 32], nonce: u64, account: u64) -> Log {
+	let mut account_topic = [0u8; 32];
+	account_topic[24..].copy_from_slice(&account.to_le_bytes());
+	Log {
+		address,
+		topics: vec![H256(signature), H256::from_low_u64_be(nonce), H256(account_topic)],
+		data: vec![],
+	}
+}
+
+/// Import `block`, holding a single transaction emitting `logs`, and prove its receipt
+fn single_receipt_proof(block: H256, logs: &[Log]) -> Vec<Vec<u8>> {
+	// The key of the first transaction is `rlp(0) = 0x80`
+	let leaf = leaf_node(&[8, 0], &encode_receipt(logs));
+	assert_ok!(InboundGovernance::import_header(
+		RuntimeOrigin::root(),
+		block,
+		H256(keccak_256(&leaf))
+	));
+	vec![leaf]
+}
+
+/// Submit the first log of the first transaction of `block`
+fn submit(block: H256, proof: Vec<Vec<u8>>) -> Result<(), DispatchError> {
+	InboundGovernance::submit_instruction(RuntimeOrigin::signed(1), block, 0, 0, proof)
+}
+
+#[test]
+fn headers_are_imported_by_the_header_origin() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			InboundGovernance::import_header(RuntimeOrigin::signed(1), BLOCK, H256::zero()),
+			DispatchError::BadOrigin
+		);
+
+		assert_ok!(InboundGovernance::import_header(RuntimeOrigin::root(), BLOCK, H256::zero()));
+		System::assert_last_event(
+			Event::HeaderImported { block_hash: BLOCK, receipts_root: H256::zero() }.into(),
+		);
+		assert_noop!(
+			InboundGovernance::import_header(RuntimeOrigin::root(), BLOCK, H256::zero()),
+			Error::<Test>::HeaderAlreadyImported
+		);
+	});
+}
+
+#[test]
+fn oldest_headers_are_dropped() {
+	new_test_ext().execute_with(|| {
+		for byte in 1..=3 {
+			assert_ok!(InboundGovernance::import_header(
+				RuntimeOrigin::root(),
+				H256::repeat_byte(byte),
+				H256::repeat_byte(byte)
+			));
+		}
+
+		assert!(ReceiptsRoots::<Test>::get(H256::repeat_byte(1)).is_none());
+		assert!(ReceiptsRoots::<Test>::get(H256::repeat_byte(2)).is_some());
+		assert!(ReceiptsRoots::<Test>::get(H256::repeat_byte(3)).is_some());
+		assert_eq!(ImportedHeaders::<Test>::get(0), Some(H256::repeat_byte(3)));
+	});
+}
+
+#[test]
+fn controller_instructions_manage_deployers() {
+	new_test_ext().execute_with(|| {
+		let proof = single_receipt_proof(
+			BLOCK,
+			&[instruction_log(CONTROLLER, AUTHORIZE_DEPLOYER_TOPIC, 0, 7)],
+		);
+		assert_ok!(submit(BLOCK, proof));
+
+		assert!(MockRegistry::is_authorized(7));
+		assert_eq!(NextNonce::<Test>::get(), 1);
+		System::assert_last_event(
+			Event::InstructionExecuted {
+				nonce: 0,
+				instruction: Instruction::AuthorizeDeployer(7),
+				applied: true,
+			}
+			.into(),
+		);
+
+		let next_block = H256::repeat_byte(0xbc);
+		let proof = single_receipt_proof(
+			next_block,
+			&[instruction_log(CONTROLLER, REVOKE_DEPLOYER_TOPIC, 1, 7)],
+		);
+		assert_ok!(submit(next_block, proof));
+
+		assert!(!MockRegistry::is_authorized(7));
+	});
+}
+
+#[test]
+fn proofs_through_branch_nodes_are_accepted() {
+	new_test_ext().execute_with(|| {
+		// Keys `0x80` and `0x01` of the first two transactions part at their first nibble
+		let first = leaf_node(&[0], &encode_receipt(&[]));
+		let second = leaf_node(
+			&[1],
+			&encode_receipt(&[
+				instruction_log(H160::repeat_byte(0x01), AUTHORIZE_DEPLOYER_TOPIC, 0, 8),
+				instruction_log(CONTROLLER, AUTHORIZE_DEPLOYER_TOPIC, 0, 7),
+			]),
+		);
+		let mut branch = rlp::RlpStream::new_list(17);
+		for nibble in 0..16 {
+			match nibble {
+				0 => branch.append(&keccak_256(&second).to_vec()),
+				8 => branch.append(&keccak_256(&first).to_vec()),
+				_ => branch.append_empty_data(),
+			};
+		}
+		branch.append_empty_data();
+		let branch = branch.out().to_vec();
+		assert_ok!(InboundGovernance::import_header(
+			RuntimeOrigin::root(),
+			BLOCK,
+			H256(keccak_256(&branch))
+		));
+
+		assert_ok!(InboundGovernance::submit_instruction(
+			RuntimeOrigin::signed(1),
+			BLOCK,
+			1,
+			1,
+			vec![branch, second]
+		));
+		assert!(MockRegistry::is_authorized(7));
+		assert!(!MockRegistry::is_authorized(8));
+	});
+}
+
+#[test]
+fn invalid_proofs_are_rejected() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(submit(BLOCK, vec![]), Error::<Test>::UnknownHeader);
+
+		let mut proof = single_receipt_proof(
+			BLOCK,
+			&[instruction_log(CONTROLLER, AUTHORIZE_DEPLOYER_TOPIC, 0, 7)],
+		);
+		let last = proof[0].len() - 1;
+		proof[0][last] ^= 0xff;
+		assert_noop!(submit(BLOCK, proof), Error::<Test>::InvalidProof);
+
+		// The proof covers the first transaction only
+		let proof = vec![leaf_node(&[8, 0], &encode_receipt(&[]))];
+		ReceiptsRoots::<Test>::insert(BLOCK, H256(keccak_256(&proof[0])));
+		assert_noop!(
+			InboundGovernance::submit_instruction(
+				RuntimeOrigin::signed(1),
+				BLOCK,
+				1,
+				0,
+				proof.clone()
+			),
+			Error::<Test>::InvalidProof
+		);
+		assert_noop!(submit(BLOCK, proof), Error::<Test>::InvalidReceipt);
+	});
+}
+
+#[test]
+fn only_controller_instructions_are_accepted() {
+	new_test_ext().execute_with(|| {
+		let proof = single_receipt_proof(
+			BLOCK,
+			&[instruction_log(H160::repeat_byte(0x01), AUTHORIZE_DEPLOYER_TOPIC, 0, 7)],
+		);
+		assert_noop!(submit(BLOCK, proof.clone()), Error::<Test>::NotFromController);
+
+		assert_ok!(InboundGovernance::set_controller(RuntimeOrigin::root(), None));
+		System::assert_last_event(Event::ControllerSet { controller: None }.into());
+		assert_noop!(submit(BLOCK, proof), Error::<Test>::NoController);
+	});
+}
+
+#[test]
+fn unknown_events_are_rejected() {
+	new_test_ext().execute_with(|| {
+		let proof = single_receipt_proof(BLOCK, &[instruction_log(CONTROLLER, [0x11; 32], 0, 7)]);
+
+		assert_noop!(submit(BLOCK, proof), Error::<Test>::UnknownInstruction);
+	});
+}
+
+#[test]
+fn padded_accounts_are_rejected() {
+	new_test_ext().execute_with(|| {
+		let mut log = instruction_log(CONTROLLER, AUTHORIZE_DEPLOYER_TOPIC, 0, 7);
+		log.topics[2].0[0] = 0x01;
+		let proof = single_receipt_proof(BLOCK, &[log]);
+
+		assert_noop!(submit(BLOCK, proof), Error::<Test>::UnknownInstruction);
+	});
+}
+
+#[test]
+fn instructions_apply_once_and_in_order() {
+	new_test_ext().execute_with(|| {
+		let early = single_receipt_proof(
+			BLOCK,
+			&[instruction_log(CONTROLLER, AUTHORIZE_DEPLOYER_TOPIC, 1, 7)],
+		);
+		assert_noop!(submit(BLOCK, early), Error::<Test>::UnexpectedNonce);
+
+		let next_block = H256::repeat_byte(0xbc);
+		let proof = single_receipt_proof(
+			next_block,
+			&[instruction_log(CONTROLLER, AUTHORIZE_DEPLOYER_TOPIC, 0, 7)],
+		);
+		assert_ok!(submit(next_block, proof.clone()));
+		assert_noop!(submit(next_block, proof), Error::<Test>::UnexpectedNonce);
+	});
+}
+
+#[test]
+fn ineffective_instructions_take_their_nonce() {
+	new_test_ext().execute_with(|| {
+		let proof = single_receipt_proof(
+			BLOCK,
+			&[instruction_log(CONTROLLER, REVOKE_DEPLOYER_TOPIC, 0, 7)],
+		);
+		assert_ok!(submit(BLOCK, proof));
+
+		assert_eq!(NextNonce::<Test>::get(), 1);
+		System::assert_last_event(
+			Event::InstructionExecuted {
+				nonce: 0,
+				instruction: Instruction::RevokeDeployer(7),
+				applied: false,
+			}
+			.into(),
+		);
+	});
+}
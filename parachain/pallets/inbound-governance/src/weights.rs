@@ -0,0 +1,110 @@
+//! Placeholder weights for `pallet_inbound_governance`
+//!
+//! NOT MEASURED: the pallet has not been benchmarked yet. The storage accesses and proof
+//! sizes follow the code, while the execution times are hand estimates doubled as a safety
+//! margin, so that blocks are underfilled rather than overfilled until they are measured.
+//! Replace this file with the output of:
+//!
+//! ./target/release/evm-template-node benchmark pallet --chain dev \
+//!     --pallet pallet_inbound_governance --extrinsic '*' --steps 50 --repeat 20 \
+//!     --output pallets/inbound-governance/src/weights.rs
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+
+use frame_support::{traits::Get, weights::Weight};
+use core::marker::PhantomData;
+
+/// Weight functions needed for pallet_inbound_governance.
+pub trait WeightInfo {
+	fn import_header() -> Weight;
+	fn set_controller() -> Weight;
+	fn submit_instruction(n: u32, ) -> Weight;
+}
+
+/// Weights for pallet_inbound_governance using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	/// Storage: `InboundGovernance::ReceiptsRoots` (r:2 w:2)
+	/// Proof: `InboundGovernance::ReceiptsRoots` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `InboundGovernance::NextHeaderSlot` (r:1 w:1)
+	/// Proof: `InboundGovernance::NextHeaderSlot` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `InboundGovernance::ImportedHeaders` (r:1 w:1)
+	/// Proof: `InboundGovernance::ImportedHeaders` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	fn import_header() -> Weight {
+		// Proof Size estimate in bytes:
+		//  Estimated: `6068`
+		Weight::from_parts(36_410_000, 0)
+			.saturating_add(Weight::from_parts(0, 6068))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(4))
+	}
+	/// Storage: `InboundGovernance::Controller` (r:0 w:1)
+	/// Proof: `InboundGovernance::Controller` (`max_values`: Some(1), `max_size`: Some(20), added: 515, mode: `MaxEncodedLen`)
+	fn set_controller() -> Weight {
+		// Proof Size estimate in bytes:
+		//  Estimated: `0`
+		Weight::from_parts(11_224_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `InboundGovernance::ReceiptsRoots` (r:1 w:0)
+	/// Proof: `InboundGovernance::ReceiptsRoots` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `InboundGovernance::Controller` (r:1 w:0)
+	/// Proof: `InboundGovernance::Controller` (`max_values`: Some(1), `max_size`: Some(20), added: 515, mode: `MaxEncodedLen`)
+	/// Storage: `InboundGovernance::NextNonce` (r:1 w:1)
+	/// Proof: `InboundGovernance::NextNonce` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `EvmDeploymentControl::EnactmentDelay` (r:1 w:0)
+	/// Proof: `EvmDeploymentControl::EnactmentDelay` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `EvmDeploymentControl::Enacting` (r:1 w:0)
+	/// Proof: `EvmDeploymentControl::Enacting` (`max_values`: Some(1), `max_size`: Some(0), added: 495, mode: `MaxEncodedLen`)
+	/// Storage: `EvmDeploymentControl::AuthorizationCache` (r:1 w:1)
+	/// Proof: `EvmDeploymentControl::AuthorizationCache` (`max_values`: None, `max_size`: Some(41), added: 2516, mode: `MaxEncodedLen`)
+	/// Storage: `EvmDeploymentControl::CachedAuthorizations` (r:1 w:1)
+	/// Proof: `EvmDeploymentControl::CachedAuthorizations` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `EvmDeploymentControl::AuthorizedDeployers` (r:1 w:1)
+	/// Proof: `EvmDeploymentControl::AuthorizedDeployers` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
+	/// Storage: `EvmDeploymentControl::PendingGovernanceCalls` (r:1 w:1)
+	/// Proof: `EvmDeploymentControl::PendingGovernanceCalls` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `EvmDeploymentControl::NextGovernanceCallId` (r:1 w:1)
+	/// Proof: `EvmDeploymentControl::NextGovernanceCallId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `EvmDeploymentControl::GovernanceCalls` (r:0 w:1)
+	/// Proof: `EvmDeploymentControl::GovernanceCalls` (`max_values`: None, `max_size`: Some(196), added: 2671, mode: `MaxEncodedLen`)
+	/// Storage: `EvmDeploymentControl::Objections` (r:0 w:1)
+	/// Proof: `EvmDeploymentControl::Objections` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
+	/// Storage: `OutboundCommitments::NextNonce` (r:1 w:1)
+	/// Proof: `OutboundCommitments::NextNonce` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `OutboundCommitments::Messages` (r:1 w:1)
+	/// Proof: `OutboundCommitments::Messages` (`max_values`: Some(1), `max_size`: Some(35331), added: 35826, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[0, 16384]`.
+	fn submit_instruction(n: u32, ) -> Weight {
+		// Proof Size estimate in bytes:
+		//  Estimated: `41823`
+		Weight::from_parts(87_037_000, 0)
+			.saturating_add(Weight::from_parts(0, 41823))
+			// Standard Error: 12
+			.saturating_add(Weight::from_parts(7_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(12))
+			.saturating_add(T::DbWeight::get().writes(10))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	fn import_header() -> Weight {
+		Weight::from_parts(36_410_000, 6068)
+			.saturating_add(frame_support::weights::constants::RocksDbWeight::get().reads(4))
+			.saturating_add(frame_support::weights::constants::RocksDbWeight::get().writes(4))
+	}
+	fn set_controller() -> Weight {
+		Weight::from_parts(11_224_000, 0)
+			.saturating_add(frame_support::weights::constants::RocksDbWeight::get().writes(1))
+	}
+	fn submit_instruction(n: u32, ) -> Weight {
+		Weight::from_parts(87_037_000, 41823)
+			.saturating_add(Weight::from_parts(7_000, 0).saturating_mul(n.into()))
+			.saturating_add(frame_support::weights::constants::RocksDbWeight::get().reads(12))
+			.saturating_add(frame_support::weights::constants::RocksDbWeight::get().writes(10))
+	}
+}
@@ -0,0 +1,55 @@
+//! Benchmarking setup for pallet-issuance
+
+use super::*;
+
+#[allow(unused)]
+use crate::Pallet as Issuance;
+use frame_benchmarking::v2::*;
+use frame_support::traits::{EnsureOrigin, Get, Hooks};
+use frame_system::pallet_prelude::BlockNumberFor;
+use sp_runtime::{Perbill, Percent};
+
+#[benchmarks]
+mod benchmarks {
+	use super::*;
+
+	#[benchmark]
+	fn set_schedule() {
+		let origin = T::ScheduleOrigin::try_successful_origin().unwrap();
+		let schedule = IssuanceSchedule {
+			per_block: T::MaxIssuancePerBlock::get(),
+			decay: Perbill::from_percent(10),
+			treasury_share: T::MaxTreasuryShare::get(),
+		};
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, schedule);
+
+		// Verify the schedule was stored
+		assert_eq!(Schedule::<T>::get(), Some(schedule));
+	}
+
+	#[benchmark]
+	fn on_initialize() {
+		// Worst case: the issuance decays and both shares are paid out
+		let schedule = IssuanceSchedule {
+			per_block: T::MaxIssuancePerBlock::get(),
+			decay: Perbill::from_percent(10),
+			treasury_share: Percent::from_percent(50).min(T::MaxTreasuryShare::get()),
+		};
+		Schedule::<T>::put(schedule);
+		CurrentIssuance::<T>::put(schedule.per_block);
+		let now: BlockNumberFor<T> = 1u32.into();
+		NextDecay::<T>::put(now);
+
+		#[block]
+		{
+			Issuance::<T>::on_initialize(now);
+		}
+
+		// Verify the issuance decayed
+		assert_eq!(NextDecay::<T>::get(), Some(now + T::DecayPeriod::get()));
+	}
+
+	impl_benchmark_test_suite!(Issuance, crate::mock::new_test_ext(), crate::mock::Test);
+}
@@ -0,0 +1,243 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+//! # Issuance Pallet
+//!
+//! Token issuance following a decaying per-block schedule.
+//!
+//! ## Overview
+//!
+//! Every block mints the current per-block issuance and splits it between the treasury
+//! and the collators according to the [`IssuanceSchedule`] in effect. At the end of every
+//! `DecayPeriod`, the per-block issuance drops by the schedule's decay, so that the
+//! supply converges instead of growing linearly.
+//!
+//! `ScheduleOrigin` replaces the schedule with [`Pallet::set_schedule`], which restarts
+//! the curve from the new per-block issuance. Schedules are bounded by the hard caps
+//! `MaxIssuancePerBlock` and `MaxTreasuryShare`, which only a runtime upgrade can lift.
+//! `DefaultSchedule` applies while none is set.
+
+pub use pallet::*;
+pub mod weights;
+
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+#[frame_support::pallet]
+pub mod pallet {
+	use frame_support::{
+		pallet_prelude::*,
+		traits::{
+			fungible::{self, Balanced, Inspect},
+			Imbalance, OnUnbalanced,
+		},
+	};
+	use frame_system::pallet_prelude::*;
+	use sp_runtime::{
+		traits::{Saturating, Zero},
+		PerThing, Perbill, Percent,
+	};
+
+	pub use crate::weights::WeightInfo;
+
+	/// Balance of the issued currency
+	pub type BalanceOf<T> =
+		<<T as Config>::Currency as Inspect<<T as frame_system::Config>::AccountId>>::Balance;
+
+	/// Newly minted tokens
+	pub type CreditOf<T> =
+		fungible::Credit<<T as frame_system::Config>::AccountId, <T as Config>::Currency>;
+
+	/// Parameters of the issuance curve
+	#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub struct IssuanceSchedule<Balance> {
+		/// Tokens minted per block at the start of the curve
+		pub per_block: Balance,
+		/// Reduction of the per-block issuance at the end of every decay period
+		pub decay: Perbill,
+		/// Share of the issuance paid into the treasury, the rest goes to the collators
+		pub treasury_share: Percent,
+	}
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		/// The overarching event type
+		#[allow(deprecated)]
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		/// Currency being issued
+		type Currency: Balanced<Self::AccountId>;
+
+		/// Receiver of the treasury share
+		type Treasury: OnUnbalanced<CreditOf<Self>>;
+
+		/// Receiver of the collator share, e.g. the collator rewards pot
+		type Collators: OnUnbalanced<CreditOf<Self>>;
+
+		/// Origin allowed to replace the schedule
+		type ScheduleOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// Schedule in effect while none is set
+		#[pallet::constant]
+		type DefaultSchedule: Get<IssuanceSchedule<BalanceOf<Self>>>;
+
+		/// Number of blocks between two decays of the per-block issuance
+		#[pallet::constant]
+		type DecayPeriod: Get<BlockNumberFor<Self>>;
+
+		/// Hard cap on the per-block issuance of a schedule
+		#[pallet::constant]
+		type MaxIssuancePerBlock: Get<BalanceOf<Self>>;
+
+		/// Hard cap on the treasury share of a schedule
+		#[pallet::constant]
+		type MaxTreasuryShare: Get<Percent>;
+
+		/// Weight information for extrinsics in this pallet
+		type WeightInfo: WeightInfo;
+	}
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	/// Schedule set by `ScheduleOrigin`
+	///
+	/// `DefaultSchedule` applies while unset.
+	#[pallet::storage]
+	pub type Schedule<T: Config> = StorageValue<_, IssuanceSchedule<BalanceOf<T>>, OptionQuery>;
+
+	/// Tokens minted per block after the decays so far
+	///
+	/// The schedule's `per_block` while unset.
+	#[pallet::storage]
+	pub type CurrentIssuance<T: Config> = StorageValue<_, BalanceOf<T>, OptionQuery>;
+
+	/// Block at which the per-block issuance decays next
+	#[pallet::storage]
+	pub type NextDecay<T: Config> = StorageValue<_, BlockNumberFor<T>, OptionQuery>;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// The schedule was replaced and the curve restarted
+		ScheduleSet {
+			/// The new schedule
+			schedule: IssuanceSchedule<BalanceOf<T>>,
+		},
+		/// The per-block issuance decayed at the end of a decay period
+		IssuanceDecayed {
+			/// The new per-block issuance
+			per_block: BalanceOf<T>,
+		},
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The per-block issuance is above `MaxIssuancePerBlock`
+		IssuanceTooHigh,
+		/// The treasury share is above `MaxTreasuryShare`
+		TreasuryShareTooHigh,
+	}
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_initialize(n: BlockNumberFor<T>) -> Weight {
+			let schedule = Self::schedule();
+			let mut per_block = Self::current_issuance();
+
+			match NextDecay::<T>::get() {
+				None => NextDecay::<T>::put(n.saturating_add(T::DecayPeriod::get())),
+				Some(decay_at) if decay_at <= n => {
+					per_block = per_block.saturating_sub(schedule.decay.mul_floor(per_block));
+					CurrentIssuance::<T>::put(per_block);
+					NextDecay::<T>::put(n.saturating_add(T::DecayPeriod::get()));
+					Self::deposit_event(Event::IssuanceDecayed { per_block });
+				},
+				Some(_) => {},
+			}
+
+			if !per_block.is_zero() {
+				let minted = T::Currency::issue(per_block);
+				let (to_treasury, to_collators) =
+					minted.split(schedule.treasury_share.mul_floor(per_block));
+				T::Treasury::on_unbalanced(to_treasury);
+				T::Collators::on_unbalanced(to_collators);
+			}
+
+			T::WeightInfo::on_initialize()
+		}
+
+		fn integrity_test() {
+			let schedule = T::DefaultSchedule::get();
+			assert!(
+				schedule.per_block <= T::MaxIssuancePerBlock::get(),
+				"`DefaultSchedule` must not exceed `MaxIssuancePerBlock`"
+			);
+			assert!(
+				schedule.treasury_share <= T::MaxTreasuryShare::get(),
+				"`DefaultSchedule` must not exceed `MaxTreasuryShare`"
+			);
+		}
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Replace the issuance schedule
+		///
+		/// Restarts the curve from the new per-block issuance, with the next decay a full
+		/// decay period ahead.
+		///
+		/// # Parameters
+		/// - `origin`: Must be `ScheduleOrigin`
+		/// - `schedule`: The new schedule
+		///
+		/// # Errors
+		/// - `IssuanceTooHigh`: The per-block issuance is above `MaxIssuancePerBlock`
+		/// - `TreasuryShareTooHigh`: The treasury share is above `MaxTreasuryShare`
+		///
+		/// # Events
+		/// - `ScheduleSet`: Emitted when the schedule is replaced
+		#[pallet::call_index(0)]
+		#[pallet::weight(T::WeightInfo::set_schedule())]
+		pub fn set_schedule(
+			origin: OriginFor<T>,
+			schedule: IssuanceSchedule<BalanceOf<T>>,
+		) -> DispatchResult {
+			T::ScheduleOrigin::ensure_origin(origin)?;
+
+			ensure!(
+				schedule.per_block <= T::MaxIssuancePerBlock::get(),
+				Error::<T>::IssuanceTooHigh
+			);
+			ensure!(
+				schedule.treasury_share <= T::MaxTreasuryShare::get(),
+				Error::<T>::TreasuryShareTooHigh
+			);
+
+			Schedule::<T>::put(schedule);
+			CurrentIssuance::<T>::put(schedule.per_block);
+			let now = frame_system::Pallet::<T>::block_number();
+			NextDecay::<T>::put(now.saturating_add(T::DecayPeriod::get()));
+			Self::deposit_event(Event::ScheduleSet { schedule });
+
+			Ok(())
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// Schedule in effect
+		pub fn schedule() -> IssuanceSchedule<BalanceOf<T>> {
+			Schedule::<T>::get().unwrap_or_else(T::DefaultSchedule::get)
+		}
+
+		/// Tokens minted per block until the next decay
+		pub fn current_issuance() -> BalanceOf<T> {
+			CurrentIssuance::<T>::get().unwrap_or_else(|| Self::schedule().per_block)
+		}
+	}
+}
@@ -0,0 +1,76 @@
+use crate as pallet_issuance;
+use frame_support::{
+	derive_impl, parameter_types,
+	traits::{tokens::imbalance::ResolveTo, ConstU64},
+};
+use frame_system::EnsureRoot;
+use pallet_issuance::IssuanceSchedule;
+use sp_runtime::{traits::IdentityLookup, BuildStorage, Perbill, Percent};
+
+type Block = frame_system::mocking::MockBlock<Test>;
+
+// Configure a mock runtime to test the pallet.
+frame_support::construct_runtime!(
+	pub enum Test
+	{
+		System: frame_system,
+		Balances: pallet_balances,
+		Issuance: pallet_issuance,
+	}
+);
+
+#[derive_impl(frame_system::config_preludes::TestDefaultConfig)]
+impl frame_system::Config for Test {
+	type Block = Block;
+	type AccountId = u64;
+	type Lookup = IdentityLookup<Self::AccountId>;
+	type AccountData = pallet_balances::AccountData<u64>;
+}
+
+#[derive_impl(pallet_balances::config_preludes::TestDefaultConfig)]
+impl pallet_balances::Config for Test {
+	type AccountStore = System;
+}
+
+parameter_types! {
+	pub const TreasuryAccount: u64 = 100;
+	pub const CollatorPot: u64 = 200;
+	pub const DefaultSchedule: IssuanceSchedule<u64> = IssuanceSchedule {
+		per_block: 100,
+		decay: Perbill::from_percent(10),
+		treasury_share: Percent::from_percent(30),
+	};
+	pub const MaxTreasuryShare: Percent = Percent::from_percent(50);
+}
+
+impl pallet_issuance::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type Currency = Balances;
+	type Treasury = ResolveTo<TreasuryAccount, Balances>;
+	type Collators = ResolveTo<CollatorPot, Balances>;
+	type ScheduleOrigin = EnsureRoot<u64>;
+	type DefaultSchedule = DefaultSchedule;
+	type DecayPeriod = ConstU64<10>;
+	type MaxIssuancePerBlock = ConstU64<1_000>;
+	type MaxTreasuryShare = MaxTreasuryShare;
+	type WeightInfo = ();
+}
+
+// Build genesis storage according to the mock runtime.
+pub fn new_test_ext() -> sp_io::TestExternalities {
+	let storage = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
+
+	let mut ext: sp_io::TestExternalities = storage.into();
+	ext.execute_with(|| System::set_block_number(1));
+	ext
+}
+
+/// Run the issuance of blocks up to `n`
+pub fn run_to_block(n: u64) {
+	use frame_support::traits::Hooks;
+
+	while System::block_number() < n {
+		System::set_block_number(System::block_number() + 1);
+		Issuance::on_initialize(System::block_number());
+	}
+}
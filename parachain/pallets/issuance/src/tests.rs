@@ -0,0 +1,118 @@
+use crate::{mock::*, CurrentIssuance, Error, Event, IssuanceSchedule, NextDecay, Schedule};
+use frame_support::{assert_noop, assert_ok, traits::fungible::Inspect};
+use sp_runtime::{DispatchError, Perbill, Percent};
+
+#[test]
+fn schedule_defaults_to_config() {
+	new_test_ext().execute_with(|| {
+		assert_eq!(Issuance::schedule(), DefaultSchedule::get());
+		assert_eq!(Issuance::current_issuance(), 100);
+	});
+}
+
+#[test]
+fn issuance_is_split_every_block() {
+	new_test_ext().execute_with(|| {
+		let issuance = Balances::total_issuance();
+
+		run_to_block(2);
+		assert_eq!(Balances::total_issuance(), issuance + 100);
+		assert_eq!(Balances::free_balance(TreasuryAccount::get()), 30);
+		assert_eq!(Balances::free_balance(CollatorPot::get()), 70);
+
+		run_to_block(4);
+		assert_eq!(Balances::total_issuance(), issuance + 300);
+		assert_eq!(Balances::free_balance(TreasuryAccount::get()), 90);
+		assert_eq!(Balances::free_balance(CollatorPot::get()), 210);
+	});
+}
+
+#[test]
+fn issuance_decays_every_period() {
+	new_test_ext().execute_with(|| {
+		run_to_block(2);
+		assert_eq!(NextDecay::<Test>::get(), Some(12));
+
+		run_to_block(11);
+		assert_eq!(Issuance::current_issuance(), 100);
+
+		run_to_block(12);
+		System::assert_has_event(Event::IssuanceDecayed { per_block: 90 }.into());
+		assert_eq!(CurrentIssuance::<Test>::get(), Some(90));
+		assert_eq!(NextDecay::<Test>::get(), Some(22));
+		assert_eq!(Balances::free_balance(TreasuryAccount::get()), 10 * 30 + 27);
+
+		run_to_block(22);
+		assert_eq!(Issuance::current_issuance(), 81);
+	});
+}
+
+#[test]
+fn fully_decayed_issuance_mints_nothing() {
+	new_test_ext().execute_with(|| {
+		let schedule = IssuanceSchedule {
+			per_block: 10,
+			decay: Perbill::one(),
+			treasury_share: Percent::zero(),
+		};
+		assert_ok!(Issuance::set_schedule(RuntimeOrigin::root(), schedule));
+
+		run_to_block(11);
+		assert_eq!(Issuance::current_issuance(), 0);
+		let issuance = Balances::total_issuance();
+
+		run_to_block(12);
+		assert_eq!(Balances::total_issuance(), issuance);
+	});
+}
+
+#[test]
+fn set_schedule_restarts_the_curve() {
+	new_test_ext().execute_with(|| {
+		run_to_block(15);
+		assert_eq!(Issuance::current_issuance(), 90);
+
+		let schedule = IssuanceSchedule {
+			per_block: 1_000,
+			decay: Perbill::from_percent(50),
+			treasury_share: Percent::from_percent(50),
+		};
+		assert_ok!(Issuance::set_schedule(RuntimeOrigin::root(), schedule));
+		System::assert_last_event(Event::ScheduleSet { schedule }.into());
+		assert_eq!(Schedule::<Test>::get(), Some(schedule));
+		assert_eq!(Issuance::current_issuance(), 1_000);
+		assert_eq!(NextDecay::<Test>::get(), Some(25));
+
+		let treasury = Balances::free_balance(TreasuryAccount::get());
+		run_to_block(16);
+		assert_eq!(Balances::free_balance(TreasuryAccount::get()), treasury + 500);
+
+		run_to_block(25);
+		assert_eq!(Issuance::current_issuance(), 500);
+	});
+}
+
+#[test]
+fn set_schedule_validates_input() {
+	new_test_ext().execute_with(|| {
+		let schedule = DefaultSchedule::get();
+		assert_noop!(
+			Issuance::set_schedule(RuntimeOrigin::signed(1), schedule),
+			DispatchError::BadOrigin
+		);
+		assert_noop!(
+			Issuance::set_schedule(
+				RuntimeOrigin::root(),
+				IssuanceSchedule { per_block: 1_001, ..schedule }
+			),
+			Error::<Test>::IssuanceTooHigh
+		);
+		assert_noop!(
+			Issuance::set_schedule(
+				RuntimeOrigin::root(),
+				IssuanceSchedule { treasury_share: Percent::from_percent(51), ..schedule }
+			),
+			Error::<Test>::TreasuryShareTooHigh
+		);
+	});
+}
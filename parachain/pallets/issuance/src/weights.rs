@@ -0,0 +1,73 @@
+//! Placeholder weights for `pallet_issuance`
+//!
+//! NOT MEASURED: the pallet has not been benchmarked yet. The storage accesses and proof
+//! sizes follow the code, while the execution times are hand estimates doubled as a safety
+//! margin, so that blocks are underfilled rather than overfilled until they are measured.
+//! Replace this file with the output of:
+//!
+//! ./target/release/evm-template-node benchmark pallet --chain dev \
+//!     --pallet pallet_issuance --extrinsic '*' --steps 50 --repeat 20 \
+//!     --output pallets/issuance/src/weights.rs
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+
+use frame_support::{traits::Get, weights::Weight};
+use core::marker::PhantomData;
+
+/// Weight functions needed for pallet_issuance.
+pub trait WeightInfo {
+	fn set_schedule() -> Weight;
+	fn on_initialize() -> Weight;
+}
+
+/// Weights for pallet_issuance using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	/// Storage: `Issuance::Schedule` (r:0 w:1)
+	/// Proof: `Issuance::Schedule` (`max_values`: Some(1), `max_size`: Some(21), added: 516, mode: `MaxEncodedLen`)
+	/// Storage: `Issuance::CurrentIssuance` (r:0 w:1)
+	/// Proof: `Issuance::CurrentIssuance` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `Issuance::NextDecay` (r:0 w:1)
+	/// Proof: `Issuance::NextDecay` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	fn set_schedule() -> Weight {
+		// Proof Size estimate in bytes:
+		//  Estimated: `0`
+		Weight::from_parts(15_786_000, 0)
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	/// Storage: `Issuance::Schedule` (r:1 w:0)
+	/// Proof: `Issuance::Schedule` (`max_values`: Some(1), `max_size`: Some(21), added: 516, mode: `MaxEncodedLen`)
+	/// Storage: `Issuance::CurrentIssuance` (r:1 w:1)
+	/// Proof: `Issuance::CurrentIssuance` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `Issuance::NextDecay` (r:1 w:1)
+	/// Proof: `Issuance::NextDecay` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::TotalIssuance` (r:1 w:1)
+	/// Proof: `Balances::TotalIssuance` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(116), added: 2591, mode: `MaxEncodedLen`)
+	fn on_initialize() -> Weight {
+		// Proof Size estimate in bytes:
+		//  Estimated: `6172`
+		Weight::from_parts(85_812_000, 0)
+			.saturating_add(Weight::from_parts(0, 6172))
+			.saturating_add(T::DbWeight::get().reads(6))
+			.saturating_add(T::DbWeight::get().writes(5))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	fn set_schedule() -> Weight {
+		Weight::from_parts(15_786_000, 0)
+			.saturating_add(frame_support::weights::constants::RocksDbWeight::get().writes(3))
+	}
+	fn on_initialize() -> Weight {
+		Weight::from_parts(85_812_000, 6172)
+			.saturating_add(Weight::from_parts(0, 6172))
+			.saturating_add(frame_support::weights::constants::RocksDbWeight::get().reads(6))
+			.saturating_add(frame_support::weights::constants::RocksDbWeight::get().writes(5))
+	}
+}
@@ -0,0 +1,41 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+//! # Outbound Commitments Runtime API
+//!
+//! Runtime API serving the Merkle proofs of the messages committed to in the header digest,
+//! so that relayers can submit them to the Ethereum light client along with the header.
+
+use parity_scale_codec::{Decode, Encode};
+use scale_info::TypeInfo;
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
+use sp_core::H256;
+use sp_runtime::RuntimeDebug;
+use sp_std::vec::Vec;
+
+/// Merkle proof of a message against the commitment of its block
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct MessageProof {
+    /// Merkle root the proof leads to
+    pub root: H256,
+    /// Hashes of the siblings along the path, from the leaf up
+    pub proof: Vec<H256>,
+    /// Number of messages of the block
+    pub number_of_leaves: u32,
+    /// Position of the message among them
+    pub leaf_index: u32,
+    /// Data of the leaf, `abi.encodePacked(uint64 nonce, bytes payload)`
+    pub leaf: Vec<u8>,
+}
+
+sp_api::decl_runtime_apis! {
+    /// Runtime API for the outbound commitments pallet
+    pub trait OutboundCommitmentsApi {
+        /// Merkle proof of the message `nonce`
+        ///
+        /// Call at the block committing to the message, `None` if the block did not queue it.
+        fn prove_message(nonce: u64) -> Option<MessageProof>;
+    }
+}
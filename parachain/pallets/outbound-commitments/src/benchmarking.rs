@@ -0,0 +1,37 @@
+//! Benchmarking setup for pallet-outbound-commitments
+
+use super::*;
+
+#[allow(unused)]
+use crate::Pallet as OutboundCommitments;
+use frame_benchmarking::v2::*;
+use frame_support::traits::Get;
+use sp_std::{vec, vec::Vec};
+
+#[benchmarks]
+mod benchmarks {
+	use super::*;
+
+	#[benchmark]
+	fn commit_messages(n: Linear<0, { T::MaxMessagesPerBlock::get() }>) {
+		let payload: Vec<u8> = vec![0xff; T::MaxMessageSize::get() as usize];
+		for _ in 0..n {
+			OutboundCommitments::<T>::queue_message(payload.clone());
+		}
+
+		#[block]
+		{
+			OutboundCommitments::<T>::commit_messages();
+		}
+
+		// Verify the messages were committed to
+		let digest = frame_system::Pallet::<T>::digest();
+		assert_eq!(OutboundCommitment::find(&digest).is_some(), n > 0);
+	}
+
+	impl_benchmark_test_suite!(
+		OutboundCommitments,
+		crate::mock::new_test_ext(),
+		crate::mock::Test
+	);
+}
@@ -0,0 +1,261 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+//! # Outbound Commitments Pallet
+//!
+//! Outbound messages to Ethereum, committed to in the block headers.
+//!
+//! ## Overview
+//!
+//! Runtime components queue messages for Ethereum with [`Pallet::queue_message`], e.g. the
+//! deployer registry updates mirrored to Ethereum mainnet. At the end of every block
+//! queuing messages, the pallet computes the binary Merkle root of the block's messages and
+//! deposits it as an [`OutboundCommitment`] digest item under
+//! [`OUTBOUND_COMMITMENT_ENGINE_ID`]. An Ethereum light client of the relay chain, once it
+//! verified the parachain header, accepts any message of the block against the root with
+//! the Merkle proof returned by [`Pallet::prove_message`].
+//!
+//! The leaves are `keccak256(abi.encodePacked(uint64 nonce, bytes payload))` and inner nodes
+//! the keccak-256 hash of their two children in order, as built by `binary-merkle-tree`.
+//! Nonces increase by one with every queued message, so that the Ethereum side can apply
+//! the messages in order and notice a missing one.
+//!
+//! ## Features
+//!
+//! - Bounded per-block message queue, cleared at the start of the next block
+//! - Keccak-256 Merkle commitments in the header digest
+//! - Merkle proofs of the messages of a block, at the state of that block
+
+pub use pallet::*;
+pub mod weights;
+
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+#[frame_support::pallet]
+pub mod pallet {
+	use binary_merkle_tree::{merkle_proof, merkle_root};
+	use frame_support::pallet_prelude::*;
+	use frame_system::pallet_prelude::*;
+	use sp_core::H256;
+	use sp_runtime::{traits::Keccak256, ConsensusEngineId, Digest, DigestItem};
+	use sp_std::vec::Vec;
+
+	pub use crate::weights::WeightInfo;
+
+	/// Engine id of the digest items carrying the [`OutboundCommitment`] of a block
+	pub const OUTBOUND_COMMITMENT_ENGINE_ID: ConsensusEngineId = *b"qobc";
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		/// The overarching event type
+		#[allow(deprecated)]
+		type RuntimeEvent: From<Event<Self>>
+			+ IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		/// Maximum size of a message payload in bytes
+		#[pallet::constant]
+		type MaxMessageSize: Get<u32>;
+
+		/// Maximum number of messages committed to in a block
+		#[pallet::constant]
+		type MaxMessagesPerBlock: Get<u32>;
+
+		/// Weight information for extrinsics in this pallet
+		type WeightInfo: WeightInfo;
+	}
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	/// A message queued for Ethereum
+	#[derive(
+		Encode,
+		Decode,
+		CloneNoBound,
+		PartialEqNoBound,
+		EqNoBound,
+		RuntimeDebugNoBound,
+		TypeInfo,
+		MaxEncodedLen,
+	)]
+	#[scale_info(skip_type_params(T))]
+	pub struct OutboundMessage<T: Config> {
+		/// Position of the message among all queued messages
+		pub nonce: u64,
+		/// Payload, decoded by the Ethereum side
+		pub payload: BoundedVec<u8, T::MaxMessageSize>,
+	}
+
+	impl<T: Config> OutboundMessage<T> {
+		/// Data of the Merkle leaf, `abi.encodePacked(uint64 nonce, bytes payload)`
+		pub fn leaf(&self) -> Vec<u8> {
+			let mut leaf = self.nonce.to_be_bytes().to_vec();
+			leaf.extend_from_slice(&self.payload);
+			leaf
+		}
+	}
+
+	/// Commitment to the messages of a block
+	///
+	/// Deposited as a consensus digest item under [`OUTBOUND_COMMITMENT_ENGINE_ID`] in every
+	/// block queuing messages.
+	#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+	pub struct OutboundCommitment {
+		/// Merkle root of the messages
+		pub root: H256,
+		/// Nonce of the first message
+		pub first_nonce: u64,
+		/// Number of messages
+		pub count: u32,
+	}
+
+	impl OutboundCommitment {
+		/// The commitment deposited in a block's digest, if any
+		pub fn find(digest: &Digest) -> Option<Self> {
+			digest
+				.logs()
+				.iter()
+				.find_map(|log| log.consensus_try_to(&OUTBOUND_COMMITMENT_ENGINE_ID))
+		}
+	}
+
+	/// Merkle proof of a message against the commitment of its block
+	#[derive(Clone, PartialEq, Eq, RuntimeDebug)]
+	pub struct MessageProof {
+		/// Merkle root the proof leads to
+		pub root: H256,
+		/// Hashes of the siblings along the path, from the leaf up
+		pub proof: Vec<H256>,
+		/// Number of messages of the block
+		pub number_of_leaves: u32,
+		/// Position of the message among them
+		pub leaf_index: u32,
+		/// Data of the leaf, `abi.encodePacked(uint64 nonce, bytes payload)`
+		pub leaf: Vec<u8>,
+	}
+
+	/// Nonce of the next queued message
+	#[pallet::storage]
+	pub type NextNonce<T: Config> = StorageValue<_, u64, ValueQuery>;
+
+	/// Messages queued in the current block
+	///
+	/// Kept until the start of the next block, so that the state of a block holds the
+	/// messages its commitment covers.
+	#[pallet::storage]
+	pub type Messages<T: Config> =
+		StorageValue<_, BoundedVec<OutboundMessage<T>, T::MaxMessagesPerBlock>, ValueQuery>;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// A message was queued for Ethereum
+		MessageQueued {
+			/// Nonce of the message
+			nonce: u64,
+			/// Payload of the message
+			payload: Vec<u8>,
+		},
+		/// A message could not be queued, as it is too large or the block is full
+		///
+		/// The nonce is still taken, so that the Ethereum side notices the gap.
+		MessageDropped {
+			/// Nonce of the message
+			nonce: u64,
+		},
+		/// The messages of the block were committed to in the digest
+		MessagesCommitted {
+			/// Merkle root of the messages
+			root: H256,
+			/// Number of messages
+			count: u32,
+		},
+	}
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_initialize(_n: BlockNumberFor<T>) -> Weight {
+			Messages::<T>::kill();
+
+			// Account for committing to a full block of messages in `on_finalize`
+			T::WeightInfo::commit_messages(T::MaxMessagesPerBlock::get())
+				.saturating_add(T::DbWeight::get().writes(1))
+		}
+
+		fn on_finalize(_n: BlockNumberFor<T>) {
+			Self::commit_messages();
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// Queue a message for Ethereum, returning its nonce
+		///
+		/// A payload above `MaxMessageSize` or beyond `MaxMessagesPerBlock` messages in the
+		/// block is dropped, with a `MessageDropped` event. Callers must keep the payload
+		/// cheap to build, as the weight of queuing is not accounted to them.
+		pub fn queue_message(payload: Vec<u8>) -> u64 {
+			let nonce = NextNonce::<T>::mutate(|next| {
+				let nonce = *next;
+				*next = next.saturating_add(1);
+				nonce
+			});
+
+			let queued = BoundedVec::try_from(payload.clone()).ok().and_then(|bounded| {
+				Messages::<T>::try_append(OutboundMessage { nonce, payload: bounded }).ok()
+			});
+			match queued {
+				Some(()) => Self::deposit_event(Event::MessageQueued { nonce, payload }),
+				None => Self::deposit_event(Event::MessageDropped { nonce }),
+			}
+
+			nonce
+		}
+
+		/// Merkle proof of the message `nonce` against the commitment of the current block
+		///
+		/// Returns `None` if the message was not queued in this block. Called on the state of
+		/// the block that committed to the message.
+		pub fn prove_message(nonce: u64) -> Option<MessageProof> {
+			let messages = Messages::<T>::get();
+			let index = messages.iter().position(|message| message.nonce == nonce)?;
+			let proof = merkle_proof::<Keccak256, _, _>(
+				messages.iter().map(OutboundMessage::leaf),
+				index as u32,
+			);
+
+			Some(MessageProof {
+				root: proof.root,
+				proof: proof.proof,
+				number_of_leaves: proof.number_of_leaves as u32,
+				leaf_index: proof.leaf_index as u32,
+				leaf: proof.leaf,
+			})
+		}
+
+		/// Deposit the commitment to the messages of the block in the digest
+		pub(crate) fn commit_messages() {
+			let messages = Messages::<T>::get();
+			let Some(first) = messages.first() else {
+				return;
+			};
+
+			let root = merkle_root::<Keccak256, _>(messages.iter().map(OutboundMessage::leaf));
+			let commitment = OutboundCommitment {
+				root,
+				first_nonce: first.nonce,
+				count: messages.len() as u32,
+			};
+			frame_system::Pallet::<T>::deposit_log(DigestItem::Consensus(
+				OUTBOUND_COMMITMENT_ENGINE_ID,
+				commitment.encode(),
+			));
+			Self::deposit_event(Event::MessagesCommitted { root, count: commitment.count });
+		}
+	}
+}
@@ -0,0 +1,106 @@
+use crate::{mock::*, Event, Messages, NextNonce, OutboundCommitment};
+use frame_support::traits::Hooks;
+use sp_core::{keccak_256, H256};
+
+/// Leaf data of a message
+fn leaf(nonce: u64, payload: &[u8]) -> Vec<u8> {
+	let mut leaf = nonce.to_be_bytes().to_vec();
+	leaf.extend_from_slice(payload);
+	leaf
+}
+
+/// Hash of a pair of nodes
+fn node(left: [u8; 32], right: [u8; 32]) -> H256 {
+	H256(keccak_256(&[left, right].concat()))
+}
+
+#[test]
+fn messages_take_consecutive_nonces() {
+	new_test_ext().execute_with(|| {
+		assert_eq!(OutboundCommitments::queue_message(b"first".to_vec()), 0);
+		System::assert_last_event(
+			Event::MessageQueued { nonce: 0, payload: b"first".to_vec() }.into(),
+		);
+		assert_eq!(OutboundCommitments::queue_message(b"second".to_vec()), 1);
+
+		assert_eq!(NextNonce::<Test>::get(), 2);
+		assert_eq!(Messages::<Test>::get().len(), 2);
+	});
+}
+
+#[test]
+fn dropped_messages_keep_their_nonce() {
+	new_test_ext().execute_with(|| {
+		// Above `MaxMessageSize`
+		assert_eq!(OutboundCommitments::queue_message(b"oversized".to_vec()), 0);
+		System::assert_last_event(Event::MessageDropped { nonce: 0 }.into());
+
+		// Beyond `MaxMessagesPerBlock`
+		for _ in 0..3 {
+			OutboundCommitments::queue_message(b"message".to_vec());
+		}
+		assert_eq!(OutboundCommitments::queue_message(b"message".to_vec()), 4);
+		System::assert_last_event(Event::MessageDropped { nonce: 4 }.into());
+
+		let nonces: Vec<_> = Messages::<Test>::get().iter().map(|message| message.nonce).collect();
+		assert_eq!(nonces, vec![1, 2, 3]);
+	});
+}
+
+#[test]
+fn messages_are_committed_to_in_the_digest() {
+	new_test_ext().execute_with(|| {
+		OutboundCommitments::queue_message(b"first".to_vec());
+		OutboundCommitments::queue_message(b"second".to_vec());
+		OutboundCommitments::on_finalize(1);
+
+		let root = node(keccak_256(&leaf(0, b"first")), keccak_256(&leaf(1, b"second")));
+		assert_eq!(
+			OutboundCommitment::find(&System::digest()),
+			Some(OutboundCommitment { root, first_nonce: 0, count: 2 })
+		);
+		System::assert_last_event(Event::MessagesCommitted { root, count: 2 }.into());
+	});
+}
+
+#[test]
+fn blocks_without_messages_carry_no_commitment() {
+	new_test_ext().execute_with(|| {
+		OutboundCommitments::on_finalize(1);
+
+		assert_eq!(OutboundCommitment::find(&System::digest()), None);
+	});
+}
+
+#[test]
+fn proofs_lead_to_the_committed_root() {
+	new_test_ext().execute_with(|| {
+		OutboundCommitments::queue_message(b"first".to_vec());
+		OutboundCommitments::queue_message(b"second".to_vec());
+		OutboundCommitments::on_finalize(1);
+		let commitment = OutboundCommitment::find(&System::digest()).unwrap();
+
+		let proof = OutboundCommitments::prove_message(1).unwrap();
+		assert_eq!(proof.root, commitment.root);
+		assert_eq!((proof.leaf_index, proof.number_of_leaves), (1, 2));
+		assert_eq!(proof.leaf, leaf(1, b"second"));
+		assert_eq!(node(proof.proof[0].0, keccak_256(&proof.leaf)), commitment.root);
+
+		assert!(OutboundCommitments::prove_message(2).is_none());
+	});
+}
+
+#[test]
+fn messages_are_cleared_in_the_next_block() {
+	new_test_ext().execute_with(|| {
+		OutboundCommitments::queue_message(b"first".to_vec());
+		OutboundCommitments::on_finalize(1);
+
+		System::set_block_number(2);
+		OutboundCommitments::on_initialize(2);
+
+		assert!(Messages::<Test>::get().is_empty());
+		assert!(OutboundCommitments::prove_message(0).is_none());
+		assert_eq!(OutboundCommitments::queue_message(b"second".to_vec()), 1);
+	});
+}
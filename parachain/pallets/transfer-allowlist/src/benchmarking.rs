@@ -0,0 +1,66 @@
+//! Benchmarking setup for pallet-transfer-allowlist
+
+use super::*;
+
+#[allow(unused)]
+use crate::Pallet as TransferAllowlist;
+use frame_benchmarking::v2::*;
+use frame_support::traits::EnsureOrigin;
+use sp_core::H160;
+
+#[benchmarks]
+mod benchmarks {
+	use super::*;
+
+	#[benchmark]
+	fn set_mode() {
+		let origin = T::ControlOrigin::try_successful_origin().unwrap();
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, TransferMode::Restricted);
+
+		// Verify the mode was switched
+		assert_eq!(Mode::<T>::get(), TransferMode::Restricted);
+	}
+
+	#[benchmark]
+	fn allow_recipient() {
+		let origin = T::ControlOrigin::try_successful_origin().unwrap();
+		let recipient = H160::repeat_byte(1);
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, recipient);
+
+		// Verify the recipient was allowlisted
+		assert!(AllowedRecipients::<T>::contains_key(recipient));
+	}
+
+	#[benchmark]
+	fn disallow_recipient() {
+		let origin = T::ControlOrigin::try_successful_origin().unwrap();
+		let recipient = H160::repeat_byte(1);
+		AllowedRecipients::<T>::insert(recipient, ());
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, recipient);
+
+		// Verify the recipient was removed
+		assert!(!AllowedRecipients::<T>::contains_key(recipient));
+	}
+
+	#[benchmark]
+	fn clear_allowlist(n: Linear<0, 1_000>) {
+		let origin = T::ControlOrigin::try_successful_origin().unwrap();
+		for index in 0..n {
+			AllowedRecipients::<T>::insert(H160::from_low_u64_be(index.into()), ());
+		}
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, n);
+
+		// Verify the allowlist was emptied
+		assert_eq!(AllowedRecipients::<T>::iter_keys().count(), 0);
+	}
+
+	impl_benchmark_test_suite!(TransferAllowlist, crate::mock::new_test_ext(), crate::mock::Test);
+}
@@ -0,0 +1,261 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+//! # Transfer Allowlist Pallet
+//!
+//! An optional compliance mode restricting who can receive value on the EVM.
+//!
+//! ## Overview
+//!
+//! While the transfer mode is `Restricted`, transactions carrying a non-zero value to an
+//! H160 address are only accepted if the recipient is on the allowlist. In `Open` mode the
+//! allowlist is ignored. The mode is switched by governance, so launch phases can be
+//! restricted and the chain opened later without a runtime upgrade.
+//!
+//! The runtime enforces the allowlist on Ethereum transactions and on
+//! `pallet_evm::Call::call`, by checking [`Pallet::is_transfer_allowed`].
+//!
+//! ## Removal
+//!
+//! Once the chain no longer needs the compliance mode:
+//! 1. Switch to `Open` through `set_mode`, which lifts every restriction at once
+//! 2. Empty the allowlist with `clear_allowlist`, over several blocks if needed
+//! 3. Remove the pallet from the runtime in a later upgrade
+//!
+//! ## Features
+//!
+//! - Governance-controlled switch between open and restricted transfers
+//! - Allowlist of recipient addresses, configurable at genesis
+//! - Bounded clearing of the allowlist once the mode is retired
+
+pub use pallet::*;
+pub mod weights;
+
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+/// Custom `InvalidTransaction` code of transfers rejected by the allowlist
+///
+/// Follows the codes of the deployment control pallet's `DeploymentValidationError`.
+pub const RECIPIENT_NOT_ALLOWED: u8 = 2;
+
+#[frame_support::pallet]
+pub mod pallet {
+	use frame_support::pallet_prelude::*;
+	use frame_system::pallet_prelude::*;
+	use sp_core::{H160, U256};
+	use sp_std::vec::Vec;
+
+	pub use crate::weights::WeightInfo;
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		/// The overarching event type
+		#[allow(deprecated)]
+		type RuntimeEvent: From<Event<Self>>
+			+ IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		/// Origin allowed to switch the mode and manage the allowlist
+		type ControlOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// Weight information for extrinsics in this pallet
+		type WeightInfo: WeightInfo;
+	}
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	/// Whether value transfers are restricted to allowlisted recipients
+	#[derive(
+		Encode, Decode, Clone, Copy, PartialEq, Eq, Default, RuntimeDebug, TypeInfo, MaxEncodedLen,
+	)]
+	pub enum TransferMode {
+		/// Value may be sent to any address
+		#[default]
+		Open,
+		/// Value may only be sent to allowlisted addresses
+		Restricted,
+	}
+
+	/// Transfer mode in effect
+	#[pallet::storage]
+	pub type Mode<T: Config> = StorageValue<_, TransferMode, ValueQuery>;
+
+	/// Addresses allowed to receive value while transfers are restricted
+	#[pallet::storage]
+	pub type AllowedRecipients<T: Config> = StorageMap<_, Identity, H160, (), OptionQuery>;
+
+	#[pallet::genesis_config]
+	#[derive(frame_support::DefaultNoBound)]
+	pub struct GenesisConfig<T: Config> {
+		/// Whether transfers are restricted at launch
+		pub restricted: bool,
+		/// Initial allowlist of recipients
+		pub allowed_recipients: Vec<H160>,
+		#[serde(skip)]
+		pub _config: PhantomData<T>,
+	}
+
+	#[pallet::genesis_build]
+	impl<T: Config> BuildGenesisConfig for GenesisConfig<T> {
+		fn build(&self) {
+			if self.restricted {
+				Mode::<T>::put(TransferMode::Restricted);
+			}
+			for recipient in &self.allowed_recipients {
+				AllowedRecipients::<T>::insert(recipient, ());
+			}
+		}
+	}
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// The transfer mode was changed
+		ModeSet {
+			/// The new mode
+			mode: TransferMode,
+		},
+		/// An address was added to the allowlist
+		RecipientAllowed {
+			/// The allowlisted address
+			recipient: H160,
+		},
+		/// An address was removed from the allowlist
+		RecipientDisallowed {
+			/// The removed address
+			recipient: H160,
+		},
+		/// Entries were removed from the allowlist
+		AllowlistCleared {
+			/// Number of removed entries
+			removed: u32,
+			/// Whether the allowlist is now empty
+			complete: bool,
+		},
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The address is already allowlisted
+		AlreadyAllowed,
+		/// The address is not allowlisted
+		NotAllowed,
+		/// The allowlist can only be cleared while transfers are open
+		TransfersRestricted,
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Switch between open and restricted transfers
+		///
+		/// # Parameters
+		/// - `origin`: Must be `ControlOrigin`
+		/// - `mode`: The new transfer mode
+		///
+		/// # Events
+		/// - `ModeSet`: Emitted when the mode is changed
+		#[pallet::call_index(0)]
+		#[pallet::weight(T::WeightInfo::set_mode())]
+		pub fn set_mode(origin: OriginFor<T>, mode: TransferMode) -> DispatchResult {
+			T::ControlOrigin::ensure_origin(origin)?;
+
+			Mode::<T>::put(mode);
+			Self::deposit_event(Event::ModeSet { mode });
+
+			Ok(())
+		}
+
+		/// Add an address to the allowlist
+		///
+		/// # Parameters
+		/// - `origin`: Must be `ControlOrigin`
+		/// - `recipient`: The address allowed to receive value
+		///
+		/// # Errors
+		/// - `AlreadyAllowed`: The address is already allowlisted
+		///
+		/// # Events
+		/// - `RecipientAllowed`: Emitted when the address is added
+		#[pallet::call_index(1)]
+		#[pallet::weight(T::WeightInfo::allow_recipient())]
+		pub fn allow_recipient(origin: OriginFor<T>, recipient: H160) -> DispatchResult {
+			T::ControlOrigin::ensure_origin(origin)?;
+
+			ensure!(!AllowedRecipients::<T>::contains_key(recipient), Error::<T>::AlreadyAllowed);
+
+			AllowedRecipients::<T>::insert(recipient, ());
+			Self::deposit_event(Event::RecipientAllowed { recipient });
+
+			Ok(())
+		}
+
+		/// Remove an address from the allowlist
+		///
+		/// # Parameters
+		/// - `origin`: Must be `ControlOrigin`
+		/// - `recipient`: The address to remove
+		///
+		/// # Errors
+		/// - `NotAllowed`: The address is not allowlisted
+		///
+		/// # Events
+		/// - `RecipientDisallowed`: Emitted when the address is removed
+		#[pallet::call_index(2)]
+		#[pallet::weight(T::WeightInfo::disallow_recipient())]
+		pub fn disallow_recipient(origin: OriginFor<T>, recipient: H160) -> DispatchResult {
+			T::ControlOrigin::ensure_origin(origin)?;
+
+			ensure!(AllowedRecipients::<T>::contains_key(recipient), Error::<T>::NotAllowed);
+
+			AllowedRecipients::<T>::remove(recipient);
+			Self::deposit_event(Event::RecipientDisallowed { recipient });
+
+			Ok(())
+		}
+
+		/// Remove up to `limit` entries from the allowlist
+		///
+		/// Part of retiring the compliance mode; repeat in later blocks until the event
+		/// reports the allowlist as complete.
+		///
+		/// # Parameters
+		/// - `origin`: Must be `ControlOrigin`
+		/// - `limit`: Maximum number of entries to remove
+		///
+		/// # Errors
+		/// - `TransfersRestricted`: Transfers are still restricted
+		///
+		/// # Events
+		/// - `AllowlistCleared`: Emitted with the number of removed entries
+		#[pallet::call_index(3)]
+		#[pallet::weight(T::WeightInfo::clear_allowlist(*limit))]
+		pub fn clear_allowlist(origin: OriginFor<T>, limit: u32) -> DispatchResult {
+			T::ControlOrigin::ensure_origin(origin)?;
+
+			ensure!(Mode::<T>::get() == TransferMode::Open, Error::<T>::TransfersRestricted);
+
+			let result = AllowedRecipients::<T>::clear(limit, None);
+			Self::deposit_event(Event::AllowlistCleared {
+				removed: result.unique,
+				complete: result.maybe_cursor.is_none(),
+			});
+
+			Ok(())
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// Check if `value` may be sent to `recipient` under the current mode
+		pub fn is_transfer_allowed(recipient: &H160, value: U256) -> bool {
+			value.is_zero() ||
+				Mode::<T>::get() == TransferMode::Open ||
+				AllowedRecipients::<T>::contains_key(recipient)
+		}
+	}
+}
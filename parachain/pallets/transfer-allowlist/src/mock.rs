@@ -0,0 +1,49 @@
+use crate as pallet_transfer_allowlist;
+use frame_support::derive_impl;
+use frame_system::EnsureRoot;
+use sp_core::H160;
+use sp_runtime::{traits::IdentityLookup, BuildStorage};
+
+type Block = frame_system::mocking::MockBlock<Test>;
+
+// Configure a mock runtime to test the pallet.
+frame_support::construct_runtime!(
+	pub enum Test
+	{
+		System: frame_system,
+		TransferAllowlist: pallet_transfer_allowlist,
+	}
+);
+
+#[derive_impl(frame_system::config_preludes::TestDefaultConfig)]
+impl frame_system::Config for Test {
+	type Block = Block;
+	type AccountId = u64;
+	type Lookup = IdentityLookup<Self::AccountId>;
+}
+
+impl pallet_transfer_allowlist::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type ControlOrigin = EnsureRoot<u64>;
+	type WeightInfo = ();
+}
+
+/// Recipient allowlisted at genesis
+pub const ALLOWED: H160 = H160::repeat_byte(0xaa);
+
+// Build genesis storage according to the mock runtime.
+pub fn new_test_ext() -> sp_io::TestExternalities {
+	let mut storage = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
+
+	pallet_transfer_allowlist::GenesisConfig::<Test> {
+		restricted: true,
+		allowed_recipients: vec![ALLOWED],
+		..Default::default()
+	}
+	.assimilate_storage(&mut storage)
+	.unwrap();
+
+	let mut ext: sp_io::TestExternalities = storage.into();
+	ext.execute_with(|| System::set_block_number(1));
+	ext
+}
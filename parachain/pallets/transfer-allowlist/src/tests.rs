@@ -0,0 +1,91 @@
+use crate::{mock::*, AllowedRecipients, Error, Event, Mode, TransferMode};
+use frame_support::{assert_noop, assert_ok};
+use sp_core::{H160, U256};
+use sp_runtime::DispatchError;
+
+const OTHER: H160 = H160::repeat_byte(0xbb);
+
+#[test]
+fn genesis_restricts_transfers() {
+	new_test_ext().execute_with(|| {
+		assert_eq!(Mode::<Test>::get(), TransferMode::Restricted);
+		assert!(TransferAllowlist::is_transfer_allowed(&ALLOWED, U256::from(1)));
+		assert!(!TransferAllowlist::is_transfer_allowed(&OTHER, U256::from(1)));
+	});
+}
+
+#[test]
+fn zero_value_is_always_allowed() {
+	new_test_ext().execute_with(|| {
+		assert!(TransferAllowlist::is_transfer_allowed(&OTHER, U256::zero()));
+	});
+}
+
+#[test]
+fn open_mode_allows_any_recipient() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(TransferAllowlist::set_mode(RuntimeOrigin::root(), TransferMode::Open));
+		System::assert_last_event(Event::ModeSet { mode: TransferMode::Open }.into());
+
+		assert!(TransferAllowlist::is_transfer_allowed(&OTHER, U256::from(1)));
+	});
+}
+
+#[test]
+fn set_mode_requires_control_origin() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			TransferAllowlist::set_mode(RuntimeOrigin::signed(1), TransferMode::Open),
+			DispatchError::BadOrigin
+		);
+	});
+}
+
+#[test]
+fn allow_and_disallow_recipient() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(TransferAllowlist::allow_recipient(RuntimeOrigin::root(), OTHER));
+		System::assert_last_event(Event::RecipientAllowed { recipient: OTHER }.into());
+		assert!(TransferAllowlist::is_transfer_allowed(&OTHER, U256::from(1)));
+
+		assert_noop!(
+			TransferAllowlist::allow_recipient(RuntimeOrigin::root(), OTHER),
+			Error::<Test>::AlreadyAllowed
+		);
+
+		assert_ok!(TransferAllowlist::disallow_recipient(RuntimeOrigin::root(), OTHER));
+		System::assert_last_event(Event::RecipientDisallowed { recipient: OTHER }.into());
+		assert!(!TransferAllowlist::is_transfer_allowed(&OTHER, U256::from(1)));
+
+		assert_noop!(
+			TransferAllowlist::disallow_recipient(RuntimeOrigin::root(), OTHER),
+			Error::<Test>::NotAllowed
+		);
+	});
+}
+
+#[test]
+fn clear_allowlist_requires_open_mode() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			TransferAllowlist::clear_allowlist(RuntimeOrigin::root(), 10),
+			Error::<Test>::TransfersRestricted
+		);
+	});
+}
+
+#[test]
+fn clear_allowlist_removes_entries_in_batches() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(TransferAllowlist::allow_recipient(RuntimeOrigin::root(), OTHER));
+		assert_ok!(TransferAllowlist::set_mode(RuntimeOrigin::root(), TransferMode::Open));
+
+		assert_ok!(TransferAllowlist::clear_allowlist(RuntimeOrigin::root(), 1));
+		System::assert_last_event(Event::AllowlistCleared { removed: 1, complete: false }.into());
+
+		assert_ok!(TransferAllowlist::clear_allowlist(RuntimeOrigin::root(), 1));
+		System::assert_last_event(Event::AllowlistCleared { removed: 1, complete: true }.into());
+
+		assert_eq!(AllowedRecipients::<Test>::iter_keys().count(), 0);
+	});
+}
@@ -0,0 +1,98 @@
+//! Placeholder weights for `pallet_transfer_allowlist`
+//!
+//! NOT MEASURED: the pallet has not been benchmarked yet. The storage accesses and proof
+//! sizes follow the code, while the execution times are hand estimates doubled as a safety
+//! margin, so that blocks are underfilled rather than overfilled until they are measured.
+//! Replace this file with the output of:
+//!
+//! ./target/release/evm-template-node benchmark pallet --chain dev \
+//!     --pallet pallet_transfer_allowlist --extrinsic '*' --steps 50 --repeat 20 \
+//!     --output pallets/transfer-allowlist/src/weights.rs
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+
+use frame_support::{traits::Get, weights::Weight};
+use core::marker::PhantomData;
+
+/// Weight functions needed for pallet_transfer_allowlist.
+pub trait WeightInfo {
+	fn set_mode() -> Weight;
+	fn allow_recipient() -> Weight;
+	fn disallow_recipient() -> Weight;
+	fn clear_allowlist(n: u32, ) -> Weight;
+}
+
+/// Weights for pallet_transfer_allowlist using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	/// Storage: `TransferAllowlist::Mode` (r:0 w:1)
+	/// Proof: `TransferAllowlist::Mode` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	fn set_mode() -> Weight {
+		// Proof Size estimate in bytes:
+		//  Estimated: `0`
+		Weight::from_parts(10_964_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `TransferAllowlist::AllowedRecipients` (r:1 w:1)
+	/// Proof: `TransferAllowlist::AllowedRecipients` (`max_values`: None, `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
+	fn allow_recipient() -> Weight {
+		// Proof Size estimate in bytes:
+		//  Estimated: `3485`
+		Weight::from_parts(19_836_000, 0)
+			.saturating_add(Weight::from_parts(0, 3485))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `TransferAllowlist::AllowedRecipients` (r:1 w:1)
+	/// Proof: `TransferAllowlist::AllowedRecipients` (`max_values`: None, `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
+	fn disallow_recipient() -> Weight {
+		// Proof Size estimate in bytes:
+		//  Estimated: `3485`
+		Weight::from_parts(21_690_000, 0)
+			.saturating_add(Weight::from_parts(0, 3485))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `TransferAllowlist::Mode` (r:1 w:0)
+	/// Proof: `TransferAllowlist::Mode` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `TransferAllowlist::AllowedRecipients` (r:0 w:1000)
+	/// Proof: `TransferAllowlist::AllowedRecipients` (`max_values`: None, `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[0, 1000]`.
+	fn clear_allowlist(n: u32, ) -> Weight {
+		// Proof Size estimate in bytes:
+		//  Estimated: `1486`
+		Weight::from_parts(14_408_000, 0)
+			.saturating_add(Weight::from_parts(0, 1486))
+			// Standard Error: 1_231
+			.saturating_add(Weight::from_parts(2_375_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(n.into())))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	fn set_mode() -> Weight {
+		Weight::from_parts(10_964_000, 0)
+			.saturating_add(frame_support::weights::constants::RocksDbWeight::get().writes(1))
+	}
+	fn allow_recipient() -> Weight {
+		Weight::from_parts(19_836_000, 3485)
+			.saturating_add(frame_support::weights::constants::RocksDbWeight::get().reads(1))
+			.saturating_add(frame_support::weights::constants::RocksDbWeight::get().writes(1))
+	}
+	fn disallow_recipient() -> Weight {
+		Weight::from_parts(21_690_000, 3485)
+			.saturating_add(frame_support::weights::constants::RocksDbWeight::get().reads(1))
+			.saturating_add(frame_support::weights::constants::RocksDbWeight::get().writes(1))
+	}
+	fn clear_allowlist(n: u32, ) -> Weight {
+		Weight::from_parts(14_408_000, 1486)
+			.saturating_add(Weight::from_parts(2_375_000, 0).saturating_mul(n.into()))
+			.saturating_add(frame_support::weights::constants::RocksDbWeight::get().reads(1))
+			.saturating_add(frame_support::weights::constants::RocksDbWeight::get().writes((1_u64).saturating_mul(n.into())))
+	}
+}
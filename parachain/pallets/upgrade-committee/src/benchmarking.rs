@@ -0,0 +1,96 @@
+//! Benchmarking setup for pallet-upgrade-committee
+
+use super::*;
+
+#[allow(unused)]
+use crate::Pallet as UpgradeCommittee;
+use frame_benchmarking::v2::*;
+use frame_support::traits::{EnsureOrigin, Get};
+use frame_system::RawOrigin;
+use sp_runtime::traits::Hash;
+use sp_std::vec::Vec;
+
+/// Name a committee of `MaxApprovals` members requiring all of their approvals
+fn committee<T: Config>() -> Vec<T::AccountId> {
+	let members = accounts::<T>(T::MaxApprovals::get());
+	let threshold = members.len() as u32;
+	Committee::<T>::put(CommitteeInfo {
+		members: BoundedVec::try_from(members.clone()).unwrap(),
+		threshold,
+	});
+	members
+}
+
+fn accounts<T: Config>(count: u32) -> Vec<T::AccountId> {
+	(0..count).map(|index| account("member", index, 0)).collect()
+}
+
+fn code_hash<T: Config>() -> T::Hash {
+	T::Hashing::hash(b"new runtime code")
+}
+
+#[benchmarks]
+mod benchmarks {
+	use super::*;
+
+	#[benchmark]
+	fn propose_upgrade() {
+		let members = committee::<T>();
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(members[0].clone()), code_hash::<T>());
+
+		// Verify the upgrade waits for the other approvals
+		assert_eq!(PendingUpgrade::<T>::get().unwrap().enact_at, None);
+	}
+
+	// The last approval, scheduling the upgrade
+	#[benchmark]
+	fn approve_upgrade() {
+		let members = committee::<T>();
+		let (last, others) = members.split_last().unwrap();
+		let approvals = BoundedVec::try_from(others.to_vec()).unwrap();
+		PendingUpgrade::<T>::put(UpgradeProposal {
+			code_hash: code_hash::<T>(),
+			approvals,
+			enact_at: None,
+		});
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(last.clone()), code_hash::<T>());
+
+		// Verify the upgrade was scheduled
+		assert!(PendingUpgrade::<T>::get().unwrap().enact_at.is_some());
+	}
+
+	#[benchmark]
+	fn cancel_upgrade() {
+		let members = committee::<T>();
+		PendingUpgrade::<T>::put(UpgradeProposal {
+			code_hash: code_hash::<T>(),
+			approvals: BoundedVec::try_from(members).unwrap(),
+			enact_at: Some(frame_system::Pallet::<T>::block_number()),
+		});
+		let origin = T::CancelOrigin::try_successful_origin().unwrap();
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin);
+
+		// Verify the upgrade was cancelled
+		assert!(PendingUpgrade::<T>::get().is_none());
+	}
+
+	#[benchmark]
+	fn set_committee(m: Linear<1, { T::MaxApprovals::get() }>) {
+		let origin = T::CommitteeOrigin::try_successful_origin().unwrap();
+		let members = accounts::<T>(m);
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, members.clone(), m);
+
+		// Verify the committee was named
+		assert_eq!(Committee::<T>::get().unwrap().members.into_inner(), members);
+	}
+
+	impl_benchmark_test_suite!(UpgradeCommittee, crate::mock::new_test_ext(), crate::mock::Test);
+}
@@ -0,0 +1,445 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+//! # Upgrade Committee Pallet
+//!
+//! Runtime code upgrades pre-authorized by an M-of-N committee rather than by sudo alone.
+//!
+//! ## Overview
+//!
+//! A committee member proposes the hash of the new runtime code, counting as its first
+//! approval. Once the proposal collects the committee's threshold of approvals, the
+//! upgrade is scheduled `EnactmentDelay` blocks ahead. Until then, the cancel window, any
+//! committee member or `CancelOrigin` can cancel it.
+//!
+//! At enactment, the code hash is authorized in `frame_system`, so that anyone can submit
+//! the code through `apply_authorized_upgrade`. The runtime wraps its `OnSetCode` in
+//! [`CommitteeSetCode`], which refuses any code but the enacted one, whatever the origin
+//! of the upgrade, `sudo(set_code)` included.
+//!
+//! The committee is kept by the pallet and named by `CommitteeOrigin`, which should be
+//! distinct from the origins whose upgrades the committee checks, sudo included. Only
+//! approvals of current members count. Other pallets read the committee through
+//! [`ApprovalCommittee`].
+
+pub use deployment_control_primitives::ApprovalCommittee;
+pub use pallet::*;
+pub mod weights;
+
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+#[frame_support::pallet]
+pub mod pallet {
+	use frame_support::pallet_prelude::*;
+	use frame_system::{pallet_prelude::*, SetCode};
+	use sp_runtime::traits::Hash;
+	use sp_std::{marker::PhantomData, vec::Vec};
+
+	pub use crate::weights::WeightInfo;
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		/// The overarching event type
+		#[allow(deprecated)]
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		/// Origin naming the committee
+		type CommitteeOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// Origin allowed to cancel a scheduled upgrade, in addition to committee members
+		type CancelOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// Blocks between the approval of an upgrade and its enactment, the cancel window
+		#[pallet::constant]
+		type EnactmentDelay: Get<BlockNumberFor<Self>>;
+
+		/// Maximum number of committee members, and of approvals kept on a proposal
+		#[pallet::constant]
+		type MaxApprovals: Get<u32>;
+
+		/// Weight information for extrinsics in this pallet
+		type WeightInfo: WeightInfo;
+	}
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	/// M-of-N committee approving upgrades
+	#[derive(
+		Encode,
+		Decode,
+		CloneNoBound,
+		PartialEqNoBound,
+		EqNoBound,
+		RuntimeDebugNoBound,
+		TypeInfo,
+		MaxEncodedLen,
+	)]
+	#[scale_info(skip_type_params(T))]
+	#[codec(mel_bound())]
+	pub struct CommitteeInfo<T: Config> {
+		/// Members of the committee
+		pub members: BoundedVec<T::AccountId, T::MaxApprovals>,
+		/// Number of approvals an upgrade requires
+		pub threshold: u32,
+	}
+
+	/// A proposed runtime upgrade
+	#[derive(
+		Encode,
+		Decode,
+		CloneNoBound,
+		PartialEqNoBound,
+		EqNoBound,
+		RuntimeDebugNoBound,
+		TypeInfo,
+		MaxEncodedLen,
+	)]
+	#[scale_info(skip_type_params(T))]
+	#[codec(mel_bound())]
+	pub struct UpgradeProposal<T: Config> {
+		/// Hash of the new runtime code
+		pub code_hash: T::Hash,
+		/// Members who approved the upgrade, the proposer included
+		pub approvals: BoundedVec<T::AccountId, T::MaxApprovals>,
+		/// Block at which the upgrade is enacted, once approved
+		pub enact_at: Option<BlockNumberFor<T>>,
+	}
+
+	/// Committee approving upgrades, `None` until one is named
+	#[pallet::storage]
+	pub type Committee<T: Config> = StorageValue<_, CommitteeInfo<T>, OptionQuery>;
+
+	/// The upgrade being approved or waiting for its enactment
+	#[pallet::storage]
+	pub type PendingUpgrade<T: Config> = StorageValue<_, UpgradeProposal<T>, OptionQuery>;
+
+	/// Hash of the enacted runtime code, the only code that can be set
+	#[pallet::storage]
+	pub type EnactedCodeHash<T: Config> = StorageValue<_, T::Hash, OptionQuery>;
+
+	#[pallet::genesis_config]
+	#[derive(frame_support::DefaultNoBound)]
+	pub struct GenesisConfig<T: Config> {
+		/// Committee members at launch, no committee being named if empty
+		pub members: Vec<T::AccountId>,
+		/// Number of approvals an upgrade requires
+		pub threshold: u32,
+	}
+
+	#[pallet::genesis_build]
+	impl<T: Config> BuildGenesisConfig for GenesisConfig<T> {
+		fn build(&self) {
+			if self.members.is_empty() {
+				return;
+			}
+			let committee = Pallet::<T>::check_committee(self.members.clone(), self.threshold)
+				.expect("Genesis upgrade committee is invalid");
+			Committee::<T>::put(committee);
+		}
+	}
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// An upgrade was proposed
+		UpgradeProposed { code_hash: T::Hash, proposer: T::AccountId },
+		/// A committee member approved the pending upgrade
+		UpgradeApproved { code_hash: T::Hash, approver: T::AccountId, approvals: u32 },
+		/// The pending upgrade collected enough approvals and will be enacted
+		UpgradeScheduled { code_hash: T::Hash, enact_at: BlockNumberFor<T> },
+		/// The pending upgrade was cancelled
+		UpgradeCancelled { code_hash: T::Hash },
+		/// The upgrade was enacted, its code may now be set
+		UpgradeEnacted { code_hash: T::Hash },
+		/// The enacted code was set
+		UpgradeApplied { code_hash: T::Hash },
+		/// The committee was named
+		CommitteeSet { members: BoundedVec<T::AccountId, T::MaxApprovals>, threshold: u32 },
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// No committee is named
+		NoCommittee,
+		/// The caller is not a committee member
+		NotMember,
+		/// Another upgrade is pending
+		UpgradePending,
+		/// No upgrade is pending
+		NoPendingUpgrade,
+		/// The code hash is not the one of the pending upgrade
+		WrongCodeHash,
+		/// The caller already approved the pending upgrade
+		AlreadyApproved,
+		/// The pending upgrade is already scheduled
+		AlreadyScheduled,
+		/// The code was not enacted by the committee
+		UpgradeNotEnacted,
+		/// The committee has more than `MaxApprovals` members
+		TooManyMembers,
+		/// An account is named twice in the committee
+		DuplicateMember,
+		/// The threshold is zero or exceeds the number of members
+		InvalidThreshold,
+	}
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_initialize(n: BlockNumberFor<T>) -> Weight {
+			let Some(proposal) = PendingUpgrade::<T>::get() else {
+				return T::DbWeight::get().reads(1);
+			};
+			if proposal.enact_at.map_or(true, |enact_at| enact_at > n) {
+				return T::DbWeight::get().reads(1);
+			}
+
+			PendingUpgrade::<T>::kill();
+			EnactedCodeHash::<T>::put(proposal.code_hash);
+			frame_system::Pallet::<T>::do_authorize_upgrade(proposal.code_hash, true);
+			Self::deposit_event(Event::UpgradeEnacted { code_hash: proposal.code_hash });
+
+			T::DbWeight::get().reads_writes(1, 3)
+		}
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Propose a runtime upgrade, counting as the proposer's approval
+		///
+		/// The upgrade is scheduled at once if the committee requires a single approval.
+		///
+		/// # Parameters
+		/// - `origin`: Must be signed by a committee member
+		/// - `code_hash`: Hash of the new runtime code
+		///
+		/// # Errors
+		/// - `NoCommittee`: No committee is named
+		/// - `NotMember`: The caller is not a committee member
+		/// - `UpgradePending`: Another upgrade is pending
+		///
+		/// # Events
+		/// - `UpgradeProposed`: Emitted when the upgrade is proposed
+		/// - `UpgradeScheduled`: Emitted if the upgrade is approved at once
+		#[pallet::call_index(0)]
+		#[pallet::weight(T::WeightInfo::propose_upgrade())]
+		pub fn propose_upgrade(origin: OriginFor<T>, code_hash: T::Hash) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let threshold = Self::ensure_member(&who)?;
+			ensure!(!PendingUpgrade::<T>::exists(), Error::<T>::UpgradePending);
+
+			let mut proposal = UpgradeProposal {
+				code_hash,
+				approvals: BoundedVec::truncate_from(sp_std::vec![who.clone()]),
+				enact_at: None,
+			};
+			Self::deposit_event(Event::UpgradeProposed { code_hash, proposer: who });
+			Self::schedule_if_approved(&mut proposal, threshold);
+			PendingUpgrade::<T>::put(proposal);
+
+			Ok(())
+		}
+
+		/// Approve the pending upgrade
+		///
+		/// The upgrade is scheduled `EnactmentDelay` blocks ahead once it collects the
+		/// committee's threshold of approvals.
+		///
+		/// # Parameters
+		/// - `origin`: Must be signed by a committee member
+		/// - `code_hash`: Hash of the code of the pending upgrade
+		///
+		/// # Errors
+		/// - `NoCommittee`: No committee is named
+		/// - `NotMember`: The caller is not a committee member
+		/// - `NoPendingUpgrade`: No upgrade is pending
+		/// - `WrongCodeHash`: The code hash is not the one of the pending upgrade
+		/// - `AlreadyScheduled`: The pending upgrade is already scheduled
+		/// - `AlreadyApproved`: The caller already approved the pending upgrade
+		///
+		/// # Events
+		/// - `UpgradeApproved`: Emitted with the number of collected approvals
+		/// - `UpgradeScheduled`: Emitted when the threshold is reached
+		#[pallet::call_index(1)]
+		#[pallet::weight(T::WeightInfo::approve_upgrade())]
+		pub fn approve_upgrade(origin: OriginFor<T>, code_hash: T::Hash) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let threshold = Self::ensure_member(&who)?;
+			PendingUpgrade::<T>::try_mutate(|proposal| {
+				let proposal = proposal.as_mut().ok_or(Error::<T>::NoPendingUpgrade)?;
+				ensure!(proposal.code_hash == code_hash, Error::<T>::WrongCodeHash);
+				ensure!(proposal.enact_at.is_none(), Error::<T>::AlreadyScheduled);
+				ensure!(!proposal.approvals.contains(&who), Error::<T>::AlreadyApproved);
+
+				// Approvals of accounts removed from the committee no longer count, dropping
+				// them keeps the list within `MaxApprovals`
+				proposal.approvals.retain(|approver| Self::is_member(approver));
+				proposal.approvals.try_push(who.clone()).map_err(|_| Error::<T>::NotMember)?;
+				Self::deposit_event(Event::UpgradeApproved {
+					code_hash,
+					approver: who,
+					approvals: proposal.approvals.len() as u32,
+				});
+				Self::schedule_if_approved(proposal, threshold);
+
+				Ok::<_, Error<T>>(())
+			})?;
+
+			Ok(())
+		}
+
+		/// Cancel the pending upgrade, until it is enacted
+		///
+		/// # Parameters
+		/// - `origin`: Must be `CancelOrigin` or signed by a committee member
+		///
+		/// # Errors
+		/// - `NotMember`: The caller is not a committee member
+		/// - `NoPendingUpgrade`: No upgrade is pending, e.g. it was already enacted
+		///
+		/// # Events
+		/// - `UpgradeCancelled`: Emitted when the upgrade is cancelled
+		#[pallet::call_index(2)]
+		#[pallet::weight(T::WeightInfo::cancel_upgrade())]
+		pub fn cancel_upgrade(origin: OriginFor<T>) -> DispatchResult {
+			if let Err(origin) = T::CancelOrigin::try_origin(origin) {
+				let who = ensure_signed(origin)?;
+				ensure!(Self::is_member(&who), Error::<T>::NotMember);
+			}
+
+			let proposal = PendingUpgrade::<T>::take().ok_or(Error::<T>::NoPendingUpgrade)?;
+			Self::deposit_event(Event::UpgradeCancelled { code_hash: proposal.code_hash });
+
+			Ok(())
+		}
+
+		/// Name the committee approving upgrades
+		///
+		/// Approvals of accounts leaving the committee no longer count towards a pending
+		/// upgrade that is not scheduled yet.
+		///
+		/// # Parameters
+		/// - `origin`: Must be `CommitteeOrigin`
+		/// - `members`: Members of the committee
+		/// - `threshold`: Number of approvals an upgrade requires
+		///
+		/// # Errors
+		/// - `TooManyMembers`: More than `MaxApprovals` members are named
+		/// - `DuplicateMember`: An account is named twice
+		/// - `InvalidThreshold`: The threshold is zero or exceeds the number of members
+		///
+		/// # Events
+		/// - `CommitteeSet`: Emitted when the committee is named
+		#[pallet::call_index(3)]
+		#[pallet::weight(T::WeightInfo::set_committee(members.len() as u32))]
+		pub fn set_committee(
+			origin: OriginFor<T>,
+			members: Vec<T::AccountId>,
+			threshold: u32,
+		) -> DispatchResult {
+			T::CommitteeOrigin::ensure_origin(origin)?;
+
+			let committee = Self::check_committee(members, threshold)?;
+			Self::deposit_event(Event::CommitteeSet {
+				members: committee.members.clone(),
+				threshold,
+			});
+			Committee::<T>::put(committee);
+
+			Ok(())
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// Check if an account is a committee member
+		pub fn is_member(who: &T::AccountId) -> bool {
+			Committee::<T>::get().map_or(false, |committee| committee.members.contains(who))
+		}
+
+		/// Check that an account is a committee member
+		///
+		/// # Returns
+		/// The number of approvals required by the committee
+		fn ensure_member(who: &T::AccountId) -> Result<u32, Error<T>> {
+			let committee = Committee::<T>::get().ok_or(Error::<T>::NoCommittee)?;
+			ensure!(committee.members.contains(who), Error::<T>::NotMember);
+			Ok(committee.threshold)
+		}
+
+		/// Check a committee of `members` requiring `threshold` approvals
+		fn check_committee(
+			members: Vec<T::AccountId>,
+			threshold: u32,
+		) -> Result<CommitteeInfo<T>, Error<T>> {
+			let members: BoundedVec<_, T::MaxApprovals> =
+				members.try_into().map_err(|_| Error::<T>::TooManyMembers)?;
+			ensure!(
+				members.iter().enumerate().all(|(i, member)| !members[..i].contains(member)),
+				Error::<T>::DuplicateMember
+			);
+			ensure!(
+				threshold > 0 && threshold as usize <= members.len(),
+				Error::<T>::InvalidThreshold
+			);
+			Ok(CommitteeInfo { members, threshold })
+		}
+
+		/// Schedule the enactment of a proposal approved by enough current members
+		fn schedule_if_approved(proposal: &mut UpgradeProposal<T>, threshold: u32) {
+			let approvals = proposal
+				.approvals
+				.iter()
+				.filter(|approver| Self::is_member(approver))
+				.count();
+			if (approvals as u32) < threshold {
+				return;
+			}
+
+			let enact_at =
+				frame_system::Pallet::<T>::block_number().saturating_add(T::EnactmentDelay::get());
+			proposal.enact_at = Some(enact_at);
+			Self::deposit_event(Event::UpgradeScheduled {
+				code_hash: proposal.code_hash,
+				enact_at,
+			});
+		}
+	}
+
+	/// `OnSetCode` setting only the code enacted by the committee, through `Inner`
+	///
+	/// Every path to a new runtime code, `set_code`, `set_code_without_checks` and
+	/// `apply_authorized_upgrade`, goes through `OnSetCode`, whatever its origin.
+	pub struct CommitteeSetCode<T, Inner>(PhantomData<(T, Inner)>);
+
+	impl<T: Config, Inner: SetCode<T>> SetCode<T> for CommitteeSetCode<T, Inner> {
+		fn set_code(code: Vec<u8>) -> DispatchResult {
+			let code_hash = T::Hashing::hash(&code);
+			ensure!(EnactedCodeHash::<T>::get() == Some(code_hash), Error::<T>::UpgradeNotEnacted);
+
+			Inner::set_code(code)?;
+			EnactedCodeHash::<T>::kill();
+			Pallet::<T>::deposit_event(Event::UpgradeApplied { code_hash });
+
+			Ok(())
+		}
+	}
+}
+
+/// The committee kept by the pallet
+impl<T: Config> ApprovalCommittee<T::AccountId> for Pallet<T> {
+	fn is_member(who: &T::AccountId) -> bool {
+		Pallet::<T>::is_member(who)
+	}
+
+	fn threshold() -> Option<u32> {
+		Committee::<T>::get().map(|committee| committee.threshold)
+	}
+}
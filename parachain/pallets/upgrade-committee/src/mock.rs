@@ -0,0 +1,55 @@
+use crate as pallet_upgrade_committee;
+use frame_support::{derive_impl, ord_parameter_types, parameter_types, traits::ConstU32};
+use frame_system::{EnsureRoot, EnsureSignedBy};
+use sp_runtime::{traits::IdentityLookup, BuildStorage};
+
+type Block = frame_system::mocking::MockBlock<Test>;
+
+// Configure a mock runtime to test the pallet.
+frame_support::construct_runtime!(
+	pub enum Test
+	{
+		System: frame_system,
+		UpgradeCommittee: pallet_upgrade_committee,
+	}
+);
+
+#[derive_impl(frame_system::config_preludes::TestDefaultConfig)]
+impl frame_system::Config for Test {
+	type Block = Block;
+	type AccountId = u64;
+	type Lookup = IdentityLookup<Self::AccountId>;
+	type OnSetCode = pallet_upgrade_committee::CommitteeSetCode<Test, ()>;
+}
+
+parameter_types! {
+	pub const EnactmentDelay: u64 = 10;
+}
+
+ord_parameter_types! {
+	/// Account naming the committee
+	pub const CommitteeManager: u64 = 10;
+}
+
+impl pallet_upgrade_committee::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type CommitteeOrigin = EnsureSignedBy<CommitteeManager, u64>;
+	type CancelOrigin = EnsureRoot<u64>;
+	type EnactmentDelay = EnactmentDelay;
+	type MaxApprovals = ConstU32<4>;
+	type WeightInfo = ();
+}
+
+// Build genesis storage according to the mock runtime, with a committee of accounts 1, 2
+// and 3 requiring two approvals.
+pub fn new_test_ext() -> sp_io::TestExternalities {
+	let mut storage = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
+
+	pallet_upgrade_committee::GenesisConfig::<Test> { members: vec![1, 2, 3], threshold: 2 }
+		.assimilate_storage(&mut storage)
+		.unwrap();
+
+	let mut ext: sp_io::TestExternalities = storage.into();
+	ext.execute_with(|| System::set_block_number(1));
+	ext
+}
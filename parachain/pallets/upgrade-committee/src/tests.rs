@@ -0,0 +1,252 @@
+use crate::{mock::*, Committee, EnactedCodeHash, Error, Event, PendingUpgrade};
+use frame_support::{assert_noop, assert_ok, traits::Hooks};
+use sp_core::H256;
+use sp_runtime::{
+	traits::{BlakeTwo256, Hash},
+	DispatchError,
+};
+
+fn run_to_block(n: u64) {
+	while System::block_number() < n {
+		System::set_block_number(System::block_number() + 1);
+		UpgradeCommittee::on_initialize(System::block_number());
+	}
+}
+
+fn code() -> Vec<u8> {
+	b"new runtime code".to_vec()
+}
+
+fn code_hash() -> H256 {
+	BlakeTwo256::hash(&code())
+}
+
+/// Name a committee of `members` through the committee origin
+fn set_committee(members: Vec<u64>, threshold: u32) {
+	assert_ok!(UpgradeCommittee::set_committee(RuntimeOrigin::signed(10), members, threshold));
+}
+
+/// Propose the upgrade by account 1 and approve it by account 2, scheduling it
+fn schedule_upgrade() {
+	assert_ok!(UpgradeCommittee::propose_upgrade(RuntimeOrigin::signed(1), code_hash()));
+	assert_ok!(UpgradeCommittee::approve_upgrade(RuntimeOrigin::signed(2), code_hash()));
+}
+
+#[test]
+fn upgrade_is_enacted_after_threshold_and_delay() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(UpgradeCommittee::propose_upgrade(RuntimeOrigin::signed(1), code_hash()));
+		System::assert_last_event(
+			Event::UpgradeProposed { code_hash: code_hash(), proposer: 1 }.into(),
+		);
+		assert_eq!(PendingUpgrade::<Test>::get().unwrap().enact_at, None);
+
+		assert_ok!(UpgradeCommittee::approve_upgrade(RuntimeOrigin::signed(2), code_hash()));
+		System::assert_has_event(
+			Event::UpgradeApproved { code_hash: code_hash(), approver: 2, approvals: 2 }.into(),
+		);
+		System::assert_last_event(
+			Event::UpgradeScheduled { code_hash: code_hash(), enact_at: 11 }.into(),
+		);
+
+		run_to_block(10);
+		assert!(EnactedCodeHash::<Test>::get().is_none());
+
+		run_to_block(11);
+		System::assert_last_event(Event::UpgradeEnacted { code_hash: code_hash() }.into());
+		assert!(PendingUpgrade::<Test>::get().is_none());
+		assert_eq!(EnactedCodeHash::<Test>::get(), Some(code_hash()));
+		System::assert_has_event(
+			frame_system::Event::UpgradeAuthorized { code_hash: code_hash(), check_version: true }
+				.into(),
+		);
+	});
+}
+
+#[test]
+fn only_enacted_code_can_be_set() {
+	new_test_ext().execute_with(|| {
+		// Root alone cannot set code
+		assert_noop!(
+			System::set_code_without_checks(RuntimeOrigin::root(), code()),
+			Error::<Test>::UpgradeNotEnacted
+		);
+
+		schedule_upgrade();
+		run_to_block(11);
+		assert_noop!(
+			System::set_code_without_checks(RuntimeOrigin::root(), b"other code".to_vec()),
+			Error::<Test>::UpgradeNotEnacted
+		);
+
+		assert_ok!(System::set_code_without_checks(RuntimeOrigin::root(), code()));
+		System::assert_has_event(Event::UpgradeApplied { code_hash: code_hash() }.into());
+		assert!(EnactedCodeHash::<Test>::get().is_none());
+		System::assert_has_event(frame_system::Event::CodeUpdated.into());
+
+		// The enactment is used up
+		assert_noop!(
+			System::set_code_without_checks(RuntimeOrigin::root(), code()),
+			Error::<Test>::UpgradeNotEnacted
+		);
+	});
+}
+
+#[test]
+fn scheduled_upgrade_can_be_cancelled_until_enacted() {
+	new_test_ext().execute_with(|| {
+		schedule_upgrade();
+		assert_noop!(
+			UpgradeCommittee::cancel_upgrade(RuntimeOrigin::signed(4)),
+			Error::<Test>::NotMember
+		);
+		assert_noop!(
+			UpgradeCommittee::cancel_upgrade(RuntimeOrigin::none()),
+			DispatchError::BadOrigin
+		);
+
+		run_to_block(10);
+		assert_ok!(UpgradeCommittee::cancel_upgrade(RuntimeOrigin::signed(3)));
+		System::assert_last_event(Event::UpgradeCancelled { code_hash: code_hash() }.into());
+
+		run_to_block(11);
+		assert!(EnactedCodeHash::<Test>::get().is_none());
+
+		// The cancel window closes at enactment
+		schedule_upgrade();
+		run_to_block(21);
+		assert_noop!(
+			UpgradeCommittee::cancel_upgrade(RuntimeOrigin::root()),
+			Error::<Test>::NoPendingUpgrade
+		);
+	});
+}
+
+#[test]
+fn cancel_origin_can_cancel() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(UpgradeCommittee::propose_upgrade(RuntimeOrigin::signed(1), code_hash()));
+		assert_ok!(UpgradeCommittee::cancel_upgrade(RuntimeOrigin::root()));
+		assert!(PendingUpgrade::<Test>::get().is_none());
+	});
+}
+
+#[test]
+fn proposals_are_restricted_to_the_committee() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			UpgradeCommittee::propose_upgrade(RuntimeOrigin::root(), code_hash()),
+			DispatchError::BadOrigin
+		);
+		assert_noop!(
+			UpgradeCommittee::propose_upgrade(RuntimeOrigin::signed(4), code_hash()),
+			Error::<Test>::NotMember
+		);
+
+		assert_ok!(UpgradeCommittee::propose_upgrade(RuntimeOrigin::signed(1), code_hash()));
+		assert_noop!(
+			UpgradeCommittee::propose_upgrade(RuntimeOrigin::signed(2), H256::repeat_byte(1)),
+			Error::<Test>::UpgradePending
+		);
+
+		Committee::<Test>::kill();
+		assert_noop!(
+			UpgradeCommittee::approve_upgrade(RuntimeOrigin::signed(2), code_hash()),
+			Error::<Test>::NoCommittee
+		);
+	});
+}
+
+#[test]
+fn approvals_are_checked() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			UpgradeCommittee::approve_upgrade(RuntimeOrigin::signed(2), code_hash()),
+			Error::<Test>::NoPendingUpgrade
+		);
+
+		assert_ok!(UpgradeCommittee::propose_upgrade(RuntimeOrigin::signed(1), code_hash()));
+		assert_noop!(
+			UpgradeCommittee::approve_upgrade(RuntimeOrigin::signed(1), code_hash()),
+			Error::<Test>::AlreadyApproved
+		);
+		assert_noop!(
+			UpgradeCommittee::approve_upgrade(RuntimeOrigin::signed(2), H256::repeat_byte(1)),
+			Error::<Test>::WrongCodeHash
+		);
+		assert_noop!(
+			UpgradeCommittee::approve_upgrade(RuntimeOrigin::signed(4), code_hash()),
+			Error::<Test>::NotMember
+		);
+
+		assert_ok!(UpgradeCommittee::approve_upgrade(RuntimeOrigin::signed(2), code_hash()));
+		assert_noop!(
+			UpgradeCommittee::approve_upgrade(RuntimeOrigin::signed(3), code_hash()),
+			Error::<Test>::AlreadyScheduled
+		);
+	});
+}
+
+#[test]
+fn approvals_of_removed_members_do_not_count() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(UpgradeCommittee::propose_upgrade(RuntimeOrigin::signed(1), code_hash()));
+		set_committee(vec![2, 3], 2);
+
+		assert_ok!(UpgradeCommittee::approve_upgrade(RuntimeOrigin::signed(2), code_hash()));
+		assert_eq!(PendingUpgrade::<Test>::get().unwrap().enact_at, None);
+
+		assert_ok!(UpgradeCommittee::approve_upgrade(RuntimeOrigin::signed(3), code_hash()));
+		assert_eq!(PendingUpgrade::<Test>::get().unwrap().enact_at, Some(11));
+	});
+}
+
+#[test]
+fn single_approval_committee_schedules_at_once() {
+	new_test_ext().execute_with(|| {
+		set_committee(vec![1], 1);
+		assert_ok!(UpgradeCommittee::propose_upgrade(RuntimeOrigin::signed(1), code_hash()));
+		System::assert_last_event(
+			Event::UpgradeScheduled { code_hash: code_hash(), enact_at: 11 }.into(),
+		);
+	});
+}
+
+#[test]
+fn committee_is_named_by_its_origin() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			UpgradeCommittee::set_committee(RuntimeOrigin::root(), vec![4, 5], 2),
+			DispatchError::BadOrigin
+		);
+		assert_noop!(
+			UpgradeCommittee::set_committee(RuntimeOrigin::signed(1), vec![4, 5], 2),
+			DispatchError::BadOrigin
+		);
+
+		let manager = RuntimeOrigin::signed(10);
+		assert_noop!(
+			UpgradeCommittee::set_committee(manager.clone(), vec![1, 2, 3, 4, 5], 2),
+			Error::<Test>::TooManyMembers
+		);
+		assert_noop!(
+			UpgradeCommittee::set_committee(manager.clone(), vec![4, 5, 4], 2),
+			Error::<Test>::DuplicateMember
+		);
+		assert_noop!(
+			UpgradeCommittee::set_committee(manager.clone(), vec![4, 5], 0),
+			Error::<Test>::InvalidThreshold
+		);
+		assert_noop!(
+			UpgradeCommittee::set_committee(manager.clone(), vec![4, 5], 3),
+			Error::<Test>::InvalidThreshold
+		);
+
+		assert_ok!(UpgradeCommittee::set_committee(manager, vec![4, 5], 2));
+		System::assert_last_event(
+			Event::CommitteeSet { members: vec![4, 5].try_into().unwrap(), threshold: 2 }.into(),
+		);
+		assert!(UpgradeCommittee::is_member(&4));
+		assert!(!UpgradeCommittee::is_member(&1));
+	});
+}
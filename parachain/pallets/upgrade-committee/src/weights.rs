@@ -0,0 +1,105 @@
+//! Placeholder weights for `pallet_upgrade_committee`
+//!
+//! NOT MEASURED: the pallet has not been benchmarked yet. The storage accesses and proof
+//! sizes follow the code, while the execution times are hand estimates doubled as a safety
+//! margin, so that blocks are underfilled rather than overfilled until they are measured.
+//! Replace this file with the output of:
+//!
+//! ./target/release/evm-template-node benchmark pallet --chain dev \
+//!     --pallet pallet_upgrade_committee --extrinsic '*' --steps 50 --repeat 20 \
+//!     --output pallets/upgrade-committee/src/weights.rs
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+
+use frame_support::{traits::Get, weights::Weight};
+use core::marker::PhantomData;
+
+/// Weight functions needed for pallet_upgrade_committee.
+pub trait WeightInfo {
+	fn propose_upgrade() -> Weight;
+	fn approve_upgrade() -> Weight;
+	fn cancel_upgrade() -> Weight;
+	fn set_committee(m: u32, ) -> Weight;
+}
+
+/// Weights for pallet_upgrade_committee using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	/// Storage: `UpgradeCommittee::Committee` (r:1 w:0)
+	/// Proof: `UpgradeCommittee::Committee` (`max_values`: Some(1), `max_size`: Some(517), added: 1012, mode: `MaxEncodedLen`)
+	/// Storage: `UpgradeCommittee::PendingUpgrade` (r:1 w:1)
+	/// Proof: `UpgradeCommittee::PendingUpgrade` (`max_values`: Some(1), `max_size`: Some(550), added: 1045, mode: `MaxEncodedLen`)
+	fn propose_upgrade() -> Weight {
+		// Proof Size estimate in bytes:
+		//  Estimated: `2035`
+		Weight::from_parts(44_208_000, 0)
+			.saturating_add(Weight::from_parts(0, 2035))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `UpgradeCommittee::Committee` (r:1 w:0)
+	/// Proof: `UpgradeCommittee::Committee` (`max_values`: Some(1), `max_size`: Some(517), added: 1012, mode: `MaxEncodedLen`)
+	/// Storage: `UpgradeCommittee::PendingUpgrade` (r:1 w:1)
+	/// Proof: `UpgradeCommittee::PendingUpgrade` (`max_values`: Some(1), `max_size`: Some(550), added: 1045, mode: `MaxEncodedLen`)
+	fn approve_upgrade() -> Weight {
+		// Proof Size estimate in bytes:
+		//  Estimated: `2035`
+		Weight::from_parts(57_822_000, 0)
+			.saturating_add(Weight::from_parts(0, 2035))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `UpgradeCommittee::Committee` (r:1 w:0)
+	/// Proof: `UpgradeCommittee::Committee` (`max_values`: Some(1), `max_size`: Some(517), added: 1012, mode: `MaxEncodedLen`)
+	/// Storage: `UpgradeCommittee::PendingUpgrade` (r:1 w:1)
+	/// Proof: `UpgradeCommittee::PendingUpgrade` (`max_values`: Some(1), `max_size`: Some(550), added: 1045, mode: `MaxEncodedLen`)
+	fn cancel_upgrade() -> Weight {
+		// Proof Size estimate in bytes:
+		//  Estimated: `2035`
+		Weight::from_parts(27_216_000, 0)
+			.saturating_add(Weight::from_parts(0, 2035))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `UpgradeCommittee::Committee` (r:0 w:1)
+	/// Proof: `UpgradeCommittee::Committee` (`max_values`: Some(1), `max_size`: Some(517), added: 1012, mode: `MaxEncodedLen`)
+	/// The range of component `m` is `[1, 16]`.
+	fn set_committee(m: u32, ) -> Weight {
+		// Proof Size estimate in bytes:
+		//  Estimated: `0`
+		Weight::from_parts(17_404_000, 0)
+			// Standard Error: 1_310
+			.saturating_add(Weight::from_parts(193_000, 0).saturating_mul(m.into()))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	fn propose_upgrade() -> Weight {
+		Weight::from_parts(44_208_000, 2035)
+			.saturating_add(Weight::from_parts(0, 2035))
+			.saturating_add(frame_support::weights::constants::RocksDbWeight::get().reads(2))
+			.saturating_add(frame_support::weights::constants::RocksDbWeight::get().writes(1))
+	}
+	fn approve_upgrade() -> Weight {
+		Weight::from_parts(57_822_000, 2035)
+			.saturating_add(Weight::from_parts(0, 2035))
+			.saturating_add(frame_support::weights::constants::RocksDbWeight::get().reads(2))
+			.saturating_add(frame_support::weights::constants::RocksDbWeight::get().writes(1))
+	}
+	fn cancel_upgrade() -> Weight {
+		Weight::from_parts(27_216_000, 2035)
+			.saturating_add(Weight::from_parts(0, 2035))
+			.saturating_add(frame_support::weights::constants::RocksDbWeight::get().reads(2))
+			.saturating_add(frame_support::weights::constants::RocksDbWeight::get().writes(1))
+	}
+	fn set_committee(m: u32, ) -> Weight {
+		Weight::from_parts(17_404_000, 0)
+			.saturating_add(Weight::from_parts(193_000, 0).saturating_mul(m.into()))
+			.saturating_add(frame_support::weights::constants::RocksDbWeight::get().writes(1))
+	}
+}
@@ -0,0 +1,26 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+//! # Address Mapping Runtime API
+//!
+//! Runtime API converting between the EVM addresses and the native accounts of the chain,
+//! so that wallets and explorers can show users which account an EVM address funds and
+//! which address an account is known by before they transfer to the wrong one.
+
+use parity_scale_codec::Codec;
+use sp_core::H160;
+
+sp_api::decl_runtime_apis! {
+    /// Runtime API for the mapping of EVM addresses to accounts
+    pub trait AddressMappingApi<AccountId>
+    where
+        AccountId: Codec,
+    {
+        /// Account holding the native balance of the EVM address `address`
+        fn account_of(address: H160) -> AccountId;
+
+        /// EVM address whose account is `account`
+        ///
+        /// `None` if the address mapping is one-way and no address of the account is known.
+        fn address_of(account: AccountId) -> Option<H160>;
+    }
+}
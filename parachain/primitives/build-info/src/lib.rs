@@ -0,0 +1,33 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+//! # Build Info Runtime API
+//!
+//! Runtime API exposing how the runtime was built, so that the upgrade verification process
+//! can check that a runtime was compiled with the expected toolchain and features before
+//! comparing its code hash with a local deterministic build.
+//!
+//! The information is recorded by the runtime's build script when the Wasm blob is compiled.
+
+use parity_scale_codec::{Decode, Encode};
+use scale_info::TypeInfo;
+use sp_runtime::RuntimeDebug;
+use sp_std::vec::Vec;
+
+/// How the runtime was built
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct BuildInfo {
+    /// Output of `rustc --version` for the compiler that built the runtime
+    pub rustc_version: Vec<u8>,
+    /// Cargo profile of the build
+    pub profile: Vec<u8>,
+    /// Cargo features the runtime was built with, `std` excluded
+    pub features: Vec<Vec<u8>>,
+}
+
+sp_api::decl_runtime_apis! {
+    /// Runtime API for the build information of the runtime
+    pub trait BuildInfoApi {
+        /// Compiler, profile and features the runtime was built with
+        fn build_info() -> BuildInfo;
+    }
+}
@@ -0,0 +1,124 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+//! # Deployment Control Primitives
+//!
+//! Traits allowing other pallets (grants, rewards, metadata, ...) to query deployment
+//! authorization and follow the deployment lifecycle without depending on the concrete
+//! deployment control pallet.
+
+use sp_core::H160;
+
+#[cfg(feature = "test-utils")]
+pub mod test_utils;
+
+/// Source of truth for whether an account may deploy EVM contracts
+pub trait DeploymentAuthorizer<AccountId> {
+    /// Check if an account is authorized to deploy EVM contracts
+    fn is_authorized(who: &AccountId) -> bool;
+}
+
+/// Authorizes no one
+impl<AccountId> DeploymentAuthorizer<AccountId> for () {
+    fn is_authorized(_who: &AccountId) -> bool {
+        false
+    }
+}
+
+/// Source of the recorded deployer of EVM contracts
+pub trait ContractProvenance {
+    /// Address that created `contract`, if recorded
+    fn deployer_of(contract: &H160) -> Option<H160>;
+}
+
+/// Records nothing
+impl ContractProvenance for () {
+    fn deployer_of(_contract: &H160) -> Option<H160> {
+        None
+    }
+}
+
+/// Hook suspending an account's right to deploy EVM contracts
+///
+/// Lets pallets scoring or policing deployers (reputation, incident response, ...) act on
+/// their findings without depending on the concrete deployment control pallet.
+pub trait DeployerSuspension<AccountId> {
+    /// Suspend the deployment rights of an account
+    fn suspend(who: &AccountId);
+}
+
+/// Suspends no one
+impl<AccountId> DeployerSuspension<AccountId> for () {
+    fn suspend(_who: &AccountId) {}
+}
+
+/// Hook granting and withdrawing deployment rights on behalf of an external authority
+///
+/// Lets pallets relaying decisions made elsewhere (e.g. governance on another chain) manage
+/// the authorized deployers without depending on the concrete deployment control pallet.
+pub trait DeployerRegistry<AccountId> {
+    /// Authorize an account to deploy, returning whether it was not authorized yet
+    ///
+    /// The registry may defer the authorization, e.g. behind an enactment delay, in which
+    /// case it returns whether the authorization was scheduled.
+    fn authorize(who: &AccountId) -> bool;
+
+    /// Revoke an account's authorization, returning whether it was authorized
+    fn revoke(who: &AccountId) -> bool;
+}
+
+/// Manages no deployers
+impl<AccountId> DeployerRegistry<AccountId> for () {
+    fn authorize(_who: &AccountId) -> bool {
+        false
+    }
+
+    fn revoke(_who: &AccountId) -> bool {
+        false
+    }
+}
+
+/// M-of-N set of named approvers, e.g. the runtime upgrade committee
+///
+/// Lets pallets gating sensitive operations on the approvals of several people read an
+/// approver set kept by another pallet.
+pub trait ApprovalCommittee<AccountId> {
+    /// Check if an account is one of the approvers
+    fn is_member(who: &AccountId) -> bool;
+
+    /// Number of approvals required, `None` while no approvers are named
+    fn threshold() -> Option<u32>;
+}
+
+/// Names no approvers
+impl<AccountId> ApprovalCommittee<AccountId> for () {
+    fn is_member(_who: &AccountId) -> bool {
+        false
+    }
+
+    fn threshold() -> Option<u32> {
+        None
+    }
+}
+
+/// Callbacks invoked along the lifecycle of deployers, factories and contracts
+///
+/// Subscribers are combined into a tuple, e.g. `(EvmDeploymentControl, Rewards)`, which
+/// forwards every callback to each member in order. Callbacks run inside the triggering
+/// transaction and must be cheap; heavy work should be deferred.
+#[impl_trait_for_tuples::impl_for_tuples(30)]
+pub trait DeploymentLifecycle<AccountId> {
+    /// A contract was created by the EVM
+    fn on_deployed(_deployer: &H160, _contract: &H160) {}
+
+    /// A deployer was authorized
+    fn on_authorized(_deployer: &AccountId) {}
+
+    /// A factory contract was approved
+    fn on_factory_registered(_factory: &H160, _admin: &AccountId) {}
+
+    /// A deployer's authorization was revoked
+    fn on_revoked(_deployer: &AccountId) {}
+
+    /// A deployer's authorization was moved from the `old` key to the `new` one
+    fn on_key_rotated(_old: &AccountId, _new: &AccountId) {}
+}
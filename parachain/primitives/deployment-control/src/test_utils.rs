@@ -0,0 +1,25 @@
+//! Test doubles for the pallets consuming these primitives
+
+use std::{cell::RefCell, collections::BTreeMap};
+
+use sp_core::H160;
+
+use crate::ContractProvenance;
+
+thread_local! {
+    static DEPLOYERS: RefCell<BTreeMap<H160, H160>> = RefCell::new(BTreeMap::new());
+}
+
+/// Provenance source backed by [`deploy`]
+pub struct MockProvenance;
+
+impl ContractProvenance for MockProvenance {
+    fn deployer_of(contract: &H160) -> Option<H160> {
+        DEPLOYERS.with(|deployers| deployers.borrow().get(contract).copied())
+    }
+}
+
+/// Record `contract` as deployed by `deployer`
+pub fn deploy(deployer: H160, contract: H160) {
+    DEPLOYERS.with(|deployers| deployers.borrow_mut().insert(contract, deployer));
+}
@@ -0,0 +1,29 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+//! # Gas Weight Runtime API
+//!
+//! Runtime API converting between the gas limit of an Ethereum transaction and the weight it
+//! takes in a block, with the runtime's own mapping, so that wallets and SDKs can size gas
+//! limits from the weight limits of the chain instead of guessing.
+//!
+//! Besides its execution time, the gas limit of a transaction reserves proof size: a
+//! transaction whose gas limit fits in the execution time of a block can still be refused for
+//! the proof size it reserves.
+
+use sp_weights::Weight;
+
+sp_api::decl_runtime_apis! {
+    /// Runtime API for the mapping of EVM gas to weight
+    pub trait GasWeightApi {
+        /// Weight of an Ethereum transaction with the gas limit `gas`
+        ///
+        /// The weight the transaction takes in the block, base extrinsic weight included,
+        /// with the proof size its gas limit reserves.
+        fn gas_to_weight(gas: u64) -> Weight;
+
+        /// Largest gas limit of an Ethereum transaction whose weight fits in `weight`
+        ///
+        /// Bounded by both the execution time and the proof size of `weight`.
+        fn weight_to_gas(weight: Weight) -> u64;
+    }
+}
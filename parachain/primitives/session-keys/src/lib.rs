@@ -0,0 +1,29 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+//! # Session Keys Runtime API
+//!
+//! Runtime API exposing the session keys of collators to the node, so that operators
+//! rotating keys with `author_rotateKeys` and `session.setKeys` can check that the new
+//! keys are queued before retiring the old ones.
+//!
+//! Keys are returned SCALE-encoded, in the layout of the runtime's `SessionKeys`.
+
+use parity_scale_codec::Codec;
+use sp_std::vec::Vec;
+
+sp_api::decl_runtime_apis! {
+    /// Runtime API for the session keys of collators
+    pub trait SessionKeysApi<AccountId>
+    where
+        AccountId: Codec,
+    {
+        /// Index of the current session
+        fn current_session() -> u32;
+
+        /// Collators of the next session with the keys they will author with
+        fn queued_keys() -> Vec<(AccountId, Vec<u8>)>;
+
+        /// Keys `collator` last set, taking effect in the session after they are queued
+        fn next_keys(collator: AccountId) -> Option<Vec<u8>>;
+    }
+}
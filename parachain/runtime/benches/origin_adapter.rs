@@ -0,0 +1,131 @@
+//! Native benchmarks of the deployment checks every EVM transaction goes through.
+//!
+//! `EnsureSudoCanDeploy::try_address_origin`, the EVM withdraw origin, and the transaction
+//! pool validation of Ethereum transactions (`validate_self_contained`) run against an
+//! externality populated with 10k authorized deployers and 1k approved factories, committed
+//! to its backend so that the checks read through the trie as they do on a node.
+//!
+//! Run with `cargo bench -p parachain-template-runtime --bench origin_adapter`, and compare
+//! releases with criterion's `--save-baseline <name>` and `--baseline <name>`.
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use ethereum::{LegacyTransaction, TransactionAction, TransactionSignature, TransactionV2};
+use fp_self_contained::SelfContainedCall;
+use frame_support::{
+    dispatch::GetDispatchInfo,
+    traits::{fungible::Mutate, Get},
+};
+use pallet_evm::{AddressMapping, EnsureAddressOrigin, FeeCalculator};
+use parachain_template_runtime::{
+    AccountId, Balances, BuildStorage, EvmDeploymentControl, Runtime, RuntimeCall,
+    RuntimeGenesisConfig, RuntimeOrigin, System, UNIT,
+};
+use parity_scale_codec::Encode;
+use sp_core::{H160, H256, U256};
+
+const DEPLOYERS: u64 = 10_000;
+const FACTORIES: u64 = 1_000;
+
+/// An authorized deployer, in the middle of the populated ones
+fn authorized() -> H160 {
+    H160::from_low_u64_be(DEPLOYERS / 2)
+}
+
+/// A funded account that is not a deployer
+fn unauthorized() -> H160 {
+    H160::repeat_byte(0xee)
+}
+
+/// An approved factory, in the middle of the populated ones
+fn factory() -> H160 {
+    H160::from_low_u64_be((1 << 32) + FACTORIES / 2)
+}
+
+fn account_of(address: H160) -> AccountId {
+    <Runtime as pallet_evm::Config>::AddressMapping::into_account_id(address)
+}
+
+fn populated_ext() -> sp_io::TestExternalities {
+    let storage = RuntimeGenesisConfig::default().build_storage().unwrap();
+    let mut ext = sp_io::TestExternalities::new(storage);
+    ext.execute_with(|| {
+        System::set_block_number(1);
+        for deployer in 1..=DEPLOYERS {
+            let account = account_of(H160::from_low_u64_be(deployer));
+            EvmDeploymentControl::authorize_deployer(RuntimeOrigin::root(), account).unwrap();
+        }
+        for factory in 1..=FACTORIES {
+            let factory = H160::from_low_u64_be((1 << 32) + factory);
+            let admin = account_of(authorized());
+            EvmDeploymentControl::register_factory(RuntimeOrigin::root(), factory, admin).unwrap();
+        }
+        for address in [authorized(), unauthorized()] {
+            Balances::set_balance(&account_of(address), 1_000 * UNIT);
+        }
+        System::reset_events();
+    });
+    ext.commit_all().unwrap();
+    ext
+}
+
+/// Ethereum transaction creating a contract, or calling `to`
+fn transact(to: Option<H160>) -> RuntimeCall {
+    let chain_id: u64 = <Runtime as pallet_evm::Config>::ChainId::get();
+    let (gas_price, _) = <Runtime as pallet_evm::Config>::FeeCalculator::min_gas_price();
+    // The pool validation takes the sender as recovered, so the signature need only be
+    // well-formed
+    let one = H256::from_low_u64_be(1);
+    let transaction = LegacyTransaction {
+        nonce: U256::zero(),
+        gas_price,
+        gas_limit: U256::from(100_000),
+        action: to.map_or(TransactionAction::Create, TransactionAction::Call),
+        value: U256::zero(),
+        input: vec![0x60, 0x00, 0x60, 0x00, 0xf3],
+        signature: TransactionSignature::new(chain_id * 2 + 35, one, one).unwrap(),
+    };
+    pallet_ethereum::Call::<Runtime>::transact { transaction: TransactionV2::Legacy(transaction) }
+        .into()
+}
+
+fn origin_adapter(c: &mut Criterion) {
+    let mut ext = populated_ext();
+    let mut group = c.benchmark_group("origin_adapter");
+    ext.execute_with(|| {
+        for (name, address) in [("authorized", authorized()), ("unauthorized", unauthorized())] {
+            let origin = RuntimeOrigin::signed(account_of(address));
+            group.bench_function(name, |b| {
+                b.iter(|| {
+                    <Runtime as pallet_evm::Config>::WithdrawOrigin::try_address_origin(
+                        black_box(&address),
+                        origin.clone(),
+                    )
+                })
+            });
+        }
+    });
+    group.finish();
+}
+
+fn pool_filter(c: &mut Criterion) {
+    let mut ext = populated_ext();
+    let mut group = c.benchmark_group("pool_filter");
+    ext.execute_with(|| {
+        let cases = [
+            ("authorized_creation", authorized(), transact(None)),
+            ("unauthorized_creation", unauthorized(), transact(None)),
+            ("factory_call", unauthorized(), transact(Some(factory()))),
+        ];
+        for (name, sender, call) in cases {
+            let info = call.get_dispatch_info();
+            let len = call.encoded_size();
+            group.bench_function(name, |b| {
+                b.iter(|| call.validate_self_contained(black_box(&sender), &info, len))
+            });
+        }
+    });
+    group.finish();
+}
+
+criterion_group!(benches, origin_adapter, pool_filter);
+criterion_main!(benches);
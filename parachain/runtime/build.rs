@@ -1,17 +1,39 @@
 #[cfg(all(feature = "std", feature = "metadata-hash"))]
 #[docify::export(template_enable_metadata_hash)]
 fn main() {
+    record_build_info();
     substrate_wasm_builder::WasmBuilder::init_with_defaults()
-        .enable_metadata_hash("UNIT", 12)
+        .enable_metadata_hash("UNIT", 18)
         .build();
 }
 
 #[cfg(all(feature = "std", not(feature = "metadata-hash")))]
 fn main() {
+    record_build_info();
     substrate_wasm_builder::WasmBuilder::build_using_defaults();
 }
 
 /// The wasm builder is deactivated when compiling
 /// this crate for wasm to speed up the compilation.
 #[cfg(not(feature = "std"))]
-fn main() {}
+fn main() {
+    record_build_info();
+}
+
+/// Record the compiler and profile served by `BuildInfoApi`
+///
+/// Runs for the Wasm build too, as the blob is compiled by a nested cargo invocation.
+fn record_build_info() {
+    let rustc = std::env::var("RUSTC").unwrap_or_else(|_| "rustc".into());
+    let rustc_version = std::process::Command::new(rustc)
+        .arg("--version")
+        .output()
+        .ok()
+        .and_then(|output| String::from_utf8(output.stdout).ok())
+        .map(|version| version.trim().to_owned())
+        .unwrap_or_else(|| "unknown".into());
+    let profile = std::env::var("PROFILE").unwrap_or_else(|_| "unknown".into());
+
+    println!("cargo:rustc-env=QNCH_RUSTC_VERSION={rustc_version}");
+    println!("cargo:rustc-env=QNCH_BUILD_PROFILE={profile}");
+}
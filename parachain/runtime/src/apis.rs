@@ -29,6 +29,7 @@ use frame_support::{
     traits::OnFinalize,
     weights::Weight,
 };
+use parity_scale_codec::Encode;
 use sp_api::impl_runtime_apis;
 use sp_consensus_aura::sr25519::AuthorityId as AuraId;
 use sp_core::{crypto::KeyTypeId, OpaqueMetadata, H160, H256, U256};
@@ -37,22 +38,46 @@ use sp_runtime::{
     transaction_validity::{TransactionSource, TransactionValidity},
     ApplyExtrinsicResult, Permill,
 };
-use sp_std::prelude::Vec;
+use sp_std::{prelude::Vec, vec};
 use sp_version::RuntimeVersion;
 // Frontier
 use fp_rpc::TransactionStatus;
 use pallet_ethereum::{
     Call::transact, Transaction as EthereumTransaction, TransactionAction, TransactionData,
 };
-use pallet_evm::{Account as EVMAccount, FeeCalculator, Runner};
+use pallet_evm::{Account as EVMAccount, AddressMapping, FeeCalculator, GasWeightMapping, Runner};
 
 // Local module imports
 use super::{
-    AccountId, Balance, Block, ConsensusHook, Ethereum, Executive, InherentDataExt, Nonce,
-    ParachainSystem, Runtime, RuntimeCall, RuntimeGenesisConfig, SessionKeys, System,
-    TransactionPayment, UncheckedExtrinsic, SLOT_DURATION, VERSION,
+    configs::RuntimeBlockWeights, contract_dependencies, deployment_control, diagnostics,
+    AccountId, AuditAnchors, Balance, Block, BlockNumber, CollatorRewards, ConsensusHook,
+    Contracts, DeployerReputation, Ethereum, EvmDeploymentControl, Executive, Hash,
+    InherentDataExt, Nonce, OutboundCommitments, ParachainSystem, Runtime, RuntimeCall,
+    RuntimeEvent, RuntimeGenesisConfig, SessionKeys, System, TransactionPayment,
+    UncheckedExtrinsic, SLOT_DURATION, VERSION,
 };
 
+/// Events of a dry-run Wasm contract call, as returned by `ContractsApi`
+type ContractsEventRecord = frame_system::EventRecord<RuntimeEvent, Hash>;
+
+/// Dry runs report the debug buffer and events, as ink! tooling expects
+const CONTRACTS_DEBUG_OUTPUT: pallet_contracts::DebugInfo =
+    pallet_contracts::DebugInfo::UnsafeDebug;
+const CONTRACTS_EVENTS: pallet_contracts::CollectEvents =
+    pallet_contracts::CollectEvents::UnsafeCollect;
+
+/// Cargo features reported by `BuildInfoApi`, with whether the runtime was built with them
+const BUILD_FEATURES: &[(&str, bool)] = &[
+    ("compliance-mode", cfg!(feature = "compliance-mode")),
+    ("evm-tracing", cfg!(feature = "evm-tracing")),
+    ("governance-full", cfg!(feature = "governance-full")),
+    ("metadata-hash", cfg!(feature = "metadata-hash")),
+    ("on-chain-release-build", cfg!(feature = "on-chain-release-build")),
+    ("runtime-benchmarks", cfg!(feature = "runtime-benchmarks")),
+    ("try-runtime", cfg!(feature = "try-runtime")),
+    ("unified-accounts", cfg!(feature = "unified-accounts")),
+];
+
 impl_runtime_apis! {
     impl sp_consensus_aura::AuraApi<Block, AuraId> for Runtime {
         fn slot_duration() -> sp_consensus_aura::SlotDuration {
@@ -141,6 +166,23 @@ impl_runtime_apis! {
         }
     }
 
+    impl session_keys_runtime_api::SessionKeysApi<Block, AccountId> for Runtime {
+        fn current_session() -> u32 {
+            pallet_session::CurrentIndex::<Runtime>::get()
+        }
+
+        fn queued_keys() -> Vec<(AccountId, Vec<u8>)> {
+            pallet_session::QueuedKeys::<Runtime>::get()
+                .into_iter()
+                .map(|(collator, keys)| (collator, keys.encode()))
+                .collect()
+        }
+
+        fn next_keys(collator: AccountId) -> Option<Vec<u8>> {
+            pallet_session::NextKeys::<Runtime>::get(&collator).map(|keys| keys.encode())
+        }
+    }
+
     impl frame_system_rpc_runtime_api::AccountNonceApi<Block, AccountId, Nonce> for Runtime {
         fn account_nonce(account: AccountId) -> Nonce {
             System::account_nonce(account)
@@ -390,6 +432,388 @@ impl_runtime_apis! {
         }
     }
 
+    impl pallet_contracts::ContractsApi<Block, AccountId, Balance, BlockNumber, Hash, ContractsEventRecord>
+        for Runtime
+    {
+        fn call(
+            origin: AccountId,
+            dest: AccountId,
+            value: Balance,
+            gas_limit: Option<Weight>,
+            storage_deposit_limit: Option<Balance>,
+            input_data: Vec<u8>,
+        ) -> pallet_contracts::ContractExecResult<Balance, ContractsEventRecord> {
+            let gas_limit = gas_limit.unwrap_or(RuntimeBlockWeights::get().max_block);
+            Contracts::bare_call(
+                origin,
+                dest,
+                value,
+                gas_limit,
+                storage_deposit_limit,
+                input_data,
+                CONTRACTS_DEBUG_OUTPUT,
+                CONTRACTS_EVENTS,
+                pallet_contracts::Determinism::Enforced,
+            )
+        }
+
+        fn instantiate(
+            origin: AccountId,
+            value: Balance,
+            gas_limit: Option<Weight>,
+            storage_deposit_limit: Option<Balance>,
+            code: pallet_contracts::Code<Hash>,
+            data: Vec<u8>,
+            salt: Vec<u8>,
+        ) -> pallet_contracts::ContractInstantiateResult<AccountId, Balance, ContractsEventRecord> {
+            let gas_limit = gas_limit.unwrap_or(RuntimeBlockWeights::get().max_block);
+            Contracts::bare_instantiate(
+                origin,
+                value,
+                gas_limit,
+                storage_deposit_limit,
+                code,
+                data,
+                salt,
+                CONTRACTS_DEBUG_OUTPUT,
+                CONTRACTS_EVENTS,
+            )
+        }
+
+        fn upload_code(
+            origin: AccountId,
+            code: Vec<u8>,
+            storage_deposit_limit: Option<Balance>,
+            determinism: pallet_contracts::Determinism,
+        ) -> pallet_contracts::CodeUploadResult<Hash, Balance> {
+            Contracts::bare_upload_code(origin, code, storage_deposit_limit, determinism)
+        }
+
+        fn get_storage(
+            address: AccountId,
+            key: Vec<u8>,
+        ) -> pallet_contracts::GetStorageResult {
+            Contracts::get_storage(address, key)
+        }
+    }
+
+    #[api_version(9)]
+    impl pallet_evm_deployment_control_runtime_api::DeploymentControlApi<Block, AccountId, BlockNumber> for Runtime {
+        fn contract_info(
+            address: H160,
+        ) -> Option<pallet_evm_deployment_control_runtime_api::ContractInfo<BlockNumber>> {
+            let code = pallet_evm::AccountCodes::<Runtime>::get(address);
+            if code.is_empty() {
+                return None;
+            }
+
+            use pallet_evm_deployment_control_runtime_api::MAX_COUNTED_STORAGE_ENTRIES;
+
+            let (account, _) = pallet_evm::Pallet::<Runtime>::account_basic(&address);
+            let provenance = EvmDeploymentControl::provenance(&address);
+
+            Some(pallet_evm_deployment_control_runtime_api::ContractInfo {
+                code_size: code.len() as u64,
+                code_hash: H256::from(sp_io::hashing::keccak_256(&code)),
+                storage_entries: pallet_evm::AccountStorages::<Runtime>::iter_key_prefix(address)
+                    .take(MAX_COUNTED_STORAGE_ENTRIES as usize)
+                    .count() as u64,
+                balance: account.balance,
+                nonce: account.nonce,
+                deployer: provenance.as_ref().map(|record| record.deployer),
+                deployed_at: provenance.map(|record| record.block),
+            })
+        }
+
+        fn list_factories(
+            start_after: Option<H160>,
+            limit: u32,
+        ) -> Vec<pallet_evm_deployment_control_runtime_api::FactoryEntry<AccountId, BlockNumber>> {
+            use pallet_evm_deployment_control::FactoryStatus;
+            use pallet_evm_deployment_control_runtime_api::{FactoryEntry, FactoryStatus as Status};
+
+            EvmDeploymentControl::factories(start_after, limit)
+                .into_iter()
+                .map(|(address, info)| FactoryEntry {
+                    address,
+                    admin: info.admin,
+                    children: info.children,
+                    // Retired factories are suspended for good
+                    status: match info.status {
+                        FactoryStatus::Active => Status::Active,
+                        FactoryStatus::Suspended | FactoryStatus::Retired => Status::Suspended,
+                    },
+                    registered_at: info.registered_at,
+                })
+                .collect()
+        }
+
+        fn denied_deployments(extrinsics: Vec<<Block as BlockT>::Extrinsic>) -> Vec<u32> {
+            deployment_control::deployment_refusals(&extrinsics)
+                .into_iter()
+                .map(|refusal| refusal.index)
+                .collect()
+        }
+
+        fn evm_account_state(
+            address: H160,
+            storage_keys: Vec<H256>,
+        ) -> pallet_evm_deployment_control_runtime_api::EvmAccountState {
+            use pallet_evm_deployment_control_runtime_api::{EvmAccountState, EvmStorageSlot};
+
+            let (account, _) = pallet_evm::Pallet::<Runtime>::account_basic(&address);
+            let code = pallet_evm::AccountCodes::<Runtime>::get(address);
+            let account_id = <Runtime as pallet_evm::Config>::AddressMapping::into_account_id(address);
+
+            EvmAccountState {
+                balance: account.balance,
+                nonce: account.nonce,
+                code_hash: H256::from(sp_io::hashing::keccak_256(&code)),
+                account_keys: vec![
+                    frame_system::Account::<Runtime>::hashed_key_for(&account_id),
+                    pallet_evm::AccountCodes::<Runtime>::hashed_key_for(address),
+                ],
+                storage: storage_keys
+                    .into_iter()
+                    .map(|key| EvmStorageSlot {
+                        key,
+                        value: pallet_evm::AccountStorages::<Runtime>::get(address, key),
+                        storage_key: pallet_evm::AccountStorages::<Runtime>::hashed_key_for(
+                            address, key,
+                        ),
+                    })
+                    .collect(),
+            }
+        }
+
+        fn preview_authorization_effects(
+            calls: Vec<Vec<u8>>,
+        ) -> pallet_evm_deployment_control_runtime_api::AuthorizationEffects<AccountId> {
+            use pallet_evm_deployment_control_runtime_api::{AuthorizationEffects, PreviewFailure};
+            use parity_scale_codec::DecodeLimit;
+
+            let mut decoded = Vec::with_capacity(calls.len());
+            let mut failed = None;
+            for (index, call) in calls.iter().enumerate() {
+                match RuntimeCall::decode_all_with_depth_limit(
+                    sp_api::MAX_EXTRINSIC_DEPTH,
+                    &mut &call[..],
+                ) {
+                    Ok(RuntimeCall::EvmDeploymentControl(call)) => decoded.push(call),
+                    Ok(_) => {
+                        failed = Some((index as u32, PreviewFailure::Unsupported));
+                        break;
+                    }
+                    Err(_) => {
+                        failed = Some((index as u32, PreviewFailure::Undecodable));
+                        break;
+                    }
+                }
+            }
+
+            // A dispatch failure comes before any decoding failure
+            let (diff, dispatch_failure) = EvmDeploymentControl::preview_calls(decoded);
+            AuthorizationEffects {
+                added_deployers: diff.added_deployers,
+                removed_deployers: diff.removed_deployers,
+                added_factories: diff.added_factories,
+                removed_factories: diff.removed_factories,
+                failed: dispatch_failure
+                    .map(|(index, err)| (index, PreviewFailure::Dispatch(err)))
+                    .or(failed),
+            }
+        }
+
+        fn diagnose_transaction(
+            extrinsics: Vec<<Block as BlockT>::Extrinsic>,
+            transaction_hash: H256,
+        ) -> Option<pallet_evm_deployment_control_runtime_api::FailureDiagnosis> {
+            diagnostics::diagnose_transaction(extrinsics, transaction_hash)
+        }
+
+        fn deployment_refusals(
+            extrinsics: Vec<<Block as BlockT>::Extrinsic>,
+        ) -> Vec<pallet_evm_deployment_control_runtime_api::DeploymentRefusal> {
+            deployment_control::deployment_refusals(&extrinsics)
+        }
+
+        fn deployer_status(
+            account: AccountId,
+        ) -> pallet_evm_deployment_control_runtime_api::DeployerStatus<BlockNumber> {
+            use pallet_evm_deployment_control::{AuthorizedDeployers, DeployerAdmins};
+
+            let info = AuthorizedDeployers::<Runtime>::get(&account);
+            pallet_evm_deployment_control_runtime_api::DeployerStatus {
+                authorized: info.is_some(),
+                authorized_at: info.map(|info| info.authorized_at),
+                deployer_admin: DeployerAdmins::<Runtime>::contains_key(&account),
+            }
+        }
+
+        fn was_authorized_at(account: AccountId, block: BlockNumber) -> Option<bool> {
+            EvmDeploymentControl::was_authorized_at(&account, block)
+        }
+
+        fn deployment_prerequisites(
+            address: H160,
+        ) -> pallet_evm_deployment_control_runtime_api::DeploymentPrerequisites<BlockNumber> {
+            let (evm_account, _) = pallet_evm::Pallet::<Runtime>::account_basic(&address);
+            let account = <Runtime as pallet_evm::Config>::AddressMapping::into_account_id(address);
+
+            // Windows and quota periods as they stand in the next block; the state changes
+            // of runtime API calls are discarded
+            let next = System::block_number() + 1;
+            System::set_block_number(next);
+            let remaining_quota = EvmDeploymentControl::remaining_quota(&account);
+
+            pallet_evm_deployment_control_runtime_api::DeploymentPrerequisites {
+                nonce: evm_account.nonce,
+                refusal: EvmDeploymentControl::ensure_can_deploy(&account).err().map(Into::into),
+                remaining_quota,
+                quota_resets_at: remaining_quota
+                    .map(|_| EvmDeploymentControl::quota_resets_at(next)),
+                announced_intents: EvmDeploymentControl::announced_intents(&account),
+            }
+        }
+    }
+
+    impl pallet_deployer_reputation_runtime_api::DeployerReputationApi<Block, AccountId, BlockNumber> for Runtime {
+        fn reputation(
+            deployer: AccountId,
+        ) -> pallet_deployer_reputation_runtime_api::Reputation<BlockNumber> {
+            pallet_deployer_reputation_runtime_api::Reputation {
+                score: DeployerReputation::score(&deployer),
+                suspended_at: DeployerReputation::suspended_at(&deployer),
+            }
+        }
+
+        fn suspension_floor() -> i32 {
+            <Runtime as pallet_deployer_reputation::Config>::SuspensionFloor::get()
+        }
+    }
+
+    impl pallet_audit_anchors_runtime_api::AuditAnchorsApi<Block, AccountId, BlockNumber> for Runtime {
+        fn anchors(
+            contract: H160,
+        ) -> Vec<pallet_audit_anchors_runtime_api::AnchoredDocument<AccountId, BlockNumber>> {
+            use pallet_audit_anchors::DocumentKind;
+            use pallet_audit_anchors_runtime_api::DocumentKind as ApiDocumentKind;
+
+            AuditAnchors::anchors(&contract)
+                .into_iter()
+                .map(|anchor| pallet_audit_anchors_runtime_api::AnchoredDocument {
+                    document: anchor.document,
+                    kind: match anchor.kind {
+                        DocumentKind::Audit => ApiDocumentKind::Audit,
+                        DocumentKind::SecurityReview => ApiDocumentKind::SecurityReview,
+                        DocumentKind::Other => ApiDocumentKind::Other,
+                    },
+                    anchored_by: anchor.anchored_by,
+                    anchored_at: anchor.anchored_at,
+                })
+                .collect()
+        }
+
+        fn contracts_of(document: H256) -> Vec<H160> {
+            AuditAnchors::contracts_of(&document)
+        }
+    }
+
+    impl pallet_contract_dependencies_runtime_api::ContractDependenciesApi<Block> for Runtime {
+        fn dependencies(
+            contract: H160,
+        ) -> Vec<pallet_contract_dependencies_runtime_api::Dependency> {
+            contract_dependencies::dependencies(contract)
+        }
+
+        fn dependents(
+            contract: H160,
+        ) -> Vec<pallet_contract_dependencies_runtime_api::Dependency> {
+            contract_dependencies::dependents(contract)
+        }
+
+        fn blast_radius(contract: H160) -> Vec<H160> {
+            contract_dependencies::blast_radius(contract)
+        }
+    }
+
+    impl pallet_outbound_commitments_runtime_api::OutboundCommitmentsApi<Block> for Runtime {
+        fn prove_message(
+            nonce: u64,
+        ) -> Option<pallet_outbound_commitments_runtime_api::MessageProof> {
+            OutboundCommitments::prove_message(nonce).map(|proof| {
+                pallet_outbound_commitments_runtime_api::MessageProof {
+                    root: proof.root,
+                    proof: proof.proof,
+                    number_of_leaves: proof.number_of_leaves,
+                    leaf_index: proof.leaf_index,
+                    leaf: proof.leaf,
+                }
+            })
+        }
+    }
+
+    impl pallet_collator_rewards_runtime_api::CollatorRewardsApi<Block, AccountId, Balance> for Runtime {
+        fn pending_rewards(collator: AccountId) -> Balance {
+            CollatorRewards::pending_rewards(&collator)
+        }
+
+        fn reward_per_block() -> Balance {
+            CollatorRewards::reward().per_block
+        }
+    }
+
+    impl address_mapping_runtime_api::AddressMappingApi<Block, AccountId> for Runtime {
+        fn account_of(address: H160) -> AccountId {
+            <Runtime as pallet_evm::Config>::AddressMapping::into_account_id(address)
+        }
+
+        fn address_of(account: AccountId) -> Option<H160> {
+            // Accounts are their own addresses
+            #[cfg(feature = "unified-accounts")]
+            let address = Some(account.into());
+            // Hashed addresses cannot be reversed, only the addresses seen calling from or
+            // receiving at the precompiles are known
+            #[cfg(not(feature = "unified-accounts"))]
+            let address = crate::precompiles::seen_address::<Runtime>(&account);
+
+            address
+        }
+    }
+
+    impl build_info_runtime_api::BuildInfoApi<Block> for Runtime {
+        fn build_info() -> build_info_runtime_api::BuildInfo {
+            build_info_runtime_api::BuildInfo {
+                rustc_version: env!("QNCH_RUSTC_VERSION").as_bytes().to_vec(),
+                profile: env!("QNCH_BUILD_PROFILE").as_bytes().to_vec(),
+                features: BUILD_FEATURES
+                    .iter()
+                    .filter(|(_, enabled)| *enabled)
+                    .map(|(feature, _)| feature.as_bytes().to_vec())
+                    .collect(),
+            }
+        }
+    }
+
+    impl gas_weight_runtime_api::GasWeightApi<Block> for Runtime {
+        fn gas_to_weight(gas: u64) -> Weight {
+            <Runtime as pallet_evm::Config>::GasWeightMapping::gas_to_weight(gas, false)
+        }
+
+        fn weight_to_gas(weight: Weight) -> u64 {
+            let gas = <Runtime as pallet_evm::Config>::GasWeightMapping::weight_to_gas(weight);
+            // The mapping reserves a unit of proof size per `GasLimitPovSizeRatio` gas,
+            // rounding down
+            match <Runtime as pallet_evm::Config>::GasLimitPovSizeRatio::get() {
+                0 => gas,
+                ratio => {
+                    let proof_gas = weight.proof_size().saturating_mul(ratio);
+                    gas.min(proof_gas.saturating_add(ratio - 1))
+                }
+            }
+        }
+    }
+
     impl cumulus_primitives_aura::AuraUnincludedSegmentApi<Block> for Runtime {
         fn can_build_upon(
             included_hash: <Block as BlockT>::Hash,
@@ -28,12 +28,40 @@ frame_benchmarking::define_benchmarks!(
     [frame_system, SystemBench::<Runtime>]
     [cumulus_pallet_parachain_system, ParachainSystem]
     [pallet_timestamp, Timestamp]
+    [pallet_migrations, MultiBlockMigrations]
     [pallet_balances, Balances]
+    [pallet_treasury, Treasury]
+    [pallet_fee_distribution, FeeDistribution]
+    [pallet_issuance, Issuance]
     [pallet_sudo, Sudo]
+    [pallet_utility, Utility]
+    [pallet_preimage, Preimage]
+    [pallet_collective, Council]
+    [pallet_emergency_pause, EmergencyPause]
     [pallet_collator_selection, CollatorSelection]
+    [pallet_collator_rewards, CollatorRewards]
+    [pallet_delegation, Delegation]
+    [pallet_collator_offences, CollatorOffences]
+    [pallet_vesting, Vesting]
+    [pallet_claims, Claims]
+    [pallet_assets, Assets]
+    [pallet_asset_conversion, AssetConversion]
+    [pallet_fee_assets, FeeAssets]
+    [pallet_fee_sponsorship, FeeSponsorship]
     [pallet_session, SessionBench::<Runtime>]
     [cumulus_pallet_xcmp_queue, XcmpQueue]
     [pallet_message_queue, MessageQueue]
     [pallet_evm, EVM]
     [pallet_evm_deployment_control, EvmDeploymentControl]
+    [pallet_transfer_allowlist, TransferAllowlist]
+    [pallet_deployer_reputation, DeployerReputation]
+    [pallet_contract_names, ContractNames]
+    [pallet_contract_dependencies, ContractDependencies]
+    [pallet_audit_anchors, AuditAnchors]
+    [pallet_code_freeze, CodeFreeze]
+    [pallet_outbound_commitments, OutboundCommitments]
+    [pallet_inbound_governance, InboundGovernance]
+    [pallet_elastic_scaling, ElasticScaling]
+    [pallet_upgrade_committee, UpgradeCommittee]
+    [pallet_deployment_escrow, DeploymentEscrow]
 );
@@ -0,0 +1,249 @@
+use fp_evm::{CallInfo, CreateInfo};
+use frame_support::{traits::Contains, weights::Weight};
+use pallet_ethereum::{Transaction as EthereumTransaction, TransactionAction};
+use pallet_evm::{EvmConfig, GasWeightMapping, OnCreate, Runner, RunnerError};
+use sp_core::{H160, H256, U256};
+use sp_io::hashing::keccak_256;
+use sp_runtime::{
+    traits::UniqueSaturatedInto,
+    transaction_validity::TransactionValidityError,
+    DispatchError,
+};
+use sp_std::prelude::*;
+
+use crate::{invalid_transaction::CustomInvalidity, CodeFreeze, Runtime, RuntimeCall};
+
+/// Check an Ethereum transaction against the code freeze list
+///
+/// Only the called contract and its input are checked; an upgrade issued by another
+/// contract is not.
+pub fn check_transaction(
+    transaction: &EthereumTransaction,
+) -> Result<(), TransactionValidityError> {
+    let (action, input) = match transaction {
+        EthereumTransaction::Legacy(t) => (&t.action, &t.input),
+        EthereumTransaction::EIP2930(t) => (&t.action, &t.input),
+        EthereumTransaction::EIP1559(t) => (&t.action, &t.input),
+    };
+    match action {
+        TransactionAction::Call(to) if CodeFreeze::is_upgrade_blocked(to, input) => {
+            Err(CustomInvalidity::CodeFrozen.into())
+        }
+        _ => Ok(()),
+    }
+}
+
+/// Base call filter refusing Substrate-level EVM calls that upgrade a frozen contract
+///
+/// Like the transfer allowlist, only the target named by `pallet_evm::call` is checked.
+/// Root bypasses the filter, as it can unfreeze the contract anyway.
+pub struct CodeFreezeCallFilter;
+
+impl Contains<RuntimeCall> for CodeFreezeCallFilter {
+    fn contains(call: &RuntimeCall) -> bool {
+        match call {
+            RuntimeCall::EVM(pallet_evm::Call::call { target, input, .. }) => {
+                !CodeFreeze::is_upgrade_blocked(target, input)
+            }
+            _ => true,
+        }
+    }
+}
+
+/// OnCreate hook noting contract creations at a frozen address for [`RefuseFrozenAddresses`]
+pub struct NoteFrozenCreations;
+
+impl OnCreate<Runtime> for NoteFrozenCreations {
+    fn on_create(_owner: H160, contract: H160) {
+        CodeFreeze::note_created(&contract);
+    }
+}
+
+/// Runner the code freeze checks wrap, failing the creations of unauthorized deployers
+type InnerRunner = crate::deployment_control::FailUnauthorizedCreations;
+
+/// Address CREATE2 gives to `init` deployed by `source` with `salt`
+pub fn create2_address(source: H160, salt: H256, init: &[u8]) -> H160 {
+    let mut preimage = Vec::with_capacity(85);
+    preimage.push(0xff);
+    preimage.extend_from_slice(source.as_bytes());
+    preimage.extend_from_slice(salt.as_bytes());
+    preimage.extend_from_slice(&keccak_256(init));
+    H160::from_slice(&keccak_256(&preimage)[12..])
+}
+
+/// EVM runner failing executions that create a contract at a frozen address
+///
+/// A frozen contract destroyed with SELFDESTRUCT, where the opcode policy allows it, could
+/// otherwise be redeployed with different code through CREATE2. A `create2` at a frozen
+/// address is refused before running. Creations by contracts are noted by
+/// [`NoteFrozenCreations`] and fail the execution once it ends with
+/// `CreationAtFrozenAddress`, which the failing dispatch rolls back.
+pub struct RefuseFrozenAddresses;
+
+impl RefuseFrozenAddresses {
+    /// Fail `result` if the execution created a contract at a frozen address
+    fn refuse_frozen_creations<I>(
+        result: Result<I, RunnerError<DispatchError>>,
+        used_gas: impl FnOnce(&I) -> U256,
+    ) -> Result<I, RunnerError<DispatchError>> {
+        let frozen_creation = CodeFreeze::take_frozen_creation();
+        let info = result?;
+        match frozen_creation {
+            None => Ok(info),
+            Some(_) => Err(RunnerError {
+                error: pallet_code_freeze::Error::<Runtime>::CreationAtFrozenAddress.into(),
+                weight: <Runtime as pallet_evm::Config>::GasWeightMapping::gas_to_weight(
+                    used_gas(&info).unique_saturated_into(),
+                    true,
+                ),
+            }),
+        }
+    }
+}
+
+impl Runner<Runtime> for RefuseFrozenAddresses {
+    type Error = DispatchError;
+
+    fn validate(
+        source: H160,
+        target: Option<H160>,
+        input: Vec<u8>,
+        value: U256,
+        gas_limit: u64,
+        max_fee_per_gas: Option<U256>,
+        max_priority_fee_per_gas: Option<U256>,
+        nonce: Option<U256>,
+        access_list: Vec<(H160, Vec<H256>)>,
+        is_transactional: bool,
+        weight_limit: Option<Weight>,
+        proof_size_base_cost: Option<u64>,
+        evm_config: &EvmConfig,
+    ) -> Result<(), RunnerError<Self::Error>> {
+        InnerRunner::validate(
+            source,
+            target,
+            input,
+            value,
+            gas_limit,
+            max_fee_per_gas,
+            max_priority_fee_per_gas,
+            nonce,
+            access_list,
+            is_transactional,
+            weight_limit,
+            proof_size_base_cost,
+            evm_config,
+        )
+    }
+
+    fn call(
+        source: H160,
+        target: H160,
+        input: Vec<u8>,
+        value: U256,
+        gas_limit: u64,
+        max_fee_per_gas: Option<U256>,
+        max_priority_fee_per_gas: Option<U256>,
+        nonce: Option<U256>,
+        access_list: Vec<(H160, Vec<H256>)>,
+        is_transactional: bool,
+        validate: bool,
+        weight_limit: Option<Weight>,
+        proof_size_base_cost: Option<u64>,
+        config: &EvmConfig,
+    ) -> Result<CallInfo, RunnerError<Self::Error>> {
+        let result = InnerRunner::call(
+            source,
+            target,
+            input,
+            value,
+            gas_limit,
+            max_fee_per_gas,
+            max_priority_fee_per_gas,
+            nonce,
+            access_list,
+            is_transactional,
+            validate,
+            weight_limit,
+            proof_size_base_cost,
+            config,
+        );
+        Self::refuse_frozen_creations(result, |info| info.used_gas.standard)
+    }
+
+    fn create(
+        source: H160,
+        init: Vec<u8>,
+        value: U256,
+        gas_limit: u64,
+        max_fee_per_gas: Option<U256>,
+        max_priority_fee_per_gas: Option<U256>,
+        nonce: Option<U256>,
+        access_list: Vec<(H160, Vec<H256>)>,
+        is_transactional: bool,
+        validate: bool,
+        weight_limit: Option<Weight>,
+        proof_size_base_cost: Option<u64>,
+        config: &EvmConfig,
+    ) -> Result<CreateInfo, RunnerError<Self::Error>> {
+        let result = InnerRunner::create(
+            source,
+            init,
+            value,
+            gas_limit,
+            max_fee_per_gas,
+            max_priority_fee_per_gas,
+            nonce,
+            access_list,
+            is_transactional,
+            validate,
+            weight_limit,
+            proof_size_base_cost,
+            config,
+        );
+        Self::refuse_frozen_creations(result, |info| info.used_gas.standard)
+    }
+
+    fn create2(
+        source: H160,
+        init: Vec<u8>,
+        salt: H256,
+        value: U256,
+        gas_limit: u64,
+        max_fee_per_gas: Option<U256>,
+        max_priority_fee_per_gas: Option<U256>,
+        nonce: Option<U256>,
+        access_list: Vec<(H160, Vec<H256>)>,
+        is_transactional: bool,
+        validate: bool,
+        weight_limit: Option<Weight>,
+        proof_size_base_cost: Option<u64>,
+        config: &EvmConfig,
+    ) -> Result<CreateInfo, RunnerError<Self::Error>> {
+        if CodeFreeze::is_frozen(&create2_address(source, salt, &init)) {
+            return Err(RunnerError {
+                error: pallet_code_freeze::Error::<Runtime>::CreationAtFrozenAddress.into(),
+                weight: Weight::zero(),
+            });
+        }
+
+        let result = InnerRunner::create2(
+            source,
+            init,
+            salt,
+            value,
+            gas_limit,
+            max_fee_per_gas,
+            max_priority_fee_per_gas,
+            nonce,
+            access_list,
+            is_transactional,
+            validate,
+            weight_limit,
+            proof_size_base_cost,
+            config,
+        );
+        Self::refuse_frozen_creations(result, |info| info.used_gas.standard)
+    }
+}
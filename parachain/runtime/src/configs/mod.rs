@@ -34,40 +34,82 @@ use frame_support::{
     dispatch::DispatchClass,
     parameter_types,
     traits::{
-        ConstBool, ConstU32, ConstU64, ConstU8, EitherOfDiverse, FindAuthor, TransformOrigin,
+        fungible::HoldConsideration,
+        tokens::{
+            fungible::{NativeFromLeft, NativeOrWithId, UnionOf},
+            imbalance::{ResolveAssetTo, ResolveTo},
+            pay::PayFromAccount,
+            UnityAssetBalanceConversion,
+        },
+        AsEnsureOriginWithArg, ConstBool, ConstU128, ConstU32, ConstU64, ConstU8, Contains,
+        Currency, EitherOfDiverse, FindAuthor, Get, Imbalance, InsideBoth, LinearStoragePrice,
+        Nothing, ReservableCurrency, TransformOrigin, WithdrawReasons,
     },
     weights::{constants::WEIGHT_REF_TIME_PER_SECOND, ConstantMultiplier, Weight},
     PalletId,
 };
 use frame_system::{
     limits::{BlockLength, BlockWeights},
-    EnsureRoot,
+    EnsureNever, EnsureRoot, EnsureSigned, EnsureWithSuccess,
 };
 use pallet_xcm::{EnsureXcm, IsVoiceOfBody};
 use parachains_common::message_queue::{NarrowOriginToSibling, ParaIdToSibling};
-use polkadot_runtime_common::{BlockHashCount, SlowAdjustingFeeUpdate};
+use polkadot_runtime_common::{impls::ToAuthor, BlockHashCount, SlowAdjustingFeeUpdate};
 use sp_consensus_aura::sr25519::AuthorityId as AuraId;
-use sp_core::{crypto::ByteArray, H160, U256};
-use sp_runtime::{traits::BlakeTwo256, ConsensusEngineId, Perbill, Permill};
+use sp_core::{
+    crypto::{key_types::AURA, ByteArray},
+    H160, U256,
+};
+use sp_runtime::{
+    traits::{Convert, ConvertInto, IdentityLookup, Saturating, UniqueSaturatedInto, Verify},
+    transaction_validity::TransactionPriority,
+    ConsensusEngineId, Perbill, Percent, Permill,
+};
 use sp_std::{marker::PhantomData, prelude::*};
 use sp_version::RuntimeVersion;
 use xcm::latest::prelude::{AssetId, BodyId};
 // Frontier
 use pallet_ethereum::PostLogContent;
-use pallet_evm::{EnsureAddressTruncated, HashedAddressMapping};
+#[cfg(feature = "governance-full")]
+use pallet_evm_deployment_control::EnsureDeployerAdmin;
+
+// Fees and rewards
+use pallet_collator_offences::{CollatorSlashing, RelayParentOf, SlashFractions};
+use pallet_collator_rewards::{RewardConfig, RewardSource};
+use pallet_delegation::CollatorCandidates;
+use pallet_fee_distribution::{FeeKind, FeeSplit};
+use pallet_issuance::IssuanceSchedule;
+
+// Token distribution
+use pallet_nfts::PalletFeatures;
+
+// Assets and liquidity
+use pallet_asset_conversion::{AccountIdConverter, Ascending, Chain, WithFirstAsset};
+use pallet_asset_conversion_tx_payment::SwapAssetAdapter;
 
 // Custom deployment control
-use crate::deployment_control::EnsureSudoCanDeploy;
+use crate::code_freeze::{CodeFreezeCallFilter, NoteFrozenCreations, RefuseFrozenAddresses};
+use crate::deployment_control::{
+    DeploymentCallFilter, EnsureAuthorizedDeployer, EnsureSudoCanDeploy, EvmAddressToAccount,
+    EvmContractDeployer, NoteNestedCreations, NotifyDeployed, UtilityCalls,
+};
+use crate::fee_assets::{DealWithAssetFees, RegisteredFeeAssets};
+use crate::registry_mirror::RegistryMirror;
+use crate::transfer_allowlist::TransferCallFilter;
 
 // Local module imports
 use super::{
     weights::{BlockExecutionWeight, ExtrinsicBaseWeight, RocksDbWeight},
-    AccountId, Aura, Balance, Balances, BaseFee, Block, BlockNumber, CollatorSelection,
-    ConsensusHook, EVMChainId, FrontierPrecompiles, Hash, MessageQueue, Nonce, PalletInfo,
-    ParachainSystem, Runtime, RuntimeCall, RuntimeEvent, RuntimeFreezeReason, RuntimeHoldReason,
-    RuntimeOrigin, RuntimeTask, Session, SessionKeys, System, Timestamp, WeightToFee, XcmpQueue,
-    AVERAGE_ON_INITIALIZE_RATIO, CENTIUNIT, EXISTENTIAL_DEPOSIT, HOURS, MAXIMUM_BLOCK_WEIGHT,
-    MICROUNIT, NORMAL_DISPATCH_RATIO, SLOT_DURATION, VERSION,
+    AccountId, AssetConversion, Assets, Aura, Balance, Balances, BaseFee, Block, BlockNumber,
+    CollatorOffences, CollatorRewards, CollatorSelection, ConsensusHook, Delegation,
+    DeployerReputation, DeploymentEscrow, EVMChainId, EmergencyPause, EvmDeploymentControl,
+    FeeDistribution, FeeSponsorship, FrontierPrecompiles, Hash, Header, MessageQueue,
+    MultiBlockMigrations, Nonce, OriginCaller, PalletInfo, ParachainSystem, PoolAssets, Preimage,
+    RandomnessCollectiveFlip, Runtime, RuntimeCall, RuntimeEvent, RuntimeFreezeReason,
+    RuntimeHoldReason, RuntimeOrigin, RuntimeTask, Session, SessionKeys, Signature, System,
+    Timestamp, Treasury, UncheckedExtrinsic, WeightToFee, XcmpQueue, AVERAGE_ON_INITIALIZE_RATIO,
+    CENTIUNIT, DAYS, EXISTENTIAL_DEPOSIT, HOURS, MAXIMUM_BLOCK_WEIGHT, MAX_CORES, MICROUNIT,
+    NORMAL_DISPATCH_RATIO, SLOT_DURATION, UNIT, VERSION,
 };
 use xcm_config::{RelayLocation, XcmOriginToTransactDispatchOrigin};
 
@@ -128,9 +170,55 @@ impl frame_system::Config for Runtime {
     type BlockLength = RuntimeBlockLength;
     /// This is used as an identifier of the chain. 42 is the generic substrate prefix.
     type SS58Prefix = SS58Prefix;
-    /// The action to take on a Runtime Upgrade
-    type OnSetCode = cumulus_pallet_parachain_system::ParachainSetCode<Self>;
+    /// The action to take on a Runtime Upgrade, only for code enacted by the upgrade committee
+    type OnSetCode = pallet_upgrade_committee::CommitteeSetCode<
+        Self,
+        cumulus_pallet_parachain_system::ParachainSetCode<Self>,
+    >;
     type MaxConsumers = frame_support::traits::ConstU32<16>;
+    /// Reject paused calls and enforce the transfer allowlist, the code freeze list and
+    /// deployment control on Substrate-level EVM calls, including batched ones. Deployment
+    /// control comes last, as it counts the creations the other filters let through.
+    type BaseCallFilter = InsideBoth<
+        EmergencyPause,
+        InsideBoth<InsideBoth<TransferCallFilter, CodeFreezeCallFilter>, DeploymentCallFilter>,
+    >;
+    /// Multi-block migrations are stepped by pallet-migrations; no transactions are
+    /// included while one is ongoing.
+    type MultiBlockMigrator = MultiBlockMigrations;
+}
+
+parameter_types! {
+    /// Share of the block that multi-block migrations may use.
+    pub MbmServiceWeight: Weight = Perbill::from_percent(80) * RuntimeBlockWeights::get().max_block;
+}
+
+impl pallet_migrations::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    #[cfg(not(feature = "runtime-benchmarks"))]
+    type Migrations = (
+        pallet_evm_deployment_control::migrations::v1::LazyMigrationV1<
+            Runtime,
+            pallet_evm_deployment_control::weights::SubstrateWeight<Runtime>,
+        >,
+        pallet_evm_deployment_control::migrations::v2::LazyMigrationV2<
+            Runtime,
+            pallet_evm_deployment_control::weights::SubstrateWeight<Runtime>,
+        >,
+        pallet_evm_deployment_control::migrations::v3::LazyMigrationV3<
+            Runtime,
+            pallet_evm_deployment_control::weights::SubstrateWeight<Runtime>,
+        >,
+    );
+    // Benchmarks need mocked migrations to guarantee that they succeed.
+    #[cfg(feature = "runtime-benchmarks")]
+    type Migrations = pallet_migrations::mock_helpers::MockedMigrations;
+    type CursorMaxLen = ConstU32<65_536>;
+    type IdentifierMaxLen = ConstU32<256>;
+    type MigrationStatusHandler = ();
+    type FailedMigrationHandler = frame_support::migrations::FreezeChainOnFailedMigration;
+    type MaxServiceWeight = MbmServiceWeight;
+    type WeightInfo = pallet_migrations::weights::SubstrateWeight<Runtime>;
 }
 
 impl pallet_timestamp::Config for Runtime {
@@ -147,6 +235,22 @@ impl pallet_timestamp::Config for Runtime {
 
 impl pallet_evm_chain_id::Config for Runtime {}
 
+/// Signed origins calling into the EVM, as the truncation of their 32-byte account
+#[cfg(not(feature = "unified-accounts"))]
+pub type EvmCallOrigin = pallet_evm::EnsureAddressTruncated;
+
+/// Accounts of EVM addresses, hashing the address as accounts and addresses differ
+#[cfg(not(feature = "unified-accounts"))]
+pub type EvmAddressMapping = pallet_evm::HashedAddressMapping<sp_runtime::traits::BlakeTwo256>;
+
+/// Signed origins calling into the EVM, as their own 20-byte account
+#[cfg(feature = "unified-accounts")]
+pub type EvmCallOrigin = pallet_evm::EnsureAccountId20;
+
+/// Accounts of EVM addresses, which are the addresses themselves with 20-byte accounts
+#[cfg(feature = "unified-accounts")]
+pub type EvmAddressMapping = pallet_evm::IdentityAddressMapping;
+
 pub struct FindAuthorTruncated<F>(PhantomData<F>);
 impl<F: FindAuthor<u32>> FindAuthor<H160> for FindAuthorTruncated<F> {
     fn find_author<'a, I>(digests: I) -> Option<H160>
@@ -191,23 +295,30 @@ impl pallet_evm::Config for Runtime {
     type GasWeightMapping = pallet_evm::FixedGasWeightMapping<Self>;
     type WeightPerGas = WeightPerGas;
     type BlockHashMapping = pallet_ethereum::EthereumBlockHashMapping<Self>;
-    type CallOrigin = EnsureAddressTruncated;
+    type CallOrigin = EvmCallOrigin;
     type WithdrawOrigin = EnsureSudoCanDeploy<Self>;
-    type AddressMapping = HashedAddressMapping<BlakeTwo256>;
+    type AddressMapping = EvmAddressMapping;
     type Currency = Balances;
     type RuntimeEvent = RuntimeEvent;
     type PrecompilesType = FrontierPrecompiles<Self>;
     type PrecompilesValue = PrecompilesValue;
     type ChainId = EVMChainId;
     type BlockGasLimit = BlockGasLimit;
-    type Runner = pallet_evm::runner::stack::Runner<Self>;
-    type OnChargeTransaction = ();
-    type OnCreate = ();
+    /// The stack runner, failing executions that create a contract at a frozen address.
+    type Runner = RefuseFrozenAddresses;
+    type OnChargeTransaction = DistributeEvmFees;
+    type OnCreate =
+        (NoteFrozenCreations, NoteNestedCreations, NotifyDeployed<DeploymentLifecycleHooks>);
     type FindAuthor = FindAuthorTruncated<Aura>;
     type GasLimitPovSizeRatio = GasLimitPovSizeRatio;
     type SuicideQuickClearLimit = SuicideQuickClearLimit;
     type Timestamp = Timestamp;
     type WeightInfo = (); // Configure based on benchmarking results.;
+
+    /// Apply the on-chain opcode policy, which bans SELFDESTRUCT by default.
+    fn config() -> &'static pallet_evm::EvmConfig {
+        crate::evm_config::evm_config()
+    }
 }
 
 parameter_types! {
@@ -248,7 +359,7 @@ impl pallet_base_fee::Config for Runtime {
 
 impl pallet_authorship::Config for Runtime {
     type FindAuthor = pallet_session::FindAccountFromAuthorIndex<Self, Aura>;
-    type EventHandler = (CollatorSelection,);
+    type EventHandler = (CollatorSelection, CollatorRewards, CollatorOffences);
 }
 
 parameter_types! {
@@ -280,22 +391,518 @@ parameter_types! {
 
 impl pallet_transaction_payment::Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
-    type OnChargeTransaction = pallet_transaction_payment::FungibleAdapter<Balances, ()>;
+    type OnChargeTransaction = pallet_transaction_payment::FungibleAdapter<Balances, DealWithFees>;
     type WeightToFee = WeightToFee;
     type LengthToFee = ConstantMultiplier<Balance, TransactionByteFee>;
     type FeeMultiplierUpdate = SlowAdjustingFeeUpdate<Self>;
     type OperationalFeeMultiplier = ConstU8<5>;
 }
 
+parameter_types! {
+    pub const TreasuryPalletId: PalletId = PalletId(*b"py/trsry");
+    pub TreasuryAccount: AccountId = Treasury::account_id();
+    pub const TreasurySpendPeriod: BlockNumber = 6 * DAYS;
+    pub const TreasuryPayoutPeriod: BlockNumber = 30 * DAYS;
+    /// Funds left unspent at the end of a spend period stay in the treasury.
+    pub const TreasuryBurn: Permill = Permill::zero();
+    /// A single council motion spends at most this much.
+    pub const MaxTreasurySpend: Balance = 100_000 * UNIT;
+}
+
+/// More than half of the council
+pub type CouncilMajority =
+    pallet_collective::EnsureProportionMoreThan<AccountId, CouncilCollective, 1, 2>;
+
+/// Creates the treasury benchmark arguments for a treasury paying out the native currency
+#[cfg(feature = "runtime-benchmarks")]
+pub struct TreasuryBenchmarkHelper;
+
+#[cfg(feature = "runtime-benchmarks")]
+impl pallet_treasury::ArgumentsFactory<(), AccountId> for TreasuryBenchmarkHelper {
+    fn create_asset_kind(_seed: u32) {}
+
+    fn create_beneficiary(seed: [u8; 32]) -> AccountId {
+        AccountId::from(seed)
+    }
+}
+
+impl pallet_treasury::Config for Runtime {
+    type PalletId = TreasuryPalletId;
+    type Currency = Balances;
+    /// Spends are rejected by sudo or a council majority.
+    type RejectOrigin = EitherOfDiverse<EnsureRoot<AccountId>, CouncilMajority>;
+    type RuntimeEvent = RuntimeEvent;
+    type SpendPeriod = TreasurySpendPeriod;
+    type Burn = TreasuryBurn;
+    type BurnDestination = ();
+    type SpendFunds = ();
+    type WeightInfo = pallet_treasury::weights::SubstrateWeight<Runtime>;
+    type MaxApprovals = ConstU32<100>;
+    /// Spends are approved by a council majority, up to `MaxTreasurySpend` each.
+    type SpendOrigin = EnsureWithSuccess<CouncilMajority, AccountId, MaxTreasurySpend>;
+    type AssetKind = ();
+    type Beneficiary = AccountId;
+    type BeneficiaryLookup = IdentityLookup<AccountId>;
+    type Paymaster = PayFromAccount<Balances, TreasuryAccount>;
+    type BalanceConverter = UnityAssetBalanceConversion;
+    type PayoutPeriod = TreasuryPayoutPeriod;
+    #[cfg(feature = "runtime-benchmarks")]
+    type BenchmarkHelper = TreasuryBenchmarkHelper;
+}
+
+parameter_types! {
+    pub const CouncilMotionDuration: BlockNumber = 3 * DAYS;
+    pub MaxCouncilProposalWeight: Weight =
+        Perbill::from_percent(50) * RuntimeBlockWeights::get().max_block;
+}
+
+/// Instance of the collective pallet forming the council
+pub type CouncilCollective = pallet_collective::Instance1;
+
+impl pallet_collective::Config<CouncilCollective> for Runtime {
+    type RuntimeOrigin = RuntimeOrigin;
+    type Proposal = RuntimeCall;
+    type RuntimeEvent = RuntimeEvent;
+    type MotionDuration = CouncilMotionDuration;
+    type MaxProposals = ConstU32<100>;
+    type MaxMembers = ConstU32<100>;
+    type DefaultVote = pallet_collective::PrimeDefaultVote;
+    type WeightInfo = pallet_collective::weights::SubstrateWeight<Runtime>;
+    /// Council members are appointed through sudo until elections are in place.
+    type SetMembersOrigin = EnsureRoot<AccountId>;
+    type MaxProposalWeight = MaxCouncilProposalWeight;
+}
+
+parameter_types! {
+    /// Fees are burned but for a fifth paid into the treasury.
+    pub const DefaultFeeSplit: FeeSplit =
+        FeeSplit { treasury: Percent::from_percent(20), author: Percent::from_percent(0) };
+    /// Tips go to the block author.
+    pub const DefaultTipSplit: FeeSplit =
+        FeeSplit { treasury: Percent::from_percent(0), author: Percent::from_percent(100) };
+}
+
+/// Splits the fees and tips of Substrate transactions and the base fees of Ethereum transactions
+pub type DealWithFees = pallet_fee_distribution::DealWithFees<Runtime>;
+
+/// Charges Ethereum transactions like `EVMFungibleAdapter`, distributing the base fee as a fee
+/// and the priority fee as a tip
+///
+/// Fees advanced by a sponsorship pool are handed back to the pool as far as unused once
+/// withdrawn and once corrected. Pools pay the base fee only, the sender paying the tip.
+pub struct DistributeEvmFees;
+
+type EvmFungibleAdapter = pallet_evm::EVMFungibleAdapter<Balances, DealWithFees>;
+
+impl pallet_evm::OnChargeEVMTransaction<Runtime> for DistributeEvmFees {
+    type LiquidityInfo =
+        <EvmFungibleAdapter as pallet_evm::OnChargeEVMTransaction<Runtime>>::LiquidityInfo;
+
+    fn withdraw_fee(
+        who: &H160,
+        fee: U256,
+    ) -> Result<Self::LiquidityInfo, pallet_evm::Error<Runtime>> {
+        let paid = EvmFungibleAdapter::withdraw_fee(who, fee)?;
+        FeeSponsorship::reclaim(who, fee.unique_saturated_into());
+        Ok(paid)
+    }
+
+    fn correct_and_deposit_fee(
+        who: &H160,
+        corrected_fee: U256,
+        base_fee: U256,
+        already_withdrawn: Self::LiquidityInfo,
+    ) -> Self::LiquidityInfo {
+        let tip = EvmFungibleAdapter::correct_and_deposit_fee(
+            who,
+            corrected_fee,
+            base_fee,
+            already_withdrawn,
+        );
+        FeeSponsorship::settle(who, base_fee.unique_saturated_into());
+        tip
+    }
+
+    fn pay_priority_fee(tip: Self::LiquidityInfo) {
+        if let Some(tip) = tip {
+            FeeDistribution::distribute(FeeKind::Tip, tip);
+        }
+    }
+}
+
+impl pallet_fee_distribution::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type Currency = Balances;
+    type Treasury = ResolveTo<TreasuryAccount, Balances>;
+    type BlockAuthor = ToAuthor<Runtime>;
+    /// Splits are set by sudo or a council majority.
+    type SplitOrigin = EitherOfDiverse<EnsureRoot<AccountId>, CouncilMajority>;
+    type DefaultFeeSplit = DefaultFeeSplit;
+    type DefaultTipSplit = DefaultTipSplit;
+    type WeightInfo = pallet_fee_distribution::weights::SubstrateWeight<Runtime>;
+}
+
 impl pallet_sudo::Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
     type RuntimeCall = RuntimeCall;
     type WeightInfo = (); // Configure based on benchmarking results.
 }
 
+impl pallet_utility::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type RuntimeCall = RuntimeCall;
+    type PalletsOrigin = OriginCaller;
+    type WeightInfo = pallet_utility::weights::SubstrateWeight<Runtime>;
+}
+
+parameter_types! {
+    pub const PreimageBaseDeposit: Balance = UNIT;
+    pub const PreimageByteDeposit: Balance = 10 * MICROUNIT;
+    pub const PreimageHoldReason: RuntimeHoldReason =
+        RuntimeHoldReason::Preimage(pallet_preimage::HoldReason::Preimage);
+}
+
+impl pallet_preimage::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type WeightInfo = pallet_preimage::weights::SubstrateWeight<Runtime>;
+    type Currency = Balances;
+    type ManagerOrigin = EnsureRoot<AccountId>;
+    /// Preimages noted by accounts are held from them, the ones noted by pallets are free.
+    type Consideration = HoldConsideration<
+        AccountId,
+        Balances,
+        PreimageHoldReason,
+        LinearStoragePrice<PreimageBaseDeposit, PreimageByteDeposit, Balance>,
+    >;
+}
+
+impl<C> frame_system::offchain::SendTransactionTypes<C> for Runtime
+where
+    RuntimeCall: From<C>,
+{
+    type OverarchingCall = RuntimeCall;
+    type Extrinsic = UncheckedExtrinsic;
+}
+
+parameter_types! {
+    /// Rejected deployments per window before an account is flagged.
+    pub const RejectedAttemptThreshold: u32 = 10;
+    /// Window over which rejected deployments are counted.
+    pub const RejectedAttemptWindow: BlockNumber = HOURS;
+    /// Alerts are cheap and security relevant, so they outrank regular unsigned transactions.
+    pub const DeploymentAlertPriority: TransactionPriority = TransactionPriority::MAX / 2;
+    /// Authorization proposals left unapproved for a week are dropped.
+    pub const AuthorizationProposalLifetime: BlockNumber = 7 * DAYS;
+    /// Integrators get a week to have an expiring factory approval renewed.
+    pub const FactoryExpiryNotice: BlockNumber = 7 * DAYS;
+    /// Bond held for every announced deployment, released when the contract is deployed.
+    pub const DeploymentIntentBond: Balance = 10 * UNIT;
+    /// Governance gets two days to revoke a compromised key before it hands over its rights.
+    pub const DeployerKeyRotationDelay: BlockNumber = 2 * DAYS;
+    /// Deployment quotas count the creations of each day.
+    pub const DeploymentQuotaPeriod: BlockNumber = DAYS;
+    /// Objections locking 2% of the issuance put a scheduled authorization to a referendum.
+    pub const AuthorizationVetoThreshold: Perbill = Perbill::from_percent(2);
+    /// Token holders vote on a vetoed authorization for a week.
+    pub const AuthorizationReferendumPeriod: BlockNumber = 7 * DAYS;
+    /// Users of a retiring contract get a month's notice before it is frozen.
+    pub const ContractRetirementGracePeriod: BlockNumber = 30 * DAYS;
+}
+
+/// Origin administering deployers, sudo or the deployer admins it appoints
+#[cfg(feature = "governance-full")]
+pub type DeployerAdminOrigin = EnsureDeployerAdmin<AccountId>;
+
+/// Origin administering deployers, sudo only
+///
+/// Deployer admins may still be appointed, but hold no rights in this build.
+#[cfg(not(feature = "governance-full"))]
+pub type DeployerAdminOrigin = EnsureRoot<AccountId>;
+
+/// Pallets following the deployment lifecycle, notified in order
+///
+/// EvmDeploymentControl comes first, so that provenance is recorded before other
+/// subscribers run. RegistryMirror queues the registry updates for Ethereum, and
+/// DeploymentEscrow locks the escrows required of new contracts.
+pub type DeploymentLifecycleHooks =
+    (EvmDeploymentControl, DeployerReputation, RegistryMirror, DeploymentEscrow);
+
 impl pallet_evm_deployment_control::Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
+    type RuntimeOrigin = RuntimeOrigin;
+    type PalletsOrigin = OriginCaller;
+    type RuntimeCall = RuntimeCall;
+    type NestedCalls = UtilityCalls;
+    type AdminOrigin = DeployerAdminOrigin;
     type WeightInfo = pallet_evm_deployment_control::weights::SubstrateWeight<Runtime>;
+    type RejectedAttemptThreshold = RejectedAttemptThreshold;
+    type RejectedAttemptWindow = RejectedAttemptWindow;
+    type UnsignedPriority = DeploymentAlertPriority;
+    type MaxDeploymentWindows = ConstU32<16>;
+    type MaxQueuedCleanups = ConstU32<1024>;
+    type ContractDeployer = EvmContractDeployer;
+    type MaxMetadataLen = ConstU32<64>;
+    type Lifecycle = DeploymentLifecycleHooks;
+    type MaxApprovers = ConstU32<16>;
+    type MaxProposals = ConstU32<64>;
+    type ProposalLifetime = AuthorizationProposalLifetime;
+    type FactoryExpiryNotice = FactoryExpiryNotice;
+    type MaxExpiriesPerBlock = ConstU32<16>;
+    type MaxCachedAuthorizations = ConstU32<64>;
+    /// The nesting the EVM call depth used to allow when the opcode policy reduced it.
+    type MaxCreateDepth = ConstU32<32>;
+    type Currency = Balances;
+    type RuntimeHoldReason = RuntimeHoldReason;
+    /// Deployment fees and slashed intent bonds are paid into the treasury.
+    type DeploymentProceeds = ResolveTo<TreasuryAccount, Balances>;
+    type IntentBond = DeploymentIntentBond;
+    type KeyRotationDelay = DeployerKeyRotationDelay;
+    type Preimages = Preimage;
+    type MaxMetadataBlobLen = ConstU32<4096>;
+    type MaxGenesisDeployers = ConstU32<100>;
+    type MaxAuthorizationTransitions = ConstU32<64>;
+    type QuotaPeriodLength = DeploymentQuotaPeriod;
+    /// Two thirds of the council may enact governance calls early or cancel them.
+    type EmergencyOrigin = EitherOfDiverse<
+        EnsureRoot<AccountId>,
+        pallet_collective::EnsureProportionAtLeast<AccountId, CouncilCollective, 2, 3>,
+    >;
+    type MaxGovernanceCalls = ConstU32<64>;
+    type VetoThreshold = AuthorizationVetoThreshold;
+    type ReferendumPeriod = AuthorizationReferendumPeriod;
+    type RetirementGracePeriod = ContractRetirementGracePeriod;
+}
+
+parameter_types! {
+    pub const InitialReputation: i32 = 100;
+    pub const DeploymentReward: i32 = 1;
+    pub const IncidentPenalty: i32 = 25;
+    /// Four incidents against a fresh deployer trigger a suspension.
+    pub const SuspensionFloor: i32 = 1;
+    pub const MaxReputation: i32 = 1_000;
+}
+
+impl pallet_deployer_reputation::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    /// Scores are managed by the deployer administration.
+    type ScoreOrigin = DeployerAdminOrigin;
+    type ReporterOrigin = DeployerAdminOrigin;
+    type AddressMapping = EvmAddressToAccount;
+    type Suspension = EvmDeploymentControl;
+    type InitialScore = InitialReputation;
+    type DeploymentReward = DeploymentReward;
+    type IncidentPenalty = IncidentPenalty;
+    type SuspensionFloor = SuspensionFloor;
+    type MaxScore = MaxReputation;
+    type WeightInfo = pallet_deployer_reputation::weights::SubstrateWeight<Runtime>;
+}
+
+/// Records a contract deployed by a fixed EVM address for the contract names benchmarks
+#[cfg(feature = "runtime-benchmarks")]
+pub struct ContractNamesBenchmarkHelper;
+
+#[cfg(feature = "runtime-benchmarks")]
+impl pallet_contract_names::BenchmarkHelper for ContractNamesBenchmarkHelper {
+    fn record_deployment() -> H160 {
+        let contract = H160::repeat_byte(0xcc);
+        EvmDeploymentControl::note_contract_created(H160::repeat_byte(0x11), contract);
+        contract
+    }
+}
+
+impl pallet_contract_names::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type Provenance = EvmDeploymentControl;
+    type AddressMapping = EvmAddressToAccount;
+    type MaxNameLen = ConstU32<64>;
+    type WeightInfo = pallet_contract_names::weights::SubstrateWeight<Runtime>;
+    #[cfg(feature = "runtime-benchmarks")]
+    type BenchmarkHelper = ContractNamesBenchmarkHelper;
+}
+
+/// Records a contract deployed by a fixed EVM address for the audit anchors benchmarks
+#[cfg(feature = "runtime-benchmarks")]
+pub struct AuditAnchorsBenchmarkHelper;
+
+#[cfg(feature = "runtime-benchmarks")]
+impl pallet_audit_anchors::BenchmarkHelper for AuditAnchorsBenchmarkHelper {
+    fn record_deployment() -> H160 {
+        let contract = H160::repeat_byte(0xcd);
+        EvmDeploymentControl::note_contract_created(H160::repeat_byte(0x11), contract);
+        contract
+    }
+}
+
+impl pallet_audit_anchors::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type Provenance = EvmDeploymentControl;
+    type AddressMapping = EvmAddressToAccount;
+    type MaxAnchorsPerContract = ConstU32<32>;
+    type WeightInfo = pallet_audit_anchors::weights::SubstrateWeight<Runtime>;
+    #[cfg(feature = "runtime-benchmarks")]
+    type BenchmarkHelper = AuditAnchorsBenchmarkHelper;
+}
+
+#[cfg(all(feature = "dependency-registry", feature = "runtime-benchmarks"))]
+pub struct ContractDependenciesBenchmarkHelper;
+
+#[cfg(all(feature = "dependency-registry", feature = "runtime-benchmarks"))]
+impl pallet_contract_dependencies::BenchmarkHelper for ContractDependenciesBenchmarkHelper {
+    fn record_deployment() -> H160 {
+        let contract = H160::repeat_byte(0xce);
+        EvmDeploymentControl::note_contract_created(H160::repeat_byte(0x11), contract);
+        contract
+    }
+}
+
+#[cfg(feature = "dependency-registry")]
+impl pallet_contract_dependencies::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type Provenance = EvmDeploymentControl;
+    type AddressMapping = EvmAddressToAccount;
+    type MaxDependencies = ConstU32<16>;
+    /// Enough for the blast radius of a shared library or oracle, within a runtime API call.
+    type MaxBlastRadius = ConstU32<1024>;
+    type WeightInfo = pallet_contract_dependencies::weights::SubstrateWeight<Runtime>;
+    #[cfg(feature = "runtime-benchmarks")]
+    type BenchmarkHelper = ContractDependenciesBenchmarkHelper;
+}
+
+impl pallet_code_freeze::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    /// Canonical contracts are declared through sudo until governance is in place.
+    type ControlOrigin = EnsureRoot<AccountId>;
+    type MaxFrozenContracts = ConstU32<64>;
+    type WeightInfo = pallet_code_freeze::weights::SubstrateWeight<Runtime>;
+}
+
+impl pallet_outbound_commitments::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    /// Registry updates hold at most two accounts and a factory address.
+    type MaxMessageSize = ConstU32<128>;
+    type MaxMessagesPerBlock = ConstU32<256>;
+    type WeightInfo = pallet_outbound_commitments::weights::SubstrateWeight<Runtime>;
+}
+
+impl pallet_inbound_governance::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    /// Receipts roots are imported through sudo until an Ethereum light client is in place.
+    type HeaderOrigin = EnsureRoot<AccountId>;
+    type ControlOrigin = EnsureRoot<AccountId>;
+    type Deployers = EvmDeploymentControl;
+    /// About a day of Ethereum blocks to relay an instruction in.
+    type MaxHeaders = ConstU32<7200>;
+    type WeightInfo = pallet_inbound_governance::weights::SubstrateWeight<Runtime>;
+}
+
+/// Seeds the deprecated `seal_random` of Wasm contracts, which must not rely on it.
+impl pallet_insecure_randomness_collective_flip::Config for Runtime {}
+
+parameter_types! {
+    /// Storage items and bytes of Wasm contracts are charged like NFT metadata bytes.
+    pub const ContractDepositPerItem: Balance = CENTIUNIT;
+    pub const ContractDepositPerByte: Balance = MICROUNIT * 10;
+    pub const ContractDefaultDepositLimit: Balance = 10 * UNIT;
+    pub const CodeHashLockupDepositPercent: Perbill = Perbill::from_percent(30);
+    pub ContractsSchedule: pallet_contracts::Schedule<Runtime> = Default::default();
+}
+
+impl pallet_contracts::Config for Runtime {
+    type Time = Timestamp;
+    type Randomness = RandomnessCollectiveFlip;
+    type Currency = Balances;
+    type RuntimeEvent = RuntimeEvent;
+    type RuntimeCall = RuntimeCall;
+    type RuntimeHoldReason = RuntimeHoldReason;
+    /// Contracts cannot dispatch runtime calls.
+    type CallFilter = Nothing;
+    type WeightPrice = pallet_transaction_payment::Pallet<Self>;
+    type WeightInfo = pallet_contracts::weights::SubstrateWeight<Self>;
+    type ChainExtension = ();
+    type Schedule = ContractsSchedule;
+    type CallStack = [pallet_contracts::Frame<Self>; 5];
+    type DepositPerByte = ContractDepositPerByte;
+    type DefaultDepositLimit = ContractDefaultDepositLimit;
+    type DepositPerItem = ContractDepositPerItem;
+    type CodeHashLockupDepositPercent = CodeHashLockupDepositPercent;
+    type AddressGenerator = pallet_contracts::DefaultAddressGenerator;
+    type MaxCodeLen = ConstU32<{ 123 * 1024 }>;
+    type MaxStorageKeyLen = ConstU32<128>;
+    type MaxTransientStorageSize = ConstU32<{ 1024 * 1024 }>;
+    type MaxDelegateDependencies = ConstU32<32>;
+    type UnsafeUnstableInterface = ConstBool<false>;
+    type MaxDebugBufferLen = ConstU32<{ 2 * 1024 * 1024 }>;
+    /// Wasm contracts are uploaded and instantiated by the authorized EVM deployers only.
+    type UploadOrigin = EnsureAuthorizedDeployer<EvmDeploymentControl>;
+    type InstantiateOrigin = EnsureAuthorizedDeployer<EvmDeploymentControl>;
+    type Migrations = ();
+    type Debug = ();
+    type Environment = ();
+    type ApiVersion = ();
+    type Xcm = ();
+}
+
+#[cfg(feature = "compliance-mode")]
+impl pallet_transfer_allowlist::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    /// The compliance mode is managed through sudo until governance is in place.
+    type ControlOrigin = EnsureRoot<AccountId>;
+    type WeightInfo = pallet_transfer_allowlist::weights::SubstrateWeight<Runtime>;
+}
+
+parameter_types! {
+    /// A single pause or extension lasts at most a day.
+    pub const MaxPauseDuration: BlockNumber = DAYS;
+}
+
+/// Pallets needed to produce blocks or to lift a pause, which must never be paused.
+pub struct UnpausablePallets;
+
+impl Contains<pallet_emergency_pause::NameOf<Runtime>> for UnpausablePallets {
+    fn contains(pallet: &pallet_emergency_pause::NameOf<Runtime>) -> bool {
+        matches!(
+            pallet.as_slice(),
+            b"System" | b"ParachainSystem" | b"Timestamp" | b"Sudo" | b"EmergencyPause"
+        )
+    }
+}
+
+impl pallet_emergency_pause::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type RuntimeCall = RuntimeCall;
+    /// Pauses are fast-tracked through sudo until governance is in place.
+    type PauseOrigin = EnsureRoot<AccountId>;
+    type ResumeOrigin = EnsureRoot<AccountId>;
+    type UnpausablePallets = UnpausablePallets;
+    type MaxNameLen = ConstU32<64>;
+    type MaxPauses = ConstU32<32>;
+    type MaxPauseDuration = MaxPauseDuration;
+    type WeightInfo = pallet_emergency_pause::weights::SubstrateWeight<Runtime>;
+}
+
+parameter_types! {
+    /// Upgrades approved by the committee can be cancelled for a day before they are enacted.
+    pub const UpgradeEnactmentDelay: BlockNumber = DAYS;
+}
+
+impl pallet_upgrade_committee::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    /// The committee is named by two thirds of the council, never by sudo alone.
+    type CommitteeOrigin =
+        pallet_collective::EnsureProportionAtLeast<AccountId, CouncilCollective, 2, 3>;
+    /// Sudo can veto an upgrade, but not push one.
+    type CancelOrigin = EnsureRoot<AccountId>;
+    type EnactmentDelay = UpgradeEnactmentDelay;
+    type MaxApprovals = ConstU32<16>;
+    type WeightInfo = pallet_upgrade_committee::weights::SubstrateWeight<Runtime>;
+}
+
+impl pallet_elastic_scaling::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    /// The core count is managed through sudo until governance is in place.
+    type ScalingOrigin = EnsureRoot<AccountId>;
+    type MaxCores = ConstU32<MAX_CORES>;
+    type WeightInfo = pallet_elastic_scaling::weights::SubstrateWeight<Runtime>;
 }
 
 parameter_types! {
@@ -392,11 +999,17 @@ impl pallet_session::Config for Runtime {
     type ValidatorIdOf = pallet_collator_selection::IdentityCollator;
     type ShouldEndSession = pallet_session::PeriodicSessions<Period, Offset>;
     type NextSessionRotation = pallet_session::PeriodicSessions<Period, Offset>;
-    type SessionManager = CollatorSelection;
+    // Collator selection, with the candidate seats filled by delegated backing, slashing
+    // the collators that authored no block in the ending session.
+    type SessionManager = pallet_collator_offences::SlashUnavailable<
+        Runtime,
+        pallet_delegation::SelectByBacking<Runtime, CollatorSelection>,
+    >;
     // Essentially just Aura, but let's be pedantic.
     type SessionHandler = <SessionKeys as sp_runtime::traits::OpaqueKeys>::KeyTypeIdProviders;
     type Keys = SessionKeys;
-    type WeightInfo = (); // Configure based on benchmarking results.
+    // Weighs `set_keys` and `purge_keys` by the reference weights of pallet-session.
+    type WeightInfo = pallet_session::weights::SubstrateWeight<Runtime>;
 }
 
 impl pallet_aura::Config for Runtime {
@@ -435,3 +1048,478 @@ impl pallet_collator_selection::Config for Runtime {
     type ValidatorRegistration = Session;
     type WeightInfo = (); // Configure based on benchmarking results.
 }
+
+parameter_types! {
+    pub const CollatorRewardPotId: PalletId = PalletId(*b"qn/clrwd");
+    /// Block authors earn a centiunit per block, paid from the funded pot.
+    pub const DefaultCollatorReward: RewardConfig<Balance> =
+        RewardConfig { per_block: CENTIUNIT, source: RewardSource::Pot };
+    pub const MaxCollatorRewardPerBlock: Balance = 10 * UNIT;
+}
+
+impl pallet_collator_rewards::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type Currency = Balances;
+    /// The reward is set by sudo or a council majority.
+    type RewardOrigin = EitherOfDiverse<EnsureRoot<AccountId>, CouncilMajority>;
+    type PotId = CollatorRewardPotId;
+    type DefaultReward = DefaultCollatorReward;
+    type MaxRewardPerBlock = MaxCollatorRewardPerBlock;
+    /// Authors share their reward with their delegators.
+    type RewardShares = Delegation;
+    type WeightInfo = pallet_collator_rewards::weights::SubstrateWeight<Runtime>;
+}
+
+parameter_types! {
+    pub CollatorRewardPot: AccountId = CollatorRewards::pot_account();
+    /// Two centiunits per block, half to the treasury and half to the collator rewards pot,
+    /// which then matches the default collator reward.
+    pub const DefaultIssuanceSchedule: IssuanceSchedule<Balance> = IssuanceSchedule {
+        per_block: 2 * CENTIUNIT,
+        decay: Perbill::from_percent(10),
+        treasury_share: Percent::from_percent(50),
+    };
+    pub const IssuanceDecayPeriod: BlockNumber = 365 * DAYS;
+    pub const MaxIssuancePerBlock: Balance = UNIT;
+    pub const MaxIssuanceTreasuryShare: Percent = Percent::from_percent(50);
+}
+
+impl pallet_issuance::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type Currency = Balances;
+    type Treasury = ResolveTo<TreasuryAccount, Balances>;
+    type Collators = ResolveTo<CollatorRewardPot, Balances>;
+    /// The schedule is set by sudo or a council majority.
+    type ScheduleOrigin = EitherOfDiverse<EnsureRoot<AccountId>, CouncilMajority>;
+    type DefaultSchedule = DefaultIssuanceSchedule;
+    type DecayPeriod = IssuanceDecayPeriod;
+    type MaxIssuancePerBlock = MaxIssuancePerBlock;
+    type MaxTreasuryShare = MaxIssuanceTreasuryShare;
+    type WeightInfo = pallet_issuance::weights::SubstrateWeight<Runtime>;
+}
+
+/// Candidates of the collator selection, with their candidacy bond
+pub struct SelectionCandidates;
+
+impl CollatorCandidates<AccountId, Balance> for SelectionCandidates {
+    fn bond_of(who: &AccountId) -> Option<Balance> {
+        pallet_collator_selection::CandidateList::<Runtime>::get()
+            .into_iter()
+            .find(|candidate| candidate.who == *who)
+            .map(|candidate| candidate.deposit)
+    }
+
+    fn candidates() -> Vec<(AccountId, Balance)> {
+        pallet_collator_selection::CandidateList::<Runtime>::get()
+            .into_iter()
+            .map(|candidate| (candidate.who, candidate.deposit))
+            .collect()
+    }
+
+    #[cfg(feature = "runtime-benchmarks")]
+    fn add_candidate(who: &AccountId, bond: Balance) {
+        pallet_collator_selection::CandidateList::<Runtime>::mutate(|candidates| {
+            let candidate = pallet_collator_selection::CandidateInfo {
+                who: who.clone(),
+                deposit: bond,
+            };
+            candidates
+                .try_push(candidate)
+                .expect("candidate list is not full in benchmarks")
+        });
+    }
+}
+
+parameter_types! {
+    pub const MinDelegation: Balance = UNIT;
+    pub const DelegationUnbondingPeriod: BlockNumber = 7 * DAYS;
+}
+
+impl pallet_delegation::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type Currency = Balances;
+    type RuntimeHoldReason = RuntimeHoldReason;
+    type Candidates = SelectionCandidates;
+    type Slashed = ResolveTo<TreasuryAccount, Balances>;
+    /// Delegations are slashed by sudo or a council majority.
+    type SlashOrigin = EitherOfDiverse<EnsureRoot<AccountId>, CouncilMajority>;
+    type MinDelegation = MinDelegation;
+    type MaxDelegatorsPerCandidate = ConstU32<100>;
+    type MaxUnbondingChunks = ConstU32<8>;
+    type UnbondingPeriod = DelegationUnbondingPeriod;
+    type WeightInfo = pallet_delegation::weights::SubstrateWeight<Runtime>;
+}
+
+parameter_types! {
+    /// Equivocating collators lose half of their bond and delegations, collators authoring
+    /// no block in a session a hundredth.
+    pub const DefaultSlashFractions: SlashFractions = SlashFractions {
+        equivocation: Perbill::from_percent(50),
+        unavailability: Perbill::from_percent(1),
+    };
+    /// Well within the 4096 block hashes kept by `frame_system`
+    pub const MaxEquivocationReportAge: BlockNumber = 4 * HOURS;
+}
+
+/// Relay parent recorded in every header by `cumulus-pallet-parachain-system`
+pub struct RelayParentDigest;
+
+impl RelayParentOf<Header> for RelayParentDigest {
+    fn relay_parent_number(header: &Header) -> Option<u32> {
+        cumulus_primitives_core::rpsr_digest::extract_relay_parent_storage_root(&header.digest)
+            .map(|(_, number)| number)
+    }
+}
+
+/// Owners of the Aura keys, as registered with the session keys
+pub struct AuraKeyOwner;
+
+impl Convert<AuraId, Option<AccountId>> for AuraKeyOwner {
+    fn convert(key: AuraId) -> Option<AccountId> {
+        pallet_session::KeyOwner::<Runtime>::get((AURA, key.to_raw_vec()))
+    }
+}
+
+/// Collators of the current session
+pub struct SessionCollators;
+
+impl Get<Vec<AccountId>> for SessionCollators {
+    fn get() -> Vec<AccountId> {
+        pallet_session::Validators::<Runtime>::get()
+    }
+}
+
+/// Slashes the candidacy bond and the delegations of collators into the treasury
+///
+/// Invulnerables have no bond, so only their delegations are slashed.
+pub struct SlashBondAndDelegations;
+
+impl CollatorSlashing<AccountId> for SlashBondAndDelegations {
+    type Balance = Balance;
+
+    fn slash(collator: &AccountId, fraction: Perbill) -> Balance {
+        let mut slashed: Balance = 0;
+        pallet_collator_selection::CandidateList::<Runtime>::mutate(|candidates| {
+            if let Some(candidate) = candidates.iter_mut().find(|c| c.who == *collator) {
+                let (imbalance, _) =
+                    Balances::slash_reserved(collator, fraction.mul_floor(candidate.deposit));
+                slashed = imbalance.peek();
+                candidate.deposit.saturating_reduce(slashed);
+                Balances::resolve_creating(&TreasuryAccount::get(), imbalance);
+                // The candidate list is sorted by ascending deposit.
+                candidates.sort_by_key(|candidate| candidate.deposit);
+            }
+        });
+        slashed.saturating_add(Delegation::slash_delegations(collator, fraction))
+    }
+
+    fn remove_candidate(collator: &AccountId) {
+        let mut removed = None;
+        pallet_collator_selection::CandidateList::<Runtime>::mutate(|candidates| {
+            if let Some(index) = candidates.iter().position(|c| c.who == *collator) {
+                removed = Some(candidates.remove(index));
+            }
+        });
+        if let Some(candidate) = removed {
+            Balances::unreserve(collator, candidate.deposit);
+        }
+    }
+
+    fn weight() -> Weight {
+        use pallet_delegation::WeightInfo;
+
+        let delegators =
+            <<Runtime as pallet_delegation::Config>::MaxDelegatorsPerCandidate as Get<u32>>::get();
+        <Runtime as pallet_delegation::Config>::WeightInfo::slash(delegators)
+            .saturating_add(RocksDbWeight::get().reads_writes(3, 4))
+    }
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+pub struct CollatorOffencesBenchmarkHelper;
+
+#[cfg(feature = "runtime-benchmarks")]
+impl pallet_collator_offences::BenchmarkHelper<AccountId, AuraId>
+    for CollatorOffencesBenchmarkHelper
+{
+    fn register_collator(who: &AccountId, key: &AuraId) {
+        pallet_session::KeyOwner::<Runtime>::insert((AURA, key.to_raw_vec()), who.clone());
+        Balances::make_free_balance_be(who, 100 * UNIT);
+        Balances::reserve(who, 10 * UNIT).expect("the bond is free");
+        SelectionCandidates::add_candidate(who, 10 * UNIT);
+    }
+}
+
+impl pallet_collator_offences::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type AuthorityId = AuraId;
+    type KeyOwner = AuraKeyOwner;
+    type Collators = SessionCollators;
+    type Slashing = SlashBondAndDelegations;
+    type RelayParent = RelayParentDigest;
+    type MaxReportAge = MaxEquivocationReportAge;
+    /// Slash fractions are set by sudo or a council majority.
+    type SlashFractionOrigin = EitherOfDiverse<EnsureRoot<AccountId>, CouncilMajority>;
+    type DefaultSlashFractions = DefaultSlashFractions;
+    type WeightInfo = pallet_collator_offences::weights::SubstrateWeight<Runtime>;
+    #[cfg(feature = "runtime-benchmarks")]
+    type BenchmarkHelper = CollatorOffencesBenchmarkHelper;
+}
+
+parameter_types! {
+    pub const MinVestedTransfer: Balance = UNIT;
+    pub UnvestedFundsAllowedWithdrawReasons: WithdrawReasons =
+        WithdrawReasons::except(WithdrawReasons::TRANSFER | WithdrawReasons::RESERVE);
+}
+
+impl pallet_vesting::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type Currency = Balances;
+    type BlockNumberToBalance = ConvertInto;
+    type MinVestedTransfer = MinVestedTransfer;
+    type WeightInfo = pallet_vesting::weights::SubstrateWeight<Runtime>;
+    type UnvestedFundsAllowedWithdrawReasons = UnvestedFundsAllowedWithdrawReasons;
+    type BlockNumberProvider = System;
+    const MAX_VESTING_SCHEDULES: u32 = 28;
+}
+
+parameter_types! {
+    pub ClaimsPrefix: &'static [u8] = b"Pay QNCH to the QNCH account:";
+    /// Claims are free for their destination; they rank below deployment alerts.
+    pub const ClaimsPriority: TransactionPriority = TransactionPriority::MAX / 4;
+}
+
+impl pallet_claims::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type Currency = Balances;
+    /// Claim sets are published by sudo or a council majority.
+    type ClaimsOrigin = EitherOfDiverse<EnsureRoot<AccountId>, CouncilMajority>;
+    type Prefix = ClaimsPrefix;
+    type MaxProofLength = ConstU32<32>;
+    type UnsignedPriority = ClaimsPriority;
+    type WeightInfo = pallet_claims::weights::SubstrateWeight<Runtime>;
+}
+
+parameter_types! {
+    pub const NftCollectionDeposit: Balance = 10 * UNIT;
+    pub const NftItemDeposit: Balance = CENTIUNIT;
+    pub const NftMetadataDepositBase: Balance = 10 * CENTIUNIT;
+    pub const NftAttributeDepositBase: Balance = 10 * CENTIUNIT;
+    pub const NftDepositPerByte: Balance = MICROUNIT * 10;
+    pub NftFeatures: PalletFeatures = PalletFeatures::all_enabled();
+}
+
+impl pallet_nfts::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    /// Collection ids are 32 bit, the width of their ERC-721 precompile addresses.
+    type CollectionId = u32;
+    type ItemId = u32;
+    type Currency = Balances;
+    /// Collections are only issued by governance, through `force_create`.
+    type CreateOrigin = AsEnsureOriginWithArg<EnsureNever<AccountId>>;
+    type ForceOrigin = EitherOfDiverse<EnsureRoot<AccountId>, CouncilMajority>;
+    type Locker = ();
+    type CollectionDeposit = NftCollectionDeposit;
+    type ItemDeposit = NftItemDeposit;
+    type MetadataDepositBase = NftMetadataDepositBase;
+    type AttributeDepositBase = NftAttributeDepositBase;
+    type DepositPerByte = NftDepositPerByte;
+    type StringLimit = ConstU32<256>;
+    type KeyLimit = ConstU32<64>;
+    type ValueLimit = ConstU32<256>;
+    type ApprovalsLimit = ConstU32<20>;
+    type ItemAttributesApprovalsLimit = ConstU32<30>;
+    type MaxTips = ConstU32<10>;
+    type MaxDeadlineDuration = ConstU32<{ 365 * DAYS }>;
+    type MaxAttributesPerCall = ConstU32<10>;
+    type Features = NftFeatures;
+    type OffchainSignature = Signature;
+    type OffchainPublic = <Signature as Verify>::Signer;
+    #[cfg(feature = "runtime-benchmarks")]
+    type Helper = ();
+    type WeightInfo = pallet_nfts::weights::SubstrateWeight<Runtime>;
+}
+
+parameter_types! {
+    pub const AssetDeposit: Balance = 10 * UNIT;
+    pub const AssetAccountDeposit: Balance = CENTIUNIT;
+    pub const AssetMetadataDepositBase: Balance = 10 * CENTIUNIT;
+    pub const AssetMetadataDepositPerByte: Balance = MICROUNIT * 10;
+    pub const AssetApprovalDeposit: Balance = EXISTENTIAL_DEPOSIT;
+}
+
+impl pallet_assets::Config<pallet_assets::Instance1> for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type Balance = Balance;
+    type RemoveItemsLimit = ConstU32<1000>;
+    type AssetId = u32;
+    type AssetIdParameter = parity_scale_codec::Compact<u32>;
+    type Currency = Balances;
+    /// Anyone can create an asset for its deposit.
+    type CreateOrigin = AsEnsureOriginWithArg<EnsureSigned<AccountId>>;
+    type ForceOrigin = EitherOfDiverse<EnsureRoot<AccountId>, CouncilMajority>;
+    type AssetDeposit = AssetDeposit;
+    type AssetAccountDeposit = AssetAccountDeposit;
+    type MetadataDepositBase = AssetMetadataDepositBase;
+    type MetadataDepositPerByte = AssetMetadataDepositPerByte;
+    type ApprovalDeposit = AssetApprovalDeposit;
+    type StringLimit = ConstU32<50>;
+    type Freezer = ();
+    type Extra = ();
+    type CallbackHandle = ();
+    type WeightInfo = pallet_assets::weights::SubstrateWeight<Runtime>;
+    #[cfg(feature = "runtime-benchmarks")]
+    type BenchmarkHelper = ();
+}
+
+impl pallet_assets::Config<pallet_assets::Instance2> for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type Balance = Balance;
+    type RemoveItemsLimit = ConstU32<1000>;
+    type AssetId = u32;
+    type AssetIdParameter = u32;
+    type Currency = Balances;
+    /// Liquidity tokens are only created by the asset conversion pallet, with its pools.
+    type CreateOrigin = AsEnsureOriginWithArg<EnsureNever<AccountId>>;
+    type ForceOrigin = EitherOfDiverse<EnsureRoot<AccountId>, CouncilMajority>;
+    type AssetDeposit = ConstU128<0>;
+    type AssetAccountDeposit = AssetAccountDeposit;
+    type MetadataDepositBase = ConstU128<0>;
+    type MetadataDepositPerByte = ConstU128<0>;
+    type ApprovalDeposit = AssetApprovalDeposit;
+    type StringLimit = ConstU32<50>;
+    type Freezer = ();
+    type Extra = ();
+    type CallbackHandle = ();
+    type WeightInfo = pallet_assets::weights::SubstrateWeight<Runtime>;
+    #[cfg(feature = "runtime-benchmarks")]
+    type BenchmarkHelper = ();
+}
+
+parameter_types! {
+    pub const AssetConversionPalletId: PalletId = PalletId(*b"py/ascon");
+    pub const Native: NativeOrWithId<u32> = NativeOrWithId::Native;
+    /// Creating a pool costs as much as creating an asset, paid to the treasury.
+    pub const PoolSetupFee: Balance = 10 * UNIT;
+    pub const LiquidityWithdrawalFee: Permill = Permill::zero();
+}
+
+/// Native token and `pallet-assets` assets, the assets pools are made of
+pub type NativeAndAssets =
+    UnionOf<Balances, Assets, NativeFromLeft, NativeOrWithId<u32>, AccountId>;
+
+/// Account of the pool of a pair, which must contain the native token if any
+pub type PoolIdToAccountId =
+    AccountIdConverter<AssetConversionPalletId, (NativeOrWithId<u32>, NativeOrWithId<u32>)>;
+
+impl pallet_asset_conversion::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type Balance = Balance;
+    type HigherPrecisionBalance = U256;
+    type AssetKind = NativeOrWithId<u32>;
+    type Assets = NativeAndAssets;
+    type PoolId = (Self::AssetKind, Self::AssetKind);
+    type PoolLocator = Chain<
+        WithFirstAsset<Native, AccountId, NativeOrWithId<u32>, PoolIdToAccountId>,
+        Ascending<AccountId, NativeOrWithId<u32>, PoolIdToAccountId>,
+    >;
+    type PoolAssetId = u32;
+    type PoolAssets = PoolAssets;
+    type PoolSetupFee = PoolSetupFee;
+    type PoolSetupFeeAsset = Native;
+    type PoolSetupFeeTarget = ResolveAssetTo<TreasuryAccount, NativeAndAssets>;
+    type PalletId = AssetConversionPalletId;
+    /// 0.3% of every swap goes to the liquidity providers.
+    type LPFee = ConstU32<3>;
+    type LiquidityWithdrawalFee = LiquidityWithdrawalFee;
+    type MintMinLiquidity = ConstU128<100>;
+    type MaxSwapPathLength = ConstU32<4>;
+    type WeightInfo = pallet_asset_conversion::weights::SubstrateWeight<Runtime>;
+    #[cfg(feature = "runtime-benchmarks")]
+    type BenchmarkHelper = ();
+}
+
+impl pallet_asset_conversion_tx_payment::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type AssetId = NativeOrWithId<u32>;
+    /// Fees are paid in the fee assets registered in `pallet-fee-assets`, swapped to the
+    /// native token through their pool at most at their registered price, and split like
+    /// native fees.
+    type OnChargeAssetTransaction =
+        SwapAssetAdapter<Native, NativeAndAssets, RegisteredFeeAssets, DealWithAssetFees>;
+}
+
+impl pallet_fee_assets::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type AssetId = u32;
+    /// Fee assets are registered by sudo or a council majority.
+    type RegistrationOrigin = EitherOfDiverse<EnsureRoot<AccountId>, CouncilMajority>;
+    type MaxFeeAssets = ConstU32<8>;
+    type WeightInfo = pallet_fee_assets::weights::SubstrateWeight<Runtime>;
+}
+
+parameter_types! {
+    pub const FeeSponsorshipPalletId: PalletId = PalletId(*b"qn/spnsr");
+}
+
+/// Records the deployment of a contract by the EVM address of the benchmarked caller
+#[cfg(feature = "runtime-benchmarks")]
+pub struct FeeSponsorshipBenchmarkHelper;
+
+#[cfg(feature = "runtime-benchmarks")]
+impl pallet_fee_sponsorship::BenchmarkHelper for FeeSponsorshipBenchmarkHelper {
+    fn record_deployment() -> H160 {
+        let contract = H160::repeat_byte(0xcd);
+        EvmDeploymentControl::note_contract_created(H160::repeat_byte(0x11), contract);
+        contract
+    }
+}
+
+impl pallet_fee_sponsorship::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type Currency = Balances;
+    type Provenance = EvmDeploymentControl;
+    type AddressMapping = EvmAddressToAccount;
+    type PalletId = FeeSponsorshipPalletId;
+    type MaxSponsoredContracts = ConstU32<64>;
+    type WeightInfo = pallet_fee_sponsorship::weights::SubstrateWeight<Runtime>;
+    #[cfg(feature = "runtime-benchmarks")]
+    type BenchmarkHelper = FeeSponsorshipBenchmarkHelper;
+}
+
+parameter_types! {
+    /// Contracts prove themselves for a month before their escrow can be released
+    pub const EscrowSafePeriod: BlockNumber = 30 * DAYS;
+    /// Users get a week's notice to raise claims against a releasing escrow
+    pub const EscrowReleaseDelay: BlockNumber = 7 * DAYS;
+}
+
+/// Records a contract deployed by a fixed EVM address for the deployment escrow benchmarks
+#[cfg(feature = "runtime-benchmarks")]
+pub struct DeploymentEscrowBenchmarkHelper;
+
+#[cfg(feature = "runtime-benchmarks")]
+impl pallet_deployment_escrow::BenchmarkHelper for DeploymentEscrowBenchmarkHelper {
+    fn record_deployment() -> H160 {
+        let contract = H160::repeat_byte(0xce);
+        EvmDeploymentControl::note_contract_created(H160::repeat_byte(0x11), contract);
+        contract
+    }
+}
+
+impl pallet_deployment_escrow::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type Currency = Balances;
+    type RuntimeHoldReason = RuntimeHoldReason;
+    type Provenance = EvmDeploymentControl;
+    type AddressMapping = EvmAddressToAccount;
+    /// Contracts are ruled on through sudo until governance is in place.
+    type GovernanceOrigin = EnsureRoot<AccountId>;
+    type Slashed = ResolveTo<TreasuryAccount, Balances>;
+    type SafePeriod = EscrowSafePeriod;
+    type ReleaseDelay = EscrowReleaseDelay;
+    type MaxPayouts = ConstU32<64>;
+    type WeightInfo = pallet_deployment_escrow::weights::SubstrateWeight<Runtime>;
+    #[cfg(feature = "runtime-benchmarks")]
+    type BenchmarkHelper = DeploymentEscrowBenchmarkHelper;
+}
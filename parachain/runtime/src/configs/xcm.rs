@@ -40,12 +40,12 @@ use polkadot_runtime_common::impls::ToAuthor;
 use sp_runtime::traits::AccountIdConversion;
 use xcm::latest::prelude::*;
 use xcm_builder::{
-    AccountId32Aliases, AllowExplicitUnpaidExecutionFrom, AllowTopLevelPaidExecutionFrom,
+    AllowExplicitUnpaidExecutionFrom, AllowTopLevelPaidExecutionFrom,
     DenyReserveTransferToRelayChain, DenyThenTry, EnsureXcmOrigin, FixedWeightBounds,
     FrameTransactionalProcessor, FungibleAdapter, IsConcrete, NativeAsset, ParentIsPreset,
     RelayChainAsNative, SiblingParachainAsNative, SiblingParachainConvertsVia,
-    SignedAccountId32AsNative, SignedToAccountId32, SovereignSignedViaLocation, TakeWeightCredit,
-    TrailingSetTopicAsId, UsingComponents, WithComputedOrigin, WithUniqueTopic,
+    SovereignSignedViaLocation, TakeWeightCredit, TrailingSetTopicAsId, UsingComponents,
+    WithComputedOrigin, WithUniqueTopic,
 };
 use xcm_executor::XcmExecutor;
 
@@ -60,6 +60,24 @@ parameter_types! {
     pub TreasuryAccount: AccountId = TREASURY_PALLET_ID.into_account_truncating();
 }
 
+/// Local `AccountId32` locations, aliasing directly to the 32-byte `AccountId`.
+#[cfg(not(feature = "unified-accounts"))]
+pub type LocalAccountAliases = xcm_builder::AccountId32Aliases<RelayNetwork, AccountId>;
+
+/// Local `AccountKey20` locations, aliasing directly to the 20-byte `AccountId`.
+#[cfg(feature = "unified-accounts")]
+pub type LocalAccountAliases = xcm_builder::AccountKey20Aliases<RelayNetwork, AccountId>;
+
+/// Native signed origins of local account locations of the same value.
+#[cfg(not(feature = "unified-accounts"))]
+pub type SignedAccountAsNative =
+    xcm_builder::SignedAccountId32AsNative<RelayNetwork, RuntimeOrigin>;
+
+/// Native signed origins of local account locations of the same value.
+#[cfg(feature = "unified-accounts")]
+pub type SignedAccountAsNative =
+    xcm_builder::SignedAccountKey20AsNative<RelayNetwork, RuntimeOrigin>;
+
 /// Type for specifying how a `Location` can be converted into an `AccountId`. This is used
 /// when determining ownership of accounts for asset transacting and when attempting to use XCM
 /// `Transact` in order to determine the dispatch Origin.
@@ -68,8 +86,8 @@ pub type LocationToAccountId = (
     ParentIsPreset<AccountId>,
     // Sibling parachain origins convert to AccountId via the `ParaId::into`.
     SiblingParachainConvertsVia<Sibling, AccountId>,
-    // Straight up local `AccountId32` origins just alias directly to `AccountId`.
-    AccountId32Aliases<RelayNetwork, AccountId>,
+    // Straight up local account origins just alias directly to `AccountId`.
+    LocalAccountAliases,
 );
 
 /// Means for transacting assets on this chain.
@@ -78,7 +96,7 @@ pub type LocalAssetTransactor = FungibleAdapter<
     Balances,
     // Use this currency when it is a fungible asset matching the given location or name:
     IsConcrete<RelayLocation>,
-    // Do a simple punn to convert an AccountId32 Location into a native chain account ID:
+    // Do a simple punn to convert an account Location into a native chain account ID:
     LocationToAccountId,
     // Our chain's account ID type (we can't get away without mentioning it explicitly):
     AccountId,
@@ -100,9 +118,9 @@ pub type XcmOriginToTransactDispatchOrigin = (
     // Native converter for sibling Parachains; will convert to a `SiblingPara` origin when
     // recognized.
     SiblingParachainAsNative<cumulus_pallet_xcm::Origin, RuntimeOrigin>,
-    // Native signed account converter; this just converts an `AccountId32` origin into a normal
-    // `RuntimeOrigin::Signed` origin of the same 32-byte value.
-    SignedAccountId32AsNative<RelayNetwork, RuntimeOrigin>,
+    // Native signed account converter; this just converts an account origin into a normal
+    // `RuntimeOrigin::Signed` origin of the same value.
+    SignedAccountAsNative,
     // Xcm origins can be represented natively under the Xcm pallet's Xcm origin.
     XcmPassthrough<RuntimeOrigin>,
 );
@@ -185,7 +203,30 @@ impl xcm_executor::Config for XcmConfig {
 }
 
 /// No local origins on this chain are allowed to dispatch XCM sends/executions.
-pub type LocalOriginToLocation = SignedToAccountId32<RuntimeOrigin, AccountId, RelayNetwork>;
+#[cfg(not(feature = "unified-accounts"))]
+pub type LocalOriginToLocation =
+    xcm_builder::SignedToAccountId32<RuntimeOrigin, AccountId, RelayNetwork>;
+
+/// No local origins on this chain are allowed to dispatch XCM sends/executions.
+#[cfg(feature = "unified-accounts")]
+pub type LocalOriginToLocation = SignedToAccountKey20;
+
+/// Converts a signed origin into the `AccountKey20` location of its 20-byte account
+///
+/// The 20-byte counterpart of `SignedToAccountId32`, which `xcm_builder` lacks.
+#[cfg(feature = "unified-accounts")]
+pub struct SignedToAccountKey20;
+
+#[cfg(feature = "unified-accounts")]
+impl sp_runtime::traits::TryConvert<RuntimeOrigin, Location> for SignedToAccountKey20 {
+    fn try_convert(origin: RuntimeOrigin) -> Result<Location, RuntimeOrigin> {
+        match origin.clone().into() {
+            Ok(frame_system::RawOrigin::<AccountId>::Signed(who)) =>
+                Ok(AccountKey20 { network: RelayNetwork::get(), key: who.into() }.into()),
+            _ => Err(origin),
+        }
+    }
+}
 
 /// The means for routing XCM messages which are not for local execution into the right message
 /// queues.
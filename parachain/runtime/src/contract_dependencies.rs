@@ -0,0 +1,39 @@
+//! Contract dependency queries served by the dependency registry
+//!
+//! The registry's types are mirrored by `pallet-contract-dependencies-runtime-api`, so that
+//! the runtime API is the same whether or not the registry is built in.
+
+use pallet_contract_dependencies::{Dependency, DependencyKind};
+use pallet_contract_dependencies_runtime_api::{
+    Dependency as ApiDependency, DependencyKind as ApiDependencyKind,
+};
+use sp_core::H160;
+use sp_std::prelude::*;
+
+use crate::ContractDependencies;
+
+fn into_api(dependency: Dependency) -> ApiDependency {
+    ApiDependency {
+        contract: dependency.contract,
+        kind: match dependency.kind {
+            DependencyKind::Library => ApiDependencyKind::Library,
+            DependencyKind::Oracle => ApiDependencyKind::Oracle,
+            DependencyKind::Other => ApiDependencyKind::Other,
+        },
+    }
+}
+
+/// Contracts `contract` is declared to depend on, in order of declaration
+pub fn dependencies(contract: H160) -> Vec<ApiDependency> {
+    ContractDependencies::dependencies(&contract).into_iter().map(into_api).collect()
+}
+
+/// Contracts declared to depend on `contract`
+pub fn dependents(contract: H160) -> Vec<ApiDependency> {
+    ContractDependencies::dependents(&contract).into_iter().map(into_api).collect()
+}
+
+/// Contracts depending on `contract` directly or through other contracts
+pub fn blast_radius(contract: H160) -> Vec<H160> {
+    ContractDependencies::blast_radius(&contract)
+}
@@ -1,41 +1,64 @@
-use pallet_evm::{AddressMapping, EnsureAddressOrigin, OnCreate};
-use sp_core::H160;
-use sp_std::marker::PhantomData;
+use fp_evm::{CallInfo, CreateInfo, ExitReason};
+use frame_support::{
+    traits::{Contains, EnsureOrigin},
+    weights::Weight,
+};
+use pallet_ethereum::Call::transact;
+use pallet_evm::{
+    AddressMapping, EnsureAddressOrigin, EvmConfig, FeeCalculator, GasWeightMapping, OnCreate,
+    Runner, RunnerError,
+};
+use pallet_evm_deployment_control::{
+    DeploymentAuthorizer, DeploymentLifecycle, DeploymentPolicy, DeploymentValidationError,
+    NestedCalls,
+};
+use pallet_evm_deployment_control_runtime_api::DeploymentRefusal;
+use parity_scale_codec::{Decode, Encode};
+use scale_info::TypeInfo;
+use sp_core::{H160, H256, U256};
+use sp_runtime::{
+    traits::{Convert, DispatchInfoOf, SignedExtension, UniqueSaturatedInto},
+    transaction_validity::{
+        InvalidTransaction, TransactionValidity, TransactionValidityError, ValidTransaction,
+    },
+    DispatchError,
+};
+use sp_std::{marker::PhantomData, prelude::*};
 
-/// EVM deployment control using pallet-evm-deployment-control
-///
-/// This implementation enforces that only authorized deployers (managed by
-/// the pallet-evm-deployment-control pallet) can deploy contracts directly to the EVM.
-///
-/// Authorization is managed through the EvmDeploymentControl pallet via root/sudo:
-/// - Sudo can authorize new deployers: `authorizeDeployer(account)`
-/// - Sudo can revoke deployers: `revokeDeployer(account)`
-/// - Regular users must deploy through pre-approved factory contracts
-///
-/// This provides runtime-level enforcement of deployment restrictions,
-/// which is more secure than application-level enforcement alone.
+use crate::{
+    invalid_transaction::CustomInvalidity, is_contract_creation, AccountId, DeploymentEscrow,
+    EvmDeploymentControl, Runtime, RuntimeCall, RuntimeOrigin, UncheckedExtrinsic,
+};
 
-/// OnCreate hook that restricts contract deployments to authorized deployers only
+/// OnCreate hook announcing every contract created by the EVM to the lifecycle subscribers
 ///
-/// Note: This panics (reverts the transaction) if the deployer is not authorized.
-/// Panicking in the OnCreate hook will cause the entire EVM transaction to fail.
-pub struct RestrictedDeployment<T>(PhantomData<T>);
+/// The deployment control pallet is one of the subscribers and records the provenance
+/// exposed through the `qnch_contractInfo` RPC.
+pub struct NotifyDeployed<L>(PhantomData<L>);
 
-impl<T> OnCreate<T> for RestrictedDeployment<T>
+impl<T, L> OnCreate<T> for NotifyDeployed<L>
 where
-    T: pallet_evm_deployment_control::Config + pallet_evm::Config,
+    L: DeploymentLifecycle<AccountId>,
 {
-    fn on_create(owner: H160, _contract: H160) {
-        // Convert H160 to AccountId using the runtime's address mapping
-        let account_id = T::AddressMapping::into_account_id(owner);
+    fn on_create(owner: H160, contract: H160) {
+        L::on_deployed(&owner, &contract);
+    }
+}
 
-        // Check if deployer is authorized - panic if not (this will revert the transaction)
-        if !pallet_evm_deployment_control::Pallet::<T>::is_authorized(&account_id) {
-            panic!("Deployer not authorized");
-        }
+/// OnCreate hook noting nested contract creations for [`FailUnauthorizedCreations`]
+pub struct NoteNestedCreations;
+
+impl OnCreate<Runtime> for NoteNestedCreations {
+    fn on_create(owner: H160, contract: H160) {
+        EvmDeploymentControl::note_nested_creation(owner, contract);
     }
 }
 
+/// EVM withdraw origin admitting the authorized deployers only
+///
+/// The signer must control the account the runtime's address mapping gives for the EVM
+/// address: its own account, which with `unified-accounts` is the address itself, or a
+/// contract wallet it operates.
 pub struct EnsureSudoCanDeploy<T, I = ()>(PhantomData<(T, I)>);
 
 impl<T, I> EnsureAddressOrigin<T::RuntimeOrigin> for EnsureSudoCanDeploy<T, I>
@@ -49,23 +72,554 @@ where
         address: &H160,
         origin: T::RuntimeOrigin,
     ) -> Result<Self::Success, T::RuntimeOrigin> {
+        sp_tracing::enter_span!(sp_tracing::trace_span!(
+            target: pallet_evm_deployment_control::TRACING_TARGET,
+            "withdraw_origin"
+        ));
+
         // Convert H160 address to AccountId using the runtime's address mapping
         let account_id = T::AddressMapping::into_account_id(*address);
 
         // Ensure origin is signed
         let who = frame_system::ensure_signed(origin.clone()).map_err(|_| origin.clone())?;
 
-        // Check if the address matches the signed origin
-        if who != account_id {
+        // Check if the address is the signer's own or a contract wallet it operates, in
+        // which case the wallet is the immediate EVM caller and deployer
+        if !pallet_evm_deployment_control::Pallet::<T>::controls(&who, &account_id) {
+            return Err(origin);
+        }
+
+        // Check if the deployment policy admits the deployer within a deployment window. The
+        // refusal is not counted as a rejected attempt here: the failing dispatch rolls the
+        // count back, refused creations are counted by `CheckDeployment`, the Ethereum
+        // transaction checks and `deploy_contract` instead
+        pallet_evm_deployment_control::Pallet::<T>::ensure_can_deploy(&account_id)
+            .map(|()| account_id)
+            .map_err(|_| origin)
+    }
+}
+
+/// Base call filter enforcing deployment control on Substrate-level EVM calls
+///
+/// `pallet_evm::create` and `create2` take the deployer as a call argument rather than
+/// going through `validate_self_contained`, so they are checked here instead. Being the
+/// `BaseCallFilter`, it also applies to every call dispatched from within
+/// `pallet_utility` batches with a non-root origin, so batching cannot be used to
+/// smuggle a deployment past the policy. Root bypasses the filter, as it can authorize
+/// deployers anyway.
+///
+/// The filter only reads state. The creations it admits are counted against the per-block
+/// creation cap and the deployer's quota by [`CheckDeployment`] in `pre_dispatch`, which also
+/// refuses them once either is exhausted, so an exhausted quota is left to it here.
+///
+/// The filter cannot charge the fee of a fee-charging permissionless policy, so under such
+/// a policy these calls stay reserved to authorized deployers; everyone else deploys
+/// through Ethereum transactions or `deploy_contract`, which charge it.
+pub struct DeploymentCallFilter;
+
+impl Contains<RuntimeCall> for DeploymentCallFilter {
+    fn contains(call: &RuntimeCall) -> bool {
+        match call {
+            RuntimeCall::EVM(pallet_evm::Call::create { source, .. })
+            | RuntimeCall::EVM(pallet_evm::Call::create2 { source, .. }) => {
+                sp_tracing::enter_span!(sp_tracing::trace_span!(
+                    target: pallet_evm_deployment_control::TRACING_TARGET,
+                    "call_filter"
+                ));
+                let account_id =
+                    <Runtime as pallet_evm::Config>::AddressMapping::into_account_id(*source);
+                if !may_create_through_evm(&account_id) {
+                    return false;
+                }
+
+                // The quota is checked last, so a refusal for it means the creation was
+                // otherwise admitted
+                matches!(
+                    EvmDeploymentControl::ensure_can_deploy(&account_id),
+                    Ok(()) | Err(DeploymentValidationError::QuotaExhausted)
+                )
+            }
+            _ => true,
+        }
+    }
+}
+
+/// Whether the deployment policy lets `account` create contracts through `pallet_evm`
+///
+/// Under a fee-charging permissionless policy only authorized deployers may, as the fee
+/// cannot be charged on this path.
+fn may_create_through_evm(account: &AccountId) -> bool {
+    !matches!(
+        EvmDeploymentControl::deployment_policy(),
+        DeploymentPolicy::PermissionlessWithFee { .. }
+    ) || EvmDeploymentControl::is_authorized(account)
+}
+
+/// EVM addresses creating contracts in `call`, or in a call nested in it, with whether the
+/// creation is a `pallet_evm::create` or `create2` rather than a `deploy_contract`
+fn creators(call: &RuntimeCall) -> Vec<(H160, bool)> {
+    match call {
+        RuntimeCall::EVM(
+            pallet_evm::Call::create { source, .. } | pallet_evm::Call::create2 { source, .. },
+        ) => vec![(*source, true)],
+        RuntimeCall::EvmDeploymentControl(
+            pallet_evm_deployment_control::Call::deploy_contract { source, .. },
+        ) => vec![(*source, false)],
+        call => UtilityCalls::nested_calls(call).into_iter().flat_map(creators).collect(),
+    }
+}
+
+/// Counts Substrate-level contract creations against the creation cap and quota and refused
+/// ones as rejected attempts, and refuses the creations of flagged accounts and of deployers
+/// who cannot afford the escrow
+///
+/// `pallet_evm::create` and `create2` calls admitted by [`DeploymentCallFilter`] are counted
+/// against the per-block creation cap and the deployer's quota in `pre_dispatch`, like
+/// Ethereum transactions in `pre_dispatch_self_contained`, and refused once either is
+/// exhausted, the cap with `ExhaustsResources` deferring the transaction to a later block.
+/// As with Ethereum transactions, a creation the EVM then fails still counts.
+///
+/// `pallet_evm::create` and `create2` calls of unauthorized deployers, batched ones
+/// included, are refused by [`DeploymentCallFilter`] once dispatched, and a count made by
+/// the failing dispatch would be rolled back with it. They are counted in `pre_dispatch`
+/// instead, whose changes stand whatever the dispatch makes of the call. `deploy_contract`
+/// counts its refusals itself.
+///
+/// Flagged accounts are refused at validation, so that their creations, `deploy_contract`
+/// included, no longer reach a block until governance unflags them. So are permitted
+/// deployers who cannot afford the escrow the deployment escrow policy requires, which is
+/// checked again in `pre_dispatch` as the balance may have been spent since.
+///
+/// Carries no data, so signers need not know about it.
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, Default, TypeInfo)]
+pub struct CheckDeployment;
+
+impl CheckDeployment {
+    /// Refuse the creations in `call` of flagged accounts, of deployers who cannot afford the
+    /// escrow and of `pallet_evm` deployers past the creation cap or their quota
+    ///
+    /// If `dispatching` is set, the admitted `pallet_evm` creations are counted against the
+    /// cap and quota, and the refused ones of unauthorized accounts as rejected attempts.
+    fn check(call: &RuntimeCall, dispatching: bool) -> Result<(), TransactionValidityError> {
+        for (source, through_evm) in creators(call) {
+            let account = <Runtime as pallet_evm::Config>::AddressMapping::into_account_id(source);
+            match EvmDeploymentControl::ensure_can_deploy(&account) {
+                Ok(()) if DeploymentEscrow::ensure_can_escrow(&account).is_err() => {
+                    return Err(CustomInvalidity::EscrowUnaffordable.into());
+                }
+                Ok(()) if through_evm && may_create_through_evm(&account) => {
+                    Self::count_creation(&account, dispatching)?;
+                }
+                Err(err @ DeploymentValidationError::FlaggedDeployer) => {
+                    return Err(CustomInvalidity::from(err).into());
+                }
+                Err(err @ DeploymentValidationError::QuotaExhausted)
+                    if through_evm && may_create_through_evm(&account) =>
+                {
+                    return Err(CustomInvalidity::from(err).into());
+                }
+                Err(DeploymentValidationError::UnauthorizedDeployer)
+                    if dispatching && through_evm =>
+                {
+                    EvmDeploymentControl::note_rejected_attempt(&account);
+                }
+                _ => {}
+            }
+        }
+        Ok(())
+    }
+
+    /// Count a `pallet_evm` creation of `account` against the per-block creation cap and its
+    /// quota if `dispatching` is set, or only check that the cap has room otherwise
+    fn count_creation(
+        account: &AccountId,
+        dispatching: bool,
+    ) -> Result<(), TransactionValidityError> {
+        let exhausts_resources =
+            TransactionValidityError::Invalid(InvalidTransaction::ExhaustsResources);
+        if !dispatching {
+            return match EvmDeploymentControl::remaining_creates() {
+                Some(0) => Err(exhausts_resources),
+                _ => Ok(()),
+            };
+        }
+
+        if !EvmDeploymentControl::try_register_create() {
+            return Err(exhausts_resources);
+        }
+        if !EvmDeploymentControl::try_use_quota(account) {
+            return Err(CustomInvalidity::QuotaExhausted.into());
+        }
+        Ok(())
+    }
+}
+
+impl core::fmt::Debug for CheckDeployment {
+    #[cfg(feature = "std")]
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(f, "CheckDeployment")
+    }
+
+    #[cfg(not(feature = "std"))]
+    fn fmt(&self, _: &mut core::fmt::Formatter) -> core::fmt::Result {
+        Ok(())
+    }
+}
+
+impl SignedExtension for CheckDeployment {
+    const IDENTIFIER: &'static str = "CheckDeployment";
+    type AccountId = AccountId;
+    type Call = RuntimeCall;
+    type AdditionalSigned = ();
+    type Pre = ();
+
+    fn additional_signed(&self) -> Result<(), TransactionValidityError> {
+        Ok(())
+    }
+
+    fn validate(
+        &self,
+        _who: &AccountId,
+        call: &RuntimeCall,
+        _info: &DispatchInfoOf<RuntimeCall>,
+        _len: usize,
+    ) -> TransactionValidity {
+        Self::check(call, false)?;
+        Ok(ValidTransaction::default())
+    }
+
+    fn pre_dispatch(
+        self,
+        _who: &AccountId,
+        call: &RuntimeCall,
+        _info: &DispatchInfoOf<RuntimeCall>,
+        _len: usize,
+    ) -> Result<(), TransactionValidityError> {
+        Self::check(call, true)
+    }
+}
+
+type StackRunner = pallet_evm::runner::stack::Runner<Runtime>;
+
+/// Init code run in place of the init code of refused creations: INVALID
+const FAILING_INIT_CODE: [u8; 1] = [0xfe];
+
+/// EVM runner failing the contract creations the deployment policy refuses
+///
+/// Ethereum transactions creating a contract from an unauthorized deployer are included, so
+/// that the attempt is counted, see `pre_dispatch_self_contained`. Their init code is swapped
+/// for INVALID, so that the creation fails consuming its gas and the sender's nonce like
+/// any failed transaction, instead of being refused for free. Other creation paths are
+/// refused before they reach the runner.
+///
+/// While the opcode policy limits nested contract creations, creations are noted by
+/// [`NoteNestedCreations`], and an execution nesting them deeper than `MaxCreateDepth`
+/// fails once it ends with `CreationTooDeep`, which the failing dispatch rolls back.
+pub struct FailUnauthorizedCreations;
+
+impl FailUnauthorizedCreations {
+    /// Fail `result` if the execution nested contract creations too deep
+    fn refuse_too_deep_creations<I>(
+        result: Result<I, RunnerError<pallet_evm::Error<Runtime>>>,
+        used_gas: impl FnOnce(&I) -> U256,
+    ) -> Result<I, RunnerError<DispatchError>> {
+        let too_deep = EvmDeploymentControl::take_too_deep_creation();
+        let info = result.map_err(|e| RunnerError { error: e.error.into(), weight: e.weight })?;
+        match too_deep {
+            None => Ok(info),
+            Some(_) => Err(RunnerError {
+                error: pallet_evm_deployment_control::Error::<Runtime>::CreationTooDeep.into(),
+                weight: <Runtime as pallet_evm::Config>::GasWeightMapping::gas_to_weight(
+                    used_gas(&info).unique_saturated_into(),
+                    true,
+                ),
+            }),
+        }
+    }
+
+    /// Init code to run for a creation of `source`
+    fn init_code(source: H160, init: Vec<u8>) -> Vec<u8> {
+        let account = <Runtime as pallet_evm::Config>::AddressMapping::into_account_id(source);
+        match EvmDeploymentControl::ensure_permitted(&account) {
+            Err(DeploymentValidationError::UnauthorizedDeployer) => FAILING_INIT_CODE.to_vec(),
+            _ => init,
+        }
+    }
+}
+
+impl Runner<Runtime> for FailUnauthorizedCreations {
+    type Error = DispatchError;
+
+    fn validate(
+        source: H160,
+        target: Option<H160>,
+        input: Vec<u8>,
+        value: U256,
+        gas_limit: u64,
+        max_fee_per_gas: Option<U256>,
+        max_priority_fee_per_gas: Option<U256>,
+        nonce: Option<U256>,
+        access_list: Vec<(H160, Vec<H256>)>,
+        is_transactional: bool,
+        weight_limit: Option<Weight>,
+        proof_size_base_cost: Option<u64>,
+        evm_config: &EvmConfig,
+    ) -> Result<(), RunnerError<Self::Error>> {
+        StackRunner::validate(
+            source,
+            target,
+            input,
+            value,
+            gas_limit,
+            max_fee_per_gas,
+            max_priority_fee_per_gas,
+            nonce,
+            access_list,
+            is_transactional,
+            weight_limit,
+            proof_size_base_cost,
+            evm_config,
+        )
+        .map_err(|e| RunnerError { error: e.error.into(), weight: e.weight })
+    }
+
+    fn call(
+        source: H160,
+        target: H160,
+        input: Vec<u8>,
+        value: U256,
+        gas_limit: u64,
+        max_fee_per_gas: Option<U256>,
+        max_priority_fee_per_gas: Option<U256>,
+        nonce: Option<U256>,
+        access_list: Vec<(H160, Vec<H256>)>,
+        is_transactional: bool,
+        validate: bool,
+        weight_limit: Option<Weight>,
+        proof_size_base_cost: Option<u64>,
+        config: &EvmConfig,
+    ) -> Result<CallInfo, RunnerError<Self::Error>> {
+        let result = StackRunner::call(
+            source,
+            target,
+            input,
+            value,
+            gas_limit,
+            max_fee_per_gas,
+            max_priority_fee_per_gas,
+            nonce,
+            access_list,
+            is_transactional,
+            validate,
+            weight_limit,
+            proof_size_base_cost,
+            config,
+        );
+        Self::refuse_too_deep_creations(result, |info| info.used_gas.standard)
+    }
+
+    fn create(
+        source: H160,
+        init: Vec<u8>,
+        value: U256,
+        gas_limit: u64,
+        max_fee_per_gas: Option<U256>,
+        max_priority_fee_per_gas: Option<U256>,
+        nonce: Option<U256>,
+        access_list: Vec<(H160, Vec<H256>)>,
+        is_transactional: bool,
+        validate: bool,
+        weight_limit: Option<Weight>,
+        proof_size_base_cost: Option<u64>,
+        config: &EvmConfig,
+    ) -> Result<CreateInfo, RunnerError<Self::Error>> {
+        let result = StackRunner::create(
+            source,
+            Self::init_code(source, init),
+            value,
+            gas_limit,
+            max_fee_per_gas,
+            max_priority_fee_per_gas,
+            nonce,
+            access_list,
+            is_transactional,
+            validate,
+            weight_limit,
+            proof_size_base_cost,
+            config,
+        );
+        Self::refuse_too_deep_creations(result, |info| info.used_gas.standard)
+    }
+
+    fn create2(
+        source: H160,
+        init: Vec<u8>,
+        salt: H256,
+        value: U256,
+        gas_limit: u64,
+        max_fee_per_gas: Option<U256>,
+        max_priority_fee_per_gas: Option<U256>,
+        nonce: Option<U256>,
+        access_list: Vec<(H160, Vec<H256>)>,
+        is_transactional: bool,
+        validate: bool,
+        weight_limit: Option<Weight>,
+        proof_size_base_cost: Option<u64>,
+        config: &EvmConfig,
+    ) -> Result<CreateInfo, RunnerError<Self::Error>> {
+        let result = StackRunner::create2(
+            source,
+            Self::init_code(source, init),
+            salt,
+            value,
+            gas_limit,
+            max_fee_per_gas,
+            max_priority_fee_per_gas,
+            nonce,
+            access_list,
+            is_transactional,
+            validate,
+            weight_limit,
+            proof_size_base_cost,
+            config,
+        );
+        Self::refuse_too_deep_creations(result, |info| info.used_gas.standard)
+    }
+}
+
+/// Signed origin of an account the authorizer `A` lets deploy contracts
+///
+/// The `UploadOrigin` and `InstantiateOrigin` of `pallet-contracts`, so that Wasm contracts
+/// are uploaded and instantiated by the same deployers as EVM contracts. Deployment
+/// windows and permissionless policies only concern EVM deployments.
+pub struct EnsureAuthorizedDeployer<A>(PhantomData<A>);
+
+impl<A> EnsureOrigin<RuntimeOrigin> for EnsureAuthorizedDeployer<A>
+where
+    A: DeploymentAuthorizer<AccountId>,
+{
+    type Success = AccountId;
+
+    fn try_origin(origin: RuntimeOrigin) -> Result<Self::Success, RuntimeOrigin> {
+        let who = frame_system::ensure_signed(origin.clone()).map_err(|_| origin.clone())?;
+        if !A::is_authorized(&who) {
             return Err(origin);
         }
 
-        // Check if deployer is authorized via the pallet
-        if pallet_evm_deployment_control::Pallet::<T>::is_authorized(&who) {
-            return Ok(who);
+        Ok(who)
+    }
+
+    /// No account is authorized without a prior call to the authorizer
+    #[cfg(feature = "runtime-benchmarks")]
+    fn try_successful_origin() -> Result<RuntimeOrigin, ()> {
+        Err(())
+    }
+}
+
+/// Account controlled by an EVM address, following the runtime's address mapping
+pub struct EvmAddressToAccount;
+
+impl Convert<H160, AccountId> for EvmAddressToAccount {
+    fn convert(address: H160) -> AccountId {
+        <Runtime as pallet_evm::Config>::AddressMapping::into_account_id(address)
+    }
+}
+
+/// Contract creation backend of `EvmDeploymentControl::deploy_contract`
+///
+/// Runs the creation through the EVM runner like `pallet_evm::create` does, charging the gas
+/// to `source`, but turns a reverted or failed creation into an error so the deployment
+/// metadata and the gas charge are rolled back.
+pub struct EvmContractDeployer;
+
+impl pallet_evm_deployment_control::ContractDeployer<AccountId> for EvmContractDeployer {
+    fn into_account_id(address: H160) -> AccountId {
+        <Runtime as pallet_evm::Config>::AddressMapping::into_account_id(address)
+    }
+
+    fn create(
+        source: H160,
+        init_code: Vec<u8>,
+        value: U256,
+        gas_limit: u64,
+    ) -> Result<(H160, u64), DispatchError> {
+        let (max_fee_per_gas, _) = <Runtime as pallet_evm::Config>::FeeCalculator::min_gas_price();
+
+        let info = <Runtime as pallet_evm::Config>::Runner::create(
+            source,
+            init_code,
+            value,
+            gas_limit,
+            Some(max_fee_per_gas),
+            None,
+            None,
+            Vec::new(),
+            true,
+            true,
+            None,
+            None,
+            <Runtime as pallet_evm::Config>::config(),
+        )
+        .map_err(|err| err.error.into())?;
+
+        match info.exit_reason {
+            ExitReason::Succeed(_) => {
+                Ok((info.value, info.used_gas.standard.unique_saturated_into()))
+            }
+            _ => Err(pallet_evm_deployment_control::Error::<Runtime>::DeploymentFailed.into()),
         }
+    }
 
-        // Deployment not authorized
-        Err(origin)
+    fn gas_weight(gas_limit: u64) -> Weight {
+        <Runtime as pallet_evm::Config>::GasWeightMapping::gas_to_weight(gas_limit, true)
     }
 }
+
+/// Calls nested in `pallet_utility` calls, so that batched deployer authorizations scheduled
+/// by governance stay open to objections
+pub struct UtilityCalls;
+
+impl pallet_evm_deployment_control::NestedCalls<RuntimeCall> for UtilityCalls {
+    fn nested_calls(call: &RuntimeCall) -> Vec<&RuntimeCall> {
+        match call {
+            RuntimeCall::Utility(
+                pallet_utility::Call::batch { calls }
+                | pallet_utility::Call::batch_all { calls }
+                | pallet_utility::Call::force_batch { calls },
+            ) => calls.iter().collect(),
+            RuntimeCall::Utility(
+                pallet_utility::Call::as_derivative { call, .. }
+                | pallet_utility::Call::dispatch_as { call, .. }
+                | pallet_utility::Call::with_weight { call, .. },
+            ) => vec![call.as_ref()],
+            _ => Vec::new(),
+        }
+    }
+}
+
+/// Contract creations among `extrinsics` whose sender the deployment policy refuses
+///
+/// Only the sender's standing is checked, deployment windows are left to block execution.
+pub fn deployment_refusals(extrinsics: &[UncheckedExtrinsic]) -> Vec<DeploymentRefusal> {
+    extrinsics
+        .iter()
+        .enumerate()
+        .filter_map(|(index, xt)| match &xt.0.function {
+            RuntimeCall::Ethereum(call @ transact { transaction })
+                if is_contract_creation(transaction) =>
+            {
+                let sender = call.check_self_contained()?.ok()?;
+                let account =
+                    <Runtime as pallet_evm::Config>::AddressMapping::into_account_id(sender);
+                EvmDeploymentControl::ensure_permitted(&account)
+                    .err()
+                    .map(|err| DeploymentRefusal {
+                        index: index as u32,
+                        transaction_hash: transaction.hash(),
+                        code: err.into(),
+                    })
+            }
+            _ => None,
+        })
+        .collect()
+}
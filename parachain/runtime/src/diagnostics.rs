@@ -0,0 +1,112 @@
+use fp_evm::{ExitError, ExitReason};
+use pallet_ethereum::{
+    Call::transact, Transaction as EthereumTransaction, TransactionAction, TransactionData,
+};
+use pallet_evm::{AddressMapping, Runner};
+use pallet_evm_deployment_control_runtime_api::FailureDiagnosis;
+use parity_scale_codec::Encode;
+use sp_core::{H160, H256};
+use sp_runtime::traits::UniqueSaturatedInto;
+use sp_std::prelude::*;
+
+use crate::{
+    is_contract_creation, transfer_allowlist, EmergencyPause, EvmDeploymentControl, Executive,
+    Runtime, RuntimeCall, UncheckedExtrinsic,
+};
+
+/// Re-execute the Ethereum transaction `transaction_hash` among the extrinsics of a block
+/// and explain its failure
+///
+/// The extrinsics preceding the transaction are applied first, so the block must have been
+/// initialized on its parent state. Returns `None` if the transaction is not among the
+/// extrinsics.
+pub fn diagnose_transaction(
+    extrinsics: Vec<UncheckedExtrinsic>,
+    transaction_hash: H256,
+) -> Option<FailureDiagnosis> {
+    for extrinsic in extrinsics {
+        if let RuntimeCall::Ethereum(call @ transact { transaction }) = &extrinsic.0.function {
+            if transaction.hash() == transaction_hash {
+                let sender = call.check_self_contained()?.ok()?;
+                return Some(diagnose(&extrinsic.0.function, sender, transaction));
+            }
+        }
+
+        // Failed extrinsics were part of the block as well, only their effects matter here
+        let _ = Executive::apply_extrinsic(extrinsic);
+    }
+
+    None
+}
+
+/// Run the runtime's checks on a transaction, then execute it
+fn diagnose(
+    call: &RuntimeCall,
+    sender: H160,
+    transaction: &EthereumTransaction,
+) -> FailureDiagnosis {
+    if is_contract_creation(transaction) {
+        let account = <Runtime as pallet_evm::Config>::AddressMapping::into_account_id(sender);
+        if let Err(e) = EvmDeploymentControl::ensure_can_deploy(&account) {
+            return FailureDiagnosis::UnauthorizedDeployment(e.into());
+        }
+    }
+    if EmergencyPause::is_call_paused(call) {
+        return FailureDiagnosis::Paused;
+    }
+    if transfer_allowlist::check_transaction(transaction).is_err() {
+        return FailureDiagnosis::RecipientNotAllowed;
+    }
+
+    let data = TransactionData::from(transaction);
+    let (weight_limit, proof_size_base_cost) =
+        pallet_ethereum::Pallet::<Runtime>::transaction_weight(&data);
+    let gas_limit = data.gas_limit.unique_saturated_into();
+    let max_fee_per_gas = data.max_fee_per_gas.or(data.gas_price);
+    let config = <Runtime as pallet_evm::Config>::config();
+
+    let execution = match data.action {
+        TransactionAction::Call(to) => <Runtime as pallet_evm::Config>::Runner::call(
+            sender,
+            to,
+            data.input,
+            data.value,
+            gas_limit,
+            max_fee_per_gas,
+            data.max_priority_fee_per_gas,
+            Some(data.nonce),
+            data.access_list,
+            true,
+            false,
+            weight_limit,
+            proof_size_base_cost,
+            config,
+        )
+        .map(|info| (info.exit_reason, info.value)),
+        // The return data of a failed creation is not kept by the runner
+        TransactionAction::Create => <Runtime as pallet_evm::Config>::Runner::create(
+            sender,
+            data.input,
+            data.value,
+            gas_limit,
+            max_fee_per_gas,
+            data.max_priority_fee_per_gas,
+            Some(data.nonce),
+            data.access_list,
+            true,
+            false,
+            weight_limit,
+            proof_size_base_cost,
+            config,
+        )
+        .map(|info| (info.exit_reason, Vec::new())),
+    };
+
+    match execution {
+        Ok((ExitReason::Succeed(_), _)) => FailureDiagnosis::Succeeded,
+        Ok((ExitReason::Error(ExitError::OutOfGas), _)) => FailureDiagnosis::OutOfGas,
+        Ok((ExitReason::Revert(_), output)) => FailureDiagnosis::Reverted(output),
+        Ok((reason, _)) => FailureDiagnosis::Halted(reason.encode()),
+        Err(err) => FailureDiagnosis::Rejected(err.error.into()),
+    }
+}
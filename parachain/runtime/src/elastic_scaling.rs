@@ -0,0 +1,68 @@
+use cumulus_pallet_parachain_system::{
+    consensus_hook::{ConsensusHook, UnincludedSegmentCapacity},
+    RelayChainStateProof,
+};
+use cumulus_primitives_aura::Slot;
+use frame_support::{traits::Get, weights::Weight};
+
+use crate::{
+    ElasticScaling, FixedVelocityConsensusHook, Hash, Runtime, RELAY_CHAIN_SLOT_DURATION_MILLIS,
+    SLOT_DURATION,
+};
+
+/// Consensus hook counting the blocks built per relay chain slot for the target core count
+///
+/// Runs the fixed velocity hook first, which keeps enforcing the block processing velocity,
+/// and with it `MaxCores`, and the unincluded segment capacity. The block is then counted
+/// under the relay chain slot of its state proof. The target itself is enforced by the
+/// collators through [`ElasticConsensusHook::can_build_upon`]: a block beyond it, built on a
+/// stale view of the target, is within the velocity the relay chain validates, so it is not
+/// refused.
+pub struct ElasticConsensusHook;
+
+impl ConsensusHook for ElasticConsensusHook {
+    fn on_state_proof(state_proof: &RelayChainStateProof) -> (Weight, UnincludedSegmentCapacity) {
+        let (weight, capacity) = FixedVelocityConsensusHook::on_state_proof(state_proof);
+
+        // The fixed velocity hook already failed the block on an unreadable slot
+        if let Ok(relay_slot) = state_proof.read_slot() {
+            let blocks = ElasticScaling::note_block(*relay_slot);
+            if blocks > ElasticScaling::target_cores() {
+                log::warn!(
+                    target: "runtime::elastic-scaling",
+                    "block {blocks} of relay chain slot {} is beyond the target core count",
+                    *relay_slot,
+                );
+            }
+        }
+
+        let db_weight = <Runtime as frame_system::Config>::DbWeight::get();
+        (
+            weight.saturating_add(db_weight.reads_writes(2, 1)),
+            capacity,
+        )
+    }
+}
+
+impl ElasticConsensusHook {
+    /// Check if a block can be built in a parachain slot on top of the included block
+    ///
+    /// Backs the `AuraUnincludedSegmentApi` collators consult before building, so that
+    /// they stop at the target core count. Blocks are counted under the relay chain slot
+    /// read from the state proof of the last block, which the new block shares until the
+    /// parachain slot starts past its end.
+    pub fn can_build_upon(included_hash: Hash, slot: Slot) -> bool {
+        if !FixedVelocityConsensusHook::can_build_upon(included_hash, slot) {
+            return false;
+        }
+
+        let Some(relay_slot) = ElasticScaling::last_relay_slot() else {
+            return true;
+        };
+        let relay_slot_end = relay_slot
+            .saturating_add(1)
+            .saturating_mul(RELAY_CHAIN_SLOT_DURATION_MILLIS.into());
+        (*slot).saturating_mul(SLOT_DURATION) >= relay_slot_end
+            || ElasticScaling::can_author(relay_slot)
+    }
+}
@@ -0,0 +1,42 @@
+//! EVM configuration driven by the on-chain opcode policy.
+//!
+//! `pallet_evm` reads its configuration through `Config::config()`, which must return a
+//! `&'static EvmConfig`. Every combination of the policy toggles is therefore prebuilt
+//! here, and the one matching the policy stored in pallet-evm-deployment-control is
+//! selected on each execution. The limit on nested contract creations is enforced by the
+//! EVM runner instead, see `deployment_control::FailUnauthorizedCreations`, as the call
+//! depth of the configuration bounds calls as well.
+
+use pallet_evm::EvmConfig;
+use pallet_evm_deployment_control::OpcodePolicy;
+
+use crate::Runtime;
+
+/// Gas charged for SELFDESTRUCT when it is banned
+///
+/// Exceeds any gas limit, so the instruction always runs out of gas. Kept well below
+/// `u64::MAX` so that the gasometer can add the cold access and new account surcharges.
+const BANNED_OPCODE_GAS: u64 = u64::MAX / 4;
+
+const fn policy_config(allow_selfdestruct: bool) -> EvmConfig {
+    let mut config = EvmConfig::cancun();
+    if !allow_selfdestruct {
+        config.gas_suicide = BANNED_OPCODE_GAS;
+    }
+    config
+}
+
+static SELFDESTRUCT_BANNED: EvmConfig = policy_config(false);
+static UNRESTRICTED: EvmConfig = policy_config(true);
+
+/// EVM configuration implementing the opcode policy currently in effect
+pub fn evm_config() -> &'static EvmConfig {
+    let OpcodePolicy { allow_selfdestruct, .. } =
+        pallet_evm_deployment_control::Pallet::<Runtime>::opcode_policy();
+
+    if allow_selfdestruct {
+        &UNRESTRICTED
+    } else {
+        &SELFDESTRUCT_BANNED
+    }
+}
@@ -0,0 +1,191 @@
+use frame_support::{
+    storage::{with_storage_layer, with_transaction_unchecked},
+    traits::{
+        fungible::{Balanced, NativeOrWithId},
+        fungibles,
+        tokens::imbalance::ResolveAssetTo,
+        OnUnbalanced,
+    },
+};
+use pallet_asset_conversion::{QuotePrice, SwapCredit};
+use pallet_ethereum::Transaction as EthereumTransaction;
+use pallet_evm::AddressMapping;
+use sp_core::{H160, U256};
+use sp_runtime::{traits::UniqueSaturatedInto, DispatchError, TransactionOutcome};
+use sp_std::prelude::*;
+
+use crate::configs::{DealWithFees, NativeAndAssets, TreasuryAccount};
+use crate::{AccountId, AssetConversion, Balance, Balances, FeeAssets, Runtime, EXISTENTIAL_DEPOSIT};
+
+/// Most an Ethereum transaction can pay in fees, and the value it transfers
+fn max_fee_and_value(transaction: &EthereumTransaction) -> (U256, U256) {
+    let (gas_limit, fee_per_gas, value) = match transaction {
+        EthereumTransaction::Legacy(t) => (t.gas_limit, t.gas_price, t.value),
+        EthereumTransaction::EIP2930(t) => (t.gas_limit, t.gas_price, t.value),
+        EthereumTransaction::EIP1559(t) => (t.gas_limit, t.max_fee_per_gas, t.value),
+    };
+    (gas_limit.saturating_mul(fee_per_gas), value)
+}
+
+/// Most of `asset` paid for `amount` native tokens, if `asset` may pay fees
+///
+/// Native tokens pay for themselves.
+fn max_fee_in(asset: &NativeOrWithId<u32>, amount: Balance) -> Option<Balance> {
+    match asset {
+        NativeOrWithId::Native => Some(amount),
+        NativeOrWithId::WithId(id) => FeeAssets::max_fee_in(id, amount),
+    }
+}
+
+/// Swap the first registered fee asset `from` holds enough of for the native tokens it lacks
+/// to pay the fee of `transaction`, so that accounts without native tokens can send Ethereum
+/// transactions
+///
+/// Must run in `pre_dispatch_self_contained`, before `pallet-ethereum` checks that the sender
+/// can pay, and after the fee is advanced by a sponsorship pool, if any. Only the fee is
+/// swapped: the transferred value must be held in native tokens. The existential deposit is
+/// swapped too, as the fee is withdrawn keeping the account alive. Each swap pays at most the
+/// price registered for its asset; failed swaps are rolled back and the next fee asset is
+/// tried. The swaps are bounded by `MaxFeeAssets` and not charged to the transaction.
+pub fn swap_for_fee(from: &H160, transaction: &EthereumTransaction) {
+    let account = <Runtime as pallet_evm::Config>::AddressMapping::into_account_id(*from);
+    let (fee, value) = max_fee_and_value(transaction);
+    let fee: Balance = fee.unique_saturated_into();
+    let missing = fee
+        .saturating_add(value.unique_saturated_into())
+        .saturating_add(EXISTENTIAL_DEPOSIT)
+        .saturating_sub(Balances::free_balance(&account));
+    // Nothing to swap, or more missing than the fee, which the swap cannot cover
+    if missing == 0 || missing > fee {
+        return;
+    }
+
+    for asset in FeeAssets::fee_assets() {
+        let asset = NativeOrWithId::WithId(asset);
+        let Some(amount_in_max) = max_fee_in(&asset, missing) else {
+            continue;
+        };
+        let swapped = with_storage_layer(|| {
+            AssetConversion::do_swap_tokens_for_exact_tokens(
+                account.clone(),
+                vec![asset, NativeOrWithId::Native],
+                missing,
+                Some(amount_in_max),
+                account.clone(),
+                true,
+            )
+        });
+        if swapped.is_ok() {
+            return;
+        }
+    }
+}
+
+/// Run `validate` as if the fee of `transaction` had been swapped by [`swap_for_fee`]
+///
+/// The swap is rolled back with anything `validate` wrote, so that validating a transaction
+/// leaves the pools as they were. The swap is made for good once the transaction is included.
+pub fn with_simulated_swap<R>(
+    from: &H160,
+    transaction: &EthereumTransaction,
+    validate: impl FnOnce() -> R,
+) -> R {
+    with_transaction_unchecked(|| {
+        swap_for_fee(from, transaction);
+        TransactionOutcome::Rollback(validate())
+    })
+}
+
+/// Swaps fees paid in assets through `pallet-asset-conversion`, quoting only the registered
+/// fee assets, and only up to their registered price
+///
+/// Unregistered assets, and swaps costing more than the registered price, get no quote, which
+/// `SwapAssetAdapter` refuses as an invalid payment. Substrate transactions thus pay fees in
+/// the same assets, and at most at the same price, as Ethereum ones.
+pub struct RegisteredFeeAssets;
+
+impl QuotePrice for RegisteredFeeAssets {
+    type Balance = Balance;
+    type AssetKind = NativeOrWithId<u32>;
+
+    fn quote_price_exact_tokens_for_tokens(
+        asset1: Self::AssetKind,
+        asset2: Self::AssetKind,
+        amount: Self::Balance,
+        include_fee: bool,
+    ) -> Option<Self::Balance> {
+        AssetConversion::quote_price_exact_tokens_for_tokens(asset1, asset2, amount, include_fee)
+    }
+
+    fn quote_price_tokens_for_exact_tokens(
+        asset1: Self::AssetKind,
+        asset2: Self::AssetKind,
+        amount: Self::Balance,
+        include_fee: bool,
+    ) -> Option<Self::Balance> {
+        let max_in = max_fee_in(&asset1, amount)?;
+        let amount_in = AssetConversion::quote_price_tokens_for_exact_tokens(
+            asset1,
+            asset2,
+            amount,
+            include_fee,
+        )?;
+        (amount_in <= max_in).then_some(amount_in)
+    }
+}
+
+impl SwapCredit<AccountId> for RegisteredFeeAssets {
+    type Balance = Balance;
+    type AssetKind = NativeOrWithId<u32>;
+    type Credit = fungibles::Credit<AccountId, NativeAndAssets>;
+
+    fn max_path_len() -> u32 {
+        <AssetConversion as SwapCredit<AccountId>>::max_path_len()
+    }
+
+    fn swap_exact_tokens_for_tokens(
+        path: Vec<Self::AssetKind>,
+        credit_in: Self::Credit,
+        amount_out_min: Option<Self::Balance>,
+    ) -> Result<Self::Credit, (Self::Credit, DispatchError)> {
+        <AssetConversion as SwapCredit<AccountId>>::swap_exact_tokens_for_tokens(
+            path,
+            credit_in,
+            amount_out_min,
+        )
+    }
+
+    fn swap_tokens_for_exact_tokens(
+        path: Vec<Self::AssetKind>,
+        credit_in: Self::Credit,
+        amount_out: Self::Balance,
+    ) -> Result<(Self::Credit, Self::Credit), (Self::Credit, DispatchError)> {
+        // The credit was withdrawn as quoted, within the registered price
+        <AssetConversion as SwapCredit<AccountId>>::swap_tokens_for_exact_tokens(
+            path,
+            credit_in,
+            amount_out,
+        )
+    }
+}
+
+/// Distributes the fees of Substrate transactions paid in assets like native ones
+///
+/// `SwapAssetAdapter` hands over the fee, tip included, swapped to the native token as a
+/// credit of `NativeAndAssets`. The credit is exchanged for a `Balances` one of the same
+/// amount, leaving the total issuance unchanged, and passed to [`DealWithFees`]. Credits left
+/// in other assets, which the adapter does not produce, go to the treasury.
+pub struct DealWithAssetFees;
+
+impl OnUnbalanced<fungibles::Credit<AccountId, NativeAndAssets>> for DealWithAssetFees {
+    fn on_nonzero_unbalanced(credit: fungibles::Credit<AccountId, NativeAndAssets>) {
+        if credit.asset() != NativeOrWithId::Native {
+            ResolveAssetTo::<TreasuryAccount, NativeAndAssets>::on_unbalanced(credit);
+            return;
+        }
+        let amount = credit.peek();
+        // Dropping the credit takes its amount out of the issuance, which issuing puts back
+        drop(credit);
+        DealWithFees::on_unbalanced(<Balances as Balanced<AccountId>>::issue(amount));
+    }
+}
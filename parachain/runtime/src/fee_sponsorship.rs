@@ -0,0 +1,54 @@
+use frame_support::storage::with_transaction_unchecked;
+use pallet_ethereum::{Transaction as EthereumTransaction, TransactionAction};
+use pallet_evm::FeeCalculator;
+use sp_core::{H160, U256};
+use sp_runtime::{traits::UniqueSaturatedInto, TransactionOutcome};
+
+use crate::{FeeSponsorship, Runtime};
+
+/// Contract called by an Ethereum transaction and the most the transaction can pay in base
+/// fees
+///
+/// Pools pay the base fee only: the sender pays any priority fee, and must hold what it
+/// offers above the base fee. Contract creations call no contract and are never sponsored.
+pub fn sponsorable_call(transaction: &EthereumTransaction) -> Option<(H160, U256)> {
+    let (action, gas_limit, fee_per_gas) = match transaction {
+        EthereumTransaction::Legacy(t) => (&t.action, t.gas_limit, t.gas_price),
+        EthereumTransaction::EIP2930(t) => (&t.action, t.gas_limit, t.gas_price),
+        EthereumTransaction::EIP1559(t) => (&t.action, t.gas_limit, t.max_fee_per_gas),
+    };
+    let (base_fee, _) = <Runtime as pallet_evm::Config>::FeeCalculator::min_gas_price();
+    match action {
+        TransactionAction::Call(to) => {
+            Some((*to, gas_limit.saturating_mul(fee_per_gas.min(base_fee))))
+        }
+        TransactionAction::Create => None,
+    }
+}
+
+/// Advance the base fee of an Ethereum transaction sent by `from` out of the sponsorship pool
+/// of the contract it calls, if any
+///
+/// Must run in `pre_dispatch_self_contained`, before `pallet-ethereum` checks that the sender
+/// can pay the fee. The advance is settled by `DistributeEvmFees` once the fee is known.
+pub fn advance_fee(from: &H160, transaction: &EthereumTransaction) {
+    if let Some((to, max_fee)) = sponsorable_call(transaction) {
+        FeeSponsorship::advance(*from, &to, max_fee.unique_saturated_into());
+    }
+}
+
+/// Run `validate` as if the fee of `transaction` had been advanced by [`advance_fee`]
+///
+/// The advance is rolled back with anything `validate` wrote, so that validating a
+/// transaction leaves the pools and their caps as they were. The fee is advanced for good
+/// once the transaction is included.
+pub fn with_simulated_advance<R>(
+    from: &H160,
+    transaction: &EthereumTransaction,
+    validate: impl FnOnce() -> R,
+) -> R {
+    with_transaction_unchecked(|| {
+        advance_fee(from, transaction);
+        TransactionOutcome::Rollback(validate())
+    })
+}
@@ -0,0 +1,68 @@
+use pallet_evm_deployment_control::DeploymentValidationError;
+use sp_runtime::transaction_validity::{InvalidTransaction, TransactionValidityError};
+
+/// Custom `InvalidTransaction` codes the runtime refuses transactions with
+///
+/// The codes are shared by the runtime's own checks and by the pallets whose checks it runs,
+/// which export theirs, so they are collected here: a code taken twice fails to compile.
+/// Wallets and the node's RPC read them back to explain why a transaction was refused.
+#[repr(u8)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CustomInvalidity {
+    /// The sender is not an authorized deployer
+    UnauthorizedDeployer = DeploymentValidationError::UnauthorizedDeployer as u8,
+    /// The creation falls outside of the deployment windows
+    OutsideDeploymentWindow = DeploymentValidationError::OutsideDeploymentWindow as u8,
+    /// The recipient of transferred value is not on the transfer allowlist
+    ///
+    /// `pallet_transfer_allowlist::RECIPIENT_NOT_ALLOWED`, which is left out of builds
+    /// without `compliance-mode`.
+    RecipientNotAllowed = 2,
+    /// The transaction is larger than `pov_limits::MAX_ETHEREUM_TRANSACTION_SIZE`
+    TransactionTooLarge = 3,
+    /// The init code is larger than `pov_limits::MAX_INIT_CODE_SIZE`
+    InitCodeTooLarge = 4,
+    /// The sender cannot pay the fee of the permissionless deployment policy
+    InsufficientDeploymentFee = DeploymentValidationError::InsufficientDeploymentFee as u8,
+    /// The transaction upgrades a frozen contract
+    CodeFrozen = pallet_code_freeze::CODE_FROZEN,
+    /// The sender used up its deployment quota for the period
+    QuotaExhausted = DeploymentValidationError::QuotaExhausted as u8,
+    /// The transaction was signed for another qnch network
+    WrongNetwork = 8,
+    /// The deployer cannot afford the escrow required for a new contract
+    EscrowUnaffordable = pallet_deployment_escrow::ESCROW_UNAFFORDABLE,
+    /// The sender is flagged for repeated unauthorized deployments
+    FlaggedDeployer = DeploymentValidationError::FlaggedDeployer as u8,
+}
+
+#[cfg(feature = "compliance-mode")]
+const _: () = assert!(
+    CustomInvalidity::RecipientNotAllowed as u8 == pallet_transfer_allowlist::RECIPIENT_NOT_ALLOWED
+);
+
+impl From<DeploymentValidationError> for CustomInvalidity {
+    fn from(err: DeploymentValidationError) -> Self {
+        match err {
+            DeploymentValidationError::UnauthorizedDeployer => Self::UnauthorizedDeployer,
+            DeploymentValidationError::OutsideDeploymentWindow => Self::OutsideDeploymentWindow,
+            DeploymentValidationError::InsufficientDeploymentFee => {
+                Self::InsufficientDeploymentFee
+            }
+            DeploymentValidationError::QuotaExhausted => Self::QuotaExhausted,
+            DeploymentValidationError::FlaggedDeployer => Self::FlaggedDeployer,
+        }
+    }
+}
+
+impl From<CustomInvalidity> for u8 {
+    fn from(code: CustomInvalidity) -> u8 {
+        code as u8
+    }
+}
+
+impl From<CustomInvalidity> for TransactionValidityError {
+    fn from(code: CustomInvalidity) -> Self {
+        TransactionValidityError::Invalid(InvalidTransaction::Custom(code.into()))
+    }
+}
@@ -34,9 +34,27 @@ include!(concat!(env!("OUT_DIR"), "/wasm_binary.rs"));
 pub mod apis;
 #[cfg(feature = "runtime-benchmarks")]
 mod benchmarks;
+mod code_freeze;
 mod configs;
 mod weights;
+#[cfg(feature = "dependency-registry")]
+mod contract_dependencies;
 mod deployment_control;
+#[cfg(feature = "evm-tracing")]
+mod diagnostics;
+mod elastic_scaling;
+mod evm_config;
+mod fee_assets;
+mod fee_sponsorship;
+mod invalid_transaction;
+mod network_binding;
+mod pov_limits;
+mod registry_mirror;
+#[cfg(feature = "compliance-mode")]
+mod transfer_allowlist;
+// The runtime tests build their accounts from 32-byte keys
+#[cfg(all(test, not(feature = "unified-accounts")))]
+mod tests;
 
 use parity_scale_codec::{Decode, Encode};
 use smallvec::smallvec;
@@ -47,11 +65,11 @@ use sp_runtime::{
         BlakeTwo256, DispatchInfoOf, Dispatchable, IdentifyAccount, PostDispatchInfoOf, Verify,
     },
     transaction_validity::{InvalidTransaction, TransactionValidity, TransactionValidityError},
-    MultiSignature,
 };
 
 use sp_std::prelude::*;
 use pallet_evm::AddressMapping;
+use pallet_evm_deployment_control::DeploymentValidationError;
 use pallet_ethereum::{Transaction as EthereumTransaction, TransactionAction};
 #[cfg(feature = "std")]
 use sp_version::NativeVersion;
@@ -70,10 +88,76 @@ pub use sp_runtime::BuildStorage;
 use weights::ExtrinsicBaseWeight;
 
 mod precompiles;
+pub use invalid_transaction::CustomInvalidity;
 pub use precompiles::FrontierPrecompiles;
 
+/// Failed transaction diagnostics, not re-executing transactions in this build
+#[cfg(not(feature = "evm-tracing"))]
+mod diagnostics {
+    use pallet_evm_deployment_control_runtime_api::FailureDiagnosis;
+    use sp_core::H256;
+    use sp_std::prelude::*;
+
+    use crate::UncheckedExtrinsic;
+
+    /// Diagnose nothing, as if the transaction were not among the extrinsics
+    pub fn diagnose_transaction(
+        _extrinsics: Vec<UncheckedExtrinsic>,
+        _transaction_hash: H256,
+    ) -> Option<FailureDiagnosis> {
+        None
+    }
+}
+
+/// Contract dependency queries, the dependency registry being left out of this build
+#[cfg(not(feature = "dependency-registry"))]
+mod contract_dependencies {
+    use pallet_contract_dependencies_runtime_api::Dependency;
+    use sp_core::H160;
+    use sp_std::prelude::*;
+
+    /// No dependencies are declared
+    pub fn dependencies(_contract: H160) -> Vec<Dependency> {
+        Vec::new()
+    }
+
+    /// No contract declares dependencies
+    pub fn dependents(_contract: H160) -> Vec<Dependency> {
+        Vec::new()
+    }
+
+    /// No contract is known to be affected
+    pub fn blast_radius(_contract: H160) -> Vec<H160> {
+        Vec::new()
+    }
+}
+
+/// Transfer allowlist checks, allowing every transfer in this build
+#[cfg(not(feature = "compliance-mode"))]
+mod transfer_allowlist {
+    use pallet_ethereum::Transaction as EthereumTransaction;
+    use sp_runtime::transaction_validity::TransactionValidityError;
+
+    /// Check an Ethereum transaction against the transfer allowlist, which is left out
+    pub fn check_transaction(
+        _transaction: &EthereumTransaction,
+    ) -> Result<(), TransactionValidityError> {
+        Ok(())
+    }
+
+    /// Base call filter of the transfer allowlist, which is left out
+    pub type TransferCallFilter = frame_support::traits::Everything;
+}
+
 /// Alias to 512-bit hash when used in the context of a transaction signature on the chain.
-pub type Signature = MultiSignature;
+#[cfg(not(feature = "unified-accounts"))]
+pub type Signature = sp_runtime::MultiSignature;
+
+/// Ethereum-style ECDSA signature, whose signers are identified by their 20-byte address.
+///
+/// Native accounts then equal the EVM addresses, mapped by identity in `pallet_evm`.
+#[cfg(feature = "unified-accounts")]
+pub type Signature = fp_account::EthereumSignature;
 
 /// Some way of identifying an account on the chain. We intentionally make it equivalent
 /// to the public key of our transaction signing scheme.
@@ -116,8 +200,17 @@ pub type SignedExtra = (
     frame_system::CheckEra<Runtime>,
     frame_system::CheckNonce<Runtime>,
     frame_system::CheckWeight<Runtime>,
-    pallet_transaction_payment::ChargeTransactionPayment<Runtime>,
+    // Charges fees in the native token, or in any asset with a native pool when `asset_id`
+    // is set
+    pallet_asset_conversion_tx_payment::ChargeAssetTxPayment<Runtime>,
     cumulus_primitives_storage_weight_reclaim::StorageWeightReclaim<Runtime>,
+    // Lets hardware wallets verify the metadata they decode calls with, so that council
+    // members can review governance calls like `authorize_deployer` instead of blind-signing
+    frame_metadata_hash_extension::CheckMetadataHash<Runtime>,
+    // Keeps transactions signed for a staging or production network off the other
+    network_binding::CheckNetwork,
+    // Counts refused Substrate-level contract creations and refuses those of flagged accounts
+    deployment_control::CheckDeployment,
 );
 
 /// Unchecked extrinsic type as expected by this runtime.
@@ -210,7 +303,13 @@ pub const VERSION: RuntimeVersion = RuntimeVersion {
 /// up by `pallet_aura` to implement `fn slot_duration()`.
 ///
 /// Change this to adjust the block time.
+///
+/// With `elastic-scaling`, one block is built per relay chain core in each 6 second relay
+/// chain slot, see `MAX_CORES`.
+#[cfg(not(feature = "elastic-scaling"))]
 pub const MILLISECS_PER_BLOCK: u64 = 6000;
+#[cfg(feature = "elastic-scaling")]
+pub const MILLISECS_PER_BLOCK: u64 = RELAY_CHAIN_SLOT_DURATION_MILLIS as u64 / MAX_CORES as u64;
 
 // NOTE: Currently it is not possible to change the slot duration after the chain has started.
 //       Attempting to do so will brick block production.
@@ -246,22 +345,33 @@ const MAXIMUM_BLOCK_WEIGHT: Weight = Weight::from_parts(
     cumulus_primitives_core::relay_chain::MAX_POV_SIZE as u64,
 );
 
-/// Maximum number of blocks simultaneously accepted by the Runtime, not yet included
-/// into the relay chain.
-const UNINCLUDED_SEGMENT_CAPACITY: u32 = 3;
+/// Most relay chain cores blocks are built for, the ceiling of the elastic scaling target
+/// core count
+///
+/// A single core until `elastic-scaling` shortens the slots for more.
+#[cfg(not(feature = "elastic-scaling"))]
+const MAX_CORES: u32 = 1;
+#[cfg(feature = "elastic-scaling")]
+const MAX_CORES: u32 = 3;
 /// How many parachain blocks are processed by the relay chain per parent. Limits the
-/// number of blocks authored per slot.
-const BLOCK_PROCESSING_VELOCITY: u32 = 1;
+/// number of blocks authored per slot, one per core.
+const BLOCK_PROCESSING_VELOCITY: u32 = MAX_CORES;
+/// Maximum number of blocks simultaneously accepted by the Runtime, not yet included
+/// into the relay chain. Covers three relay chain blocks at the full velocity.
+const UNINCLUDED_SEGMENT_CAPACITY: u32 = 3 * BLOCK_PROCESSING_VELOCITY;
 /// Relay chain slot duration, in milliseconds.
 const RELAY_CHAIN_SLOT_DURATION_MILLIS: u32 = 6000;
 
-type ConsensusHook = cumulus_pallet_aura_ext::FixedVelocityConsensusHook<
+type FixedVelocityConsensusHook = cumulus_pallet_aura_ext::FixedVelocityConsensusHook<
     Runtime,
     RELAY_CHAIN_SLOT_DURATION_MILLIS,
     BLOCK_PROCESSING_VELOCITY,
     UNINCLUDED_SEGMENT_CAPACITY,
 >;
 
+/// Fixed velocity consensus hook, further limited to the elastic scaling target core count.
+type ConsensusHook = elastic_scaling::ElasticConsensusHook;
+
 /// The version information used to identify this runtime when compiled natively.
 #[cfg(feature = "std")]
 pub fn native_version() -> NativeVersion {
@@ -297,17 +407,41 @@ mod runtime {
     pub type Timestamp = pallet_timestamp::Pallet<Runtime>;
     #[runtime::pallet_index(3)]
     pub type ParachainInfo = parachain_info::Pallet<Runtime>;
+    #[runtime::pallet_index(4)]
+    pub type MultiBlockMigrations = pallet_migrations::Pallet<Runtime>;
 
     // Monetary stuff.
     #[runtime::pallet_index(10)]
     pub type Balances = pallet_balances::Pallet<Runtime>;
     #[runtime::pallet_index(11)]
     pub type TransactionPayment = pallet_transaction_payment::Pallet<Runtime>;
+    #[runtime::pallet_index(12)]
+    pub type Treasury = pallet_treasury::Pallet<Runtime>;
+    #[runtime::pallet_index(13)]
+    pub type FeeDistribution = pallet_fee_distribution::Pallet<Runtime>;
+    #[runtime::pallet_index(14)]
+    pub type Issuance = pallet_issuance::Pallet<Runtime>;
 
     // Governance
     #[runtime::pallet_index(15)]
     pub type Sudo = pallet_sudo;
 
+    // Utility
+    #[runtime::pallet_index(16)]
+    pub type Utility = pallet_utility::Pallet<Runtime>;
+
+    // Incident response
+    #[runtime::pallet_index(17)]
+    pub type EmergencyPause = pallet_emergency_pause::Pallet<Runtime>;
+
+    // Storage of large blobs, e.g. factory and deployer metadata
+    #[runtime::pallet_index(18)]
+    pub type Preimage = pallet_preimage::Pallet<Runtime>;
+
+    // Council, approving treasury spends
+    #[runtime::pallet_index(19)]
+    pub type Council = pallet_collective::Pallet<Runtime, pallet_collective::Instance1>;
+
     // Collator support. The order of these 4 are important and shall not change.
     #[runtime::pallet_index(20)]
     pub type Authorship = pallet_authorship::Pallet<Runtime>;
@@ -319,6 +453,18 @@ mod runtime {
     pub type Aura = pallet_aura::Pallet<Runtime>;
     #[runtime::pallet_index(24)]
     pub type AuraExt = cumulus_pallet_aura_ext;
+    #[runtime::pallet_index(25)]
+    pub type CollatorRewards = pallet_collator_rewards::Pallet<Runtime>;
+    #[runtime::pallet_index(26)]
+    pub type Delegation = pallet_delegation::Pallet<Runtime>;
+
+    // Token distribution
+    #[runtime::pallet_index(27)]
+    pub type Vesting = pallet_vesting::Pallet<Runtime>;
+    #[runtime::pallet_index(28)]
+    pub type Claims = pallet_claims::Pallet<Runtime>;
+    #[runtime::pallet_index(29)]
+    pub type Nfts = pallet_nfts::Pallet<Runtime>;
 
     // XCM helpers.
     #[runtime::pallet_index(30)]
@@ -343,6 +489,68 @@ mod runtime {
     // Custom Pallets
     #[runtime::pallet_index(44)]
     pub type EvmDeploymentControl = pallet_evm_deployment_control::Pallet<Runtime>;
+    #[cfg(feature = "compliance-mode")]
+    #[runtime::pallet_index(45)]
+    pub type TransferAllowlist = pallet_transfer_allowlist::Pallet<Runtime>;
+    #[runtime::pallet_index(46)]
+    pub type DeployerReputation = pallet_deployer_reputation::Pallet<Runtime>;
+    #[runtime::pallet_index(47)]
+    pub type ContractNames = pallet_contract_names::Pallet<Runtime>;
+    #[runtime::pallet_index(48)]
+    pub type ElasticScaling = pallet_elastic_scaling::Pallet<Runtime>;
+    #[runtime::pallet_index(49)]
+    pub type UpgradeCommittee = pallet_upgrade_committee::Pallet<Runtime>;
+
+    // Assets and liquidity
+    #[runtime::pallet_index(50)]
+    pub type Assets = pallet_assets::Pallet<Runtime, pallet_assets::Instance1>;
+    #[runtime::pallet_index(51)]
+    pub type PoolAssets = pallet_assets::Pallet<Runtime, pallet_assets::Instance2>;
+    #[runtime::pallet_index(52)]
+    pub type AssetConversion = pallet_asset_conversion::Pallet<Runtime>;
+    #[runtime::pallet_index(53)]
+    pub type AssetTxPayment = pallet_asset_conversion_tx_payment::Pallet<Runtime>;
+    #[runtime::pallet_index(54)]
+    pub type FeeAssets = pallet_fee_assets::Pallet<Runtime>;
+
+    // Fee sponsorship of dApp interactions
+    #[runtime::pallet_index(55)]
+    pub type FeeSponsorship = pallet_fee_sponsorship::Pallet<Runtime>;
+
+    // Offences and slashing of collators
+    #[runtime::pallet_index(56)]
+    pub type CollatorOffences = pallet_collator_offences::Pallet<Runtime>;
+
+    // Audit documents anchored to deployments
+    #[runtime::pallet_index(57)]
+    pub type AuditAnchors = pallet_audit_anchors::Pallet<Runtime>;
+
+    // Canonical contracts whose code is frozen
+    #[runtime::pallet_index(58)]
+    pub type CodeFreeze = pallet_code_freeze::Pallet<Runtime>;
+
+    // Messages to Ethereum committed to in the header digest
+    #[runtime::pallet_index(59)]
+    pub type OutboundCommitments = pallet_outbound_commitments::Pallet<Runtime>;
+
+    // Deployer registry instructions proven against Ethereum receipts
+    #[runtime::pallet_index(60)]
+    pub type InboundGovernance = pallet_inbound_governance::Pallet<Runtime>;
+
+    // Wasm (ink!) contracts, deployed under the EVM deployment authorization
+    #[runtime::pallet_index(61)]
+    pub type Contracts = pallet_contracts::Pallet<Runtime>;
+    #[runtime::pallet_index(62)]
+    pub type RandomnessCollectiveFlip = pallet_insecure_randomness_collective_flip::Pallet<Runtime>;
+
+    // Escrows of deployed contracts, slashable toward their users
+    #[runtime::pallet_index(63)]
+    pub type DeploymentEscrow = pallet_deployment_escrow::Pallet<Runtime>;
+
+    // Dependencies declared between contracts
+    #[cfg(feature = "dependency-registry")]
+    #[runtime::pallet_index(64)]
+    pub type ContractDependencies = pallet_contract_dependencies::Pallet<Runtime>;
 }
 
 #[derive(Clone)]
@@ -395,36 +603,77 @@ impl fp_self_contained::SelfContainedCall for RuntimeCall {
     ) -> Option<TransactionValidity> {
         match self {
             RuntimeCall::Ethereum(call) => {
-                // First do standard ethereum validation
-                let result = call.validate_self_contained(info, dispatch_info, len)?;
+                // Reject paused calls before they reach the pool
+                if EmergencyPause::is_call_paused(self) {
+                    return Some(Err(InvalidTransaction::Call.into()));
+                }
+
+                // First do standard ethereum validation. Sponsored senders, and senders paying
+                // in fee assets, pass its balance checks: the fee advance and the fee asset
+                // swap are only simulated, so that validation leaves the pools as they were.
+                let result = if let pallet_ethereum::Call::transact { transaction } = call {
+                    fee_sponsorship::with_simulated_advance(info, transaction, || {
+                        fee_assets::with_simulated_swap(info, transaction, || {
+                            call.validate_self_contained(info, dispatch_info, len)
+                        })
+                    })?
+                } else {
+                    call.validate_self_contained(info, dispatch_info, len)?
+                };
 
                 // Check if transaction is valid so far
                 if result.is_err() {
                     return Some(result);
                 }
 
+                // Reject transactions that would blow up the PoV before they reach a block
+                if let pallet_ethereum::Call::transact { transaction } = call {
+                    if let Err(e) = pov_limits::check_transaction(transaction, len) {
+                        return Some(Err(e));
+                    }
+                }
+
                 // Check deployment authorization for contract creation transactions
                 if let pallet_ethereum::Call::transact { transaction } = call {
-                    // Check if this is a contract creation (CREATE)
-                    let is_create = match transaction {
-                        EthereumTransaction::Legacy(t) => t.action == TransactionAction::Create,
-                        EthereumTransaction::EIP2930(t) => t.action == TransactionAction::Create,
-                        EthereumTransaction::EIP1559(t) => t.action == TransactionAction::Create,
-                    };
-
-                    if is_create {
+                    if is_contract_creation(transaction) {
+                        sp_tracing::enter_span!(sp_tracing::trace_span!(
+                            target: pallet_evm_deployment_control::TRACING_TARGET,
+                            "validate_creation"
+                        ));
+
                         // Convert H160 to AccountId
                         let account_id = <Runtime as pallet_evm::Config>::AddressMapping::into_account_id(*info);
 
-                        // Check if deployer is authorized
-                        if !pallet_evm_deployment_control::Pallet::<Runtime>::is_authorized(&account_id) {
-                            return Some(Err(TransactionValidityError::Invalid(
-                                InvalidTransaction::Custom(
-                                    pallet_evm_deployment_control::DeploymentValidationError::UnauthorizedDeployer.into()
-                                )
-                            )));
+                        // Check if deployer is authorized and within a deployment window.
+                        // Creations of unauthorized deployers are admitted, so that the attempt
+                        // is counted once included; the EVM runner fails them.
+                        match EvmDeploymentControl::ensure_can_deploy(&account_id) {
+                            Ok(()) => {
+                                // Check the deployer can afford the escrow required for the
+                                // contract
+                                if DeploymentEscrow::ensure_can_escrow(&account_id).is_err() {
+                                    sp_tracing::trace!(
+                                        target: pallet_evm_deployment_control::TRACING_TARGET,
+                                        decision = "refused",
+                                        reason = "deployer cannot afford the escrow"
+                                    );
+                                    return Some(Err(CustomInvalidity::EscrowUnaffordable.into()));
+                                }
+                            }
+                            Err(DeploymentValidationError::UnauthorizedDeployer) => {}
+                            Err(e) => return Some(Err(CustomInvalidity::from(e).into())),
                         }
                     }
+
+                    // Check the recipient of transferred value while in compliance mode
+                    if let Err(e) = transfer_allowlist::check_transaction(transaction) {
+                        return Some(Err(e));
+                    }
+
+                    // Refuse proxy upgrades of frozen contracts
+                    if let Err(e) = code_freeze::check_transaction(transaction) {
+                        return Some(Err(e));
+                    }
                 }
 
                 Some(result)
@@ -441,8 +690,87 @@ impl fp_self_contained::SelfContainedCall for RuntimeCall {
     ) -> Option<Result<(), TransactionValidityError>> {
         match self {
             RuntimeCall::Ethereum(call) => {
-                // Authorization already checked in validate_self_contained
-                call.pre_dispatch_self_contained(info, dispatch_info, len)
+                // The call may have been paused since it entered the pool
+                if EmergencyPause::is_call_paused(self) {
+                    return Some(Err(InvalidTransaction::Call.into()));
+                }
+
+                // Let sponsored senders, and senders paying in fee assets, pass the balance
+                // checks of the standard validation
+                if let pallet_ethereum::Call::transact { transaction } = call {
+                    fee_sponsorship::advance_fee(info, transaction);
+                    fee_assets::swap_for_fee(info, transaction);
+                }
+
+                if let Err(e) = call.pre_dispatch_self_contained(info, dispatch_info, len)? {
+                    return Some(Err(e));
+                }
+
+                if let pallet_ethereum::Call::transact { transaction } = call {
+                    // Block authors may include transactions that never went through the pool
+                    if let Err(e) = pov_limits::check_transaction(transaction, len) {
+                        return Some(Err(e));
+                    }
+
+                    // The allowlist may have changed since the transaction entered the pool
+                    if let Err(e) = transfer_allowlist::check_transaction(transaction) {
+                        return Some(Err(e));
+                    }
+
+                    // So may the code freeze list
+                    if let Err(e) = code_freeze::check_transaction(transaction) {
+                        return Some(Err(e));
+                    }
+
+                    if let Some(init_code) = contract_init_code(transaction) {
+                        let account_id =
+                            <Runtime as pallet_evm::Config>::AddressMapping::into_account_id(*info);
+
+                        // The deployer may have been revoked, or the window closed, since the
+                        // transaction entered the pool, which block authors may also bypass.
+                        // Creations of unauthorized deployers are included to count the
+                        // attempt, and fail in the EVM runner.
+                        match EvmDeploymentControl::ensure_can_deploy(&account_id) {
+                            Ok(()) => {}
+                            Err(DeploymentValidationError::UnauthorizedDeployer) => {
+                                EvmDeploymentControl::note_rejected_attempt(&account_id);
+                                return Some(Ok(()));
+                            }
+                            Err(e) => return Some(Err(CustomInvalidity::from(e).into())),
+                        }
+
+                        // The deployer may also have spent what the escrow requires
+                        if DeploymentEscrow::ensure_can_escrow(&account_id).is_err() {
+                            return Some(Err(CustomInvalidity::EscrowUnaffordable.into()));
+                        }
+
+                        // Enforce the per-block contract creation cap. Exhausting it only
+                        // defers the transaction to a later block.
+                        if !EvmDeploymentControl::try_register_create() {
+                            return Some(Err(TransactionValidityError::Invalid(
+                                InvalidTransaction::ExhaustsResources,
+                            )));
+                        }
+
+                        // Count the creation against the deployer's quota for the period
+                        if !pallet_evm_deployment_control::Pallet::<Runtime>::try_use_quota(&account_id) {
+                            return Some(Err(CustomInvalidity::QuotaExhausted.into()));
+                        }
+
+                        // Charge the fee of the permissionless deployment policy, if any
+                        if pallet_evm_deployment_control::Pallet::<Runtime>::charge_deployment_fee(&account_id).is_err() {
+                            return Some(Err(CustomInvalidity::InsufficientDeploymentFee.into()));
+                        }
+
+                        // Let the creation be linked to the deployer's announced intent, if any
+                        pallet_evm_deployment_control::Pallet::<Runtime>::note_pending_creation(
+                            &account_id,
+                            sp_io::hashing::keccak_256(init_code).into(),
+                        );
+                    }
+                }
+
+                Some(Ok(()))
             }
             _ => None,
         }
@@ -463,6 +791,26 @@ impl fp_self_contained::SelfContainedCall for RuntimeCall {
     }
 }
 
+/// Whether an Ethereum transaction creates a contract (CREATE).
+fn is_contract_creation(transaction: &EthereumTransaction) -> bool {
+    let action = match transaction {
+        EthereumTransaction::Legacy(t) => &t.action,
+        EthereumTransaction::EIP2930(t) => &t.action,
+        EthereumTransaction::EIP1559(t) => &t.action,
+    };
+    *action == TransactionAction::Create
+}
+
+/// Init code of an Ethereum transaction creating a contract
+fn contract_init_code(transaction: &EthereumTransaction) -> Option<&[u8]> {
+    let input = match transaction {
+        EthereumTransaction::Legacy(t) => &t.input,
+        EthereumTransaction::EIP2930(t) => &t.input,
+        EthereumTransaction::EIP1559(t) => &t.input,
+    };
+    is_contract_creation(transaction).then_some(&input[..])
+}
+
 cumulus_pallet_parachain_system::register_validate_block! {
     Runtime = Runtime,
     BlockExecutor = cumulus_pallet_aura_ext::BlockExecutor::<Runtime, Executive>,
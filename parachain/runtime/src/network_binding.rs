@@ -0,0 +1,77 @@
+use frame_support::traits::Get;
+use parity_scale_codec::{Decode, Encode};
+use scale_info::TypeInfo;
+use sp_runtime::{
+    traits::{DispatchInfoOf, SignedExtension, Zero},
+    transaction_validity::{TransactionValidity, TransactionValidityError, ValidTransaction},
+};
+
+use crate::{
+    invalid_transaction::CustomInvalidity, AccountId, BlockNumber, EVMChainId, Hash, Runtime,
+    RuntimeCall,
+};
+
+/// Binds signed transactions to one qnch network
+///
+/// Staging and production chains share their runtime and may share their genesis, so a
+/// transaction signed against one, e.g. an `authorize_deployer` of a deploy script, could
+/// be replayed on the other. The signer states the EVM chain id of the network it targets,
+/// which is checked against `EVMChainId`, and signs the genesis hash along with it.
+///
+/// Ethereum transactions are bound to their chain id by EIP-155 and skip this check.
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, TypeInfo)]
+pub struct CheckNetwork(#[codec(compact)] pub u64);
+
+impl CheckNetwork {
+    fn check(&self) -> Result<(), TransactionValidityError> {
+        if self.0 != EVMChainId::get() {
+            return Err(CustomInvalidity::WrongNetwork.into());
+        }
+        Ok(())
+    }
+}
+
+impl core::fmt::Debug for CheckNetwork {
+    #[cfg(feature = "std")]
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(f, "CheckNetwork({})", self.0)
+    }
+
+    #[cfg(not(feature = "std"))]
+    fn fmt(&self, _: &mut core::fmt::Formatter) -> core::fmt::Result {
+        Ok(())
+    }
+}
+
+impl SignedExtension for CheckNetwork {
+    const IDENTIFIER: &'static str = "CheckNetwork";
+    type AccountId = AccountId;
+    type Call = RuntimeCall;
+    type AdditionalSigned = Hash;
+    type Pre = ();
+
+    fn additional_signed(&self) -> Result<Hash, TransactionValidityError> {
+        Ok(frame_system::Pallet::<Runtime>::block_hash(BlockNumber::zero()))
+    }
+
+    fn validate(
+        &self,
+        _who: &AccountId,
+        _call: &RuntimeCall,
+        _info: &DispatchInfoOf<RuntimeCall>,
+        _len: usize,
+    ) -> TransactionValidity {
+        self.check()?;
+        Ok(ValidTransaction::default())
+    }
+
+    fn pre_dispatch(
+        self,
+        _who: &AccountId,
+        _call: &RuntimeCall,
+        _info: &DispatchInfoOf<RuntimeCall>,
+        _len: usize,
+    ) -> Result<(), TransactionValidityError> {
+        self.check()
+    }
+}
@@ -0,0 +1,34 @@
+use cumulus_primitives_core::relay_chain::MAX_POV_SIZE;
+use pallet_ethereum::Transaction as EthereumTransaction;
+use sp_runtime::transaction_validity::TransactionValidityError;
+
+use crate::invalid_transaction::CustomInvalidity;
+
+/// Largest encoded Ethereum transaction accepted, in bytes
+///
+/// Transactions are part of the block body and therefore of the proof of validity sent to
+/// the relay chain. A fortieth of the PoV limit leaves room for the storage proof of the
+/// transaction's execution and for the other transactions of the block, so a single
+/// transaction cannot push a block over the limit and get it rejected after inclusion.
+pub const MAX_ETHEREUM_TRANSACTION_SIZE: usize = MAX_POV_SIZE as usize / 40;
+
+/// Largest init code of a contract creation, in bytes, twice the EIP-170 code size limit
+/// as set by EIP-3860
+pub const MAX_INIT_CODE_SIZE: usize = 2 * 24_576;
+
+/// Check an Ethereum transaction of encoded length `len` against the PoV size limits
+pub fn check_transaction(
+    transaction: &EthereumTransaction,
+    len: usize,
+) -> Result<(), TransactionValidityError> {
+    if len > MAX_ETHEREUM_TRANSACTION_SIZE {
+        return Err(CustomInvalidity::TransactionTooLarge.into());
+    }
+
+    match crate::contract_init_code(transaction) {
+        Some(code) if code.len() > MAX_INIT_CODE_SIZE => {
+            Err(CustomInvalidity::InitCodeTooLarge.into())
+        }
+        _ => Ok(()),
+    }
+}
@@ -1,12 +1,31 @@
+use fp_evm::{ExitRevert, ExitSucceed, PrecompileFailure, PrecompileOutput};
+use frame_support::traits::{
+    tokens::{
+        fungible::NativeOrWithId,
+        nonfungibles_v2::{Inspect, InspectEnumerable},
+    },
+    Get, LockableCurrency, VestingSchedule,
+};
 use pallet_evm::{
-    IsPrecompileResult, Precompile, PrecompileHandle, PrecompileResult, PrecompileSet,
+    AddressMapping, GasWeightMapping, IsPrecompileResult, Precompile, PrecompileHandle,
+    PrecompileResult, PrecompileSet,
 };
-use sp_core::H160;
-use sp_std::marker::PhantomData;
+use sp_core::{H160, H256};
+use sp_runtime::traits::{SaturatedConversion, StaticLookup};
+use sp_std::{marker::PhantomData, prelude::*};
 
+use pallet_asset_conversion::weights::WeightInfo as AssetConversionWeightInfo;
+use pallet_collator_rewards::weights::WeightInfo as CollatorRewardsWeightInfo;
+use pallet_delegation::weights::WeightInfo as DelegationWeightInfo;
+use pallet_evm_deployment_control::{
+    weights::WeightInfo, ContractMetadata, DeploymentPolicy, DeploymentValidationError,
+    FactoryPermissions,
+};
 use pallet_evm_precompile_modexp::Modexp;
 use pallet_evm_precompile_sha3fips::Sha3FIPS256;
 use pallet_evm_precompile_simple::{ECRecover, ECRecoverPublicKey, Identity, Ripemd160, Sha256};
+use pallet_nfts::WeightInfo as NftsWeightInfo;
+use pallet_vesting::WeightInfo as VestingWeightInfo;
 
 pub struct FrontierPrecompiles<R>(PhantomData<R>);
 
@@ -18,7 +37,7 @@ where
         Self(Default::default())
     }
 
-    pub fn used_addresses() -> [H160; 7] {
+    pub fn used_addresses() -> [H160; 13] {
         [
             hash(1),
             hash(2),
@@ -27,6 +46,12 @@ where
             hash(5),
             hash(1024),
             hash(1025),
+            hash(2048),
+            hash(2049),
+            hash(2050),
+            hash(2051),
+            hash(2052),
+            hash(2053),
         ]
     }
 }
@@ -42,7 +67,17 @@ where
 
 impl<R> PrecompileSet for FrontierPrecompiles<R>
 where
-    R: pallet_evm::Config,
+    R: pallet_evm::Config
+        + pallet_contract_names::Config
+        + pallet_evm_deployment_control::Config
+        + pallet_delegation::Config
+        + pallet_collator_rewards::Config
+        + pallet_vesting::Config
+        + pallet_nfts::Config
+        + pallet_asset_conversion::Config<AssetKind = NativeOrWithId<u32>>,
+    R::AccountId: From<[u8; 32]>,
+    <R as pallet_nfts::Config>::CollectionId: From<u32>,
+    <R as pallet_nfts::Config>::ItemId: TryFrom<u128>,
 {
     fn execute(&self, handle: &mut impl PrecompileHandle) -> Option<PrecompileResult> {
         match handle.code_address() {
@@ -55,18 +90,1150 @@ where
             // Non-Frontier specific nor Ethereum precompiles :
             a if a == hash(1024) => Some(Sha3FIPS256::execute(handle)),
             a if a == hash(1025) => Some(ECRecoverPublicKey::execute(handle)),
-            _ => None,
+            // Chain specific precompiles :
+            a if a == hash(2048) => Some(ContractNamesPrecompile::<R>::execute(handle)),
+            a if a == hash(2049) => Some(FactoryPrecompile::<R>::execute(handle)),
+            a if a == hash(2050) => Some(StakingPrecompile::<R>::execute(handle)),
+            a if a == hash(2051) => Some(VestingPrecompile::<R>::execute(handle)),
+            a if a == hash(2052) => Some(DexPrecompile::<R>::execute(handle)),
+            a if a == hash(2053) => Some(DeploymentPolicyPrecompile::<R>::execute(handle)),
+            // ERC-721 views of NFT collections :
+            a => Erc721Precompile::<R>::collection_at(a)
+                .map(|collection| Erc721Precompile::<R>::execute(handle, collection)),
         }
     }
 
     fn is_precompile(&self, address: H160, _gas: u64) -> IsPrecompileResult {
-        IsPrecompileResult::Answer {
-            is_precompile: Self::used_addresses().contains(&address),
-            extra_cost: 0,
+        if Self::used_addresses().contains(&address) {
+            return IsPrecompileResult::Answer {
+                is_precompile: true,
+                extra_cost: 0,
+            };
+        }
+        match collection_id(address) {
+            // Whether the collection exists is a storage read
+            Some(_) => IsPrecompileResult::Answer {
+                is_precompile: Erc721Precompile::<R>::collection_at(address).is_some(),
+                extra_cost: R::GasWeightMapping::weight_to_gas(
+                    <R as frame_system::Config>::DbWeight::get().reads(1),
+                ),
+            },
+            None => IsPrecompileResult::Answer {
+                is_precompile: false,
+                extra_cost: 0,
+            },
+        }
+    }
+}
+
+/// Resolves names of the contract names pallet, the on-chain service discovery layer
+///
+/// The input is the raw name, e.g. `qnch.amm.router.v2`. The output is the ABI-encoded
+/// address the name resolves to, or the zero address if the name is not claimed.
+pub struct ContractNamesPrecompile<R>(PhantomData<R>);
+
+impl<R> Precompile for ContractNamesPrecompile<R>
+where
+    R: pallet_evm::Config + pallet_contract_names::Config,
+{
+    fn execute(handle: &mut impl PrecompileHandle) -> PrecompileResult {
+        let read = <R as frame_system::Config>::DbWeight::get().reads(1);
+        handle.record_cost(R::GasWeightMapping::weight_to_gas(read))?;
+
+        let target =
+            pallet_contract_names::Pallet::<R>::resolve(handle.input()).unwrap_or_default();
+
+        Ok(PrecompileOutput {
+            exit_status: ExitSucceed::Returned,
+            output: H256::from(target).as_bytes().to_vec(),
+        })
+    }
+}
+
+/// Selector of `registerChildMetadata(address,bytes,bytes)`
+const REGISTER_CHILD_METADATA: [u8; 4] = [0x2b, 0x8a, 0x77, 0x53];
+/// Selector of `remainingCreates()`
+const REMAINING_CREATES: [u8; 4] = [0x8c, 0xd3, 0xd3, 0x92];
+
+/// Deployment control functions callable by approved factories
+///
+/// The caller must be an active approved factory holding the [`FactoryPermissions`] bit
+/// of the function, granted by governance through `set_factory_permissions`. Calls made
+/// from the transaction creating a contract are rolled back with it, so a factory can
+/// report the metadata of its children atomically.
+///
+/// ABI-encoded functions:
+/// - `registerChildMetadata(address child, bytes label, bytes version)`, requiring
+///   `REGISTER_METADATA`
+/// - `remainingCreates() returns (uint256)`, requiring `READ_QUOTA`: contract creations
+///   still allowed in the current block, `type(uint256).max` if they are not rate limited
+pub struct FactoryPrecompile<R>(PhantomData<R>);
+
+impl<R> Precompile for FactoryPrecompile<R>
+where
+    R: pallet_evm::Config + pallet_evm_deployment_control::Config,
+{
+    fn execute(handle: &mut impl PrecompileHandle) -> PrecompileResult {
+        ensure_not_delegated(handle)?;
+
+        let input = handle.input().to_vec();
+        let factory = handle.context().caller;
+
+        match input.get(..4) {
+            Some(selector) if selector == REGISTER_CHILD_METADATA => {
+                Self::register_child_metadata(handle, factory, &input[4..])
+            }
+            Some(selector) if selector == REMAINING_CREATES => {
+                Self::remaining_creates(handle, factory)
+            }
+            _ => Err(revert("unknown selector")),
         }
     }
 }
 
+impl<R> FactoryPrecompile<R>
+where
+    R: pallet_evm::Config + pallet_evm_deployment_control::Config,
+{
+    fn register_child_metadata(
+        handle: &mut impl PrecompileHandle,
+        factory: H160,
+        args: &[u8],
+    ) -> PrecompileResult {
+        let weight =
+            <R as pallet_evm_deployment_control::Config>::WeightInfo::register_child_metadata();
+        handle.record_cost(R::GasWeightMapping::weight_to_gas(weight))?;
+
+        if handle.is_static() {
+            return Err(revert("cannot register metadata in a static call"));
+        }
+
+        let contract = read_address(args, 0)?;
+        let label = read_bytes(args, 1)?;
+        let version = read_bytes(args, 2)?;
+        let metadata = ContractMetadata {
+            label: label.try_into().map_err(|_| revert("label too long"))?,
+            version: version.try_into().map_err(|_| revert("version too long"))?,
+        };
+        pallet_evm_deployment_control::Pallet::<R>::register_child_metadata(
+            factory, contract, metadata,
+        )
+        .map_err(|err| revert(err.into()))?;
+
+        Ok(PrecompileOutput {
+            exit_status: ExitSucceed::Returned,
+            output: Vec::new(),
+        })
+    }
+
+    fn remaining_creates(handle: &mut impl PrecompileHandle, factory: H160) -> PrecompileResult {
+        let reads = <R as frame_system::Config>::DbWeight::get().reads(4);
+        handle.record_cost(R::GasWeightMapping::weight_to_gas(reads))?;
+
+        pallet_evm_deployment_control::Pallet::<R>::ensure_factory_permission(
+            &factory,
+            FactoryPermissions::READ_QUOTA,
+        )
+        .map_err(|err| revert(err.into()))?;
+
+        let remaining = match pallet_evm_deployment_control::Pallet::<R>::remaining_creates() {
+            Some(remaining) => H256::from_low_u64_be(remaining.into()),
+            None => H256::repeat_byte(0xff),
+        };
+
+        Ok(PrecompileOutput {
+            exit_status: ExitSucceed::Returned,
+            output: remaining.as_bytes().to_vec(),
+        })
+    }
+}
+
+/// Selector of `delegate(bytes32,uint256)`
+const DELEGATE: [u8; 4] = [0x8e, 0xb7, 0xcb, 0x38];
+/// Selector of `undelegate(bytes32,uint256)`
+const UNDELEGATE: [u8; 4] = [0x5e, 0x10, 0xb8, 0x3c];
+/// Selector of `withdrawUnbonded(bytes32)`
+const WITHDRAW_UNBONDED: [u8; 4] = [0x49, 0x27, 0x89, 0x4c];
+/// Selector of `claimRewards()`
+const CLAIM_REWARDS: [u8; 4] = [0x37, 0x25, 0x00, 0xab];
+/// Selector of `delegated(bytes32,address)`
+const DELEGATED: [u8; 4] = [0xef, 0x22, 0x9b, 0x3a];
+/// Selector of `pendingRewards(address)`
+const PENDING_REWARDS: [u8; 4] = [0x31, 0xd7, 0xa2, 0x62];
+
+/// Collator staking for EVM accounts, through the delegation and collator rewards pallets
+///
+/// Calls are dispatched as signed by the account the caller maps to. Candidates are
+/// identified by their 32 byte account id, and amounts are in the smallest unit of the
+/// native token.
+///
+/// ABI-encoded functions:
+/// - `delegate(bytes32 candidate, uint256 amount)`: delegate `amount` to `candidate`
+/// - `undelegate(bytes32 candidate, uint256 amount)`: start unbonding `amount`
+/// - `withdrawUnbonded(bytes32 candidate)`: release the amounts done unbonding
+/// - `claimRewards()`: claim the block rewards accrued by the caller
+/// - `delegated(bytes32 candidate, address delegator) returns (uint256)`: amount
+///   `delegator` delegates to `candidate`, unbonding amounts excluded
+/// - `pendingRewards(address account) returns (uint256)`: rewards `account` can claim
+pub struct StakingPrecompile<R>(PhantomData<R>);
+
+impl<R> Precompile for StakingPrecompile<R>
+where
+    R: pallet_evm::Config + pallet_delegation::Config + pallet_collator_rewards::Config,
+    R::AccountId: From<[u8; 32]>,
+{
+    fn execute(handle: &mut impl PrecompileHandle) -> PrecompileResult {
+        ensure_not_delegated(handle)?;
+
+        let input = handle.input().to_vec();
+        let caller = R::AddressMapping::into_account_id(handle.context().caller);
+        let args = input.get(4..).unwrap_or_default();
+
+        match input.get(..4) {
+            Some(selector) if selector == DELEGATE => Self::delegate(handle, caller, args),
+            Some(selector) if selector == UNDELEGATE => Self::undelegate(handle, caller, args),
+            Some(selector) if selector == WITHDRAW_UNBONDED => {
+                Self::withdraw_unbonded(handle, caller, args)
+            }
+            Some(selector) if selector == CLAIM_REWARDS => Self::claim_rewards(handle, caller),
+            Some(selector) if selector == DELEGATED => Self::delegated(handle, args),
+            Some(selector) if selector == PENDING_REWARDS => Self::pending_rewards(handle, args),
+            _ => Err(revert("unknown selector")),
+        }
+    }
+}
+
+impl<R> StakingPrecompile<R>
+where
+    R: pallet_evm::Config + pallet_delegation::Config + pallet_collator_rewards::Config,
+    R::AccountId: From<[u8; 32]>,
+{
+    fn delegate(
+        handle: &mut impl PrecompileHandle,
+        caller: R::AccountId,
+        args: &[u8],
+    ) -> PrecompileResult {
+        let weight = <R as pallet_delegation::Config>::WeightInfo::delegate();
+        handle.record_cost(R::GasWeightMapping::weight_to_gas(weight))?;
+        ensure_mutable(handle)?;
+
+        let candidate = read_account::<R>(args, 0)?;
+        let amount = read_balance(args, 1)?;
+        pallet_delegation::Pallet::<R>::delegate(
+            frame_system::RawOrigin::Signed(caller).into(),
+            candidate,
+            amount,
+        )
+        .map_err(|err| revert(err.into()))?;
+
+        Ok(returned(Vec::new()))
+    }
+
+    fn undelegate(
+        handle: &mut impl PrecompileHandle,
+        caller: R::AccountId,
+        args: &[u8],
+    ) -> PrecompileResult {
+        let weight = <R as pallet_delegation::Config>::WeightInfo::undelegate();
+        handle.record_cost(R::GasWeightMapping::weight_to_gas(weight))?;
+        ensure_mutable(handle)?;
+
+        let candidate = read_account::<R>(args, 0)?;
+        let amount = read_balance(args, 1)?;
+        pallet_delegation::Pallet::<R>::undelegate(
+            frame_system::RawOrigin::Signed(caller).into(),
+            candidate,
+            amount,
+        )
+        .map_err(|err| revert(err.into()))?;
+
+        Ok(returned(Vec::new()))
+    }
+
+    fn withdraw_unbonded(
+        handle: &mut impl PrecompileHandle,
+        caller: R::AccountId,
+        args: &[u8],
+    ) -> PrecompileResult {
+        let weight = <R as pallet_delegation::Config>::WeightInfo::withdraw_unbonded();
+        handle.record_cost(R::GasWeightMapping::weight_to_gas(weight))?;
+        ensure_mutable(handle)?;
+
+        let candidate = read_account::<R>(args, 0)?;
+        pallet_delegation::Pallet::<R>::withdraw_unbonded(
+            frame_system::RawOrigin::Signed(caller).into(),
+            candidate,
+        )
+        .map_err(|err| revert(err.into()))?;
+
+        Ok(returned(Vec::new()))
+    }
+
+    fn claim_rewards(handle: &mut impl PrecompileHandle, caller: R::AccountId) -> PrecompileResult {
+        let weight = <R as pallet_collator_rewards::Config>::WeightInfo::claim_rewards();
+        handle.record_cost(R::GasWeightMapping::weight_to_gas(weight))?;
+        ensure_mutable(handle)?;
+
+        pallet_collator_rewards::Pallet::<R>::claim_rewards(
+            frame_system::RawOrigin::Signed(caller).into(),
+        )
+        .map_err(|err| revert(err.into()))?;
+
+        Ok(returned(Vec::new()))
+    }
+
+    fn delegated(handle: &mut impl PrecompileHandle, args: &[u8]) -> PrecompileResult {
+        let read = <R as frame_system::Config>::DbWeight::get().reads(1);
+        handle.record_cost(R::GasWeightMapping::weight_to_gas(read))?;
+
+        let candidate = read_account::<R>(args, 0)?;
+        let delegator = R::AddressMapping::into_account_id(read_address(args, 1)?);
+        let amount = pallet_delegation::Pallet::<R>::delegated(&candidate, &delegator);
+
+        Ok(returned(encode_u128(amount.saturated_into())))
+    }
+
+    fn pending_rewards(handle: &mut impl PrecompileHandle, args: &[u8]) -> PrecompileResult {
+        let read = <R as frame_system::Config>::DbWeight::get().reads(1);
+        handle.record_cost(R::GasWeightMapping::weight_to_gas(read))?;
+
+        let account = R::AddressMapping::into_account_id(read_address(args, 0)?);
+        let amount = pallet_collator_rewards::Pallet::<R>::pending_rewards(&account);
+
+        Ok(returned(encode_u128(amount.saturated_into())))
+    }
+}
+
+/// Selector of `vest()`
+const VEST: [u8; 4] = [0x45, 0x8e, 0xfd, 0xe3];
+/// Selector of `vestOther(address)`
+const VEST_OTHER: [u8; 4] = [0x05, 0x5e, 0x60, 0xc8];
+/// Selector of `vestingBalance(address)`
+const VESTING_BALANCE: [u8; 4] = [0x19, 0x23, 0x99, 0xd1];
+
+/// Vesting schedules of the vesting pallet for EVM accounts
+///
+/// Accounts are the ones the addresses map to, so an EVM wallet unlocks the tokens vested
+/// to its address without signing a Substrate extrinsic.
+///
+/// ABI-encoded functions:
+/// - `vest()`: unlock the tokens vested to the caller so far
+/// - `vestOther(address target)`: unlock the tokens vested to `target` so far
+/// - `vestingBalance(address account) returns (uint256)`: tokens of `account` still
+///   locked by its vesting schedules, unlocked or not
+pub struct VestingPrecompile<R>(PhantomData<R>);
+
+impl<R> Precompile for VestingPrecompile<R>
+where
+    R: pallet_evm::Config + pallet_vesting::Config,
+{
+    fn execute(handle: &mut impl PrecompileHandle) -> PrecompileResult {
+        ensure_not_delegated(handle)?;
+
+        let input = handle.input().to_vec();
+        let caller = R::AddressMapping::into_account_id(handle.context().caller);
+        let args = input.get(4..).unwrap_or_default();
+
+        match input.get(..4) {
+            Some(selector) if selector == VEST => Self::vest(handle, caller, None),
+            Some(selector) if selector == VEST_OTHER => {
+                let target = R::AddressMapping::into_account_id(read_address(args, 0)?);
+                Self::vest(handle, caller, Some(target))
+            }
+            Some(selector) if selector == VESTING_BALANCE => Self::vesting_balance(handle, args),
+            _ => Err(revert("unknown selector")),
+        }
+    }
+}
+
+impl<R> VestingPrecompile<R>
+where
+    R: pallet_evm::Config + pallet_vesting::Config,
+{
+    fn vest(
+        handle: &mut impl PrecompileHandle,
+        caller: R::AccountId,
+        target: Option<R::AccountId>,
+    ) -> PrecompileResult {
+        let locks = <<R as pallet_vesting::Config>::Currency as LockableCurrency<
+            R::AccountId,
+        >>::MaxLocks::get();
+        let schedules = R::MAX_VESTING_SCHEDULES;
+        let locked = <R as pallet_vesting::Config>::WeightInfo::vest_locked(locks, schedules);
+        let unlocked = <R as pallet_vesting::Config>::WeightInfo::vest_unlocked(locks, schedules);
+        handle.record_cost(R::GasWeightMapping::weight_to_gas(locked.max(unlocked)))?;
+        ensure_mutable(handle)?;
+
+        let origin = frame_system::RawOrigin::Signed(caller).into();
+        match target {
+            Some(target) => pallet_vesting::Pallet::<R>::vest_other(
+                origin,
+                <R as frame_system::Config>::Lookup::unlookup(target),
+            ),
+            None => pallet_vesting::Pallet::<R>::vest(origin),
+        }
+        .map_err(|err| revert(err.into()))?;
+
+        Ok(returned(Vec::new()))
+    }
+
+    fn vesting_balance(handle: &mut impl PrecompileHandle, args: &[u8]) -> PrecompileResult {
+        let read = <R as frame_system::Config>::DbWeight::get().reads(1);
+        handle.record_cost(R::GasWeightMapping::weight_to_gas(read))?;
+
+        let account = R::AddressMapping::into_account_id(read_address(args, 0)?);
+        let locked = <pallet_vesting::Pallet<R> as VestingSchedule<_>>::vesting_balance(&account)
+            .unwrap_or_default();
+
+        Ok(returned(encode_u128(locked.saturated_into())))
+    }
+}
+
+/// Selector of `createPool(uint256,uint256)`
+const CREATE_POOL: [u8; 4] = [0x74, 0xa1, 0x10, 0xc2];
+/// Selector of `addLiquidity(uint256,uint256,uint256,uint256,uint256,uint256)`
+const ADD_LIQUIDITY: [u8; 4] = [0x62, 0xc4, 0xc5, 0xfb];
+/// Selector of `swapExactTokensForTokens(uint256[],uint256,uint256,address)`
+const SWAP_EXACT_TOKENS_FOR_TOKENS: [u8; 4] = [0x3f, 0xd5, 0x50, 0x0c];
+/// Selector of `quoteExactTokensForTokens(uint256,uint256,uint256)`
+const QUOTE_EXACT_TOKENS_FOR_TOKENS: [u8; 4] = [0x01, 0x0e, 0x6e, 0x36];
+
+/// Native token in the asset arguments of [`DexPrecompile`]
+const NATIVE_ASSET: [u8; 32] = [0xff; 32];
+
+/// Pools of the asset conversion pallet for EVM accounts
+///
+/// Calls are dispatched as signed by the account the caller maps to. Assets are `uint256`:
+/// `type(uint256).max` is the native token, anything else the id of a `pallet-assets` asset.
+/// Amounts are in the smallest unit of their asset.
+///
+/// ABI-encoded functions:
+/// - `createPool(uint256 asset1, uint256 asset2)`: create the pool of a pair, paying the
+///   pool setup fee
+/// - `addLiquidity(uint256 asset1, uint256 asset2, uint256 amount1, uint256 amount2,
+///   uint256 min1, uint256 min2)`: provide up to `amount1` and `amount2` to the pool of the
+///   pair, for its liquidity tokens
+/// - `swapExactTokensForTokens(uint256[] path, uint256 amountIn, uint256 amountOutMin,
+///   address to) returns (uint256)`: swap `amountIn` of the first asset of `path` for at
+///   least `amountOutMin` of the last, sent to `to`
+/// - `quoteExactTokensForTokens(uint256 assetIn, uint256 assetOut, uint256 amountIn)
+///   returns (uint256)`: amount a swap of `amountIn` would receive, fees included, or zero
+///   without a pool
+pub struct DexPrecompile<R>(PhantomData<R>);
+
+impl<R> Precompile for DexPrecompile<R>
+where
+    R: pallet_evm::Config + pallet_asset_conversion::Config<AssetKind = NativeOrWithId<u32>>,
+{
+    fn execute(handle: &mut impl PrecompileHandle) -> PrecompileResult {
+        ensure_not_delegated(handle)?;
+
+        let input = handle.input().to_vec();
+        let caller = R::AddressMapping::into_account_id(handle.context().caller);
+        let args = input.get(4..).unwrap_or_default();
+
+        match input.get(..4) {
+            Some(selector) if selector == CREATE_POOL => Self::create_pool(handle, caller, args),
+            Some(selector) if selector == ADD_LIQUIDITY => {
+                Self::add_liquidity(handle, caller, args)
+            }
+            Some(selector) if selector == SWAP_EXACT_TOKENS_FOR_TOKENS => {
+                Self::swap_exact_tokens_for_tokens(handle, caller, args)
+            }
+            Some(selector) if selector == QUOTE_EXACT_TOKENS_FOR_TOKENS => {
+                Self::quote_exact_tokens_for_tokens(handle, args)
+            }
+            _ => Err(revert("unknown selector")),
+        }
+    }
+}
+
+impl<R> DexPrecompile<R>
+where
+    R: pallet_evm::Config + pallet_asset_conversion::Config<AssetKind = NativeOrWithId<u32>>,
+{
+    fn create_pool(
+        handle: &mut impl PrecompileHandle,
+        caller: R::AccountId,
+        args: &[u8],
+    ) -> PrecompileResult {
+        let weight = <R as pallet_asset_conversion::Config>::WeightInfo::create_pool();
+        handle.record_cost(R::GasWeightMapping::weight_to_gas(weight))?;
+        ensure_mutable(handle)?;
+
+        let (asset1, asset2) = (read_asset(args, 0)?, read_asset(args, 1)?);
+        pallet_asset_conversion::Pallet::<R>::create_pool(
+            frame_system::RawOrigin::Signed(caller).into(),
+            Box::new(asset1),
+            Box::new(asset2),
+        )
+        .map_err(|err| revert(err.into()))?;
+
+        Ok(returned(Vec::new()))
+    }
+
+    fn add_liquidity(
+        handle: &mut impl PrecompileHandle,
+        caller: R::AccountId,
+        args: &[u8],
+    ) -> PrecompileResult {
+        let weight = <R as pallet_asset_conversion::Config>::WeightInfo::add_liquidity();
+        handle.record_cost(R::GasWeightMapping::weight_to_gas(weight))?;
+        ensure_mutable(handle)?;
+
+        let (asset1, asset2) = (read_asset(args, 0)?, read_asset(args, 1)?);
+        pallet_asset_conversion::Pallet::<R>::add_liquidity(
+            frame_system::RawOrigin::Signed(caller.clone()).into(),
+            Box::new(asset1),
+            Box::new(asset2),
+            read_balance(args, 2)?,
+            read_balance(args, 3)?,
+            read_balance(args, 4)?,
+            read_balance(args, 5)?,
+            caller,
+        )
+        .map_err(|err| revert(err.into()))?;
+
+        Ok(returned(Vec::new()))
+    }
+
+    fn swap_exact_tokens_for_tokens(
+        handle: &mut impl PrecompileHandle,
+        caller: R::AccountId,
+        args: &[u8],
+    ) -> PrecompileResult {
+        let path = read_words(args, 0)?
+            .into_iter()
+            .map(read_asset_word)
+            .collect::<Result<Vec<_>, _>>()?;
+        let hops = <R as pallet_asset_conversion::Config>::MaxSwapPathLength::get();
+        if path.len() > hops as usize {
+            return Err(revert("path too long"));
+        }
+        let weight =
+            <R as pallet_asset_conversion::Config>::WeightInfo::swap_exact_tokens_for_tokens(
+                path.len() as u32,
+            );
+        handle.record_cost(R::GasWeightMapping::weight_to_gas(weight))?;
+        ensure_mutable(handle)?;
+
+        let to = R::AddressMapping::into_account_id(read_address(args, 3)?);
+        let amount_out = pallet_asset_conversion::Pallet::<R>::do_swap_exact_tokens_for_tokens(
+            caller,
+            path,
+            read_balance(args, 1)?,
+            Some(read_balance(args, 2)?),
+            to,
+            true,
+        )
+        .map_err(|err| revert(err.into()))?;
+
+        Ok(returned(encode_u128(amount_out.saturated_into())))
+    }
+
+    fn quote_exact_tokens_for_tokens(
+        handle: &mut impl PrecompileHandle,
+        args: &[u8],
+    ) -> PrecompileResult {
+        let reads = <R as frame_system::Config>::DbWeight::get().reads(3);
+        handle.record_cost(R::GasWeightMapping::weight_to_gas(reads))?;
+
+        let (asset_in, asset_out) = (read_asset(args, 0)?, read_asset(args, 1)?);
+        let amount_out = pallet_asset_conversion::Pallet::<R>::quote_price_exact_tokens_for_tokens(
+            asset_in,
+            asset_out,
+            read_balance(args, 2)?,
+            true,
+        )
+        .unwrap_or_default();
+
+        Ok(returned(encode_u128(amount_out.saturated_into())))
+    }
+}
+
+/// ABI-encoded asset of [`DexPrecompile`] at position `index`
+fn read_asset(args: &[u8], index: usize) -> Result<NativeOrWithId<u32>, PrecompileFailure> {
+    read_asset_word(read_word(args, index)?)
+}
+
+/// Asset of [`DexPrecompile`] encoded in an ABI word
+fn read_asset_word(word: &[u8]) -> Result<NativeOrWithId<u32>, PrecompileFailure> {
+    if word == NATIVE_ASSET {
+        return Ok(NativeOrWithId::Native);
+    }
+    if word[..28].iter().any(|byte| *byte != 0) {
+        return Err(revert("unknown asset"));
+    }
+    Ok(NativeOrWithId::WithId(u32::from_be_bytes([
+        word[28], word[29], word[30], word[31],
+    ])))
+}
+
+/// Selector of `balanceOf(address)`
+const BALANCE_OF: [u8; 4] = [0x70, 0xa0, 0x82, 0x31];
+/// Selector of `ownerOf(uint256)`
+const OWNER_OF: [u8; 4] = [0x63, 0x52, 0x21, 0x1e];
+/// Selector of `transferFrom(address,address,uint256)`
+const TRANSFER_FROM: [u8; 4] = [0x23, 0xb8, 0x72, 0xdd];
+/// Selector of `safeTransferFrom(address,address,uint256)`
+const SAFE_TRANSFER_FROM: [u8; 4] = [0x42, 0x84, 0x2e, 0x0e];
+/// Selector of `approve(address,uint256)`
+const APPROVE: [u8; 4] = [0x09, 0x5e, 0xa7, 0xb3];
+/// Selector of `supportsInterface(bytes4)`, also the ERC-165 interface id
+const SUPPORTS_INTERFACE: [u8; 4] = [0x01, 0xff, 0xc9, 0xa7];
+
+/// Topic of `Transfer(address,address,uint256)`
+const TRANSFER_TOPIC: [u8; 32] =
+    hex_literal::hex!("ddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3ef");
+/// Topic of `Approval(address,address,uint256)`
+const APPROVAL_TOPIC: [u8; 32] =
+    hex_literal::hex!("8c5be1e5ebec7d5bd14f71427d1e84f3dd0314c0f7b2291e5b200ac8c7c3b925");
+
+/// Address prefix of the ERC-721 views of NFT collections, followed by the collection id
+const ERC721_PREFIX: [u8; 16] = [0xff, 0xff, 0xff, 0xff, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0];
+
+/// Address of the ERC-721 view of NFT collection `collection`
+pub fn collection_address(collection: u32) -> H160 {
+    let mut address = [0u8; 20];
+    address[..16].copy_from_slice(&ERC721_PREFIX);
+    address[16..].copy_from_slice(&collection.to_be_bytes());
+    H160(address)
+}
+
+/// NFT collection whose ERC-721 view would be at `address`
+fn collection_id(address: H160) -> Option<u32> {
+    let (prefix, id) = address.as_bytes().split_at(16);
+    (prefix == ERC721_PREFIX).then(|| u32::from_be_bytes([id[0], id[1], id[2], id[3]]))
+}
+
+/// EVM addresses the ERC-721 precompiles saw accounts call from or receive at
+///
+/// The address mapping is one-way, so `ownerOf` can only report owners through this index.
+#[frame_support::storage_alias]
+type Erc721Addresses<R: frame_system::Config> =
+    StorageMap<Erc721, Blake2_128Concat, <R as frame_system::Config>::AccountId, H160>;
+
+/// EVM address the ERC-721 precompiles last saw `account` at, if any
+pub fn seen_address<R: frame_system::Config>(account: &R::AccountId) -> Option<H160> {
+    Erc721Addresses::<R>::get(account)
+}
+
+/// ERC-721 views of the collections of the NFTs pallet
+///
+/// Collection `id` is at `0xffffffff000000000000000000000000` followed by `id` as 4 bytes,
+/// see [`collection_address`]. Token ids are item ids, and holders are the accounts their
+/// addresses map to, so items move between EVM and Substrate accounts without a bridge.
+///
+/// ABI-encoded functions:
+/// - `balanceOf(address owner) returns (uint256)`: items of the collection `owner` holds
+/// - `ownerOf(uint256 tokenId) returns (address)`: address the owner of `tokenId` was last
+///   seen at by these precompiles, the zero address if none
+/// - `transferFrom(address from, address to, uint256 tokenId)`: transfer `tokenId` owned
+///   by `from`, as its owner or as the delegate it approved
+/// - `safeTransferFrom(address from, address to, uint256 tokenId)`: as `transferFrom`, but
+///   reverts for contract recipients, which cannot be asked for `onERC721Received`
+/// - `approve(address approved, uint256 tokenId)`: let `approved` transfer `tokenId`, or
+///   clear the approvals of `tokenId` for the zero address
+/// - `supportsInterface(bytes4 interfaceId) returns (bool)`: ERC-165 only, as operators
+///   and `getApproved` have no counterpart in the NFTs pallet
+///
+/// Transfers and approvals emit the ERC-721 `Transfer` and `Approval` logs. Collections have
+/// no EVM code, so Solidity callers of functions without return values use low-level calls.
+pub struct Erc721Precompile<R>(PhantomData<R>);
+
+impl<R> Erc721Precompile<R>
+where
+    R: pallet_evm::Config + pallet_nfts::Config,
+    <R as pallet_nfts::Config>::CollectionId: From<u32>,
+    <R as pallet_nfts::Config>::ItemId: TryFrom<u128>,
+{
+    /// Existing collection whose ERC-721 view is at `address`
+    fn collection_at(address: H160) -> Option<<R as pallet_nfts::Config>::CollectionId> {
+        let collection = collection_id(address)?.into();
+        pallet_nfts::Pallet::<R>::collection_owner(&collection).map(|_| collection)
+    }
+
+    fn execute(
+        handle: &mut impl PrecompileHandle,
+        collection: <R as pallet_nfts::Config>::CollectionId,
+    ) -> PrecompileResult {
+        let input = handle.input().to_vec();
+        let caller = handle.context().caller;
+        let args = input.get(4..).unwrap_or_default();
+
+        match input.get(..4) {
+            Some(selector) if selector == BALANCE_OF => Self::balance_of(handle, collection, args),
+            Some(selector) if selector == OWNER_OF => Self::owner_of(handle, collection, args),
+            Some(selector) if selector == TRANSFER_FROM => {
+                ensure_not_delegated(handle)?;
+                Self::transfer_from(handle, collection, caller, args, false)
+            }
+            Some(selector) if selector == SAFE_TRANSFER_FROM => {
+                ensure_not_delegated(handle)?;
+                Self::transfer_from(handle, collection, caller, args, true)
+            }
+            Some(selector) if selector == APPROVE => {
+                ensure_not_delegated(handle)?;
+                Self::approve(handle, collection, caller, args)
+            }
+            Some(selector) if selector == SUPPORTS_INTERFACE => Self::supports_interface(args),
+            _ => Err(revert("unknown selector")),
+        }
+    }
+
+    fn balance_of(
+        handle: &mut impl PrecompileHandle,
+        collection: <R as pallet_nfts::Config>::CollectionId,
+        args: &[u8],
+    ) -> PrecompileResult {
+        let read = <R as frame_system::Config>::DbWeight::get().reads(1);
+        let owner = R::AddressMapping::into_account_id(read_address(args, 0)?);
+
+        // Items are counted one storage read at a time
+        let mut count = 0u128;
+        handle.record_cost(R::GasWeightMapping::weight_to_gas(read))?;
+        for _ in pallet_nfts::Pallet::<R>::owned_in_collection(&collection, &owner) {
+            handle.record_cost(R::GasWeightMapping::weight_to_gas(read))?;
+            count += 1;
+        }
+
+        Ok(returned(encode_u128(count)))
+    }
+
+    fn owner_of(
+        handle: &mut impl PrecompileHandle,
+        collection: <R as pallet_nfts::Config>::CollectionId,
+        args: &[u8],
+    ) -> PrecompileResult {
+        let reads = <R as frame_system::Config>::DbWeight::get().reads(2);
+        handle.record_cost(R::GasWeightMapping::weight_to_gas(reads))?;
+
+        let item = read_id(args, 0)?;
+        let owner = pallet_nfts::Pallet::<R>::owner(&collection, &item)
+            .ok_or_else(|| revert("nonexistent token"))?;
+        let address = Erc721Addresses::<R>::get(owner).unwrap_or_default();
+
+        Ok(returned(encode_address(address)))
+    }
+
+    fn transfer_from(
+        handle: &mut impl PrecompileHandle,
+        collection: <R as pallet_nfts::Config>::CollectionId,
+        caller: H160,
+        args: &[u8],
+        safe: bool,
+    ) -> PrecompileResult {
+        let weight = <R as pallet_nfts::Config>::WeightInfo::transfer()
+            .saturating_add(<R as frame_system::Config>::DbWeight::get().reads_writes(2, 2));
+        handle.record_cost(R::GasWeightMapping::weight_to_gas(weight))?;
+        handle.record_cost(log_cost(4, 0))?;
+        ensure_mutable(handle)?;
+
+        let (from, to) = (read_address(args, 0)?, read_address(args, 1)?);
+        let item = read_id(args, 2)?;
+        if safe && pallet_evm::AccountCodes::<R>::decode_len(to).unwrap_or_default() > 0 {
+            return Err(revert("contract recipients are not supported"));
+        }
+        let from_account = R::AddressMapping::into_account_id(from);
+        if pallet_nfts::Pallet::<R>::owner(&collection, &item) != Some(from_account) {
+            return Err(revert("transfer from incorrect owner"));
+        }
+
+        let (caller_account, to_account) = (
+            R::AddressMapping::into_account_id(caller),
+            R::AddressMapping::into_account_id(to),
+        );
+        pallet_nfts::Pallet::<R>::transfer(
+            frame_system::RawOrigin::Signed(caller_account.clone()).into(),
+            collection,
+            item,
+            <R as frame_system::Config>::Lookup::unlookup(to_account.clone()),
+        )
+        .map_err(|err| revert(err.into()))?;
+        Erc721Addresses::<R>::insert(caller_account, caller);
+        Erc721Addresses::<R>::insert(to_account, to);
+
+        handle.log(
+            handle.code_address(),
+            vec![
+                H256(TRANSFER_TOPIC),
+                address_topic(from),
+                address_topic(to),
+                H256::from_slice(read_word(args, 2)?),
+            ],
+            Vec::new(),
+        )?;
+
+        Ok(returned(Vec::new()))
+    }
+
+    fn approve(
+        handle: &mut impl PrecompileHandle,
+        collection: <R as pallet_nfts::Config>::CollectionId,
+        caller: H160,
+        args: &[u8],
+    ) -> PrecompileResult {
+        let weight = <R as pallet_nfts::Config>::WeightInfo::approve_transfer()
+            .max(<R as pallet_nfts::Config>::WeightInfo::clear_all_transfer_approvals())
+            .saturating_add(<R as frame_system::Config>::DbWeight::get().writes(1));
+        handle.record_cost(R::GasWeightMapping::weight_to_gas(weight))?;
+        handle.record_cost(log_cost(4, 0))?;
+        ensure_mutable(handle)?;
+
+        let approved = read_address(args, 0)?;
+        let item = read_id(args, 1)?;
+        let caller_account = R::AddressMapping::into_account_id(caller);
+        let origin = frame_system::RawOrigin::Signed(caller_account.clone()).into();
+        if approved.is_zero() {
+            pallet_nfts::Pallet::<R>::clear_all_transfer_approvals(origin, collection, item)
+        } else {
+            pallet_nfts::Pallet::<R>::approve_transfer(
+                origin,
+                collection,
+                item,
+                <R as frame_system::Config>::Lookup::unlookup(R::AddressMapping::into_account_id(
+                    approved,
+                )),
+                None,
+            )
+        }
+        .map_err(|err| revert(err.into()))?;
+        Erc721Addresses::<R>::insert(caller_account, caller);
+
+        handle.log(
+            handle.code_address(),
+            vec![
+                H256(APPROVAL_TOPIC),
+                address_topic(caller),
+                address_topic(approved),
+                H256::from_slice(read_word(args, 1)?),
+            ],
+            Vec::new(),
+        )?;
+
+        Ok(returned(Vec::new()))
+    }
+
+    fn supports_interface(args: &[u8]) -> PrecompileResult {
+        let interface = read_word(args, 0)?;
+        let supported =
+            interface[..4] == SUPPORTS_INTERFACE && interface[4..].iter().all(|b| *b == 0);
+
+        Ok(returned(encode_u128(supported.into())))
+    }
+}
+
+/// Selector of `deploymentPolicy()`
+const DEPLOYMENT_POLICY: [u8; 4] = [0x26, 0xb4, 0x22, 0xd4];
+/// Selector of `deploymentQuota()`
+const DEPLOYMENT_QUOTA: [u8; 4] = [0xd0, 0x0a, 0x15, 0x06];
+/// Selector of `remainingQuota(address)`
+const REMAINING_QUOTA: [u8; 4] = [0xd8, 0x72, 0xe4, 0x13];
+/// Selector of `maxCreatesPerBlock()`
+const MAX_CREATES_PER_BLOCK: [u8; 4] = [0xb1, 0x6c, 0x5d, 0x92];
+/// Selector of `deploymentWindows()`
+const DEPLOYMENT_WINDOWS: [u8; 4] = [0xb7, 0x07, 0xeb, 0x9b];
+/// Selector of `maxCodeSize()`
+const MAX_CODE_SIZE: [u8; 4] = [0xb6, 0xb2, 0x6f, 0xd2];
+/// Selector of `canDeploy(address)`
+const CAN_DEPLOY: [u8; 4] = [0x56, 0x9e, 0x0d, 0x29];
+
+/// Read-only view of the deployment control policy, for EVM tooling to adapt to the chain,
+/// e.g. a Hardhat plugin warning before a deployment the chain would refuse
+///
+/// Limits that are not set read as `type(uint256).max`.
+///
+/// ABI-encoded functions:
+/// - `deploymentPolicy() returns (uint8 mode, uint256 parameter)`: `0` permissioned, `1`
+///   permissionless, `2` permissionless with the fee `parameter`, `3` rollout admitting
+///   `parameter` percent of the creations of accounts that are not authorized
+/// - `deploymentQuota() returns (uint256 quota, uint256 period)`: contract creations of a
+///   deployer per period of `period` blocks
+/// - `remainingQuota(address deployer) returns (uint256)`: creations left to `deployer` in
+///   the current quota period
+/// - `maxCreatesPerBlock() returns (uint256)`: contract creations admitted per block
+/// - `deploymentWindows() returns ((uint256 period, uint256 offset, uint256 duration)[])`:
+///   recurring windows deployments are open in, none meaning always open
+/// - `maxCodeSize() returns (uint256 code, uint256 initCode)`: largest deployed code and
+///   init code, in bytes
+/// - `canDeploy(address deployer) returns (bool allowed, uint8 reason)`: whether
+///   `deployer` is permitted, within a deployment window and quota in this block, and if
+///   not the `DeploymentValidationError` code its transaction would be refused with. The
+///   fee of the permissionless policy is not checked
+pub struct DeploymentPolicyPrecompile<R>(PhantomData<R>);
+
+impl<R> Precompile for DeploymentPolicyPrecompile<R>
+where
+    R: pallet_evm::Config + pallet_evm_deployment_control::Config,
+{
+    fn execute(handle: &mut impl PrecompileHandle) -> PrecompileResult {
+        let input = handle.input().to_vec();
+        let args = input.get(4..).unwrap_or_default();
+
+        match input.get(..4) {
+            Some(selector) if selector == DEPLOYMENT_POLICY => Self::deployment_policy(handle),
+            Some(selector) if selector == DEPLOYMENT_QUOTA => Self::deployment_quota(handle),
+            Some(selector) if selector == REMAINING_QUOTA => Self::remaining_quota(handle, args),
+            Some(selector) if selector == MAX_CREATES_PER_BLOCK => {
+                Self::max_creates_per_block(handle)
+            }
+            Some(selector) if selector == DEPLOYMENT_WINDOWS => Self::deployment_windows(handle),
+            Some(selector) if selector == MAX_CODE_SIZE => Ok(Self::max_code_size()),
+            Some(selector) if selector == CAN_DEPLOY => Self::can_deploy(handle, args),
+            _ => Err(revert("unknown selector")),
+        }
+    }
+}
+
+impl<R> DeploymentPolicyPrecompile<R>
+where
+    R: pallet_evm::Config + pallet_evm_deployment_control::Config,
+{
+    fn record_reads(
+        handle: &mut impl PrecompileHandle,
+        reads: u64,
+    ) -> Result<(), PrecompileFailure> {
+        let weight = <R as frame_system::Config>::DbWeight::get().reads(reads);
+        handle.record_cost(R::GasWeightMapping::weight_to_gas(weight))
+    }
+
+    fn deployment_policy(handle: &mut impl PrecompileHandle) -> PrecompileResult {
+        Self::record_reads(handle, 1)?;
+
+        let (mode, parameter) =
+            match pallet_evm_deployment_control::EvmDeploymentPolicy::<R>::get() {
+                DeploymentPolicy::Permissioned => (0, 0),
+                DeploymentPolicy::Permissionless => (1, 0),
+                DeploymentPolicy::PermissionlessWithFee { fee } => (2, fee.saturated_into()),
+                DeploymentPolicy::Rollout { admitted } => (3, admitted.deconstruct().into()),
+            };
+
+        let mut output = encode_u128(mode);
+        output.extend(encode_u128(parameter));
+        Ok(returned(output))
+    }
+
+    fn deployment_quota(handle: &mut impl PrecompileHandle) -> PrecompileResult {
+        Self::record_reads(handle, 1)?;
+
+        let quota = pallet_evm_deployment_control::DeploymentQuota::<R>::get();
+        let period = <R as pallet_evm_deployment_control::Config>::QuotaPeriodLength::get();
+
+        let mut output = encode_limit(quota);
+        output.extend(encode_u128(period.saturated_into()));
+        Ok(returned(output))
+    }
+
+    fn remaining_quota(handle: &mut impl PrecompileHandle, args: &[u8]) -> PrecompileResult {
+        Self::record_reads(handle, 2)?;
+
+        let deployer = R::AddressMapping::into_account_id(read_address(args, 0)?);
+        let remaining = pallet_evm_deployment_control::Pallet::<R>::remaining_quota(&deployer);
+
+        Ok(returned(encode_limit(remaining)))
+    }
+
+    fn max_creates_per_block(handle: &mut impl PrecompileHandle) -> PrecompileResult {
+        Self::record_reads(handle, 1)?;
+
+        let max = pallet_evm_deployment_control::MaxCreatesPerBlock::<R>::get();
+
+        Ok(returned(encode_limit(max)))
+    }
+
+    fn deployment_windows(handle: &mut impl PrecompileHandle) -> PrecompileResult {
+        Self::record_reads(handle, 1)?;
+
+        let windows = pallet_evm_deployment_control::DeploymentWindows::<R>::get();
+
+        let mut output = encode_u128(32);
+        output.extend(encode_u128(windows.len() as u128));
+        for window in windows {
+            output.extend(encode_u128(window.period.saturated_into()));
+            output.extend(encode_u128(window.offset.saturated_into()));
+            output.extend(encode_u128(window.duration.saturated_into()));
+        }
+        Ok(returned(output))
+    }
+
+    fn max_code_size() -> PrecompileOutput {
+        let config = R::config();
+        let limit = |size: Option<usize>| encode_limit(size.map(|size| size as u128));
+
+        let mut output = limit(config.create_contract_limit);
+        output.extend(limit(config.max_initcode_size));
+        returned(output)
+    }
+
+    fn can_deploy(handle: &mut impl PrecompileHandle, args: &[u8]) -> PrecompileResult {
+        sp_tracing::enter_span!(sp_tracing::trace_span!(
+            target: pallet_evm_deployment_control::TRACING_TARGET,
+            "precompile_can_deploy"
+        ));
+        let weight = <R as pallet_evm_deployment_control::Config>::WeightInfo::ensure_can_deploy(
+            <R as pallet_evm_deployment_control::Config>::MaxDeploymentWindows::get(),
+        );
+        handle.record_cost(R::GasWeightMapping::weight_to_gas(weight))?;
+        Self::record_reads(handle, 2)?;
+
+        let deployer = R::AddressMapping::into_account_id(read_address(args, 0)?);
+        let checked = pallet_evm_deployment_control::Pallet::<R>::ensure_can_deploy(&deployer)
+            .and_then(|()| {
+                match pallet_evm_deployment_control::Pallet::<R>::remaining_quota(&deployer) {
+                    Some(0) => Err(DeploymentValidationError::QuotaExhausted),
+                    _ => Ok(()),
+                }
+            });
+        let (allowed, reason) = match checked {
+            Ok(()) => (true, 0),
+            Err(err) => (false, u8::from(err)),
+        };
+
+        let mut output = encode_u128(allowed.into());
+        output.extend(encode_u128(reason.into()));
+        Ok(returned(output))
+    }
+}
+
+fn ensure_mutable(handle: &impl PrecompileHandle) -> Result<(), PrecompileFailure> {
+    if handle.is_static() {
+        return Err(revert("cannot modify state in a static call"));
+    }
+    Ok(())
+}
+
+fn returned(output: Vec<u8>) -> PrecompileOutput {
+    PrecompileOutput {
+        exit_status: ExitSucceed::Returned,
+        output,
+    }
+}
+
+fn encode_u128(value: u128) -> Vec<u8> {
+    let mut word = [0u8; 32];
+    word[16..].copy_from_slice(&value.to_be_bytes());
+    word.to_vec()
+}
+
+/// ABI-encoded `uint256` of a limit, `type(uint256).max` if there is none
+fn encode_limit<N: Into<u128>>(limit: Option<N>) -> Vec<u8> {
+    match limit {
+        Some(limit) => encode_u128(limit.into()),
+        None => vec![0xff; 32],
+    }
+}
+
+fn encode_address(address: H160) -> Vec<u8> {
+    let mut word = [0u8; 32];
+    word[12..].copy_from_slice(address.as_bytes());
+    word.to_vec()
+}
+
+/// Log topic of an indexed `address`
+fn address_topic(address: H160) -> H256 {
+    H256::from_slice(&encode_address(address))
+}
+
+/// Gas of a log with `topics` topics and `data_len` bytes of data
+fn log_cost(topics: u64, data_len: u64) -> u64 {
+    375u64
+        .saturating_add(375u64.saturating_mul(topics))
+        .saturating_add(8u64.saturating_mul(data_len))
+}
+
+fn revert(message: &str) -> PrecompileFailure {
+    PrecompileFailure::Revert {
+        exit_status: ExitRevert::Reverted,
+        output: message.as_bytes().to_vec(),
+    }
+}
+
+/// Refuse DELEGATECALL and CALLCODE, under which the caller seen by the precompile is whoever
+/// called the delegating contract
+fn ensure_not_delegated(handle: &impl PrecompileHandle) -> Result<(), PrecompileFailure> {
+    if handle.code_address() != handle.context().address {
+        return Err(revert("cannot be called with DELEGATECALL or CALLCODE"));
+    }
+    Ok(())
+}
+
+/// ABI word at position `index` of the arguments
+fn read_word(args: &[u8], index: usize) -> Result<&[u8], PrecompileFailure> {
+    let start = index
+        .checked_mul(32)
+        .ok_or_else(|| revert("invalid input"))?;
+    args.get(start..start.saturating_add(32))
+        .ok_or_else(|| revert("invalid input"))
+}
+
+/// ABI word at position `index`, read as an offset or length that must fit in 64 bits
+fn read_usize(args: &[u8], index: usize) -> Result<usize, PrecompileFailure> {
+    let word = read_word(args, index)?;
+    if word[..24].iter().any(|byte| *byte != 0) {
+        return Err(revert("invalid input"));
+    }
+    let mut value = [0u8; 8];
+    value.copy_from_slice(&word[24..]);
+    usize::try_from(u64::from_be_bytes(value)).map_err(|_| revert("invalid input"))
+}
+
+/// ABI-encoded `address` at position `index`
+fn read_address(args: &[u8], index: usize) -> Result<H160, PrecompileFailure> {
+    Ok(H160::from_slice(&read_word(args, index)?[12..]))
+}
+
+/// ABI-encoded `bytes32` account id at position `index`
+fn read_account<R>(args: &[u8], index: usize) -> Result<R::AccountId, PrecompileFailure>
+where
+    R: frame_system::Config,
+    R::AccountId: From<[u8; 32]>,
+{
+    let mut account = [0u8; 32];
+    account.copy_from_slice(read_word(args, index)?);
+    Ok(account.into())
+}
+
+/// ABI-encoded `uint256` amount at position `index`, which must fit the balance type
+fn read_balance<B: TryFrom<u128>>(args: &[u8], index: usize) -> Result<B, PrecompileFailure> {
+    let word = read_word(args, index)?;
+    if word[..16].iter().any(|byte| *byte != 0) {
+        return Err(revert("amount too large"));
+    }
+    let mut value = [0u8; 16];
+    value.copy_from_slice(&word[16..]);
+    B::try_from(u128::from_be_bytes(value)).map_err(|_| revert("amount too large"))
+}
+
+/// ABI-encoded `uint256` id at position `index`, which must fit the id type
+fn read_id<I: TryFrom<u128>>(args: &[u8], index: usize) -> Result<I, PrecompileFailure> {
+    read_balance(args, index).map_err(|_| revert("id too large"))
+}
+
+/// ABI-encoded `bytes` whose offset is at position `index`
+fn read_bytes(args: &[u8], index: usize) -> Result<Vec<u8>, PrecompileFailure> {
+    let offset = read_usize(args, index)?;
+    if offset % 32 != 0 {
+        return Err(revert("invalid input"));
+    }
+    let data = args.get(offset..).ok_or_else(|| revert("invalid input"))?;
+    let len = read_usize(data, 0)?;
+    data.get(32..32usize.saturating_add(len))
+        .map(|bytes| bytes.to_vec())
+        .ok_or_else(|| revert("invalid input"))
+}
+
+/// ABI-encoded fixed size words of the array whose offset is at position `index`
+fn read_words(args: &[u8], index: usize) -> Result<Vec<&[u8]>, PrecompileFailure> {
+    let offset = read_usize(args, index)?;
+    if offset % 32 != 0 {
+        return Err(revert("invalid input"));
+    }
+    let data = args.get(offset..).ok_or_else(|| revert("invalid input"))?;
+    let len = read_usize(data, 0)?;
+    let items = data.get(32..).ok_or_else(|| revert("invalid input"))?;
+    (0..len).map(|i| read_word(items, i)).collect()
+}
+
 fn hash(a: u64) -> H160 {
     H160::from_low_u64_be(a)
 }
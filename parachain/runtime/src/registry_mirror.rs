@@ -0,0 +1,54 @@
+//! Mirror of the deployer registry on Ethereum mainnet
+//!
+//! Registry updates are queued as outbound messages, committed to in the header digest and
+//! replayed by the registry contract on Ethereum once proven against the commitment. The
+//! payloads are packed as the contract decodes them, with a one byte tag followed by the
+//! SCALE encoded accounts and raw factory addresses.
+
+use pallet_evm_deployment_control::DeploymentLifecycle;
+use parity_scale_codec::Encode;
+use sp_core::H160;
+use sp_std::vec::Vec;
+
+use crate::{AccountId, OutboundCommitments};
+
+/// `0x00 ++ deployer`
+pub const DEPLOYER_AUTHORIZED: u8 = 0x00;
+/// `0x01 ++ deployer`
+pub const DEPLOYER_REVOKED: u8 = 0x01;
+/// `0x02 ++ factory ++ admin`
+pub const FACTORY_REGISTERED: u8 = 0x02;
+/// `0x03 ++ old ++ new`
+pub const KEY_ROTATED: u8 = 0x03;
+
+/// Queues the deployer registry updates for Ethereum
+pub struct RegistryMirror;
+
+impl RegistryMirror {
+    fn queue(tag: u8, fields: &[&[u8]]) {
+        let mut payload = Vec::with_capacity(1 + fields.iter().map(|f| f.len()).sum::<usize>());
+        payload.push(tag);
+        for field in fields {
+            payload.extend_from_slice(field);
+        }
+        OutboundCommitments::queue_message(payload);
+    }
+}
+
+impl DeploymentLifecycle<AccountId> for RegistryMirror {
+    fn on_authorized(deployer: &AccountId) {
+        Self::queue(DEPLOYER_AUTHORIZED, &[&deployer.encode()]);
+    }
+
+    fn on_factory_registered(factory: &H160, admin: &AccountId) {
+        Self::queue(FACTORY_REGISTERED, &[factory.as_bytes(), &admin.encode()]);
+    }
+
+    fn on_revoked(deployer: &AccountId) {
+        Self::queue(DEPLOYER_REVOKED, &[&deployer.encode()]);
+    }
+
+    fn on_key_rotated(old: &AccountId, new: &AccountId) {
+        Self::queue(KEY_ROTATED, &[&old.encode(), &new.encode()]);
+    }
+}
@@ -0,0 +1,2430 @@
+//! Runtime-level tests for deployment control enforcement.
+
+use ethereum::{LegacyTransaction, TransactionAction, TransactionSignature, TransactionV2};
+use fp_evm::{ExitError, ExitReason, ExitSucceed};
+use fp_self_contained::SelfContainedCall;
+use frame_support::{
+    assert_noop, assert_ok,
+    dispatch::GetDispatchInfo,
+    traits::{
+        fungible, fungible::NativeOrWithId, fungibles, tokens::nonfungibles_v2, Contains, Get,
+        Hooks, OnUnbalanced, ReservableCurrency, VestingSchedule,
+    },
+    BoundedVec,
+};
+use pallet_evm::{AddressMapping, FeeCalculator, Runner};
+use sp_core::{crypto::Ss58Codec, H160, H256, U256};
+use sp_runtime::{
+    traits::{Dispatchable, SignedExtension},
+    transaction_validity::{InvalidTransaction, TransactionValidityError},
+    BuildStorage, DispatchError, FixedPointNumber, FixedU128, Percent,
+};
+
+use pallet_asset_conversion::QuotePrice;
+use pallet_audit_anchors::DocumentKind;
+use pallet_evm_deployment_control::{
+    ApprovalPolicy, ContractMetadata, DeployerAddress, DeployerAdmins, DeploymentPolicy,
+    DeploymentValidationError, FactoryPermissions,
+    GenesisConfig as DeploymentControlGenesisConfig, GenesisDeployerRole, OpcodePolicy,
+};
+use pallet_fee_distribution::{FeeKind, FeeSplit};
+use pallet_session::SessionManager;
+#[cfg(feature = "compliance-mode")]
+use pallet_transfer_allowlist::TransferMode;
+
+use crate::{
+    code_freeze::{self, CodeFreezeCallFilter},
+    configs::{CouncilCollective, DealWithFees, NativeAndAssets},
+    deployment_control::{CheckDeployment, DeploymentCallFilter},
+    fee_assets::{DealWithAssetFees, RegisteredFeeAssets},
+    network_binding::CheckNetwork,
+    registry_mirror,
+    AccountId, AssetConversion, Assets, AuditAnchors, Balance, Balances, BlockNumber, CodeFreeze,
+    CollatorOffences, CollatorRewards, ContractNames, Contracts, CustomInvalidity, Delegation,
+    DeployerReputation, DeploymentEscrow, EmergencyPause, EvmDeploymentControl, FeeAssets,
+    FeeDistribution, FeeSponsorship, Issuance, Nfts, OutboundCommitments, Runtime, RuntimeCall,
+    RuntimeEvent, RuntimeGenesisConfig, RuntimeOrigin, System, Treasury, UpgradeCommittee,
+    Vesting, CENTIUNIT, DAYS, EXISTENTIAL_DEPOSIT, UNIT,
+};
+#[cfg(feature = "compliance-mode")]
+use crate::{transfer_allowlist::TransferCallFilter, TransferAllowlist};
+
+fn new_test_ext() -> sp_io::TestExternalities {
+    let storage = RuntimeGenesisConfig::default().build_storage().unwrap();
+    let mut ext = sp_io::TestExternalities::new(storage);
+    ext.execute_with(|| System::set_block_number(1));
+    ext
+}
+
+fn deployer() -> H160 {
+    H160::repeat_byte(0x11)
+}
+
+fn account_of(address: H160) -> AccountId {
+    <Runtime as pallet_evm::Config>::AddressMapping::into_account_id(address)
+}
+
+fn create_call(source: H160) -> RuntimeCall {
+    RuntimeCall::EVM(pallet_evm::Call::create {
+        source,
+        init: vec![0x60, 0x00, 0x60, 0x00, 0xf3],
+        value: U256::zero(),
+        gas_limit: 1_000_000,
+        max_fee_per_gas: U256::from(1_000_000_000u64),
+        max_priority_fee_per_gas: None,
+        nonce: None,
+        access_list: vec![],
+    })
+}
+
+#[cfg(feature = "compliance-mode")]
+fn transfer_call(target: H160, value: U256) -> RuntimeCall {
+    RuntimeCall::EVM(pallet_evm::Call::call {
+        source: deployer(),
+        target,
+        input: vec![],
+        value,
+        gas_limit: 21_000,
+        max_fee_per_gas: U256::from(1_000_000_000u64),
+        max_priority_fee_per_gas: None,
+        nonce: None,
+        access_list: vec![],
+    })
+}
+
+fn authorize_call(deployer: AccountId) -> RuntimeCall {
+    RuntimeCall::EvmDeploymentControl(pallet_evm_deployment_control::Call::authorize_deployer {
+        deployer,
+    })
+}
+
+fn batch_all(calls: Vec<RuntimeCall>) -> RuntimeCall {
+    RuntimeCall::Utility(pallet_utility::Call::batch_all { calls })
+}
+
+/// Ethereum transaction creating a contract with `input` as init code, or calling `to`
+///
+/// The self-contained checks take the sender as recovered, so the signature need only be
+/// well-formed.
+fn transact(to: Option<H160>, input: Vec<u8>) -> RuntimeCall {
+    let chain_id = pallet_evm_chain_id::ChainId::<Runtime>::get();
+    let (gas_price, _) = <Runtime as pallet_evm::Config>::FeeCalculator::min_gas_price();
+    let one = H256::from_low_u64_be(1);
+    let transaction = LegacyTransaction {
+        nonce: U256::zero(),
+        gas_price,
+        gas_limit: U256::from(100_000),
+        action: to.map_or(TransactionAction::Create, TransactionAction::Call),
+        value: U256::zero(),
+        input,
+        signature: TransactionSignature::new(chain_id * 2 + 35, one, one).unwrap(),
+    };
+    pallet_ethereum::Call::<Runtime>::transact { transaction: TransactionV2::Legacy(transaction) }
+        .into()
+}
+
+/// Deploy a contract forwarding its calldata to `precompile` with DELEGATECALL, reverting when
+/// the delegated call fails, and return its address
+fn delegating_proxy(precompile: H160) -> H160 {
+    let proxy = H160::repeat_byte(0x43);
+    // CALLDATACOPY the input, DELEGATECALL `precompile` with all gas, then RETURN or REVERT
+    // with the returned data
+    let code = [
+        &[0x36, 0x60, 0x00, 0x60, 0x00, 0x37][..],
+        &[0x60, 0x00, 0x60, 0x00, 0x36, 0x60, 0x00, 0x73],
+        precompile.as_bytes(),
+        &[0x5a, 0xf4, 0x60, 0x31, 0x57],
+        &[0x3d, 0x60, 0x00, 0x60, 0x00, 0x3e, 0x3d, 0x60, 0x00, 0xfd],
+        &[0x5b, 0x3d, 0x60, 0x00, 0x60, 0x00, 0x3e, 0x3d, 0x60, 0x00, 0xf3],
+    ]
+    .concat();
+    pallet_evm::AccountCodes::<Runtime>::insert(proxy, code);
+    proxy
+}
+
+/// Call `precompile` with `input` through a DELEGATECALL and return how the execution ended
+fn call_delegated(caller: H160, precompile: H160, input: Vec<u8>) -> ExitReason {
+    <Runtime as pallet_evm::Config>::Runner::call(
+        caller,
+        delegating_proxy(precompile),
+        input,
+        U256::zero(),
+        1_000_000,
+        None,
+        None,
+        None,
+        vec![],
+        false,
+        false,
+        None,
+        None,
+        <Runtime as pallet_evm::Config>::config(),
+    )
+    .unwrap_or_else(|_| panic!("call failed"))
+    .exit_reason
+}
+
+/// Call a contract running CALLER SELFDESTRUCT and return how the execution ended
+fn call_selfdestruct() -> ExitReason {
+    let contract = H160::repeat_byte(0x42);
+    pallet_evm::AccountCodes::<Runtime>::insert(contract, vec![0x33, 0xff]);
+
+    <Runtime as pallet_evm::Config>::Runner::call(
+        deployer(),
+        contract,
+        vec![],
+        U256::zero(),
+        1_000_000,
+        None,
+        None,
+        None,
+        vec![],
+        false,
+        false,
+        None,
+        None,
+        <Runtime as pallet_evm::Config>::config(),
+    )
+    .unwrap_or_else(|_| panic!("call failed"))
+    .exit_reason
+}
+
+fn call_filtered() -> DispatchError {
+    frame_system::Error::<Runtime>::CallFiltered.into()
+}
+
+#[test]
+fn filter_rejects_unauthorized_create() {
+    new_test_ext().execute_with(|| {
+        assert!(!DeploymentCallFilter::contains(&create_call(deployer())));
+
+        assert_ok!(EvmDeploymentControl::authorize_deployer(
+            RuntimeOrigin::root(),
+            account_of(deployer())
+        ));
+        assert!(DeploymentCallFilter::contains(&create_call(deployer())));
+    });
+}
+
+#[test]
+fn filter_follows_deployment_policy() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(EvmDeploymentControl::set_deployment_policy(
+            RuntimeOrigin::root(),
+            DeploymentPolicy::Permissionless
+        ));
+        assert!(DeploymentCallFilter::contains(&create_call(deployer())));
+
+        // The fee cannot be charged at the filter, so Substrate-level creations stay closed
+        assert_ok!(EvmDeploymentControl::set_deployment_policy(
+            RuntimeOrigin::root(),
+            DeploymentPolicy::PermissionlessWithFee { fee: 1 }
+        ));
+        assert!(!DeploymentCallFilter::contains(&create_call(deployer())));
+    });
+}
+
+#[test]
+fn unauthorized_create_is_filtered() {
+    new_test_ext().execute_with(|| {
+        let origin = RuntimeOrigin::signed(account_of(deployer()));
+
+        let result = create_call(deployer()).dispatch(origin);
+        assert_eq!(result.map_err(|e| e.error), Err(call_filtered()));
+    });
+}
+
+#[test]
+fn batched_create_is_filtered() {
+    new_test_ext().execute_with(|| {
+        let origin = RuntimeOrigin::signed(account_of(deployer()));
+
+        let result = batch_all(vec![create_call(deployer())]).dispatch(origin);
+        assert_eq!(result.map_err(|e| e.error), Err(call_filtered()));
+    });
+}
+
+#[test]
+fn batch_cannot_authorize_without_root() {
+    new_test_ext().execute_with(|| {
+        let origin = RuntimeOrigin::signed(account_of(deployer()));
+
+        // batch(authorize + deploy) from a regular account fails on the authorization
+        let result = batch_all(vec![
+            authorize_call(account_of(deployer())),
+            create_call(deployer()),
+        ])
+        .dispatch(origin);
+
+        assert_eq!(result.map_err(|e| e.error), Err(DispatchError::BadOrigin));
+        assert!(!EvmDeploymentControl::is_authorized(&account_of(deployer())));
+    });
+}
+
+#[test]
+fn root_batch_authorization_unlocks_create() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(batch_all(vec![authorize_call(account_of(deployer()))])
+            .dispatch(RuntimeOrigin::root()));
+        assert!(EvmDeploymentControl::is_authorized(&account_of(deployer())));
+
+        // The create now passes the filter, whatever the EVM makes of it afterwards
+        let origin = RuntimeOrigin::signed(account_of(deployer()));
+        let result = batch_all(vec![create_call(deployer())]).dispatch(origin);
+        assert_ne!(result.map_err(|e| e.error), Err(call_filtered()));
+    });
+}
+
+#[test]
+fn substrate_creates_count_against_the_block_cap() {
+    new_test_ext().execute_with(|| {
+        let account = account_of(deployer());
+        assert_ok!(EvmDeploymentControl::authorize_deployer(
+            RuntimeOrigin::root(),
+            account.clone()
+        ));
+        assert_ok!(EvmDeploymentControl::set_max_creates_per_block(RuntimeOrigin::root(), Some(1)));
+        let exhausted: Result<(), TransactionValidityError> =
+            Err(InvalidTransaction::ExhaustsResources.into());
+        let create = create_call(deployer());
+        let info = create.get_dispatch_info();
+
+        // The filter only reads, the creation is counted before dispatch
+        assert!(DeploymentCallFilter::contains(&create));
+        assert_eq!(EvmDeploymentControl::remaining_creates(), Some(1));
+        assert_ok!(CheckDeployment.pre_dispatch(&account, &create, &info, 0));
+        assert_eq!(EvmDeploymentControl::remaining_creates(), Some(0));
+        let result = create.clone().dispatch(RuntimeOrigin::signed(account.clone()));
+        assert_ne!(result.map_err(|e| e.error), Err(call_filtered()));
+
+        assert_eq!(
+            CheckDeployment.validate(&account, &create, &info, 0).map(|_| ()),
+            exhausted
+        );
+        assert_eq!(CheckDeployment.pre_dispatch(&account, &create, &info, 0), exhausted);
+
+        // The cap is per block
+        EvmDeploymentControl::on_finalize(1);
+        System::set_block_number(2);
+        assert_ok!(CheckDeployment.pre_dispatch(&account, &create, &info, 0));
+    });
+}
+
+#[test]
+fn substrate_creates_count_against_the_quota() {
+    new_test_ext().execute_with(|| {
+        let account = account_of(deployer());
+        assert_ok!(EvmDeploymentControl::authorize_deployer(
+            RuntimeOrigin::root(),
+            account.clone()
+        ));
+        assert_ok!(EvmDeploymentControl::set_deployment_quota(RuntimeOrigin::root(), Some(1)));
+        let exhausted: Result<(), TransactionValidityError> =
+            Err(CustomInvalidity::QuotaExhausted.into());
+        let create = create_call(deployer());
+        let info = create.get_dispatch_info();
+
+        assert_ok!(CheckDeployment.pre_dispatch(&account, &create, &info, 0));
+        assert_eq!(EvmDeploymentControl::remaining_quota(&account), Some(0));
+        let result = create.clone().dispatch(RuntimeOrigin::signed(account.clone()));
+        assert_ne!(result.map_err(|e| e.error), Err(call_filtered()));
+        assert_eq!(EvmDeploymentControl::remaining_quota(&account), Some(0));
+
+        assert_eq!(
+            CheckDeployment.validate(&account, &create, &info, 0).map(|_| ()),
+            exhausted
+        );
+        assert_eq!(CheckDeployment.pre_dispatch(&account, &create, &info, 0), exhausted);
+
+        // A batch cannot create past the quota either
+        assert_ok!(EvmDeploymentControl::set_deployment_quota(RuntimeOrigin::root(), Some(2)));
+        let batched = batch_all(vec![create_call(deployer()), create_call(deployer())]);
+        assert_eq!(CheckDeployment.pre_dispatch(&account, &batched, &info, 0), exhausted);
+    });
+}
+
+#[test]
+fn paused_pallet_is_filtered() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(EvmDeploymentControl::authorize_deployer(
+            RuntimeOrigin::root(),
+            account_of(deployer())
+        ));
+        assert_ok!(EmergencyPause::pause(
+            RuntimeOrigin::root(),
+            b"EVM".to_vec().try_into().unwrap(),
+            None,
+            10
+        ));
+
+        let origin = RuntimeOrigin::signed(account_of(deployer()));
+        let result = create_call(deployer()).dispatch(origin);
+        assert_eq!(result.map_err(|e| e.error), Err(call_filtered()));
+    });
+}
+
+#[test]
+fn block_production_pallets_cannot_be_paused() {
+    new_test_ext().execute_with(|| {
+        for pallet in [&b"System"[..], b"ParachainSystem", b"Timestamp", b"EmergencyPause"] {
+            assert!(EmergencyPause::pause(
+                RuntimeOrigin::root(),
+                pallet.to_vec().try_into().unwrap(),
+                None,
+                10
+            )
+            .is_err());
+        }
+    });
+}
+
+#[test]
+fn selfdestruct_is_banned_by_default() {
+    new_test_ext().execute_with(|| {
+        assert_eq!(call_selfdestruct(), ExitReason::Error(ExitError::OutOfGas));
+
+        assert_ok!(EvmDeploymentControl::set_opcode_policy(
+            RuntimeOrigin::root(),
+            OpcodePolicy { allow_selfdestruct: true, limit_create_depth: false }
+        ));
+        assert_eq!(call_selfdestruct(), ExitReason::Succeed(ExitSucceed::Suicided));
+    });
+}
+
+#[test]
+fn opcode_policy_keeps_call_depth() {
+    new_test_ext().execute_with(|| {
+        assert_eq!(<Runtime as pallet_evm::Config>::config().call_stack_limit, 1024);
+
+        assert_ok!(EvmDeploymentControl::set_opcode_policy(
+            RuntimeOrigin::root(),
+            OpcodePolicy { allow_selfdestruct: false, limit_create_depth: true }
+        ));
+        assert_eq!(<Runtime as pallet_evm::Config>::config().call_stack_limit, 1024);
+    });
+}
+
+#[test]
+fn deploy_contract_requires_matching_source() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(EvmDeploymentControl::authorize_deployer(
+            RuntimeOrigin::root(),
+            account_of(deployer())
+        ));
+
+        let result = EvmDeploymentControl::deploy_contract(
+            RuntimeOrigin::signed(account_of(deployer())),
+            H160::repeat_byte(0x22),
+            vec![0x60, 0x00, 0x60, 0x00, 0xf3],
+            U256::zero(),
+            1_000_000,
+            Default::default(),
+        );
+        assert_eq!(
+            result,
+            Err(pallet_evm_deployment_control::Error::<Runtime>::InvalidSource.into())
+        );
+    });
+}
+
+#[test]
+fn repeated_refused_deployments_raise_the_alert() {
+    new_test_ext().execute_with(|| {
+        let intruder = H160::repeat_byte(0x66);
+        let threshold =
+            <Runtime as pallet_evm_deployment_control::Config>::RejectedAttemptThreshold::get();
+
+        for _ in 0..threshold {
+            assert_ok!(EvmDeploymentControl::deploy_contract(
+                RuntimeOrigin::signed(account_of(intruder)),
+                intruder,
+                vec![0x60, 0x00, 0x60, 0x00, 0xf3],
+                U256::zero(),
+                1_000_000,
+                Default::default(),
+            ));
+        }
+        assert_eq!(EvmDeploymentControl::rejected_attempts(&account_of(intruder)), threshold);
+
+        assert_ok!(EvmDeploymentControl::report_rejected_attempts(
+            RuntimeOrigin::none(),
+            account_of(intruder)
+        ));
+        System::assert_last_event(
+            pallet_evm_deployment_control::Event::<Runtime>::RepeatedRejectedAttempts {
+                deployer: account_of(intruder),
+                attempts: threshold,
+            }
+            .into(),
+        );
+        assert!(EvmDeploymentControl::is_flagged(&account_of(intruder)));
+    });
+}
+
+#[test]
+#[cfg(feature = "compliance-mode")]
+fn restricted_mode_filters_transfers_to_unlisted_recipients() {
+    new_test_ext().execute_with(|| {
+        let transfer = transfer_call(H160::repeat_byte(0x33), U256::one());
+        assert!(TransferCallFilter::contains(&transfer));
+
+        assert_ok!(TransferAllowlist::set_mode(
+            RuntimeOrigin::root(),
+            TransferMode::Restricted
+        ));
+        assert!(!TransferCallFilter::contains(&transfer));
+        let empty_transfer = transfer_call(H160::repeat_byte(0x33), U256::zero());
+        assert!(TransferCallFilter::contains(&empty_transfer));
+
+        let origin = RuntimeOrigin::signed(account_of(deployer()));
+        let result = transfer.dispatch(origin);
+        assert_eq!(result.map_err(|e| e.error), Err(call_filtered()));
+    });
+}
+
+#[test]
+#[cfg(feature = "compliance-mode")]
+fn allowlisted_recipient_receives_transfers() {
+    new_test_ext().execute_with(|| {
+        let recipient = H160::repeat_byte(0x33);
+        assert_ok!(TransferAllowlist::set_mode(
+            RuntimeOrigin::root(),
+            TransferMode::Restricted
+        ));
+        assert_ok!(TransferAllowlist::allow_recipient(
+            RuntimeOrigin::root(),
+            recipient
+        ));
+
+        let transfer = transfer_call(recipient, U256::one());
+        assert!(TransferCallFilter::contains(&transfer));
+    });
+}
+
+#[test]
+fn frozen_contracts_cannot_be_upgraded() {
+    new_test_ext().execute_with(|| {
+        let proxy = H160::repeat_byte(0x44);
+        // upgradeTo(address) naming a new implementation
+        let mut input = vec![0x36, 0x59, 0xcf, 0xe6];
+        input.extend_from_slice(&[0u8; 12]);
+        input.extend_from_slice(H160::repeat_byte(0x55).as_bytes());
+        let call_proxy = |input| {
+            RuntimeCall::EVM(pallet_evm::Call::call {
+                source: deployer(),
+                target: proxy,
+                input,
+                value: U256::zero(),
+                gas_limit: 100_000,
+                max_fee_per_gas: U256::from(1_000_000_000u64),
+                max_priority_fee_per_gas: None,
+                nonce: None,
+                access_list: vec![],
+            })
+        };
+        let upgrade = call_proxy(input);
+        assert!(CodeFreezeCallFilter::contains(&upgrade));
+
+        assert_ok!(CodeFreeze::freeze_contract(RuntimeOrigin::root(), proxy));
+        assert!(!CodeFreezeCallFilter::contains(&upgrade));
+        // Other calls to the frozen contract are unaffected
+        assert!(CodeFreezeCallFilter::contains(&call_proxy(vec![])));
+
+        let origin = RuntimeOrigin::signed(account_of(deployer()));
+        let result = batch_all(vec![upgrade]).dispatch(origin);
+        assert_eq!(result.map_err(|e| e.error), Err(call_filtered()));
+    });
+}
+
+#[test]
+fn contracts_cannot_be_created_at_frozen_addresses() {
+    new_test_ext().execute_with(|| {
+        // A factory running CREATE2 of empty code with a zero salt
+        let factory = H160::repeat_byte(0x46);
+        pallet_evm::AccountCodes::<Runtime>::insert(
+            factory,
+            vec![0x60, 0x00, 0x60, 0x00, 0x60, 0x00, 0x60, 0x00, 0xf5, 0x00],
+        );
+        let created = code_freeze::create2_address(factory, H256::zero(), &[]);
+        assert_ok!(CodeFreeze::freeze_contract(RuntimeOrigin::root(), created));
+        let frozen_creation: Result<(), DispatchError> =
+            Err(pallet_code_freeze::Error::<Runtime>::CreationAtFrozenAddress.into());
+
+        // Creations by contracts fail the execution instead of trapping the block
+        let result = <Runtime as pallet_evm::Config>::Runner::call(
+            deployer(),
+            factory,
+            vec![],
+            U256::zero(),
+            1_000_000,
+            None,
+            None,
+            None,
+            vec![],
+            false,
+            false,
+            None,
+            None,
+            <Runtime as pallet_evm::Config>::config(),
+        );
+        assert_eq!(result.map(|_| ()).map_err(|e| e.error), frozen_creation);
+        assert_eq!(CodeFreeze::take_frozen_creation(), None);
+
+        // Top-level creations are refused before running
+        let result = <Runtime as pallet_evm::Config>::Runner::create2(
+            factory,
+            vec![],
+            H256::zero(),
+            U256::zero(),
+            1_000_000,
+            None,
+            None,
+            None,
+            vec![],
+            false,
+            false,
+            None,
+            None,
+            <Runtime as pallet_evm::Config>::config(),
+        );
+        assert_eq!(result.map(|_| ()).map_err(|e| e.error), frozen_creation);
+
+        // Other addresses remain free
+        assert_ok!(CodeFreeze::unfreeze_contract(RuntimeOrigin::root(), created));
+        assert!(<Runtime as pallet_evm::Config>::Runner::call(
+            deployer(),
+            factory,
+            vec![],
+            U256::zero(),
+            1_000_000,
+            None,
+            None,
+            None,
+            vec![],
+            false,
+            false,
+            None,
+            None,
+            <Runtime as pallet_evm::Config>::config(),
+        )
+        .is_ok());
+    });
+}
+
+#[test]
+fn low_reputation_revokes_deployer() {
+    new_test_ext().execute_with(|| {
+        let account = account_of(deployer());
+        assert_ok!(EvmDeploymentControl::authorize_deployer(
+            RuntimeOrigin::root(),
+            account.clone()
+        ));
+
+        for _ in 0..4 {
+            assert_ok!(DeployerReputation::report_incident(
+                RuntimeOrigin::root(),
+                account.clone()
+            ));
+        }
+
+        assert!(DeployerReputation::is_suspended(&account));
+        assert!(!EvmDeploymentControl::is_authorized(&account));
+        assert!(!DeploymentCallFilter::contains(&create_call(deployer())));
+    });
+}
+
+#[test]
+fn deployers_anchor_audit_documents_to_their_contracts() {
+    new_test_ext().execute_with(|| {
+        let contract = H160::repeat_byte(0x42);
+        let report = H256::repeat_byte(0xaa);
+        EvmDeploymentControl::note_contract_created(deployer(), contract);
+
+        assert_ok!(AuditAnchors::anchor(
+            RuntimeOrigin::signed(account_of(deployer())),
+            contract,
+            report,
+            DocumentKind::Audit
+        ));
+        assert!(AuditAnchors::anchor(
+            RuntimeOrigin::signed(account_of(H160::repeat_byte(0x99))),
+            contract,
+            H256::repeat_byte(0xbb),
+            DocumentKind::Audit
+        )
+        .is_err());
+
+        let anchors = AuditAnchors::anchors(&contract);
+        assert_eq!(anchors.len(), 1);
+        assert_eq!(anchors[0].document, report);
+        assert_eq!(anchors[0].anchored_by, account_of(deployer()));
+        assert_eq!(AuditAnchors::contracts_of(&report), vec![contract]);
+    });
+}
+
+#[test]
+#[cfg(feature = "dependency-registry")]
+fn declared_dependencies_are_served_in_reverse() {
+    use pallet_contract_dependencies::DependencyKind;
+    use pallet_contract_dependencies_runtime_api::{
+        runtime_decl_for_contract_dependencies_api::ContractDependenciesApiV1 as Api,
+    };
+
+    use crate::ContractDependencies;
+
+    new_test_ext().execute_with(|| {
+        let (oracle, vault, router) =
+            (H160::repeat_byte(0x42), H160::repeat_byte(0x43), H160::repeat_byte(0x44));
+        for contract in [vault, router] {
+            EvmDeploymentControl::note_contract_created(deployer(), contract);
+        }
+        let origin = RuntimeOrigin::signed(account_of(deployer()));
+
+        assert_ok!(ContractDependencies::declare_dependency(
+            origin.clone(),
+            vault,
+            oracle,
+            DependencyKind::Oracle
+        ));
+        assert_ok!(ContractDependencies::declare_dependency(
+            origin,
+            router,
+            vault,
+            DependencyKind::Other
+        ));
+        // Only the deployer of the dependent contract declares its dependencies
+        assert!(ContractDependencies::declare_dependency(
+            RuntimeOrigin::signed(account_of(H160::repeat_byte(0x99))),
+            router,
+            oracle,
+            DependencyKind::Oracle
+        )
+        .is_err());
+
+        let dependents = <Runtime as Api<crate::Block>>::dependents(oracle);
+        assert_eq!(dependents.len(), 1);
+        assert_eq!(dependents[0].contract, vault);
+        assert_eq!(<Runtime as Api<crate::Block>>::blast_radius(oracle), vec![vault, router]);
+    });
+}
+
+#[test]
+fn required_escrows_are_locked_on_creation_and_slashed_toward_users() {
+    use pallet_deployment_escrow::EscrowPolicy;
+    use pallet_evm::OnCreate;
+
+    new_test_ext().execute_with(|| {
+        let account = account_of(deployer());
+        let user = account_of(H160::repeat_byte(0x77));
+        let contract = H160::repeat_byte(0x42);
+        <Balances as fungible::Mutate<AccountId>>::set_balance(&account, 100 * UNIT);
+        assert_ok!(DeploymentEscrow::set_policy(
+            RuntimeOrigin::root(),
+            EscrowPolicy::Required { amount: 10 * UNIT }
+        ));
+
+        <Runtime as pallet_evm::Config>::OnCreate::on_create(deployer(), contract);
+        let escrow = DeploymentEscrow::escrow(&contract).unwrap();
+        assert_eq!((escrow.deployer, escrow.amount), (account.clone(), 10 * UNIT));
+        assert_eq!(Balances::reserved_balance(&account), 10 * UNIT);
+
+        let treasury_balance = Balances::free_balance(Treasury::account_id());
+        assert_ok!(DeploymentEscrow::slash(
+            RuntimeOrigin::root(),
+            contract,
+            BoundedVec::truncate_from(vec![(user.clone(), 4 * UNIT)])
+        ));
+        assert_eq!(Balances::free_balance(&user), 4 * UNIT);
+        assert_eq!(
+            Balances::free_balance(Treasury::account_id()),
+            treasury_balance + 6 * UNIT
+        );
+
+        // Deployers who cannot afford the next escrow are refused on every creation path
+        <Balances as fungible::Mutate<AccountId>>::set_balance(&account, UNIT);
+        assert!(DeploymentEscrow::ensure_can_escrow(&account).is_err());
+        assert_ok!(EvmDeploymentControl::authorize_deployer(
+            RuntimeOrigin::root(),
+            account.clone()
+        ));
+        let unaffordable: Result<(), TransactionValidityError> =
+            Err(CustomInvalidity::EscrowUnaffordable.into());
+        let create = batch_all(vec![create_call(deployer())]);
+        let info = create.get_dispatch_info();
+        assert_eq!(
+            CheckDeployment.validate(&account, &create, &info, 0).map(|_| ()),
+            unaffordable
+        );
+        assert_eq!(CheckDeployment.pre_dispatch(&account, &create, &info, 0), unaffordable);
+        let creation = transact(None, vec![0x60, 0x00, 0x60, 0x00, 0xf3]);
+        let info = creation.get_dispatch_info();
+        assert_eq!(
+            creation.pre_dispatch_self_contained(&deployer(), &info, 0),
+            Some(unaffordable)
+        );
+    });
+}
+
+#[test]
+fn wasm_contracts_follow_the_deployment_authorization() {
+    use frame_support::traits::EnsureOrigin;
+
+    new_test_ext().execute_with(|| {
+        let account = account_of(deployer());
+        let upload = |account: &AccountId| {
+            <Runtime as pallet_contracts::Config>::UploadOrigin::try_origin(RuntimeOrigin::signed(
+                account.clone(),
+            ))
+            .is_ok()
+        };
+        let instantiate = |account: &AccountId| {
+            <Runtime as pallet_contracts::Config>::InstantiateOrigin::try_origin(
+                RuntimeOrigin::signed(account.clone()),
+            )
+            .is_ok()
+        };
+        assert!(!upload(&account) && !instantiate(&account));
+        assert_eq!(
+            Contracts::upload_code(
+                RuntimeOrigin::signed(account.clone()),
+                vec![0x00, 0x61, 0x73, 0x6d],
+                None,
+                pallet_contracts::Determinism::Enforced
+            ),
+            Err(DispatchError::BadOrigin)
+        );
+
+        assert_ok!(EvmDeploymentControl::authorize_deployer(
+            RuntimeOrigin::root(),
+            account.clone()
+        ));
+        assert!(upload(&account) && instantiate(&account));
+
+        assert_ok!(EvmDeploymentControl::revoke_deployer(
+            RuntimeOrigin::root(),
+            account.clone()
+        ));
+        assert!(!upload(&account) && !instantiate(&account));
+    });
+}
+
+#[test]
+fn registry_updates_are_committed_for_ethereum() {
+    use pallet_outbound_commitments::OutboundCommitment;
+    use parity_scale_codec::Encode;
+
+    new_test_ext().execute_with(|| {
+        let account = account_of(deployer());
+        assert_ok!(EvmDeploymentControl::authorize_deployer(
+            RuntimeOrigin::root(),
+            account.clone()
+        ));
+        assert_ok!(EvmDeploymentControl::revoke_deployer(
+            RuntimeOrigin::root(),
+            account.clone()
+        ));
+        OutboundCommitments::on_finalize(1);
+
+        let mut authorized = vec![registry_mirror::DEPLOYER_AUTHORIZED];
+        authorized.extend(account.encode());
+        let mut revoked = vec![registry_mirror::DEPLOYER_REVOKED];
+        revoked.extend(account.encode());
+        let payloads: Vec<_> = pallet_outbound_commitments::Messages::<Runtime>::get()
+            .into_iter()
+            .map(|message| message.payload.into_inner())
+            .collect();
+        assert_eq!(payloads, vec![authorized, revoked]);
+
+        let commitment = OutboundCommitment::find(&System::digest()).unwrap();
+        assert_eq!((commitment.first_nonce, commitment.count), (0, 2));
+        let proof = OutboundCommitments::prove_message(1).unwrap();
+        assert_eq!(proof.root, commitment.root);
+    });
+}
+
+#[test]
+fn precompile_resolves_contract_names() {
+    new_test_ext().execute_with(|| {
+        let contract = H160::repeat_byte(0x42);
+        let name = b"qnch.amm.router.v2".to_vec();
+        EvmDeploymentControl::note_contract_created(deployer(), contract);
+        assert_ok!(ContractNames::claim(
+            RuntimeOrigin::signed(account_of(deployer())),
+            name.clone().try_into().unwrap(),
+            contract
+        ));
+
+        let resolve = |name: Vec<u8>| {
+            <Runtime as pallet_evm::Config>::Runner::call(
+                deployer(),
+                H160::from_low_u64_be(2048),
+                name,
+                U256::zero(),
+                1_000_000,
+                None,
+                None,
+                None,
+                vec![],
+                false,
+                false,
+                None,
+                None,
+                <Runtime as pallet_evm::Config>::config(),
+            )
+            .unwrap_or_else(|_| panic!("call failed"))
+            .value
+        };
+
+        assert_eq!(resolve(name), H256::from(contract).as_bytes().to_vec());
+        assert_eq!(
+            resolve(b"unknown".to_vec()),
+            H256::zero().as_bytes().to_vec()
+        );
+    });
+}
+
+#[test]
+fn factory_precompile_registers_child_metadata() {
+    new_test_ext().execute_with(|| {
+        let factory = H160::repeat_byte(0x33);
+        let child = H160::repeat_byte(0x44);
+        assert_ok!(EvmDeploymentControl::register_factory(
+            RuntimeOrigin::root(),
+            factory,
+            account_of(deployer())
+        ));
+        EvmDeploymentControl::note_contract_created(factory, child);
+
+        let call = |input: Vec<u8>| {
+            <Runtime as pallet_evm::Config>::Runner::call(
+                factory,
+                H160::from_low_u64_be(2049),
+                input,
+                U256::zero(),
+                1_000_000,
+                None,
+                None,
+                None,
+                vec![],
+                false,
+                false,
+                None,
+                None,
+                <Runtime as pallet_evm::Config>::config(),
+            )
+            .unwrap_or_else(|_| panic!("call failed"))
+        };
+
+        // registerChildMetadata(child, "pool", "1")
+        let mut input = vec![0x2b, 0x8a, 0x77, 0x53];
+        input.extend_from_slice(H256::from(child).as_bytes());
+        input.extend_from_slice(H256::from_low_u64_be(0x60).as_bytes());
+        input.extend_from_slice(H256::from_low_u64_be(0xa0).as_bytes());
+        for field in [&b"pool"[..], &b"1"[..]] {
+            input.extend_from_slice(H256::from_low_u64_be(field.len() as u64).as_bytes());
+            let mut word = [0u8; 32];
+            word[..field.len()].copy_from_slice(field);
+            input.extend_from_slice(&word);
+        }
+
+        // Rejected until governance grants the permission
+        assert!(matches!(call(input.clone()).exit_reason, ExitReason::Revert(_)));
+        assert_eq!(EvmDeploymentControl::contract_metadata(&child), None);
+
+        assert_ok!(EvmDeploymentControl::set_factory_permissions(
+            RuntimeOrigin::root(),
+            factory,
+            FactoryPermissions::REGISTER_METADATA
+        ));
+        // A contract called by the factory cannot register on its behalf with a DELEGATECALL
+        let precompile = H160::from_low_u64_be(2049);
+        assert!(matches!(
+            call_delegated(factory, precompile, input.clone()),
+            ExitReason::Revert(_)
+        ));
+        assert_eq!(EvmDeploymentControl::contract_metadata(&child), None);
+        assert!(matches!(call(input).exit_reason, ExitReason::Succeed(_)));
+        assert_eq!(
+            EvmDeploymentControl::contract_metadata(&child),
+            Some(ContractMetadata {
+                label: b"pool".to_vec().try_into().unwrap(),
+                version: b"1".to_vec().try_into().unwrap(),
+            })
+        );
+
+        // remainingCreates() requires its own permission
+        let remaining_creates = vec![0x8c, 0xd3, 0xd3, 0x92];
+        assert!(matches!(call(remaining_creates.clone()).exit_reason, ExitReason::Revert(_)));
+        assert_ok!(EvmDeploymentControl::set_factory_permissions(
+            RuntimeOrigin::root(),
+            factory,
+            FactoryPermissions(
+                FactoryPermissions::REGISTER_METADATA.0 | FactoryPermissions::READ_QUOTA.0
+            )
+        ));
+        assert_eq!(call(remaining_creates).value, H256::repeat_byte(0xff).as_bytes().to_vec());
+    });
+}
+
+#[test]
+fn metadata_supports_hardware_wallets() {
+    // Metadata hash checks on hardware wallets build on metadata v15
+    assert!(Runtime::metadata_versions().contains(&15));
+    assert!(Runtime::metadata_at_version(15).is_some());
+
+    let extensions = Runtime::metadata_ir().extrinsic.signed_extensions;
+    assert!(extensions
+        .iter()
+        .any(|extension| extension.identifier == "CheckMetadataHash"));
+}
+
+#[test]
+fn pooled_creations_of_revoked_deployers_fail_and_count() {
+    new_test_ext().execute_with(|| {
+        let account = account_of(deployer());
+        <Balances as fungible::Mutate<AccountId>>::set_balance(&account, 1_000 * UNIT);
+        assert_ok!(EvmDeploymentControl::authorize_deployer(
+            RuntimeOrigin::root(),
+            account.clone()
+        ));
+        let creation = transact(None, vec![0x60, 0x00, 0x60, 0x00, 0xf3]);
+        let info = creation.get_dispatch_info();
+        assert!(matches!(
+            creation.validate_self_contained(&deployer(), &info, 0),
+            Some(Ok(_))
+        ));
+
+        // Revoked after the transaction entered the pool, the creation is included to count
+        // the attempt, and fails
+        assert_ok!(EvmDeploymentControl::revoke_deployer(
+            RuntimeOrigin::root(),
+            account.clone()
+        ));
+        assert_eq!(
+            creation.pre_dispatch_self_contained(&deployer(), &info, 0),
+            Some(Ok(()))
+        );
+        assert_eq!(EvmDeploymentControl::rejected_attempts(&account), 1);
+        assert_ok!(creation.apply_self_contained(deployer()).unwrap());
+        assert!(System::events().iter().any(|record| matches!(
+            record.event,
+            RuntimeEvent::Ethereum(pallet_ethereum::Event::Executed {
+                exit_reason: ExitReason::Error(ExitError::DesignatedInvalid),
+                ..
+            })
+        )));
+    });
+}
+
+#[test]
+fn unauthorized_creations_count_as_rejected_attempts_until_flagged() {
+    new_test_ext().execute_with(|| {
+        let account = account_of(deployer());
+        <Balances as fungible::Mutate<AccountId>>::set_balance(&account, 1_000 * UNIT);
+        let flagged: Result<(), TransactionValidityError> =
+            Err(CustomInvalidity::FlaggedDeployer.into());
+        let creation = transact(None, vec![0x60, 0x00, 0x60, 0x00, 0xf3]);
+        let info = creation.get_dispatch_info();
+        assert!(matches!(
+            creation.validate_self_contained(&deployer(), &info, 0),
+            Some(Ok(_))
+        ));
+
+        // Substrate-level creations are refused by the filter, and counted beforehand
+        let create = create_call(deployer());
+        let batched = batch_all(vec![create_call(deployer())]);
+        let threshold =
+            <Runtime as pallet_evm_deployment_control::Config>::RejectedAttemptThreshold::get();
+        for _ in 0..threshold / 2 {
+            assert_ok!(CheckDeployment.pre_dispatch(&account, &create, &info, 0));
+            assert_ok!(CheckDeployment.pre_dispatch(&account, &batched, &info, 0));
+        }
+        assert_eq!(EvmDeploymentControl::rejected_attempts(&account), threshold);
+
+        // Once flagged, creations no longer reach a block
+        assert_ok!(EvmDeploymentControl::report_rejected_attempts(
+            RuntimeOrigin::none(),
+            account.clone()
+        ));
+        assert_eq!(
+            CheckDeployment.validate(&account, &batched, &info, 0).map(|_| ()),
+            flagged
+        );
+        assert_eq!(
+            creation.validate_self_contained(&deployer(), &info, 0).map(|r| r.map(|_| ())),
+            Some(flagged)
+        );
+        assert_eq!(
+            creation.pre_dispatch_self_contained(&deployer(), &info, 0),
+            Some(flagged)
+        );
+        assert_eq!(EvmDeploymentControl::rejected_attempts(&account), threshold);
+    });
+}
+
+#[test]
+fn batched_authorizations_scheduled_by_governance_are_open_to_objections() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(EvmDeploymentControl::set_enactment_delay(RuntimeOrigin::root(), 10));
+        let authorize = RuntimeCall::EvmDeploymentControl(
+            pallet_evm_deployment_control::Call::authorize_deployer {
+                deployer: account_of(deployer()),
+            },
+        );
+        let remark = RuntimeCall::System(frame_system::Call::remark { remark: vec![] });
+        assert_ok!(EvmDeploymentControl::schedule_governance_call(
+            RuntimeOrigin::root(),
+            Box::new(batch_all(vec![remark.clone(), batch_all(vec![authorize])]))
+        ));
+        assert_ok!(EvmDeploymentControl::schedule_governance_call(
+            RuntimeOrigin::root(),
+            Box::new(batch_all(vec![remark]))
+        ));
+
+        assert!(pallet_evm_deployment_control::Objections::<Runtime>::contains_key(0));
+        assert!(!pallet_evm_deployment_control::Objections::<Runtime>::contains_key(1));
+    });
+}
+
+#[test]
+fn transactions_signed_for_another_network_are_rejected() {
+    new_test_ext().execute_with(|| {
+        pallet_evm_chain_id::ChainId::<Runtime>::put(4242);
+        let who = AccountId::from([0xaa; 32]);
+        let call = RuntimeCall::EvmDeploymentControl(
+            pallet_evm_deployment_control::Call::authorize_deployer { deployer: who.clone() },
+        );
+        let info = call.get_dispatch_info();
+
+        assert!(CheckNetwork(4242).validate(&who, &call, &info, 0).is_ok());
+        assert_eq!(
+            CheckNetwork(4243).validate(&who, &call, &info, 0),
+            Err(CustomInvalidity::WrongNetwork.into())
+        );
+        assert!(CheckNetwork(4243).pre_dispatch(&who, &call, &info, 0).is_err());
+        // The genesis hash is signed along with the chain id
+        assert_eq!(CheckNetwork(4242).additional_signed(), Ok(System::block_hash(0)));
+    });
+
+    let extensions = Runtime::metadata_ir().extrinsic.signed_extensions;
+    assert!(extensions
+        .iter()
+        .any(|extension| extension.identifier == "CheckNetwork"));
+}
+
+#[test]
+fn genesis_deployers_round_trip_as_readable_addresses() {
+    let alice = AccountId::from([0xaa; 32]);
+    let evm_deployer = H160::repeat_byte(0x22);
+    let spec = serde_json::json!({
+        "authorizedDeployers": [alice.to_ss58check()],
+        "deployers": [["0x2222222222222222222222222222222222222222", "admin", 100]],
+    });
+
+    let config: DeploymentControlGenesisConfig<Runtime> =
+        serde_json::from_value(spec.clone()).unwrap();
+    let alice_address = DeployerAddress::Account(alice.clone());
+    let evm_address = DeployerAddress::Evm(evm_deployer);
+    assert_eq!(config.authorized_deployers, vec![alice_address]);
+    assert_eq!(
+        config.deployers,
+        vec![(evm_address, GenesisDeployerRole::Admin, Some(100))]
+    );
+    assert_eq!(serde_json::to_value(&config).unwrap(), spec);
+
+    let admin = account_of(evm_deployer);
+    sp_io::TestExternalities::new(config.build_storage().unwrap()).execute_with(|| {
+        assert!(EvmDeploymentControl::is_authorized(&alice));
+        assert!(EvmDeploymentControl::is_authorized(&admin));
+        assert!(DeployerAdmins::<Runtime>::contains_key(&admin));
+
+        // The admin role lapses at its expiry
+        System::set_block_number(100);
+        EvmDeploymentControl::on_initialize(100);
+        assert!(!EvmDeploymentControl::is_authorized(&admin));
+        assert!(!DeployerAdmins::<Runtime>::contains_key(&admin));
+        assert!(EvmDeploymentControl::is_authorized(&alice));
+    });
+}
+
+#[test]
+fn genesis_deployers_reject_unreadable_addresses() {
+    for address in ["0x2222", "not an address", "5GrwvaEF"] {
+        let spec = serde_json::json!({ "authorizedDeployers": [address], "deployers": [] });
+        assert!(serde_json::from_value::<DeploymentControlGenesisConfig<Runtime>>(spec).is_err());
+    }
+}
+
+#[test]
+fn root_cannot_set_code_without_the_upgrade_committee() {
+    new_test_ext().execute_with(|| {
+        let set_code = RuntimeCall::System(frame_system::Call::set_code_without_checks {
+            code: vec![1, 2, 3],
+        });
+
+        let result = set_code.dispatch(RuntimeOrigin::root());
+        assert_eq!(
+            result.map_err(|e| e.error),
+            Err(pallet_upgrade_committee::Error::<Runtime>::UpgradeNotEnacted.into())
+        );
+    });
+}
+
+#[test]
+fn council_names_the_upgrade_committee() {
+    new_test_ext().execute_with(|| {
+        let alice = account_of(H160::repeat_byte(0xa1));
+        let bob = account_of(H160::repeat_byte(0xb0));
+        let members = vec![alice.clone(), bob.clone()];
+
+        // Neither sudo nor the deployment control approvers name the committee
+        assert_noop!(
+            UpgradeCommittee::set_committee(RuntimeOrigin::root(), members.clone(), 2),
+            DispatchError::BadOrigin
+        );
+        assert_ok!(EvmDeploymentControl::set_approval_policy(
+            RuntimeOrigin::root(),
+            Some(ApprovalPolicy {
+                approvers: members.clone().try_into().unwrap(),
+                threshold: 1,
+                risk_tier: 0,
+            })
+        ));
+        assert_noop!(
+            UpgradeCommittee::propose_upgrade(RuntimeOrigin::signed(alice.clone()), H256::zero()),
+            pallet_upgrade_committee::Error::<Runtime>::NoCommittee
+        );
+
+        let council =
+            pallet_collective::RawOrigin::<AccountId, CouncilCollective>::Members(2, 3).into();
+        assert_ok!(UpgradeCommittee::set_committee(council, members, 2));
+
+        let code_hash = H256::repeat_byte(0x01);
+        assert_ok!(UpgradeCommittee::propose_upgrade(
+            RuntimeOrigin::signed(alice),
+            code_hash
+        ));
+        assert_ok!(UpgradeCommittee::approve_upgrade(
+            RuntimeOrigin::signed(bob),
+            code_hash
+        ));
+        let pending = pallet_upgrade_committee::PendingUpgrade::<Runtime>::get().unwrap();
+        assert_eq!(pending.enact_at, Some(1 + DAYS));
+    });
+}
+
+#[test]
+fn treasury_receives_its_share_of_fees() {
+    new_test_ext().execute_with(|| {
+        let treasury = Treasury::account_id();
+        let balance = Balances::free_balance(&treasury);
+        let issuance = Balances::total_issuance();
+
+        let fees = <Balances as fungible::Balanced<AccountId>>::issue(1_000 * UNIT);
+        DealWithFees::on_unbalanced(fees);
+
+        // 20% is paid into the treasury and the rest is burned
+        assert_eq!(Balances::free_balance(&treasury), balance + 200 * UNIT);
+        assert_eq!(Balances::total_issuance(), issuance + 200 * UNIT);
+
+        // The council or sudo can send all fees to the treasury
+        assert_ok!(FeeDistribution::set_fee_split(
+            RuntimeOrigin::root(),
+            FeeKind::Fee,
+            FeeSplit {
+                treasury: Percent::from_percent(100),
+                author: Percent::from_percent(0)
+            }
+        ));
+        let fees = <Balances as fungible::Balanced<AccountId>>::issue(1_000 * UNIT);
+        DealWithFees::on_unbalanced(fees);
+        assert_eq!(Balances::free_balance(&treasury), balance + 1_200 * UNIT);
+    });
+}
+
+#[test]
+fn deployment_fees_are_paid_into_the_treasury() {
+    new_test_ext().execute_with(|| {
+        let who = account_of(H160::repeat_byte(0x77));
+        let fee = 10 * UNIT;
+        <Balances as fungible::Mutate<AccountId>>::set_balance(&who, 100 * UNIT);
+        assert_ok!(EvmDeploymentControl::set_deployment_policy(
+            RuntimeOrigin::root(),
+            DeploymentPolicy::PermissionlessWithFee { fee }
+        ));
+        let treasury_balance = Balances::free_balance(Treasury::account_id());
+
+        assert_ok!(EvmDeploymentControl::charge_deployment_fee(&who));
+        assert_eq!(Balances::free_balance(&who), 90 * UNIT);
+        assert_eq!(
+            Balances::free_balance(Treasury::account_id()),
+            treasury_balance + fee
+        );
+    });
+}
+
+#[test]
+fn block_authors_claim_rewards_from_the_pot() {
+    new_test_ext().execute_with(|| {
+        let collator = account_of(H160::repeat_byte(0xc0));
+        <CollatorRewards as pallet_authorship::EventHandler<AccountId, BlockNumber>>::note_author(
+            collator.clone(),
+        );
+        assert_eq!(CollatorRewards::pending_rewards(&collator), CENTIUNIT);
+
+        // Rewards are paid from the pot, which must be funded first
+        assert!(CollatorRewards::claim_rewards(RuntimeOrigin::signed(collator.clone())).is_err());
+        <Balances as fungible::Mutate<AccountId>>::set_balance(
+            &CollatorRewards::pot_account(),
+            UNIT,
+        );
+        assert_ok!(CollatorRewards::claim_rewards(RuntimeOrigin::signed(
+            collator.clone()
+        )));
+        assert_eq!(Balances::free_balance(&collator), CENTIUNIT);
+    });
+}
+
+#[test]
+fn issuance_funds_the_treasury_and_collator_rewards() {
+    new_test_ext().execute_with(|| {
+        let (treasury, pot) = (Treasury::account_id(), CollatorRewards::pot_account());
+        let treasury_balance = Balances::free_balance(&treasury);
+        let pot_balance = Balances::free_balance(&pot);
+
+        Issuance::on_initialize(System::block_number());
+
+        // Half of the default issuance goes to the treasury, the other half covers the
+        // default collator reward
+        assert_eq!(
+            Balances::free_balance(&treasury),
+            treasury_balance + CENTIUNIT
+        );
+        assert_eq!(Balances::free_balance(&pot), pot_balance + CENTIUNIT);
+        assert_eq!(CollatorRewards::reward().per_block, CENTIUNIT);
+    });
+}
+
+#[test]
+fn delegators_share_the_rewards_of_their_candidate() {
+    new_test_ext().execute_with(|| {
+        let candidate = account_of(H160::repeat_byte(0xc1));
+        let delegator = account_of(H160::repeat_byte(0xd1));
+        pallet_collator_selection::CandidateList::<Runtime>::put(
+            BoundedVec::try_from(vec![pallet_collator_selection::CandidateInfo {
+                who: candidate.clone(),
+                deposit: 10 * UNIT,
+            }])
+            .unwrap(),
+        );
+        <Balances as fungible::Mutate<AccountId>>::set_balance(&delegator, 100 * UNIT);
+
+        assert_ok!(Delegation::delegate(
+            RuntimeOrigin::signed(delegator.clone()),
+            candidate.clone(),
+            10 * UNIT
+        ));
+        <CollatorRewards as pallet_authorship::EventHandler<AccountId, BlockNumber>>::note_author(
+            candidate.clone(),
+        );
+
+        // Half of the backing is delegated, so is half of the reward
+        assert_eq!(CollatorRewards::pending_rewards(&candidate), CENTIUNIT / 2);
+        assert_eq!(CollatorRewards::pending_rewards(&delegator), CENTIUNIT / 2);
+    });
+}
+
+#[test]
+fn collators_authoring_no_block_are_slashed_into_the_treasury() {
+    new_test_ext().execute_with(|| {
+        type Sessions = <Runtime as pallet_session::Config>::SessionManager;
+        let idle = account_of(H160::repeat_byte(0xc1));
+        let active = account_of(H160::repeat_byte(0xc2));
+        let delegator = account_of(H160::repeat_byte(0xd1));
+        <Balances as fungible::Mutate<AccountId>>::set_balance(&idle, 100 * UNIT);
+        <Balances as fungible::Mutate<AccountId>>::set_balance(&delegator, 100 * UNIT);
+        assert_ok!(Balances::reserve(&idle, 10 * UNIT));
+        pallet_collator_selection::CandidateList::<Runtime>::put(
+            BoundedVec::try_from(vec![pallet_collator_selection::CandidateInfo {
+                who: idle.clone(),
+                deposit: 10 * UNIT,
+            }])
+            .unwrap(),
+        );
+        assert_ok!(Delegation::delegate(
+            RuntimeOrigin::signed(delegator.clone()),
+            idle.clone(),
+            10 * UNIT
+        ));
+        pallet_session::Validators::<Runtime>::put(vec![idle.clone(), active.clone()]);
+        let treasury = Treasury::account_id();
+        let treasury_balance = Balances::free_balance(&treasury);
+
+        Sessions::start_session(1);
+        <CollatorOffences as pallet_authorship::EventHandler<AccountId, BlockNumber>>::note_author(
+            active.clone(),
+        );
+        Sessions::end_session(1);
+
+        // A hundredth of the bond and of the delegation of the idle collator
+        let left = 10 * UNIT - UNIT / 10;
+        assert_eq!(
+            pallet_collator_selection::CandidateList::<Runtime>::get()[0].deposit,
+            left
+        );
+        assert_eq!(Balances::reserved_balance(&idle), left);
+        assert_eq!(Delegation::delegated(&idle, &delegator), left);
+        assert_eq!(
+            Balances::free_balance(&treasury),
+            treasury_balance + UNIT / 5
+        );
+    });
+}
+
+#[test]
+fn staking_precompile_delegates_and_claims_for_evm_accounts() {
+    new_test_ext().execute_with(|| {
+        let candidate = account_of(H160::repeat_byte(0xc2));
+        let caller = H160::repeat_byte(0xe1);
+        let delegator = account_of(caller);
+        pallet_collator_selection::CandidateList::<Runtime>::put(
+            BoundedVec::try_from(vec![pallet_collator_selection::CandidateInfo {
+                who: candidate.clone(),
+                deposit: 10 * UNIT,
+            }])
+            .unwrap(),
+        );
+        <Balances as fungible::Mutate<AccountId>>::set_balance(&delegator, 100 * UNIT);
+        <Balances as fungible::Mutate<AccountId>>::set_balance(
+            &CollatorRewards::pot_account(),
+            UNIT,
+        );
+
+        let call = |input: Vec<u8>| {
+            <Runtime as pallet_evm::Config>::Runner::call(
+                caller,
+                H160::from_low_u64_be(2050),
+                input,
+                U256::zero(),
+                1_000_000,
+                None,
+                None,
+                None,
+                vec![],
+                false,
+                false,
+                None,
+                None,
+                <Runtime as pallet_evm::Config>::config(),
+            )
+            .unwrap_or_else(|_| panic!("call failed"))
+        };
+        let candidate_word: [u8; 32] = candidate.clone().into();
+        let mut amount = [0u8; 32];
+        U256::from(10 * UNIT).to_big_endian(&mut amount);
+
+        // delegate(candidate, 10 UNIT), refused through a DELEGATECALL
+        let mut input = vec![0x8e, 0xb7, 0xcb, 0x38];
+        input.extend_from_slice(&candidate_word);
+        input.extend_from_slice(&amount);
+        let staking = H160::from_low_u64_be(2050);
+        assert!(matches!(
+            call_delegated(caller, staking, input.clone()),
+            ExitReason::Revert(_)
+        ));
+        assert_eq!(Delegation::delegated(&candidate, &delegator), 0);
+        assert!(matches!(call(input).exit_reason, ExitReason::Succeed(_)));
+        assert_eq!(Delegation::delegated(&candidate, &delegator), 10 * UNIT);
+
+        // delegated(candidate, caller)
+        let mut input = vec![0xef, 0x22, 0x9b, 0x3a];
+        input.extend_from_slice(&candidate_word);
+        input.extend_from_slice(H256::from(caller).as_bytes());
+        assert_eq!(call(input).value, amount.to_vec());
+
+        <CollatorRewards as pallet_authorship::EventHandler<AccountId, BlockNumber>>::note_author(
+            candidate.clone(),
+        );
+        let balance = Balances::free_balance(&delegator);
+
+        // claimRewards()
+        assert!(matches!(
+            call(vec![0x37, 0x25, 0x00, 0xab]).exit_reason,
+            ExitReason::Succeed(_)
+        ));
+        assert_eq!(CollatorRewards::pending_rewards(&delegator), 0);
+        assert!(Balances::free_balance(&delegator) > balance);
+    });
+}
+
+#[test]
+fn vesting_precompile_unlocks_vested_tokens_of_evm_accounts() {
+    new_test_ext().execute_with(|| {
+        let caller = H160::repeat_byte(0xe2);
+        let who = account_of(caller);
+        <Balances as fungible::Mutate<AccountId>>::set_balance(&who, 100 * UNIT);
+        assert_ok!(
+            <Vesting as VestingSchedule<AccountId>>::add_vesting_schedule(&who, 10 * UNIT, UNIT, 1)
+        );
+
+        let call = |input: Vec<u8>| {
+            <Runtime as pallet_evm::Config>::Runner::call(
+                caller,
+                H160::from_low_u64_be(2051),
+                input,
+                U256::zero(),
+                1_000_000,
+                None,
+                None,
+                None,
+                vec![],
+                false,
+                false,
+                None,
+                None,
+                <Runtime as pallet_evm::Config>::config(),
+            )
+            .unwrap_or_else(|_| panic!("call failed"))
+        };
+        let locked = || pallet_balances::Locks::<Runtime>::get(&who)[0].amount;
+        assert_eq!(locked(), 10 * UNIT);
+
+        System::set_block_number(5);
+        // vestingBalance(caller)
+        let mut input = vec![0x19, 0x23, 0x99, 0xd1];
+        input.extend_from_slice(H256::from(caller).as_bytes());
+        let mut expected = [0u8; 32];
+        U256::from(6 * UNIT).to_big_endian(&mut expected);
+        assert_eq!(call(input).value, expected.to_vec());
+
+        // vest(), refused through a DELEGATECALL
+        let vesting = H160::from_low_u64_be(2051);
+        assert!(matches!(
+            call_delegated(caller, vesting, vec![0x45, 0x8e, 0xfd, 0xe3]),
+            ExitReason::Revert(_)
+        ));
+        assert_eq!(locked(), 10 * UNIT);
+        assert!(matches!(
+            call(vec![0x45, 0x8e, 0xfd, 0xe3]).exit_reason,
+            ExitReason::Succeed(_)
+        ));
+        assert_eq!(locked(), 6 * UNIT);
+    });
+}
+
+#[test]
+fn claim_sets_are_published_by_governance_and_claims_are_not_filtered() {
+    new_test_ext().execute_with(|| {
+        let root = H256::repeat_byte(1);
+        let publish = RuntimeCall::Claims(pallet_claims::Call::set_claim_set {
+            set_id: 0,
+            root,
+            total: 1_000 * UNIT,
+        });
+        assert_eq!(
+            publish
+                .clone()
+                .dispatch(RuntimeOrigin::signed(AccountId::from([1u8; 32])))
+                .map_err(|e| e.error),
+            Err(DispatchError::BadOrigin)
+        );
+        assert_ok!(publish.dispatch(RuntimeOrigin::root()));
+        assert_eq!(
+            pallet_claims::ClaimSets::<Runtime>::get(0).map(|set| set.remaining),
+            Some(1_000 * UNIT)
+        );
+
+        // Claims are unsigned, so the base filter must let them through
+        let claim = RuntimeCall::Claims(pallet_claims::Call::claim {
+            set_id: 0,
+            dest: AccountId::from([2u8; 32]),
+            amount: UNIT,
+            proof: BoundedVec::new(),
+            signature: pallet_claims::EcdsaSignature([0; 65]),
+        });
+        assert!(<Runtime as frame_system::Config>::BaseCallFilter::contains(
+            &claim
+        ));
+    });
+}
+
+#[test]
+fn erc721_precompile_transfers_nfts_of_evm_accounts() {
+    new_test_ext().execute_with(|| {
+        let (seller, market, buyer) = (
+            H160::repeat_byte(0xa1),
+            H160::repeat_byte(0xa2),
+            H160::repeat_byte(0xa3),
+        );
+        let issuer = account_of(seller);
+        <Balances as fungible::Mutate<AccountId>>::set_balance(&issuer, 100 * UNIT);
+        <Balances as fungible::Mutate<AccountId>>::set_balance(&account_of(market), 100 * UNIT);
+
+        // Governance issues collection 0 and its owner mints item 7 to themselves
+        assert_ok!(Nfts::force_create(
+            RuntimeOrigin::root(),
+            issuer.clone().into(),
+            pallet_nfts::CollectionConfig {
+                settings: pallet_nfts::CollectionSettings::all_enabled(),
+                max_supply: None,
+                mint_settings: Default::default(),
+            },
+        ));
+        assert_ok!(Nfts::mint(
+            RuntimeOrigin::signed(issuer.clone()),
+            0,
+            7,
+            issuer.clone().into(),
+            None
+        ));
+
+        let collection = crate::precompiles::collection_address(0);
+        let call_at = |target: H160, caller: H160, input: Vec<u8>| {
+            <Runtime as pallet_evm::Config>::Runner::call(
+                caller,
+                target,
+                input,
+                U256::zero(),
+                1_000_000,
+                None,
+                None,
+                None,
+                vec![],
+                false,
+                false,
+                None,
+                None,
+                <Runtime as pallet_evm::Config>::config(),
+            )
+            .unwrap_or_else(|_| panic!("call failed"))
+        };
+        let call = |caller: H160, input: Vec<u8>| call_at(collection, caller, input);
+        let word = |address: H160| H256::from(address).as_bytes().to_vec();
+        let token = H256::from_low_u64_be(7).as_bytes().to_vec();
+
+        // approve(market, 7) by the seller
+        let input = [vec![0x09, 0x5e, 0xa7, 0xb3], word(market), token.clone()].concat();
+        assert!(matches!(
+            call(seller, input).exit_reason,
+            ExitReason::Succeed(_)
+        ));
+
+        // transferFrom(seller, buyer, 7) by the approved market
+        let transfer = [
+            vec![0x23, 0xb8, 0x72, 0xdd],
+            word(seller),
+            word(buyer),
+            token.clone(),
+        ]
+        .concat();
+        let info = call(market, transfer.clone());
+        assert!(matches!(info.exit_reason, ExitReason::Succeed(_)));
+        assert_eq!(info.logs.len(), 1);
+        assert_eq!(
+            <Nfts as nonfungibles_v2::Inspect<AccountId>>::owner(&0, &7),
+            Some(account_of(buyer))
+        );
+
+        // ownerOf(7) and balanceOf(buyer)
+        let owner_of = [vec![0x63, 0x52, 0x21, 0x1e], token.clone()].concat();
+        assert_eq!(call(buyer, owner_of).value, word(buyer));
+        let balance_of = [vec![0x70, 0xa0, 0x82, 0x31], word(buyer)].concat();
+        assert_eq!(
+            call(buyer, balance_of).value,
+            H256::from_low_u64_be(1).as_bytes().to_vec()
+        );
+
+        // The approval was consumed by the transfer
+        assert!(matches!(
+            call(market, transfer).exit_reason,
+            ExitReason::Revert(_)
+        ));
+
+        // Approving through a contract would act on behalf of the buyer
+        let approve = [vec![0x09, 0x5e, 0xa7, 0xb3], word(market), token.clone()].concat();
+        let proxy = delegating_proxy(collection);
+        assert!(matches!(
+            call_at(proxy, buyer, approve).exit_reason,
+            ExitReason::Revert(_)
+        ));
+        let transfer_back = [
+            vec![0x23, 0xb8, 0x72, 0xdd],
+            word(buyer),
+            word(seller),
+            token,
+        ]
+        .concat();
+        assert!(matches!(
+            call(market, transfer_back).exit_reason,
+            ExitReason::Revert(_)
+        ));
+
+        // The buyer's account is now known by its address
+        assert_eq!(
+            crate::precompiles::seen_address::<Runtime>(&account_of(buyer)),
+            Some(buyer)
+        );
+    });
+}
+
+#[test]
+fn address_mapping_api_reverses_known_addresses_only() {
+    use address_mapping_runtime_api::runtime_decl_for_address_mapping_api::AddressMappingApiV1;
+
+    new_test_ext().execute_with(|| {
+        let address = H160::repeat_byte(0xe1);
+        let account =
+            <Runtime as AddressMappingApiV1<crate::Block, AccountId>>::account_of(address);
+        assert_eq!(account, account_of(address));
+
+        // Hashed addresses cannot be recovered from the account
+        assert_eq!(
+            <Runtime as AddressMappingApiV1<crate::Block, AccountId>>::address_of(account),
+            None
+        );
+    });
+}
+
+#[test]
+fn gas_weight_api_inverts_the_gas_weight_mapping() {
+    use frame_support::{dispatch::DispatchClass, traits::Get, weights::Weight};
+    use gas_weight_runtime_api::runtime_decl_for_gas_weight_api::GasWeightApiV1;
+    use pallet_evm::GasWeightMapping;
+
+    use crate::configs::RuntimeBlockWeights;
+
+    new_test_ext().execute_with(|| {
+        let weight_of = <Runtime as GasWeightApiV1<crate::Block>>::gas_to_weight;
+        let gas_of = <Runtime as GasWeightApiV1<crate::Block>>::weight_to_gas;
+
+        // The weight of a transaction is the weight its gas limit maps to
+        assert_eq!(
+            weight_of(1_000_000),
+            <Runtime as pallet_evm::Config>::GasWeightMapping::gas_to_weight(1_000_000, false)
+        );
+
+        // The largest gas limit fitting in a weight, bound by either component
+        let max_extrinsic =
+            RuntimeBlockWeights::get().get(DispatchClass::Normal).max_extrinsic.unwrap();
+        let execution_bound = Weight::from_parts(max_extrinsic.ref_time(), u64::MAX);
+        let proof_bound = Weight::from_parts(u64::MAX / 2, 10_000);
+        for weight in [max_extrinsic, execution_bound, proof_bound] {
+            let gas = gas_of(weight);
+            assert!(weight_of(gas).all_lte(weight));
+            assert!(!weight_of(gas + 1).all_lte(weight));
+        }
+        assert!(gas_of(proof_bound) < gas_of(execution_bound));
+    });
+}
+
+#[test]
+fn dex_precompile_pools_and_swaps_assets_of_evm_accounts() {
+    new_test_ext().execute_with(|| {
+        let (provider, trader) = (H160::repeat_byte(0xd1), H160::repeat_byte(0xd2));
+        let issuer = account_of(provider);
+        <Balances as fungible::Mutate<AccountId>>::set_balance(&issuer, 1_000 * UNIT);
+        <Balances as fungible::Mutate<AccountId>>::set_balance(&account_of(trader), 100 * UNIT);
+        assert_ok!(Assets::create(
+            RuntimeOrigin::signed(issuer.clone()),
+            1.into(),
+            issuer.clone().into(),
+            1
+        ));
+        assert_ok!(Assets::mint(
+            RuntimeOrigin::signed(issuer.clone()),
+            1.into(),
+            issuer.clone().into(),
+            1_000 * UNIT
+        ));
+
+        let call = |caller: H160, input: Vec<u8>| {
+            <Runtime as pallet_evm::Config>::Runner::call(
+                caller,
+                H160::from_low_u64_be(2052),
+                input,
+                U256::zero(),
+                1_000_000,
+                None,
+                None,
+                None,
+                vec![],
+                false,
+                false,
+                None,
+                None,
+                <Runtime as pallet_evm::Config>::config(),
+            )
+            .unwrap_or_else(|_| panic!("call failed"))
+        };
+        let word = |value: u128| {
+            let mut word = [0u8; 32];
+            U256::from(value).to_big_endian(&mut word);
+            word.to_vec()
+        };
+        let native = vec![0xff; 32];
+
+        // createPool(native, 1) and addLiquidity(native, 1, 100 UNIT, 100 UNIT, 0, 0)
+        let input = [vec![0x74, 0xa1, 0x10, 0xc2], native.clone(), word(1)].concat();
+        assert!(matches!(
+            call(provider, input).exit_reason,
+            ExitReason::Succeed(_)
+        ));
+        let input = [
+            vec![0x62, 0xc4, 0xc5, 0xfb],
+            native.clone(),
+            word(1),
+            word(100 * UNIT),
+            word(100 * UNIT),
+            word(0),
+            word(0),
+        ]
+        .concat();
+        assert!(matches!(
+            call(provider, input).exit_reason,
+            ExitReason::Succeed(_)
+        ));
+
+        // quoteExactTokensForTokens(native, 1, UNIT)
+        let input = [
+            vec![0x01, 0x0e, 0x6e, 0x36],
+            native.clone(),
+            word(1),
+            word(UNIT),
+        ]
+        .concat();
+        let quote = call(trader, input).value;
+        assert!(quote > word(0) && quote < word(UNIT));
+
+        // swapExactTokensForTokens([native, 1], UNIT, 1, trader)
+        let input = [
+            vec![0x3f, 0xd5, 0x50, 0x0c],
+            word(128),
+            word(UNIT),
+            word(1),
+            H256::from(trader).as_bytes().to_vec(),
+            word(2),
+            native,
+            word(1),
+        ]
+        .concat();
+        let info = call(trader, input);
+        assert!(matches!(info.exit_reason, ExitReason::Succeed(_)));
+        assert_eq!(info.value, quote);
+        assert_eq!(word(Assets::balance(1, account_of(trader))), quote);
+    });
+}
+
+#[test]
+fn dex_precompile_refuses_delegatecalls() {
+    new_test_ext().execute_with(|| {
+        let trader = H160::repeat_byte(0xd2);
+        let issuer = account_of(trader);
+        <Balances as fungible::Mutate<AccountId>>::set_balance(&issuer, 1_000 * UNIT);
+        assert_ok!(Assets::create(
+            RuntimeOrigin::signed(issuer.clone()),
+            1.into(),
+            issuer.into(),
+            1
+        ));
+        let dex = H160::from_low_u64_be(2052);
+        let proxy = delegating_proxy(dex);
+
+        let call = |target: H160, input: Vec<u8>| {
+            <Runtime as pallet_evm::Config>::Runner::call(
+                trader,
+                target,
+                input,
+                U256::zero(),
+                1_000_000,
+                None,
+                None,
+                None,
+                vec![],
+                false,
+                false,
+                None,
+                None,
+                <Runtime as pallet_evm::Config>::config(),
+            )
+            .unwrap_or_else(|_| panic!("call failed"))
+            .exit_reason
+        };
+        let mut asset = [0u8; 32];
+        U256::one().to_big_endian(&mut asset);
+
+        // createPool(native, 1) through a contract would act on behalf of the trader
+        let input = [&[0x74, 0xa1, 0x10, 0xc2][..], &[0xff; 32], &asset].concat();
+        assert!(matches!(call(proxy, input.clone()), ExitReason::Revert(_)));
+        assert!(matches!(call(dex, input), ExitReason::Succeed(_)));
+    });
+}
+
+#[test]
+fn deployment_policy_precompile_reports_policy_to_evm_tooling() {
+    new_test_ext().execute_with(|| {
+        let (tool, authorized) = (H160::repeat_byte(0xd3), deployer());
+        <Balances as fungible::Mutate<AccountId>>::set_balance(&account_of(tool), UNIT);
+        assert_ok!(EvmDeploymentControl::authorize_deployer(
+            RuntimeOrigin::root(),
+            account_of(authorized)
+        ));
+
+        let call = |input: Vec<u8>| {
+            <Runtime as pallet_evm::Config>::Runner::call(
+                tool,
+                H160::from_low_u64_be(2053),
+                input,
+                U256::zero(),
+                1_000_000,
+                None,
+                None,
+                None,
+                vec![],
+                false,
+                false,
+                None,
+                None,
+                <Runtime as pallet_evm::Config>::config(),
+            )
+            .unwrap_or_else(|_| panic!("call failed"))
+            .value
+        };
+        let word = |value: u128| {
+            let mut word = [0u8; 32];
+            U256::from(value).to_big_endian(&mut word);
+            word.to_vec()
+        };
+        let unlimited = vec![0xff; 32];
+        let address = |address: H160| H256::from(address).as_bytes().to_vec();
+
+        // Unset limits read as type(uint256).max
+        assert_eq!(call(vec![0xb1, 0x6c, 0x5d, 0x92]), unlimited);
+        assert_eq!(
+            call([vec![0xd8, 0x72, 0xe4, 0x13], address(authorized)].concat()),
+            unlimited
+        );
+
+        assert_ok!(EvmDeploymentControl::set_deployment_policy(
+            RuntimeOrigin::root(),
+            DeploymentPolicy::Rollout { admitted: Percent::from_percent(25) }
+        ));
+        assert_ok!(EvmDeploymentControl::set_deployment_quota(RuntimeOrigin::root(), Some(3)));
+        assert_ok!(EvmDeploymentControl::set_deployment_windows(
+            RuntimeOrigin::root(),
+            BoundedVec::truncate_from(vec![pallet_evm_deployment_control::DeploymentWindow {
+                period: 100,
+                offset: 0,
+                duration: 10,
+            }])
+        ));
+
+        // deploymentPolicy(), deploymentQuota() and deploymentWindows()
+        assert_eq!(call(vec![0x26, 0xb4, 0x22, 0xd4]), [word(3), word(25)].concat());
+        assert_eq!(call(vec![0xd0, 0x0a, 0x15, 0x06]), [word(3), word(DAYS.into())].concat());
+        assert_eq!(
+            call(vec![0xb7, 0x07, 0xeb, 0x9b]),
+            [word(32), word(1), word(100), word(0), word(10)].concat()
+        );
+
+        // canDeploy(authorized) within and outside of the window
+        let can_deploy = [vec![0x56, 0x9e, 0x0d, 0x29], address(authorized)].concat();
+        assert_eq!(call(can_deploy.clone()), [word(1), word(0)].concat());
+        System::set_block_number(50);
+        assert_eq!(call(can_deploy), [word(0), word(1)].concat());
+    });
+}
+
+/// Create asset 1 with a pool of a hundred units against the native token, and mint ten units
+/// to `holder`
+fn create_fee_asset_pool(holder: &AccountId) {
+    let provider = account_of(H160::repeat_byte(0xe1));
+    <Balances as fungible::Mutate<AccountId>>::set_balance(&provider, 1_000 * UNIT);
+    assert_ok!(Assets::force_create(
+        RuntimeOrigin::root(),
+        1.into(),
+        provider.clone().into(),
+        true,
+        1
+    ));
+    assert_ok!(Assets::mint(
+        RuntimeOrigin::signed(provider.clone()),
+        1.into(),
+        provider.clone().into(),
+        1_000 * UNIT
+    ));
+    assert_ok!(Assets::mint(
+        RuntimeOrigin::signed(provider.clone()),
+        1.into(),
+        holder.clone().into(),
+        10 * UNIT
+    ));
+    let pair = (
+        Box::new(NativeOrWithId::Native),
+        Box::new(NativeOrWithId::WithId(1)),
+    );
+    assert_ok!(AssetConversion::create_pool(
+        RuntimeOrigin::signed(provider.clone()),
+        pair.0.clone(),
+        pair.1.clone()
+    ));
+    assert_ok!(AssetConversion::add_liquidity(
+        RuntimeOrigin::signed(provider.clone()),
+        pair.0,
+        pair.1,
+        100 * UNIT,
+        100 * UNIT,
+        0,
+        0,
+        provider
+    ));
+}
+
+#[test]
+fn evm_fees_are_swapped_from_registered_fee_assets() {
+    new_test_ext().execute_with(|| {
+        let payer = H160::repeat_byte(0xe2);
+        create_fee_asset_pool(&account_of(payer));
+        // A transaction paying at most a centiunit in fees
+        pallet_base_fee::BaseFeePerGas::<Runtime>::put(U256::from(CENTIUNIT / 100_000));
+        let transfer = transact(Some(H160::repeat_byte(0xe3)), vec![]);
+        let info = transfer.get_dispatch_info();
+
+        // Unregistered assets are not swapped
+        assert!(matches!(
+            transfer.validate_self_contained(&payer, &info, 0),
+            Some(Err(_))
+        ));
+        assert!(matches!(
+            transfer.pre_dispatch_self_contained(&payer, &info, 0),
+            Some(Err(_))
+        ));
+
+        // Nor are assets the pool prices above their registered price
+        let max_price = FixedU128::from_rational(1, 2);
+        assert_ok!(FeeAssets::register_fee_asset(RuntimeOrigin::root(), 1, max_price));
+        assert!(matches!(
+            transfer.validate_self_contained(&payer, &info, 0),
+            Some(Err(_))
+        ));
+
+        // Validation only simulates the swap
+        assert_ok!(FeeAssets::set_max_price(RuntimeOrigin::root(), 1, FixedU128::from_u32(2)));
+        assert!(matches!(
+            transfer.validate_self_contained(&payer, &info, 0),
+            Some(Ok(_))
+        ));
+        assert_eq!(Assets::balance(1, account_of(payer)), 10 * UNIT);
+        assert_eq!(Balances::free_balance(account_of(payer)), 0);
+
+        // The fee alone is swapped once the transaction is included
+        assert_eq!(
+            transfer.pre_dispatch_self_contained(&payer, &info, 0),
+            Some(Ok(()))
+        );
+        let swapped = 10 * UNIT - Assets::balance(1, account_of(payer));
+        assert!(swapped > 0 && swapped < 2 * (CENTIUNIT + EXISTENTIAL_DEPOSIT));
+        assert_ok!(transfer.apply_self_contained(payer).unwrap());
+        assert!(Balances::free_balance(account_of(payer)) > 0);
+    });
+}
+
+#[test]
+fn substrate_fees_are_paid_in_registered_fee_assets_and_distributed() {
+    new_test_ext().execute_with(|| {
+        let payer = account_of(H160::repeat_byte(0xe2));
+        create_fee_asset_pool(&payer);
+        let (native, asset) = (NativeOrWithId::Native, NativeOrWithId::WithId(1));
+        let quote = || {
+            RegisteredFeeAssets::quote_price_tokens_for_exact_tokens(
+                asset.clone(),
+                native.clone(),
+                CENTIUNIT,
+                true,
+            )
+        };
+
+        // Unregistered assets, and assets priced above their registered price, are not quoted
+        assert_eq!(quote(), None);
+        let max_price = FixedU128::from_rational(1, 2);
+        assert_ok!(FeeAssets::register_fee_asset(RuntimeOrigin::root(), 1, max_price));
+        assert_eq!(quote(), None);
+        assert_ok!(FeeAssets::set_max_price(RuntimeOrigin::root(), 1, FixedU128::from_u32(2)));
+        assert!(quote().is_some());
+
+        // Fees swapped to the native token are split like native ones
+        let treasury = Treasury::account_id();
+        let issuance = Balances::total_issuance();
+        let treasury_balance = Balances::free_balance(&treasury);
+        let fee = <NativeAndAssets as fungibles::Balanced<AccountId>>::issue(native, 10 * UNIT);
+        DealWithAssetFees::on_unbalanced(fee);
+        // 20% is paid into the treasury and the rest is burned
+        assert_eq!(Balances::free_balance(&treasury), treasury_balance + 2 * UNIT);
+        assert_eq!(Balances::total_issuance(), issuance + 2 * UNIT);
+    });
+}
+
+#[test]
+fn sponsored_calls_are_paid_by_the_pool() {
+    new_test_ext().execute_with(|| {
+        let (dapp, user, contract) = (
+            H160::repeat_byte(0xf1),
+            H160::repeat_byte(0xf2),
+            H160::repeat_byte(0xf3),
+        );
+        <Balances as fungible::Mutate<AccountId>>::set_balance(&account_of(dapp), 100 * UNIT);
+        EvmDeploymentControl::note_contract_created(dapp, contract);
+        assert_ok!(FeeSponsorship::create_pool(
+            RuntimeOrigin::signed(account_of(dapp)),
+            UNIT,
+            10 * UNIT
+        ));
+        assert_ok!(FeeSponsorship::fund_pool(
+            RuntimeOrigin::signed(account_of(dapp)),
+            0,
+            50 * UNIT
+        ));
+        assert_ok!(FeeSponsorship::sponsor_contract(
+            RuntimeOrigin::signed(account_of(dapp)),
+            0,
+            contract
+        ));
+        assert_ok!(FeeSponsorship::allow_user(
+            RuntimeOrigin::signed(account_of(dapp)),
+            0,
+            user
+        ));
+
+        // Validation only simulates the advance
+        let pool = pallet_fee_sponsorship::Pallet::<Runtime>::pool_account(0);
+        let call = transact(Some(contract), vec![]);
+        let info = call.get_dispatch_info();
+        assert!(matches!(call.validate_self_contained(&user, &info, 0), Some(Ok(_))));
+        assert_eq!(Balances::free_balance(&pool), 50 * UNIT);
+        assert_eq!(Balances::free_balance(account_of(user)), 0);
+        assert!(pallet_fee_sponsorship::PendingAdvance::<Runtime>::get().is_none());
+
+        // The runtime advances the base fee before applying the transaction
+        let max_fee: Balance = 21_000 * 1_000_000_000;
+        assert!(FeeSponsorship::advance(user, &contract, max_fee));
+        let info = <Runtime as pallet_evm::Config>::Runner::call(
+            user,
+            contract,
+            vec![],
+            U256::zero(),
+            21_000,
+            Some(U256::from(1_000_000_000u64)),
+            None,
+            None,
+            vec![],
+            true,
+            true,
+            None,
+            None,
+            <Runtime as pallet_evm::Config>::config(),
+        )
+        .unwrap_or_else(|_| panic!("call failed"));
+        assert!(matches!(info.exit_reason, ExitReason::Succeed(_)));
+
+        // The sender keeps only the existential deposit advanced with the fee
+        let paid = 50 * UNIT - Balances::free_balance(&pool);
+        assert_eq!(
+            Balances::free_balance(account_of(user)),
+            EXISTENTIAL_DEPOSIT
+        );
+        assert!(paid > EXISTENTIAL_DEPOSIT && paid <= EXISTENTIAL_DEPOSIT + max_fee);
+        assert!(pallet_fee_sponsorship::PendingAdvance::<Runtime>::get().is_none());
+    });
+}
+
+#[test]
+fn custom_runtime_apis_are_advertised_at_their_declared_version() {
+    use address_mapping_runtime_api::AddressMappingApi;
+    use build_info_runtime_api::BuildInfoApi;
+    use gas_weight_runtime_api::GasWeightApi;
+    use pallet_audit_anchors_runtime_api::AuditAnchorsApi;
+    use pallet_collator_rewards_runtime_api::CollatorRewardsApi;
+    use pallet_contract_dependencies_runtime_api::ContractDependenciesApi;
+    use pallet_deployer_reputation_runtime_api::DeployerReputationApi;
+    use pallet_evm_deployment_control_runtime_api::DeploymentControlApi;
+    use pallet_outbound_commitments_runtime_api::OutboundCommitmentsApi;
+    use session_keys_runtime_api::SessionKeysApi;
+    use sp_api::RuntimeApiInfo;
+
+    use crate::{Block, VERSION};
+
+    // Light clients only call methods of the version listed in the runtime version, so an
+    // implementation lagging behind its declaration hides the newer methods from them
+    let declared = [
+        (
+            <dyn DeploymentControlApi<Block, AccountId, BlockNumber>>::ID,
+            <dyn DeploymentControlApi<Block, AccountId, BlockNumber>>::VERSION,
+        ),
+        (
+            <dyn DeployerReputationApi<Block, AccountId, BlockNumber>>::ID,
+            <dyn DeployerReputationApi<Block, AccountId, BlockNumber>>::VERSION,
+        ),
+        (
+            <dyn AuditAnchorsApi<Block, AccountId, BlockNumber>>::ID,
+            <dyn AuditAnchorsApi<Block, AccountId, BlockNumber>>::VERSION,
+        ),
+        (
+            <dyn CollatorRewardsApi<Block, AccountId, Balance>>::ID,
+            <dyn CollatorRewardsApi<Block, AccountId, Balance>>::VERSION,
+        ),
+        (
+            <dyn SessionKeysApi<Block, AccountId>>::ID,
+            <dyn SessionKeysApi<Block, AccountId>>::VERSION,
+        ),
+        (<dyn BuildInfoApi<Block>>::ID, <dyn BuildInfoApi<Block>>::VERSION),
+        (<dyn GasWeightApi<Block>>::ID, <dyn GasWeightApi<Block>>::VERSION),
+        (
+            <dyn ContractDependenciesApi<Block>>::ID,
+            <dyn ContractDependenciesApi<Block>>::VERSION,
+        ),
+        (
+            <dyn OutboundCommitmentsApi<Block>>::ID,
+            <dyn OutboundCommitmentsApi<Block>>::VERSION,
+        ),
+        (
+            <dyn AddressMappingApi<Block, AccountId>>::ID,
+            <dyn AddressMappingApi<Block, AccountId>>::VERSION,
+        ),
+    ];
+    for (id, version) in declared {
+        assert_eq!(VERSION.api_version(&id), Some(version));
+    }
+}
+
+#[test]
+fn deployment_control_api_versions_end_with_the_declared_version() {
+    use pallet_evm_deployment_control_runtime_api::{versions, DeploymentControlApi};
+    use sp_api::RuntimeApiInfo;
+
+    // Nodes gate each method on these versions, the newest method bumps the declaration
+    assert_eq!(
+        <dyn DeploymentControlApi<crate::Block, AccountId, BlockNumber>>::VERSION,
+        versions::DEPLOYMENT_PREREQUISITES
+    );
+}
+
+/// `DeploymentControlApi` as compiled into nodes built against versions 1 and 2
+mod deployment_control_api_v2 {
+    use parity_scale_codec::{Codec, Decode};
+    use sp_core::{H160, H256, U256};
+
+    #[derive(Decode, Debug, PartialEq)]
+    pub struct ContractInfo<BlockNumber> {
+        pub code_size: u64,
+        pub code_hash: H256,
+        pub storage_entries: u64,
+        pub balance: U256,
+        pub nonce: U256,
+        pub deployer: Option<H160>,
+        pub deployed_at: Option<BlockNumber>,
+    }
+
+    #[derive(Decode, Debug, PartialEq)]
+    pub enum FactoryStatus {
+        Active,
+        Suspended,
+    }
+
+    #[derive(Decode, Debug, PartialEq)]
+    pub struct FactoryEntry<AccountId, BlockNumber> {
+        pub address: H160,
+        pub admin: AccountId,
+        pub children: u32,
+        pub status: FactoryStatus,
+        pub registered_at: BlockNumber,
+    }
+
+    sp_api::decl_runtime_apis! {
+        #[api_version(2)]
+        pub trait DeploymentControlApi<AccountId, BlockNumber>
+        where
+            AccountId: Codec,
+            BlockNumber: Codec,
+        {
+            fn contract_info(address: H160) -> Option<ContractInfo<BlockNumber>>;
+
+            #[api_version(2)]
+            fn list_factories(
+                start_after: Option<H160>,
+                limit: u32,
+            ) -> Vec<FactoryEntry<AccountId, BlockNumber>>;
+        }
+    }
+}
+
+#[test]
+fn older_deployment_control_api_declarations_decode_current_answers() {
+    use deployment_control_api_v2 as v2;
+    use pallet_evm_deployment_control_runtime_api::{
+        ContractInfo, DeploymentControlApi, FactoryEntry, FactoryStatus,
+    };
+    use parity_scale_codec::{DecodeAll, Encode};
+    use sp_api::RuntimeApiInfo;
+
+    type Current = dyn DeploymentControlApi<crate::Block, AccountId, BlockNumber>;
+    type Older = dyn v2::DeploymentControlApi<crate::Block, AccountId, BlockNumber>;
+
+    // Older nodes call the same API, its methods only gain versions
+    assert_eq!(<Older>::ID, <Current>::ID);
+    assert!(<Older>::VERSION <= <Current>::VERSION);
+
+    let info = ContractInfo::<BlockNumber> {
+        code_size: 5,
+        code_hash: H256::repeat_byte(0xc0),
+        storage_entries: 2,
+        balance: U256::from(7),
+        nonce: U256::one(),
+        deployer: Some(deployer()),
+        deployed_at: Some(3),
+    };
+    let decoded =
+        Option::<v2::ContractInfo<BlockNumber>>::decode_all(&mut &Some(info).encode()[..]);
+    assert_eq!(
+        decoded.unwrap(),
+        Some(v2::ContractInfo {
+            code_size: 5,
+            code_hash: H256::repeat_byte(0xc0),
+            storage_entries: 2,
+            balance: U256::from(7),
+            nonce: U256::one(),
+            deployer: Some(deployer()),
+            deployed_at: Some(3),
+        })
+    );
+
+    let admin = account_of(deployer());
+    let factories = vec![FactoryEntry::<AccountId, BlockNumber> {
+        address: H160::repeat_byte(0xfa),
+        admin: admin.clone(),
+        children: 4,
+        status: FactoryStatus::Suspended,
+        registered_at: 9,
+    }];
+    let decoded = Vec::<v2::FactoryEntry<AccountId, BlockNumber>>::decode_all(
+        &mut &factories.encode()[..],
+    );
+    assert_eq!(
+        decoded.unwrap(),
+        vec![v2::FactoryEntry {
+            address: H160::repeat_byte(0xfa),
+            admin,
+            children: 4,
+            status: v2::FactoryStatus::Suspended,
+            registered_at: 9,
+        }]
+    );
+}
+
+#[test]
+fn deployer_status_matches_the_storage_layout_older_nodes_fall_back_to() {
+    use frame_support::{storage::storage_prefix, Blake2_128Concat, StorageHasher};
+    use pallet_evm_deployment_control::AuthorizedDeployers;
+    use pallet_evm_deployment_control_runtime_api::{
+        runtime_decl_for_deployment_control_api::DeploymentControlApiV10, DeployerStatus,
+    };
+    use parity_scale_codec::{Decode, Encode};
+
+    let status = |account: &AccountId| {
+        <Runtime as DeploymentControlApiV10<crate::Block, AccountId, BlockNumber>>::deployer_status(
+            account.clone(),
+        )
+    };
+
+    new_test_ext().execute_with(|| {
+        let account = account_of(deployer());
+        assert_eq!(
+            status(&account),
+            DeployerStatus {
+                authorized: false,
+                authorized_at: None,
+                deployer_admin: false,
+            }
+        );
+
+        System::set_block_number(5);
+        assert_ok!(EvmDeploymentControl::authorize_deployer(
+            RuntimeOrigin::root(),
+            account.clone()
+        ));
+        DeployerAdmins::<Runtime>::insert(&account, ());
+        assert_eq!(
+            status(&account),
+            DeployerStatus {
+                authorized: true,
+                authorized_at: Some(5),
+                deployer_admin: true,
+            }
+        );
+
+        // `qnch_deployerStatus` reads this key and value for runtimes without the method, a
+        // storage redesign must come with a new key or version of the record
+        let mut key = storage_prefix(b"EvmDeploymentControl", b"AuthorizedDeployers").to_vec();
+        key.extend(Blake2_128Concat::hash(&account.encode()));
+        assert_eq!(AuthorizedDeployers::<Runtime>::hashed_key_for(&account), key);
+        let record = sp_io::storage::get(&key).unwrap();
+        assert_eq!(BlockNumber::decode(&mut &record[..]).unwrap(), 5);
+    });
+}
+
+#[test]
+fn deployment_prerequisites_are_those_of_the_next_block() {
+    use pallet_evm_deployment_control_runtime_api::{
+        runtime_decl_for_deployment_control_api::DeploymentControlApiV10 as Api,
+        DeploymentPrerequisites,
+    };
+
+    // Restores the block number, as the node's call discards the state changes
+    let prerequisites = |address: H160| {
+        let now = System::block_number();
+        let prerequisites =
+            <Runtime as Api<crate::Block, AccountId, BlockNumber>>::deployment_prerequisites(
+                address,
+            );
+        System::set_block_number(now);
+        prerequisites
+    };
+
+    new_test_ext().execute_with(|| {
+        System::set_block_number(DAYS - 1);
+        assert_eq!(
+            prerequisites(deployer()),
+            DeploymentPrerequisites {
+                nonce: U256::zero(),
+                refusal: Some(DeploymentValidationError::UnauthorizedDeployer.into()),
+                remaining_quota: None,
+                quota_resets_at: None,
+                announced_intents: Vec::new(),
+            }
+        );
+
+        let account = account_of(deployer());
+        <Balances as fungible::Mutate<AccountId>>::set_balance(&account, 100 * UNIT);
+        assert_ok!(EvmDeploymentControl::authorize_deployer(
+            RuntimeOrigin::root(),
+            account.clone()
+        ));
+        assert_ok!(EvmDeploymentControl::set_deployment_quota(RuntimeOrigin::root(), Some(3)));
+        let code_hash = H256::repeat_byte(0xc0);
+        assert_ok!(EvmDeploymentControl::announce_deployment(
+            RuntimeOrigin::signed(account),
+            code_hash,
+            b"Router".to_vec().try_into().unwrap()
+        ));
+
+        // The next block opens the second quota period
+        assert_eq!(
+            prerequisites(deployer()),
+            DeploymentPrerequisites {
+                nonce: U256::zero(),
+                refusal: None,
+                remaining_quota: Some(3),
+                quota_resets_at: Some(2 * DAYS),
+                announced_intents: vec![code_hash],
+            }
+        );
+    });
+}
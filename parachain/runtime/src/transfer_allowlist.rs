@@ -0,0 +1,53 @@
+use frame_support::traits::Contains;
+use pallet_ethereum::{Transaction as EthereumTransaction, TransactionAction};
+use sp_core::{H160, U256};
+use sp_runtime::transaction_validity::TransactionValidityError;
+
+use crate::{invalid_transaction::CustomInvalidity, Runtime, RuntimeCall};
+
+/// Recipient and value of an Ethereum transaction calling an address
+///
+/// Contract creations carry no recipient and are left to the deployment control checks.
+pub fn value_transfer(transaction: &EthereumTransaction) -> Option<(H160, U256)> {
+    let (action, value) = match transaction {
+        EthereumTransaction::Legacy(t) => (&t.action, t.value),
+        EthereumTransaction::EIP2930(t) => (&t.action, t.value),
+        EthereumTransaction::EIP1559(t) => (&t.action, t.value),
+    };
+    match action {
+        TransactionAction::Call(to) => Some((*to, value)),
+        TransactionAction::Create => None,
+    }
+}
+
+/// Check an Ethereum transaction against the transfer allowlist
+pub fn check_transaction(
+    transaction: &EthereumTransaction,
+) -> Result<(), TransactionValidityError> {
+    match value_transfer(transaction) {
+        Some((to, value))
+            if !pallet_transfer_allowlist::Pallet::<Runtime>::is_transfer_allowed(&to, value) =>
+        {
+            Err(CustomInvalidity::RecipientNotAllowed.into())
+        }
+        _ => Ok(()),
+    }
+}
+
+/// Base call filter enforcing the transfer allowlist on Substrate-level EVM calls
+///
+/// `pallet_evm::call` names its target as a call argument, so it is checked here while
+/// Ethereum transactions are checked in `validate_self_contained`. Only the top-level
+/// recipient is checked; value forwarded by a contract is not.
+pub struct TransferCallFilter;
+
+impl Contains<RuntimeCall> for TransferCallFilter {
+    fn contains(call: &RuntimeCall) -> bool {
+        match call {
+            RuntimeCall::EVM(pallet_evm::Call::call { target, value, .. }) => {
+                pallet_transfer_allowlist::Pallet::<Runtime>::is_transfer_allowed(target, *value)
+            }
+            _ => true,
+        }
+    }
+}
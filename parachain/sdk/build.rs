@@ -0,0 +1,20 @@
+//! Generates the typed API of the SDK from the metadata of the runtime of this repository,
+//! so that the calls, storage items and events it uses follow the runtime it ships with.
+
+use std::{env, fs, path::PathBuf};
+
+use parachain_template_runtime::Runtime;
+use parity_scale_codec::Decode;
+
+fn main() {
+    let metadata = Runtime::metadata_at_version(15).expect("the runtime serves metadata v15; qed");
+    let metadata = subxt_metadata::Metadata::decode(&mut &metadata[..])
+        .expect("the runtime encodes its own metadata; qed");
+
+    let api = subxt_codegen::CodegenBuilder::new()
+        .generate(metadata)
+        .expect("failed to generate the runtime API");
+
+    let out_dir = PathBuf::from(env::var("OUT_DIR").expect("set by cargo; qed"));
+    fs::write(out_dir.join("api.rs"), api.to_string()).expect("failed to write the runtime API");
+}
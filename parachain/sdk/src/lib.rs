@@ -0,0 +1,170 @@
+//! # qnch SDK
+//!
+//! Typed async client of the deployment control, for backend services that check deployers,
+//! follow deployments or deploy through approved factories without copying storage keys
+//! and call encodings around.
+//!
+//! The [`api`] module is generated at build time from the metadata of the runtime of this
+//! repository. Calls and storage queries are validated against the metadata of the node
+//! they are sent to, so a runtime upgrade that changes them fails loudly instead of
+//! decoding garbage; rebuild the SDK against the upgraded runtime to follow it.
+//!
+//! Signed transactions are bound to the network they are meant for, named by its EVM chain
+//! id, see [`network`].
+
+pub mod network;
+
+use std::fmt;
+
+use futures::{stream, Stream, TryStreamExt};
+use subxt::tx::Signer;
+pub use subxt::{
+    utils::{AccountId32, H160, H256},
+    OnlineClient,
+};
+
+pub use network::QnchConfig;
+
+include!(concat!(env!("OUT_DIR"), "/api.rs"));
+
+use api::runtime_types::{pallet_evm_deployment_control::FactoryStatus, primitive_types::U256};
+
+/// Client of a qnch node
+pub type Client = OnlineClient<QnchConfig>;
+
+/// Event of the deployment control pallet
+pub type DeploymentEvent = api::evm_deployment_control::Event;
+
+/// Errors of the SDK
+#[derive(Debug)]
+pub enum Error {
+    /// The node could not be reached, or answered with something the runtime API does not
+    /// describe
+    Subxt(subxt::Error),
+    /// The factory is not approved, or suspended
+    FactoryNotApproved(H160),
+    /// The factory call was executed but reverted or ran out of gas
+    ExecutionFailed,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Subxt(err) => err.fmt(f),
+            Self::FactoryNotApproved(factory) => write!(f, "factory {factory:?} is not approved"),
+            Self::ExecutionFailed => f.write_str("the factory call failed"),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Subxt(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<subxt::Error> for Error {
+    fn from(err: subxt::Error) -> Self {
+        Self::Subxt(err)
+    }
+}
+
+/// Connect to the node at `url`, e.g. `ws://127.0.0.1:9944`
+pub async fn connect(url: &str) -> Result<Client, Error> {
+    Ok(Client::from_url(url).await?)
+}
+
+/// Whether `account` is an authorized deployer at the best block
+pub async fn is_authorized(client: &Client, account: &AccountId32) -> Result<bool, Error> {
+    let query = api::storage().evm_deployment_control().authorized_deployers(account);
+    Ok(client.storage().at_latest().await?.fetch(&query).await?.is_some())
+}
+
+/// Deployment control events of the finalized blocks, from the next one on, with the hash of
+/// the block that emitted them
+pub async fn watch_deployment_events(
+    client: &Client,
+) -> Result<impl Stream<Item = Result<(H256, DeploymentEvent), Error>>, Error> {
+    let blocks = client.blocks().subscribe_finalized().await?;
+
+    Ok(blocks
+        .map_err(Error::from)
+        .and_then(|block| async move {
+            let mut found = Vec::new();
+            for event in block.events().await?.iter() {
+                if let api::Event::EvmDeploymentControl(event) = event?.as_root_event()? {
+                    found.push(Ok((block.hash(), event)));
+                }
+            }
+            Ok(stream::iter(found))
+        })
+        .try_flatten())
+}
+
+/// Call the approved `factory` with `input`, signed by `signer` for the network with EVM
+/// chain id `chain_id`, and return the addresses of the contracts it created
+///
+/// The call is sent from the EVM address of the signer, the first 20 bytes of its account,
+/// and pays the current base fee. The factory is checked to be approved and active at the
+/// best block first, so calls it would have refused are not paid for. Networks with
+/// another chain id refuse the transaction.
+pub async fn deploy_via_factory<S: Signer<QnchConfig>>(
+    client: &Client,
+    signer: &S,
+    chain_id: u64,
+    factory: H160,
+    input: Vec<u8>,
+    gas_limit: u64,
+) -> Result<Vec<H160>, Error> {
+    let storage = client.storage().at_latest().await?;
+    let approved = api::storage().evm_deployment_control().approved_factories(factory);
+    match storage.fetch(&approved).await? {
+        Some(info) if matches!(info.status, FactoryStatus::Active) => {}
+        _ => return Err(Error::FactoryNotApproved(factory)),
+    }
+    let base_fee = storage.fetch_or_default(&api::storage().base_fee().base_fee_per_gas()).await?;
+
+    let source = H160::from_slice(&signer.account_id().0[..20]);
+    let call = api::tx().evm().call(
+        source,
+        factory,
+        input,
+        U256([0; 4]),
+        gas_limit,
+        base_fee,
+        None,
+        None,
+        Vec::new(),
+    );
+    let events = client
+        .tx()
+        .sign_and_submit_then_watch(&call, signer, network::tx_params(chain_id))
+        .await?
+        .wait_for_finalized_success()
+        .await?;
+
+    // A reverted call is still a successful extrinsic, only its events tell
+    if events.has::<api::evm::events::ExecutedFailed>()? {
+        return Err(Error::ExecutionFailed);
+    }
+    let created = events
+        .find::<api::evm::events::Created>()
+        .map(|created| created.map(|created| created.address))
+        .collect::<Result<_, _>>()?;
+    Ok(created)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn api_is_generated_from_the_runtime() {
+        for pallet in ["EVM", "BaseFee", "EvmDeploymentControl"] {
+            assert!(api::PALLETS.contains(&pallet), "{pallet} is missing");
+        }
+    }
+}
@@ -0,0 +1,109 @@
+//! Transaction parameters of qnch networks.
+//!
+//! Signed transactions carry the `CheckNetwork` extension of the runtime, binding them to
+//! the EVM chain id and genesis hash of one network, so that a call signed for staging is
+//! refused by production and the other way round.
+
+use scale_info::PortableRegistry;
+use subxt::{
+    client::ClientState,
+    config::{
+        signed_extensions::{
+            AnyOf, ChargeAssetTxPayment, ChargeTransactionPayment, CheckGenesis,
+            CheckMetadataHash, CheckMortality, CheckNonce, CheckSpecVersion, CheckTxVersion,
+            SignedExtension,
+        },
+        DefaultExtrinsicParamsBuilder, ExtrinsicParams, ExtrinsicParamsEncoder,
+        ExtrinsicParamsError, RefineParams,
+    },
+    ext::codec::{Compact, Encode},
+    Config, PolkadotConfig,
+};
+
+/// Configuration of qnch networks, Polkadot's with the `CheckNetwork` extension
+pub enum QnchConfig {}
+
+impl Config for QnchConfig {
+    type Hash = <PolkadotConfig as Config>::Hash;
+    type AccountId = <PolkadotConfig as Config>::AccountId;
+    type Address = <PolkadotConfig as Config>::Address;
+    type Signature = <PolkadotConfig as Config>::Signature;
+    type Hasher = <PolkadotConfig as Config>::Hasher;
+    type Header = <PolkadotConfig as Config>::Header;
+    type ExtrinsicParams = QnchExtrinsicParams;
+    type AssetId = <PolkadotConfig as Config>::AssetId;
+}
+
+/// Signed extensions of qnch networks
+pub type QnchExtrinsicParams = AnyOf<
+    QnchConfig,
+    (
+        CheckSpecVersion,
+        CheckTxVersion,
+        CheckNonce,
+        CheckGenesis<QnchConfig>,
+        CheckMortality<QnchConfig>,
+        ChargeAssetTxPayment<QnchConfig>,
+        ChargeTransactionPayment,
+        CheckMetadataHash,
+        CheckNetwork,
+    ),
+>;
+
+/// Parameters of a transaction for the network with EVM chain id `chain_id`, otherwise
+/// the defaults
+pub fn tx_params(
+    chain_id: u64,
+) -> <QnchExtrinsicParams as ExtrinsicParams<QnchConfig>>::Params {
+    let (spec_version, tx_version, nonce, genesis, mortality, asset_payment, payment, hash) =
+        DefaultExtrinsicParamsBuilder::<QnchConfig>::new().build();
+    (
+        spec_version,
+        tx_version,
+        nonce,
+        genesis,
+        mortality,
+        asset_payment,
+        payment,
+        hash,
+        CheckNetworkParams(chain_id),
+    )
+}
+
+/// The `CheckNetwork` signed extension: the chain id in the transaction, the genesis hash
+/// in the signed payload
+pub struct CheckNetwork {
+    chain_id: u64,
+    genesis_hash: Vec<u8>,
+}
+
+/// EVM chain id of the network a transaction is meant for
+pub struct CheckNetworkParams(pub u64);
+
+impl<T: Config> RefineParams<T> for CheckNetworkParams {}
+
+impl<T: Config> ExtrinsicParams<T> for CheckNetwork {
+    type Params = CheckNetworkParams;
+
+    fn new(client: &ClientState<T>, params: Self::Params) -> Result<Self, ExtrinsicParamsError> {
+        Ok(Self { chain_id: params.0, genesis_hash: client.genesis_hash.encode() })
+    }
+}
+
+impl ExtrinsicParamsEncoder for CheckNetwork {
+    fn encode_extra_to(&self, v: &mut Vec<u8>) {
+        Compact(self.chain_id).encode_to(v);
+    }
+
+    fn encode_additional_to(&self, v: &mut Vec<u8>) {
+        v.extend_from_slice(&self.genesis_hash);
+    }
+}
+
+impl<T: Config> SignedExtension<T> for CheckNetwork {
+    type Decoded = u64;
+
+    fn matches(identifier: &str, _type_id: u32, _types: &PortableRegistry) -> bool {
+        identifier == "CheckNetwork"
+    }
+}
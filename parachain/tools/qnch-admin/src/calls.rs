@@ -0,0 +1,165 @@
+//! Deployment control calls of the admin flows, built against the metadata of the node.
+//!
+//! Calls are dynamic values rather than types generated from a metadata snapshot, so that
+//! the tool keeps working across runtime upgrades that leave the calls themselves unchanged.
+
+use std::str::FromStr;
+
+use subxt::{
+    dynamic::{self, Value},
+    ext::scale_value::Composite,
+    tx::DynamicPayload,
+    utils::{AccountId32, H160},
+};
+
+/// Name of the deployment control pallet in the runtime
+pub const PALLET: &str = "EvmDeploymentControl";
+
+/// Origin a call is dispatched with
+#[derive(Clone, Copy, Debug, PartialEq, Eq, clap::ValueEnum)]
+pub enum Via {
+    /// Wrapped in `Sudo.sudo`, signed by the sudo key
+    Sudo,
+    /// Wrapped in `EvmDeploymentControl.dispatch_as_deployer_admin`, signed by a deployer
+    /// admin
+    DeployerAdmin,
+    /// Dispatched as is, e.g. as the proposal of a council motion
+    None,
+}
+
+/// Deployment policy, as given on the command line
+///
+/// One of `permissioned`, `permissionless`, `fee:<amount>` with the amount in the smallest
+/// unit, or `rollout:<percent>`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Policy {
+    /// Only authorized deployers may deploy contracts
+    Permissioned,
+    /// Every account may deploy contracts
+    Permissionless,
+    /// Every account may deploy contracts, paying a burned fee unless authorized
+    PermissionlessWithFee(u128),
+    /// A share of the creations of accounts that are not authorized is admitted
+    Rollout(u8),
+}
+
+impl FromStr for Policy {
+    type Err = String;
+
+    fn from_str(policy: &str) -> Result<Self, Self::Err> {
+        match policy.split_once(':') {
+            None if policy == "permissioned" => Ok(Self::Permissioned),
+            None if policy == "permissionless" => Ok(Self::Permissionless),
+            Some(("fee", fee)) => fee
+                .parse()
+                .map(Self::PermissionlessWithFee)
+                .map_err(|err| format!("invalid fee `{fee}`: {err}")),
+            Some(("rollout", percent)) => match percent.parse() {
+                Ok(percent) if percent <= 100 => Ok(Self::Rollout(percent)),
+                _ => Err(format!("invalid rollout share `{percent}`, expected 0 to 100")),
+            },
+            _ => Err(format!(
+                "unknown policy `{policy}`, expected `permissioned`, `permissionless`, \
+                 `fee:<amount>` or `rollout:<percent>`"
+            )),
+        }
+    }
+}
+
+impl Policy {
+    fn into_value(self) -> Value {
+        match self {
+            Self::Permissioned => Value::unnamed_variant("Permissioned", []),
+            Self::Permissionless => Value::unnamed_variant("Permissionless", []),
+            Self::PermissionlessWithFee(fee) => {
+                Value::named_variant("PermissionlessWithFee", [("fee", Value::u128(fee))])
+            }
+            Self::Rollout(admitted) => {
+                Value::named_variant("Rollout", [("admitted", Value::u128(admitted.into()))])
+            }
+        }
+    }
+}
+
+/// A deployment control call of the admin flows
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum AdminCall {
+    /// `authorize_deployer`
+    Authorize(AccountId32),
+    /// `revoke_deployer`
+    Revoke(AccountId32),
+    /// `register_factory`
+    RegisterFactory {
+        /// Address of the factory contract
+        factory: H160,
+        /// Account administering the factory
+        admin: AccountId32,
+    },
+    /// `set_deployment_policy`
+    SetPolicy(Policy),
+}
+
+impl AdminCall {
+    /// Name of the call in the pallet
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Authorize(_) => "authorize_deployer",
+            Self::Revoke(_) => "revoke_deployer",
+            Self::RegisterFactory { .. } => "register_factory",
+            Self::SetPolicy(_) => "set_deployment_policy",
+        }
+    }
+
+    fn fields(self) -> Vec<(&'static str, Value)> {
+        match self {
+            Self::Authorize(deployer) | Self::Revoke(deployer) => {
+                vec![("deployer", Value::from_bytes(deployer.0))]
+            }
+            Self::RegisterFactory { factory, admin } => vec![
+                ("factory", Value::from_bytes(factory.0)),
+                ("admin", Value::from_bytes(admin.0)),
+            ],
+            Self::SetPolicy(policy) => vec![("policy", policy.into_value())],
+        }
+    }
+
+    /// The call as a `RuntimeCall` value, to be wrapped in another call
+    fn into_runtime_call(self) -> Value {
+        let name = self.name();
+        Value::unnamed_variant(PALLET, [Value::named_variant(name, self.fields())])
+    }
+
+    /// The extrinsic payload dispatching the call `via` the given origin
+    pub fn into_payload(self, via: Via) -> DynamicPayload {
+        match via {
+            Via::Sudo => dynamic::tx(
+                "Sudo",
+                "sudo",
+                Composite::named([("call", self.into_runtime_call())]),
+            ),
+            Via::DeployerAdmin => dynamic::tx(
+                PALLET,
+                "dispatch_as_deployer_admin",
+                Composite::named([("call", self.into_runtime_call())]),
+            ),
+            Via::None => dynamic::tx(PALLET, self.name(), Composite::named(self.fields())),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn policies_parse_from_the_command_line() {
+        assert_eq!("permissioned".parse(), Ok(Policy::Permissioned));
+        assert_eq!("permissionless".parse(), Ok(Policy::Permissionless));
+        assert_eq!("fee:1000".parse(), Ok(Policy::PermissionlessWithFee(1_000)));
+        assert_eq!("rollout:25".parse(), Ok(Policy::Rollout(25)));
+
+        for invalid in ["rollout:101", "fee:-1", "fee", "open"] {
+            assert!(invalid.parse::<Policy>().is_err(), "{invalid} parsed");
+        }
+    }
+}
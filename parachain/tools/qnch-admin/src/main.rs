@@ -0,0 +1,280 @@
+//! # qnch-admin
+//!
+//! Command line tool for the deployment control admin flows: authorizing and revoking
+//! deployers, registering factories, setting the deployment policy and inspecting the
+//! deployment control state, without hand-crafting extrinsics in polkadot.js.
+//!
+//! Every call is dispatched through `--via` the sudo key (default), a deployer admin or
+//! as is, and either submitted, dry-run against the best block, or printed as the call
+//! data and call hash that the signatories of a multisig or council motion approve.
+//!
+//! Signed calls name the EVM chain id of the network they are meant for with `--chain-id`,
+//! so that a call aimed at staging cannot be applied to production by a wrong `--url`.
+
+mod calls;
+mod network;
+
+use std::{error::Error, str::FromStr};
+
+use clap::{Parser, Subcommand, ValueEnum};
+use subxt::{
+    backend::{
+        legacy::{rpc_methods::DryRunResult, LegacyRpcMethods},
+        rpc::{rpc_params, RpcClient},
+    },
+    config::Hasher,
+    dynamic::{self, Value},
+    tx::DynamicPayload,
+    utils::{AccountId32, H160},
+    Config, OnlineClient,
+};
+use subxt_signer::{sr25519::Keypair, SecretUri};
+
+use crate::{
+    calls::{AdminCall, Policy, Via, PALLET},
+    network::{tx_params, QnchConfig},
+};
+
+type Result<T> = std::result::Result<T, Box<dyn Error>>;
+type Storage = subxt::storage::Storage<QnchConfig, OnlineClient<QnchConfig>>;
+
+/// Deployment control admin flows.
+#[derive(Debug, Parser)]
+#[command(name = "qnch-admin", version, about)]
+struct Cli {
+    /// WebSocket endpoint of the node
+    #[arg(long, global = true, default_value = "ws://127.0.0.1:9944")]
+    url: String,
+
+    /// Secret URI of the signer, e.g. `//Alice` or a mnemonic
+    #[arg(long, global = true, env = "QNCH_ADMIN_SURI", hide_env_values = true)]
+    suri: Option<String>,
+
+    /// EVM chain id of the network signed calls are meant for, refused by other networks
+    #[arg(long, global = true, env = "QNCH_CHAIN_ID")]
+    chain_id: Option<u64>,
+
+    /// Origin the call is dispatched with
+    #[arg(long, global = true, value_enum, default_value_t = Via::Sudo)]
+    via: Via,
+
+    /// What to do with the call
+    #[arg(long, global = true, value_enum, default_value_t = Output::Submit)]
+    output: Output,
+
+    #[command(subcommand)]
+    command: Command,
+}
+
+/// What to do with a call
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+enum Output {
+    /// Sign, submit and wait for the call to be finalized
+    Submit,
+    /// Sign and dry-run the call at the best block, without submitting it
+    ///
+    /// Served by `system_dryRun`, an unsafe RPC method.
+    DryRun,
+    /// Print the call data and call hash as JSON, for the signatories of a multisig or the
+    /// members of a council motion to approve
+    MultisigPayload,
+}
+
+#[derive(Debug, Subcommand)]
+enum Command {
+    /// Authorize a deployer
+    Authorize {
+        /// SS58 account or `0x` EVM address of the deployer
+        deployer: String,
+    },
+    /// Revoke the authorization of a deployer
+    Revoke {
+        /// SS58 account or `0x` EVM address of the deployer
+        deployer: String,
+    },
+    /// Register an approved factory
+    RegisterFactory {
+        /// `0x` address of the factory contract
+        factory: H160,
+        /// SS58 account or `0x` EVM address of the factory admin
+        admin: String,
+    },
+    /// Replace the deployment policy, which requires `--via sudo` or `--via none`
+    SetPolicy {
+        /// `permissioned`, `permissionless`, `fee:<amount>` or `rollout:<percent>`
+        policy: Policy,
+    },
+    /// Print deployment control state at the best block
+    #[command(subcommand)]
+    Inspect(Inspect),
+}
+
+#[derive(Debug, Subcommand)]
+enum Inspect {
+    /// Authorization and deployer admin role of an account
+    Deployer {
+        /// SS58 account or `0x` EVM address of the deployer
+        deployer: String,
+    },
+    /// Registration and expiry of a factory
+    Factory {
+        /// `0x` address of the factory contract
+        factory: H160,
+    },
+    /// The deployment policy
+    Policy,
+}
+
+/// Connection to the node
+struct Node {
+    rpc: RpcClient,
+    client: OnlineClient<QnchConfig>,
+}
+
+impl Node {
+    async fn connect(url: &str) -> Result<Self> {
+        let rpc = RpcClient::from_url(url).await?;
+        let client = OnlineClient::<QnchConfig>::from_rpc_client(rpc.clone()).await?;
+        Ok(Self { rpc, client })
+    }
+
+    /// The account of an SS58 account or EVM address, as mapped by the runtime
+    async fn account(&self, account: &str) -> Result<AccountId32> {
+        if !account.starts_with("0x") {
+            return Ok(AccountId32::from_str(account)?);
+        }
+        let address = H160::from_str(account)?;
+        let pair: serde_json::Value =
+            self.rpc.request("qnch_addressOf", rpc_params![address]).await?;
+        let account = pair["account"].as_str().ok_or("`qnch_addressOf` returned no account")?;
+        Ok(AccountId32::from_str(account)?)
+    }
+}
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    let cli = Cli::parse();
+    let node = Node::connect(&cli.url).await?;
+
+    let call = match cli.command {
+        Command::Authorize { deployer } => AdminCall::Authorize(node.account(&deployer).await?),
+        Command::Revoke { deployer } => AdminCall::Revoke(node.account(&deployer).await?),
+        Command::RegisterFactory { factory, admin } => AdminCall::RegisterFactory {
+            factory,
+            admin: node.account(&admin).await?,
+        },
+        Command::SetPolicy { policy } => AdminCall::SetPolicy(policy),
+        Command::Inspect(inspect) => return print_state(&node, inspect).await,
+    };
+    let payload = call.into_payload(cli.via);
+    // Fail on calls the runtime does not know before anything is signed or printed
+    node.client.tx().validate(&payload)?;
+
+    match cli.output {
+        Output::MultisigPayload => {
+            let call_data = node.client.tx().call_data(&payload)?;
+            let call_hash = <QnchConfig as Config>::Hasher::hash(&call_data);
+            let output = serde_json::json!({
+                "callData": format!("0x{}", hex::encode(&call_data)),
+                "callHash": format!("{call_hash:?}"),
+            });
+            println!("{}", serde_json::to_string_pretty(&output)?);
+        }
+        Output::DryRun => dry_run(&node, &payload, &signer(cli.suri, cli.chain_id)?).await?,
+        Output::Submit => submit(&node, &payload, &signer(cli.suri, cli.chain_id)?).await?,
+    }
+
+    Ok(())
+}
+
+/// Key signing calls for the network with the given EVM chain id
+struct Signer {
+    keypair: Keypair,
+    chain_id: u64,
+}
+
+fn signer(suri: Option<String>, chain_id: Option<u64>) -> Result<Signer> {
+    let suri = suri.ok_or("a signer is required, pass `--suri` or set `QNCH_ADMIN_SURI`")?;
+    let chain_id = chain_id
+        .ok_or("the target network is required, pass `--chain-id` or set `QNCH_CHAIN_ID`")?;
+    Ok(Signer { keypair: Keypair::from_uri(&SecretUri::from_str(&suri)?)?, chain_id })
+}
+
+async fn dry_run(node: &Node, payload: &DynamicPayload, signer: &Signer) -> Result<()> {
+    let tx = node
+        .client
+        .tx()
+        .create_signed(payload, &signer.keypair, tx_params(signer.chain_id))
+        .await?;
+    let result = LegacyRpcMethods::<QnchConfig>::new(node.rpc.clone())
+        .dry_run(tx.encoded(), None)
+        .await?
+        .into_dry_run_result(&node.client.metadata())?;
+
+    match result {
+        // Sudo and deployer admin dispatches succeed whatever the outcome of the wrapped
+        // call, which is only reported in their events
+        DryRunResult::Success => println!("dispatched"),
+        DryRunResult::DispatchError(err) => return Err(format!("dispatch failed: {err}").into()),
+        // Including one signed for another network, refused with custom code 8
+        DryRunResult::TransactionValidityError => return Err("invalid transaction".into()),
+    }
+    Ok(())
+}
+
+async fn submit(node: &Node, payload: &DynamicPayload, signer: &Signer) -> Result<()> {
+    let events = node
+        .client
+        .tx()
+        .sign_and_submit_then_watch(payload, &signer.keypair, tx_params(signer.chain_id))
+        .await?
+        .wait_for_finalized_success()
+        .await?;
+
+    println!("finalized in block {:?}", events.block_hash());
+    for event in events.iter() {
+        let event = event?;
+        if matches!(event.pallet_name(), "Sudo" | PALLET) {
+            println!(
+                "{}.{} {}",
+                event.pallet_name(),
+                event.variant_name(),
+                event.field_values()?
+            );
+        }
+    }
+    Ok(())
+}
+
+async fn print_state(node: &Node, inspect: Inspect) -> Result<()> {
+    let storage = node.client.storage().at_latest().await?;
+
+    match inspect {
+        Inspect::Deployer { deployer } => {
+            let account = Value::from_bytes(node.account(&deployer).await?.0);
+            let authorized = fetch(&storage, "AuthorizedDeployers", account.clone()).await?;
+            println!("authorized: {authorized}");
+            println!("deployer admin: {}", fetch(&storage, "DeployerAdmins", account).await?);
+        }
+        Inspect::Factory { factory } => {
+            let factory = Value::from_bytes(factory.0);
+            let info = fetch(&storage, "ApprovedFactories", factory.clone()).await?;
+            println!("factory: {info}");
+            println!("expiry: {}", fetch(&storage, "FactoryExpiry", factory).await?);
+        }
+        Inspect::Policy => {
+            let query = dynamic::storage(PALLET, "EvmDeploymentPolicy", Vec::<Value>::new());
+            println!("policy: {}", storage.fetch_or_default(&query).await?.to_value()?);
+        }
+    }
+    Ok(())
+}
+
+/// The entry of the storage map `item` at `key`, `none` if there is none
+async fn fetch(storage: &Storage, item: &str, key: Value) -> Result<String> {
+    let value = storage.fetch(&dynamic::storage(PALLET, item, vec![key])).await?;
+    Ok(match value {
+        Some(value) => value.to_value()?.to_string(),
+        None => "none".into(),
+    })
+}
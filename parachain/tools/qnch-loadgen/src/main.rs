@@ -0,0 +1,347 @@
+//! # qnch-loadgen
+//!
+//! Load-test harness for the deployment authorization path: sends signed Ethereum
+//! transactions to a dev node through `eth_sendRawTransaction` in a configurable `--mix` of
+//!
+//! - `authorized`: contract creations by authorized deployers, expected to be admitted;
+//! - `unauthorized`: contract creations by funded keys that are not deployers, expected to be
+//!   refused by the transaction pool;
+//! - `factory`: calls creating a contract through an approved `--factory`, expected to be
+//!   admitted,
+//!
+//! and reports the submission and inclusion TPS along with the latency percentiles of the
+//! admissions and refusals, the refusal reasons, and the transactions answered against the
+//! expectation of their kind. `--json` prints the report as JSON, for comparing a release with
+//! the baseline of the previous one.
+//!
+//! The defaults target the dev chain spec: Hardhat account 0 is an authorized deployer and
+//! Hardhat accounts 1 to 3 are funded without being authorized.
+//!
+//! Admitted traffic is sent in nonce order, with one transaction in flight per key: more
+//! `--authorized-key` and `--caller-key` keys raise the concurrency of the admitted traffic.
+
+mod report;
+mod traffic;
+
+use std::{
+    error::Error,
+    sync::{
+        atomic::{AtomicU32, Ordering},
+        Arc,
+    },
+    time::{Duration, Instant, SystemTime, UNIX_EPOCH},
+};
+
+use clap::Parser;
+use ethereum::TransactionAction;
+use jsonrpsee::{
+    core::{client::ClientT, ClientError},
+    http_client::{HttpClient, HttpClientBuilder},
+    rpc_params,
+};
+use libsecp256k1::SecretKey;
+use sp_core::{Bytes, H160, H256, U256};
+
+use crate::{
+    report::{Inclusion, Outcome, Report},
+    traffic::{Kind, Mix, Sender, Unsigned},
+};
+
+type Result<T> = std::result::Result<T, Box<dyn Error + Send + Sync>>;
+
+/// Hardhat test account 0, an authorized deployer of the dev chain spec
+const AUTHORIZED_KEY: &str = "0xac0974bec39a17e36ba4a6b4d238ff944bacb478cbed5efcae784d7bfc2ff80";
+
+/// Hardhat test accounts 1 to 3, funded by the dev chain spec
+const CALLER_KEYS: [&str; 3] = [
+    "0x59c6995e998f97a5a0044966f0945389dc9e86dae88c7a8412f4603b6b78690d",
+    "0x5de4111afa1a4b94908f83103eb1f1706367c2e68ca870fc3fb9a804cdab365a",
+    "0x7c852118294e51e653712a81e05800f419141751be58f605c371e15141b007a6",
+];
+
+/// Synthetic deployment traffic against a dev node.
+#[derive(Debug, Parser)]
+#[command(name = "qnch-loadgen", version, about)]
+struct Cli {
+    /// HTTP endpoint of the node's Ethereum RPC
+    #[arg(long, default_value = "http://127.0.0.1:9944")]
+    url: String,
+
+    /// EVM chain id to sign the transactions for, queried from the node if not given
+    #[arg(long, env = "QNCH_CHAIN_ID")]
+    chain_id: Option<u64>,
+
+    /// Number of transactions to send
+    #[arg(long, default_value_t = 1_000)]
+    transactions: u32,
+
+    /// Number of transactions in flight at once
+    #[arg(long, default_value_t = 16)]
+    concurrency: u32,
+
+    /// Shares of the traffic kinds: `authorized`, `unauthorized` and `factory`
+    #[arg(long, default_value = "authorized=50,unauthorized=50")]
+    mix: Mix,
+
+    /// Secret key of an authorized deployer, sending the authorized traffic
+    #[arg(
+        long = "authorized-key",
+        value_parser = traffic::parse_key,
+        default_value = AUTHORIZED_KEY
+    )]
+    authorized_keys: Vec<SecretKey>,
+
+    /// Secret key of a funded account that is not a deployer, sending the unauthorized and
+    /// factory traffic
+    #[arg(long = "caller-key", value_parser = traffic::parse_key, default_values = CALLER_KEYS)]
+    caller_keys: Vec<SecretKey>,
+
+    /// Approved factory called by the factory traffic
+    #[arg(long, value_parser = traffic::parse_address, requires = "factory_input")]
+    factory: Option<H160>,
+
+    /// Hex call data of the factory function creating a contract
+    #[arg(long, value_parser = parse_bytes, requires = "factory")]
+    factory_input: Option<Bytes>,
+
+    /// Gas price of the transactions in wei, queried from the node if not given
+    #[arg(long)]
+    gas_price: Option<u128>,
+
+    /// Seconds to wait for the admitted transactions to be included, 0 to skip the wait
+    #[arg(long, default_value_t = 60)]
+    inclusion_timeout: u64,
+
+    /// Print the report as JSON
+    #[arg(long)]
+    json: bool,
+}
+
+fn parse_bytes(s: &str) -> std::result::Result<Bytes, String> {
+    traffic::parse_hex(s).map(Bytes)
+}
+
+/// State shared by the workers of a run
+struct Run {
+    client: HttpClient,
+    chain_id: u64,
+    gas_price: U256,
+    mix: Mix,
+    transactions: u32,
+    /// Seed making the creation codes of the run unique
+    seed: u64,
+    authorized: Vec<Sender>,
+    callers: Vec<Sender>,
+    factory: Option<(H160, Vec<u8>)>,
+}
+
+impl Run {
+    /// Sign and send the `index`-th transaction of the run
+    async fn submit(&self, index: u32) -> Result<Outcome> {
+        let kind = self.mix.kind_of(index);
+        let senders = match kind {
+            Kind::Authorized => &self.authorized,
+            Kind::Unauthorized | Kind::Factory => &self.callers,
+        };
+        let sender = &senders[index as usize % senders.len()];
+        let (gas_limit, action, input) = match (kind, &self.factory) {
+            (Kind::Factory, Some((factory, input))) => {
+                (traffic::CALL_GAS_LIMIT, TransactionAction::Call(*factory), input.clone())
+            },
+            (Kind::Factory, None) => return Err("factory traffic requires --factory".into()),
+            _ => (
+                traffic::CREATE_GAS_LIMIT,
+                TransactionAction::Create,
+                traffic::creation_code(self.seed, index),
+            ),
+        };
+        let unsigned =
+            |nonce| Unsigned { nonce, gas_price: self.gas_price, gas_limit, action, input };
+
+        if !kind.expects_admission() {
+            // Refused transactions leave the nonce as is, so the key is not held while they are
+            // sent
+            let nonce = *sender.nonce.lock().await;
+            let raw = traffic::sign(&sender.secret, self.chain_id, unsigned(nonce));
+            let outcome = self.send(kind, raw).await?;
+            if outcome.refusal.is_none() {
+                *sender.nonce.lock().await += 1;
+            }
+            return Ok(outcome);
+        }
+
+        let mut nonce = sender.nonce.lock().await;
+        let raw = traffic::sign(&sender.secret, self.chain_id, unsigned(*nonce));
+        let outcome = self.send(kind, raw).await?;
+        if outcome.refusal.is_none() {
+            *nonce += 1;
+        }
+        Ok(outcome)
+    }
+
+    /// Send a signed transaction, timing the node's answer
+    ///
+    /// Errors answered by the node are refusals of the transaction, any other error ends the
+    /// run.
+    async fn send(&self, kind: Kind, raw: Vec<u8>) -> Result<Outcome> {
+        let raw = format!("0x{}", hex::encode(raw));
+        let started = Instant::now();
+        let result =
+            self.client.request::<H256, _>("eth_sendRawTransaction", rpc_params![raw]).await;
+        let latency = started.elapsed();
+
+        let refusal = match result {
+            Ok(_) => None,
+            Err(ClientError::Call(err)) => Some(err.message().to_string()),
+            Err(err) => return Err(err.into()),
+        };
+        Ok(Outcome { kind, latency, refusal })
+    }
+
+    /// Wait for the admitted transactions to be included, and count the transactions of the
+    /// blocks built since `from`
+    async fn wait_for_inclusion(&self, from: u64, timeout: Duration) -> Result<Inclusion> {
+        let deadline = Instant::now() + timeout;
+        let pending = loop {
+            let mut pending = 0;
+            for sender in self.authorized.iter().chain(&self.callers) {
+                let included = nonce_of(&self.client, sender.address, "latest").await?;
+                pending += sender.nonce.lock().await.saturating_sub(included);
+            }
+            if pending == 0 || Instant::now() >= deadline {
+                break pending;
+            }
+            tokio::time::sleep(Duration::from_secs(1)).await;
+        };
+
+        let to = block_number(&self.client).await?;
+        let mut transactions = 0;
+        for number in from + 1..=to {
+            let block = block_by_number(&self.client, number).await?;
+            transactions += block["transactions"].as_array().map_or(0, |hashes| hashes.len());
+        }
+        // Timestamps are in seconds, the span runs from the block before the first one built
+        let first = timestamp_of(&block_by_number(&self.client, from).await?)?;
+        let last = timestamp_of(&block_by_number(&self.client, to).await?)?;
+
+        Ok(Inclusion {
+            transactions: transactions as u64,
+            blocks: to - from,
+            span: Duration::from_secs(last.saturating_sub(first)),
+            pending,
+        })
+    }
+}
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    let cli = Cli::parse();
+
+    let factory = match (cli.factory, cli.factory_input) {
+        (Some(factory), Some(input)) => Some((factory, input.0)),
+        _ if cli.mix.share(Kind::Factory) > 0 => {
+            return Err("factory traffic requires --factory and --factory-input".into())
+        },
+        _ => None,
+    };
+    if cli.concurrency == 0 {
+        return Err("--concurrency must be above zero".into());
+    }
+
+    let client = HttpClientBuilder::default().build(&cli.url)?;
+    let chain_id = match cli.chain_id {
+        Some(chain_id) => chain_id,
+        None => quantity(&client, "eth_chainId").await?.low_u64(),
+    };
+    let gas_price = match cli.gas_price {
+        Some(gas_price) => gas_price.into(),
+        None => quantity(&client, "eth_gasPrice").await?,
+    };
+    let mut authorized = Vec::new();
+    for secret in cli.authorized_keys {
+        authorized.push(funded(&client, secret).await?);
+    }
+    let mut callers = Vec::new();
+    for secret in cli.caller_keys {
+        callers.push(funded(&client, secret).await?);
+    }
+    let from = block_number(&client).await?;
+    let run = Arc::new(Run {
+        client,
+        chain_id,
+        gas_price,
+        mix: cli.mix,
+        transactions: cli.transactions,
+        seed: SystemTime::now().duration_since(UNIX_EPOCH)?.as_nanos() as u64,
+        authorized,
+        callers,
+        factory,
+    });
+
+    let next = Arc::new(AtomicU32::new(0));
+    let started = Instant::now();
+    let workers: Vec<_> = (0..cli.concurrency)
+        .map(|_| {
+            let run = run.clone();
+            let next = next.clone();
+            tokio::spawn(async move {
+                let mut outcomes = Vec::new();
+                loop {
+                    let index = next.fetch_add(1, Ordering::Relaxed);
+                    if index >= run.transactions {
+                        return Ok::<_, Box<dyn Error + Send + Sync>>(outcomes);
+                    }
+                    outcomes.push(run.submit(index).await?);
+                }
+            })
+        })
+        .collect();
+    let mut outcomes = Vec::new();
+    for worker in workers {
+        outcomes.extend(worker.await??);
+    }
+    let elapsed = started.elapsed();
+
+    let inclusion = match cli.inclusion_timeout {
+        0 => None,
+        secs => Some(run.wait_for_inclusion(from, Duration::from_secs(secs)).await?),
+    };
+
+    let report = Report { elapsed, outcomes, inclusion };
+    if cli.json {
+        println!("{}", serde_json::to_string_pretty(&report.to_json())?);
+    } else {
+        println!("{}", report.to_text());
+    }
+
+    Ok(())
+}
+
+/// Sender of `secret`, from its pending nonce
+async fn funded(client: &HttpClient, secret: SecretKey) -> Result<Sender> {
+    let address = traffic::address_of(&secret);
+    let nonce = nonce_of(client, address, "pending").await?;
+    Ok(Sender::new(secret, nonce))
+}
+
+async fn quantity(client: &HttpClient, method: &str) -> Result<U256> {
+    Ok(client.request(method, rpc_params![]).await?)
+}
+
+async fn nonce_of(client: &HttpClient, address: H160, block: &str) -> Result<u64> {
+    let nonce: U256 = client.request("eth_getTransactionCount", rpc_params![address, block]).await?;
+    Ok(nonce.low_u64())
+}
+
+async fn block_number(client: &HttpClient) -> Result<u64> {
+    Ok(quantity(client, "eth_blockNumber").await?.low_u64())
+}
+
+async fn block_by_number(client: &HttpClient, number: u64) -> Result<serde_json::Value> {
+    Ok(client.request("eth_getBlockByNumber", rpc_params![U256::from(number), false]).await?)
+}
+
+fn timestamp_of(block: &serde_json::Value) -> Result<u64> {
+    let timestamp: U256 = serde_json::from_value(block["timestamp"].clone())?;
+    Ok(timestamp.low_u64())
+}
@@ -0,0 +1,189 @@
+//! Outcomes of the submitted transactions and the report summarizing them.
+
+use std::{collections::BTreeMap, time::Duration};
+
+use serde_json::{json, Value};
+
+use crate::traffic::Kind;
+
+/// How the node answered a submitted transaction
+pub struct Outcome {
+    pub kind: Kind,
+    /// Time between sending the transaction and the node's answer
+    pub latency: Duration,
+    /// `None` if the transaction was admitted to the pool, the node's reason otherwise
+    pub refusal: Option<String>,
+}
+
+/// Blocks including the admitted transactions
+pub struct Inclusion {
+    /// Transactions included, admitted by the run or not
+    pub transactions: u64,
+    pub blocks: u64,
+    /// Time between the first and the last block, from their timestamps
+    pub span: Duration,
+    /// Admitted transactions left pending when the inclusion wait timed out
+    pub pending: u64,
+}
+
+/// Summary of a run
+pub struct Report {
+    /// Wall time of the submissions
+    pub elapsed: Duration,
+    pub outcomes: Vec<Outcome>,
+    pub inclusion: Option<Inclusion>,
+}
+
+#[derive(Default)]
+struct KindSummary {
+    admitted: Vec<Duration>,
+    refused: Vec<Duration>,
+    reasons: BTreeMap<String, u64>,
+}
+
+impl Report {
+    fn by_kind(&self) -> BTreeMap<Kind, KindSummary> {
+        let mut kinds = BTreeMap::<Kind, KindSummary>::new();
+        for outcome in &self.outcomes {
+            let summary = kinds.entry(outcome.kind).or_default();
+            match &outcome.refusal {
+                None => summary.admitted.push(outcome.latency),
+                Some(reason) => {
+                    summary.refused.push(outcome.latency);
+                    *summary.reasons.entry(reason.clone()).or_default() += 1;
+                },
+            }
+        }
+        kinds
+    }
+
+    /// Transactions answered per second of the run
+    fn submission_tps(&self) -> f64 {
+        self.outcomes.len() as f64 / self.elapsed.as_secs_f64()
+    }
+
+    /// Transactions against the expectation of their kind, admitted but expected to be
+    /// refused or the other way round
+    fn unexpected(&self) -> usize {
+        self.outcomes
+            .iter()
+            .filter(|outcome| outcome.kind.expects_admission() != outcome.refusal.is_none())
+            .count()
+    }
+
+    /// Report as JSON, for the release checks to compare with a baseline
+    pub fn to_json(&self) -> Value {
+        let kinds: serde_json::Map<_, _> = self
+            .by_kind()
+            .into_iter()
+            .map(|(kind, summary)| {
+                let value = json!({
+                    "admitted": summary.admitted.len(),
+                    "refused": summary.refused.len(),
+                    "admissionLatencyMs": percentiles(summary.admitted).map(Percentiles::to_json),
+                    "rejectionLatencyMs": percentiles(summary.refused).map(Percentiles::to_json),
+                    "refusalReasons": summary.reasons,
+                });
+                (kind.name().to_string(), value)
+            })
+            .collect();
+        let inclusion = self.inclusion.as_ref().map(|inclusion| {
+            json!({
+                "transactions": inclusion.transactions,
+                "blocks": inclusion.blocks,
+                "seconds": inclusion.span.as_secs_f64(),
+                "tps": inclusion_tps(inclusion),
+                "pending": inclusion.pending,
+            })
+        });
+
+        json!({
+            "transactions": self.outcomes.len(),
+            "seconds": self.elapsed.as_secs_f64(),
+            "submissionTps": self.submission_tps(),
+            "unexpected": self.unexpected(),
+            "kinds": kinds,
+            "inclusion": inclusion,
+        })
+    }
+
+    /// Report as text, for a person reading the terminal
+    pub fn to_text(&self) -> String {
+        let mut lines = vec![format!(
+            "{} transactions answered in {:.2}s: {:.1} TPS submitted, {} unexpected",
+            self.outcomes.len(),
+            self.elapsed.as_secs_f64(),
+            self.submission_tps(),
+            self.unexpected(),
+        )];
+        if let Some(inclusion) = &self.inclusion {
+            lines.push(format!(
+                "{} transactions included in {} blocks over {:.2}s: {:.1} TPS, {} left pending",
+                inclusion.transactions,
+                inclusion.blocks,
+                inclusion.span.as_secs_f64(),
+                inclusion_tps(inclusion),
+                inclusion.pending,
+            ));
+        }
+
+        for (kind, summary) in self.by_kind() {
+            lines.push(String::new());
+            lines.push(format!(
+                "{kind}: {} admitted, {} refused",
+                summary.admitted.len(),
+                summary.refused.len()
+            ));
+            let latencies = [("admission", summary.admitted), ("rejection", summary.refused)];
+            for (label, latencies) in latencies {
+                if let Some(p) = percentiles(latencies) {
+                    lines.push(format!(
+                        "  {label} latency: p50 {:.1}ms, p95 {:.1}ms, p99 {:.1}ms, max {:.1}ms",
+                        p.p50, p.p95, p.p99, p.max
+                    ));
+                }
+            }
+            for (reason, count) in summary.reasons {
+                lines.push(format!("  {count} x {reason}"));
+            }
+        }
+
+        lines.join("\n")
+    }
+}
+
+/// Latency percentiles, in milliseconds
+#[derive(Clone, Copy)]
+struct Percentiles {
+    p50: f64,
+    p95: f64,
+    p99: f64,
+    max: f64,
+}
+
+impl Percentiles {
+    fn to_json(self) -> Value {
+        json!({ "p50": self.p50, "p95": self.p95, "p99": self.p99, "max": self.max })
+    }
+}
+
+fn percentiles(mut latencies: Vec<Duration>) -> Option<Percentiles> {
+    latencies.sort();
+    let at = |fraction: f64| {
+        let rank = ((latencies.len() as f64 * fraction).ceil() as usize).max(1);
+        latencies[rank - 1].as_secs_f64() * 1_000.0
+    };
+    (!latencies.is_empty()).then(|| Percentiles {
+        p50: at(0.50),
+        p95: at(0.95),
+        p99: at(0.99),
+        max: at(1.0),
+    })
+}
+
+fn inclusion_tps(inclusion: &Inclusion) -> f64 {
+    match inclusion.span.as_secs_f64() {
+        secs if secs > 0.0 => inclusion.transactions as f64 / secs,
+        _ => 0.0,
+    }
+}
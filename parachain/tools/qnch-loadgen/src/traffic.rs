@@ -0,0 +1,200 @@
+//! Traffic kinds, their mix and the signed transactions making them up.
+
+use std::{fmt, str::FromStr};
+
+use ethereum::{
+    EnvelopedEncodable, LegacyTransaction, LegacyTransactionMessage, TransactionAction,
+    TransactionSignature, TransactionV2,
+};
+use libsecp256k1::{Message, PublicKey, SecretKey};
+use sp_core::{hashing::keccak_256, H160, H256, U256};
+use tokio::sync::Mutex;
+
+/// Init code of the created contracts, returning an empty contract
+const INIT_CODE: [u8; 5] = [0x60, 0x00, 0x60, 0x00, 0xf3];
+
+/// Gas limit of the contract creations
+pub const CREATE_GAS_LIMIT: u64 = 100_000;
+
+/// Gas limit of the factory calls, which create a contract each
+pub const CALL_GAS_LIMIT: u64 = 1_000_000;
+
+/// Kind of generated transaction
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Kind {
+    /// Contract creation by an authorized deployer, expected to be admitted
+    Authorized,
+    /// Contract creation by a funded key that is not an authorized deployer, expected to be
+    /// refused by the transaction pool
+    Unauthorized,
+    /// Call to an approved factory by a funded key, expected to be admitted
+    Factory,
+}
+
+impl Kind {
+    pub const ALL: [Kind; 3] = [Kind::Authorized, Kind::Unauthorized, Kind::Factory];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Kind::Authorized => "authorized",
+            Kind::Unauthorized => "unauthorized",
+            Kind::Factory => "factory",
+        }
+    }
+
+    /// Whether the node is expected to admit transactions of the kind
+    pub fn expects_admission(self) -> bool {
+        self != Kind::Unauthorized
+    }
+}
+
+impl fmt::Display for Kind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// Shares of the traffic kinds, e.g. `authorized=60,unauthorized=30,factory=10`
+#[derive(Clone, Debug)]
+pub struct Mix(Vec<(Kind, u32)>);
+
+impl FromStr for Mix {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut shares = Vec::new();
+        for part in s.split(',') {
+            let (name, share) =
+                part.split_once('=').ok_or_else(|| format!("expected `kind=share`: {part}"))?;
+            let kind = Kind::ALL
+                .into_iter()
+                .find(|kind| kind.name() == name.trim())
+                .ok_or_else(|| format!("unknown traffic kind: {name}"))?;
+            let share = share.trim().parse().map_err(|_| format!("invalid share: {share}"))?;
+            shares.push((kind, share));
+        }
+        if shares.iter().all(|(_, share)| *share == 0) {
+            return Err("the mix needs a share above zero".into());
+        }
+        Ok(Self(shares))
+    }
+}
+
+impl Mix {
+    /// Share of `kind` in the mix
+    pub fn share(&self, kind: Kind) -> u32 {
+        self.0.iter().filter(|(k, _)| *k == kind).map(|(_, share)| share).sum()
+    }
+
+    /// Kind of the `index`-th transaction
+    ///
+    /// Every run of as many transactions as the total of the shares holds each kind in
+    /// proportion to its share.
+    pub fn kind_of(&self, index: u32) -> Kind {
+        let total: u32 = self.0.iter().map(|(_, share)| share).sum();
+        let mut slot = index % total;
+        for &(kind, share) in &self.0 {
+            if slot < share {
+                return kind;
+            }
+            slot -= share;
+        }
+        unreachable!("slot is below the total of the shares")
+    }
+}
+
+/// A funded key sending transactions in nonce order
+pub struct Sender {
+    pub secret: SecretKey,
+    pub address: H160,
+    /// Nonce of the next transaction, held while an admitted transaction of the key is
+    /// submitted
+    pub nonce: Mutex<u64>,
+}
+
+impl Sender {
+    pub fn new(secret: SecretKey, nonce: u64) -> Self {
+        Self { address: address_of(&secret), secret, nonce: Mutex::new(nonce) }
+    }
+}
+
+/// Parse a hex secret key, with or without `0x`
+pub fn parse_key(s: &str) -> Result<SecretKey, String> {
+    let bytes = parse_hex(s)?;
+    SecretKey::parse_slice(&bytes).map_err(|err| format!("invalid secret key: {err:?}"))
+}
+
+/// Parse hex bytes, with or without `0x`
+pub fn parse_hex(s: &str) -> Result<Vec<u8>, String> {
+    hex::decode(s.trim_start_matches("0x")).map_err(|err| format!("invalid hex: {err}"))
+}
+
+/// Parse a `0x` EVM address
+pub fn parse_address(s: &str) -> Result<H160, String> {
+    let bytes = parse_hex(s)?;
+    if bytes.len() != 20 {
+        return Err(format!("expected a 20-byte address: {s}"));
+    }
+    Ok(H160::from_slice(&bytes))
+}
+
+/// EVM address of a secret key
+pub fn address_of(secret: &SecretKey) -> H160 {
+    let public = PublicKey::from_secret_key(secret).serialize();
+    H160::from_slice(&keccak_256(&public[1..65])[12..])
+}
+
+/// Init code of the `index`-th creation of the run seeded by `seed`
+///
+/// The seed and index trail the returning code, so that no two creations share a hash: the
+/// transaction pool bans refused transactions for a while, and would answer a repeated one
+/// with the ban rather than with the authorization check.
+pub fn creation_code(seed: u64, index: u32) -> Vec<u8> {
+    let mut code = INIT_CODE.to_vec();
+    code.extend(seed.to_be_bytes());
+    code.extend(index.to_be_bytes());
+    code
+}
+
+/// Parameters of a transaction to sign
+pub struct Unsigned {
+    pub nonce: u64,
+    pub gas_price: U256,
+    pub gas_limit: u64,
+    pub action: TransactionAction,
+    pub input: Vec<u8>,
+}
+
+/// Legacy EIP-155 transaction signed by `secret` for `chain_id`, as sent to
+/// `eth_sendRawTransaction`
+pub fn sign(secret: &SecretKey, chain_id: u64, unsigned: Unsigned) -> Vec<u8> {
+    let message = LegacyTransactionMessage {
+        nonce: unsigned.nonce.into(),
+        gas_price: unsigned.gas_price,
+        gas_limit: unsigned.gas_limit.into(),
+        action: unsigned.action,
+        value: U256::zero(),
+        input: unsigned.input,
+        chain_id: Some(chain_id),
+    };
+    let (signature, recovery_id) = libsecp256k1::sign(&Message::parse(&message.hash().0), secret);
+    let rs = signature.serialize();
+    let signature = TransactionSignature::new(
+        u64::from(recovery_id.serialize()) + chain_id * 2 + 35,
+        H256::from_slice(&rs[..32]),
+        H256::from_slice(&rs[32..]),
+    )
+    .expect("libsecp256k1 signatures have a low s");
+
+    TransactionV2::Legacy(LegacyTransaction {
+        nonce: message.nonce,
+        gas_price: message.gas_price,
+        gas_limit: message.gas_limit,
+        action: message.action,
+        value: message.value,
+        input: message.input,
+        signature,
+    })
+    .encode()
+    .to_vec()
+}